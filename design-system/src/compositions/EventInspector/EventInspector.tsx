@@ -16,6 +16,7 @@ const typeToClass: Record<string, string> = {
   CLAUDE: 'claude',
   TOOL: 'tool',
   HOOK: 'hook',
+  PLUGIN: 'plugin',
   ERROR: 'error',
   ASSESS: 'assess',
 };
@@ -9,7 +9,7 @@ export interface DisplayEvent {
   id: string;
   /** When the event occurred */
   timestamp: Date;
-  /** Event category: SESSION, CLAUDE, TOOL, HOOK, ERROR, ASSESS */
+  /** Event category: SESSION, CLAUDE, TOOL, HOOK, PLUGIN, ERROR, ASSESS */
   type: string;
   /** Human-readable one-line summary */
   summary: string;
@@ -6,21 +6,16 @@
 //! - Handling lifecycle events (`on_load`, `on_unload`)
 //! - Tracking state across turns (`on_turn_complete`)
 //!
-//! ## Building
-//!
-//! ```bash
-//! cargo build --release
-//! ```
-//!
 //! ## Installing
 //!
 //! ```bash
-//! mkdir -p ~/.config/vibes/plugins/hello
-//! cp target/release/libhello_plugin.so ~/.config/vibes/plugins/hello/hello.so
+//! vibes plugin install examples/plugins/hello-plugin
 //! vibes plugin enable hello
 //! ```
 
-use vibes_plugin_api::{export_plugin, Plugin, PluginContext, PluginError, PluginManifest, Usage};
+use vibes_plugin_api::{
+    export_plugin, Permission, Plugin, PluginContext, PluginError, PluginManifest, Usage,
+};
 
 /// A simple plugin that logs turn completions and tracks token usage.
 #[derive(Default)]
@@ -40,6 +35,8 @@ impl Plugin for HelloPlugin {
             version: "0.1.0".to_string(),
             description: "A simple example plugin that tracks token usage".to_string(),
             author: "vibes-team".to_string(),
+            // Session events are only delivered with this permission
+            permissions: vec![Permission::ReadEvents],
             ..Default::default()
         }
     }
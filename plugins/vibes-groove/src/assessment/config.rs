@@ -3,9 +3,12 @@
 //! Provides configuration for the assessment framework including sampling rates,
 //! circuit breakers, LLM backend settings, and retention policies.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use super::types::AssessmentEvent;
+
 /// Main configuration for the assessment framework.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -110,15 +113,25 @@ impl Default for CircuitBreakerConfig {
 }
 
 /// LLM backend configuration for assessments.
+///
+/// Session analysis during extraction and the contradiction judge call the
+/// model through a vibes-models provider, and vibes-models only ships
+/// Ollama so far. With the default "harness" backend neither runs, and
+/// other names such as "anthropic" are rejected as unknown backends.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct LlmConfig {
     /// Whether LLM-based assessment is enabled.
     pub enabled: bool,
-    /// Backend to use for LLM calls ("harness" for Claude Code subprocess).
+    /// Backend to use for LLM calls: "harness" for the Claude Code subprocess,
+    /// or "ollama", the only vibes-models provider.
     pub backend: String,
     /// Model to use for assessments.
     pub model: String,
+    /// Provider endpoint override (e.g. a remote Ollama instance).
+    pub base_url: Option<String>,
+    /// Maximum tokens the model may generate per analysis.
+    pub max_tokens: u32,
     /// Timeout for LLM calls in seconds.
     pub timeout_seconds: u32,
     /// Maximum retries for failed LLM calls.
@@ -131,6 +144,8 @@ impl Default for LlmConfig {
             enabled: true,
             backend: "harness".to_string(),
             model: "claude-3-haiku".to_string(),
+            base_url: None,
+            max_tokens: 2048,
             timeout_seconds: 60,
             max_retries: 2,
         }
@@ -226,6 +241,29 @@ impl RetentionConfig {
     pub fn heavy_forever(&self) -> bool {
         self.heavy_days == Self::FOREVER
     }
+
+    /// Days to retain events of the named tier. `None` means forever.
+    pub fn days_for(&self, tier: &str) -> Option<u32> {
+        let days = match tier {
+            "lightweight" => self.lightweight_days,
+            "medium" => self.medium_days,
+            "heavy" => self.heavy_days,
+            _ => Self::FOREVER,
+        };
+        u32::try_from(days).ok()
+    }
+
+    /// Events of the named tier recorded before this are expired at `now`.
+    pub fn cutoff(&self, tier: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days_for(tier)
+            .map(|days| now - chrono::Duration::days(i64::from(days)))
+    }
+
+    /// Check if an event has outlived its tier's retention window at `now`.
+    pub fn is_expired(&self, event: &AssessmentEvent, now: DateTime<Utc>) -> bool {
+        self.cutoff(event.tier(), now)
+            .is_some_and(|cutoff| event.timestamp() < cutoff)
+    }
 }
 
 /// Iggy server configuration.
@@ -353,4 +391,27 @@ mod tests {
         // Verify constant value
         assert_eq!(RetentionConfig::FOREVER, -1);
     }
+
+    #[test]
+    fn retention_config_expires_by_tier() {
+        use crate::assessment::{AssessmentContext, LightweightEvent};
+
+        let config = RetentionConfig::default();
+        let now = Utc::now();
+        let mut context = AssessmentContext::new("sess-1");
+        context.timestamp = now - chrono::Duration::days(8);
+        let event = AssessmentEvent::Lightweight(LightweightEvent {
+            context,
+            message_idx: 0,
+            signals: vec![],
+            frustration_ema: 0.0,
+            success_ema: 1.0,
+            triggering_event_id: uuid::Uuid::now_v7(),
+        });
+
+        assert!(config.is_expired(&event, now));
+        assert!(!config.is_expired(&event, now - chrono::Duration::days(2)));
+        assert_eq!(config.days_for("medium"), Some(30));
+        assert_eq!(config.cutoff("heavy", now), None);
+    }
 }
@@ -11,6 +11,9 @@
 //! 3. **Resource limits**: Can constrain memory/CPU per analysis
 //! 4. **Fault isolation**: Crashes don't affect the main process
 //!
+//! Backends served by a vibes-models provider (e.g. `"ollama"`) go through
+//! [`LlmSessionAnalyzer`](super::llm_analyzer::LlmSessionAnalyzer) instead.
+//!
 //! ## Usage
 //!
 //! ```text
@@ -161,6 +164,15 @@ pub enum HarnessError {
     /// Serialization error.
     #[error("serialization error: {0}")]
     SerializationError(String),
+    /// Backend is not a known subprocess or model provider.
+    #[error("unknown LLM backend: {0}")]
+    UnknownBackend(String),
+    /// Model provider request failed.
+    #[error("model provider error: {0}")]
+    Provider(String),
+    /// Session's circuit breaker is open.
+    #[error("circuit open for session {0}")]
+    CircuitOpen(String),
 }
 
 /// Subprocess-based LLM harness.
@@ -18,6 +18,12 @@
 //! When disconnected from Iggy, events are buffered in memory (up to 10,000).
 //! When buffer is full, oldest events are dropped. On reconnection, buffered
 //! events are flushed to Iggy.
+//!
+//! # Retention
+//!
+//! Each tier topic is created with a message expiry taken from
+//! [`RetentionConfig`], so the server ages out persisted events on its own.
+//! [`AssessmentLog::expire`] only trims the in-memory buffer.
 
 use std::sync::Arc;
 
@@ -29,6 +35,7 @@ use tracing::{debug, info, warn};
 
 use crate::assessment::log::AssessmentLog;
 use crate::assessment::types::{AssessmentEvent, EventId, SessionId};
+use crate::assessment::{RetentionConfig, TierCounts};
 use crate::error::{GrooveError, Result};
 use vibes_iggy::IggyManager;
 
@@ -83,6 +90,9 @@ pub struct IggyAssessmentLog {
 
     /// Whether we're connected to the Iggy server.
     connected: RwLock<bool>,
+
+    /// Per-tier retention applied as topic message expiry.
+    retention: RetentionConfig,
 }
 
 impl IggyAssessmentLog {
@@ -105,6 +115,28 @@ impl IggyAssessmentLog {
             tx,
             buffer: RwLock::new(Vec::new()),
             connected: RwLock::new(false),
+            retention: RetentionConfig::default(),
+        }
+    }
+
+    /// Use the given retention for tier topics.
+    ///
+    /// Takes effect on the next `connect()`, which also updates topics that
+    /// already exist.
+    #[must_use]
+    pub fn with_retention(mut self, retention: RetentionConfig) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Message expiry for a topic under the configured retention.
+    #[must_use]
+    pub fn topic_expiry(&self, topic_name: &str) -> IggyExpiry {
+        match self.retention.days_for(topic_name) {
+            Some(days) => IggyExpiry::ExpireDuration(IggyDuration::new(
+                std::time::Duration::from_secs(u64::from(days) * 86_400),
+            )),
+            None => IggyExpiry::NeverExpire,
         }
     }
 
@@ -163,6 +195,7 @@ impl IggyAssessmentLog {
             topics::HEAVY_TOPIC,
             topics::STATS_TOPIC,
         ] {
+            let expiry = self.topic_expiry(topic_name);
             match self
                 .client
                 .create_topic(
@@ -171,7 +204,7 @@ impl IggyAssessmentLog {
                     topics::PARTITION_COUNT,
                     CompressionAlgorithm::None,
                     None, // replication_factor
-                    IggyExpiry::NeverExpire,
+                    expiry,
                     MaxTopicSize::ServerDefault,
                 )
                 .await
@@ -179,6 +212,9 @@ impl IggyAssessmentLog {
                 Ok(_) => info!("Created assessment topic '{topic_name}'"),
                 Err(e) if is_already_exists_error(&e) => {
                     debug!("Assessment topic '{topic_name}' already exists");
+                    // Retention may have changed since the topic was created
+                    self.update_topic_expiry(&stream_id, topic_name, expiry)
+                        .await;
                 }
                 Err(e) => {
                     return Err(GrooveError::Assessment(format!(
@@ -197,6 +233,40 @@ impl IggyAssessmentLog {
         Ok(())
     }
 
+    /// Apply the configured expiry to an existing topic.
+    ///
+    /// Failure only means old events are kept longer, so it is logged rather
+    /// than failing the connection.
+    async fn update_topic_expiry(
+        &self,
+        stream_id: &Identifier,
+        topic_name: &str,
+        expiry: IggyExpiry,
+    ) {
+        let topic_id = match Identifier::named(topic_name) {
+            Ok(id) => id,
+            Err(e) => {
+                warn!(topic = topic_name, error = %e, "Invalid topic name");
+                return;
+            }
+        };
+        if let Err(e) = self
+            .client
+            .update_topic(
+                stream_id,
+                &topic_id,
+                topic_name,
+                CompressionAlgorithm::None,
+                None, // replication_factor
+                expiry,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+        {
+            warn!(topic = topic_name, error = %e, "Failed to update topic retention");
+        }
+    }
+
     /// Determine which topic an event should be routed to.
     #[must_use]
     pub fn topic_for_event(event: &AssessmentEvent) -> &'static str {
@@ -629,6 +699,19 @@ impl AssessmentLog for IggyAssessmentLog {
     fn subscribe(&self) -> broadcast::Receiver<AssessmentEvent> {
         self.tx.subscribe()
     }
+
+    async fn expire(&self, retention: &RetentionConfig, now: DateTime<Utc>) -> Result<TierCounts> {
+        // Persisted events expire server-side through the topic expiry
+        let mut expired = TierCounts::default();
+        self.buffer.write().await.retain(|e| {
+            let keep = !retention.is_expired(e, now);
+            if !keep {
+                expired.increment(e.tier());
+            }
+            keep
+        });
+        Ok(expired)
+    }
 }
 
 #[cfg(test)]
@@ -719,6 +802,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn topic_expiry_follows_retention() {
+        let manager = Arc::new(IggyManager::new(IggyConfig::default()));
+        let log = IggyAssessmentLog::new(manager).with_retention(RetentionConfig {
+            lightweight_days: 7,
+            medium_days: 30,
+            heavy_days: RetentionConfig::FOREVER,
+        });
+
+        assert_eq!(
+            log.topic_expiry(topics::LIGHTWEIGHT_TOPIC),
+            IggyExpiry::ExpireDuration(IggyDuration::new(std::time::Duration::from_secs(
+                7 * 86_400
+            )))
+        );
+        assert_eq!(
+            log.topic_expiry(topics::HEAVY_TOPIC),
+            IggyExpiry::NeverExpire
+        );
+        assert_eq!(
+            log.topic_expiry(topics::STATS_TOPIC),
+            IggyExpiry::NeverExpire
+        );
+    }
+
+    #[tokio::test]
+    async fn iggy_log_expire_trims_buffer_by_tier() {
+        let manager = Arc::new(IggyManager::new(IggyConfig::default()));
+        let log = IggyAssessmentLog::new(manager);
+
+        log.append(make_lightweight_event("sess-1")).await.unwrap();
+        log.append(make_heavy_event("sess-1")).await.unwrap();
+
+        let later = Utc::now() + chrono::Duration::days(8);
+        let expired = log
+            .expire(&RetentionConfig::default(), later)
+            .await
+            .unwrap();
+
+        assert_eq!(expired.lightweight, 1);
+        assert_eq!(expired.heavy, 0);
+        assert_eq!(log.buffer_len().await, 1);
+    }
+
     #[tokio::test]
     async fn iggy_log_starts_disconnected() {
         let config = IggyConfig::default();
@@ -193,8 +193,14 @@ impl LightweightDetector {
         state.success_ema =
             SessionState::update_ema(state.success_ema, success_value, self.config.ema_alpha);
 
-        // Create lightweight event
-        let context = AssessmentContext::new(session_id);
+        // Create lightweight event, keeping the session's working directory
+        // so learnings can be scoped to its repository
+        let mut context = AssessmentContext::new(session_id);
+        if let VibesEvent::Hook { event, .. } = event
+            && let Some(cwd) = event.cwd()
+        {
+            context = context.with_project_id(cwd);
+        }
         let event = LightweightEvent {
             context,
             message_idx: state.message_idx,
@@ -806,6 +812,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hook_cwd_becomes_project() {
+        let detector = LightweightDetector::with_default_patterns();
+        let mut state = SessionState::new();
+        let mut event = make_hook_user_prompt("sess-1", "Fix the build");
+        if let VibesEvent::Hook {
+            event: HookEvent::UserPromptSubmit(data),
+            ..
+        } = &mut event
+        {
+            data.cwd = Some("/work/repo".to_string());
+        }
+
+        let result = detector
+            .process(&event, &mut state, test_event_id())
+            .unwrap();
+
+        assert_eq!(
+            result.context.project_id.as_ref().map(|p| p.as_str()),
+            Some("/work/repo")
+        );
+    }
+
     #[test]
     fn test_hook_user_prompt_updates_ema() {
         let detector = LightweightDetector::with_default_patterns();
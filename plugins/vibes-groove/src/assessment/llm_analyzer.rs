@@ -0,0 +1,782 @@
+//! Heavy-tier session analysis through a model provider.
+//!
+//! The `LlmSessionAnalyzer` sends a session transcript to any
+//! [`ModelProvider`] - a local Ollama model, Anthropic, or whatever else is
+//! registered with vibes-models - and asks for a JSON verdict matching
+//! [`analysis_schema`]. The reply is validated against the schema before it is
+//! turned into an outcome, a task summary and [`ExtractionCandidate`]s, so a
+//! sloppy model can't produce malformed learnings.
+//!
+//! ## Flow
+//!
+//! ```text
+//! LlmSessionAnalyzer::analyze(session_id, transcript)
+//!     │
+//!     ├─ Check circuit breaker → If open, return Err(CircuitOpen)
+//!     │
+//!     ├─ Render transcript + schema into a chat request
+//!     │
+//!     ├─ provider.chat() with timeout, retrying failed or invalid replies
+//!     │
+//!     ├─ Validate JSON against the schema → SessionAnalysis
+//!     │
+//!     └─ Record tokens and cost in AnalysisUsage
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::sync::RwLock;
+use vibes_models::Pricing;
+use vibes_models::providers::{ChatRequest, Message, ModelProvider, OllamaProvider};
+
+use super::circuit_breaker::{CircuitBreaker, CircuitState};
+use super::config::LlmConfig;
+use super::harness_llm::{Finding, HarnessError};
+use super::types::{AssessmentContext, ExtractionCandidate, HeavyEvent, Outcome, SessionId};
+use crate::capture::ParsedTranscript;
+
+/// Longest message body sent to the model, in characters.
+const MAX_MESSAGE_CHARS: usize = 2_000;
+
+/// System prompt for session analysis. The schema is appended at request time.
+const SYSTEM_PROMPT: &str = "\
+You review finished sessions between a developer and an AI coding agent. \
+Classify the outcome, summarise the task in one sentence, and list durable \
+learnings: preferences, conventions or fixes the agent should remember for \
+future sessions in this project. Only include learnings that generalise beyond \
+this session. Reference messages by their [index].
+
+Respond with a single JSON object and nothing else. It must match this JSON schema:";
+
+/// JSON schema the model's reply is validated against.
+pub fn analysis_schema() -> Value {
+    let message_range = json!({
+        "type": "array",
+        "items": {"type": "integer", "minimum": 0},
+        "minItems": 2,
+        "maxItems": 2
+    });
+    let confidence = json!({"type": "number", "minimum": 0.0, "maximum": 1.0});
+
+    json!({
+        "type": "object",
+        "required": ["outcome", "task_summary", "score", "findings", "learnings"],
+        "properties": {
+            "outcome": {
+                "type": "string",
+                "enum": ["success", "partial", "failure", "abandoned"]
+            },
+            "task_summary": {"type": "string"},
+            "score": {"type": "number", "minimum": -1.0, "maximum": 1.0},
+            "findings": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["finding_type", "description", "confidence"],
+                    "properties": {
+                        "finding_type": {
+                            "type": "string",
+                            "enum": [
+                                "frustration", "pattern_applied", "tool_failures",
+                                "correction", "progress", "task_complete"
+                            ]
+                        },
+                        "description": {"type": "string"},
+                        "message_range": message_range,
+                        "confidence": confidence
+                    }
+                }
+            },
+            "learnings": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["description", "insight", "message_range", "confidence"],
+                    "properties": {
+                        "description": {"type": "string"},
+                        "insight": {"type": "string"},
+                        "message_range": message_range,
+                        "confidence": confidence
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Token and cost accounting for LLM analysis.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisUsage {
+    /// Prompt tokens sent, across all attempts.
+    pub input_tokens: u64,
+    /// Completion tokens received, across all attempts.
+    pub output_tokens: u64,
+    /// Estimated cost in USD (zero when the provider has no pricing).
+    pub cost_usd: f64,
+}
+
+impl AnalysisUsage {
+    /// Total tokens in and out.
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    fn add(&mut self, other: &AnalysisUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_usd += other.cost_usd;
+    }
+}
+
+/// Running totals across every analysis an analyzer has run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    /// Analyses that produced a result.
+    pub analyses: u64,
+    /// Analyses that failed after all retries.
+    pub failures: u64,
+    /// Combined token and cost usage.
+    pub usage: AnalysisUsage,
+}
+
+/// Result of analysing one session.
+#[derive(Debug, Clone)]
+pub struct SessionAnalysis {
+    /// Classified session outcome.
+    pub outcome: Outcome,
+    /// What the session was trying to accomplish.
+    pub task_summary: String,
+    /// Overall score (-1.0 to 1.0).
+    pub score: f64,
+    /// Notable moments in the session.
+    pub findings: Vec<Finding>,
+    /// Learnings worth extracting.
+    pub candidates: Vec<ExtractionCandidate>,
+    /// Tokens and cost spent on this analysis.
+    pub usage: AnalysisUsage,
+}
+
+impl SessionAnalysis {
+    /// Build the heavy assessment event for this analysis.
+    #[must_use]
+    pub fn into_heavy_event(self, context: AssessmentContext) -> HeavyEvent {
+        HeavyEvent::new(context, self.outcome)
+            .with_task_summary(self.task_summary)
+            .with_extraction_candidates(self.candidates)
+    }
+}
+
+/// The model's reply, after schema validation.
+#[derive(Debug, Deserialize)]
+struct AnalysisReply {
+    outcome: Outcome,
+    task_summary: String,
+    score: f64,
+    findings: Vec<Finding>,
+    learnings: Vec<LearningReply>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LearningReply {
+    description: String,
+    insight: String,
+    message_range: (u32, u32),
+    confidence: f64,
+}
+
+/// Session analyzer backed by a [`ModelProvider`].
+pub struct LlmSessionAnalyzer {
+    provider: Arc<dyn ModelProvider>,
+    config: LlmConfig,
+    circuit_breaker: Option<Arc<RwLock<CircuitBreaker>>>,
+    totals: Mutex<UsageTotals>,
+}
+
+impl LlmSessionAnalyzer {
+    /// Create an analyzer that sends requests to `provider`.
+    pub fn new(provider: Arc<dyn ModelProvider>, config: LlmConfig) -> Self {
+        Self {
+            provider,
+            config,
+            circuit_breaker: None,
+            totals: Mutex::new(UsageTotals::default()),
+        }
+    }
+
+    /// Create an analyzer for the provider named by `config.backend`.
+    ///
+    /// Returns `Ok(None)` when LLM analysis is disabled or the backend is the
+    /// Claude Code subprocess, which [`HarnessLLM`](super::HarnessLLM) handles.
+    pub fn from_config(config: &LlmConfig) -> Result<Option<Self>, HarnessError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let provider: Arc<dyn ModelProvider> = match config.backend.as_str() {
+            "harness" | "mock" => return Ok(None),
+            "ollama" => Arc::new(match &config.base_url {
+                Some(url) => OllamaProvider::with_base_url(url),
+                None => OllamaProvider::new(),
+            }),
+            other => return Err(HarnessError::UnknownBackend(other.to_string())),
+        };
+        Ok(Some(Self::new(provider, config.clone())))
+    }
+
+    /// Skip sessions whose circuit is open.
+    ///
+    /// An open circuit means vibes just intervened and is waiting out the
+    /// cooldown; judging the session now would score it before the
+    /// intervention had a chance to work.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, circuit_breaker: Arc<RwLock<CircuitBreaker>>) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Name of the provider requests go to.
+    pub fn provider_name(&self) -> &str {
+        self.provider.name()
+    }
+
+    /// Token and cost totals so far.
+    pub fn usage(&self) -> UsageTotals {
+        self.totals.lock().unwrap().clone()
+    }
+
+    /// Analyze a finished session.
+    pub async fn analyze(
+        &self,
+        session_id: &SessionId,
+        transcript: &ParsedTranscript,
+    ) -> Result<SessionAnalysis, HarnessError> {
+        if !self.config.enabled {
+            return Err(HarnessError::Disabled);
+        }
+        if let Some(breaker) = &self.circuit_breaker
+            && breaker.read().await.state(session_id) == CircuitState::Open
+        {
+            return Err(HarnessError::CircuitOpen(session_id.as_str().to_string()));
+        }
+
+        let request = self.request(transcript);
+        let mut usage = AnalysisUsage::default();
+        let mut last_error = None;
+
+        for attempt in 0..=self.config.max_retries {
+            match self.attempt(request.clone(), &mut usage).await {
+                Ok(reply) => {
+                    let analysis = into_analysis(reply, usage);
+                    let mut totals = self.totals.lock().unwrap();
+                    totals.analyses += 1;
+                    totals.usage.add(&analysis.usage);
+                    tracing::debug!(
+                        session_id = %session_id,
+                        provider = self.provider.name(),
+                        input_tokens = analysis.usage.input_tokens,
+                        output_tokens = analysis.usage.output_tokens,
+                        cost_usd = analysis.usage.cost_usd,
+                        "LLM session analysis complete"
+                    );
+                    return Ok(analysis);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        attempt = attempt + 1,
+                        max_retries = self.config.max_retries,
+                        error = %e,
+                        "LLM analysis attempt failed"
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        let mut totals = self.totals.lock().unwrap();
+        totals.failures += 1;
+        totals.usage.add(&usage);
+        Err(last_error.unwrap_or(HarnessError::Disabled))
+    }
+
+    /// Send one request and validate the reply, adding its usage to `usage`.
+    async fn attempt(
+        &self,
+        request: ChatRequest,
+        usage: &mut AnalysisUsage,
+    ) -> Result<AnalysisReply, HarnessError> {
+        let timeout = Duration::from_secs(self.config.timeout_seconds.into());
+        let response = tokio::time::timeout(timeout, self.provider.chat(request))
+            .await
+            .map_err(|_| HarnessError::Timeout(self.config.timeout_seconds))?
+            .map_err(|e| HarnessError::Provider(e.to_string()))?;
+
+        let tokens = &response.usage;
+        usage.add(&AnalysisUsage {
+            input_tokens: tokens.input_tokens,
+            output_tokens: tokens.output_tokens,
+            cost_usd: self
+                .pricing()
+                .map(|p| p.calculate(tokens.input_tokens, tokens.output_tokens))
+                .unwrap_or_default(),
+        });
+
+        parse_reply(&response.content.as_text())
+    }
+
+    fn pricing(&self) -> Option<Pricing> {
+        self.provider.pricing(&self.config.model).or_else(|| {
+            self.provider
+                .models()
+                .into_iter()
+                .find(|m| m.name == self.config.model)
+                .and_then(|m| m.pricing)
+        })
+    }
+
+    fn request(&self, transcript: &ParsedTranscript) -> ChatRequest {
+        let schema = serde_json::to_string_pretty(&analysis_schema()).unwrap_or_default();
+        ChatRequest::new(
+            self.config.model.clone(),
+            vec![
+                Message::system(format!("{SYSTEM_PROMPT}\n{schema}")),
+                Message::user(render_transcript(transcript)),
+            ],
+        )
+        .temperature(0.0)
+        .max_tokens(self.config.max_tokens)
+    }
+}
+
+impl std::fmt::Debug for LlmSessionAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlmSessionAnalyzer")
+            .field("provider", &self.provider.name())
+            .field("model", &self.config.model)
+            .field("circuit_breaker", &self.circuit_breaker.is_some())
+            .finish()
+    }
+}
+
+/// Render the transcript as indexed messages followed by a tool summary.
+fn render_transcript(transcript: &ParsedTranscript) -> String {
+    let mut out = String::from("Session transcript:\n");
+    for (index, message) in transcript.messages.iter().enumerate() {
+        let content: String = message.content.chars().take(MAX_MESSAGE_CHARS).collect();
+        let ellipsis = if content.len() < message.content.len() {
+            " …"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "\n[{index}] {}: {content}{ellipsis}\n",
+            message.role
+        ));
+    }
+
+    if !transcript.tool_uses.is_empty() {
+        out.push_str("\nTool uses:\n");
+        for tool in &transcript.tool_uses {
+            let status = if tool.success { "ok" } else { "failed" };
+            out.push_str(&format!("- {} ({status})\n", tool.tool_name));
+        }
+    }
+    out
+}
+
+/// Parse and validate the model's reply.
+fn parse_reply(text: &str) -> Result<AnalysisReply, HarnessError> {
+    let json = strip_code_fence(text);
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| HarnessError::ParseFailed(format!("{e}: {text}")))?;
+    validate(&value, &analysis_schema(), "$").map_err(HarnessError::ParseFailed)?;
+    serde_json::from_value(value).map_err(|e| HarnessError::ParseFailed(e.to_string()))
+}
+
+/// Models often wrap JSON in a markdown fence despite being told not to.
+pub(crate) fn strip_code_fence(text: &str) -> &str {
+    let trimmed = text.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    let rest = rest.strip_prefix("json").unwrap_or(rest);
+    rest.strip_suffix("```").unwrap_or(rest).trim()
+}
+
+/// Check `value` against the subset of JSON schema used by [`analysis_schema`].
+fn validate(value: &Value, schema: &Value, path: &str) -> Result<(), String> {
+    let expected = schema.get("type").and_then(Value::as_str);
+    let type_ok = match expected {
+        Some("object") => value.is_object(),
+        Some("array") => value.is_array(),
+        Some("string") => value.is_string(),
+        Some("number") => value.is_number(),
+        Some("integer") => value.is_u64() || value.is_i64(),
+        _ => true,
+    };
+    if !type_ok {
+        return Err(format!("{path}: expected {}", expected.unwrap_or("value")));
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        return Err(format!("{path}: {value} is not one of {allowed:?}"));
+    }
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && n < min
+        {
+            return Err(format!("{path}: {n} is below {min}"));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && n > max
+        {
+            return Err(format!("{path}: {n} is above {max}"));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        for field in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !object.contains_key(field) {
+                return Err(format!("{path}: missing {field}"));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (field, field_schema) in properties {
+                if let Some(field_value) = object.get(field) {
+                    validate(field_value, field_schema, &format!("{path}.{field}"))?;
+                }
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        let len = items.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && len < min
+        {
+            return Err(format!("{path}: expected at least {min} items"));
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && len > max
+        {
+            return Err(format!("{path}: expected at most {max} items"));
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (i, item) in items.iter().enumerate() {
+                validate(item, item_schema, &format!("{path}[{i}]"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn into_analysis(reply: AnalysisReply, usage: AnalysisUsage) -> SessionAnalysis {
+    let candidates = reply
+        .learnings
+        .into_iter()
+        .filter(|l| !l.description.trim().is_empty())
+        .map(|l| {
+            ExtractionCandidate::new(l.message_range, l.description, l.confidence)
+                .with_insight(l.insight)
+        })
+        .collect();
+
+    SessionAnalysis {
+        outcome: reply.outcome,
+        task_summary: reply.task_summary,
+        score: reply.score,
+        findings: reply.findings,
+        candidates,
+        usage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::{TranscriptMessage, TranscriptToolUse};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use vibes_models::providers::{ChatResponse, ChatStream, Content, StopReason, Usage};
+    use vibes_models::{ModelInfo, Result as ModelResult};
+
+    const REPLY: &str = r#"{
+        "outcome": "success",
+        "task_summary": "Add a CLI flag for verbose logging",
+        "score": 0.7,
+        "findings": [
+            {"finding_type": "correction", "description": "User asked for clap derive",
+             "message_range": [1, 2], "confidence": 0.8}
+        ],
+        "learnings": [
+            {"description": "Use clap derive macros for CLI arguments",
+             "insight": "The project standardises on derive-based clap parsers",
+             "message_range": [1, 2], "confidence": 0.85}
+        ]
+    }"#;
+
+    /// Provider that replays canned replies and records requests.
+    struct ScriptedProvider {
+        replies: Vec<String>,
+        calls: AtomicUsize,
+        pricing: Option<Pricing>,
+        requests: Mutex<Vec<ChatRequest>>,
+    }
+
+    impl ScriptedProvider {
+        fn new(replies: &[&str]) -> Self {
+            Self {
+                replies: replies.iter().map(|r| r.to_string()).collect(),
+                calls: AtomicUsize::new(0),
+                pricing: None,
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn with_pricing(mut self, pricing: Pricing) -> Self {
+            self.pricing = Some(pricing);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl ModelProvider for ScriptedProvider {
+        fn name(&self) -> &str {
+            "scripted"
+        }
+
+        fn models(&self) -> Vec<ModelInfo> {
+            Vec::new()
+        }
+
+        async fn chat(&self, request: ChatRequest) -> ModelResult<ChatResponse> {
+            self.requests.lock().unwrap().push(request);
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let reply = self.replies[call.min(self.replies.len() - 1)].clone();
+            Ok(ChatResponse {
+                content: Content::text(reply),
+                stop_reason: StopReason::EndTurn,
+                tool_calls: vec![],
+                usage: Usage::new(1_000, 200),
+            })
+        }
+
+        async fn chat_stream(&self, _request: ChatRequest) -> ModelResult<ChatStream> {
+            Err(vibes_models::Error::ProviderApi(
+                "analysis does not stream".to_string(),
+            ))
+        }
+
+        fn pricing(&self, _model: &str) -> Option<Pricing> {
+            self.pricing.clone()
+        }
+    }
+
+    fn config() -> LlmConfig {
+        LlmConfig {
+            backend: "scripted".to_string(),
+            model: "test-model".to_string(),
+            max_retries: 1,
+            ..Default::default()
+        }
+    }
+
+    fn transcript() -> ParsedTranscript {
+        let messages = vec![
+            TranscriptMessage {
+                role: "user".to_string(),
+                content: "Add a --verbose flag".to_string(),
+                timestamp: None,
+            },
+            TranscriptMessage {
+                role: "user".to_string(),
+                content: "Use clap derive, not the builder".to_string(),
+                timestamp: None,
+            },
+        ];
+        let tool_uses = vec![TranscriptToolUse {
+            tool_name: "Edit".to_string(),
+            input: Value::Null,
+            output: None,
+            success: true,
+        }];
+        ParsedTranscript::new("s1", messages, tool_uses)
+    }
+
+    #[tokio::test]
+    async fn test_analyze_produces_candidates() {
+        let provider = Arc::new(ScriptedProvider::new(&[REPLY]));
+        let analyzer = LlmSessionAnalyzer::new(provider.clone(), config());
+
+        let analysis = analyzer
+            .analyze(&SessionId::from("s1"), &transcript())
+            .await
+            .unwrap();
+
+        assert_eq!(analysis.outcome, Outcome::Success);
+        assert_eq!(analysis.findings.len(), 1);
+        assert_eq!(analysis.candidates.len(), 1);
+        let candidate = &analysis.candidates[0];
+        assert_eq!(candidate.message_range, (1, 2));
+        assert_eq!(
+            candidate.insight.as_deref(),
+            Some("The project standardises on derive-based clap parsers")
+        );
+
+        let requests = provider.requests.lock().unwrap();
+        assert_eq!(requests[0].model, "test-model");
+        assert!(
+            requests[0].messages[0]
+                .content
+                .as_text()
+                .contains("\"learnings\"")
+        );
+        assert!(
+            requests[0].messages[1]
+                .content
+                .as_text()
+                .contains("[1] user: Use clap derive")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_analyze_retries_invalid_reply_and_counts_tokens() {
+        let invalid = r#"{"outcome": "great", "task_summary": "", "score": 0,
+                          "findings": [], "learnings": []}"#;
+        let provider = Arc::new(
+            ScriptedProvider::new(&[invalid, &format!("```json\n{REPLY}\n```")])
+                .with_pricing(Pricing::new(3.0, 15.0)),
+        );
+        let analyzer = LlmSessionAnalyzer::new(provider, config());
+
+        let analysis = analyzer
+            .analyze(&SessionId::from("s1"), &transcript())
+            .await
+            .unwrap();
+
+        assert_eq!(analysis.usage.input_tokens, 2_000);
+        assert_eq!(analysis.usage.output_tokens, 400);
+        assert!((analysis.usage.cost_usd - 0.012).abs() < 1e-9);
+
+        let totals = analyzer.usage();
+        assert_eq!(totals.analyses, 1);
+        assert_eq!(totals.failures, 0);
+        assert_eq!(totals.usage, analysis.usage);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_gives_up_after_retries() {
+        let provider = Arc::new(ScriptedProvider::new(&["I think it went well!"]));
+        let analyzer = LlmSessionAnalyzer::new(provider.clone(), config());
+
+        let result = analyzer
+            .analyze(&SessionId::from("s1"), &transcript())
+            .await;
+
+        assert!(matches!(result, Err(HarnessError::ParseFailed(_))));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(analyzer.usage().failures, 1);
+        assert_eq!(analyzer.usage().usage.input_tokens, 2_000);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_skips_open_circuit() {
+        use crate::assessment::config::CircuitBreakerConfig;
+        use crate::assessment::types::{LightweightEvent, LightweightSignal};
+
+        let mut breaker = CircuitBreaker::new(CircuitBreakerConfig::default());
+        let event = LightweightEvent::new(AssessmentContext::new("s1"), 0, uuid::Uuid::now_v7())
+            .with_signal(LightweightSignal::Negative {
+                pattern: "frustrated".to_string(),
+                confidence: 1.0,
+            });
+        breaker.record_event(&event);
+        let breaker = Arc::new(RwLock::new(breaker));
+
+        let provider = Arc::new(ScriptedProvider::new(&[REPLY]));
+        let analyzer =
+            LlmSessionAnalyzer::new(provider.clone(), config()).with_circuit_breaker(breaker);
+
+        let result = analyzer
+            .analyze(&SessionId::from("s1"), &transcript())
+            .await;
+        assert!(matches!(result, Err(HarnessError::CircuitOpen(_))));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+
+        let other = analyzer
+            .analyze(&SessionId::from("s2"), &transcript())
+            .await;
+        assert!(other.is_ok());
+    }
+
+    #[test]
+    fn test_from_config_selects_backend() {
+        let ollama = LlmConfig {
+            backend: "ollama".to_string(),
+            ..Default::default()
+        };
+        let analyzer = LlmSessionAnalyzer::from_config(&ollama).unwrap().unwrap();
+        assert_eq!(analyzer.provider_name(), "ollama");
+
+        assert!(
+            LlmSessionAnalyzer::from_config(&LlmConfig::default())
+                .unwrap()
+                .is_none()
+        );
+
+        let unknown = LlmConfig {
+            backend: "carrier-pigeon".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            LlmSessionAnalyzer::from_config(&unknown),
+            Err(HarnessError::UnknownBackend(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_config_has_no_anthropic_provider() {
+        let anthropic = LlmConfig {
+            backend: "anthropic".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            LlmSessionAnalyzer::from_config(&anthropic),
+            Err(HarnessError::UnknownBackend(backend)) if backend == "anthropic"
+        ));
+    }
+
+    #[test]
+    fn test_validate_reports_path() {
+        let reply: Value = serde_json::from_str(
+            r#"{"outcome": "success", "task_summary": "x", "score": 0.1, "findings": [],
+                "learnings": [{"description": "d", "insight": "i",
+                               "message_range": [1], "confidence": 0.5}]}"#,
+        )
+        .unwrap();
+
+        let err = validate(&reply, &analysis_schema(), "$").unwrap_err();
+        assert!(err.starts_with("$.learnings[0].message_range"), "{err}");
+    }
+
+    #[test]
+    fn test_into_heavy_event() {
+        let reply = parse_reply(REPLY).unwrap();
+        let event = into_analysis(reply, AnalysisUsage::default())
+            .into_heavy_event(AssessmentContext::new("s1"));
+
+        assert_eq!(event.outcome, Outcome::Success);
+        assert_eq!(event.task_summary, "Add a CLI flag for verbose logging");
+        assert_eq!(event.extraction_candidates.len(), 1);
+    }
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tokio::sync::broadcast;
 
-use super::{AssessmentEvent, EventId, SessionId};
+use super::{AssessmentEvent, EventId, RetentionConfig, SessionId, TierCounts};
 use crate::error::Result;
 
 /// Trait for assessment event log storage.
@@ -17,6 +17,7 @@ use crate::error::Result;
 /// - Session-scoped queries
 /// - Time-range queries
 /// - Real-time subscription
+/// - Per-tier retention
 #[async_trait]
 pub trait AssessmentLog: Send + Sync {
     /// Append an event to the immutable log.
@@ -38,6 +39,11 @@ pub trait AssessmentLog: Send + Sync {
     ///
     /// Returns a broadcast receiver for new events.
     fn subscribe(&self) -> broadcast::Receiver<AssessmentEvent>;
+
+    /// Drop events that have outlived their tier's retention window at `now`.
+    ///
+    /// Returns how many events were dropped per tier.
+    async fn expire(&self, retention: &RetentionConfig, now: DateTime<Utc>) -> Result<TierCounts>;
 }
 
 /// In-memory implementation for testing.
@@ -115,6 +121,18 @@ impl AssessmentLog for InMemoryAssessmentLog {
     fn subscribe(&self) -> broadcast::Receiver<AssessmentEvent> {
         self.tx.subscribe()
     }
+
+    async fn expire(&self, retention: &RetentionConfig, now: DateTime<Utc>) -> Result<TierCounts> {
+        let mut expired = TierCounts::default();
+        self.events.write().unwrap().retain(|e| {
+            let keep = !retention.is_expired(e, now);
+            if !keep {
+                expired.increment(e.tier());
+            }
+            keep
+        });
+        Ok(expired)
+    }
 }
 
 #[cfg(test)]
@@ -146,6 +164,21 @@ mod tests {
         assert_eq!(*events[0].event_id(), event_id);
     }
 
+    #[tokio::test]
+    async fn in_memory_log_expires_by_tier() {
+        let log = InMemoryAssessmentLog::new();
+        log.append(make_lightweight_event("sess-1")).await.unwrap();
+
+        let retention = RetentionConfig::default();
+        let expired = log.expire(&retention, Utc::now()).await.unwrap();
+        assert_eq!(expired.total(), 0);
+
+        let later = Utc::now() + chrono::Duration::days(8);
+        let expired = log.expire(&retention, later).await.unwrap();
+        assert_eq!(expired.lightweight, 1);
+        assert!(log.is_empty());
+    }
+
     #[tokio::test]
     async fn in_memory_log_filters_by_session() {
         let log = InMemoryAssessmentLog::new();
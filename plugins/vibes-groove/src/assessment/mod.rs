@@ -32,6 +32,7 @@ pub mod harness_llm;
 pub mod iggy;
 pub mod intervention;
 pub mod lightweight;
+pub mod llm_analyzer;
 pub mod log;
 pub mod processor;
 pub mod sampling;
@@ -64,6 +65,9 @@ pub use intervention::{
     HookIntervention, InterventionConfig, InterventionError, InterventionResult, Learning,
 };
 pub use lightweight::{LightweightDetector, LightweightDetectorConfig, SessionState};
+pub use llm_analyzer::{
+    AnalysisUsage, LlmSessionAnalyzer, SessionAnalysis, UsageTotals, analysis_schema,
+};
 pub use log::{AssessmentLog, InMemoryAssessmentLog};
 pub use processor::AssessmentProcessor;
 pub use sampling::{SamplingContext, SamplingDecision, SamplingStrategy};
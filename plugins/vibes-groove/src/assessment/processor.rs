@@ -11,7 +11,9 @@ use tracing::{debug, error, trace};
 
 use super::checkpoint::{CheckpointConfig, CheckpointManager};
 use super::circuit_breaker::{CircuitBreaker, CircuitState, CircuitTransition};
+use super::harness_llm::HarnessError;
 use super::lightweight::{LightweightDetector, LightweightDetectorConfig, SessionState};
+use super::llm_analyzer::LlmSessionAnalyzer;
 use super::session_buffer::{SessionBuffer, SessionBufferConfig};
 use super::types::{MediumEvent, SessionId};
 use super::{AssessmentConfig, AssessmentEvent, AssessmentLog};
@@ -217,6 +219,25 @@ impl AssessmentProcessor {
         cb.state(session_id)
     }
 
+    /// Get a shared handle to the circuit breaker.
+    ///
+    /// Pass this to [`LlmSessionAnalyzer::with_circuit_breaker`] so heavy
+    /// analysis skips sessions that are cooling down after an intervention.
+    ///
+    /// [`LlmSessionAnalyzer::with_circuit_breaker`]: super::LlmSessionAnalyzer::with_circuit_breaker
+    pub fn circuit_breaker(&self) -> Arc<RwLock<CircuitBreaker>> {
+        Arc::clone(&self.circuit_breaker)
+    }
+
+    /// Create the heavy-tier session analyzer configured in `config.llm`.
+    ///
+    /// The analyzer shares this processor's circuit breaker. Returns
+    /// `Ok(None)` when LLM analysis is disabled or runs through the harness.
+    pub fn session_analyzer(&self) -> Result<Option<LlmSessionAnalyzer>, HarnessError> {
+        Ok(LlmSessionAnalyzer::from_config(&self.config.llm)?
+            .map(|analyzer| analyzer.with_circuit_breaker(self.circuit_breaker())))
+    }
+
     /// Get the number of events buffered for a session.
     ///
     /// This is primarily useful for testing and debugging.
@@ -258,7 +258,8 @@ pub struct AssessmentContext {
     /// Version of the harness (e.g., "1.0.54").
     pub harness_version: Option<String>,
 
-    /// The project this session is associated with.
+    /// The project this session is associated with: its working directory
+    /// when the harness reports one.
     pub project_id: Option<ProjectId>,
 
     /// The user running this session.
@@ -695,6 +696,10 @@ pub struct ExtractionCandidate {
 
     /// Confidence that this should be extracted (0.0 to 1.0).
     pub confidence: f64,
+
+    /// Why the learning matters, when the analysis explained it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insight: Option<String>,
 }
 
 impl ExtractionCandidate {
@@ -705,8 +710,16 @@ impl ExtractionCandidate {
             message_range,
             description: description.into(),
             confidence: confidence.clamp(0.0, 1.0),
+            insight: None,
         }
     }
+
+    /// Set the insight.
+    #[must_use]
+    pub fn with_insight(mut self, insight: impl Into<String>) -> Self {
+        self.insight = Some(insight.into());
+        self
+    }
 }
 
 /// Heavy assessment event (full session analysis).
@@ -829,6 +842,16 @@ impl AssessmentEvent {
     pub fn timestamp(&self) -> DateTime<Utc> {
         self.context().timestamp
     }
+
+    /// Get the tier name for this event.
+    #[must_use]
+    pub fn tier(&self) -> &'static str {
+        match self {
+            Self::Lightweight(_) => "lightweight",
+            Self::Medium(_) => "medium",
+            Self::Heavy(_) => "heavy",
+        }
+    }
 }
 
 #[cfg(test)]
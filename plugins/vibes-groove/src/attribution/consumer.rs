@@ -427,7 +427,7 @@ fn outcome_to_value(outcome: &Outcome) -> f64 {
 }
 
 /// Create initial learning value for a new learning
-fn create_initial_learning_value(learning_id: LearningId) -> LearningValue {
+pub(crate) fn create_initial_learning_value(learning_id: LearningId) -> LearningValue {
     LearningValue {
         learning_id,
         estimated_value: 0.0,
@@ -16,6 +16,7 @@ pub use activation::{
     ActivationConfig, ActivationDetector, ActivationResult, HybridActivationDetector,
 };
 pub use aggregation::{AggregationConfig, ValueAggregator};
+pub(crate) use consumer::create_initial_learning_value;
 pub use consumer::{
     AttributionConfig, AttributionConsumer, AttributionResult as AttributionConsumerResult,
     ConsumerLoopResult as AttributionConsumerLoopResult, LearningLoader, LightweightEventFetcher,
@@ -0,0 +1,153 @@
+//! File-backed transcript fetcher
+//!
+//! Sessions are registered with the path of their transcript and, when
+//! known, the harness that wrote it. Fetching reads the file and parses it
+//! with that harness's format, or sniffs the format from the content.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use super::ParsedTranscript;
+use super::formats::{TranscriptFormat, detect_format, format_for_harness};
+use crate::Result;
+use crate::assessment::SessionId;
+use crate::attribution::AttributionTranscriptFetcher;
+use crate::extraction::TranscriptFetcher;
+
+/// Where a session's transcript lives
+#[derive(Debug, Clone)]
+struct TranscriptSource {
+    path: PathBuf,
+    harness: Option<String>,
+}
+
+/// Fetches transcripts from session log files of any supported harness
+#[derive(Debug, Default)]
+pub struct FileTranscriptFetcher {
+    sources: RwLock<HashMap<String, TranscriptSource>>,
+}
+
+impl FileTranscriptFetcher {
+    /// Create a fetcher with no registered sessions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the transcript file for a session
+    ///
+    /// `harness` is the command name ("claude", "aider", "codex", ...); when
+    /// `None` or unrecognised the format is detected from the file content.
+    pub async fn register(
+        &self,
+        session_id: impl Into<String>,
+        path: impl Into<PathBuf>,
+        harness: Option<&str>,
+    ) {
+        self.sources.write().await.insert(
+            session_id.into(),
+            TranscriptSource {
+                path: path.into(),
+                harness: harness.map(String::from),
+            },
+        );
+    }
+
+    async fn load(&self, session_id: &SessionId) -> Result<Option<ParsedTranscript>> {
+        let Some(source) = self.sources.read().await.get(session_id.as_str()).cloned() else {
+            return Ok(None);
+        };
+
+        let content = match tokio::fs::read_to_string(&source.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let format: Box<dyn TranscriptFormat> = source
+            .harness
+            .as_deref()
+            .and_then(format_for_harness)
+            .unwrap_or_else(|| detect_format(&content));
+        format.parse(&content, session_id.as_str()).map(Some)
+    }
+}
+
+#[async_trait]
+impl TranscriptFetcher for FileTranscriptFetcher {
+    async fn fetch(&self, session_id: &SessionId) -> Result<Option<ParsedTranscript>> {
+        self.load(session_id).await
+    }
+}
+
+#[async_trait]
+impl AttributionTranscriptFetcher for FileTranscriptFetcher {
+    async fn fetch(&self, session_id: &SessionId) -> Result<Option<ParsedTranscript>> {
+        self.load(session_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_fetch_unregistered_session_returns_none() {
+        let fetcher = FileTranscriptFetcher::new();
+        let result = TranscriptFetcher::fetch(&fetcher, &SessionId::from("missing")).await;
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_missing_file_returns_none() {
+        let temp = TempDir::new().unwrap();
+        let fetcher = FileTranscriptFetcher::new();
+        fetcher
+            .register("s1", temp.path().join("gone.jsonl"), Some("codex"))
+            .await;
+
+        let result = TranscriptFetcher::fetch(&fetcher, &SessionId::from("s1")).await;
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_uses_harness_format() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("history.md");
+        tokio::fs::write(&path, "#### Add tests\n\nDone.\n")
+            .await
+            .unwrap();
+
+        let fetcher = FileTranscriptFetcher::new();
+        fetcher.register("s1", &path, Some("aider")).await;
+
+        let transcript = AttributionTranscriptFetcher::fetch(&fetcher, &SessionId::from("s1"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(transcript.session_id, "s1");
+        assert_eq!(transcript.metadata.user_messages, 1);
+        assert_eq!(transcript.metadata.assistant_messages, 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_detects_format_without_harness() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("chat.json");
+        tokio::fs::write(&path, r#"[{"role": "user", "content": "hi"}]"#)
+            .await
+            .unwrap();
+
+        let fetcher = FileTranscriptFetcher::new();
+        fetcher.register("s1", &path, None).await;
+
+        let transcript = TranscriptFetcher::fetch(&fetcher, &SessionId::from("s1"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(transcript.messages[0].content, "hi");
+    }
+}
@@ -0,0 +1,246 @@
+//! Aider chat history parser
+//!
+//! Aider appends every session to `.aider.chat.history.md`. Each session
+//! starts with a `# aider chat started at ...` heading, user input is written
+//! as `#### ` lines, aider's own output (edit results, command output) is
+//! quoted with `> `, and everything else is the model's reply.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::json;
+
+use super::TranscriptFormat;
+use crate::GrooveError;
+use crate::capture::{ParsedTranscript, TranscriptMessage, TranscriptToolUse};
+
+const SESSION_HEADER: &str = "# aider chat started at ";
+const USER_PREFIX: &str = "#### ";
+
+/// Parser for Aider `.aider.chat.history.md` files
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AiderHistoryParser;
+
+impl TranscriptFormat for AiderHistoryParser {
+    fn harness(&self) -> &'static str {
+        "aider"
+    }
+
+    fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError> {
+        let mut history = History::default();
+
+        for line in content.lines() {
+            if let Some(started) = line.strip_prefix(SESSION_HEADER) {
+                history.flush();
+                history.timestamp = parse_timestamp(started);
+            } else if let Some(text) = line.strip_prefix(USER_PREFIX) {
+                history.close_command();
+                history.push_text("user", text);
+            } else if line == ">" || line.starts_with("> ") {
+                history.aider_output(line.trim_start_matches('>').trim());
+            } else if history.seen_user {
+                history.close_command();
+                history.push_text("assistant", line);
+            }
+        }
+        history.flush();
+
+        Ok(ParsedTranscript::new(
+            session_id,
+            history.messages,
+            history.tool_uses,
+        ))
+    }
+}
+
+/// Accumulates messages and tool uses while walking the history file
+#[derive(Default)]
+struct History {
+    messages: Vec<TranscriptMessage>,
+    tool_uses: Vec<TranscriptToolUse>,
+    timestamp: Option<DateTime<Utc>>,
+    /// Message currently being built, as (role, lines)
+    current: Option<(&'static str, Vec<String>)>,
+    /// Index of a `/run` command still collecting output
+    running: Option<usize>,
+    /// Startup banners are quoted too, so ignore text before the first prompt
+    seen_user: bool,
+}
+
+impl History {
+    fn push_text(&mut self, role: &'static str, line: &str) {
+        if role == "user" {
+            self.seen_user = true;
+        }
+        match &mut self.current {
+            Some((current, lines)) if *current == role => lines.push(line.to_string()),
+            _ => {
+                self.flush_message();
+                self.current = Some((role, vec![line.to_string()]));
+            }
+        }
+    }
+
+    fn aider_output(&mut self, text: &str) {
+        if !self.seen_user {
+            return;
+        }
+        self.flush_message();
+
+        if let Some(file) = text.strip_prefix("Applied edit to ") {
+            self.close_command();
+            self.push_edit(file, true, text);
+        } else if let Some(file) = text.strip_prefix("Failed to apply edit to ") {
+            self.close_command();
+            self.push_edit(file, false, text);
+        } else if let Some(command) = text.strip_prefix("Running ") {
+            self.close_command();
+            self.tool_uses.push(TranscriptToolUse {
+                tool_name: "Bash".to_string(),
+                input: json!({ "command": command }),
+                output: None,
+                success: true,
+            });
+            self.running = Some(self.tool_uses.len() - 1);
+        } else if let Some(index) = self.running {
+            let output = self.tool_uses[index].output.get_or_insert_with(String::new);
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.push_str(text);
+        }
+    }
+
+    fn push_edit(&mut self, file: &str, success: bool, output: &str) {
+        self.tool_uses.push(TranscriptToolUse {
+            tool_name: "Edit".to_string(),
+            input: json!({ "file_path": file.trim() }),
+            output: Some(output.to_string()),
+            success,
+        });
+    }
+
+    fn close_command(&mut self) {
+        if let Some(index) = self.running.take()
+            && let Some(output) = &mut self.tool_uses[index].output
+        {
+            *output = output.trim().to_string();
+        }
+    }
+
+    fn flush_message(&mut self) {
+        if let Some((role, lines)) = self.current.take() {
+            let content = lines.join("\n").trim().to_string();
+            if !content.is_empty() {
+                self.messages.push(TranscriptMessage {
+                    role: role.to_string(),
+                    content,
+                    timestamp: self.timestamp,
+                });
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        self.close_command();
+        self.flush_message();
+    }
+}
+
+/// Aider writes the local start time without a zone; treat it as UTC
+fn parse_timestamp(text: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(text.trim(), "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORY: &str = "\
+# aider chat started at 2025-01-15 10:30:00
+
+> /usr/local/bin/aider --model gpt-4o
+> Aider v0.82.1
+
+#### Add a retry helper
+#### with exponential backoff
+
+I'll add a `retry` function to `src/http.rs`.
+
+> Applied edit to src/http.rs
+
+#### /run cargo test
+> Running cargo test
+> test result: ok. 3 passed
+>
+> Add command output to the chat? (Y)es/(N)o [Yes]: n
+
+#### Rename it to with_retry
+> Failed to apply edit to src/http.rs
+";
+
+    #[test]
+    fn test_parse_merges_consecutive_user_lines() {
+        let transcript = AiderHistoryParser.parse(HISTORY, "s1").unwrap();
+
+        assert_eq!(transcript.messages[0].role, "user");
+        assert_eq!(
+            transcript.messages[0].content,
+            "Add a retry helper\nwith exponential backoff"
+        );
+        assert_eq!(transcript.messages[1].role, "assistant");
+        assert!(transcript.messages[1].content.contains("retry"));
+        assert_eq!(transcript.metadata.user_messages, 3);
+        assert_eq!(transcript.metadata.assistant_messages, 1);
+    }
+
+    #[test]
+    fn test_parse_skips_startup_banner() {
+        let transcript = AiderHistoryParser.parse(HISTORY, "s1").unwrap();
+        assert!(
+            transcript
+                .messages
+                .iter()
+                .all(|m| !m.content.contains("Aider v0.82.1"))
+        );
+    }
+
+    #[test]
+    fn test_parse_extracts_edits_and_commands() {
+        let transcript = AiderHistoryParser.parse(HISTORY, "s1").unwrap();
+        let tools = &transcript.tool_uses;
+
+        assert_eq!(tools.len(), 3);
+        assert_eq!(tools[0].tool_name, "Edit");
+        assert_eq!(tools[0].input["file_path"], "src/http.rs");
+        assert!(tools[0].success);
+
+        assert_eq!(tools[1].tool_name, "Bash");
+        assert_eq!(tools[1].input["command"], "cargo test");
+        assert!(
+            tools[1]
+                .output
+                .as_deref()
+                .unwrap()
+                .starts_with("test result: ok")
+        );
+
+        assert_eq!(tools[2].tool_name, "Edit");
+        assert!(!tools[2].success);
+    }
+
+    #[test]
+    fn test_parse_uses_session_start_timestamp() {
+        let transcript = AiderHistoryParser.parse(HISTORY, "s1").unwrap();
+        let expected = parse_timestamp("2025-01-15 10:30:00");
+        assert!(expected.is_some());
+        assert_eq!(transcript.messages[0].timestamp, expected);
+    }
+
+    #[test]
+    fn test_parse_empty_history() {
+        let transcript = AiderHistoryParser.parse("", "s1").unwrap();
+        assert!(transcript.messages.is_empty());
+        assert!(transcript.tool_uses.is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+//! Codex CLI rollout parser
+//!
+//! Codex CLI records each session as a rollout JSONL file under
+//! `~/.codex/sessions/`. Current versions wrap every line as
+//! `{"timestamp", "type", "payload"}` where conversation items have type
+//! `response_item`; older versions wrote the items bare. Tool calls and their
+//! outputs are separate items joined by `call_id`.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::TranscriptFormat;
+use crate::GrooveError;
+use crate::capture::{ParsedTranscript, TranscriptMessage, TranscriptToolUse};
+
+/// Line types written by current Codex CLI versions
+const LINE_TYPES: &[&str] = &[
+    "session_meta",
+    "response_item",
+    "event_msg",
+    "turn_context",
+    "compacted",
+];
+
+/// Context blocks Codex injects as user messages
+const INJECTED_PREFIXES: &[&str] = &["<environment_context>", "<user_instructions>"];
+
+/// Parser for Codex CLI rollout JSONL files
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CodexRolloutParser;
+
+impl TranscriptFormat for CodexRolloutParser {
+    fn harness(&self) -> &'static str {
+        "codex"
+    }
+
+    fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError> {
+        let mut messages = Vec::new();
+        let mut tool_uses: Vec<TranscriptToolUse> = Vec::new();
+        let mut calls: HashMap<String, usize> = HashMap::new();
+
+        for line in content.lines() {
+            let Ok(value) = serde_json::from_str::<Value>(line.trim()) else {
+                continue;
+            };
+            let timestamp = value
+                .get("timestamp")
+                .and_then(Value::as_str)
+                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+                .map(|ts| ts.with_timezone(&Utc));
+
+            let item = match value.get("type").and_then(Value::as_str) {
+                Some("response_item") => match value.get("payload") {
+                    Some(payload) => payload,
+                    None => continue,
+                },
+                _ => &value,
+            };
+
+            match item.get("type").and_then(Value::as_str) {
+                Some("message") => {
+                    if let Some(message) = message(item, timestamp) {
+                        messages.push(message);
+                    }
+                }
+                Some("function_call" | "custom_tool_call" | "local_shell_call") => {
+                    let tool_use = tool_call(item);
+                    if let Some(call_id) = item.get("call_id").and_then(Value::as_str) {
+                        calls.insert(call_id.to_string(), tool_uses.len());
+                    }
+                    tool_uses.push(tool_use);
+                }
+                Some("function_call_output" | "custom_tool_call_output") => {
+                    let index = item
+                        .get("call_id")
+                        .and_then(Value::as_str)
+                        .and_then(|call_id| calls.get(call_id));
+                    if let Some(&index) = index {
+                        let (output, success) = tool_output(item.get("output"));
+                        tool_uses[index].output = Some(output);
+                        tool_uses[index].success = success;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(ParsedTranscript::new(session_id, messages, tool_uses))
+    }
+}
+
+/// Whether a JSONL line looks like it came from a Codex rollout
+pub(super) fn is_rollout_line(line: &str) -> bool {
+    let Ok(value) = serde_json::from_str::<Value>(line) else {
+        return false;
+    };
+    match value.get("type").and_then(Value::as_str) {
+        Some(kind) if LINE_TYPES.contains(&kind) => true,
+        Some("message") => value.get("content").is_some_and(Value::is_array),
+        // Older rollouts open with a bare session header
+        _ => value.get("id").is_some() && value.get("instructions").is_some(),
+    }
+}
+
+fn message(item: &Value, timestamp: Option<DateTime<Utc>>) -> Option<TranscriptMessage> {
+    let role = item.get("role").and_then(Value::as_str)?;
+    if role != "user" && role != "assistant" {
+        return None;
+    }
+
+    let content = item
+        .get("content")?
+        .as_array()?
+        .iter()
+        .filter(|part| {
+            matches!(
+                part.get("type").and_then(Value::as_str),
+                Some("input_text" | "output_text" | "text")
+            )
+        })
+        .filter_map(|part| part.get("text").and_then(Value::as_str))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let trimmed = content.trim_start();
+    if content.is_empty() || INJECTED_PREFIXES.iter().any(|p| trimmed.starts_with(p)) {
+        return None;
+    }
+
+    Some(TranscriptMessage {
+        role: role.to_string(),
+        content,
+        timestamp,
+    })
+}
+
+fn tool_call(item: &Value) -> TranscriptToolUse {
+    let (tool_name, input) = match item.get("type").and_then(Value::as_str) {
+        Some("local_shell_call") => (
+            "local_shell",
+            item.get("action").cloned().unwrap_or(Value::Null),
+        ),
+        _ => {
+            let name = item
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown");
+            // Function arguments are a JSON string; custom tool input is free text
+            let raw = item.get("arguments").or_else(|| item.get("input"));
+            let input = match raw {
+                Some(Value::String(s)) => {
+                    serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.clone()))
+                }
+                Some(other) => other.clone(),
+                None => Value::Null,
+            };
+            (name, input)
+        }
+    };
+
+    TranscriptToolUse {
+        tool_name: tool_name.to_string(),
+        input,
+        output: None,
+        success: true,
+    }
+}
+
+/// Codex serialises shell results as `{"output", "metadata": {"exit_code"}}`
+fn tool_output(output: Option<&Value>) -> (String, bool) {
+    let text = match output {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => return (String::new(), true),
+    };
+
+    match serde_json::from_str::<Value>(&text) {
+        Ok(structured) if structured.is_object() => {
+            let output = structured
+                .get("output")
+                .and_then(Value::as_str)
+                .map(String::from)
+                .unwrap_or(text);
+            let exit_code = structured
+                .get("metadata")
+                .and_then(|m| m.get("exit_code"))
+                .and_then(Value::as_i64)
+                .unwrap_or(0);
+            (output, exit_code == 0)
+        }
+        _ => (text, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROLLOUT: &str = r#"{"timestamp":"2025-08-01T09:00:00.000Z","type":"session_meta","payload":{"id":"abc","cwd":"/work"}}
+{"timestamp":"2025-08-01T09:00:01.000Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context>\n  <cwd>/work</cwd>\n</environment_context>"}]}}
+{"timestamp":"2025-08-01T09:00:02.000Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"Fix the failing test"}]}}
+{"timestamp":"2025-08-01T09:00:03.000Z","type":"response_item","payload":{"type":"reasoning","summary":[]}}
+{"timestamp":"2025-08-01T09:00:04.000Z","type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"command\":[\"bash\",\"-lc\",\"cargo test\"]}","call_id":"call_1"}}
+{"timestamp":"2025-08-01T09:00:05.000Z","type":"response_item","payload":{"type":"function_call_output","call_id":"call_1","output":"{\"output\":\"1 failed\",\"metadata\":{\"exit_code\":101}}"}}
+{"timestamp":"2025-08-01T09:00:06.000Z","type":"event_msg","payload":{"type":"token_count"}}
+{"timestamp":"2025-08-01T09:00:07.000Z","type":"response_item","payload":{"type":"message","role":"assistant","content":[{"type":"output_text","text":"The assertion was off by one; fixed."}]}}
+"#;
+
+    #[test]
+    fn test_parse_skips_injected_context() {
+        let transcript = CodexRolloutParser.parse(ROLLOUT, "abc").unwrap();
+
+        assert_eq!(transcript.messages.len(), 2);
+        assert_eq!(transcript.messages[0].content, "Fix the failing test");
+        assert_eq!(transcript.messages[1].role, "assistant");
+        assert!(transcript.messages[0].timestamp.is_some());
+    }
+
+    #[test]
+    fn test_parse_pairs_calls_with_outputs() {
+        let transcript = CodexRolloutParser.parse(ROLLOUT, "abc").unwrap();
+
+        assert_eq!(transcript.tool_uses.len(), 1);
+        let tool = &transcript.tool_uses[0];
+        assert_eq!(tool.tool_name, "shell");
+        assert_eq!(tool.input["command"][2], "cargo test");
+        assert_eq!(tool.output.as_deref(), Some("1 failed"));
+        assert!(!tool.success);
+    }
+
+    #[test]
+    fn test_parse_legacy_bare_items() {
+        let content = r#"{"id":"abc","timestamp":"2025-05-01T09:00:00Z","instructions":null}
+{"type":"message","role":"user","content":[{"type":"input_text","text":"hello"}]}
+{"record_type":"state"}
+{"type":"custom_tool_call","name":"apply_patch","input":"*** Begin Patch","call_id":"c1"}
+{"type":"custom_tool_call_output","call_id":"c1","output":"Success"}
+"#;
+        let transcript = CodexRolloutParser.parse(content, "abc").unwrap();
+
+        assert_eq!(transcript.metadata.user_messages, 1);
+        assert_eq!(transcript.tool_uses[0].tool_name, "apply_patch");
+        assert_eq!(transcript.tool_uses[0].input, "*** Begin Patch");
+        assert_eq!(transcript.tool_uses[0].output.as_deref(), Some("Success"));
+        assert!(transcript.tool_uses[0].success);
+    }
+
+    #[test]
+    fn test_is_rollout_line() {
+        assert!(is_rollout_line(ROLLOUT.lines().next().unwrap()));
+        assert!(is_rollout_line(
+            r#"{"id":"abc","timestamp":"2025-05-01T09:00:00Z","instructions":null}"#
+        ));
+        assert!(!is_rollout_line(r#"{"role":"user","content":"hi"}"#));
+        assert!(!is_rollout_line("not json"));
+    }
+}
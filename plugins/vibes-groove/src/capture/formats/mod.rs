@@ -0,0 +1,112 @@
+//! Transcript formats for the harnesses groove can learn from
+//!
+//! Every format normalises into [`ParsedTranscript`], so extraction,
+//! assessment and attribution work the same whichever agent ran the session.
+
+mod aider;
+mod codex;
+mod openai;
+
+use std::path::Path;
+
+use super::parser::{ParsedTranscript, TranscriptParser};
+use crate::GrooveError;
+
+pub use aider::AiderHistoryParser;
+pub use codex::CodexRolloutParser;
+pub use openai::OpenAiMessagesParser;
+
+/// A session log format that can be parsed into a [`ParsedTranscript`]
+pub trait TranscriptFormat: Send + Sync {
+    /// Harness the format belongs to ("claude", "aider", "codex", "openai")
+    fn harness(&self) -> &'static str;
+
+    /// Parse transcript content
+    fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError>;
+
+    /// Parse a transcript file, using its file stem as the session ID
+    fn parse_file(&self, path: &Path) -> Result<ParsedTranscript, GrooveError> {
+        let content = std::fs::read_to_string(path)?;
+        let session_id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+        self.parse(&content, session_id)
+    }
+}
+
+impl TranscriptFormat for TranscriptParser {
+    fn harness(&self) -> &'static str {
+        "claude"
+    }
+
+    fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError> {
+        TranscriptParser::parse(self, content, session_id)
+    }
+}
+
+/// The transcript format written by a harness, by its command name
+pub fn format_for_harness(harness: &str) -> Option<Box<dyn TranscriptFormat>> {
+    match harness {
+        "claude" => Some(Box::new(TranscriptParser::new())),
+        "aider" => Some(Box::new(AiderHistoryParser)),
+        "codex" => Some(Box::new(CodexRolloutParser)),
+        "openai" => Some(Box::new(OpenAiMessagesParser)),
+        _ => None,
+    }
+}
+
+/// Guess the format of a transcript from its content
+///
+/// Falls back to the Claude Code JSONL format when nothing else matches.
+pub fn detect_format(content: &str) -> Box<dyn TranscriptFormat> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') || is_messages_object(trimmed) {
+        return Box::new(OpenAiMessagesParser);
+    }
+    if trimmed.starts_with("# aider chat started") {
+        return Box::new(AiderHistoryParser);
+    }
+    let first_line = trimmed.lines().next().unwrap_or_default();
+    if codex::is_rollout_line(first_line) {
+        return Box::new(CodexRolloutParser);
+    }
+    Box::new(TranscriptParser::new())
+}
+
+/// A single JSON document with a `messages` array, rather than JSONL
+fn is_messages_object(content: &str) -> bool {
+    content.starts_with('{')
+        && serde_json::from_str::<serde_json::Value>(content)
+            .is_ok_and(|value| value.get("messages").is_some_and(|m| m.is_array()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_for_harness() {
+        for harness in ["claude", "aider", "codex", "openai"] {
+            assert_eq!(format_for_harness(harness).unwrap().harness(), harness);
+        }
+        assert!(format_for_harness("gemini").is_none());
+    }
+
+    #[test]
+    fn test_detect_format() {
+        let cases = [
+            (r#"[{"role": "user", "content": "hi"}]"#, "openai"),
+            (r#"{"model": "gpt-4o", "messages": []}"#, "openai"),
+            ("# aider chat started at 2025-01-01 10:00:00\n", "aider"),
+            (
+                r#"{"timestamp":"2025-01-01T10:00:00Z","type":"session_meta","payload":{"id":"x"}}"#,
+                "codex",
+            ),
+            (r#"{"role": "user", "content": "hi"}"#, "claude"),
+        ];
+        for (content, harness) in cases {
+            assert_eq!(detect_format(content).harness(), harness, "{}", content);
+        }
+    }
+}
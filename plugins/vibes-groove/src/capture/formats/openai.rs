@@ -0,0 +1,173 @@
+//! OpenAI-style message array parser
+//!
+//! Many agents log the raw chat-completions conversation: a JSON array of
+//! `{"role", "content"}` messages, optionally wrapped as `{"messages": [...]}`.
+//! Assistant `tool_calls` become tool uses, and `role: "tool"` replies fill in
+//! their output by `tool_call_id`.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::TranscriptFormat;
+use crate::GrooveError;
+use crate::capture::{ParsedTranscript, TranscriptMessage, TranscriptToolUse};
+
+/// Parser for OpenAI-style chat message arrays
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpenAiMessagesParser;
+
+impl TranscriptFormat for OpenAiMessagesParser {
+    fn harness(&self) -> &'static str {
+        "openai"
+    }
+
+    fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError> {
+        let value: Value =
+            serde_json::from_str(content).map_err(|e| GrooveError::Serialization(e.to_string()))?;
+        let entries = match &value {
+            Value::Array(entries) => entries,
+            _ => value
+                .get("messages")
+                .and_then(Value::as_array)
+                .ok_or_else(|| {
+                    GrooveError::Serialization("expected a message array".to_string())
+                })?,
+        };
+
+        let mut messages = Vec::new();
+        let mut tool_uses: Vec<TranscriptToolUse> = Vec::new();
+        let mut calls: HashMap<&str, usize> = HashMap::new();
+
+        for entry in entries {
+            let Some(role) = entry.get("role").and_then(Value::as_str) else {
+                continue;
+            };
+            let text = text_content(entry.get("content"));
+
+            if role == "tool" {
+                let index = entry
+                    .get("tool_call_id")
+                    .and_then(Value::as_str)
+                    .and_then(|id| calls.get(id));
+                if let Some(&index) = index {
+                    tool_uses[index].output = Some(text);
+                }
+                continue;
+            }
+
+            for call in entry
+                .get("tool_calls")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                if let Some(id) = call.get("id").and_then(Value::as_str) {
+                    calls.insert(id, tool_uses.len());
+                }
+                tool_uses.push(tool_call(call));
+            }
+
+            if !text.is_empty() {
+                messages.push(TranscriptMessage {
+                    role: role.to_string(),
+                    content: text,
+                    timestamp: None,
+                });
+            }
+        }
+
+        Ok(ParsedTranscript::new(session_id, messages, tool_uses))
+    }
+}
+
+/// Content is either a string or a list of typed parts
+fn text_content(content: Option<&Value>) -> String {
+    match content {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|part| match part {
+                Value::String(s) => Some(s.as_str()),
+                _ => part.get("text").and_then(Value::as_str),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+fn tool_call(call: &Value) -> TranscriptToolUse {
+    let function = call.get("function");
+    let name = function
+        .and_then(|f| f.get("name"))
+        .and_then(Value::as_str)
+        .unwrap_or("unknown");
+    let input = match function.and_then(|f| f.get("arguments")) {
+        Some(Value::String(s)) => {
+            serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.clone()))
+        }
+        Some(other) => other.clone(),
+        None => Value::Null,
+    };
+
+    TranscriptToolUse {
+        tool_name: name.to_string(),
+        input,
+        output: None,
+        success: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGES: &str = r#"[
+        {"role": "system", "content": "You are a coding agent."},
+        {"role": "user", "content": "What's in main.rs?"},
+        {"role": "assistant", "content": null, "tool_calls": [
+            {"id": "call_1", "type": "function",
+             "function": {"name": "read_file", "arguments": "{\"path\": \"src/main.rs\"}"}}
+        ]},
+        {"role": "tool", "tool_call_id": "call_1", "content": "fn main() {}"},
+        {"role": "assistant", "content": [{"type": "text", "text": "An empty main."}]}
+    ]"#;
+
+    #[test]
+    fn test_parse_message_array() {
+        let transcript = OpenAiMessagesParser.parse(MESSAGES, "s1").unwrap();
+
+        assert_eq!(transcript.metadata.total_messages, 3);
+        assert_eq!(transcript.metadata.user_messages, 1);
+        assert_eq!(transcript.metadata.assistant_messages, 1);
+        assert_eq!(transcript.messages[2].content, "An empty main.");
+    }
+
+    #[test]
+    fn test_parse_joins_tool_results() {
+        let transcript = OpenAiMessagesParser.parse(MESSAGES, "s1").unwrap();
+
+        assert_eq!(transcript.tool_uses.len(), 1);
+        let tool = &transcript.tool_uses[0];
+        assert_eq!(tool.tool_name, "read_file");
+        assert_eq!(tool.input["path"], "src/main.rs");
+        assert_eq!(tool.output.as_deref(), Some("fn main() {}"));
+    }
+
+    #[test]
+    fn test_parse_messages_wrapper() {
+        let content = r#"{"messages": [{"role": "user", "content": "hi"}]}"#;
+        let transcript = OpenAiMessagesParser.parse(content, "s1").unwrap();
+        assert_eq!(transcript.metadata.user_messages, 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_json() {
+        let result = OpenAiMessagesParser.parse("not json", "s1");
+        assert!(matches!(result, Err(GrooveError::Serialization(_))));
+
+        let result = OpenAiMessagesParser.parse(r#"{"role": "user"}"#, "s1");
+        assert!(matches!(result, Err(GrooveError::Serialization(_))));
+    }
+}
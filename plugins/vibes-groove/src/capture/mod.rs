@@ -1,14 +1,22 @@
 //! Capture pipeline for session events
 //!
 //! This module handles collecting, parsing, and extracting learnings
-//! from Claude Code sessions.
+//! from agent sessions. Transcripts from each supported harness are
+//! normalised into [`ParsedTranscript`] by a [`TranscriptFormat`].
 
 mod collector;
 mod extractor;
+mod fetcher;
+mod formats;
 mod parser;
 
 pub use collector::{SessionBuffer, SessionCollector, ToolEvent};
 pub use extractor::{ExtractedLearning, LearningCategory, LearningExtractor};
+pub use fetcher::FileTranscriptFetcher;
+pub use formats::{
+    AiderHistoryParser, CodexRolloutParser, OpenAiMessagesParser, TranscriptFormat, detect_format,
+    format_for_harness,
+};
 pub use parser::{
     ParsedTranscript, TranscriptMessage, TranscriptMetadata, TranscriptParser, TranscriptToolUse,
 };
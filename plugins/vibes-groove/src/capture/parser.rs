@@ -58,6 +58,30 @@ pub struct ParsedTranscript {
     pub metadata: TranscriptMetadata,
 }
 
+impl ParsedTranscript {
+    /// Build a transcript, computing its metadata from the messages and tool uses
+    pub fn new(
+        session_id: impl Into<String>,
+        messages: Vec<TranscriptMessage>,
+        tool_uses: Vec<TranscriptToolUse>,
+    ) -> Self {
+        let count = |role: &str| messages.iter().filter(|m| m.role == role).count();
+        let metadata = TranscriptMetadata {
+            total_messages: messages.len(),
+            user_messages: count("user"),
+            assistant_messages: count("assistant"),
+            tool_uses: tool_uses.len(),
+        };
+
+        Self {
+            session_id: session_id.into(),
+            messages,
+            tool_uses,
+            metadata,
+        }
+    }
+}
+
 /// Parser for Claude Code JSONL transcripts
 pub struct TranscriptParser {
     /// Supported transcript versions
@@ -97,8 +121,6 @@ impl TranscriptParser {
     pub fn parse(&self, content: &str, session_id: &str) -> Result<ParsedTranscript, GrooveError> {
         let mut messages = Vec::new();
         let mut tool_uses = Vec::new();
-        let mut user_count = 0;
-        let mut assistant_count = 0;
 
         for line in content.lines() {
             let line = line.trim();
@@ -127,12 +149,6 @@ impl TranscriptParser {
                             })
                             .unwrap_or_default();
 
-                        match role {
-                            "user" => user_count += 1,
-                            "assistant" => assistant_count += 1,
-                            _ => {}
-                        }
-
                         messages.push(TranscriptMessage {
                             role: role.to_string(),
                             content: content_text,
@@ -163,19 +179,7 @@ impl TranscriptParser {
             }
         }
 
-        let metadata = TranscriptMetadata {
-            total_messages: messages.len(),
-            user_messages: user_count,
-            assistant_messages: assistant_count,
-            tool_uses: tool_uses.len(),
-        };
-
-        Ok(ParsedTranscript {
-            session_id: session_id.to_string(),
-            messages,
-            tool_uses,
-            metadata,
-        })
+        Ok(ParsedTranscript::new(session_id, messages, tool_uses))
     }
 }
 
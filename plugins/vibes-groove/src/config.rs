@@ -5,14 +5,20 @@
 //! - Project tier: Project-specific settings
 //! - Enterprise tier: Organization-wide policies
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::assessment::AssessmentConfig;
 use crate::attribution::{AblationConfig, AggregationConfig, TemporalConfig};
-use crate::extraction::DEFAULT_SIMILARITY_THRESHOLD;
+use crate::consolidation::ConsolidationConfig;
+use crate::contradiction::ContradictionConfig;
 use crate::extraction::patterns::CorrectionConfig;
+use crate::extraction::{DEFAULT_SIMILARITY_THRESHOLD, EmbeddingConfig};
+use crate::inject::RetrievalConfig;
 use crate::openworld::{GapsConfig, NoveltyConfig, ResponseConfig, SolutionsConfig};
+use crate::retention::DecayConfig;
 
 /// Configuration for the groove storage system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +45,27 @@ pub struct GrooveConfig {
     /// Open-world adaptation settings
     #[serde(default)]
     pub openworld: OpenWorldConfig,
+    /// Prompt-aware retrieval settings for injection
+    #[serde(default)]
+    pub retrieval: RetrievalConfig,
+    /// Periodic consolidation of similar learnings
+    #[serde(default)]
+    pub consolidation: ConsolidationConfig,
+    /// Contradiction detection between learnings
+    #[serde(default)]
+    pub contradiction: ContradictionConfig,
+
+    /// Confidence decay and retention enforcement
+    #[serde(default)]
+    pub decay: DecayConfig,
+
+    /// Embedding model, and re-embedding when it changes
+    #[serde(default)]
+    pub embedding: EmbeddingConfig,
+
+    /// Session assessment, including the LLM that analyzes ended sessions
+    #[serde(default)]
+    pub assessment: AssessmentConfig,
 }
 
 /// Configuration for semantic deduplication
@@ -133,13 +160,57 @@ impl Default for GrooveConfig {
             ablation: AblationConfig::default(),
             aggregation: AggregationConfig::default(),
             openworld: OpenWorldConfig::default(),
+            retrieval: RetrievalConfig::default(),
+            consolidation: ConsolidationConfig::default(),
+            contradiction: ContradictionConfig::default(),
+            decay: DecayConfig::default(),
+            embedding: EmbeddingConfig::default(),
+            assessment: AssessmentConfig::default(),
+        }
+    }
+}
+
+impl GrooveConfig {
+    /// Defaults overridden by the sections present in a plugin `config.toml`
+    ///
+    /// `lookup` returns the table for a top-level key such as `retrieval`.
+    /// A section that does not parse is an error rather than a silent
+    /// fallback to defaults.
+    pub fn from_sections(lookup: impl Fn(&str) -> Option<toml::Value>) -> Result<Self, String> {
+        fn section<T: DeserializeOwned>(
+            lookup: &dyn Fn(&str) -> Option<toml::Value>,
+            name: &str,
+            target: &mut T,
+        ) -> Result<(), String> {
+            if let Some(value) = lookup(name) {
+                *target = value
+                    .try_into()
+                    .map_err(|e| format!("invalid [{name}] config: {e}"))?;
+            }
+            Ok(())
         }
+
+        let mut config = Self::default();
+        section(&lookup, "deduplication", &mut config.deduplication)?;
+        section(&lookup, "correction", &mut config.correction)?;
+        section(&lookup, "temporal", &mut config.temporal)?;
+        section(&lookup, "ablation", &mut config.ablation)?;
+        section(&lookup, "aggregation", &mut config.aggregation)?;
+        section(&lookup, "openworld", &mut config.openworld)?;
+        section(&lookup, "retrieval", &mut config.retrieval)?;
+        section(&lookup, "consolidation", &mut config.consolidation)?;
+        section(&lookup, "contradiction", &mut config.contradiction)?;
+        section(&lookup, "decay", &mut config.decay)?;
+        section(&lookup, "embedding", &mut config.embedding)?;
+        section(&lookup, "assessment", &mut config.assessment)?;
+        Ok(config)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assessment::LlmConfig;
 
     #[test]
     fn test_default_config() {
@@ -223,4 +294,39 @@ mod tests {
         let config = GrooveConfig::default();
         assert!(config.openworld.enabled);
     }
+
+    #[test]
+    fn test_from_sections_overrides_present_sections() {
+        let file: toml::Table = toml::from_str(
+            r#"
+            [retrieval]
+            token_budget = 123
+
+            [decay]
+            enabled = false
+
+            [assessment.llm]
+            backend = "ollama"
+            "#,
+        )
+        .unwrap();
+
+        let config = GrooveConfig::from_sections(|key| file.get(key).cloned()).unwrap();
+
+        assert_eq!(config.retrieval.token_budget, 123);
+        assert!(!config.decay.enabled);
+        assert_eq!(config.assessment.llm.backend, "ollama");
+        assert_eq!(config.assessment.llm.model, LlmConfig::default().model);
+        assert_eq!(
+            config.consolidation.enabled,
+            ConsolidationConfig::default().enabled
+        );
+    }
+
+    #[test]
+    fn test_from_sections_rejects_invalid_section() {
+        let file: toml::Table = toml::from_str("retrieval = 5").unwrap();
+        let err = GrooveConfig::from_sections(|key| file.get(key).cloned()).unwrap_err();
+        assert!(err.contains("[retrieval]"));
+    }
 }
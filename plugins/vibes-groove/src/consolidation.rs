@@ -0,0 +1,714 @@
+//! Periodic consolidation of near-duplicate learnings
+//!
+//! Capture-time dedup only compares a candidate against its nearest stored
+//! neighbour, so paraphrases learned in different sessions and projects pile
+//! up. Consolidation clusters the whole store by embedding similarity and
+//! merges each cluster into one generalized learning that:
+//!
+//! - links to every member with [`RelationType::DerivedFrom`]
+//! - [`RelationType::Supersedes`] the members, so scope resolution stops
+//!   injecting them
+//! - takes its confidence from the members' pooled [`UsageStats`]
+//!
+//! Members are never modified, so a run is undone by removing exactly the
+//! learnings and relations it created. Every run is recorded as a
+//! [`ConsolidationReport`], whether it was applied or only proposed.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::extraction::{DEFAULT_SIMILARITY_THRESHOLD, Embedder, cosine_similarity};
+use crate::security::ConsolidationPolicy;
+use crate::store::LearningStore;
+use crate::{
+    GrooveError, Learning, LearningId, LearningRelation, LearningSource, RelationType, Result,
+    Scope, UsageStats,
+};
+
+/// Identifier of one consolidation run
+pub type ConsolidationRunId = Uuid;
+
+/// Configuration for the consolidation job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsolidationConfig {
+    /// Whether the periodic job runs
+    pub enabled: bool,
+    /// Hours between runs
+    pub interval_hours: u64,
+    /// Minimum cosine similarity to a cluster's seed to join the cluster
+    pub similarity_threshold: f64,
+    /// Smallest cluster worth merging
+    pub min_cluster_size: usize,
+    /// Largest cluster merged in one step
+    pub max_cluster_size: usize,
+}
+
+impl Default for ConsolidationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: 24,
+            similarity_threshold: DEFAULT_SIMILARITY_THRESHOLD,
+            min_cluster_size: 2,
+            max_cluster_size: 10,
+        }
+    }
+}
+
+/// A proposed merge of one cluster of similar learnings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeProposal {
+    /// The generalized learning that replaces the cluster
+    pub generalized: Learning,
+    /// Usage statistics pooled from the members
+    pub usage: UsageStats,
+    /// Cluster members, seed first
+    pub members: Vec<LearningId>,
+    /// Lowest similarity of any member to the seed
+    pub min_similarity: f64,
+}
+
+impl MergeProposal {
+    /// Relations written when the merge is applied
+    pub fn relations(&self) -> Vec<LearningRelation> {
+        let from = self.generalized.id;
+        self.members
+            .iter()
+            .flat_map(|member| {
+                [
+                    LearningRelation::new(from, RelationType::DerivedFrom, *member),
+                    LearningRelation::new(from, RelationType::Supersedes, *member),
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Outcome of one consolidation run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationReport {
+    pub run_id: ConsolidationRunId,
+    pub created_at: DateTime<Utc>,
+    /// Active learnings considered for clustering
+    pub learnings_scanned: usize,
+    pub merges: Vec<MergeProposal>,
+    /// When the merges were written to the store
+    pub applied_at: Option<DateTime<Utc>>,
+    /// When an applied run was undone
+    pub reverted_at: Option<DateTime<Utc>>,
+}
+
+impl ConsolidationReport {
+    /// Number of learnings that would be (or were) superseded
+    pub fn learnings_merged(&self) -> usize {
+        self.merges.iter().map(|m| m.members.len()).sum()
+    }
+}
+
+/// Storage needed by the consolidation job on top of [`LearningStore`]
+#[async_trait]
+pub trait ConsolidationStore: LearningStore {
+    /// Every learning in the store
+    async fn all_learnings(&self) -> Result<Vec<Learning>>;
+
+    /// IDs of learnings that another learning supersedes
+    async fn superseded_ids(&self) -> Result<HashSet<LearningId>>;
+
+    /// Usage statistics for a learning
+    async fn get_usage(&self, id: LearningId) -> Result<Option<UsageStats>>;
+
+    /// Store the embedding for a learning
+    async fn store_embedding(&self, id: LearningId, embedding: &[f32]) -> Result<()>;
+
+    /// Remove a single relation
+    async fn delete_relation(&self, relation: &LearningRelation) -> Result<()>;
+
+    /// Insert or update the record of a run
+    async fn save_consolidation_run(&self, report: &ConsolidationReport) -> Result<()>;
+
+    /// Fetch the record of a run
+    async fn get_consolidation_run(
+        &self,
+        id: ConsolidationRunId,
+    ) -> Result<Option<ConsolidationReport>>;
+
+    /// Most recent runs first
+    async fn list_consolidation_runs(&self, limit: usize) -> Result<Vec<ConsolidationReport>>;
+}
+
+/// Clusters similar learnings and merges them into generalized learnings
+pub struct Consolidator {
+    store: Arc<dyn ConsolidationStore>,
+    embedder: Arc<dyn Embedder>,
+    config: ConsolidationConfig,
+    policy: ConsolidationPolicy,
+}
+
+impl Consolidator {
+    pub fn new(
+        store: Arc<dyn ConsolidationStore>,
+        embedder: Arc<dyn Embedder>,
+        config: ConsolidationConfig,
+        policy: ConsolidationPolicy,
+    ) -> Self {
+        Self {
+            store,
+            embedder,
+            config,
+            policy,
+        }
+    }
+
+    /// Cluster the store and record the proposal, applying it when the
+    /// policy allows
+    pub async fn run(&self) -> Result<ConsolidationReport> {
+        let mut report = self.propose().await?;
+        if self.policy.auto_apply && !report.merges.is_empty() {
+            self.apply_merges(&mut report).await?;
+        }
+        self.store.save_consolidation_run(&report).await?;
+
+        info!(
+            run_id = %report.run_id,
+            scanned = report.learnings_scanned,
+            merges = report.merges.len(),
+            applied = report.applied_at.is_some(),
+            "Consolidation run finished"
+        );
+        Ok(report)
+    }
+
+    /// Cluster the store without writing anything
+    pub async fn propose(&self) -> Result<ConsolidationReport> {
+        let run_id = Uuid::now_v7();
+        let superseded = self.store.superseded_ids().await?;
+        let learnings: Vec<Learning> = self
+            .store
+            .all_learnings()
+            .await?
+            .into_iter()
+            .filter(|l| !superseded.contains(&l.id))
+            .collect();
+
+        let mut embeddings = Vec::with_capacity(learnings.len());
+        for learning in &learnings {
+            embeddings.push(self.embed(learning).await?);
+        }
+
+        let mut merges = Vec::new();
+        for cluster in self.cluster(&learnings, &embeddings) {
+            let members: Vec<&Learning> = cluster.iter().map(|(i, _)| &learnings[*i]).collect();
+            let min_similarity = cluster.iter().map(|(_, s)| *s).fold(1.0, f64::min);
+            merges.push(self.merge(run_id, &members, min_similarity).await?);
+        }
+
+        Ok(ConsolidationReport {
+            run_id,
+            created_at: Utc::now(),
+            learnings_scanned: learnings.len(),
+            merges,
+            applied_at: None,
+            reverted_at: None,
+        })
+    }
+
+    /// Apply a previously proposed run
+    ///
+    /// Merges whose members were deleted or superseded since the proposal
+    /// are dropped from the report rather than applied.
+    pub async fn apply(&self, run_id: ConsolidationRunId) -> Result<ConsolidationReport> {
+        let mut report = self.load_run(run_id).await?;
+        if report.applied_at.is_some() {
+            return Err(GrooveError::Consolidation(format!(
+                "run {} was already applied",
+                run_id
+            )));
+        }
+
+        let superseded = self.store.superseded_ids().await?;
+        let mut current = Vec::with_capacity(report.merges.len());
+        for merge in std::mem::take(&mut report.merges) {
+            let mut intact = true;
+            for member in &merge.members {
+                if superseded.contains(member) || self.store.get(*member).await?.is_none() {
+                    intact = false;
+                    break;
+                }
+            }
+            if intact {
+                current.push(merge);
+            } else {
+                warn!(
+                    run_id = %run_id,
+                    generalized = %merge.generalized.id,
+                    "Skipping stale consolidation merge"
+                );
+            }
+        }
+        report.merges = current;
+
+        self.apply_merges(&mut report).await?;
+        self.store.save_consolidation_run(&report).await?;
+        Ok(report)
+    }
+
+    /// Undo an applied run
+    ///
+    /// Removes the generalized learnings and their relations; the members
+    /// become active again.
+    pub async fn revert(&self, run_id: ConsolidationRunId) -> Result<ConsolidationReport> {
+        let mut report = self.load_run(run_id).await?;
+        if report.applied_at.is_none() {
+            return Err(GrooveError::Consolidation(format!(
+                "run {} was never applied",
+                run_id
+            )));
+        }
+        if report.reverted_at.is_some() {
+            return Err(GrooveError::Consolidation(format!(
+                "run {} was already reverted",
+                run_id
+            )));
+        }
+
+        for merge in &report.merges {
+            for relation in merge.relations() {
+                self.store.delete_relation(&relation).await?;
+            }
+            self.store.delete(merge.generalized.id).await?;
+        }
+
+        report.reverted_at = Some(Utc::now());
+        self.store.save_consolidation_run(&report).await?;
+        info!(run_id = %run_id, merges = report.merges.len(), "Consolidation run reverted");
+        Ok(report)
+    }
+
+    async fn load_run(&self, run_id: ConsolidationRunId) -> Result<ConsolidationReport> {
+        self.store
+            .get_consolidation_run(run_id)
+            .await?
+            .ok_or_else(|| GrooveError::Consolidation(format!("run {} not found", run_id)))
+    }
+
+    async fn embed(&self, learning: &Learning) -> Result<Vec<f32>> {
+        self.embedder
+            .embed(&learning.content.description)
+            .await
+            .map_err(|e| GrooveError::Embedding(format!("Failed to embed learning: {}", e)))
+    }
+
+    /// Greedy leader clustering
+    ///
+    /// Seeds are taken in order of confidence; each collects the unassigned
+    /// learnings of its category within the similarity threshold. Returns
+    /// `(index, similarity to seed)` per member, seed first.
+    fn cluster(&self, learnings: &[Learning], embeddings: &[Vec<f32>]) -> Vec<Vec<(usize, f64)>> {
+        let mut order: Vec<usize> = (0..learnings.len()).collect();
+        order.sort_by(|a, b| {
+            learnings[*b]
+                .confidence
+                .partial_cmp(&learnings[*a].confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(learnings[*a].created_at.cmp(&learnings[*b].created_at))
+        });
+
+        let mut assigned = vec![false; learnings.len()];
+        let mut clusters = Vec::new();
+        for &seed in &order {
+            if assigned[seed] {
+                continue;
+            }
+            let mut members = vec![(seed, 1.0)];
+            for &other in &order {
+                if other == seed || assigned[other] {
+                    continue;
+                }
+                if learnings[other].category != learnings[seed].category {
+                    continue;
+                }
+                if !self.policy.allow_scope_generalization
+                    && learnings[other].scope != learnings[seed].scope
+                {
+                    continue;
+                }
+                let similarity = cosine_similarity(&embeddings[seed], &embeddings[other]) as f64;
+                if similarity >= self.config.similarity_threshold {
+                    members.push((other, similarity));
+                }
+            }
+
+            // Seed first, then the closest members
+            members[1..].sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            members.truncate(self.config.max_cluster_size);
+            if members.len() < self.config.min_cluster_size.max(2) {
+                continue;
+            }
+            for (i, _) in &members {
+                assigned[*i] = true;
+            }
+            clusters.push(members);
+        }
+        clusters
+    }
+
+    /// Build the generalized learning for a cluster
+    async fn merge(
+        &self,
+        run_id: ConsolidationRunId,
+        members: &[&Learning],
+        min_similarity: f64,
+    ) -> Result<MergeProposal> {
+        let seed = members[0];
+
+        let mut stats = Vec::with_capacity(members.len());
+        for member in members {
+            stats.push(self.store.get_usage(member.id).await?.unwrap_or_default());
+        }
+        let usage = pool_usage(&stats);
+
+        let scopes: Vec<&Scope> = members.iter().map(|m| &m.scope).collect();
+        let mut generalized = Learning::new(
+            generalize_scope(&scopes),
+            seed.category.clone(),
+            seed.content.clone(),
+            LearningSource::Consolidated { run_id },
+        );
+        // Without usage evidence the members' own confidence is all there is
+        generalized.confidence = if usage.times_injected > 0 {
+            usage.confidence()
+        } else {
+            members.iter().map(|m| m.confidence).sum::<f64>() / members.len() as f64
+        };
+
+        Ok(MergeProposal {
+            generalized,
+            usage,
+            members: members.iter().map(|m| m.id).collect(),
+            min_similarity,
+        })
+    }
+
+    async fn apply_merges(&self, report: &mut ConsolidationReport) -> Result<()> {
+        for merge in &report.merges {
+            let generalized = &merge.generalized;
+            self.store.store(generalized).await?;
+            self.store
+                .update_usage(generalized.id, &merge.usage)
+                .await?;
+            let embedding = self.embed(generalized).await?;
+            self.store
+                .store_embedding(generalized.id, &embedding)
+                .await?;
+            for relation in merge.relations() {
+                self.store.store_relation(&relation).await?;
+            }
+        }
+        report.applied_at = Some(Utc::now());
+        Ok(())
+    }
+}
+
+/// Pool usage statistics into one Beta posterior
+///
+/// Each member's evidence beyond the uniform prior is added once, so a
+/// merged learning is as certain as its members were together.
+pub fn pool_usage(stats: &[UsageStats]) -> UsageStats {
+    let mut pooled = UsageStats::default();
+    for s in stats {
+        pooled.times_injected += s.times_injected;
+        pooled.times_helpful += s.times_helpful;
+        pooled.times_ignored += s.times_ignored;
+        pooled.times_contradicted += s.times_contradicted;
+        pooled.last_used = pooled.last_used.max(s.last_used);
+        pooled.confidence_alpha += (s.confidence_alpha - 1.0).max(0.0);
+        pooled.confidence_beta += (s.confidence_beta - 1.0).max(0.0);
+    }
+    pooled
+}
+
+/// Narrowest scope that covers every member
+///
+/// Branches of one repository generalize to the repository; anything else
+/// that differs generalizes to global.
+pub fn generalize_scope(scopes: &[&Scope]) -> Scope {
+    let Some(first) = scopes.first() else {
+        return Scope::Global;
+    };
+    if scopes.iter().all(|s| s == first) {
+        return (*first).clone();
+    }
+
+    let repository_of = |scope: &Scope| match scope {
+        Scope::Repository(repository) | Scope::Branch { repository, .. } => {
+            Some(repository.clone())
+        }
+        _ => None,
+    };
+    if let Some(repository) = repository_of(first)
+        && scopes
+            .iter()
+            .all(|s| repository_of(s).as_ref() == Some(&repository))
+    {
+        return Scope::Repository(repository);
+    }
+
+    Scope::Global
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::EmbedderResult;
+    use crate::store::memory::MemoryStore;
+    use crate::{LearningCategory, LearningContent};
+
+    /// Embeds descriptions by their first word, so "tabs ..." and "tabs ..."
+    /// are identical and anything else is orthogonal
+    struct TopicEmbedder;
+
+    #[async_trait]
+    impl Embedder for TopicEmbedder {
+        async fn embed(&self, text: &str) -> EmbedderResult<Vec<f32>> {
+            let topic = text.split_whitespace().next().unwrap_or_default();
+            let slot = topic.bytes().map(usize::from).sum::<usize>() % 8;
+            let mut embedding = vec![0.0; 8];
+            embedding[slot] = 1.0;
+            Ok(embedding)
+        }
+
+        fn dimensions(&self) -> usize {
+            8
+        }
+    }
+
+    fn learning(scope: Scope, description: &str, confidence: f64) -> Learning {
+        let mut learning = Learning::new(
+            scope,
+            LearningCategory::Preference,
+            LearningContent {
+                description: description.into(),
+                pattern: None,
+                insight: format!("Insight for {}", description),
+            },
+            LearningSource::UserCreated,
+        );
+        learning.confidence = confidence;
+        learning
+    }
+
+    fn usage(helpful: u32, contradicted: u32) -> UsageStats {
+        let mut stats = UsageStats::default();
+        for _ in 0..helpful {
+            stats.record_outcome(crate::Outcome::Helpful);
+        }
+        for _ in 0..contradicted {
+            stats.record_outcome(crate::Outcome::Contradicted);
+        }
+        stats
+    }
+
+    async fn seeded_store() -> (Arc<MemoryStore>, Vec<Learning>) {
+        let store = Arc::new(MemoryStore::default());
+        let learnings = vec![
+            learning(Scope::Project("/a".into()), "tabs for indentation", 0.8),
+            learning(Scope::Project("/b".into()), "tabs please, not spaces", 0.6),
+            learning(
+                Scope::Project("/a".into()),
+                "commit messages in imperative mood",
+                0.7,
+            ),
+        ];
+        for l in &learnings {
+            store.store(l).await.unwrap();
+        }
+        store
+            .update_usage(learnings[0].id, &usage(3, 0))
+            .await
+            .unwrap();
+        store
+            .update_usage(learnings[1].id, &usage(1, 1))
+            .await
+            .unwrap();
+        (store, learnings)
+    }
+
+    fn consolidator(store: Arc<MemoryStore>, auto_apply: bool) -> Consolidator {
+        Consolidator::new(
+            store,
+            Arc::new(TopicEmbedder),
+            ConsolidationConfig::default(),
+            ConsolidationPolicy {
+                auto_apply,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_propose_merges_similar_learnings_without_writing() {
+        let (store, learnings) = seeded_store().await;
+        let consolidator = consolidator(store.clone(), false);
+
+        let report = consolidator.run().await.unwrap();
+
+        assert_eq!(report.learnings_scanned, 3);
+        assert_eq!(report.merges.len(), 1);
+        assert!(report.applied_at.is_none());
+
+        let merge = &report.merges[0];
+        // Highest-confidence member seeds the cluster and supplies the content
+        assert_eq!(merge.members, vec![learnings[0].id, learnings[1].id]);
+        assert_eq!(
+            merge.generalized.content.description,
+            learnings[0].content.description
+        );
+        assert_eq!(merge.generalized.scope, Scope::Global);
+        assert!(matches!(
+            merge.generalized.source,
+            LearningSource::Consolidated { run_id } if run_id == report.run_id
+        ));
+
+        // Proposal only: nothing but the run record was written
+        assert_eq!(store.learnings.lock().unwrap().len(), 3);
+        assert!(store.relations.lock().unwrap().is_empty());
+        assert!(store.runs.lock().unwrap().contains_key(&report.run_id));
+    }
+
+    #[tokio::test]
+    async fn test_apply_writes_generalized_learning_and_relations() {
+        let (store, learnings) = seeded_store().await;
+        let consolidator = consolidator(store.clone(), false);
+        let proposed = consolidator.run().await.unwrap();
+
+        let report = consolidator.apply(proposed.run_id).await.unwrap();
+        assert!(report.applied_at.is_some());
+
+        let generalized = &report.merges[0].generalized;
+        assert!(store.get(generalized.id).await.unwrap().is_some());
+        assert!(
+            store
+                .embeddings
+                .lock()
+                .unwrap()
+                .contains_key(&generalized.id)
+        );
+
+        let superseded = store.superseded_ids().await.unwrap();
+        assert_eq!(
+            superseded,
+            HashSet::from([learnings[0].id, learnings[1].id])
+        );
+        let derived = store
+            .relations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.relation_type == RelationType::DerivedFrom)
+            .count();
+        assert_eq!(derived, 2);
+
+        // Pooled evidence: 4 helpful, 1 contradicted on a uniform prior
+        let pooled = store.get_usage(generalized.id).await.unwrap().unwrap();
+        assert_eq!(pooled.times_helpful, 4);
+        assert_eq!(pooled.times_contradicted, 1);
+        assert!((generalized.confidence - 5.0 / 7.5).abs() < 1e-9);
+
+        assert!(matches!(
+            consolidator.apply(proposed.run_id).await,
+            Err(GrooveError::Consolidation(_))
+        ));
+
+        // Merged members no longer take part in the next run
+        let next = consolidator.propose().await.unwrap();
+        assert_eq!(next.learnings_scanned, 2);
+        assert!(next.merges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_revert_restores_members() {
+        let (store, _) = seeded_store().await;
+        let consolidator = consolidator(store.clone(), true);
+
+        let applied = consolidator.run().await.unwrap();
+        assert!(applied.applied_at.is_some());
+        assert_eq!(store.learnings.lock().unwrap().len(), 4);
+
+        let reverted = consolidator.revert(applied.run_id).await.unwrap();
+        assert!(reverted.reverted_at.is_some());
+        assert_eq!(store.learnings.lock().unwrap().len(), 3);
+        assert!(store.relations.lock().unwrap().is_empty());
+        assert!(
+            !store
+                .usage
+                .lock()
+                .unwrap()
+                .contains_key(&applied.merges[0].generalized.id)
+        );
+
+        assert!(matches!(
+            consolidator.revert(applied.run_id).await,
+            Err(GrooveError::Consolidation(_))
+        ));
+        let runs = store.list_consolidation_runs(10).await.unwrap();
+        assert!(runs[0].reverted_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_apply_skips_stale_merges() {
+        let (store, learnings) = seeded_store().await;
+        let consolidator = consolidator(store.clone(), false);
+        let proposed = consolidator.run().await.unwrap();
+
+        store.delete(learnings[1].id).await.unwrap();
+
+        let report = consolidator.apply(proposed.run_id).await.unwrap();
+        assert!(report.merges.is_empty());
+        assert!(store.relations.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_policy_can_forbid_scope_generalization() {
+        let (store, _) = seeded_store().await;
+        let consolidator = Consolidator::new(
+            store,
+            Arc::new(TopicEmbedder),
+            ConsolidationConfig::default(),
+            ConsolidationPolicy {
+                allow_scope_generalization: false,
+                ..Default::default()
+            },
+        );
+
+        let report = consolidator.propose().await.unwrap();
+        assert!(report.merges.is_empty());
+    }
+
+    #[test]
+    fn test_generalize_scope() {
+        let a = Scope::Project("/a".into());
+        let main = Scope::branch("git@github.com:org/repo.git", "main");
+        let dev = Scope::branch("https://github.com/org/repo", "dev");
+        let repo = Scope::repository("github.com/org/repo");
+
+        assert_eq!(generalize_scope(&[&a, &a]), a);
+        assert_eq!(generalize_scope(&[&main, &dev, &repo]), repo);
+        assert_eq!(generalize_scope(&[&main, &a]), Scope::Global);
+        assert_eq!(generalize_scope(&[]), Scope::Global);
+    }
+
+    #[test]
+    fn test_pool_usage_without_evidence_keeps_prior() {
+        let pooled = pool_usage(&[UsageStats::default(), UsageStats::default()]);
+        assert_eq!(pooled.confidence_alpha, 1.0);
+        assert_eq!(pooled.confidence_beta, 1.0);
+        assert_eq!(pooled.times_injected, 0);
+    }
+}
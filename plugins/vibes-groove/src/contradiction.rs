@@ -0,0 +1,781 @@
+//! Contradiction detection between learnings
+//!
+//! "Use `#[tokio::test]`" and "Never use `#[tokio::test]` in this repo" sit
+//! right next to each other in embedding space, so dedup leaves both alone
+//! and retrieval happily injects both. Detection compares each learning with
+//! its semantic neighbours and keeps the pairs that disagree:
+//!
+//! - [`polarity_verdict`] flags pairs where exactly one side is negated
+//!   ("never", "don't", "avoid", ...) and the remaining wording overlaps
+//! - an optional [`ContradictionJudge`] such as [`LlmJudge`] has the final
+//!   say on every neighbour pair, falling back to the polarity check when it
+//!   fails
+//!
+//! Confirmed pairs are recorded as [`RelationType::Contradicts`] from the
+//! newer learning to the older one. Until a human resolves the conflict,
+//! injection only returns the higher-ranked side (see [`drop_contradicted`]).
+//! Resolving either keeps one side, recorded as [`RelationType::Supersedes`],
+//! or dismisses the conflict as a false positive, recorded as
+//! [`RelationType::RelatedTo`] so the pair isn't flagged again.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use vibes_models::providers::{ChatRequest, Message, ModelProvider, OllamaProvider};
+
+use crate::assessment::HarnessError;
+use crate::assessment::LlmConfig;
+use crate::assessment::llm_analyzer::strip_code_fence;
+use crate::consolidation::ConsolidationStore;
+use crate::extraction::Embedder;
+use crate::{GrooveError, Learning, LearningId, LearningRelation, RelationType, Result};
+
+/// Words that flip the meaning of an instruction
+const NEGATION_CUES: &[&str] = &[
+    "never",
+    "not",
+    "no",
+    "don't",
+    "dont",
+    "doesn't",
+    "shouldn't",
+    "mustn't",
+    "can't",
+    "cannot",
+    "avoid",
+    "stop",
+    "without",
+];
+
+/// Words too common in learnings to count as shared subject matter
+const FILLER: &[&str] = &[
+    "a", "an", "the", "in", "on", "of", "to", "for", "and", "or", "with", "this", "that", "is",
+    "are", "be", "it", "when", "use", "using", "prefer", "always", "should", "must", "please",
+];
+
+/// Instructions for the LLM judge
+const JUDGE_PROMPT: &str = "\
+You compare two learnings an AI coding agent has recorded about a user's \
+projects. Decide whether following one would violate the other. Learnings that \
+apply to different situations, or where one only refines the other, do not \
+contradict.
+
+Respond with a single JSON object and nothing else: \
+{\"contradicts\": boolean, \"confidence\": number from 0 to 1, \"reason\": one sentence}";
+
+/// Configuration for contradiction detection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContradictionConfig {
+    /// Whether learnings are checked when stored and by the backfill job
+    pub enabled: bool,
+    /// Hours between backfill scans of the whole store
+    pub backfill_interval_hours: u64,
+    /// Minimum cosine similarity for two learnings to be compared
+    pub similarity_threshold: f64,
+    /// Most neighbours compared per learning
+    pub max_candidates: usize,
+    /// Ask the assessment LLM backend to judge each neighbour pair
+    pub llm_judge: bool,
+}
+
+impl Default for ContradictionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            backfill_interval_hours: 24,
+            similarity_threshold: 0.75,
+            max_candidates: 10,
+            llm_judge: false,
+        }
+    }
+}
+
+/// Whether two learnings contradict each other, and why
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Verdict {
+    pub contradicts: bool,
+    /// How sure the judge is (0.0-1.0); stored as the relation weight
+    pub confidence: f64,
+    pub reason: String,
+}
+
+/// A pending conflict between two learnings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conflict {
+    /// The newer learning
+    pub learning: Learning,
+    /// The older learning it contradicts
+    pub conflicting: Learning,
+    /// Judge confidence when the conflict was detected
+    pub confidence: f64,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// How a human settled a conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// Keep this learning; it supersedes the other side
+    Keep(LearningId),
+    /// Not a real contradiction; inject both
+    Dismiss,
+}
+
+/// Outcome of a backfill scan
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackfillReport {
+    pub learnings_scanned: usize,
+    /// Conflicts found by this scan
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Storage needed for contradiction detection
+///
+/// Builds on [`ConsolidationStore`] for whole-store scans, embeddings and
+/// relation removal.
+#[async_trait]
+pub trait ContradictionStore: ConsolidationStore {
+    /// IDs linked to `id` by a relation of any type, in either direction
+    async fn related_ids(&self, id: LearningId) -> Result<HashSet<LearningId>>;
+
+    /// Every recorded `Contradicts` relation
+    async fn contradictions(&self) -> Result<Vec<LearningRelation>>;
+}
+
+/// Decides whether two neighbouring learnings contradict each other
+#[async_trait]
+pub trait ContradictionJudge: Send + Sync {
+    async fn judge(&self, a: &Learning, b: &Learning) -> Result<Verdict>;
+}
+
+/// Judges contradictions through a vibes-models provider
+pub struct LlmJudge {
+    provider: Arc<dyn ModelProvider>,
+    config: LlmConfig,
+}
+
+impl LlmJudge {
+    pub fn new(provider: Arc<dyn ModelProvider>, config: LlmConfig) -> Self {
+        Self { provider, config }
+    }
+
+    /// Create a judge for the provider named by `config.backend`
+    ///
+    /// Returns `Ok(None)` when LLM analysis is disabled or the backend is the
+    /// Claude Code subprocess, which only handles session analysis.
+    pub fn from_config(config: &LlmConfig) -> std::result::Result<Option<Self>, HarnessError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let provider: Arc<dyn ModelProvider> = match config.backend.as_str() {
+            "harness" | "mock" => return Ok(None),
+            "ollama" => Arc::new(match &config.base_url {
+                Some(url) => OllamaProvider::with_base_url(url),
+                None => OllamaProvider::new(),
+            }),
+            other => return Err(HarnessError::UnknownBackend(other.to_string())),
+        };
+        Ok(Some(Self::new(provider, config.clone())))
+    }
+
+    fn request(&self, a: &Learning, b: &Learning) -> ChatRequest {
+        let render = |label: &str, l: &Learning| {
+            format!(
+                "Learning {label} ({}):\n{}\n{}",
+                l.scope.to_db_string(),
+                l.content.description,
+                l.content.insight
+            )
+        };
+        ChatRequest::new(
+            self.config.model.clone(),
+            vec![
+                Message::system(JUDGE_PROMPT),
+                Message::user(format!("{}\n\n{}", render("A", a), render("B", b))),
+            ],
+        )
+        .temperature(0.0)
+        .max_tokens(256)
+    }
+}
+
+#[async_trait]
+impl ContradictionJudge for LlmJudge {
+    async fn judge(&self, a: &Learning, b: &Learning) -> Result<Verdict> {
+        let timeout = Duration::from_secs(self.config.timeout_seconds.into());
+        let response = tokio::time::timeout(timeout, self.provider.chat(self.request(a, b)))
+            .await
+            .map_err(|_| {
+                GrooveError::Contradiction(format!(
+                    "judge timed out after {} seconds",
+                    self.config.timeout_seconds
+                ))
+            })?
+            .map_err(|e| GrooveError::Contradiction(format!("judge request failed: {e}")))?;
+
+        parse_verdict(&response.content.as_text())
+    }
+}
+
+impl std::fmt::Debug for LlmJudge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlmJudge")
+            .field("provider", &self.provider.name())
+            .field("model", &self.config.model)
+            .finish()
+    }
+}
+
+/// Finds, records and resolves contradictions between learnings
+pub struct ContradictionDetector {
+    store: Arc<dyn ContradictionStore>,
+    config: ContradictionConfig,
+    judge: Option<Arc<dyn ContradictionJudge>>,
+}
+
+impl ContradictionDetector {
+    pub fn new(store: Arc<dyn ContradictionStore>, config: ContradictionConfig) -> Self {
+        Self {
+            store,
+            config,
+            judge: None,
+        }
+    }
+
+    /// Let `judge` decide each neighbour pair instead of the polarity check
+    #[must_use]
+    pub fn with_judge(mut self, judge: Arc<dyn ContradictionJudge>) -> Self {
+        self.judge = Some(judge);
+        self
+    }
+
+    /// Check a newly stored learning against its neighbours
+    ///
+    /// Stores `embedding` so later learnings can find this one, then records
+    /// a `Contradicts` relation for every neighbour judged to disagree.
+    pub async fn check(&self, learning: &Learning, embedding: &[f32]) -> Result<Vec<Conflict>> {
+        self.store.store_embedding(learning.id, embedding).await?;
+        let superseded = self.store.superseded_ids().await?;
+        self.compare(learning, embedding, &superseded).await
+    }
+
+    /// Scan the whole store for contradictions missed at store time
+    ///
+    /// Every active learning is embedded first so each one can be found as a
+    /// neighbour, then they are checked oldest first.
+    pub async fn backfill(&self, embedder: &dyn Embedder) -> Result<BackfillReport> {
+        let superseded = self.store.superseded_ids().await?;
+        let mut learnings: Vec<Learning> = self
+            .store
+            .all_learnings()
+            .await?
+            .into_iter()
+            .filter(|l| !superseded.contains(&l.id))
+            .collect();
+        learnings.sort_by_key(|l| l.created_at);
+
+        let mut embeddings = Vec::with_capacity(learnings.len());
+        for learning in &learnings {
+            let embedding = embedder
+                .embed(&learning.content.description)
+                .await
+                .map_err(|e| GrooveError::Embedding(e.to_string()))?;
+            self.store.store_embedding(learning.id, &embedding).await?;
+            embeddings.push(embedding);
+        }
+
+        let mut report = BackfillReport {
+            learnings_scanned: learnings.len(),
+            conflicts: Vec::new(),
+        };
+        for (learning, embedding) in learnings.iter().zip(&embeddings) {
+            report
+                .conflicts
+                .extend(self.compare(learning, embedding, &superseded).await?);
+        }
+        Ok(report)
+    }
+
+    /// Conflicts waiting for a human, newest first
+    ///
+    /// Skips conflicts where either side was deleted or has since been
+    /// superseded, since neither would be injected anyway.
+    pub async fn pending(&self) -> Result<Vec<Conflict>> {
+        let superseded = self.store.superseded_ids().await?;
+        let mut conflicts = Vec::new();
+        for relation in self.store.contradictions().await? {
+            if superseded.contains(&relation.from_id) || superseded.contains(&relation.to_id) {
+                continue;
+            }
+            let (Some(learning), Some(conflicting)) = (
+                self.store.get(relation.from_id).await?,
+                self.store.get(relation.to_id).await?,
+            ) else {
+                continue;
+            };
+            conflicts.push(Conflict {
+                learning,
+                conflicting,
+                confidence: relation.weight,
+                detected_at: relation.created_at,
+            });
+        }
+        conflicts.sort_by_key(|c| std::cmp::Reverse(c.detected_at));
+        Ok(conflicts)
+    }
+
+    /// Settle the conflict between `a` and `b`
+    pub async fn resolve(
+        &self,
+        a: LearningId,
+        b: LearningId,
+        resolution: Resolution,
+    ) -> Result<()> {
+        let relation = self
+            .store
+            .contradictions()
+            .await?
+            .into_iter()
+            .find(|r| (r.from_id == a && r.to_id == b) || (r.from_id == b && r.to_id == a))
+            .ok_or_else(|| {
+                GrooveError::Contradiction(format!("No pending conflict between {a} and {b}"))
+            })?;
+
+        let replacement = match resolution {
+            Resolution::Keep(winner) if winner == a || winner == b => {
+                let loser = if winner == a { b } else { a };
+                LearningRelation::new(winner, RelationType::Supersedes, loser)
+            }
+            Resolution::Keep(other) => {
+                return Err(GrooveError::Contradiction(format!(
+                    "{other} is not part of the conflict between {a} and {b}"
+                )));
+            }
+            Resolution::Dismiss => {
+                LearningRelation::new(relation.from_id, RelationType::RelatedTo, relation.to_id)
+            }
+        };
+
+        self.store.store_relation(&replacement).await?;
+        self.store.delete_relation(&relation).await?;
+        info!(%a, %b, ?resolution, "Resolved contradiction");
+        Ok(())
+    }
+
+    /// Judge `learning` against its unrelated, active neighbours
+    async fn compare(
+        &self,
+        learning: &Learning,
+        embedding: &[f32],
+        superseded: &HashSet<LearningId>,
+    ) -> Result<Vec<Conflict>> {
+        let related = self.store.related_ids(learning.id).await?;
+        let neighbours = self
+            .store
+            .find_similar(
+                embedding,
+                self.config.similarity_threshold,
+                self.config.max_candidates + 1,
+            )
+            .await?;
+
+        let mut conflicts = Vec::new();
+        for (other, _) in neighbours {
+            if other.id == learning.id
+                || related.contains(&other.id)
+                || superseded.contains(&other.id)
+            {
+                continue;
+            }
+
+            let verdict = self.verdict(learning, &other).await;
+            if !verdict.contradicts {
+                continue;
+            }
+
+            let (newer, older) = if other.created_at > learning.created_at {
+                (other, learning.clone())
+            } else {
+                (learning.clone(), other)
+            };
+            let relation = LearningRelation::new(newer.id, RelationType::Contradicts, older.id)
+                .with_weight(verdict.confidence);
+            self.store.store_relation(&relation).await?;
+            info!(
+                newer = %newer.id,
+                older = %older.id,
+                confidence = verdict.confidence,
+                reason = %verdict.reason,
+                "Detected contradiction"
+            );
+
+            conflicts.push(Conflict {
+                learning: newer,
+                conflicting: older,
+                confidence: verdict.confidence,
+                detected_at: relation.created_at,
+            });
+        }
+        Ok(conflicts)
+    }
+
+    async fn verdict(&self, a: &Learning, b: &Learning) -> Verdict {
+        if let Some(judge) = &self.judge {
+            match judge.judge(a, b).await {
+                Ok(verdict) => return verdict,
+                Err(e) => warn!(error = %e, "Contradiction judge failed, using polarity check"),
+            }
+        }
+        polarity_verdict(&a.content.description, &b.content.description)
+    }
+}
+
+/// Compare two instructions by negation and shared wording
+///
+/// They contradict when exactly one is negated and at least half the subject
+/// terms of the shorter one also appear in the other. Confidence is that
+/// overlap ratio.
+pub fn polarity_verdict(a: &str, b: &str) -> Verdict {
+    let (terms_a, terms_b) = (terms(a), terms(b));
+    let negated_a = terms_a.iter().any(|t| NEGATION_CUES.contains(&t.as_str()));
+    let negated_b = terms_b.iter().any(|t| NEGATION_CUES.contains(&t.as_str()));
+
+    let subject = |terms: &[String]| -> HashSet<String> {
+        terms
+            .iter()
+            .filter(|t| !NEGATION_CUES.contains(&t.as_str()) && !FILLER.contains(&t.as_str()))
+            .cloned()
+            .collect()
+    };
+    let (subject_a, subject_b) = (subject(&terms_a), subject(&terms_b));
+    let mut shared: Vec<&String> = subject_a.intersection(&subject_b).collect();
+    shared.sort();
+
+    let smaller = subject_a.len().min(subject_b.len()).max(1);
+    let overlap = shared.len() as f64 / smaller as f64;
+
+    if negated_a == negated_b {
+        return Verdict {
+            contradicts: false,
+            confidence: 0.0,
+            reason: "same polarity".to_string(),
+        };
+    }
+    if shared.is_empty() || overlap < 0.5 {
+        return Verdict {
+            contradicts: false,
+            confidence: 0.0,
+            reason: "opposite polarity about different subjects".to_string(),
+        };
+    }
+
+    let shared: Vec<&str> = shared.into_iter().map(String::as_str).collect();
+    Verdict {
+        contradicts: true,
+        confidence: overlap,
+        reason: format!("opposite polarity over {}", shared.join(", ")),
+    }
+}
+
+/// Drop the lower-ranked side of every pending conflict
+///
+/// `learnings` must be in rank order, as returned by
+/// [`ScopeChain::resolve`](crate::ScopeChain::resolve); a learning is
+/// dropped when it contradicts one already kept.
+pub fn drop_contradicted(
+    learnings: Vec<Learning>,
+    contradictions: &[(LearningId, LearningId)],
+) -> Vec<Learning> {
+    let mut kept = HashSet::new();
+    learnings
+        .into_iter()
+        .filter(|l| {
+            let conflicted = contradictions.iter().any(|(from, to)| {
+                (*from == l.id && kept.contains(to)) || (*to == l.id && kept.contains(from))
+            });
+            if !conflicted {
+                kept.insert(l.id);
+            }
+            !conflicted
+        })
+        .collect()
+}
+
+/// Lowercased words with surrounding punctuation removed
+fn terms(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.replace('\u{2019}', "'")
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Parse the judge's JSON reply
+fn parse_verdict(text: &str) -> Result<Verdict> {
+    let mut verdict: Verdict = serde_json::from_str(strip_code_fence(text))
+        .map_err(|e| GrooveError::Contradiction(format!("invalid judge reply: {e}: {text}")))?;
+    verdict.confidence = verdict.confidence.clamp(0.0, 1.0);
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::EmbedderResult;
+    use crate::store::LearningStore;
+    use crate::store::memory::MemoryStore;
+    use crate::{LearningCategory, LearningContent, LearningSource, Scope};
+    use std::sync::Mutex;
+
+    /// Embeds by the last word, so "... tokio::test" instructions are
+    /// identical whatever their polarity and anything else is orthogonal
+    struct SubjectEmbedder;
+
+    #[async_trait]
+    impl Embedder for SubjectEmbedder {
+        async fn embed(&self, text: &str) -> EmbedderResult<Vec<f32>> {
+            let subject = text.split_whitespace().last().unwrap_or_default();
+            let slot = subject.bytes().map(usize::from).sum::<usize>() % 8;
+            let mut embedding = vec![0.0; 8];
+            embedding[slot] = 1.0;
+            Ok(embedding)
+        }
+
+        fn dimensions(&self) -> usize {
+            8
+        }
+    }
+
+    /// Judge with a fixed answer that counts its calls
+    struct FixedJudge {
+        verdict: Option<Verdict>,
+        calls: Mutex<usize>,
+    }
+
+    #[async_trait]
+    impl ContradictionJudge for FixedJudge {
+        async fn judge(&self, _a: &Learning, _b: &Learning) -> Result<Verdict> {
+            *self.calls.lock().unwrap() += 1;
+            self.verdict
+                .clone()
+                .ok_or_else(|| GrooveError::Contradiction("unavailable".into()))
+        }
+    }
+
+    fn learning(description: &str, age_minutes: i64) -> Learning {
+        let mut learning = Learning::new(
+            Scope::Global,
+            LearningCategory::CodePattern,
+            LearningContent {
+                description: description.to_string(),
+                pattern: None,
+                insight: String::new(),
+            },
+            LearningSource::UserCreated,
+        );
+        learning.created_at = Utc::now() - chrono::Duration::minutes(age_minutes);
+        learning
+    }
+
+    async fn seeded(descriptions: &[&str]) -> (Arc<MemoryStore>, Vec<Learning>) {
+        let store = Arc::new(MemoryStore::default());
+        let mut learnings = Vec::new();
+        for (age, description) in descriptions.iter().enumerate() {
+            let l = learning(description, (descriptions.len() - age) as i64);
+            store.store(&l).await.unwrap();
+            learnings.push(l);
+        }
+        (store, learnings)
+    }
+
+    fn detector(store: &Arc<MemoryStore>) -> ContradictionDetector {
+        ContradictionDetector::new(store.clone(), ContradictionConfig::default())
+    }
+
+    #[test]
+    fn test_polarity_verdict_flags_negated_instruction() {
+        let verdict = polarity_verdict(
+            "Use #[tokio::test] for async tests",
+            "Never use `#[tokio::test]` for async tests in this repo",
+        );
+        assert!(verdict.contradicts);
+        assert!(verdict.confidence >= 0.5);
+        assert!(verdict.reason.contains("tokio::test"));
+    }
+
+    #[test]
+    fn test_polarity_verdict_ignores_consistent_pairs() {
+        // Same polarity
+        assert!(
+            !polarity_verdict("Never commit .env files", "Don't commit .env files").contradicts
+        );
+        // Opposite polarity, different subjects
+        assert!(!polarity_verdict("Use tabs", "Never use spaces").contradicts);
+        assert!(!polarity_verdict("Use tabs", "Use tabs").contradicts);
+    }
+
+    #[tokio::test]
+    async fn test_check_records_contradiction_from_newer_to_older() {
+        let (store, learnings) = seeded(&["Use tokio::test", "Run cargo fmt"]).await;
+        let detector = detector(&store);
+        for l in &learnings {
+            let embedding = SubjectEmbedder.embed(&l.content.description).await.unwrap();
+            detector.check(l, &embedding).await.unwrap();
+        }
+
+        let newer = learning("Never use tokio::test", 0);
+        store.store(&newer).await.unwrap();
+        let embedding = SubjectEmbedder
+            .embed(&newer.content.description)
+            .await
+            .unwrap();
+        let conflicts = detector.check(&newer, &embedding).await.unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].learning.id, newer.id);
+        assert_eq!(conflicts[0].conflicting.id, learnings[0].id);
+
+        // Already related pairs aren't judged again
+        assert!(detector.check(&newer, &embedding).await.unwrap().is_empty());
+        assert_eq!(store.contradictions().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_scans_existing_learnings() {
+        let (store, _) = seeded(&[
+            "Use tokio::test",
+            "Never use tokio::test",
+            "Run cargo fmt",
+            "Don't run cargo clippy",
+        ])
+        .await;
+
+        let report = detector(&store).backfill(&SubjectEmbedder).await.unwrap();
+
+        assert_eq!(report.learnings_scanned, 4);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(
+            report.conflicts[0].learning.content.description,
+            "Never use tokio::test"
+        );
+        assert_eq!(detector(&store).pending().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_judge_overrides_polarity_and_falls_back_on_error() {
+        let (store, _) = seeded(&["Indent with tabs", "Never indent with tabs"]).await;
+
+        let agree = Arc::new(FixedJudge {
+            verdict: Some(Verdict {
+                contradicts: false,
+                confidence: 0.9,
+                reason: "different file types".into(),
+            }),
+            calls: Mutex::new(0),
+        });
+        let report = detector(&store)
+            .with_judge(agree.clone())
+            .backfill(&SubjectEmbedder)
+            .await
+            .unwrap();
+        assert!(report.conflicts.is_empty());
+        assert!(*agree.calls.lock().unwrap() > 0);
+
+        let broken = Arc::new(FixedJudge {
+            verdict: None,
+            calls: Mutex::new(0),
+        });
+        let report = detector(&store)
+            .with_judge(broken)
+            .backfill(&SubjectEmbedder)
+            .await
+            .unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_keep_supersedes_loser() {
+        let (store, learnings) = seeded(&["Use tokio::test", "Never use tokio::test"]).await;
+        let detector = detector(&store);
+        detector.backfill(&SubjectEmbedder).await.unwrap();
+        let (old, new) = (learnings[0].id, learnings[1].id);
+
+        let outsider = uuid::Uuid::now_v7();
+        assert!(matches!(
+            detector.resolve(old, new, Resolution::Keep(outsider)).await,
+            Err(GrooveError::Contradiction(_))
+        ));
+
+        detector
+            .resolve(old, new, Resolution::Keep(new))
+            .await
+            .unwrap();
+
+        assert!(detector.pending().await.unwrap().is_empty());
+        assert_eq!(store.superseded_ids().await.unwrap(), HashSet::from([old]));
+        assert!(matches!(
+            detector.resolve(old, new, Resolution::Dismiss).await,
+            Err(GrooveError::Contradiction(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_dismiss_is_not_flagged_again() {
+        let (store, learnings) = seeded(&["Use tokio::test", "Never use tokio::test"]).await;
+        let detector = detector(&store);
+        detector.backfill(&SubjectEmbedder).await.unwrap();
+
+        detector
+            .resolve(learnings[1].id, learnings[0].id, Resolution::Dismiss)
+            .await
+            .unwrap();
+
+        let report = detector.backfill(&SubjectEmbedder).await.unwrap();
+        assert!(report.conflicts.is_empty());
+        assert!(detector.pending().await.unwrap().is_empty());
+        assert!(store.superseded_ids().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_drop_contradicted_keeps_higher_ranked_side() {
+        let a = learning("Never use tokio::test", 0);
+        let b = learning("Run cargo fmt", 0);
+        let c = learning("Use tokio::test", 0);
+        let ids = [a.id, b.id, c.id];
+
+        // c ranks below a, so it's dropped whichever way the relation points
+        let kept = drop_contradicted(vec![a.clone(), b.clone(), c.clone()], &[(c.id, a.id)]);
+        assert_eq!(
+            kept.iter().map(|l| l.id).collect::<Vec<_>>(),
+            ids[..2].to_vec()
+        );
+
+        let kept = drop_contradicted(vec![c, b, a], &[(ids[2], ids[0])]);
+        assert_eq!(
+            kept.iter().map(|l| l.id).collect::<Vec<_>>(),
+            vec![ids[2], ids[1]]
+        );
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let verdict = parse_verdict(
+            "```json\n{\"contradicts\": true, \"confidence\": 1.4, \"reason\": \"opposite\"}\n```",
+        )
+        .unwrap();
+        assert!(verdict.contradicts);
+        assert_eq!(verdict.confidence, 1.0);
+        assert!(matches!(
+            parse_verdict("yes"),
+            Err(GrooveError::Contradiction(_))
+        ));
+    }
+}
@@ -4,12 +4,13 @@
 
 use std::sync::Arc;
 
+use crate::contradiction::ContradictionDetector;
 use crate::strategy::StrategyStore;
 use crate::{
     AttributionStore, LearningId, LearningStore,
     dashboard::{
-        ActivitySummary, AttributionData, DashboardData, DashboardTopic, GapBrief, GapCounts,
-        HealthData, HookStatsData, LearningDetailData, LearningsData, LearningsFilter,
+        ActivitySummary, AttributionData, ConflictsData, DashboardData, DashboardTopic, GapBrief,
+        GapCounts, HealthData, HookStatsData, LearningDetailData, LearningsData, LearningsFilter,
         OpenWorldActivityData, OpenWorldActivityEntry, OpenWorldEventType, OpenWorldGapDetailData,
         OpenWorldGapsData, OpenWorldOverviewData, OpenWorldSolutionsData, OverviewData, Period,
         SessionTimelineData, SolutionBrief, SolutionEntry, SolutionStatus,
@@ -28,6 +29,7 @@ pub struct DashboardHandler {
     _strategy_store: Arc<dyn StrategyStore>,
     openworld_store: Option<Arc<dyn OpenWorldStore>>,
     openworld_hook: Option<Arc<OpenWorldHook>>,
+    contradictions: Option<Arc<ContradictionDetector>>,
 }
 
 impl DashboardHandler {
@@ -43,6 +45,7 @@ impl DashboardHandler {
             _strategy_store: strategy_store,
             openworld_store: None,
             openworld_hook: None,
+            contradictions: None,
         }
     }
 
@@ -57,12 +60,19 @@ impl DashboardHandler {
         self
     }
 
+    /// Configure the source of pending contradictions
+    pub fn with_contradictions(mut self, detector: Arc<ContradictionDetector>) -> Self {
+        self.contradictions = Some(detector);
+        self
+    }
+
     /// Get data for a topic
     pub async fn get_data(&self, topic: &DashboardTopic) -> Result<DashboardData, String> {
         match topic {
             DashboardTopic::Overview => self.get_overview_data().await,
             DashboardTopic::Learnings { filters } => self.get_learnings_data(filters).await,
             DashboardTopic::LearningDetail { id } => self.get_learning_detail(id).await,
+            DashboardTopic::Conflicts => self.get_conflicts_data().await,
             DashboardTopic::Attribution { period } => self.get_attribution_data(period).await,
             DashboardTopic::SessionTimeline { period } => {
                 self.get_session_timeline_data(period).await
@@ -117,6 +127,14 @@ impl DashboardHandler {
         }))
     }
 
+    async fn get_conflicts_data(&self) -> Result<DashboardData, String> {
+        let conflicts = match &self.contradictions {
+            Some(detector) => detector.pending().await.map_err(|e| e.to_string())?,
+            None => Vec::new(),
+        };
+        Ok(DashboardData::Conflicts(ConflictsData::from(conflicts)))
+    }
+
     async fn get_attribution_data(&self, _period: &Period) -> Result<DashboardData, String> {
         Ok(DashboardData::Attribution(AttributionData::default()))
     }
@@ -582,7 +600,8 @@ mod tests {
     use tokio::sync::RwLock;
 
     use crate::{
-        GrooveError, Learning, LearningCategory, LearningRelation, RelationType, Scope, UsageStats,
+        GrooveError, Learning, LearningCategory, LearningRelation, RelationType, Scope, ScopeChain,
+        UsageStats,
         assessment::SessionId,
         attribution::{AblationExperiment, AttributionRecord, LearningValue},
         strategy::{
@@ -652,6 +671,14 @@ mod tests {
         ) -> Result<Vec<(Learning, f64)>, GrooveError> {
             Ok(Vec::new())
         }
+        async fn hybrid_search(
+            &self,
+            _: &str,
+            _: Option<&[f32]>,
+            _: usize,
+        ) -> Result<Vec<(Learning, f64)>, GrooveError> {
+            Ok(Vec::new())
+        }
         async fn update_usage(
             &self,
             _: crate::LearningId,
@@ -688,7 +715,7 @@ mod tests {
         }
         async fn find_for_injection(
             &self,
-            _: &Scope,
+            _: &ScopeChain,
             _: Option<&[f32]>,
             _: usize,
         ) -> Result<Vec<Learning>, GrooveError> {
@@ -861,6 +888,20 @@ mod tests {
 
     use crate::dashboard::{DashboardData, LearningsFilter};
 
+    #[tokio::test]
+    async fn get_conflicts_data_without_detector_is_empty() {
+        let handler = create_test_handler();
+        let result = handler.get_data(&DashboardTopic::Conflicts).await;
+
+        match result.unwrap() {
+            DashboardData::Conflicts(data) => {
+                assert!(data.conflicts.is_empty());
+                assert_eq!(data.total, 0);
+            }
+            other => panic!("Expected Conflicts data, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn get_overview_data_returns_overview() {
         let handler = create_test_handler();
@@ -8,8 +8,11 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::contradiction::Conflict;
 use crate::openworld::{GapCategory, GapId, GapSeverity, GapStatus};
-use crate::{LearningCategory, LearningId, LearningStatus, Scope, strategy::InjectionStrategy};
+use crate::{
+    Learning, LearningCategory, LearningId, LearningStatus, Scope, strategy::InjectionStrategy,
+};
 
 /// Filter parameters for learnings queries
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -45,6 +48,8 @@ pub enum DashboardTopic {
     },
     /// Single learning detail
     LearningDetail { id: LearningId },
+    /// Contradicting learnings waiting for a human decision
+    Conflicts,
     /// Attribution leaderboard and data
     Attribution {
         #[serde(default)]
@@ -131,6 +136,7 @@ pub enum DashboardData {
     Overview(OverviewData),
     Learnings(LearningsData),
     LearningDetail(LearningDetailData),
+    Conflicts(ConflictsData),
     Attribution(AttributionData),
     SessionTimeline(SessionTimelineData),
     StrategyDistributions(StrategyDistributionsData),
@@ -284,6 +290,65 @@ pub struct LearningDetailData {
     pub extraction_method: String,
 }
 
+/// Pending contradictions between learnings
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ConflictsData {
+    pub conflicts: Vec<ConflictBrief>,
+    pub total: u32,
+}
+
+/// One pending contradiction
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictBrief {
+    /// The newer learning
+    pub learning: ConflictSide,
+    /// The older learning it contradicts
+    pub conflicting: ConflictSide,
+    /// Detector confidence (0.0-1.0)
+    pub confidence: f64,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// One side of a contradiction
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictSide {
+    pub id: LearningId,
+    pub content: String,
+    pub insight: String,
+    pub scope: Scope,
+    pub confidence: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Conflict> for ConflictBrief {
+    fn from(conflict: Conflict) -> Self {
+        let side = |learning: Learning| ConflictSide {
+            id: learning.id,
+            content: learning.content.description,
+            insight: learning.content.insight,
+            scope: learning.scope,
+            confidence: learning.confidence,
+            created_at: learning.created_at,
+        };
+        Self {
+            learning: side(conflict.learning),
+            conflicting: side(conflict.conflicting),
+            confidence: conflict.confidence,
+            detected_at: conflict.detected_at,
+        }
+    }
+}
+
+impl From<Vec<Conflict>> for ConflictsData {
+    fn from(conflicts: Vec<Conflict>) -> Self {
+        let conflicts: Vec<ConflictBrief> = conflicts.into_iter().map(Into::into).collect();
+        Self {
+            total: conflicts.len() as u32,
+            conflicts,
+        }
+    }
+}
+
 // ============================================================
 // Attribution Data
 // ============================================================
@@ -645,6 +710,38 @@ mod tests {
         assert!(json.contains("learnings"));
     }
 
+    #[test]
+    fn conflicts_data_from_conflicts() {
+        use crate::{LearningContent, LearningSource};
+
+        let learning = |description: &str| {
+            Learning::new(
+                Scope::Global,
+                LearningCategory::CodePattern,
+                LearningContent {
+                    description: description.to_string(),
+                    pattern: None,
+                    insight: String::new(),
+                },
+                LearningSource::UserCreated,
+            )
+        };
+        let conflict = Conflict {
+            learning: learning("Never use tokio::test"),
+            conflicting: learning("Use tokio::test"),
+            confidence: 0.8,
+            detected_at: Utc::now(),
+        };
+
+        let data = ConflictsData::from(vec![conflict]);
+        assert_eq!(data.total, 1);
+        assert_eq!(data.conflicts[0].learning.content, "Never use tokio::test");
+
+        let json = serde_json::to_string(&DashboardData::Conflicts(data)).unwrap();
+        assert!(json.contains(r#""data_type":"conflicts""#));
+        assert!(json.contains(r#""conflicting":{"#));
+    }
+
     #[test]
     fn dashboard_request_deserializes_subscribe() {
         let json = r#"{"type":"subscribe","topics":[{"topic":"overview"},{"topic":"health"}]}"#;
@@ -46,6 +46,18 @@ pub enum GrooveError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Consolidation run could not be applied or reverted
+    #[error("Consolidation error: {0}")]
+    Consolidation(String),
+
+    /// Contradiction could not be judged or resolved
+    #[error("Contradiction error: {0}")]
+    Contradiction(String),
+
+    /// Retention run could not expire, decay or audit groove data
+    #[error("Retention error: {0}")]
+    Retention(String),
+
     /// Serialization or deserialization failed
     #[error("Serialization error: {0}")]
     Serialization(String),
@@ -70,13 +70,21 @@ impl From<Learning> for LearningExport {
 }
 
 /// Statistics from an import operation
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ImportStats {
     pub learnings_imported: u32,
     pub learnings_skipped: u32,
+    #[serde(default)]
+    pub learnings_overwritten: u32,
+    #[serde(default)]
+    pub learnings_blocked: u32,
     pub params_imported: u32,
     pub relations_imported: u32,
+    /// Learnings still waiting for an embedding
     pub embeddings_queued: u32,
+    /// Learnings embedded as part of the import
+    #[serde(default)]
+    pub embeddings_stored: u32,
 }
 
 #[cfg(test)]
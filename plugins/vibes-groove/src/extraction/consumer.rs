@@ -4,6 +4,7 @@
 //! extraction pipeline (LLM candidates, pattern detectors, deduplication),
 //! and persists learnings.
 
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -11,13 +12,21 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 use vibes_iggy::{EventConsumer, Offset, SeekPosition};
 
-use crate::assessment::{EventId, HeavyEvent, SessionId};
+use crate::assessment::{
+    AssessmentContext, AssessmentProcessor, EventId, ExtractionCandidate, HarnessError, HeavyEvent,
+    LlmSessionAnalyzer, SessionId,
+};
 use crate::capture::ParsedTranscript;
-use crate::extraction::patterns::{CorrectionDetector, ErrorRecoveryDetector};
+use crate::contradiction::ContradictionDetector;
+use crate::extraction::patterns::{
+    CodePatternDetector, CorrectionDetector, ErrorRecoveryDetector, HarnessKnowledgeDetector,
+    PreferenceDetector, ToolUsageDetector,
+};
 use crate::extraction::{DeduplicationStrategy, Embedder, LearningCandidate};
+use crate::inject::checkout_scope;
 use crate::store::LearningStore;
 use crate::types::{Learning, LearningCategory, LearningContent, LearningSource, Scope};
 use crate::{GrooveError, Result};
@@ -109,7 +118,7 @@ pub trait TranscriptFetcher: Send + Sync {
 pub struct ExtractionConsumer<S, E, D, T>
 where
     S: LearningStore,
-    E: Embedder,
+    E: Embedder + ?Sized,
     D: DeduplicationStrategy,
     T: TranscriptFetcher,
 {
@@ -125,6 +134,18 @@ where
     correction_detector: CorrectionDetector,
     /// Error recovery pattern detector
     error_recovery_detector: ErrorRecoveryDetector,
+    /// Standing user instruction detector
+    preference_detector: PreferenceDetector,
+    /// Command sequence habit detector
+    tool_usage_detector: ToolUsageDetector,
+    /// Recurring code shape detector
+    code_pattern_detector: CodePatternDetector,
+    /// Harness quirk detector
+    harness_knowledge_detector: HarnessKnowledgeDetector,
+    /// LLM analyzer for heavy events that arrive without candidates
+    analyzer: Option<Arc<LlmSessionAnalyzer>>,
+    /// Checks new learnings against their neighbours for contradictions
+    contradictions: Option<Arc<ContradictionDetector>>,
     /// Configuration
     config: ExtractionConfig,
 }
@@ -132,7 +153,7 @@ where
 impl<S, E, D, T> ExtractionConsumer<S, E, D, T>
 where
     S: LearningStore,
-    E: Embedder,
+    E: Embedder + ?Sized,
     D: DeduplicationStrategy,
     T: TranscriptFetcher,
 {
@@ -151,10 +172,46 @@ where
             transcript_fetcher,
             correction_detector: CorrectionDetector::new(),
             error_recovery_detector: ErrorRecoveryDetector::new(),
+            preference_detector: PreferenceDetector::new(),
+            tool_usage_detector: ToolUsageDetector::new(),
+            code_pattern_detector: CodePatternDetector::new(),
+            harness_knowledge_detector: HarnessKnowledgeDetector::new(),
+            analyzer: None,
+            contradictions: None,
             config,
         }
     }
 
+    /// Analyze transcripts with an LLM when heavy events carry no candidates
+    #[must_use]
+    pub fn with_analyzer(mut self, analyzer: Arc<LlmSessionAnalyzer>) -> Self {
+        self.analyzer = Some(analyzer);
+        self
+    }
+
+    /// Analyze transcripts with the LLM configured for an assessment processor
+    ///
+    /// The analyzer is built from the processor's `llm` config and shares its
+    /// circuit breaker, so sessions cooling down after an intervention are
+    /// not analyzed. Backends without a vibes-models provider leave the
+    /// consumer on pattern detectors only.
+    pub fn with_session_analysis(
+        self,
+        processor: &AssessmentProcessor,
+    ) -> std::result::Result<Self, HarnessError> {
+        Ok(match processor.session_analyzer()? {
+            Some(analyzer) => self.with_analyzer(Arc::new(analyzer)),
+            None => self,
+        })
+    }
+
+    /// Check newly created learnings for contradictions with stored ones
+    #[must_use]
+    pub fn with_contradiction_detector(mut self, detector: Arc<ContradictionDetector>) -> Self {
+        self.contradictions = Some(detector);
+        self
+    }
+
     /// Check if extraction is enabled
     pub fn is_enabled(&self) -> bool {
         self.config.enabled
@@ -169,7 +226,8 @@ where
     ///
     /// This runs the full extraction pipeline:
     /// 1. Collect LLM-extracted candidates from event
-    /// 2. Fetch transcript and run pattern detectors
+    /// 2. Fetch transcript, analyze it with the LLM if the event had no
+    ///    candidates, and run pattern detectors
     /// 3. Filter by minimum confidence
     /// 4. Embed, deduplicate, and store learnings
     pub async fn process_heavy_event(&self, event: &HeavyEvent) -> Result<ExtractionResult> {
@@ -178,27 +236,13 @@ where
         }
 
         let mut result = ExtractionResult::new(event.context.event_id);
-        let mut candidates = Vec::new();
 
         // Collect LLM-extracted candidates from the event
-        for extraction_candidate in &event.extraction_candidates {
-            let source = crate::extraction::ExtractionSource::new(
-                event.context.session_id.clone(),
-                event.context.event_id,
-                crate::extraction::ExtractionMethod::Llm,
-            )
-            .with_message_range(
-                extraction_candidate.message_range.0,
-                extraction_candidate.message_range.1,
-            );
-
-            candidates.push(LearningCandidate::new(
-                extraction_candidate.description.clone(),
-                extraction_candidate.description.clone(),
-                extraction_candidate.confidence,
-                source,
-            ));
-        }
+        let mut candidates: Vec<_> = event
+            .extraction_candidates
+            .iter()
+            .map(|candidate| Self::llm_candidate(event, candidate))
+            .collect();
 
         // Fetch transcript and run pattern detectors
         if let Some(transcript) = self
@@ -206,6 +250,30 @@ where
             .fetch(&event.context.session_id)
             .await?
         {
+            // Analyze with the LLM when the assessment didn't already
+            if event.extraction_candidates.is_empty()
+                && let Some(analyzer) = &self.analyzer
+            {
+                match analyzer
+                    .analyze(&event.context.session_id, &transcript)
+                    .await
+                {
+                    Ok(analysis) => {
+                        candidates.extend(
+                            analysis
+                                .candidates
+                                .iter()
+                                .map(|candidate| Self::llm_candidate(event, candidate)),
+                        );
+                    }
+                    Err(e) => warn!(
+                        session_id = %event.context.session_id,
+                        error = %e,
+                        "LLM session analysis failed, using pattern detectors only"
+                    ),
+                }
+            }
+
             // Run correction detector
             let correction_candidates = self.correction_detector.detect(&transcript)?;
             candidates.extend(correction_candidates);
@@ -213,22 +281,70 @@ where
             // Run error recovery detector
             let error_recovery_candidates = self.error_recovery_detector.detect(&transcript)?;
             candidates.extend(error_recovery_candidates);
+
+            candidates.extend(self.preference_detector.detect(&transcript)?);
+            candidates.extend(self.tool_usage_detector.detect(&transcript)?);
+            candidates.extend(self.code_pattern_detector.detect(&transcript)?);
+            candidates.extend(self.harness_knowledge_detector.detect(&transcript)?);
         }
 
         result.candidates_processed = candidates.len() as u32;
+        if candidates.is_empty() {
+            return Ok(result);
+        }
 
-        // Process each candidate
+        let scope = Self::session_scope(&event.context);
         for candidate in candidates {
-            self.process_candidate(&candidate, &mut result).await?;
+            self.process_candidate(&candidate, &scope, &mut result)
+                .await?;
         }
 
         Ok(result)
     }
 
+    /// Convert an LLM extraction candidate into a learning candidate
+    fn llm_candidate(event: &HeavyEvent, candidate: &ExtractionCandidate) -> LearningCandidate {
+        let source = crate::extraction::ExtractionSource::new(
+            event.context.session_id.clone(),
+            event.context.event_id,
+            crate::extraction::ExtractionMethod::Llm,
+        )
+        .with_message_range(candidate.message_range.0, candidate.message_range.1);
+
+        LearningCandidate::new(
+            candidate.description.clone(),
+            candidate
+                .insight
+                .clone()
+                .unwrap_or_else(|| candidate.description.clone()),
+            candidate.confidence,
+            source,
+        )
+    }
+
+    /// Scope for learnings from a session
+    ///
+    /// Learnings belong to the repository the session worked in, so every
+    /// clone shares them, or to the project path outside git. Sessions with
+    /// no known working directory fall back to the user.
+    fn session_scope(context: &AssessmentContext) -> Scope {
+        match &context.project_id {
+            Some(project) => checkout_scope(Path::new(project.as_str())),
+            None => Scope::User(
+                context
+                    .user_id
+                    .as_ref()
+                    .map_or("default", |user| user.as_str())
+                    .to_string(),
+            ),
+        }
+    }
+
     /// Process a single learning candidate
     async fn process_candidate(
         &self,
         candidate: &LearningCandidate,
+        scope: &Scope,
         result: &mut ExtractionResult,
     ) -> Result<()> {
         // Filter by minimum confidence
@@ -250,7 +366,7 @@ where
             .map_err(|e| GrooveError::Embedding(e.to_string()))?;
 
         // Create a learning from the candidate
-        let learning = self.candidate_to_learning(candidate, embedding.clone())?;
+        let learning = self.candidate_to_learning(candidate, scope.clone(), embedding.clone())?;
 
         // Check for duplicates
         if let Some(existing) = self
@@ -272,6 +388,17 @@ where
             let id = self.store.store(&learning).await?;
             result.created.push(id);
             debug!(id = %id, "Created new learning");
+
+            // A failed check must not lose the learning; the backfill job
+            // catches anything missed here
+            if let Some(detector) = &self.contradictions {
+                match detector.check(&learning, &embedding).await {
+                    Ok(conflicts) => result
+                        .contradicted
+                        .extend(conflicts.iter().map(|c| (c.learning.id, c.conflicting.id))),
+                    Err(e) => warn!(id = %id, error = %e, "Contradiction check failed"),
+                }
+            }
         }
 
         Ok(())
@@ -281,17 +408,18 @@ where
     fn candidate_to_learning(
         &self,
         candidate: &LearningCandidate,
+        scope: Scope,
         _embedding: Vec<f32>,
     ) -> Result<Learning> {
-        // Determine scope from session context
-        // For now, default to user scope
-        let scope = Scope::User("default".to_string());
-
         // Determine category from extraction method
         let category = match &candidate.source.extraction_method {
             crate::extraction::ExtractionMethod::Pattern(pattern_type) => match pattern_type {
                 crate::extraction::PatternType::Correction => LearningCategory::Preference,
                 crate::extraction::PatternType::ErrorRecovery => LearningCategory::ErrorRecovery,
+                crate::extraction::PatternType::Preference => LearningCategory::Preference,
+                crate::extraction::PatternType::ToolSequence => LearningCategory::ToolUsage,
+                crate::extraction::PatternType::CodeShape => LearningCategory::CodePattern,
+                crate::extraction::PatternType::HarnessQuirk => LearningCategory::HarnessKnowledge,
             },
             crate::extraction::ExtractionMethod::Llm => LearningCategory::CodePattern,
         };
@@ -333,6 +461,8 @@ pub struct ExtractionResult {
     pub created: Vec<uuid::Uuid>,
     /// Pairs of (new_id, merged_into_id) for merged learnings
     pub merged: Vec<(uuid::Uuid, uuid::Uuid)>,
+    /// Pairs of (newer_id, older_id) for contradictions found at store time
+    pub contradicted: Vec<(uuid::Uuid, uuid::Uuid)>,
     /// Number of candidates rejected (below confidence threshold)
     pub rejected: u32,
     /// Whether extraction was disabled
@@ -479,7 +609,7 @@ pub async fn extraction_consumer_loop<S, E, D, T, P>(
 ) -> ConsumerResult
 where
     S: LearningStore + 'static,
-    E: Embedder + 'static,
+    E: Embedder + ?Sized + 'static,
     D: DeduplicationStrategy + 'static,
     T: TranscriptFetcher + 'static,
     P: vibes_iggy::EventLog<ExtractionEvent> + 'static,
@@ -615,7 +745,7 @@ pub async fn start_extraction_consumer<S, E, D, T, L, P>(
 ) -> std::result::Result<JoinHandle<ConsumerResult>, StartConsumerError>
 where
     S: LearningStore + 'static,
-    E: Embedder + 'static,
+    E: Embedder + ?Sized + 'static,
     D: DeduplicationStrategy + 'static,
     T: TranscriptFetcher + 'static,
     L: vibes_iggy::EventLog<HeavyEvent> + 'static,
@@ -653,7 +783,7 @@ mod tests {
     use crate::assessment::{AssessmentContext, ExtractionCandidate, Outcome};
     use crate::capture::{TranscriptMessage, TranscriptMetadata};
     use crate::extraction::EmbedderResult;
-    use crate::types::LearningId;
+    use crate::types::{LearningId, ScopeChain};
     use std::collections::HashMap;
     use std::sync::Mutex;
     use vibes_iggy::{EventLog as _, InMemoryEventLog};
@@ -721,6 +851,15 @@ mod tests {
             Ok(Vec::new())
         }
 
+        async fn hybrid_search(
+            &self,
+            _query: &str,
+            _embedding: Option<&[f32]>,
+            _limit: usize,
+        ) -> std::result::Result<Vec<(Learning, f64)>, GrooveError> {
+            Ok(Vec::new())
+        }
+
         async fn update_usage(
             &self,
             _id: LearningId,
@@ -771,7 +910,7 @@ mod tests {
 
         async fn find_for_injection(
             &self,
-            _scope: &Scope,
+            _scopes: &ScopeChain,
             _context_embedding: Option<&[f32]>,
             _limit: usize,
         ) -> std::result::Result<Vec<Learning>, GrooveError> {
@@ -1008,6 +1147,41 @@ mod tests {
         assert_eq!(store.stored_count(), 2);
     }
 
+    #[tokio::test]
+    async fn test_learnings_are_scoped_to_the_session_checkout() {
+        let store = Arc::new(MockStore::new());
+        let consumer = ExtractionConsumer::new(
+            store.clone(),
+            Arc::new(MockEmbedder::new()),
+            Arc::new(MockDedup),
+            Arc::new(MockTranscriptFetcher::new()),
+            ExtractionConfig::default(),
+        );
+        let candidates = vec![ExtractionCandidate::new((0, 5), "Use snake_case", 0.9)];
+
+        // Outside git the project path is the scope
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path().display().to_string();
+        let event = HeavyEvent::new(
+            AssessmentContext::new("s1").with_project_id(cwd.as_str()),
+            Outcome::Success,
+        )
+        .with_extraction_candidates(candidates.clone());
+        let result = consumer.process_heavy_event(&event).await.unwrap();
+        let stored = store.learnings.lock().unwrap()[&result.created[0]].clone();
+        assert_eq!(stored.scope, Scope::Project(cwd));
+
+        // Without a working directory learnings stay with the user
+        let event = HeavyEvent::new(
+            AssessmentContext::new("s2").with_user_id("alex"),
+            Outcome::Success,
+        )
+        .with_extraction_candidates(candidates);
+        let result = consumer.process_heavy_event(&event).await.unwrap();
+        let stored = store.learnings.lock().unwrap()[&result.created[0]].clone();
+        assert_eq!(stored.scope, Scope::User("alex".into()));
+    }
+
     #[tokio::test]
     async fn test_runs_pattern_detectors_on_transcript() {
         let store = Arc::new(MockStore::new());
@@ -1036,6 +1210,166 @@ mod tests {
         assert!(!result.created.is_empty());
     }
 
+    /// Model provider that always gives the same analysis
+    struct FixedProvider(&'static str);
+
+    #[async_trait]
+    impl vibes_models::providers::ModelProvider for FixedProvider {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        fn models(&self) -> Vec<vibes_models::ModelInfo> {
+            Vec::new()
+        }
+
+        async fn chat(
+            &self,
+            _request: vibes_models::providers::ChatRequest,
+        ) -> vibes_models::Result<vibes_models::providers::ChatResponse> {
+            use vibes_models::providers::{ChatResponse, Content, StopReason, Usage};
+            Ok(ChatResponse {
+                content: Content::text(self.0),
+                stop_reason: StopReason::EndTurn,
+                tool_calls: vec![],
+                usage: Usage::new(100, 50),
+            })
+        }
+
+        async fn chat_stream(
+            &self,
+            _request: vibes_models::providers::ChatRequest,
+        ) -> vibes_models::Result<vibes_models::providers::ChatStream> {
+            Err(vibes_models::Error::ProviderApi(
+                "analysis does not stream".to_string(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_analyzes_transcript_without_candidates() {
+        let store = Arc::new(MockStore::new());
+        let transcript = make_transcript(vec![
+            ("user", "Add a --verbose flag"),
+            ("assistant", "Added it with clap derive."),
+        ]);
+        let fetcher = Arc::new(MockTranscriptFetcher::new().with_transcript(transcript));
+        let analyzer = Arc::new(LlmSessionAnalyzer::new(
+            Arc::new(FixedProvider(
+                r#"{"outcome": "success", "task_summary": "Add a flag", "score": 0.6,
+                    "findings": [],
+                    "learnings": [{"description": "Use clap derive for CLI flags",
+                                   "insight": "Derive parsers are the project convention",
+                                   "message_range": [0, 1], "confidence": 0.9}]}"#,
+            )),
+            crate::assessment::LlmConfig::default(),
+        ));
+
+        let consumer = ExtractionConsumer::new(
+            store.clone(),
+            Arc::new(MockEmbedder::new()),
+            Arc::new(MockDedup),
+            fetcher,
+            ExtractionConfig::default(),
+        )
+        .with_analyzer(analyzer.clone());
+
+        let result = consumer
+            .process_heavy_event(&make_heavy_event(vec![]))
+            .await
+            .unwrap();
+
+        assert_eq!(result.created.len(), 1);
+        let learnings = store.learnings.lock().unwrap();
+        let learning = &learnings[&result.created[0]];
+        assert_eq!(learning.category, LearningCategory::CodePattern);
+        assert_eq!(
+            learning.content.insight,
+            "Derive parsers are the project convention"
+        );
+        assert_eq!(analyzer.usage().analyses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_session_analysis_follows_processor_config_and_breaker() {
+        use crate::assessment::{
+            AssessmentConfig, InMemoryAssessmentLog, LightweightEvent, LightweightSignal,
+        };
+
+        let consumer = || {
+            ExtractionConsumer::new(
+                Arc::new(MockStore::new()),
+                Arc::new(MockEmbedder::new()),
+                Arc::new(MockDedup),
+                Arc::new(MockTranscriptFetcher::new()),
+                ExtractionConfig::default(),
+            )
+        };
+
+        // The default harness backend has no provider to analyze with
+        let harness = AssessmentProcessor::new_for_test(
+            AssessmentConfig::default(),
+            Arc::new(InMemoryAssessmentLog::new()),
+        );
+        let without = consumer().with_session_analysis(&harness).unwrap();
+        assert!(without.analyzer.is_none());
+
+        let mut config = AssessmentConfig::default();
+        config.llm.backend = "ollama".to_string();
+        let processor =
+            AssessmentProcessor::new_for_test(config, Arc::new(InMemoryAssessmentLog::new()));
+        let with = consumer().with_session_analysis(&processor).unwrap();
+        let analyzer = with.analyzer.as_ref().unwrap();
+        assert_eq!(analyzer.provider_name(), "ollama");
+
+        // Opening the processor's circuit stops analysis of that session
+        let event = LightweightEvent::new(AssessmentContext::new("s1"), 0, uuid::Uuid::now_v7())
+            .with_signal(LightweightSignal::Negative {
+                pattern: "frustrated".to_string(),
+                confidence: 1.0,
+            });
+        processor
+            .circuit_breaker()
+            .write()
+            .await
+            .record_event(&event);
+        let transcript = make_transcript(vec![("user", "hi")]);
+        assert!(matches!(
+            analyzer.analyze(&SessionId::from("s1"), &transcript).await,
+            Err(HarnessError::CircuitOpen(_))
+        ));
+
+        harness.shutdown();
+        processor.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_skips_analyzer_when_event_has_candidates() {
+        let store = Arc::new(MockStore::new());
+        let fetcher = Arc::new(
+            MockTranscriptFetcher::new().with_transcript(make_transcript(vec![("user", "hi")])),
+        );
+        let analyzer = Arc::new(LlmSessionAnalyzer::new(
+            Arc::new(FixedProvider("not json")),
+            crate::assessment::LlmConfig::default(),
+        ));
+
+        let consumer = ExtractionConsumer::new(
+            store.clone(),
+            Arc::new(MockEmbedder::new()),
+            Arc::new(MockDedup),
+            fetcher,
+            ExtractionConfig::default(),
+        )
+        .with_analyzer(analyzer.clone());
+
+        let event = make_heavy_event(vec![ExtractionCandidate::new((0, 1), "Existing", 0.9)]);
+        let result = consumer.process_heavy_event(&event).await.unwrap();
+
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(analyzer.usage(), Default::default());
+    }
+
     #[tokio::test]
     async fn test_filters_by_confidence() {
         let store = Arc::new(MockStore::new());
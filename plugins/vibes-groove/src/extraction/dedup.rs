@@ -124,7 +124,9 @@ impl DeduplicationStrategy for SemanticDedup {
 mod tests {
     use super::*;
     use crate::extraction::EmbedderResult;
-    use crate::types::{LearningCategory, LearningContent, LearningId, LearningSource, Scope};
+    use crate::types::{
+        LearningCategory, LearningContent, LearningId, LearningSource, Scope, ScopeChain,
+    };
     use crate::{LearningRelation, RelationType, UsageStats};
     use std::collections::HashMap;
     use std::sync::Mutex;
@@ -210,6 +212,15 @@ mod tests {
             Ok(Vec::new())
         }
 
+        async fn hybrid_search(
+            &self,
+            _query: &str,
+            _embedding: Option<&[f32]>,
+            _limit: usize,
+        ) -> Result<Vec<(Learning, f64)>, GrooveError> {
+            Ok(Vec::new())
+        }
+
         async fn update_usage(
             &self,
             _id: LearningId,
@@ -253,7 +264,7 @@ mod tests {
 
         async fn find_for_injection(
             &self,
-            _scope: &Scope,
+            _scopes: &ScopeChain,
             _context_embedding: Option<&[f32]>,
             _limit: usize,
         ) -> Result<Vec<Learning>, GrooveError> {
@@ -62,6 +62,14 @@ pub trait Embedder: Send + Sync {
 
     /// Returns the dimensionality of generated embeddings
     fn dimensions(&self) -> usize;
+
+    /// Identity of the model behind the embeddings
+    ///
+    /// Vectors from different models are not comparable even when their
+    /// dimensions match, so the store records this with every vector.
+    fn model_id(&self) -> &str {
+        "unknown"
+    }
 }
 
 /// Compute cosine similarity between two embedding vectors
@@ -84,6 +92,9 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 /// Embedding dimensions for gte-small model
 pub const GTE_SMALL_DIMENSIONS: usize = 384;
 
+/// Model identity recorded with gte-small embeddings
+pub const GTE_SMALL_MODEL_ID: &str = "gte-small";
+
 /// Default model cache directory
 pub fn default_cache_dir() -> PathBuf {
     dirs::cache_dir()
@@ -251,7 +262,7 @@ impl LocalEmbedder {
         }
 
         Ok(ModelInfo {
-            model_name: "gte-small".to_string(),
+            model_name: GTE_SMALL_MODEL_ID.to_string(),
             dimensions: GTE_SMALL_DIMENSIONS,
             model_dir: self.model_dir.clone(),
         })
@@ -346,6 +357,10 @@ impl Embedder for LocalEmbedder {
     fn dimensions(&self) -> usize {
         GTE_SMALL_DIMENSIONS
     }
+
+    fn model_id(&self) -> &str {
+        GTE_SMALL_MODEL_ID
+    }
 }
 
 /// Sync embedding implementation used by spawn_blocking
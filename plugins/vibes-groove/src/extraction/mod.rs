@@ -8,6 +8,7 @@ pub mod consumer;
 pub mod dedup;
 pub mod embedder;
 pub mod patterns;
+pub mod provider;
 pub mod types;
 
 pub use consumer::{
@@ -16,7 +17,8 @@ pub use consumer::{
 };
 pub use dedup::{DEFAULT_SIMILARITY_THRESHOLD, DeduplicationStrategy, SemanticDedup};
 pub use embedder::{
-    Embedder, EmbedderError, EmbedderResult, GTE_SMALL_DIMENSIONS, LocalEmbedder, ModelInfo,
-    cosine_similarity, default_cache_dir,
+    Embedder, EmbedderError, EmbedderResult, GTE_SMALL_DIMENSIONS, GTE_SMALL_MODEL_ID,
+    LocalEmbedder, ModelInfo, cosine_similarity, default_cache_dir,
 };
+pub use provider::{EmbeddingConfig, ProviderEmbedder};
 pub use types::*;
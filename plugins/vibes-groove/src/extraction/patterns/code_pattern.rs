@@ -0,0 +1,412 @@
+//! Code pattern detector
+//!
+//! Detects recurring code shapes in the content written by Edit/Write tool
+//! calls to extract code pattern learnings. Lines are compared after string and
+//! number literals are blanked out, so `.context("reading config")?` and
+//! `.context("parsing args")?` count as the same shape.
+
+use std::collections::BTreeSet;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::Result;
+use crate::assessment::{EventId, SessionId};
+use crate::capture::{ParsedTranscript, TranscriptToolUse};
+use crate::extraction::{ExtractionMethod, ExtractionSource, LearningCandidate, PatternType};
+
+/// Shortest line worth treating as a code shape
+const MIN_LINE_CHARS: usize = 12;
+
+/// Line prefixes that are comments rather than code
+const COMMENT_PREFIXES: &[&str] = &["//", "/*", "*", "--", "<!--"];
+
+/// Configuration for the code pattern detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodePatternConfig {
+    /// Whether code pattern detection is enabled
+    pub enabled: bool,
+    /// Minimum confidence threshold
+    pub min_confidence: f64,
+    /// Minimum number of edits a shape must appear in
+    pub min_occurrences: u32,
+    /// Minimum number of distinct files a shape must appear in
+    pub min_files: usize,
+    /// Maximum patterns reported per transcript
+    pub max_patterns: usize,
+}
+
+impl Default for CodePatternConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_confidence: 0.5,
+            min_occurrences: 3,
+            min_files: 2,
+            max_patterns: 5,
+        }
+    }
+}
+
+/// A normalized line and where it was written
+#[derive(Debug)]
+struct Shape {
+    shape: String,
+    example: String,
+    occurrences: u32,
+    files: BTreeSet<String>,
+    first_index: usize,
+    last_index: usize,
+}
+
+/// Detects recurring code shapes in file edits
+pub struct CodePatternDetector {
+    string_literal: Regex,
+    number_literal: Regex,
+    config: CodePatternConfig,
+}
+
+impl CodePatternDetector {
+    /// Create a new detector with default configuration
+    pub fn new() -> Self {
+        Self::with_config(&CodePatternConfig::default())
+    }
+
+    /// Create with custom configuration
+    pub fn with_config(config: &CodePatternConfig) -> Self {
+        Self {
+            string_literal: Regex::new(r#""(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'"#).unwrap(),
+            number_literal: Regex::new(r"\b\d+(\.\d+)?\b").unwrap(),
+            config: config.clone(),
+        }
+    }
+
+    /// Detect recurring code shapes in a transcript
+    pub fn detect(&self, transcript: &ParsedTranscript) -> Result<Vec<LearningCandidate>> {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let mut shapes: Vec<Shape> = Vec::new();
+
+        for (i, tool) in transcript.tool_uses.iter().enumerate() {
+            if !tool.success {
+                continue;
+            }
+            let Some((file, code)) = written_code(tool) else {
+                continue;
+            };
+
+            // Count a shape once per edit, however often the edit repeats it
+            let mut seen = BTreeSet::new();
+            for line in code.lines().map(str::trim) {
+                let Some(shape) = self.shape(line) else {
+                    continue;
+                };
+                if !seen.insert(shape.clone()) {
+                    continue;
+                }
+
+                match shapes.iter_mut().find(|s| s.shape == shape) {
+                    Some(existing) => {
+                        existing.occurrences += 1;
+                        existing.files.insert(file.clone());
+                        existing.last_index = i;
+                    }
+                    None => shapes.push(Shape {
+                        shape,
+                        example: line.to_string(),
+                        occurrences: 1,
+                        files: BTreeSet::from([file.clone()]),
+                        first_index: i,
+                        last_index: i,
+                    }),
+                }
+            }
+        }
+
+        let mut recurring: Vec<(Shape, f64)> = shapes
+            .into_iter()
+            .filter(|s| {
+                s.occurrences >= self.config.min_occurrences
+                    && s.files.len() >= self.config.min_files
+            })
+            .map(|s| {
+                let confidence = confidence(s.occurrences, s.files.len());
+                (s, confidence)
+            })
+            .filter(|(_, confidence)| *confidence >= self.config.min_confidence)
+            .collect();
+
+        // Most widespread first
+        recurring.sort_by(|(a, _), (b, _)| {
+            (b.occurrences, b.files.len()).cmp(&(a.occurrences, a.files.len()))
+        });
+        recurring.truncate(self.config.max_patterns);
+
+        Ok(recurring
+            .iter()
+            .map(|(shape, confidence)| to_learning_candidate(transcript, shape, *confidence))
+            .collect())
+    }
+
+    /// Normalize a line of code, or `None` if it is too trivial to be a pattern
+    fn shape(&self, line: &str) -> Option<String> {
+        if line.chars().count() < MIN_LINE_CHARS
+            || COMMENT_PREFIXES.iter().any(|p| line.starts_with(p))
+            || (line.starts_with('#') && !line.starts_with("#["))
+        {
+            return None;
+        }
+
+        // Must contain a word, not just punctuation and literals
+        let has_word = line
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|w| w.len() >= 3 && w.starts_with(|c: char| c.is_alphabetic()));
+        if !has_word {
+            return None;
+        }
+
+        let shape = self.string_literal.replace_all(line, "\"_\"");
+        let shape = self.number_literal.replace_all(&shape, "0");
+        Some(shape.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Default for CodePatternDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score a shape by how many edits and files it spans
+fn confidence(occurrences: u32, files: usize) -> f64 {
+    let occurrence_bonus = 0.1 * f64::from(occurrences.min(6) - 1);
+    let file_bonus = 0.05 * (files.min(5) - 1) as f64;
+    (0.45 + occurrence_bonus + file_bonus).min(0.9)
+}
+
+fn to_learning_candidate(
+    transcript: &ParsedTranscript,
+    shape: &Shape,
+    confidence: f64,
+) -> LearningCandidate {
+    let language = shape
+        .files
+        .iter()
+        .find_map(|f| language(f))
+        .unwrap_or("edits");
+    let example = truncate(&shape.example, 80);
+
+    let description = format!("Recurring code in {language}: `{example}`");
+    let insight = format!(
+        "Code in this project repeatedly uses `{}` ({} edits across {} files); follow the same shape",
+        example,
+        shape.occurrences,
+        shape.files.len()
+    );
+
+    let source = ExtractionSource::new(
+        SessionId::from(transcript.session_id.as_str()),
+        EventId::new(),
+        ExtractionMethod::Pattern(PatternType::CodeShape),
+    )
+    .with_message_range(shape.first_index as u32, shape.last_index as u32);
+
+    LearningCandidate::new(description, insight, confidence, source).with_pattern(json!({
+        "shape": shape.shape,
+        "example": shape.example,
+        "occurrences": shape.occurrences,
+        "files": shape.files,
+    }))
+}
+
+/// The file and code written by an edit tool call
+fn written_code(tool: &TranscriptToolUse) -> Option<(String, String)> {
+    let input = &tool.input;
+    let file = input
+        .get("file_path")
+        .or_else(|| input.get("path"))
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+
+    let code = match tool.tool_name.as_str() {
+        "Write" => input.get("content")?.as_str()?.to_string(),
+        "Edit" => input.get("new_string")?.as_str()?.to_string(),
+        "MultiEdit" => input
+            .get("edits")?
+            .as_array()?
+            .iter()
+            .filter_map(|edit| edit.get("new_string").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => return None,
+    };
+    Some((file, code))
+}
+
+/// Language name from a file extension
+fn language(path: &str) -> Option<&'static str> {
+    let extension = path.rsplit_once('.')?.1;
+    Some(match extension {
+        "rs" => "Rust",
+        "ts" | "tsx" => "TypeScript",
+        "js" | "jsx" | "mjs" => "JavaScript",
+        "py" => "Python",
+        "go" => "Go",
+        "java" => "Java",
+        "kt" => "Kotlin",
+        "rb" => "Ruby",
+        "swift" => "Swift",
+        "c" | "h" => "C",
+        "cpp" | "cc" | "hpp" => "C++",
+        _ => return None,
+    })
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let cut: String = s.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{cut}...")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(file: &str, new_string: &str) -> TranscriptToolUse {
+        TranscriptToolUse {
+            tool_name: "Edit".to_string(),
+            input: json!({ "file_path": file, "old_string": "", "new_string": new_string }),
+            output: None,
+            success: true,
+        }
+    }
+
+    fn make_transcript(tool_uses: Vec<TranscriptToolUse>) -> ParsedTranscript {
+        ParsedTranscript {
+            session_id: "test-session".to_string(),
+            messages: Vec::new(),
+            tool_uses,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_detects_shape_across_files() {
+        let detector = CodePatternDetector::new();
+        let transcript = make_transcript(vec![
+            edit(
+                "/src/config.rs",
+                "let text = fs::read_to_string(path).context(\"reading config\")?;",
+            ),
+            edit(
+                "/src/main.rs",
+                "let text = fs::read_to_string(path).context(\"reading args\")?;",
+            ),
+            edit(
+                "/src/cache.rs",
+                "let text = fs::read_to_string(path).context(\"reading cache\")?;\nOk(())",
+            ),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert!(candidate.description.starts_with("Recurring code in Rust:"));
+        assert!((candidate.confidence - 0.75).abs() < 1e-9);
+        assert_eq!(candidate.source.message_range, Some((0, 2)));
+        assert_eq!(
+            candidate.pattern.as_ref().unwrap()["shape"],
+            "let text = fs::read_to_string(path).context(\"_\")?;"
+        );
+        assert_eq!(
+            candidate.source.extraction_method,
+            ExtractionMethod::Pattern(PatternType::CodeShape)
+        );
+    }
+
+    #[test]
+    fn test_requires_multiple_files() {
+        let detector = CodePatternDetector::new();
+        let line = "#[derive(Debug, Clone, Serialize, Deserialize)]";
+        let transcript = make_transcript(vec![
+            edit("/src/types.rs", line),
+            edit("/src/types.rs", line),
+            edit("/src/types.rs", line),
+        ]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reads_write_and_multi_edit() {
+        let detector = CodePatternDetector::new();
+        let line = "#[derive(Debug, Clone, Serialize, Deserialize)]";
+        let write = TranscriptToolUse {
+            tool_name: "Write".to_string(),
+            input: json!({ "file_path": "/src/a.rs", "content": format!("{line}\n{line}") }),
+            output: None,
+            success: true,
+        };
+        let multi = TranscriptToolUse {
+            tool_name: "MultiEdit".to_string(),
+            input: json!({ "file_path": "/src/b.rs", "edits": [{ "new_string": line }] }),
+            output: None,
+            success: true,
+        };
+        let transcript = make_transcript(vec![write, multi, edit("/src/c.rs", line)]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].pattern.as_ref().unwrap()["occurrences"], 3);
+    }
+
+    #[test]
+    fn test_skips_comments_and_trivial_lines() {
+        let detector = CodePatternDetector::new();
+        assert!(detector.shape("// TODO: handle errors here").is_none());
+        assert!(detector.shape("# Heading in markdown").is_none());
+        assert!(detector.shape("});").is_none());
+        assert!(detector.shape("[0, 1, 2, 3, 4, 5, 6]").is_none());
+        assert!(detector.shape("#[tokio::test]").is_some());
+    }
+
+    #[test]
+    fn test_failed_edits_are_ignored() {
+        let detector = CodePatternDetector::new();
+        let line = "tracing::info!(\"starting server\");";
+        let mut failed = edit("/src/a.rs", line);
+        failed.success = false;
+        let transcript = make_transcript(vec![
+            failed,
+            edit("/src/b.rs", line),
+            edit("/src/c.rs", line),
+        ]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_max_patterns() {
+        let detector = CodePatternDetector::with_config(&CodePatternConfig {
+            max_patterns: 1,
+            ..Default::default()
+        });
+        let code = "use std::collections::HashMap;\nuse serde::{Deserialize, Serialize};";
+        let transcript = make_transcript(vec![
+            edit("/a.rs", code),
+            edit("/b.rs", code),
+            edit("/c.rs", code),
+        ]);
+
+        assert_eq!(detector.detect(&transcript).unwrap().len(), 1);
+    }
+}
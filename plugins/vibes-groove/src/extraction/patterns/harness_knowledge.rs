@@ -0,0 +1,334 @@
+//! Harness knowledge detector
+//!
+//! Detects harness quirks - rules the coding assistant's tools enforce, such
+//! as having to read a file before editing it - from failed tool calls, to
+//! extract harness knowledge learnings. A quirk the agent then worked around
+//! scores higher than one it only ran into.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::Result;
+use crate::assessment::{EventId, SessionId};
+use crate::capture::ParsedTranscript;
+use crate::extraction::{ExtractionMethod, ExtractionSource, LearningCandidate, PatternType};
+
+/// A known harness rule and the tool error that reveals it
+struct Quirk {
+    id: &'static str,
+    pattern: &'static str,
+    description: &'static str,
+    insight: &'static str,
+}
+
+/// Known quirks, checked in order; the first match wins
+const QUIRKS: &[Quirk] = &[
+    Quirk {
+        id: "read_before_write",
+        pattern: r"(?i)(file has not been read yet|must (read|use the read tool).{0,40}before)",
+        description: "Read a file before editing or overwriting it",
+        insight: "The harness rejects edits to files that have not been read in this session",
+    },
+    Quirk {
+        id: "modified_since_read",
+        pattern: r"(?i)(modified since (it was )?(last )?read|file has been (unexpectedly )?modified)",
+        description: "Re-read a file after it changes on disk",
+        insight: "The harness rejects edits when the file changed since it was last read",
+    },
+    Quirk {
+        id: "old_string_not_found",
+        pattern: r"(?i)(string to replace not found|old_string.{0,20}not found|no match(es)? found for)",
+        description: "Copy `old_string` exactly from the file when editing",
+        insight: "Edits fail unless the replaced text matches the file exactly, including whitespace",
+    },
+    Quirk {
+        id: "old_string_ambiguous",
+        pattern: r"(?i)(found \d+ matches|matches? (multiple|more than one) (locations|times)|not unique)",
+        description: "Give edits enough context to match exactly once",
+        insight: "Edits fail when the replaced text appears more than once in the file",
+    },
+    Quirk {
+        id: "absolute_path",
+        pattern: r"(?i)(must be (an )?absolute|absolute path (is )?required|relative paths? (are|is) not (allowed|supported))",
+        description: "Use absolute paths in file tool calls",
+        insight: "The harness file tools reject relative paths",
+    },
+    Quirk {
+        id: "command_timeout",
+        pattern: r"(?i)(command timed out|timed out after|exceeded (the )?timeout)",
+        description: "Run long commands in the background or with a longer timeout",
+        insight: "Shell commands are killed when they exceed the harness timeout",
+    },
+    Quirk {
+        id: "permission_denied",
+        pattern: r"(?i)(permission to use \w+ (has been|was) denied|user (denied|rejected) (this|the) (tool|request)|requires approval)",
+        description: "Ask before running tools the user has not approved",
+        insight: "The user or harness permission settings blocked this tool",
+    },
+    Quirk {
+        id: "interactive_command",
+        pattern: r"(?i)(interactive (mode|commands?) (is |are )?not supported|waiting for (user )?input|stdin is not a (tty|terminal))",
+        description: "Use non-interactive flags for shell commands",
+        insight: "The harness shell has no terminal, so interactive prompts hang or fail",
+    },
+];
+
+/// Configuration for the harness knowledge detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HarnessKnowledgeConfig {
+    /// Whether harness knowledge detection is enabled
+    pub enabled: bool,
+    /// Minimum confidence threshold
+    pub min_confidence: f64,
+    /// How many tool calls after a failure to look for the workaround
+    pub recovery_window: usize,
+}
+
+impl Default for HarnessKnowledgeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_confidence: 0.5,
+            recovery_window: 3,
+        }
+    }
+}
+
+/// Every failure caused by one quirk
+#[derive(Debug)]
+struct QuirkHit {
+    quirk: usize,
+    tool_name: String,
+    occurrences: u32,
+    recovered: bool,
+    first_index: usize,
+    last_index: usize,
+}
+
+/// Detects harness quirks from tool errors in transcripts
+pub struct HarnessKnowledgeDetector {
+    patterns: Vec<Regex>,
+    config: HarnessKnowledgeConfig,
+}
+
+impl HarnessKnowledgeDetector {
+    /// Create a new detector with default configuration
+    pub fn new() -> Self {
+        Self::with_config(&HarnessKnowledgeConfig::default())
+    }
+
+    /// Create with custom configuration
+    pub fn with_config(config: &HarnessKnowledgeConfig) -> Self {
+        Self {
+            patterns: QUIRKS
+                .iter()
+                .map(|q| Regex::new(q.pattern).unwrap())
+                .collect(),
+            config: config.clone(),
+        }
+    }
+
+    /// Detect harness quirks in a transcript
+    pub fn detect(&self, transcript: &ParsedTranscript) -> Result<Vec<LearningCandidate>> {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let tools = &transcript.tool_uses;
+        let mut hits: Vec<QuirkHit> = Vec::new();
+
+        for (i, tool) in tools.iter().enumerate() {
+            if tool.success {
+                continue;
+            }
+            let Some(output) = tool.output.as_deref() else {
+                continue;
+            };
+            let Some(quirk) = self.patterns.iter().position(|p| p.is_match(output)) else {
+                continue;
+            };
+
+            let recovered = tools
+                .iter()
+                .skip(i + 1)
+                .take(self.config.recovery_window)
+                .any(|later| later.success && later.tool_name == tool.tool_name);
+
+            match hits.iter_mut().find(|h| h.quirk == quirk) {
+                Some(hit) => {
+                    hit.occurrences += 1;
+                    hit.recovered |= recovered;
+                    hit.last_index = i;
+                }
+                None => hits.push(QuirkHit {
+                    quirk,
+                    tool_name: tool.tool_name.clone(),
+                    occurrences: 1,
+                    recovered,
+                    first_index: i,
+                    last_index: i,
+                }),
+            }
+        }
+
+        let candidates = hits
+            .iter()
+            .filter_map(|hit| {
+                let confidence = confidence(hit);
+                (confidence >= self.config.min_confidence)
+                    .then(|| to_learning_candidate(transcript, hit, confidence))
+            })
+            .collect();
+
+        Ok(candidates)
+    }
+}
+
+impl Default for HarnessKnowledgeDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score a quirk by whether it was worked around and whether it recurred
+fn confidence(hit: &QuirkHit) -> f64 {
+    let recovery_bonus: f64 = if hit.recovered { 0.15 } else { 0.0 };
+    let repeat_bonus = if hit.occurrences >= 2 { 0.1 } else { 0.0 };
+    (0.6 + recovery_bonus + repeat_bonus).min(0.95)
+}
+
+fn to_learning_candidate(
+    transcript: &ParsedTranscript,
+    hit: &QuirkHit,
+    confidence: f64,
+) -> LearningCandidate {
+    let quirk = &QUIRKS[hit.quirk];
+    let insight = format!("{} (seen on {})", quirk.insight, hit.tool_name);
+
+    let source = ExtractionSource::new(
+        SessionId::from(transcript.session_id.as_str()),
+        EventId::new(),
+        ExtractionMethod::Pattern(PatternType::HarnessQuirk),
+    )
+    .with_message_range(hit.first_index as u32, hit.last_index as u32);
+
+    LearningCandidate::new(quirk.description.to_string(), insight, confidence, source).with_pattern(
+        json!({
+            "quirk": quirk.id,
+            "tool": hit.tool_name,
+            "occurrences": hit.occurrences,
+            "recovered": hit.recovered,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::TranscriptToolUse;
+
+    fn tool(name: &str, output: Option<&str>, success: bool) -> TranscriptToolUse {
+        TranscriptToolUse {
+            tool_name: name.to_string(),
+            input: json!({}),
+            output: output.map(String::from),
+            success,
+        }
+    }
+
+    fn make_transcript(tool_uses: Vec<TranscriptToolUse>) -> ParsedTranscript {
+        ParsedTranscript {
+            session_id: "test-session".to_string(),
+            messages: Vec::new(),
+            tool_uses,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_all_quirk_patterns_compile() {
+        let detector = HarnessKnowledgeDetector::new();
+        assert_eq!(detector.patterns.len(), QUIRKS.len());
+    }
+
+    #[test]
+    fn test_detects_read_before_write_with_recovery() {
+        let detector = HarnessKnowledgeDetector::new();
+        let transcript = make_transcript(vec![
+            tool(
+                "Edit",
+                Some("File has not been read yet. Read it first before writing to it."),
+                false,
+            ),
+            tool("Read", Some("fn main() {}"), true),
+            tool("Edit", Some("ok"), true),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(
+            candidate.description,
+            "Read a file before editing or overwriting it"
+        );
+        assert!((candidate.confidence - 0.75).abs() < 1e-9);
+        assert_eq!(
+            candidate.pattern.as_ref().unwrap()["quirk"],
+            "read_before_write"
+        );
+        assert_eq!(
+            candidate.source.extraction_method,
+            ExtractionMethod::Pattern(PatternType::HarnessQuirk)
+        );
+    }
+
+    #[test]
+    fn test_repeated_quirk_is_grouped() {
+        let detector = HarnessKnowledgeDetector::new();
+        let not_found = "String to replace not found in file.";
+        let transcript = make_transcript(vec![
+            tool("Edit", Some(not_found), false),
+            tool("Bash", Some("ok"), true),
+            tool("Bash", Some("ok"), true),
+            tool("Bash", Some("ok"), true),
+            tool("Edit", Some(not_found), false),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].pattern.as_ref().unwrap()["occurrences"], 2);
+        assert_eq!(candidates[0].pattern.as_ref().unwrap()["recovered"], false);
+        assert!((candidates[0].confidence - 0.7).abs() < 1e-9);
+        assert_eq!(candidates[0].source.message_range, Some((0, 4)));
+    }
+
+    #[test]
+    fn test_ignores_successful_and_unknown_errors() {
+        let detector = HarnessKnowledgeDetector::new();
+        let transcript = make_transcript(vec![
+            tool("Bash", Some("Command timed out after 120s"), true),
+            tool("Bash", Some("error[E0308]: mismatched types"), false),
+            tool("Read", None, false),
+        ]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_min_confidence_filter() {
+        let detector = HarnessKnowledgeDetector::with_config(&HarnessKnowledgeConfig {
+            min_confidence: 0.7,
+            ..Default::default()
+        });
+        let transcript = make_transcript(vec![tool(
+            "Bash",
+            Some("Command timed out after 2m 0s"),
+            false,
+        )]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+}
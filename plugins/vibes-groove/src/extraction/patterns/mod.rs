@@ -1,10 +1,19 @@
 //! Pattern detection for learning extraction
 //!
 //! This module provides pattern detectors that scan transcripts for specific
-//! patterns indicating learnable behavior (corrections, error recovery, etc.).
+//! patterns indicating learnable behavior (corrections, error recovery, standing
+//! preferences, tool habits, recurring code and harness quirks).
 
+pub mod code_pattern;
 pub mod correction;
 pub mod error_recovery;
+pub mod harness_knowledge;
+pub mod preference;
+pub mod tool_usage;
 
+pub use code_pattern::{CodePatternConfig, CodePatternDetector};
 pub use correction::{CorrectionConfig, CorrectionDetector};
 pub use error_recovery::{ErrorRecoveryConfig, ErrorRecoveryDetector, ErrorType};
+pub use harness_knowledge::{HarnessKnowledgeConfig, HarnessKnowledgeDetector};
+pub use preference::{PreferenceConfig, PreferenceDetector};
+pub use tool_usage::{ToolUsageConfig, ToolUsageDetector};
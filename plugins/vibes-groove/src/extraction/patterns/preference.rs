@@ -0,0 +1,374 @@
+//! Preference pattern detector
+//!
+//! Detects standing instructions from the user ("always use anyhow", "never
+//! push to main") to extract preference learnings. Instructions the user has to
+//! repeat during a session score higher than ones given once.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::Result;
+use crate::assessment::{EventId, SessionId};
+use crate::capture::ParsedTranscript;
+use crate::extraction::{ExtractionMethod, ExtractionSource, LearningCandidate, PatternType};
+
+/// Default instruction patterns
+const DEFAULT_PATTERNS: &[&str] = &[
+    r"(?i)\balways\b",
+    r"(?i)\bnever\b",
+    r"(?i)\bfrom now on\b",
+    r"(?i)\bmake sure (to|you)\b",
+    r"(?i)\bi (prefer|like|want you) to\b",
+    r"(?i)\b(we|i) prefer\b",
+    r"(?i)\bin this (project|repo|codebase)\b",
+];
+
+/// Patterns that make an instruction unconditional
+const STRONG_PATTERNS: &[&str] = &[r"(?i)\balways\b", r"(?i)\bnever\b", r"(?i)\bfrom now on\b"];
+
+/// Acknowledgment patterns that indicate Claude took the instruction on board
+const ACKNOWLEDGMENT_PATTERNS: &[&str] = &[
+    r"(?i)i'll (always|never|use|make sure|keep)",
+    r"(?i)got it",
+    r"(?i)understood",
+    r"(?i)will do",
+    r"(?i)from now on",
+    r"(?i)noted",
+];
+
+/// Configuration for the preference detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreferenceConfig {
+    /// Whether preference detection is enabled
+    pub enabled: bool,
+    /// Custom instruction patterns (in addition to defaults)
+    pub patterns: Vec<String>,
+    /// Minimum confidence threshold for extracted preferences
+    pub min_confidence: f64,
+}
+
+impl Default for PreferenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            patterns: Vec::new(),
+            min_confidence: 0.5,
+        }
+    }
+}
+
+/// An instruction and every message that gave it
+#[derive(Debug)]
+struct Instruction {
+    text: String,
+    first_index: usize,
+    last_index: usize,
+    mentions: u32,
+    strong: bool,
+    acknowledged: bool,
+}
+
+/// Detects standing user instructions in transcripts
+pub struct PreferenceDetector {
+    patterns: Vec<Regex>,
+    strong_patterns: Vec<Regex>,
+    acknowledgment_patterns: Vec<Regex>,
+    config: PreferenceConfig,
+}
+
+impl PreferenceDetector {
+    /// Create a new PreferenceDetector with default patterns
+    pub fn new() -> Self {
+        Self::with_config(&PreferenceConfig::default())
+    }
+
+    /// Create with custom configuration
+    pub fn with_config(config: &PreferenceConfig) -> Self {
+        let compile = |patterns: &[&str]| -> Vec<Regex> {
+            patterns.iter().filter_map(|p| Regex::new(p).ok()).collect()
+        };
+
+        let mut patterns = compile(DEFAULT_PATTERNS);
+        patterns.extend(config.patterns.iter().filter_map(|p| Regex::new(p).ok()));
+
+        Self {
+            patterns,
+            strong_patterns: compile(STRONG_PATTERNS),
+            acknowledgment_patterns: compile(ACKNOWLEDGMENT_PATTERNS),
+            config: config.clone(),
+        }
+    }
+
+    /// Detect standing instructions in a transcript
+    pub fn detect(&self, transcript: &ParsedTranscript) -> Result<Vec<LearningCandidate>> {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        // Group instructions by normalized wording, in order of first mention
+        let mut instructions: Vec<(String, Instruction)> = Vec::new();
+        let messages = &transcript.messages;
+
+        for (i, msg) in messages.iter().enumerate() {
+            if msg.role != "user" {
+                continue;
+            }
+
+            let acknowledged = messages.get(i + 1).is_some_and(|next| {
+                next.role == "assistant"
+                    && self
+                        .acknowledgment_patterns
+                        .iter()
+                        .any(|p| p.is_match(&next.content))
+            });
+
+            for sentence in sentences(&msg.content) {
+                if !self.patterns.iter().any(|p| p.is_match(sentence)) {
+                    continue;
+                }
+                let key = normalize(sentence);
+                if key.split_whitespace().count() < 3 {
+                    continue;
+                }
+
+                match instructions.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, instruction)) => {
+                        instruction.mentions += 1;
+                        instruction.last_index = i;
+                        instruction.acknowledged |= acknowledged;
+                    }
+                    None => instructions.push((
+                        key,
+                        Instruction {
+                            text: sentence.to_string(),
+                            first_index: i,
+                            last_index: i,
+                            mentions: 1,
+                            strong: self.strong_patterns.iter().any(|p| p.is_match(sentence)),
+                            acknowledged,
+                        },
+                    )),
+                }
+            }
+        }
+
+        let candidates = instructions
+            .into_iter()
+            .map(|(_, instruction)| instruction)
+            .filter_map(|instruction| {
+                let confidence = confidence(&instruction);
+                (confidence >= self.config.min_confidence)
+                    .then(|| to_learning_candidate(transcript, &instruction, confidence))
+            })
+            .collect();
+
+        Ok(candidates)
+    }
+}
+
+impl Default for PreferenceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score an instruction
+///
+/// Unconditional wording, an acknowledgment, and having to repeat the
+/// instruction all make it more likely to be a lasting preference.
+fn confidence(instruction: &Instruction) -> f64 {
+    let strong_bonus = if instruction.strong { 0.1 } else { 0.0 };
+    let ack_bonus = if instruction.acknowledged { 0.1 } else { 0.0 };
+    let repeat_bonus = 0.15 * instruction.mentions.saturating_sub(1) as f64;
+    (0.55 + strong_bonus + ack_bonus + repeat_bonus).min(0.95)
+}
+
+fn to_learning_candidate(
+    transcript: &ParsedTranscript,
+    instruction: &Instruction,
+    confidence: f64,
+) -> LearningCandidate {
+    let description = capitalize(&truncate(&instruction.text, 100));
+    let insight = if instruction.mentions > 1 {
+        format!("User repeatedly asks: {}", description)
+    } else {
+        format!("User asks: {}", description)
+    };
+
+    let source = ExtractionSource::new(
+        SessionId::from(transcript.session_id.as_str()),
+        EventId::new(),
+        ExtractionMethod::Pattern(PatternType::Preference),
+    )
+    .with_message_range(
+        instruction.first_index as u32,
+        instruction.last_index as u32,
+    );
+
+    LearningCandidate::new(description, insight, confidence, source).with_pattern(json!({
+        "instruction": instruction.text,
+        "mentions": instruction.mentions,
+    }))
+}
+
+/// Split a message into trimmed sentences
+fn sentences(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .split(['.', '!', '?', '\n'])
+        .map(|s| s.trim().trim_start_matches(['-', '*']).trim())
+        .filter(|s| !s.is_empty())
+}
+
+/// Lowercase, drop punctuation and politeness so rewordings group together
+fn normalize(sentence: &str) -> String {
+    let lower: String = sentence
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    lower
+        .split_whitespace()
+        .filter(|w| !matches!(*w, "please" | "and" | "also" | "again"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let cut: String = s.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{cut}...")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::TranscriptMessage;
+
+    fn make_transcript(messages: Vec<(&str, &str)>) -> ParsedTranscript {
+        ParsedTranscript {
+            session_id: "test-session".to_string(),
+            messages: messages
+                .into_iter()
+                .map(|(role, content)| TranscriptMessage {
+                    role: role.to_string(),
+                    content: content.to_string(),
+                    timestamp: None,
+                })
+                .collect(),
+            tool_uses: Vec::new(),
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_detects_always_instruction() {
+        let detector = PreferenceDetector::new();
+        let transcript = make_transcript(vec![
+            (
+                "user",
+                "Add error handling to the loader. Always use anyhow for errors.",
+            ),
+            ("assistant", "Got it, I'll use anyhow."),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].description, "Always use anyhow for errors");
+        assert!((candidates[0].confidence - 0.75).abs() < 1e-9);
+        assert_eq!(
+            candidates[0].source.extraction_method,
+            ExtractionMethod::Pattern(PatternType::Preference)
+        );
+    }
+
+    #[test]
+    fn test_repeated_instruction_scores_higher() {
+        let detector = PreferenceDetector::new();
+        let once = make_transcript(vec![("user", "Never use unwrap in library code")]);
+        let repeated = make_transcript(vec![
+            ("user", "Never use unwrap in library code"),
+            ("assistant", "Here's the parser."),
+            ("user", "Please, never use unwrap in library code!"),
+        ]);
+
+        let once = detector.detect(&once).unwrap();
+        let repeated = detector.detect(&repeated).unwrap();
+
+        assert_eq!(repeated.len(), 1);
+        assert!(repeated[0].confidence > once[0].confidence);
+        assert_eq!(repeated[0].source.message_range, Some((0, 2)));
+        assert_eq!(repeated[0].pattern.as_ref().unwrap()["mentions"], 2);
+        assert!(repeated[0].insight.starts_with("User repeatedly asks"));
+    }
+
+    #[test]
+    fn test_ignores_assistant_and_plain_requests() {
+        let detector = PreferenceDetector::new();
+        let transcript = make_transcript(vec![
+            ("user", "Can you add a --verbose flag?"),
+            ("assistant", "I always add tests for new flags."),
+        ]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_skips_fragments() {
+        let detector = PreferenceDetector::new();
+        let transcript = make_transcript(vec![("user", "Never mind.")]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_min_confidence_filter() {
+        let detector = PreferenceDetector::with_config(&PreferenceConfig {
+            min_confidence: 0.7,
+            ..Default::default()
+        });
+        let transcript = make_transcript(vec![("user", "I prefer to keep functions short")]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let detector = PreferenceDetector::with_config(&PreferenceConfig {
+            patterns: vec![r"(?i)\bstick to\b".to_string()],
+            ..Default::default()
+        });
+        let transcript = make_transcript(vec![("user", "Stick to the standard library here")]);
+
+        assert_eq!(detector.detect(&transcript).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let detector = PreferenceDetector::with_config(&PreferenceConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        let transcript = make_transcript(vec![("user", "Always run clippy before pushing")]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,388 @@
+//! Tool usage pattern detector
+//!
+//! Detects tool-sequence habits in shell tool calls - a command that is
+//! consistently run before another, such as `cargo fmt` before `git commit` -
+//! to extract tool usage learnings.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::Result;
+use crate::assessment::{EventId, SessionId};
+use crate::capture::{ParsedTranscript, TranscriptToolUse};
+use crate::extraction::{ExtractionMethod, ExtractionSource, LearningCandidate, PatternType};
+
+/// Tool names that run shell commands, across harnesses
+const SHELL_TOOLS: &[&str] = &["Bash", "shell", "local_shell", "run_shell_command"];
+
+/// Commands too generic to form a meaningful habit
+const IGNORED_COMMANDS: &[&str] = &[
+    "cd", "ls", "cat", "echo", "pwd", "head", "tail", "grep", "rg", "find", "sed", "awk", "wc",
+    "which", "clear", "sleep", "true",
+];
+
+/// Configuration for the tool usage detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolUsageConfig {
+    /// Whether tool usage detection is enabled
+    pub enabled: bool,
+    /// Minimum confidence threshold
+    pub min_confidence: f64,
+    /// Minimum times a sequence must occur
+    pub min_occurrences: u32,
+    /// How many preceding commands count as "before"
+    pub window: usize,
+}
+
+impl Default for ToolUsageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_confidence: 0.5,
+            min_occurrences: 2,
+            window: 3,
+        }
+    }
+}
+
+/// One command run in a shell tool call
+#[derive(Debug, Clone)]
+struct Step {
+    tool_index: usize,
+    command: String,
+}
+
+/// How often `before` preceded `after`
+#[derive(Debug)]
+struct Sequence {
+    before: String,
+    after: String,
+    occurrences: u32,
+    first_index: usize,
+    last_index: usize,
+}
+
+/// Detects recurring command sequences in transcripts
+pub struct ToolUsageDetector {
+    config: ToolUsageConfig,
+}
+
+impl ToolUsageDetector {
+    /// Create a new detector with default configuration
+    pub fn new() -> Self {
+        Self::with_config(&ToolUsageConfig::default())
+    }
+
+    /// Create with custom configuration
+    pub fn with_config(config: &ToolUsageConfig) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /// Detect command sequence habits in a transcript
+    pub fn detect(&self, transcript: &ParsedTranscript) -> Result<Vec<LearningCandidate>> {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let steps = steps(&transcript.tool_uses);
+        let mut runs: HashMap<&str, u32> = HashMap::new();
+        let mut sequences: Vec<Sequence> = Vec::new();
+
+        for (j, step) in steps.iter().enumerate() {
+            *runs.entry(step.command.as_str()).or_default() += 1;
+
+            // Count each distinct predecessor once per run of this command
+            let mut seen: Vec<&str> = Vec::new();
+            for earlier in &steps[j.saturating_sub(self.config.window)..j] {
+                if earlier.command == step.command || seen.contains(&earlier.command.as_str()) {
+                    continue;
+                }
+                seen.push(&earlier.command);
+
+                match sequences
+                    .iter_mut()
+                    .find(|s| s.before == earlier.command && s.after == step.command)
+                {
+                    Some(sequence) => {
+                        sequence.occurrences += 1;
+                        sequence.last_index = step.tool_index;
+                    }
+                    None => sequences.push(Sequence {
+                        before: earlier.command.clone(),
+                        after: step.command.clone(),
+                        occurrences: 1,
+                        first_index: earlier.tool_index,
+                        last_index: step.tool_index,
+                    }),
+                }
+            }
+        }
+
+        let candidates = sequences
+            .iter()
+            .filter(|s| s.occurrences >= self.config.min_occurrences)
+            .filter_map(|sequence| {
+                let total = runs.get(sequence.after.as_str()).copied().unwrap_or(1);
+                let confidence = confidence(sequence.occurrences, total);
+                (confidence >= self.config.min_confidence)
+                    .then(|| to_learning_candidate(transcript, sequence, total, confidence))
+            })
+            .collect();
+
+        Ok(candidates)
+    }
+}
+
+impl Default for ToolUsageDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score a sequence
+///
+/// More occurrences help, but consistency matters more: `fmt` before 2 of 2
+/// commits is a habit, before 2 of 10 is coincidence.
+fn confidence(occurrences: u32, total: u32) -> f64 {
+    let consistency = f64::from(occurrences) / f64::from(total.max(occurrences));
+    let frequency = (0.4 + 0.1 * f64::from(occurrences - 1)).min(0.65);
+    (frequency + 0.3 * consistency).min(0.95)
+}
+
+fn to_learning_candidate(
+    transcript: &ParsedTranscript,
+    sequence: &Sequence,
+    total: u32,
+    confidence: f64,
+) -> LearningCandidate {
+    let description = format!("Run `{}` before `{}`", sequence.before, sequence.after);
+    let how_often = if sequence.occurrences == total {
+        "every"
+    } else {
+        "most"
+    };
+    let insight = format!(
+        "This workflow runs `{}` before {} `{}`",
+        sequence.before, how_often, sequence.after
+    );
+
+    let source = ExtractionSource::new(
+        SessionId::from(transcript.session_id.as_str()),
+        EventId::new(),
+        ExtractionMethod::Pattern(PatternType::ToolSequence),
+    )
+    .with_message_range(sequence.first_index as u32, sequence.last_index as u32);
+
+    LearningCandidate::new(description, insight, confidence, source).with_pattern(json!({
+        "before": sequence.before,
+        "after": sequence.after,
+        "occurrences": sequence.occurrences,
+        "total": total,
+    }))
+}
+
+/// Flatten successful shell calls into individual commands
+fn steps(tools: &[TranscriptToolUse]) -> Vec<Step> {
+    tools
+        .iter()
+        .enumerate()
+        .filter(|(_, tool)| tool.success && SHELL_TOOLS.contains(&tool.tool_name.as_str()))
+        .filter_map(|(i, tool)| shell_command(&tool.input).map(|cmd| (i, cmd)))
+        .flat_map(|(tool_index, cmd)| {
+            cmd.split(['&', ';', '|', '\n'])
+                .filter_map(command_name)
+                .map(|command| Step {
+                    tool_index,
+                    command,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The command line, whether given as a string or an argv array
+///
+/// Codex usually wraps commands as `["bash", "-lc", "<script>"]`.
+fn shell_command(input: &Value) -> Option<String> {
+    match input.get("command")? {
+        Value::String(cmd) => Some(cmd.clone()),
+        Value::Array(argv) => {
+            let argv: Vec<&str> = argv.iter().filter_map(Value::as_str).collect();
+            match argv.as_slice() {
+                [_, "-c" | "-lc", script] => Some(script.to_string()),
+                _ => Some(argv.join(" ")),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Reduce a command to its program and subcommand (`cargo fmt`, `git commit`)
+fn command_name(segment: &str) -> Option<String> {
+    let mut words = segment
+        .split_whitespace()
+        .skip_while(|w| w.contains('=') && !w.starts_with('-'));
+    let program = words.next()?.rsplit('/').next()?;
+    if IGNORED_COMMANDS.contains(&program) || !program.starts_with(|c: char| c.is_alphabetic()) {
+        return None;
+    }
+
+    let subcommand = words.next().filter(|w| {
+        w.starts_with(|c: char| c.is_ascii_lowercase())
+            && w.chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    });
+
+    Some(match subcommand {
+        Some(sub) => format!("{program} {sub}"),
+        None => program.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bash(command: &str) -> TranscriptToolUse {
+        TranscriptToolUse {
+            tool_name: "Bash".to_string(),
+            input: json!({ "command": command }),
+            output: None,
+            success: true,
+        }
+    }
+
+    fn make_transcript(tool_uses: Vec<TranscriptToolUse>) -> ParsedTranscript {
+        ParsedTranscript {
+            session_id: "test-session".to_string(),
+            messages: Vec::new(),
+            tool_uses,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_detects_fmt_before_commit() {
+        let detector = ToolUsageDetector::new();
+        let transcript = make_transcript(vec![
+            bash("cargo fmt"),
+            bash("git commit -m 'first'"),
+            bash("cargo test"),
+            bash("cargo fmt --all"),
+            bash("git commit -am second"),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        let fmt = candidates
+            .iter()
+            .find(|c| c.description == "Run `cargo fmt` before `git commit`")
+            .expect("fmt before commit");
+        assert!((fmt.confidence - 0.8).abs() < 1e-9);
+        assert_eq!(fmt.source.message_range, Some((0, 4)));
+        assert_eq!(
+            fmt.source.extraction_method,
+            ExtractionMethod::Pattern(PatternType::ToolSequence)
+        );
+        assert!(fmt.insight.contains("before every `git commit`"));
+    }
+
+    #[test]
+    fn test_chained_commands_count() {
+        let detector = ToolUsageDetector::new();
+        let transcript = make_transcript(vec![
+            bash("cargo clippy && git push"),
+            bash("cd repo; cargo clippy -- -D warnings && git push origin main"),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(
+            candidates[0].description,
+            "Run `cargo clippy` before `git push`"
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_sequence_scores_lower() {
+        let consistent = confidence(2, 2);
+        let inconsistent = confidence(2, 8);
+        assert!(consistent > inconsistent);
+    }
+
+    #[test]
+    fn test_single_occurrence_is_not_a_habit() {
+        let detector = ToolUsageDetector::new();
+        let transcript = make_transcript(vec![bash("npm run lint"), bash("npm test")]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ignores_failed_and_non_shell_tools() {
+        let detector = ToolUsageDetector::new();
+        let mut failed = bash("make");
+        failed.success = false;
+        let read = TranscriptToolUse {
+            tool_name: "Read".to_string(),
+            input: json!({ "command": "make" }),
+            output: None,
+            success: true,
+        };
+        let transcript = make_transcript(vec![
+            failed.clone(),
+            bash("make install"),
+            read,
+            bash("make install"),
+        ]);
+
+        assert!(detector.detect(&transcript).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reads_codex_argv_commands() {
+        let detector = ToolUsageDetector::new();
+        let shell = |script: &str| TranscriptToolUse {
+            tool_name: "shell".to_string(),
+            input: json!({ "command": ["bash", "-lc", script] }),
+            output: None,
+            success: true,
+        };
+        let transcript = make_transcript(vec![
+            shell("go vet ./..."),
+            shell("go test ./..."),
+            shell("go vet ./..."),
+            shell("go test ./..."),
+        ]);
+
+        let candidates = detector.detect(&transcript).unwrap();
+        assert!(
+            candidates
+                .iter()
+                .any(|c| c.description == "Run `go vet` before `go test`")
+        );
+    }
+
+    #[test]
+    fn test_command_name() {
+        assert_eq!(
+            command_name(" git commit -m x"),
+            Some("git commit".to_string())
+        );
+        assert_eq!(
+            command_name("RUST_LOG=debug cargo test"),
+            Some("cargo test".to_string())
+        );
+        assert_eq!(
+            command_name("/usr/bin/python script.py"),
+            Some("python".to_string())
+        );
+        assert_eq!(command_name("ls -la"), None);
+        assert_eq!(command_name("  "), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! Embeddings from a vibes-models provider
+//!
+//! [`ProviderEmbedder`] adapts any [`ModelProvider`] that implements
+//! `embed` to the [`Embedder`] trait, so groove can use a hosted or local
+//! embedding model instead of the bundled gte-small. [`EmbeddingConfig`]
+//! selects which one is used; changing it triggers a re-embedding migration
+//! (see [`crate::reembed`]).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use vibes_models::providers::{EmbedRequest, ModelProvider, OllamaProvider};
+
+use super::embedder::{
+    Embedder, EmbedderError, EmbedderResult, GTE_SMALL_DIMENSIONS, GTE_SMALL_MODEL_ID,
+    LocalEmbedder,
+};
+
+/// Which model embeds learnings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbeddingConfig {
+    /// "local" for the bundled gte-small model, otherwise a provider name
+    pub provider: String,
+    /// Model name passed to the provider
+    pub model: String,
+    /// Dimensions of the vectors the model returns
+    pub dimensions: usize,
+    /// Provider endpoint, if not the provider's default
+    pub base_url: Option<String>,
+    /// Re-embed the store in the background when the model changes
+    pub reembed: bool,
+    /// Learnings embedded per batch during re-embedding
+    pub batch_size: usize,
+}
+
+impl Default for EmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            provider: "local".to_string(),
+            model: GTE_SMALL_MODEL_ID.to_string(),
+            dimensions: GTE_SMALL_DIMENSIONS,
+            base_url: None,
+            reembed: true,
+            batch_size: 32,
+        }
+    }
+}
+
+impl EmbeddingConfig {
+    /// Identity recorded with vectors from the configured model
+    pub fn model_id(&self) -> String {
+        if self.provider == "local" {
+            self.model.clone()
+        } else {
+            format!("{}:{}", self.provider, self.model)
+        }
+    }
+
+    /// Whether the configured model can be opened without a download
+    pub fn available(&self) -> bool {
+        self.provider != "local" || LocalEmbedder::default_model_exists()
+    }
+
+    /// Build the configured embedder
+    ///
+    /// The local model must already be downloaded; providers are only
+    /// contacted when the first text is embedded.
+    pub fn open(&self) -> EmbedderResult<Arc<dyn Embedder>> {
+        let provider: Arc<dyn ModelProvider> = match self.provider.as_str() {
+            "local" => return Ok(Arc::new(LocalEmbedder::new()?)),
+            "ollama" => Arc::new(match &self.base_url {
+                Some(url) => OllamaProvider::with_base_url(url),
+                None => OllamaProvider::new(),
+            }),
+            other => {
+                return Err(EmbedderError::ModelLoadError(format!(
+                    "Unknown embedding provider: {other}"
+                )));
+            }
+        };
+        Ok(Arc::new(ProviderEmbedder::new(
+            provider,
+            self.model.clone(),
+            self.dimensions,
+        )))
+    }
+}
+
+/// Embedder backed by [`ModelProvider::embed`]
+pub struct ProviderEmbedder {
+    provider: Arc<dyn ModelProvider>,
+    model: String,
+    model_id: String,
+    dimensions: usize,
+}
+
+impl ProviderEmbedder {
+    pub fn new(provider: Arc<dyn ModelProvider>, model: String, dimensions: usize) -> Self {
+        let model_id = format!("{}:{}", provider.name(), model);
+        Self {
+            provider,
+            model,
+            model_id,
+            dimensions,
+        }
+    }
+}
+
+#[async_trait]
+impl Embedder for ProviderEmbedder {
+    async fn embed(&self, text: &str) -> EmbedderResult<Vec<f32>> {
+        self.embed_batch(&[text])
+            .await?
+            .pop()
+            .ok_or_else(|| EmbedderError::InferenceError("Provider returned no embedding".into()))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> EmbedderResult<Vec<Vec<f32>>> {
+        let request = EmbedRequest::new(
+            self.model.clone(),
+            texts.iter().map(|t| t.to_string()).collect(),
+        );
+        let response = self
+            .provider
+            .embed(request)
+            .await
+            .map_err(|e| EmbedderError::InferenceError(e.to_string()))?;
+
+        if response.embeddings.len() != texts.len() {
+            return Err(EmbedderError::InferenceError(format!(
+                "Provider returned {} embeddings for {} texts",
+                response.embeddings.len(),
+                texts.len()
+            )));
+        }
+        if let Some(bad) = response
+            .embeddings
+            .iter()
+            .find(|e| e.len() != self.dimensions)
+        {
+            return Err(EmbedderError::InferenceError(format!(
+                "Expected {} dimensions from {}, got {}",
+                self.dimensions,
+                self.model_id,
+                bad.len()
+            )));
+        }
+        Ok(response.embeddings)
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+}
+
+impl std::fmt::Debug for ProviderEmbedder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderEmbedder")
+            .field("model_id", &self.model_id)
+            .field("dimensions", &self.dimensions)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vibes_models::providers::{ChatRequest, ChatResponse, ChatStream, EmbedResponse, Usage};
+
+    /// Returns `dims`-long vectors whose first value is the text length
+    struct LengthProvider {
+        dims: usize,
+    }
+
+    #[async_trait]
+    impl ModelProvider for LengthProvider {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn models(&self) -> Vec<vibes_models::ModelInfo> {
+            Vec::new()
+        }
+
+        async fn chat(&self, _request: ChatRequest) -> vibes_models::Result<ChatResponse> {
+            Err(vibes_models::Error::ProviderApi(
+                "chat not supported".into(),
+            ))
+        }
+
+        async fn chat_stream(&self, _request: ChatRequest) -> vibes_models::Result<ChatStream> {
+            Err(vibes_models::Error::ProviderApi(
+                "chat not supported".into(),
+            ))
+        }
+
+        async fn embed(&self, request: EmbedRequest) -> vibes_models::Result<EmbedResponse> {
+            Ok(EmbedResponse {
+                embeddings: request
+                    .texts
+                    .iter()
+                    .map(|t| {
+                        let mut v = vec![0.0; self.dims];
+                        v[0] = t.len() as f32;
+                        v
+                    })
+                    .collect(),
+                usage: Usage::new(0, 0),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn provider_embedder_batches_and_reports_identity() {
+        let embedder =
+            ProviderEmbedder::new(Arc::new(LengthProvider { dims: 8 }), "tiny".into(), 8);
+
+        let vectors = embedder.embed_batch(&["a", "abc"]).await.unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors[1][0], 3.0);
+        assert_eq!(embedder.embed("ab").await.unwrap()[0], 2.0);
+        assert_eq!(embedder.model_id(), "mock:tiny");
+        assert_eq!(embedder.dimensions(), 8);
+    }
+
+    #[tokio::test]
+    async fn provider_embedder_rejects_wrong_dimensions() {
+        let embedder =
+            ProviderEmbedder::new(Arc::new(LengthProvider { dims: 4 }), "tiny".into(), 8);
+        assert!(embedder.embed("text").await.is_err());
+    }
+
+    #[test]
+    fn embedding_config_model_id() {
+        let config = EmbeddingConfig::default();
+        assert_eq!(config.model_id(), GTE_SMALL_MODEL_ID);
+
+        let config = EmbeddingConfig {
+            provider: "ollama".into(),
+            model: "nomic-embed-text".into(),
+            ..Default::default()
+        };
+        assert_eq!(config.model_id(), "ollama:nomic-embed-text");
+        assert!(
+            EmbeddingConfig {
+                provider: "nope".into(),
+                ..Default::default()
+            }
+            .open()
+            .is_err()
+        );
+    }
+}
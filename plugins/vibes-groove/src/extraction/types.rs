@@ -25,6 +25,14 @@ pub enum PatternType {
     Correction,
     /// Error recovery pattern (failure → fix → success)
     ErrorRecovery,
+    /// Standing user instruction ("Always use anyhow")
+    Preference,
+    /// Command habit (`cargo fmt` before `git commit`)
+    ToolSequence,
+    /// Code shape recurring across edited files
+    CodeShape,
+    /// Harness rule revealed by a tool error
+    HarnessQuirk,
 }
 
 /// Source information for extracted learning
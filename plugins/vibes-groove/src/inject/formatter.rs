@@ -3,6 +3,7 @@
 //! Formats extracted learnings as markdown for injection into
 //! CLAUDE.md files or hook responses.
 
+use crate::Learning;
 use crate::capture::{ExtractedLearning, LearningCategory};
 
 /// Markdown header prefixes for h1-h6
@@ -132,6 +133,54 @@ impl LearningFormatter {
             LearningCategory::Preference => "Project Preferences",
         }
     }
+
+    /// Format one stored learning as a list item
+    pub fn format_learning(&self, learning: &Learning) -> String {
+        format!(
+            "- {}: {}\n",
+            learning.content.description, learning.content.insight
+        )
+    }
+
+    /// Format stored learnings grouped by category, keeping their order
+    ///
+    /// Categories appear in the order of their first learning, so the most
+    /// relevant learnings stay at the top.
+    pub fn format_learnings(&self, learnings: &[Learning]) -> String {
+        let mut categories: Vec<&crate::LearningCategory> = Vec::new();
+        for learning in learnings {
+            if !categories.contains(&&learning.category) {
+                categories.push(&learning.category);
+            }
+        }
+
+        let header = HEADERS[(self.header_level as usize).saturating_sub(1).min(5)];
+        categories
+            .into_iter()
+            .map(|category| {
+                let mut content =
+                    format!("{} {}\n", header, Self::learning_category_title(category));
+                for learning in learnings.iter().filter(|l| &l.category == category) {
+                    content.push_str(&self.format_learning(learning));
+                }
+                content
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Get the section title for a stored learning category
+    pub fn learning_category_title(category: &crate::LearningCategory) -> &'static str {
+        match category {
+            crate::LearningCategory::Correction => "Corrections",
+            crate::LearningCategory::CodePattern => "Code Patterns",
+            crate::LearningCategory::Preference => "Preferences",
+            crate::LearningCategory::Solution => "Solutions",
+            crate::LearningCategory::ErrorRecovery => "Error Recovery",
+            crate::LearningCategory::ToolUsage => "Tool Usage",
+            crate::LearningCategory::HarnessKnowledge => "Harness Knowledge",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -273,4 +322,32 @@ mod tests {
             "Project Preferences"
         );
     }
+
+    #[test]
+    fn test_format_learnings_groups_in_relevance_order() {
+        let stored = |description: &str, category: crate::LearningCategory| {
+            Learning::new(
+                crate::Scope::Global,
+                category,
+                crate::LearningContent {
+                    description: description.into(),
+                    pattern: None,
+                    insight: "why".into(),
+                },
+                crate::LearningSource::UserCreated,
+            )
+        };
+        let learnings = vec![
+            stored("Run clippy", crate::LearningCategory::ToolUsage),
+            stored("Prefer iterators", crate::LearningCategory::Preference),
+            stored("Run fmt", crate::LearningCategory::ToolUsage),
+        ];
+
+        let result = LearningFormatter::new().format_learnings(&learnings);
+
+        assert_eq!(
+            result,
+            "## Tool Usage\n- Run clippy: why\n- Run fmt: why\n\n## Preferences\n- Prefer iterators: why\n"
+        );
+    }
 }
@@ -82,9 +82,7 @@ impl ClaudeCodeInjector {
         if formatted.is_empty() {
             HookResponse::default()
         } else {
-            HookResponse {
-                additional_context: Some(formatted.to_string()),
-            }
+            HookResponse::with_context(formatted)
         }
     }
 
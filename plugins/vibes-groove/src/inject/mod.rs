@@ -1,10 +1,15 @@
 //! Injection pipeline for learnings
 //!
-//! This module handles formatting learnings and injecting them
-//! into Claude Code sessions via CLAUDE.md or hook responses.
+//! This module handles retrieving and formatting learnings and injecting
+//! them into Claude Code sessions via CLAUDE.md or hook responses.
 
 mod formatter;
 mod injector;
+mod retrieval;
 
 pub use formatter::{FormattedSection, LearningFormatter};
 pub use injector::{ClaudeCodeInjector, InjectionMethod, InjectionResult};
+pub use retrieval::{
+    DecisionOutcome, InjectionDecision, PromptRetriever, RetrievalConfig, RetrievalContext,
+    RetrievalOutcome, checkout_scope, checkout_scope_chain, estimate_tokens, languages_for_files,
+};
@@ -0,0 +1,572 @@
+//! Prompt-aware retrieval of learnings for injection
+//!
+//! At `UserPromptSubmit` the [`PromptRetriever`] embeds the prompt together
+//! with the working directory and recently touched files, pulls semantically
+//! similar candidates through the session's [`ScopeChain`], re-ranks them by
+//! similarity, confidence and attribution value, and packs the best into a
+//! token budget with per-category caps.
+//!
+//! Every candidate gets an [`InjectionDecision`] recording its scores and
+//! why it was or wasn't injected; decisions are logged and returned.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::attribution::{AttributionStore, LearningStatus};
+use crate::extraction::Embedder;
+use crate::inject::LearningFormatter;
+use crate::store::LearningStore;
+use crate::{GrooveError, Learning, LearningId, Result, Scope, ScopeChain};
+
+/// Configuration for prompt-aware retrieval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetrievalConfig {
+    /// Whether learnings are injected at `UserPromptSubmit`
+    pub enabled: bool,
+    /// How many semantic candidates to consider before re-ranking
+    pub candidate_limit: usize,
+    /// Approximate token budget for the injected context
+    pub token_budget: usize,
+    /// Candidates below this similarity (0-1) are never injected
+    pub min_similarity: f64,
+    /// Most learnings injected from one category
+    pub max_per_category: usize,
+    /// Per-category overrides of `max_per_category`, keyed by category
+    /// (e.g. `harness_knowledge = 1`)
+    pub category_caps: HashMap<String, usize>,
+    /// Weight of prompt similarity in the ranking score
+    pub similarity_weight: f64,
+    /// Weight of learning confidence in the ranking score
+    pub confidence_weight: f64,
+    /// Weight of attribution value in the ranking score
+    pub value_weight: f64,
+}
+
+impl Default for RetrievalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            candidate_limit: 30,
+            token_budget: 800,
+            min_similarity: 0.85,
+            max_per_category: 3,
+            category_caps: HashMap::new(),
+            similarity_weight: 0.5,
+            confidence_weight: 0.3,
+            value_weight: 0.2,
+        }
+    }
+}
+
+impl RetrievalConfig {
+    /// Cap for a category
+    pub fn category_cap(&self, category: &crate::LearningCategory) -> usize {
+        self.category_caps
+            .get(category.as_str())
+            .copied()
+            .unwrap_or(self.max_per_category)
+    }
+}
+
+/// What the user is doing when a prompt is submitted
+#[derive(Debug, Clone, Default)]
+pub struct RetrievalContext {
+    pub prompt: String,
+    pub cwd: Option<String>,
+    /// Files touched recently in the session, most recent last
+    pub recent_files: Vec<String>,
+}
+
+impl RetrievalContext {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_cwd(mut self, cwd: impl Into<String>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    pub fn with_recent_files(mut self, files: Vec<String>) -> Self {
+        self.recent_files = files;
+        self
+    }
+
+    /// Text embedded to find relevant learnings
+    pub fn query_text(&self) -> String {
+        let mut text = self.prompt.clone();
+        if let Some(cwd) = &self.cwd {
+            text.push_str(&format!("\nWorking directory: {cwd}"));
+        }
+        if !self.recent_files.is_empty() {
+            text.push_str(&format!("\nRecent files: {}", self.recent_files.join(", ")));
+        }
+        text
+    }
+}
+
+/// Why a candidate was or wasn't injected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionOutcome {
+    Injected,
+    /// Similarity to the prompt below `min_similarity`
+    NotRelevant,
+    /// Disabled or deprecated by attribution
+    Disabled,
+    /// Its category was already full
+    CategoryCap,
+    /// Would not fit in the remaining token budget
+    OverBudget,
+}
+
+/// Scores and outcome for one candidate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InjectionDecision {
+    pub learning_id: LearningId,
+    pub category: String,
+    pub scope: String,
+    pub similarity: f64,
+    pub confidence: f64,
+    /// Attribution value normalized to 0-1, 0.5 when unknown
+    pub value: f64,
+    pub score: f64,
+    pub tokens: usize,
+    pub outcome: DecisionOutcome,
+}
+
+/// Result of retrieving learnings for a prompt
+#[derive(Debug, Clone, Default)]
+pub struct RetrievalOutcome {
+    /// Learnings to inject, best first
+    pub learnings: Vec<Learning>,
+    /// One decision per candidate, in ranking order
+    pub decisions: Vec<InjectionDecision>,
+    pub tokens_used: usize,
+}
+
+/// Rough token count for budgeting, about four characters per token
+pub fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+/// Retrieves learnings relevant to a prompt
+pub struct PromptRetriever {
+    store: Arc<dyn LearningStore>,
+    embedder: Arc<dyn Embedder>,
+    attribution: Option<Arc<dyn AttributionStore>>,
+    config: RetrievalConfig,
+}
+
+impl PromptRetriever {
+    pub fn new(
+        store: Arc<dyn LearningStore>,
+        embedder: Arc<dyn Embedder>,
+        config: RetrievalConfig,
+    ) -> Self {
+        Self {
+            store,
+            embedder,
+            attribution: None,
+            config,
+        }
+    }
+
+    /// Rank by attribution value as well
+    pub fn with_attribution(mut self, attribution: Arc<dyn AttributionStore>) -> Self {
+        self.attribution = Some(attribution);
+        self
+    }
+
+    /// Retrieve and pack learnings for a prompt
+    pub async fn retrieve(
+        &self,
+        scopes: &ScopeChain,
+        context: &RetrievalContext,
+    ) -> Result<RetrievalOutcome> {
+        if !self.config.enabled || context.prompt.trim().is_empty() {
+            return Ok(RetrievalOutcome::default());
+        }
+
+        let embedding = self
+            .embedder
+            .embed(&context.query_text())
+            .await
+            .map_err(|e| GrooveError::Embedding(e.to_string()))?;
+
+        // Similarities for the candidates; find_for_injection resolves scopes
+        // and overrides but doesn't return scores
+        let limit = self.config.candidate_limit;
+        let similarities: HashMap<LearningId, f64> = self
+            .store
+            .find_similar(&embedding, 0.0, limit * 3)
+            .await?
+            .into_iter()
+            .map(|(l, similarity)| (l.id, similarity))
+            .collect();
+        let candidates = self
+            .store
+            .find_for_injection(scopes, Some(&embedding), limit)
+            .await?;
+
+        let mut scored = Vec::with_capacity(candidates.len());
+        for learning in candidates {
+            let similarity = similarities.get(&learning.id).copied().unwrap_or(0.0);
+            let (value, disabled) = self.value_of(learning.id).await;
+            let score = self.config.similarity_weight * similarity
+                + self.config.confidence_weight * learning.confidence
+                + self.config.value_weight * value;
+            scored.push((learning, similarity, value, score, disabled));
+        }
+        // Stable, so equal scores keep scope order
+        scored.sort_by(|a, b| b.3.total_cmp(&a.3));
+
+        let formatter = LearningFormatter::new();
+        let mut outcome = RetrievalOutcome::default();
+        let mut per_category: HashMap<&'static str, usize> = HashMap::new();
+
+        for (learning, similarity, value, score, disabled) in scored {
+            let tokens = estimate_tokens(&formatter.format_learning(&learning));
+            let category = learning.category.as_str();
+            let taken = per_category.get(category).copied().unwrap_or(0);
+
+            let decision = if disabled {
+                DecisionOutcome::Disabled
+            } else if similarity < self.config.min_similarity {
+                DecisionOutcome::NotRelevant
+            } else if taken >= self.config.category_cap(&learning.category) {
+                DecisionOutcome::CategoryCap
+            } else if outcome.tokens_used + tokens > self.config.token_budget {
+                DecisionOutcome::OverBudget
+            } else {
+                DecisionOutcome::Injected
+            };
+
+            let decision = InjectionDecision {
+                learning_id: learning.id,
+                category: category.to_string(),
+                scope: learning.scope.to_db_string(),
+                similarity,
+                confidence: learning.confidence,
+                value,
+                score,
+                tokens,
+                outcome: decision,
+            };
+            info!(
+                learning_id = %decision.learning_id,
+                category = %decision.category,
+                scope = %decision.scope,
+                similarity = decision.similarity,
+                confidence = decision.confidence,
+                value = decision.value,
+                score = decision.score,
+                tokens = decision.tokens,
+                outcome = ?decision.outcome,
+                "Injection decision"
+            );
+
+            if decision.outcome == DecisionOutcome::Injected {
+                outcome.tokens_used += tokens;
+                *per_category.entry(category).or_default() += 1;
+                outcome.learnings.push(learning);
+            }
+            outcome.decisions.push(decision);
+        }
+
+        Ok(outcome)
+    }
+
+    /// Attribution value normalized to 0-1, and whether injection is disabled
+    async fn value_of(&self, id: LearningId) -> (f64, bool) {
+        let Some(attribution) = &self.attribution else {
+            return (0.5, false);
+        };
+        match attribution.get_learning_value(id).await {
+            Ok(Some(value)) => {
+                let disabled = matches!(
+                    value.status,
+                    LearningStatus::Disabled | LearningStatus::Deprecated { .. }
+                );
+                (
+                    ((value.estimated_value + 1.0) / 2.0).clamp(0.0, 1.0),
+                    disabled,
+                )
+            }
+            Ok(None) => (0.5, false),
+            Err(e) => {
+                debug!(learning_id = %id, "No attribution value: {}", e);
+                (0.5, false)
+            }
+        }
+    }
+}
+
+/// Language tags for files by extension, in first-seen order
+pub fn languages_for_files(files: &[String]) -> Vec<&'static str> {
+    let mut languages = Vec::new();
+    for file in files {
+        let ext = Path::new(file)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let language = match ext {
+            "rs" => "rust",
+            "ts" | "tsx" => "typescript",
+            "js" | "jsx" | "mjs" | "cjs" => "javascript",
+            "py" => "python",
+            "go" => "go",
+            "java" => "java",
+            "kt" | "kts" => "kotlin",
+            "rb" => "ruby",
+            "swift" => "swift",
+            "c" | "h" => "c",
+            "cc" | "cpp" | "hpp" => "cpp",
+            "cs" => "csharp",
+            "nix" => "nix",
+            _ => continue,
+        };
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+    languages
+}
+
+/// Scope chain for a checkout: the user, the project path, and its git
+/// repository and branch if any
+///
+/// Runs git, so callers cache the chain and add language scopes for the
+/// files in play with [`languages_for_files`].
+pub fn checkout_scope_chain(user: &str, cwd: &Path) -> ScopeChain {
+    let mut chain = ScopeChain::new()
+        .with_user(user)
+        .with_project(cwd.display().to_string());
+    if let Some(remote) = git(cwd, &["remote", "get-url", "origin"]) {
+        let branch = git(cwd, &["rev-parse", "--abbrev-ref", "HEAD"]).filter(|b| b != "HEAD");
+        chain = chain.with_repository(&remote, branch.as_deref());
+    }
+    chain
+}
+
+/// Repository scope for a checkout, or its project path outside git
+///
+/// This is where learnings from a session in `cwd` belong: every clone of
+/// the repository shares them.
+pub fn checkout_scope(cwd: &Path) -> Scope {
+    match git(cwd, &["remote", "get-url", "origin"]) {
+        Some(remote) => Scope::repository(&remote),
+        None => Scope::Project(cwd.display().to_string()),
+    }
+}
+
+/// Trimmed stdout of a successful git command in `cwd`
+fn git(cwd: &Path, args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .arg("-C")
+        .arg(cwd)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::EmbedderResult;
+    use crate::store::memory::MemoryStore;
+    use crate::{LearningCategory, LearningContent, LearningSource, Scope};
+    use async_trait::async_trait;
+
+    struct FixedEmbedder;
+
+    #[async_trait]
+    impl Embedder for FixedEmbedder {
+        async fn embed(&self, _text: &str) -> EmbedderResult<Vec<f32>> {
+            Ok(vec![1.0; 4])
+        }
+
+        fn dimensions(&self) -> usize {
+            4
+        }
+    }
+
+    fn learning(category: LearningCategory, description: &str, confidence: f64) -> Learning {
+        let mut learning = Learning::new(
+            Scope::Global,
+            category,
+            LearningContent {
+                description: description.into(),
+                pattern: None,
+                insight: "insight".into(),
+            },
+            LearningSource::UserCreated,
+        );
+        learning.confidence = confidence;
+        learning
+    }
+
+    fn retriever(learnings: Vec<(Learning, f64)>, config: RetrievalConfig) -> PromptRetriever {
+        PromptRetriever::new(
+            Arc::new(
+                learnings
+                    .into_iter()
+                    .fold(MemoryStore::default(), |store, (learning, similarity)| {
+                        store.with_similar(learning, similarity)
+                    }),
+            ),
+            Arc::new(FixedEmbedder),
+            config,
+        )
+    }
+
+    #[test]
+    fn test_query_text_includes_context() {
+        let context = RetrievalContext::new("fix the flaky test")
+            .with_cwd("/repo")
+            .with_recent_files(vec!["src/lib.rs".into(), "src/main.rs".into()]);
+
+        assert_eq!(
+            context.query_text(),
+            "fix the flaky test\nWorking directory: /repo\nRecent files: src/lib.rs, src/main.rs"
+        );
+    }
+
+    #[test]
+    fn test_languages_for_files() {
+        let files = vec![
+            "src/lib.rs".to_string(),
+            "web/App.tsx".to_string(),
+            "build.rs".to_string(),
+            "README.md".to_string(),
+        ];
+        assert_eq!(languages_for_files(&files), ["rust", "typescript"]);
+    }
+
+    #[tokio::test]
+    async fn test_reranks_by_similarity_confidence_and_drops_irrelevant() {
+        let weak = learning(LearningCategory::Preference, "weak", 0.2);
+        let strong = learning(LearningCategory::Preference, "strong", 0.9);
+        let unrelated = learning(LearningCategory::Preference, "unrelated", 1.0);
+        let retriever = retriever(
+            vec![(weak, 0.9), (strong, 0.9), (unrelated, 0.5)],
+            RetrievalConfig::default(),
+        );
+
+        let outcome = retriever
+            .retrieve(&ScopeChain::new(), &RetrievalContext::new("prompt"))
+            .await
+            .unwrap();
+
+        let descriptions: Vec<_> = outcome
+            .learnings
+            .iter()
+            .map(|l| l.content.description.as_str())
+            .collect();
+        assert_eq!(descriptions, ["strong", "weak"]);
+        assert_eq!(outcome.decisions.len(), 3);
+        assert!(
+            outcome
+                .decisions
+                .iter()
+                .any(|d| d.outcome == DecisionOutcome::NotRelevant)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_category_cap_and_token_budget() {
+        let a = learning(LearningCategory::Preference, "pref a", 0.9);
+        let b = learning(LearningCategory::Preference, "pref b", 0.8);
+        let c = learning(LearningCategory::ToolUsage, "a tool usage learning", 0.7);
+        let tokens_a = estimate_tokens(&LearningFormatter::new().format_learning(&a));
+        let config = RetrievalConfig {
+            max_per_category: 1,
+            token_budget: tokens_a,
+            ..Default::default()
+        };
+        let ids = [a.id, b.id, c.id];
+        let retriever = retriever(vec![(a, 0.9), (b, 0.9), (c, 0.9)], config);
+
+        let outcome = retriever
+            .retrieve(&ScopeChain::new(), &RetrievalContext::new("prompt"))
+            .await
+            .unwrap();
+
+        let decisions: Vec<_> = outcome
+            .decisions
+            .iter()
+            .map(|d| (d.learning_id, d.outcome))
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                (ids[0], DecisionOutcome::Injected),
+                (ids[1], DecisionOutcome::CategoryCap),
+                (ids[2], DecisionOutcome::OverBudget),
+            ]
+        );
+        assert_eq!(outcome.tokens_used, tokens_a);
+    }
+
+    #[tokio::test]
+    async fn test_empty_prompt_or_disabled_injects_nothing() {
+        let l = learning(LearningCategory::Preference, "pref", 0.9);
+        let retriever_enabled = retriever(vec![(l.clone(), 0.9)], RetrievalConfig::default());
+        let outcome = retriever_enabled
+            .retrieve(&ScopeChain::new(), &RetrievalContext::new("  "))
+            .await
+            .unwrap();
+        assert!(outcome.decisions.is_empty());
+
+        let disabled = retriever(
+            vec![(l, 0.9)],
+            RetrievalConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        );
+        let outcome = disabled
+            .retrieve(&ScopeChain::new(), &RetrievalContext::new("prompt"))
+            .await
+            .unwrap();
+        assert!(outcome.learnings.is_empty());
+    }
+
+    #[test]
+    fn test_checkout_scope_prefers_repository() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            checkout_scope(dir.path()),
+            Scope::Project(dir.path().display().to_string())
+        );
+
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(dir.path())
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        run(&["init", "-q"]);
+        run(&["remote", "add", "origin", "git@github.com:org/repo.git"]);
+        assert_eq!(
+            checkout_scope(dir.path()),
+            Scope::Repository("github.com/org/repo".into())
+        );
+    }
+}
@@ -9,14 +9,19 @@ pub mod assessment;
 pub mod attribution;
 pub mod capture;
 pub mod config;
+pub mod consolidation;
+pub mod contradiction;
 pub mod dashboard;
 pub mod error;
 pub mod export;
 pub mod extraction;
+pub mod import;
 pub mod inject;
 pub mod openworld;
 pub mod paths;
 pub mod plugin;
+pub mod reembed;
+pub mod retention;
 pub mod security;
 pub mod storage;
 pub mod store;
@@ -27,10 +32,25 @@ pub mod types;
 pub use config::{
     DeduplicationConfig, EnterpriseConfig, GrooveConfig, OpenWorldConfig, ProjectContext,
 };
+pub use consolidation::{
+    ConsolidationConfig, ConsolidationReport, ConsolidationRunId, ConsolidationStore, Consolidator,
+    MergeProposal,
+};
+pub use contradiction::{
+    ContradictionConfig, ContradictionDetector, ContradictionJudge, ContradictionStore, LlmJudge,
+};
 pub use error::{GrooveError, Result};
 pub use export::{EXPORT_VERSION, GrooveExport, ImportStats, LearningExport};
 pub use extraction::patterns::{CorrectionConfig, CorrectionDetector};
+pub use import::{
+    ConflictPolicy, ImportAction, ImportOptions, ImportReport, ImportedLearning, LearningImporter,
+    ScopeRemap,
+};
 pub use paths::GroovePaths;
+pub use reembed::{
+    EmbeddingIndex, EmbeddingStore, IndexState, ReembedJob, ReembedStatus, Reembedder,
+};
+pub use retention::{DecayConfig, RetentionEnforcer, RetentionReport};
 pub use storage::GrooveStorage;
 pub use store::{
     CURRENT_SCHEMA_VERSION, CozoStore, INITIAL_SCHEMA, LearningStore, MIGRATIONS, Migration,
@@ -52,9 +72,9 @@ pub use assessment::{
 
 // Extraction re-exports
 pub use extraction::{
-    ConsumerResult as ExtractionConsumerResult, ExtractionConfig, ExtractionConsumer,
-    ExtractionEvent, ExtractionMethod, ExtractionResult, ExtractionSource, LearningCandidate,
-    PatternType, StartConsumerError as ExtractionStartError, TranscriptFetcher,
+    ConsumerResult as ExtractionConsumerResult, EmbeddingConfig, ExtractionConfig,
+    ExtractionConsumer, ExtractionEvent, ExtractionMethod, ExtractionResult, ExtractionSource,
+    LearningCandidate, PatternType, StartConsumerError as ExtractionStartError, TranscriptFetcher,
     extraction_consumer_loop, start_extraction_consumer,
 };
 
@@ -70,6 +70,11 @@ impl GroovePaths {
         self.transcripts_dir.join(project_id)
     }
 
+    /// Security audit log (JSON Lines)
+    pub fn audit_log_path(&self) -> PathBuf {
+        self.data_dir.join("audit.jsonl")
+    }
+
     /// Get the project-local groove directory
     ///
     /// Returns `{project_root}/.vibes/plugins/groove/`
@@ -85,6 +90,15 @@ impl GroovePaths {
         Self::project_local_dir(project_root).join("learnings.md")
     }
 
+    /// Get the project's shared learning pack
+    ///
+    /// Returns `{project_root}/.vibes/plugins/groove/learning-pack.json`, a
+    /// `GrooveExport` committed to the repository so every checkout can
+    /// import the team's learnings.
+    pub fn project_learning_pack(project_root: &Path) -> PathBuf {
+        Self::project_local_dir(project_root).join("learning-pack.json")
+    }
+
     /// Ensure project-local groove directories exist
     pub fn ensure_project_local_dirs(project_root: &Path) -> std::io::Result<()> {
         std::fs::create_dir_all(Self::project_local_dir(project_root))
@@ -135,6 +149,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_audit_log_path() {
+        let paths = GroovePaths::from_base(PathBuf::from("/data/groove"));
+        assert_eq!(
+            paths.audit_log_path(),
+            PathBuf::from("/data/groove/audit.jsonl")
+        );
+    }
+
     #[test]
     fn test_default_creates_valid_paths() {
         let paths = GroovePaths::default();
@@ -213,6 +236,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_project_learning_pack_returns_correct_path() {
+        let project_root = PathBuf::from("/home/user/my-project");
+        let pack = GroovePaths::project_learning_pack(&project_root);
+
+        assert_eq!(
+            pack,
+            PathBuf::from("/home/user/my-project/.vibes/plugins/groove/learning-pack.json")
+        );
+    }
+
     #[test]
     fn test_ensure_project_local_dirs_creates_directories() {
         let temp = tempfile::tempdir().unwrap();
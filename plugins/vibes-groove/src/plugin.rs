@@ -2,29 +2,56 @@
 //!
 //! Provides CLI commands and HTTP routes for security, trust, and quarantine management.
 
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use vibes_core::hooks::socket::DEFAULT_HOOK_TIMEOUT;
 use vibes_core::hooks::{HookInstaller, HookInstallerConfig};
+use vibes_iggy::{EventLog, InMemoryEventLog};
 use vibes_plugin_api::{
-    ArgSpec, AssessmentQuery, AssessmentQueryResponse, CommandOutput, CommandSpec, HttpMethod,
-    Plugin, PluginAssessmentResult, PluginContext, PluginError, PluginManifest, RawEvent,
-    RouteRequest, RouteResponse, RouteSpec,
+    ArgSpec, AssessmentQuery, AssessmentQueryResponse, CommandOutput, CommandSpec, HookDecision,
+    HookInput, HttpMethod, Permission, Plugin, PluginAssessmentResult, PluginContext, PluginError,
+    PluginManifest, RawEvent, RouteRequest, RouteResponse, RouteSpec, StreamHandle, StreamId,
+    StreamMessage, StreamSpec, TuiColumn, TuiCommandSpec, TuiField, TuiViewSpec, TuiWidget,
 };
 
 use crate::assessment::{
-    ActivityStatus, AssessmentConfig, AssessmentHistoryResponse, AssessmentStatsResponse,
-    AssessmentStatusResponse, CircuitBreakerStatus, SamplingStatus, SessionHistoryItem,
-    SessionStats, SyncAssessmentProcessor, TierDistribution,
+    ActivityStatus, AssessmentConfig, AssessmentContext, AssessmentHistoryResponse, AssessmentLog,
+    AssessmentProcessor, AssessmentStatsResponse, AssessmentStatusResponse, CircuitBreakerStatus,
+    HeavyEvent, IggyAssessmentLog, IggyManager, InMemoryAssessmentLog, Outcome, ProjectId,
+    SamplingStatus, SessionHistoryItem, SessionStats, SyncAssessmentProcessor, TierDistribution,
 };
 
-use crate::CozoStore;
+use crate::attribution::CozoAttributionStore;
+use crate::capture::FileTranscriptFetcher;
+use crate::consolidation::{ConsolidationReport, Consolidator};
+use crate::contradiction::{
+    BackfillReport, Conflict, ContradictionConfig, ContradictionDetector, LlmJudge, Resolution,
+};
+use crate::extraction::{
+    ConsumerResult, Embedder, EmbeddingConfig, ExtractionConfig, ExtractionConsumer,
+    ExtractionEvent, SemanticDedup, extraction_consumer_loop,
+};
+use crate::import::{
+    ConflictPolicy, ImportAction, ImportOptions, ImportReport, LearningImporter, ScopeRemap,
+    check_source,
+};
+use crate::inject::{
+    LearningFormatter, PromptRetriever, RetrievalContext, checkout_scope_chain, languages_for_files,
+};
 use crate::openworld::{
     AnomalyCluster, CapabilityGap, ClusterId, GapId, GapStatus, OpenWorldStore, PatternFingerprint,
 };
 use crate::paths::GroovePaths;
+use crate::reembed::{EmbeddingStore, ReembedJob, ReembedStatus, Reembedder};
+use crate::retention::{DecayConfig, RetentionEnforcer, RetentionReport};
 use crate::security::load_policy_or_default;
-use crate::security::{OrgRole, Policy, ReviewOutcome, TrustLevel};
+use crate::security::{JsonlAuditLog, OrgRole, Policy, ReviewOutcome, TrustLevel};
 use crate::strategy::{CozoStrategyStore, StrategyStore};
-use crate::types::{Learning, LearningCategory, Scope};
+use crate::types::{Learning, LearningCategory, Scope, ScopeChain};
+use crate::{CozoStore, GrooveConfig, GrooveError, GrooveExport};
 
 /// Initialize the groove database at the configured path
 ///
@@ -237,6 +264,9 @@ pub struct EmbedderStatus {
     pub model: String,
     pub dimensions: usize,
     pub healthy: bool,
+    /// Unfinished migration to a new embedding model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reembedding: Option<ReembedJob>,
 }
 
 /// Learning list response
@@ -258,6 +288,21 @@ pub struct LearningSummary {
     pub created_at: String,
 }
 
+/// Learning search response, best match first
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LearningSearchResponse {
+    pub query: String,
+    pub results: Vec<LearningSearchHit>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LearningSearchHit {
+    #[serde(flatten)]
+    pub learning: LearningSummary,
+    /// Reciprocal rank fusion score (higher = more relevant)
+    pub score: f64,
+}
+
 /// Learning detail response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LearningDetailResponse {
@@ -295,6 +340,25 @@ pub struct DeleteResponse {
     pub id: String,
 }
 
+/// Learning pack import request body
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportRequest {
+    pub pack: GrooveExport,
+    pub options: ImportOptions,
+}
+
+/// Contradiction resolution request body
+///
+/// `keep` names the side that supersedes the other; without it the conflict
+/// is dismissed and both learnings stay injectable.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolveConflictRequest {
+    pub learning_id: uuid::Uuid,
+    pub conflicting_id: uuid::Uuid,
+    #[serde(default)]
+    pub keep: Option<uuid::Uuid>,
+}
+
 // Assessment API types are now in crate::assessment::api_types
 // (AssessmentStatusResponse, CircuitBreakerStatus, SamplingStatus, ActivityStatus,
 //  AssessmentHistoryResponse, SessionHistoryItem, AssessmentStatsResponse,
@@ -486,6 +550,21 @@ pub const API_LEARN_STATUS_PATH: &str = "/api/groove/learnings/status";
 /// API endpoint path for learning list
 pub const API_LEARN_LIST_PATH: &str = "/api/groove/learnings";
 
+/// API endpoint path for learning pack import
+pub const API_LEARN_IMPORT_PATH: &str = "/api/groove/learnings/import";
+
+/// API endpoint path for hybrid learning search
+pub const API_LEARN_SEARCH_PATH: &str = "/api/groove/learnings/search";
+
+/// API endpoint path for learning consolidation
+pub const API_LEARN_CONSOLIDATE_PATH: &str = "/api/groove/learnings/consolidate";
+
+/// API endpoint path for contradictions between learnings
+pub const API_LEARN_CONFLICTS_PATH: &str = "/api/groove/learnings/conflicts";
+
+/// API endpoint path for retention enforcement
+pub const API_LEARN_RETENTION_PATH: &str = "/api/groove/learnings/retention";
+
 /// Server configuration for CLI HTTP calls
 #[derive(Debug, Clone)]
 pub struct ServerUrlConfig {
@@ -599,6 +678,68 @@ impl ServerUrlConfig {
     pub fn learning_url(&self, id: &str) -> String {
         format!("{}{}/{}", self.base_url(), API_LEARN_LIST_PATH, id)
     }
+
+    /// Build full URL for learning pack import endpoint
+    pub fn learnings_import_url(&self) -> String {
+        format!("{}{}", self.base_url(), API_LEARN_IMPORT_PATH)
+    }
+
+    /// Build full URL for starting a consolidation run
+    pub fn learnings_consolidate_url(&self) -> String {
+        format!("{}{}", self.base_url(), API_LEARN_CONSOLIDATE_PATH)
+    }
+
+    /// Build full URL for listing consolidation runs
+    pub fn consolidation_runs_url(&self) -> String {
+        format!("{}{}/runs", self.base_url(), API_LEARN_CONSOLIDATE_PATH)
+    }
+
+    /// Build full URL for applying or reverting a consolidation run
+    pub fn consolidation_run_url(&self, run_id: &str, action: &str) -> String {
+        format!(
+            "{}{}/{}/{}",
+            self.base_url(),
+            API_LEARN_CONSOLIDATE_PATH,
+            run_id,
+            action
+        )
+    }
+
+    /// Build full URL for listing pending contradictions
+    pub fn learnings_conflicts_url(&self) -> String {
+        format!("{}{}", self.base_url(), API_LEARN_CONFLICTS_PATH)
+    }
+
+    /// Build full URL for scanning the store for contradictions
+    pub fn conflicts_scan_url(&self) -> String {
+        format!("{}{}/scan", self.base_url(), API_LEARN_CONFLICTS_PATH)
+    }
+
+    /// Build full URL for resolving a contradiction
+    pub fn conflicts_resolve_url(&self) -> String {
+        format!("{}{}/resolve", self.base_url(), API_LEARN_CONFLICTS_PATH)
+    }
+
+    /// Build full URL for a retention run, optionally only reporting changes
+    pub fn learnings_retention_url(&self, dry_run: bool) -> String {
+        let query = if dry_run { "?dry_run=true" } else { "" };
+        format!("{}{}{}", self.base_url(), API_LEARN_RETENTION_PATH, query)
+    }
+
+    /// Build URL for hybrid learning search, encoding the query text
+    pub fn learnings_search_url(&self, query: &str, limit: Option<usize>) -> String {
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        params.append_pair("q", query);
+        if let Some(l) = limit {
+            params.append_pair("limit", &l.to_string());
+        }
+        format!(
+            "{}{}?{}",
+            self.base_url(),
+            API_LEARN_SEARCH_PATH,
+            params.finish()
+        )
+    }
 }
 
 // ============================================================================
@@ -617,6 +758,515 @@ impl ServerUrlConfig {
 // Plugin Implementation
 // ============================================================================
 
+/// Permissions groove asks for at `vibes plugin enable`
+fn groove_permissions() -> Vec<Permission> {
+    let mut permissions = vec![
+        Permission::ReadEvents,
+        Permission::RegisterRoutes,
+        Permission::AnswerHooks,
+        // The LLM assessment tier runs the `claude` CLI
+        Permission::SpawnProcesses,
+    ];
+    // Learning store and captured transcripts
+    if let Some(data_dir) = GroovePaths::default_data_dir() {
+        permissions.push(Permission::write_path(data_dir));
+    }
+    // Claude Code transcripts, settings.json and installed hook scripts
+    if let Some(home) = dirs::home_dir() {
+        permissions.push(Permission::write_path(home.join(".claude")));
+    }
+    permissions
+}
+
+/// Files remembered per session for prompt retrieval
+const RECENT_FILES_LIMIT: usize = 10;
+
+/// How long a prompt hook waits for retrieval, leaving the rest of the
+/// hook timeout for the response to reach the client
+const PROMPT_RETRIEVAL_DEADLINE: std::time::Duration =
+    DEFAULT_HOOK_TIMEOUT.saturating_sub(std::time::Duration::from_millis(500));
+
+/// Run a future on a fresh runtime in a helper thread
+///
+/// Plugin callbacks run inside the server's runtime, where blocking on a
+/// nested runtime would panic.
+fn block_on_thread<T: Send>(
+    future: impl std::future::Future<Output = crate::Result<T>> + Send,
+) -> crate::Result<T> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| tokio::runtime::Runtime::new()?.block_on(future))
+            .join()
+            .unwrap_or_else(|_| {
+                Err(GrooveError::Io(std::io::Error::other(
+                    "helper thread panicked",
+                )))
+            })
+    })
+}
+
+/// Load the configured embedding model, or `None` if it is not downloaded
+fn configured_embedder(config: &EmbeddingConfig) -> crate::Result<Option<Arc<dyn Embedder>>> {
+    if !config.available() {
+        return Ok(None);
+    }
+    config
+        .open()
+        .map(Some)
+        .map_err(|e| GrooveError::Embedding(e.to_string()))
+}
+
+/// Load the embedding model that store-wide maintenance jobs compare with
+///
+/// Refuses while the stored vectors come from another model, since they are
+/// not comparable until re-embedding finishes.
+async fn require_embedder(
+    job: &str,
+    store: &CozoStore,
+    config: &EmbeddingConfig,
+) -> crate::Result<Arc<dyn Embedder>> {
+    let Some(embedder) = configured_embedder(config)? else {
+        return Err(GrooveError::Embedding(format!(
+            "Embedding model not downloaded; {job} needs it to compare learnings"
+        )));
+    };
+    let index = store.active_embedding_index().await?;
+    if !index.matches(embedder.as_ref()) {
+        return Err(GrooveError::Embedding(format!(
+            "Stored vectors come from {} but {} is configured; {job} waits for re-embedding",
+            index.model_id,
+            embedder.model_id()
+        )));
+    }
+    Ok(embedder)
+}
+
+/// Build a consolidator over `store` from the loaded config and policy
+async fn consolidator(store: CozoStore, config: &GrooveConfig) -> crate::Result<Consolidator> {
+    let embedder = require_embedder("consolidation", &store, &config.embedding).await?;
+    let policy = load_policy_or_default("groove-policy.toml");
+
+    Ok(Consolidator::new(
+        Arc::new(store),
+        embedder,
+        config.consolidation.clone(),
+        policy.consolidation,
+    ))
+}
+
+/// Build a contradiction detector over `store` from the loaded config
+///
+/// The LLM judge is only attached when enabled and the assessment backend
+/// supports it; otherwise the detector falls back to the polarity check.
+fn contradiction_detector(
+    store: Arc<CozoStore>,
+    config: &ContradictionConfig,
+) -> ContradictionDetector {
+    let llm_judge = config.llm_judge;
+    let mut detector = ContradictionDetector::new(store, config.clone());
+
+    if llm_judge {
+        match LlmJudge::from_config(&AssessmentConfig::default().llm) {
+            Ok(Some(judge)) => detector = detector.with_judge(Arc::new(judge)),
+            Ok(None) => {
+                tracing::debug!("LLM backend cannot judge contradictions, using polarity check")
+            }
+            Err(e) => tracing::warn!("Contradiction judge unavailable: {}", e),
+        }
+    }
+    detector
+}
+
+/// Build a retention enforcer over `store` from the loaded config and policy
+///
+/// With an `events` log, its buffered assessment events are expired too;
+/// events already persisted to Iggy expire through their topics' message
+/// expiry instead.
+fn retention_enforcer(
+    store: CozoStore,
+    paths: &GroovePaths,
+    config: &DecayConfig,
+    events: Option<Arc<dyn AssessmentLog>>,
+) -> RetentionEnforcer {
+    let _ = CozoAttributionStore::init_schema(&store.db());
+    let attribution = CozoAttributionStore::new(store.db());
+    let audit = JsonlAuditLog::new(paths.audit_log_path());
+    let policy = load_policy_or_default("groove-policy.toml");
+
+    let mut enforcer = RetentionEnforcer::new(
+        Arc::new(store),
+        Arc::new(attribution),
+        Arc::new(audit),
+        config.clone(),
+    );
+    if let Some(events) = events {
+        enforcer = enforcer.with_event_log(events, AssessmentConfig::default().retention);
+    }
+    if policy.audit.enabled {
+        enforcer.with_audit_retention(policy.audit.retention_days)
+    } else {
+        enforcer
+    }
+}
+
+/// Assessment log on the daemon's Iggy server with the configured event retention
+fn assessment_log(manager: Arc<IggyManager>) -> IggyAssessmentLog {
+    IggyAssessmentLog::new(manager).with_retention(AssessmentConfig::default().retention)
+}
+
+/// Ended sessions waiting for the extraction consumer
+///
+/// Each session is queued as a heavy event, with its transcript registered
+/// for the consumer to fetch.
+struct SessionExtraction {
+    handle: tokio::runtime::Handle,
+    sessions: Arc<InMemoryEventLog<HeavyEvent>>,
+    transcripts: Arc<FileTranscriptFetcher>,
+}
+
+impl SessionExtraction {
+    fn new(handle: tokio::runtime::Handle, transcripts: Arc<FileTranscriptFetcher>) -> Self {
+        Self {
+            handle,
+            sessions: Arc::new(InMemoryEventLog::new()),
+            transcripts,
+        }
+    }
+
+    /// Queue a Claude Code session whose transcript is at `transcript`
+    ///
+    /// Learnings are scoped to `cwd`, the directory the session worked in.
+    fn submit(&self, session_id: &str, transcript: &str, cwd: Option<&str>) {
+        let mut context = AssessmentContext::new(session_id);
+        context.project_id = cwd.map(ProjectId::new);
+        // Nothing classifies the outcome at session end, and extraction
+        // doesn't read it
+        let event = HeavyEvent::new(context, Outcome::Partial);
+
+        let sessions = self.sessions.clone();
+        let transcripts = self.transcripts.clone();
+        let (session_id, transcript) = (session_id.to_string(), transcript.to_string());
+        self.handle.spawn(async move {
+            transcripts
+                .register(session_id, transcript, Some("claude"))
+                .await;
+            if let Err(e) = sessions.append(event).await {
+                tracing::warn!("Failed to queue session for extraction: {}", e);
+            }
+        });
+    }
+}
+
+/// Run a maintenance job over the learning store every `interval` until shutdown
+///
+/// The first run waits a full interval so startup is not slowed down.
+async fn run_periodically<F, Fut>(
+    job: &'static str,
+    interval: std::time::Duration,
+    store: CozoStore,
+    shutdown: CancellationToken,
+    run: F,
+) where
+    F: Fn(CozoStore) -> Fut,
+    Fut: std::future::Future<Output = crate::Result<()>>,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = shutdown.cancelled() => break,
+            _ = ticker.tick() => {}
+        }
+
+        if let Err(e) = run(store.clone()).await {
+            tracing::warn!("Scheduled {} skipped: {}", job, e);
+        }
+    }
+}
+
+/// Batches embedded per re-embedding step
+const REEMBED_STEP_BATCHES: usize = 8;
+
+/// Pause between re-embedding steps
+const REEMBED_STEP_PAUSE: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Pause before retrying a failed re-embedding step
+const REEMBED_RETRY_PAUSE: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Migrate the store to the configured embedding model in the background
+///
+/// Works in short steps with pauses in between, so request handlers get a
+/// turn at the database. Progress is checkpointed by the [`Reembedder`], so a
+/// migration interrupted by shutdown resumes on the next start.
+async fn run_reembedding(config: EmbeddingConfig, store: CozoStore, shutdown: CancellationToken) {
+    if !config.available() {
+        tracing::debug!("Embedding model not downloaded, skipping re-embedding");
+        return;
+    }
+    let embedder = match config.open() {
+        Ok(embedder) => embedder,
+        Err(e) => {
+            tracing::warn!("Re-embedding skipped: {}", e);
+            return;
+        }
+    };
+
+    let reembedder = Reembedder::new(Arc::new(store), embedder)
+        .with_batch_size(config.batch_size)
+        .with_max_batches(REEMBED_STEP_BATCHES);
+    loop {
+        let pause = match reembedder.run().await {
+            Ok(Some(job)) if job.status == ReembedStatus::Running => REEMBED_STEP_PAUSE,
+            Ok(_) => return,
+            Err(e) => {
+                tracing::warn!("Re-embedding paused: {}", e);
+                REEMBED_RETRY_PAUSE
+            }
+        };
+
+        tokio::select! {
+            _ = shutdown.cancelled() => return,
+            _ = tokio::time::sleep(pause) => {}
+        }
+    }
+}
+
+/// One-line progress of a re-embedding migration
+fn format_reembed_progress(job: &ReembedJob) -> String {
+    let state = match job.status {
+        ReembedStatus::Running => "running".to_string(),
+        ReembedStatus::Completed => "complete".to_string(),
+        ReembedStatus::Failed => format!(
+            "failed: {}",
+            job.error.as_deref().unwrap_or("unknown error")
+        ),
+    };
+    format!(
+        "Re-embedding to {} ({} dims): {}/{} ({:.0}%) - {}",
+        job.model_id,
+        job.dimensions,
+        job.embedded,
+        job.total,
+        job.progress() * 100.0,
+        state
+    )
+}
+
+/// Convert a configured hour count into a scheduler interval of at least an hour
+fn hours(count: u64) -> std::time::Duration {
+    std::time::Duration::from_secs(count.max(1) * 3600)
+}
+
+/// Render a consolidation run for `learn consolidate`
+fn format_consolidation_report(report: &ConsolidationReport) -> String {
+    let mut output = format!(
+        "Consolidation run {} ({})\n",
+        report.run_id,
+        consolidation_status(report)
+    );
+    output.push_str(&format!(
+        "Scanned {} learnings: {} merges covering {} learnings\n",
+        report.learnings_scanned,
+        report.merges.len(),
+        report.learnings_merged()
+    ));
+
+    if !report.merges.is_empty() {
+        output.push('\n');
+    }
+    for merge in &report.merges {
+        output.push_str(&format!(
+            "  [{}] {} (confidence {:.2})\n      from {} learnings, min similarity {:.2}\n",
+            merge.generalized.scope.to_db_string(),
+            merge.generalized.content.description,
+            merge.generalized.confidence,
+            merge.members.len(),
+            merge.min_similarity
+        ));
+    }
+
+    if report.applied_at.is_none() && !report.merges.is_empty() {
+        output.push_str(&format!(
+            "\nApply with: vibes groove learn consolidate --apply {}\n",
+            report.run_id
+        ));
+    } else if report.applied_at.is_some() && report.reverted_at.is_none() {
+        output.push_str(&format!(
+            "\nUndo with: vibes groove learn consolidate --revert {}\n",
+            report.run_id
+        ));
+    }
+    output
+}
+
+/// Render recent runs for `learn consolidate --history`
+fn format_consolidation_runs(runs: &[ConsolidationReport]) -> String {
+    if runs.is_empty() {
+        return "No consolidation runs yet.\n".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str("Run ID                                Created           Status    Merges\n");
+    for run in runs {
+        output.push_str(&format!(
+            "{:<37} {:<17} {:<9} {}\n",
+            run.run_id,
+            run.created_at.format("%Y-%m-%d %H:%M"),
+            consolidation_status(run),
+            run.merges.len()
+        ));
+    }
+    output
+}
+
+fn consolidation_status(report: &ConsolidationReport) -> &'static str {
+    match (report.applied_at, report.reverted_at) {
+        (_, Some(_)) => "reverted",
+        (Some(_), None) => "applied",
+        (None, None) => "proposed",
+    }
+}
+
+/// Render pending contradictions for `learn conflicts`
+fn format_conflicts(conflicts: &[Conflict]) -> String {
+    if conflicts.is_empty() {
+        return "No pending conflicts.\n".to_string();
+    }
+
+    let mut output = format!("{} pending conflicts:\n", conflicts.len());
+    for conflict in conflicts {
+        output.push_str(&format!(
+            "\n  {} [{}] {}\n  {} [{}] {}\n      confidence {:.2}, detected {}\n",
+            conflict.learning.id,
+            conflict.learning.scope.to_db_string(),
+            conflict.learning.content.description,
+            conflict.conflicting.id,
+            conflict.conflicting.scope.to_db_string(),
+            conflict.conflicting.content.description,
+            conflict.confidence,
+            conflict.detected_at.format("%Y-%m-%d %H:%M")
+        ));
+    }
+    output
+        .push_str("\nResolve with: vibes groove learn resolve <ID> <ID> --keep <ID> | --dismiss\n");
+    output
+}
+
+/// Render a retention run for `learn retention`
+fn format_retention_report(report: &RetentionReport) -> String {
+    let mut output = String::new();
+    if report.dry_run {
+        output.push_str("Dry run - nothing was changed\n\n");
+    }
+    output.push_str(&format!(
+        "Scanned {} learnings: {} decayed, {} disabled\n",
+        report.learnings_scanned,
+        report.decayed.len(),
+        report.disabled.len()
+    ));
+    if !report.dry_run {
+        output.push_str(&format!(
+            "Expired {} assessment events, pruned {} audit entries\n",
+            report.events_expired.total(),
+            report.audit_entries_pruned
+        ));
+    }
+
+    if !report.decayed.is_empty() {
+        output.push('\n');
+    }
+    for decayed in &report.decayed {
+        let marker = if report.disabled.contains(&decayed.learning_id) {
+            " (disabled)"
+        } else {
+            ""
+        };
+        output.push_str(&format!(
+            "  {} {:.2} -> {:.2}{}  {}\n",
+            decayed.learning_id, decayed.from, decayed.to, marker, decayed.description
+        ));
+    }
+    output
+}
+
+/// Hybrid search over learnings, embedding the query with the loaded model
+///
+/// The full-text side ranks by TF-IDF rather than BM25, which Cozo's
+/// full-text index does not offer. Without the embedding model only that
+/// ranking is used.
+async fn search_learnings(
+    store: &CozoStore,
+    embedder: Option<&dyn Embedder>,
+    query: &str,
+    limit: usize,
+) -> crate::Result<Vec<(Learning, f64)>> {
+    let mut embedding = None;
+    if let Some(embedder) = embedder {
+        // Query vectors are not comparable until re-embedding finishes
+        let index = store.active_embedding_index().await?;
+        if index.matches(embedder) {
+            match embedder.embed(query).await {
+                Ok(e) => embedding = Some(e),
+                Err(e) => tracing::warn!("Searching without embeddings: {}", e),
+            }
+        }
+    }
+
+    store
+        .hybrid_search(query, embedding.as_deref(), limit)
+        .await
+}
+
+/// Source recorded for packs uploaded to the import route
+fn upload_source() -> String {
+    format!("http:{}", API_LEARN_IMPORT_PATH)
+}
+
+/// Render an import report for `learn import`
+fn format_import_report(report: &ImportReport) -> String {
+    let stats = &report.stats;
+    let mut output = String::new();
+    if report.dry_run {
+        output.push_str("Dry run - nothing was written\n\n");
+    }
+    output.push_str(&format!(
+        "Imported:    {}\nOverwritten: {}\nSkipped:     {}\nBlocked:     {}\nRelations:   {}\nEmbedded:    {}\n",
+        stats.learnings_imported,
+        stats.learnings_overwritten,
+        stats.learnings_skipped,
+        stats.learnings_blocked,
+        stats.relations_imported,
+        stats.embeddings_stored,
+    ));
+    if !report.dry_run && stats.embeddings_queued > 0 {
+        output.push_str(&format!(
+            "\n{} learning(s) will be searchable once the embedding model is available\n",
+            stats.embeddings_queued
+        ));
+    }
+
+    if !report.learnings.is_empty() {
+        output.push('\n');
+    }
+    for learning in &report.learnings {
+        let action = match &learning.action {
+            ImportAction::Created { .. } => "created".to_string(),
+            ImportAction::Skipped { existing } => format!("skipped (matches {})", existing),
+            ImportAction::Overwritten { existing } => format!("overwrote {}", existing),
+            ImportAction::KeptBoth { existing, .. } => {
+                format!("kept both (contradicts {})", existing)
+            }
+            ImportAction::Blocked { reason } => format!("blocked: {}", reason),
+        };
+        output.push_str(&format!(
+            "  [{}] {} - {}\n",
+            learning.scope, learning.description, action
+        ));
+    }
+    output
+}
+
 /// Groove continual learning plugin
 ///
 /// Provides CLI commands and HTTP routes for:
@@ -628,9 +1278,36 @@ impl ServerUrlConfig {
 /// host to call plugin assessment logic without async complications.
 #[derive(Default)]
 pub struct GroovePlugin {
+    /// Settings from the plugin's `config.toml`, loaded in `on_load()`
+    config: GrooveConfig,
+    /// Learning store shared by route handlers and background jobs
+    ///
+    /// Opened in `on_ready()`, so only the server has one; CLI commands
+    /// open the store for each call.
+    store: Option<CozoStore>,
+    /// Stops the background jobs started in `on_ready()`
+    jobs: Option<CancellationToken>,
+    /// Background jobs, aborted on unload if they outlive cancellation
+    job_handles: Vec<tokio::task::JoinHandle<()>>,
+    /// Assessment log on the server's Iggy, whose tier topics carry the
+    /// event retention
+    events: Option<Arc<IggyAssessmentLog>>,
     /// Synchronous assessment processor for event callbacks.
     /// Initialized during `on_load()` with default config.
     processor: Option<SyncAssessmentProcessor>,
+    /// Clients connected to the `/live` feed
+    live: Vec<StreamHandle>,
+    /// Embedding model for prompt retrieval, search and extraction, loaded
+    /// in `on_ready()`
+    embedder: Option<Arc<dyn Embedder>>,
+    /// Queue of ended sessions for the extraction consumer started in
+    /// `on_ready()`
+    extraction: Option<SessionExtraction>,
+    /// Files each session touched recently, most recent last
+    recent_files: HashMap<String, VecDeque<String>>,
+    /// Checkout scope chain per session and working directory, so prompts
+    /// don't run git each time
+    scope_chains: HashMap<(String, String), ScopeChain>,
 }
 
 impl Plugin for GroovePlugin {
@@ -640,6 +1317,7 @@ impl Plugin for GroovePlugin {
             version: env!("CARGO_PKG_VERSION").to_string(),
             description: "Continual learning system for vibes".to_string(),
             author: "vibes".to_string(),
+            permissions: groove_permissions(),
             ..Default::default()
         }
     }
@@ -647,6 +1325,9 @@ impl Plugin for GroovePlugin {
     fn on_load(&mut self, ctx: &mut PluginContext) -> Result<(), PluginError> {
         ctx.log_info("Loading groove plugin");
 
+        self.config =
+            GrooveConfig::from_sections(|key| ctx.config_get(key)).map_err(PluginError::config)?;
+
         // NOTE: Don't initialize processor here - it's created lazily in on_event()
         // This ensures CLI mode (no events) queries the server instead of empty local state
 
@@ -656,6 +1337,9 @@ impl Plugin for GroovePlugin {
         // Register HTTP routes
         self.register_routes(ctx)?;
 
+        // Register TUI views and commands
+        self.register_tui(ctx)?;
+
         ctx.log_info("Groove plugin loaded successfully");
         Ok(())
     }
@@ -665,20 +1349,54 @@ impl Plugin for GroovePlugin {
         // Assessment processing is handled via on_event() callback which is called
         // synchronously by the host for each event. The host owns the AssessmentLog
         // due to TypeId mismatch issues with dynamic libraries.
+
+        let Some(paths) = GroovePaths::new() else {
+            ctx.log_debug("Groove not initialized, skipping background jobs");
+            return Ok(());
+        };
+        match block_on_thread(CozoStore::open(&paths.db_path)) {
+            Ok(store) => self.store = Some(store),
+            Err(e) => {
+                ctx.log_warn(&format!("Failed to open learning store: {}", e));
+                return Ok(());
+            }
+        }
+        // Loaded once up front: prompt hooks can't wait for the model, and
+        // searches would otherwise load it per request
+        self.load_embedder(ctx);
+        if let (Some(handle), Some(shutdown)) = (ctx.runtime_handle(), ctx.shutdown()) {
+            if let Some(manager) = ctx.iggy_manager() {
+                self.events = Some(Arc::new(assessment_log(manager)));
+            }
+            let jobs = shutdown.child_token();
+            self.job_handles = self.spawn_jobs(&handle, &paths, &jobs);
+            if let Some(extraction) = self.start_extraction(&handle, &jobs) {
+                self.job_handles.push(extraction);
+            }
+            self.jobs = Some(jobs);
+        }
         Ok(())
     }
 
     fn on_unload(&mut self) -> Result<(), PluginError> {
+        if let Some(jobs) = self.jobs.take() {
+            jobs.cancel();
+        }
+        for handle in self.job_handles.drain(..) {
+            handle.abort();
+        }
+        // Release the database so a reload can open it again
+        self.store = None;
+        self.events = None;
+        self.extraction = None;
         Ok(())
     }
 
-    fn on_hook(
-        &mut self,
-        session_id: Option<&str>,
-        hook_type: &str,
-        project_path: Option<&str>,
-        ctx: &mut PluginContext,
-    ) -> Option<String> {
+    fn on_hook(&mut self, hook: &HookInput, ctx: &mut PluginContext) -> Option<HookDecision> {
+        let session_id = hook.session_id.as_deref();
+        let hook_type = hook.hook_type.as_str();
+        let project_path = hook.project_path.as_deref();
+
         ctx.log_debug(&format!(
             "Received hook: type={}, session={:?}, project={:?}",
             hook_type, session_id, project_path
@@ -693,9 +1411,9 @@ impl Plugin for GroovePlugin {
                 // Return None for now - context injection will be implemented in Milestone 4.5
                 None
             }
-            "UserPromptSubmit" => {
-                // In the future, this will capture the prompt and potentially inject context
-                ctx.log_debug("User prompt submitted");
+            "UserPromptSubmit" => self.inject_for_prompt(hook, ctx),
+            "PostToolUse" => {
+                self.record_touched_file(hook);
                 None
             }
             "PermissionRequest" => {
@@ -708,6 +1426,11 @@ impl Plugin for GroovePlugin {
             "SessionEnd" => {
                 // Session ended - good time to finalize any pending assessments
                 ctx.log_info(&format!("Session ended for session: {:?}", session_id));
+                if let Some(id) = session_id {
+                    self.recent_files.remove(id);
+                    self.scope_chains.retain(|(session, _), _| session != id);
+                    self.queue_extraction(id, hook, ctx);
+                }
                 None
             }
             "Notification" | "SubagentStop" | "PreCompact" => {
@@ -735,10 +1458,13 @@ impl Plugin for GroovePlugin {
         }
 
         // Delegate to the processor
-        self.processor
+        let results = self
+            .processor
             .as_ref()
             .map(|p| p.process(&event))
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.push_live(&results);
+        results
     }
 
     fn query_assessment_results(
@@ -773,9 +1499,15 @@ impl Plugin for GroovePlugin {
             ["assess", "history"] => self.cmd_assess_history(args),
             ["learn", "status"] => self.cmd_learn_status(args),
             ["learn", "list"] => self.cmd_learn_list(args),
+            ["learn", "search"] => self.cmd_learn_search(args),
             ["learn", "show"] => self.cmd_learn_show(args),
             ["learn", "delete"] => self.cmd_learn_delete(args),
             ["learn", "export"] => self.cmd_learn_export(args),
+            ["learn", "import"] => self.cmd_learn_import(args),
+            ["learn", "consolidate"] => self.cmd_learn_consolidate(args),
+            ["learn", "conflicts"] => self.cmd_learn_conflicts(args),
+            ["learn", "resolve"] => self.cmd_learn_resolve(args),
+            ["learn", "retention"] => self.cmd_learn_retention(args),
             ["learn", "enable"] => self.cmd_learn_enable(args),
             ["learn", "disable"] => self.cmd_learn_disable(args),
             ["attr", "status"] => self.cmd_attr_status(args),
@@ -821,7 +1553,25 @@ impl Plugin for GroovePlugin {
             (HttpMethod::Get, "/assess/history") => self.route_assess_history(&request),
             (HttpMethod::Get, "/assess/stats") => self.route_assess_stats(),
             (HttpMethod::Get, "/learnings/status") => self.route_learnings_status(),
+            (HttpMethod::Post, "/learnings/import") => self.route_learnings_import(&request),
+            (HttpMethod::Post, "/learnings/consolidate") => self.route_consolidate_run(),
+            (HttpMethod::Get, "/learnings/consolidate/runs") => {
+                self.route_consolidate_runs(&request)
+            }
+            (HttpMethod::Post, "/learnings/consolidate/:run_id/apply") => {
+                self.route_consolidate_apply(&request)
+            }
+            (HttpMethod::Post, "/learnings/consolidate/:run_id/revert") => {
+                self.route_consolidate_revert(&request)
+            }
+            (HttpMethod::Get, "/learnings/conflicts") => self.route_conflicts_list(),
+            (HttpMethod::Post, "/learnings/conflicts/scan") => self.route_conflicts_scan(),
+            (HttpMethod::Post, "/learnings/conflicts/resolve") => {
+                self.route_conflicts_resolve(&request)
+            }
+            (HttpMethod::Post, "/learnings/retention") => self.route_retention_run(&request),
             (HttpMethod::Get, "/learnings") => self.route_learnings_list(&request),
+            (HttpMethod::Get, "/learnings/search") => self.route_learnings_search(&request),
             (HttpMethod::Get, "/learnings/:id") => self.route_learnings_get(&request),
             (HttpMethod::Delete, "/learnings/:id") => self.route_learnings_delete(&request),
             (HttpMethod::Post, "/learnings/:id/enable") => self.route_learnings_enable(&request),
@@ -851,6 +1601,9 @@ impl Plugin for GroovePlugin {
             // Dashboard routes
             (HttpMethod::Get, "/dashboard/overview") => self.route_dashboard_overview(),
             (HttpMethod::Get, "/dashboard/learnings") => self.route_dashboard_learnings(&request),
+            (HttpMethod::Get, "/dashboard/learnings/search") => {
+                self.route_dashboard_learnings_search(&request)
+            }
             (HttpMethod::Get, "/dashboard/learnings/:id") => {
                 self.route_dashboard_learning_detail(&request)
             }
@@ -858,6 +1611,7 @@ impl Plugin for GroovePlugin {
                 self.route_dashboard_attribution(&request)
             }
             (HttpMethod::Get, "/dashboard/health") => self.route_dashboard_health(),
+            (HttpMethod::Get, "/dashboard/conflicts") => self.route_dashboard_conflicts(),
             (HttpMethod::Get, "/dashboard/strategy/distributions") => {
                 self.route_dashboard_strategy_distributions()
             }
@@ -886,47 +1640,418 @@ impl Plugin for GroovePlugin {
             _ => Err(PluginError::UnknownRoute(format!("{:?} {}", method, path))),
         }
     }
+
+    fn open_stream(
+        &mut self,
+        path: &str,
+        _request: RouteRequest,
+        stream: StreamHandle,
+        _ctx: &mut PluginContext,
+    ) -> Result<(), PluginError> {
+        match path {
+            "/live" => {
+                self.live.push(stream);
+                Ok(())
+            }
+            _ => Err(PluginError::UnknownRoute(format!("stream {}", path))),
+        }
+    }
+
+    fn on_stream_closed(&mut self, stream: StreamId, _ctx: &mut PluginContext) {
+        self.live.retain(|s| s.id() != stream);
+    }
 }
 
 impl GroovePlugin {
-    // ─── Command Registration ─────────────────────────────────────────
-
-    fn register_commands(&self, ctx: &mut PluginContext) -> Result<(), PluginError> {
-        // init [project_path]
-        ctx.register_command(CommandSpec {
-            path: vec!["init".into()],
-            description: "Initialize groove for a project".into(),
-            args: vec![ArgSpec {
-                name: "project_path".into(),
-                description: "Project path (defaults to current directory)".into(),
-                required: false,
-            }],
-        })?;
+    // ─── Live Feed ────────────────────────────────────────────────────
+
+    /// Push assessment results to `/live` clients
+    ///
+    /// Each result is sent as an event named after its `result_type`, so the
+    /// dashboard can refresh as soon as something changes instead of polling.
+    /// A client that has fallen behind misses results rather than blocking
+    /// event processing.
+    fn push_live(&mut self, results: &[PluginAssessmentResult]) {
+        self.live.retain(|s| !s.is_closed());
+        if self.live.is_empty() {
+            return;
+        }
+
+        for result in results {
+            let Ok(message) = StreamMessage::json(result) else {
+                continue;
+            };
+            let message = message.with_event(result.result_type.clone());
+            for stream in &self.live {
+                let _ = stream.send(message.clone());
+            }
+        }
+    }
 
-        // list [limit]
-        ctx.register_command(CommandSpec {
-            path: vec!["list".into()],
-            description: "List captured learnings".into(),
-            args: vec![ArgSpec {
-                name: "limit".into(),
-                description: "Maximum number of learnings to show (default: 10)".into(),
-                required: false,
-            }],
-        })?;
+    // ─── Background Jobs ──────────────────────────────────────────────
 
-        // status
-        ctx.register_command(CommandSpec {
-            path: vec!["status".into()],
-            description: "Show groove system status".into(),
-            args: vec![],
-        })?;
+    /// Start the enabled maintenance jobs over the shared store
+    fn spawn_jobs(
+        &self,
+        handle: &tokio::runtime::Handle,
+        paths: &GroovePaths,
+        shutdown: &CancellationToken,
+    ) -> Vec<tokio::task::JoinHandle<()>> {
+        let Some(store) = self.store.clone() else {
+            return Vec::new();
+        };
+        let config = &self.config;
+        let mut jobs = Vec::new();
+
+        if let Some(events) = self.events.clone() {
+            // Connecting creates the tier topics with their expiry, or
+            // updates the expiry of existing ones
+            jobs.push(handle.spawn(async move {
+                if let Err(e) = events.connect().await {
+                    tracing::warn!("Assessment event retention not applied: {}", e);
+                }
+            }));
+        }
 
-        // trust levels
-        ctx.register_command(CommandSpec {
-            path: vec!["trust".into(), "levels".into()],
-            description: "Show trust level hierarchy".into(),
-            args: vec![],
-        })?;
+        if config.embedding.reembed {
+            jobs.push(handle.spawn(run_reembedding(
+                config.embedding.clone(),
+                store.clone(),
+                shutdown.clone(),
+            )));
+        }
+        if config.consolidation.enabled {
+            let config = config.clone();
+            jobs.push(handle.spawn(run_periodically(
+                "consolidation",
+                hours(config.consolidation.interval_hours),
+                store.clone(),
+                shutdown.clone(),
+                move |store| {
+                    let config = config.clone();
+                    async move { consolidator(store, &config).await?.run().await.map(|_| ()) }
+                },
+            )));
+        }
+        if config.contradiction.enabled {
+            let config = config.clone();
+            jobs.push(handle.spawn(run_periodically(
+                "contradiction backfill",
+                hours(config.contradiction.backfill_interval_hours),
+                store.clone(),
+                shutdown.clone(),
+                move |store| {
+                    let config = config.clone();
+                    async move {
+                        let store = Arc::new(store);
+                        let embedder = require_embedder(
+                            "the contradiction backfill",
+                            &store,
+                            &config.embedding,
+                        )
+                        .await?;
+                        contradiction_detector(store, &config.contradiction)
+                            .backfill(embedder.as_ref())
+                            .await
+                            .map(|_| ())
+                    }
+                },
+            )));
+        }
+        if config.decay.enabled {
+            let decay = config.decay.clone();
+            let paths = paths.clone();
+            let events = self.event_log();
+            jobs.push(handle.spawn(run_periodically(
+                "retention",
+                hours(decay.interval_hours),
+                store,
+                shutdown.clone(),
+                move |store| {
+                    let enforcer = retention_enforcer(store, &paths, &decay, events.clone());
+                    async move { enforcer.run().await.map(|_| ()) }
+                },
+            )));
+        }
+        jobs
+    }
+
+    /// Start the extraction consumer over sessions that end from now on
+    ///
+    /// The LLM session analyzer comes from an assessment processor built
+    /// from the `[assessment]` config, so it shares that processor's circuit
+    /// breaker. Without an embedding model learnings can't be stored, so
+    /// extraction stays off.
+    fn start_extraction(
+        &mut self,
+        handle: &tokio::runtime::Handle,
+        shutdown: &CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let store = self.store.clone()?;
+        let embedder = self.embedder.clone()?;
+
+        let log = self
+            .event_log()
+            .unwrap_or_else(|| Arc::new(InMemoryAssessmentLog::new()) as Arc<dyn AssessmentLog>);
+        let processor =
+            AssessmentProcessor::new(self.config.assessment.clone(), log, handle.clone());
+        let store = Arc::new(store);
+        let transcripts = Arc::new(FileTranscriptFetcher::new());
+        let config = ExtractionConfig::default();
+        let group = config.group.clone();
+        let mut consumer = ExtractionConsumer::new(
+            store.clone(),
+            embedder.clone(),
+            Arc::new(SemanticDedup::new(embedder)),
+            transcripts.clone(),
+            config,
+        );
+        consumer = match consumer.with_session_analysis(&processor) {
+            Ok(consumer) => consumer,
+            Err(e) => {
+                tracing::warn!(
+                    "Learning extraction disabled, check [assessment.llm]: {}",
+                    e
+                );
+                return None;
+            }
+        };
+        if self.config.contradiction.enabled {
+            consumer = consumer.with_contradiction_detector(Arc::new(contradiction_detector(
+                store,
+                &self.config.contradiction,
+            )));
+        }
+
+        let extraction = SessionExtraction::new(handle.clone(), transcripts);
+        let sessions = extraction.sessions.clone();
+        let consumer = Arc::new(consumer);
+        let shutdown = shutdown.clone();
+        self.extraction = Some(extraction);
+        Some(handle.spawn(async move {
+            let events = match sessions.consumer(&group).await {
+                Ok(events) => events,
+                Err(e) => {
+                    tracing::warn!("Learning extraction not started: {}", e);
+                    return;
+                }
+            };
+            let producer: Option<Arc<InMemoryEventLog<ExtractionEvent>>> = None;
+            if let ConsumerResult::Error(e) =
+                extraction_consumer_loop(events, consumer, producer, shutdown).await
+            {
+                tracing::warn!("Learning extraction stopped: {}", e);
+            }
+        }))
+    }
+
+    /// Queue an ended session for learning extraction
+    ///
+    /// Claude Code names the session's transcript in the hook payload;
+    /// sessions without one have nothing to extract from.
+    fn queue_extraction(&self, session_id: &str, hook: &HookInput, ctx: &PluginContext) {
+        let Some(extraction) = &self.extraction else {
+            return;
+        };
+        let Some(transcript) = hook.payload.get("transcript_path").and_then(|v| v.as_str()) else {
+            ctx.log_debug("Session ended without a transcript, skipping extraction");
+            return;
+        };
+        let cwd = hook
+            .payload
+            .get("cwd")
+            .and_then(|v| v.as_str())
+            .or(hook.project_path.as_deref());
+        extraction.submit(session_id, transcript, cwd);
+    }
+
+    /// The assessment log retention runs expire events from, if any
+    fn event_log(&self) -> Option<Arc<dyn AssessmentLog>> {
+        self.events
+            .clone()
+            .map(|events| events as Arc<dyn AssessmentLog>)
+    }
+
+    /// The learning store: the server's shared one, or one opened for this call
+    async fn open_store(&self, paths: &GroovePaths) -> crate::Result<CozoStore> {
+        match &self.store {
+            Some(store) => Ok(store.clone()),
+            None => CozoStore::open(&paths.db_path).await,
+        }
+    }
+
+    // ─── Prompt Injection ─────────────────────────────────────────────
+
+    /// Inject learnings relevant to a submitted prompt
+    ///
+    /// Hooks are answered while the host is locked, so retrieval runs on its
+    /// own thread and is abandoned at [`PROMPT_RETRIEVAL_DEADLINE`].
+    fn inject_for_prompt(
+        &mut self,
+        hook: &HookInput,
+        ctx: &mut PluginContext,
+    ) -> Option<HookDecision> {
+        let config = self.config.retrieval.clone();
+        let prompt = hook.prompt().filter(|_| config.enabled)?;
+        let (Some(store), Some(embedder)) = (self.store.clone(), self.embedder.clone()) else {
+            ctx.log_debug("Learning store or embedding model unavailable, skipping retrieval");
+            return None;
+        };
+
+        let cwd = hook
+            .payload
+            .get("cwd")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or_else(|| hook.project_path.clone());
+        let recent_files: Vec<String> = hook
+            .session_id
+            .as_ref()
+            .and_then(|id| self.recent_files.get(id))
+            .map(|files| files.iter().cloned().collect())
+            .unwrap_or_default();
+
+        let checkout = cwd.clone().unwrap_or_else(|| ".".to_string());
+        let mut scopes = self
+            .scope_chains
+            .entry((
+                hook.session_id.clone().unwrap_or_default(),
+                checkout.clone(),
+            ))
+            // "default" is the user scope extraction stores learnings under
+            .or_insert_with(|| checkout_scope_chain("default", std::path::Path::new(&checkout)))
+            .clone();
+        for language in languages_for_files(&recent_files) {
+            scopes = scopes.with_language(language);
+        }
+        let mut context = RetrievalContext::new(prompt).with_recent_files(recent_files);
+        if let Some(cwd) = cwd {
+            context = context.with_cwd(cwd);
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let retrieval = async move {
+                // Prompt vectors are not comparable until re-embedding finishes
+                if !store
+                    .active_embedding_index()
+                    .await?
+                    .matches(embedder.as_ref())
+                {
+                    return Ok(Default::default());
+                }
+                let attribution = CozoAttributionStore::new(store.db());
+                PromptRetriever::new(Arc::new(store), embedder, config)
+                    .with_attribution(Arc::new(attribution))
+                    .retrieve(&scopes, &context)
+                    .await
+            };
+            let result = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(GrooveError::Io)
+                .and_then(|rt| rt.block_on(retrieval));
+            // The hook may have given up waiting
+            let _ = tx.send(result);
+        });
+
+        match rx.recv_timeout(PROMPT_RETRIEVAL_DEADLINE) {
+            Ok(Ok(outcome)) if !outcome.learnings.is_empty() => {
+                ctx.log_info(&format!(
+                    "Injecting {} of {} candidate learnings (~{} tokens)",
+                    outcome.learnings.len(),
+                    outcome.decisions.len(),
+                    outcome.tokens_used
+                ));
+                Some(HookDecision::context(
+                    LearningFormatter::new().format_learnings(&outcome.learnings),
+                ))
+            }
+            Ok(Ok(outcome)) => {
+                ctx.log_debug(&format!(
+                    "No learnings injected ({} candidates)",
+                    outcome.decisions.len()
+                ));
+                None
+            }
+            Ok(Err(e)) => {
+                ctx.log_warn(&format!("Prompt retrieval failed: {}", e));
+                None
+            }
+            Err(_) => {
+                ctx.log_warn("Prompt retrieval timed out, continuing without learnings");
+                None
+            }
+        }
+    }
+
+    /// Load the embedding model shared by retrieval, search and extraction
+    fn load_embedder(&mut self, ctx: &PluginContext) {
+        match configured_embedder(&self.config.embedding) {
+            Ok(Some(embedder)) => self.embedder = Some(embedder),
+            Ok(None) => ctx.log_debug("Embedding model not downloaded, searching by text only"),
+            Err(e) => ctx.log_warn(&format!("Failed to load embedding model: {}", e)),
+        }
+    }
+
+    /// Remember the file a tool call touched, for retrieval context
+    fn record_touched_file(&mut self, hook: &HookInput) {
+        let (Some(session_id), Some(input)) = (&hook.session_id, hook.tool_input()) else {
+            return;
+        };
+        let Some(file) = ["file_path", "notebook_path", "path"]
+            .iter()
+            .find_map(|key| input.get(key).and_then(|v| v.as_str()))
+        else {
+            return;
+        };
+
+        let files = self.recent_files.entry(session_id.clone()).or_default();
+        files.retain(|f| f != file);
+        files.push_back(file.to_string());
+        if files.len() > RECENT_FILES_LIMIT {
+            files.pop_front();
+        }
+    }
+
+    // ─── Command Registration ─────────────────────────────────────────
+
+    fn register_commands(&self, ctx: &mut PluginContext) -> Result<(), PluginError> {
+        // init [project_path]
+        ctx.register_command(CommandSpec {
+            path: vec!["init".into()],
+            description: "Initialize groove for a project".into(),
+            args: vec![ArgSpec {
+                name: "project_path".into(),
+                description: "Project path (defaults to current directory)".into(),
+                required: false,
+            }],
+        })?;
+
+        // list [limit]
+        ctx.register_command(CommandSpec {
+            path: vec!["list".into()],
+            description: "List captured learnings".into(),
+            args: vec![ArgSpec {
+                name: "limit".into(),
+                description: "Maximum number of learnings to show (default: 10)".into(),
+                required: false,
+            }],
+        })?;
+
+        // status
+        ctx.register_command(CommandSpec {
+            path: vec!["status".into()],
+            description: "Show groove system status".into(),
+            args: vec![],
+        })?;
+
+        // trust levels
+        ctx.register_command(CommandSpec {
+            path: vec!["trust".into(), "levels".into()],
+            description: "Show trust level hierarchy".into(),
+            args: vec![],
+        })?;
 
         // trust role <role>
         ctx.register_command(CommandSpec {
@@ -1012,6 +2137,17 @@ impl GroovePlugin {
             ],
         })?;
 
+        // learn search <query> [--limit]
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "search".into()],
+            description: "Search learnings by keyword and meaning".into(),
+            args: vec![ArgSpec {
+                name: "query".into(),
+                description: "Text to search for".into(),
+                required: true,
+            }],
+        })?;
+
         // learn show <id>
         ctx.register_command(CommandSpec {
             path: vec!["learn".into(), "show".into()],
@@ -1045,6 +2181,56 @@ impl GroovePlugin {
             }],
         })?;
 
+        // learn import [file] [--dry-run] [--on-conflict] [--remap]
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "import".into()],
+            description: "Import a learning pack".into(),
+            args: vec![ArgSpec {
+                name: "file".into(),
+                description: "Learning pack file (default: the project's learning pack)".into(),
+                required: false,
+            }],
+        })?;
+
+        // learn consolidate [--apply <run>] [--revert <run>] [--history]
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "consolidate".into()],
+            description: "Merge similar learnings, or apply/revert a consolidation run".into(),
+            args: vec![],
+        })?;
+
+        // learn conflicts [--scan]
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "conflicts".into()],
+            description: "List learnings that contradict each other".into(),
+            args: vec![],
+        })?;
+
+        // learn resolve <id> <id> (--keep <id> | --dismiss)
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "resolve".into()],
+            description: "Resolve a contradiction between two learnings".into(),
+            args: vec![
+                ArgSpec {
+                    name: "id".into(),
+                    description: "First learning in the conflict".into(),
+                    required: true,
+                },
+                ArgSpec {
+                    name: "other".into(),
+                    description: "Second learning in the conflict".into(),
+                    required: true,
+                },
+            ],
+        })?;
+
+        // learn retention [--dry-run]
+        ctx.register_command(CommandSpec {
+            path: vec!["learn".into(), "retention".into()],
+            description: "Decay idle learnings and expire old groove data".into(),
+            args: vec![],
+        })?;
+
         // learn enable <id>
         ctx.register_command(CommandSpec {
             path: vec!["learn".into(), "enable".into()],
@@ -1365,6 +2551,135 @@ impl GroovePlugin {
         Ok(())
     }
 
+    // ─── TUI Registration ─────────────────────────────────────────────
+
+    fn register_tui(&self, ctx: &mut PluginContext) -> Result<(), PluginError> {
+        ctx.register_tui_view(TuiViewSpec {
+            id: "learnings".into(),
+            title: "Learnings".into(),
+            widgets: vec![
+                TuiWidget::KeyValue {
+                    title: "Counts".into(),
+                    source: "/learnings/status".into(),
+                    fields: vec![
+                        TuiField::new("Project", "/counts_by_scope/project"),
+                        TuiField::new("User", "/counts_by_scope/user"),
+                        TuiField::new("Global", "/counts_by_scope/global"),
+                        TuiField::new("Corrections", "/counts_by_category/correction"),
+                        TuiField::new("Error recovery", "/counts_by_category/error_recovery"),
+                        TuiField::new("Patterns", "/counts_by_category/pattern"),
+                        TuiField::new("Preferences", "/counts_by_category/preference"),
+                    ],
+                },
+                TuiWidget::Table {
+                    title: "Learnings".into(),
+                    source: "/learnings".into(),
+                    items: "/learnings".into(),
+                    columns: vec![
+                        TuiColumn::new("Category", "/category").width(16),
+                        TuiColumn::new("Confidence", "/confidence").width(10),
+                        TuiColumn::new("Scope", "/scope").width(10),
+                        TuiColumn::new("Description", "/description"),
+                    ],
+                },
+            ],
+        })?;
+
+        ctx.register_tui_view(TuiViewSpec {
+            id: "attribution".into(),
+            title: "Attribution".into(),
+            widgets: vec![
+                TuiWidget::KeyValue {
+                    title: "Status".into(),
+                    source: "/attr/status".into(),
+                    fields: vec![
+                        TuiField::new("Active", "/active_learnings"),
+                        TuiField::new("Experimental", "/experimental_learnings"),
+                        TuiField::new("Deprecated", "/deprecated_learnings"),
+                        TuiField::new("Attributions (24h)", "/attributions_24h"),
+                        TuiField::new("Average value", "/average_value"),
+                    ],
+                },
+                TuiWidget::Sparkline {
+                    title: "Estimated value".into(),
+                    source: "/attr/values".into(),
+                    items: "/values".into(),
+                    value: "/estimated_value".into(),
+                },
+                TuiWidget::Table {
+                    title: "Values".into(),
+                    source: "/attr/values".into(),
+                    items: "/values".into(),
+                    columns: vec![
+                        TuiColumn::new("Learning", "/learning_id").width(36),
+                        TuiColumn::new("Value", "/estimated_value").width(8),
+                        TuiColumn::new("Confidence", "/confidence").width(10),
+                        TuiColumn::new("Sessions", "/session_count").width(8),
+                        TuiColumn::new("Status", "/status"),
+                    ],
+                },
+            ],
+        })?;
+
+        ctx.register_tui_view(TuiViewSpec {
+            id: "gaps".into(),
+            title: "Capability gaps".into(),
+            widgets: vec![
+                TuiWidget::KeyValue {
+                    title: "Status".into(),
+                    source: "/gaps/status".into(),
+                    fields: vec![
+                        TuiField::new("Detected", "/detected"),
+                        TuiField::new("Confirmed", "/confirmed"),
+                        TuiField::new("In progress", "/in_progress"),
+                        TuiField::new("Resolved", "/resolved"),
+                        TuiField::new("Dismissed", "/dismissed"),
+                    ],
+                },
+                TuiWidget::Table {
+                    title: "Gaps".into(),
+                    source: "/gaps".into(),
+                    items: "".into(),
+                    columns: vec![
+                        TuiColumn::new("ID", "/id").width(36),
+                        TuiColumn::new("Severity", "/severity").width(10),
+                        TuiColumn::new("Status", "/status").width(12),
+                        TuiColumn::new("Failures", "/failure_count").width(8),
+                        TuiColumn::new("Pattern", "/context_pattern"),
+                    ],
+                },
+            ],
+        })?;
+
+        ctx.register_tui_command(TuiCommandSpec::open_view(
+            "learnings",
+            "Show learnings",
+            "learnings",
+        ))?;
+        ctx.register_tui_command(TuiCommandSpec::open_view(
+            "attribution",
+            "Show learning attribution",
+            "attribution",
+        ))?;
+        ctx.register_tui_command(TuiCommandSpec::open_view(
+            "gaps",
+            "Show capability gaps",
+            "gaps",
+        ))?;
+        ctx.register_tui_command(TuiCommandSpec::post(
+            "dismiss-gap",
+            "Dismiss a capability gap",
+            "/gaps/:id/dismiss",
+        ))?;
+        ctx.register_tui_command(TuiCommandSpec::post(
+            "resolve-gap",
+            "Mark a capability gap as resolved",
+            "/gaps/:id/resolve",
+        ))?;
+
+        Ok(())
+    }
+
     // ─── Route Registration ───────────────────────────────────────────
 
     fn register_routes(&self, ctx: &mut PluginContext) -> Result<(), PluginError> {
@@ -1425,6 +2740,17 @@ impl GroovePlugin {
             path: "/learnings".into(),
         })?;
 
+        // Before /learnings/:id, which would otherwise capture it
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/learnings/search".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/learnings/conflicts".into(),
+        })?;
+
         ctx.register_route(RouteSpec {
             method: HttpMethod::Get,
             path: "/learnings/:id".into(),
@@ -1435,6 +2761,46 @@ impl GroovePlugin {
             path: "/learnings/:id".into(),
         })?;
 
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/import".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/consolidate".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/learnings/consolidate/runs".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/consolidate/:run_id/apply".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/consolidate/:run_id/revert".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/conflicts/scan".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/conflicts/resolve".into(),
+        })?;
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Post,
+            path: "/learnings/retention".into(),
+        })?;
+
         // Attribution routes
         ctx.register_route(RouteSpec {
             method: HttpMethod::Get,
@@ -1514,6 +2880,11 @@ impl GroovePlugin {
             path: "/dashboard/learnings".into(),
         })?;
 
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/dashboard/learnings/search".into(),
+        })?;
+
         ctx.register_route(RouteSpec {
             method: HttpMethod::Get,
             path: "/dashboard/learnings/:id".into(),
@@ -1529,6 +2900,11 @@ impl GroovePlugin {
             path: "/dashboard/health".into(),
         })?;
 
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/dashboard/conflicts".into(),
+        })?;
+
         ctx.register_route(RouteSpec {
             method: HttpMethod::Get,
             path: "/dashboard/strategy/distributions".into(),
@@ -1539,6 +2915,9 @@ impl GroovePlugin {
             path: "/dashboard/strategy/overrides".into(),
         })?;
 
+        // Live feed of assessment results for the dashboard
+        ctx.register_stream(StreamSpec::sse("/live"))?;
+
         // Novelty routes
         ctx.register_route(RouteSpec {
             method: HttpMethod::Get,
@@ -1974,19 +3353,27 @@ impl GroovePlugin {
         ))
     }
 
-    /// Fetch a specific learning by ID
-    pub async fn fetch_learning_with_config(
+    /// Send a store maintenance request to the server
+    ///
+    /// Covers consolidation runs and contradiction scans; `post` starts or
+    /// changes something, otherwise results are listed.
+    pub async fn maintenance_request_with_config<T: serde::de::DeserializeOwned>(
         config: &ServerUrlConfig,
-        id: &str,
-    ) -> Result<LearningDetailResponse, String> {
-        let url = config.learning_url(id);
-
+        url: &str,
+        post: bool,
+    ) -> Result<T, String> {
+        // Consolidation and contradiction scans embed every learning in the store
         let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(5))
+            .timeout(std::time::Duration::from_secs(300))
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
-        let response = client.get(&url).send().await.map_err(|e| {
+        let request = if post {
+            client.post(url)
+        } else {
+            client.get(url)
+        };
+        let response = request.send().await.map_err(|e| {
             format!(
                 "Failed to connect to server at {}: {}",
                 config.base_url(),
@@ -1994,45 +3381,46 @@ impl GroovePlugin {
             )
         })?;
 
-        if response.status() == reqwest::StatusCode::NOT_FOUND {
-            return Err(format!("Learning not found: {}", id));
-        }
-
         if !response.status().is_success() {
-            return Err(format!("Server returned error: {}", response.status()));
+            let status = response.status();
+            return match response.json::<ErrorResponse>().await {
+                Ok(err) => Err(err.error),
+                Err(_) => Err(format!("Server returned error: {}", status)),
+            };
         }
 
         response
-            .json::<LearningDetailResponse>()
+            .json::<T>()
             .await
             .map_err(|e| format!("Failed to parse response: {}", e))
     }
 
-    /// Blocking version of fetch_learning
-    fn fetch_learning_blocking(
+    /// Blocking version of maintenance_request
+    fn maintenance_request_blocking<T: serde::de::DeserializeOwned>(
         config: &ServerUrlConfig,
-        id: &str,
-    ) -> Result<LearningDetailResponse, String> {
+        url: &str,
+        post: bool,
+    ) -> Result<T, String> {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| format!("Failed to create runtime: {}", e))?;
-        let config = config.clone();
-        let id = id.to_string();
-        rt.block_on(Self::fetch_learning_with_config(&config, &id))
+        rt.block_on(Self::maintenance_request_with_config(config, url, post))
     }
 
-    /// Delete a learning by ID
-    pub async fn delete_learning_with_config(
+    /// Run a hybrid learning search on the server
+    pub async fn search_learnings_with_config(
         config: &ServerUrlConfig,
-        id: &str,
-    ) -> Result<DeleteResponse, String> {
-        let url = config.learning_url(id);
+        query: &str,
+        limit: Option<usize>,
+    ) -> Result<LearningSearchResponse, String> {
+        let url = config.learnings_search_url(query, limit);
 
+        // Embedding the query takes longer than a plain lookup
         let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(5))
+            .timeout(std::time::Duration::from_secs(30))
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
-        let response = client.delete(&url).send().await.map_err(|e| {
+        let response = client.get(&url).send().await.map_err(|e| {
             format!(
                 "Failed to connect to server at {}: {}",
                 config.base_url(),
@@ -2040,37 +3428,213 @@ impl GroovePlugin {
             )
         })?;
 
-        if response.status() == reqwest::StatusCode::NOT_FOUND {
-            return Err(format!("Learning not found: {}", id));
-        }
-
         if !response.status().is_success() {
             return Err(format!("Server returned error: {}", response.status()));
         }
 
         response
-            .json::<DeleteResponse>()
+            .json::<LearningSearchResponse>()
             .await
             .map_err(|e| format!("Failed to parse response: {}", e))
     }
 
-    /// Blocking version of delete_learning
-    fn delete_learning_blocking(
+    /// Blocking version of search_learnings
+    fn search_learnings_blocking(
         config: &ServerUrlConfig,
-        id: &str,
-    ) -> Result<DeleteResponse, String> {
+        query: &str,
+        limit: Option<usize>,
+    ) -> Result<LearningSearchResponse, String> {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| format!("Failed to create runtime: {}", e))?;
-        let config = config.clone();
-        let id = id.to_string();
-        rt.block_on(Self::delete_learning_with_config(&config, &id))
+        rt.block_on(Self::search_learnings_with_config(config, query, limit))
     }
 
-    // ─── Command Handlers ─────────────────────────────────────────────
-
-    fn cmd_init(&self, args: &vibes_plugin_api::CommandArgs) -> Result<CommandOutput, PluginError> {
-        // Get project path from args or use current directory
-        let project_path = args
+    /// Fetch a specific learning by ID
+    pub async fn fetch_learning_with_config(
+        config: &ServerUrlConfig,
+        id: &str,
+    ) -> Result<LearningDetailResponse, String> {
+        let url = config.learning_url(id);
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let response = client.get(&url).send().await.map_err(|e| {
+            format!(
+                "Failed to connect to server at {}: {}",
+                config.base_url(),
+                e
+            )
+        })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(format!("Learning not found: {}", id));
+        }
+
+        if !response.status().is_success() {
+            return Err(format!("Server returned error: {}", response.status()));
+        }
+
+        response
+            .json::<LearningDetailResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    /// Blocking version of fetch_learning
+    fn fetch_learning_blocking(
+        config: &ServerUrlConfig,
+        id: &str,
+    ) -> Result<LearningDetailResponse, String> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| format!("Failed to create runtime: {}", e))?;
+        let config = config.clone();
+        let id = id.to_string();
+        rt.block_on(Self::fetch_learning_with_config(&config, &id))
+    }
+
+    /// Delete a learning by ID
+    pub async fn delete_learning_with_config(
+        config: &ServerUrlConfig,
+        id: &str,
+    ) -> Result<DeleteResponse, String> {
+        let url = config.learning_url(id);
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let response = client.delete(&url).send().await.map_err(|e| {
+            format!(
+                "Failed to connect to server at {}: {}",
+                config.base_url(),
+                e
+            )
+        })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(format!("Learning not found: {}", id));
+        }
+
+        if !response.status().is_success() {
+            return Err(format!("Server returned error: {}", response.status()));
+        }
+
+        response
+            .json::<DeleteResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    /// Blocking version of delete_learning
+    fn delete_learning_blocking(
+        config: &ServerUrlConfig,
+        id: &str,
+    ) -> Result<DeleteResponse, String> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| format!("Failed to create runtime: {}", e))?;
+        let config = config.clone();
+        let id = id.to_string();
+        rt.block_on(Self::delete_learning_with_config(&config, &id))
+    }
+
+    /// Import a learning pack through the server
+    pub async fn import_learnings_with_config(
+        config: &ServerUrlConfig,
+        request: &ImportRequest,
+    ) -> Result<ImportReport, String> {
+        let url = config.learnings_import_url();
+
+        // Packs can be large and dedup embeds every learning, so allow longer
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(120))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let response = client.post(&url).json(request).send().await.map_err(|e| {
+            format!(
+                "Failed to connect to server at {}: {}",
+                config.base_url(),
+                e
+            )
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return match response.json::<ErrorResponse>().await {
+                Ok(err) => Err(err.error),
+                Err(_) => Err(format!("Server returned error: {}", status)),
+            };
+        }
+
+        response
+            .json::<ImportReport>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    /// Blocking version of import_learnings
+    fn import_learnings_blocking(
+        config: &ServerUrlConfig,
+        request: &ImportRequest,
+    ) -> Result<ImportReport, String> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| format!("Failed to create runtime: {}", e))?;
+        rt.block_on(Self::import_learnings_with_config(config, request))
+    }
+
+    /// Resolve a contradiction through the server
+    pub async fn resolve_conflict_with_config(
+        config: &ServerUrlConfig,
+        request: &ResolveConflictRequest,
+    ) -> Result<Vec<Conflict>, String> {
+        let url = config.conflicts_resolve_url();
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let response = client.post(&url).json(request).send().await.map_err(|e| {
+            format!(
+                "Failed to connect to server at {}: {}",
+                config.base_url(),
+                e
+            )
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return match response.json::<ErrorResponse>().await {
+                Ok(err) => Err(err.error),
+                Err(_) => Err(format!("Server returned error: {}", status)),
+            };
+        }
+
+        response
+            .json::<Vec<Conflict>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    /// Blocking version of resolve_conflict
+    fn resolve_conflict_blocking(
+        config: &ServerUrlConfig,
+        request: &ResolveConflictRequest,
+    ) -> Result<Vec<Conflict>, String> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| format!("Failed to create runtime: {}", e))?;
+        rt.block_on(Self::resolve_conflict_with_config(config, request))
+    }
+
+    // ─── Command Handlers ─────────────────────────────────────────────
+
+    fn cmd_init(&self, args: &vibes_plugin_api::CommandArgs) -> Result<CommandOutput, PluginError> {
+        // Get project path from args or use current directory
+        let project_path = args
             .args
             .first()
             .map(std::path::PathBuf::from)
@@ -2203,6 +3767,21 @@ impl GroovePlugin {
             output.push_str("  ? Could not determine hooks directory\n");
         }
 
+        // Embeddings are owned by the server, so ask it
+        output.push_str("\nEmbeddings:\n");
+        match Self::fetch_learnings_status_blocking(&Self::load_server_config()) {
+            Ok(status) => {
+                output.push_str(&format!(
+                    "  Model: {} ({} dims)\n",
+                    status.embedder.model, status.embedder.dimensions
+                ));
+                if let Some(job) = &status.embedder.reembedding {
+                    output.push_str(&format!("  {}\n", format_reembed_progress(job)));
+                }
+            }
+            Err(_) => output.push_str("  ? Server not running\n"),
+        }
+
         // Summary
         output.push_str("\nStatus: ");
         if paths.data_dir.exists() && paths.transcripts_dir.exists() {
@@ -2241,6 +3820,10 @@ impl GroovePlugin {
             "  {:<24} {:>6}  Enterprise content, not yet approved\n",
             "OrganizationUnverified", "50"
         ));
+        output.push_str(&format!(
+            "  {:<24} {:>6}  Imported learning pack, not yet reviewed\n",
+            "Imported", "40"
+        ));
         output.push_str(&format!(
             "  {:<24} {:>6}  Community content, verified\n",
             "PublicVerified", "30"
@@ -2256,7 +3839,8 @@ impl GroovePlugin {
         output.push_str("\nInjection Policy:\n");
         output
             .push_str("  - Local, PrivateCloud, OrganizationVerified: Allowed without scanning\n");
-        output.push_str("  - OrganizationUnverified, PublicVerified: Requires scanning\n");
+        output
+            .push_str("  - OrganizationUnverified, Imported, PublicVerified: Requires scanning\n");
         output.push_str("  - PublicUnverified: Requires scanning, may show warnings\n");
         output.push_str("  - Quarantined: Blocked from injection\n");
 
@@ -2366,6 +3950,18 @@ impl GroovePlugin {
             policy.audit.retention_days
         ));
 
+        output.push('\n');
+
+        output.push_str("Consolidation Policy:\n");
+        output.push_str(&format!(
+            "  Auto-apply merges:       {}\n",
+            policy.consolidation.auto_apply
+        ));
+        output.push_str(&format!(
+            "  Generalize across scopes: {}\n",
+            policy.consolidation.allow_scope_generalization
+        ));
+
         Ok(CommandOutput::Text(output))
     }
 
@@ -2713,6 +4309,9 @@ impl GroovePlugin {
             "Embedder: {} ({} dims) - {}\n",
             status.embedder.model, status.embedder.dimensions, embedder_status
         ));
+        if let Some(job) = &status.embedder.reembedding {
+            output.push_str(&format!("{}\n", format_reembed_progress(job)));
+        }
 
         match status.last_extraction {
             Some(ts) => output.push_str(&format!("Last extraction: {}\n", ts)),
@@ -2731,7 +4330,9 @@ impl GroovePlugin {
                 "Usage: vibes groove learn list [OPTIONS]\n\n\
                  List learnings with optional filters.\n\n\
                  Options:\n\
-                   --scope <SCOPE>      Filter by scope (project, user, global)\n\
+                   --scope <SCOPE>      Filter by scope kind (global, user, project, team, repo,\n\
+                                        branch, language) or an exact scope such as\n\
+                                        repo:github.com/org/repo\n\
                    --category <CAT>     Filter by category (correction, error_recovery, pattern, preference)\n\
                    --help, -h           Show this help message\n"
                     .to_string(),
@@ -2789,6 +4390,86 @@ impl GroovePlugin {
         Ok(CommandOutput::Text(output))
     }
 
+    fn cmd_learn_search(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) || args.args.is_empty() {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn search <QUERY> [OPTIONS]\n\n\
+                 Search learnings by exact keywords and by meaning.\n\n\
+                 Arguments:\n\
+                   <QUERY>          Text to search for; identifiers like E0502 or --dry-run match exactly\n\n\
+                 Options:\n\
+                   --limit <N>      Maximum results (default: 10)\n\
+                   --help, -h       Show this help message\n"
+                    .to_string(),
+            ));
+        }
+
+        // Everything except --limit is query text, so flags can be searched for
+        let mut terms = Vec::new();
+        let mut limit = None;
+        let mut iter = args.args.iter();
+        while let Some(arg) = iter.next() {
+            if arg == "--limit" {
+                limit = iter.next().cloned();
+            } else if let Some(value) = arg.strip_prefix("--limit=") {
+                limit = Some(value.to_string());
+            } else {
+                terms.push(arg.as_str());
+            }
+        }
+        let limit = limit
+            .map(|l| {
+                l.parse::<usize>()
+                    .map_err(|_| PluginError::custom(format!("Invalid --limit: {}", l)))
+            })
+            .transpose()?;
+        let query = terms.join(" ");
+        if query.trim().is_empty() {
+            return Err(PluginError::custom("Search query is empty"));
+        }
+
+        let config = Self::load_server_config();
+        let response = Self::search_learnings_blocking(&config, &query, limit)
+            .map_err(|e| PluginError::custom(format!("Failed to search learnings: {}", e)))?;
+
+        if response.results.is_empty() {
+            return Ok(CommandOutput::Text(format!(
+                "No learnings match '{}'.\n",
+                response.query
+            )));
+        }
+
+        let mut output = String::new();
+        output.push_str("ID       Category        Score   Scope    Description\n");
+        output
+            .push_str("──────── ─────────────── ─────── ──────── ─────────────────────────────\n");
+
+        for hit in &response.results {
+            let learning = &hit.learning;
+            let id_short = if learning.id.len() > 8 {
+                &learning.id[..8]
+            } else {
+                &learning.id
+            };
+            let desc_truncated = if learning.description.len() > 29 {
+                format!("{}...", &learning.description[..26])
+            } else {
+                learning.description.clone()
+            };
+            output.push_str(&format!(
+                "{:<8} {:<15} {:>7.4} {:<8} {}\n",
+                id_short, learning.category, hit.score, learning.scope, desc_truncated
+            ));
+        }
+
+        output.push_str(&format!("\n{} results\n", response.results.len()));
+
+        Ok(CommandOutput::Text(output))
+    }
+
     fn cmd_learn_show(
         &self,
         args: &vibes_plugin_api::CommandArgs,
@@ -2930,66 +4611,326 @@ impl GroovePlugin {
         Ok(CommandOutput::Text(json))
     }
 
-    // ─── Learn Enable/Disable Commands ────────────────────────────────
-
-    fn cmd_learn_enable(
+    fn cmd_learn_consolidate(
         &self,
         args: &vibes_plugin_api::CommandArgs,
     ) -> Result<CommandOutput, PluginError> {
         if Self::wants_help(&args.args) {
             return Ok(CommandOutput::Text(
-                "Usage: vibes groove learn enable <id>\n\n\
-                 Re-enable a deprecated learning.\n\n\
-                 Arguments:\n\
-                   <id>         Learning ID to enable\n"
+                "Usage: vibes groove learn consolidate [OPTIONS]\n\n\
+                 Cluster similar learnings and merge each cluster into one generalized\n\
+                 learning. Without options a new run is proposed, or applied directly\n\
+                 when the policy sets consolidation.auto_apply.\n\n\
+                 Options:\n\
+                   --apply <RUN_ID>    Apply a proposed run\n\
+                   --revert <RUN_ID>   Undo an applied run\n\
+                   --history           List recent runs\n\
+                   --help, -h          Show this help message\n"
                     .to_string(),
             ));
         }
 
-        let id = args
-            .args
-            .first()
-            .ok_or_else(|| PluginError::custom("Missing learning ID"))?;
+        let config = Self::load_server_config();
+        let consolidation_err =
+            |e: String| PluginError::custom(format!("Consolidation failed: {}", e));
+
+        if args.args.iter().any(|a| a == "--history") {
+            let runs: Vec<ConsolidationReport> = Self::maintenance_request_blocking(
+                &config,
+                &config.consolidation_runs_url(),
+                false,
+            )
+            .map_err(consolidation_err)?;
+            return Ok(CommandOutput::Text(format_consolidation_runs(&runs)));
+        }
 
-        let mut output = String::new();
-        output.push_str(&format!("Enabling learning: {}\n", id));
-        output.push_str("(Not yet implemented - requires server connection)\n");
+        let url = match (
+            Self::parse_flag(&args.args, "--apply"),
+            Self::parse_flag(&args.args, "--revert"),
+        ) {
+            (Some(_), Some(_)) => {
+                return Err(PluginError::custom(
+                    "--apply and --revert cannot be combined",
+                ));
+            }
+            (Some(run_id), None) => config.consolidation_run_url(&run_id, "apply"),
+            (None, Some(run_id)) => config.consolidation_run_url(&run_id, "revert"),
+            (None, None) => config.learnings_consolidate_url(),
+        };
 
-        Ok(CommandOutput::Text(output))
+        let report: ConsolidationReport =
+            Self::maintenance_request_blocking(&config, &url, true).map_err(consolidation_err)?;
+        Ok(CommandOutput::Text(format_consolidation_report(&report)))
     }
 
-    fn cmd_learn_disable(
+    fn cmd_learn_conflicts(
         &self,
         args: &vibes_plugin_api::CommandArgs,
     ) -> Result<CommandOutput, PluginError> {
         if Self::wants_help(&args.args) {
             return Ok(CommandOutput::Text(
-                "Usage: vibes groove learn disable <id> [reason]\n\n\
-                 Manually deprecate a learning.\n\n\
-                 Arguments:\n\
-                   <id>         Learning ID to disable\n\
-                   [reason]     Optional reason for deprecation\n"
+                "Usage: vibes groove learn conflicts [OPTIONS]\n\n\
+                 List pairs of learnings that give contradictory advice. Until a\n\
+                 conflict is resolved only the newer side is injected.\n\n\
+                 Options:\n\
+                   --scan       Check the whole store for new conflicts first\n\
+                   --help, -h   Show this help message\n"
                     .to_string(),
             ));
         }
 
-        let id = args
-            .args
-            .first()
-            .ok_or_else(|| PluginError::custom("Missing learning ID"))?;
-        let reason = args.args.get(1).cloned();
-
-        let mut output = String::new();
-        output.push_str(&format!("Disabling learning: {}\n", id));
-        if let Some(r) = reason {
-            output.push_str(&format!("Reason: {}\n", r));
+        let config = Self::load_server_config();
+        let conflicts_err =
+            |e: String| PluginError::custom(format!("Conflict check failed: {}", e));
+
+        if args.args.iter().any(|a| a == "--scan") {
+            let report: BackfillReport =
+                Self::maintenance_request_blocking(&config, &config.conflicts_scan_url(), true)
+                    .map_err(conflicts_err)?;
+            return Ok(CommandOutput::Text(format!(
+                "Scanned {} learnings: {} new conflicts\n",
+                report.learnings_scanned,
+                report.conflicts.len()
+            )));
         }
-        output.push_str("(Not yet implemented - requires server connection)\n");
 
-        Ok(CommandOutput::Text(output))
+        let conflicts: Vec<Conflict> =
+            Self::maintenance_request_blocking(&config, &config.learnings_conflicts_url(), false)
+                .map_err(conflicts_err)?;
+        Ok(CommandOutput::Text(format_conflicts(&conflicts)))
     }
 
-    // ─── Attribution Commands ─────────────────────────────────────────
+    fn cmd_learn_retention(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn retention [OPTIONS]\n\n\
+                 Decay the confidence of learnings that haven't been injected\n\
+                 lately, disable those that fall below the floor, and prune audit\n\
+                 entries past the policy's retention window. Runs daily on its own.\n\n\
+                 Options:\n\
+                   --dry-run    Show what would change without changing anything\n\
+                   --help, -h   Show this help message\n"
+                    .to_string(),
+            ));
+        }
+
+        let dry_run = args.args.iter().any(|a| a == "--dry-run");
+        let config = Self::load_server_config();
+        let report: RetentionReport = Self::maintenance_request_blocking(
+            &config,
+            &config.learnings_retention_url(dry_run),
+            true,
+        )
+        .map_err(|e| PluginError::custom(format!("Retention run failed: {}", e)))?;
+        Ok(CommandOutput::Text(format_retention_report(&report)))
+    }
+
+    fn cmd_learn_resolve(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn resolve <ID> <ID> (--keep <ID> | --dismiss)\n\n\
+                 Settle a conflict listed by `learn conflicts`.\n\n\
+                 Options:\n\
+                   --keep <ID>   Keep this learning; it supersedes the other\n\
+                   --dismiss     Not a real contradiction; inject both\n\
+                   --help, -h    Show this help message\n"
+                    .to_string(),
+            ));
+        }
+
+        let parse_id = |id: &str| {
+            id.parse::<uuid::Uuid>()
+                .map_err(|_| PluginError::InvalidInput(format!("Invalid learning ID: {}", id)))
+        };
+        let (Some(learning_id), Some(conflicting_id)) = (args.args.first(), args.args.get(1))
+        else {
+            return Err(PluginError::InvalidInput(
+                "Usage: vibes groove learn resolve <ID> <ID> (--keep <ID> | --dismiss)".into(),
+            ));
+        };
+        let keep = Self::parse_flag(&args.args, "--keep");
+        let dismiss = args.args.iter().any(|a| a == "--dismiss");
+        if keep.is_some() == dismiss {
+            return Err(PluginError::InvalidInput(
+                "Pass exactly one of --keep <ID> or --dismiss".into(),
+            ));
+        }
+
+        let request = ResolveConflictRequest {
+            learning_id: parse_id(learning_id)?,
+            conflicting_id: parse_id(conflicting_id)?,
+            keep: keep.as_deref().map(parse_id).transpose()?,
+        };
+        let config = Self::load_server_config();
+        let remaining = Self::resolve_conflict_blocking(&config, &request)
+            .map_err(|e| PluginError::custom(format!("Failed to resolve conflict: {}", e)))?;
+
+        let outcome = match request.keep {
+            Some(id) => format!("Kept {}; it now supersedes the other learning", id),
+            None => "Dismissed; both learnings will be injected".to_string(),
+        };
+        Ok(CommandOutput::Text(format!(
+            "{}\n{} conflicts still pending.\n",
+            outcome,
+            remaining.len()
+        )))
+    }
+
+    fn cmd_learn_import(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn import [FILE] [OPTIONS]\n\n\
+                 Import a learning pack exported from another machine.\n\n\
+                 Arguments:\n\
+                   [FILE]                  Pack to import (default: .vibes/plugins/groove/learning-pack.json)\n\n\
+                 Options:\n\
+                   --dry-run               Show what would be imported without writing anything\n\
+                   --on-conflict <POLICY>  skip, overwrite or keep-both (default: skip)\n\
+                   --remap <FROM=TO>       Rewrite project paths or scopes (repeatable), e.g.\n\
+                                           /home/alex/repo=/work/repo or\n\
+                                           /home/alex/repo=repo:github.com/org/repo\n\
+                   --help, -h              Show this help message\n"
+                    .to_string(),
+            ));
+        }
+
+        // Split positional file from flags; --remap may be given several times
+        let mut file = None;
+        let mut dry_run = false;
+        let mut conflict = ConflictPolicy::default();
+        let mut remaps = Vec::new();
+        let mut iter = args.args.iter();
+        while let Some(arg) = iter.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            match flag {
+                "--dry-run" => dry_run = true,
+                "--on-conflict" | "--remap" => {
+                    let value = inline
+                        .or_else(|| iter.next().cloned())
+                        .ok_or_else(|| PluginError::custom(format!("{} requires a value", flag)))?;
+                    if flag == "--on-conflict" {
+                        conflict = value
+                            .parse()
+                            .map_err(|e: GrooveError| PluginError::custom(e.to_string()))?;
+                    } else {
+                        remaps.push(
+                            ScopeRemap::parse(&value)
+                                .map_err(|e| PluginError::custom(e.to_string()))?,
+                        );
+                    }
+                }
+                _ if flag.starts_with("--") => {
+                    return Err(PluginError::custom(format!("Unknown option: {}", flag)));
+                }
+                _ => file = Some(std::path::PathBuf::from(arg)),
+            }
+        }
+
+        let file = match file {
+            Some(f) => f,
+            None => {
+                let cwd = std::env::current_dir()
+                    .map_err(|e| PluginError::custom(format!("Failed to get cwd: {}", e)))?;
+                GroovePaths::project_learning_pack(&cwd)
+            }
+        };
+
+        let json = std::fs::read_to_string(&file).map_err(|e| {
+            PluginError::custom(format!("Failed to read {}: {}", file.display(), e))
+        })?;
+        let pack =
+            LearningImporter::parse_pack(&json).map_err(|e| PluginError::custom(e.to_string()))?;
+
+        // The server only sees an upload, so the file source is checked here
+        let source = std::fs::canonicalize(&file).unwrap_or(file);
+        let policy = load_policy_or_default("groove-policy.toml");
+        check_source(&policy.import_export, &source.display().to_string())
+            .map_err(|e| PluginError::custom(e.to_string()))?;
+
+        let mut options = ImportOptions::new(source.display().to_string())
+            .with_conflict(conflict)
+            .with_dry_run(dry_run);
+        options.remaps = remaps;
+
+        let config = Self::load_server_config();
+        let report = Self::import_learnings_blocking(&config, &ImportRequest { pack, options })
+            .map_err(|e| PluginError::custom(format!("Failed to import learnings: {}", e)))?;
+
+        Ok(CommandOutput::Text(format_import_report(&report)))
+    }
+
+    // ─── Learn Enable/Disable Commands ────────────────────────────────
+
+    fn cmd_learn_enable(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn enable <id>\n\n\
+                 Re-enable a deprecated learning.\n\n\
+                 Arguments:\n\
+                   <id>         Learning ID to enable\n"
+                    .to_string(),
+            ));
+        }
+
+        let id = args
+            .args
+            .first()
+            .ok_or_else(|| PluginError::custom("Missing learning ID"))?;
+
+        let mut output = String::new();
+        output.push_str(&format!("Enabling learning: {}\n", id));
+        output.push_str("(Not yet implemented - requires server connection)\n");
+
+        Ok(CommandOutput::Text(output))
+    }
+
+    fn cmd_learn_disable(
+        &self,
+        args: &vibes_plugin_api::CommandArgs,
+    ) -> Result<CommandOutput, PluginError> {
+        if Self::wants_help(&args.args) {
+            return Ok(CommandOutput::Text(
+                "Usage: vibes groove learn disable <id> [reason]\n\n\
+                 Manually deprecate a learning.\n\n\
+                 Arguments:\n\
+                   <id>         Learning ID to disable\n\
+                   [reason]     Optional reason for deprecation\n"
+                    .to_string(),
+            ));
+        }
+
+        let id = args
+            .args
+            .first()
+            .ok_or_else(|| PluginError::custom("Missing learning ID"))?;
+        let reason = args.args.get(1).cloned();
+
+        let mut output = String::new();
+        output.push_str(&format!("Disabling learning: {}\n", id));
+        if let Some(r) = reason {
+            output.push_str(&format!("Reason: {}\n", r));
+        }
+        output.push_str("(Not yet implemented - requires server connection)\n");
+
+        Ok(CommandOutput::Text(output))
+    }
+
+    // ─── Attribution Commands ─────────────────────────────────────────
 
     fn cmd_attr_status(
         &self,
@@ -3138,7 +5079,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3176,7 +5118,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3232,7 +5175,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3290,7 +5234,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3345,7 +5290,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3396,7 +5342,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3438,7 +5385,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3507,7 +5455,7 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self.open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3565,7 +5513,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3634,7 +5583,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3675,7 +5625,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3729,7 +5680,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3782,7 +5734,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3840,7 +5793,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -3901,6 +5855,11 @@ impl GroovePlugin {
                 score: TrustLevel::OrganizationUnverified as u8,
                 description: "Enterprise content, not yet approved".to_string(),
             },
+            TrustLevelInfo {
+                name: "Imported".to_string(),
+                score: TrustLevel::Imported as u8,
+                description: "Imported learning pack, not yet reviewed".to_string(),
+            },
             TrustLevelInfo {
                 name: "PublicVerified".to_string(),
                 score: TrustLevel::PublicVerified as u8,
@@ -4211,7 +6170,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -4244,6 +6204,15 @@ impl GroovePlugin {
                 .await
                 .unwrap_or(0);
 
+            let index = store.active_embedding_index().await.map_err(|e| {
+                PluginError::custom(format!("Failed to read embedding index: {}", e))
+            })?;
+            let reembedding = store
+                .latest_reembed_job()
+                .await
+                .unwrap_or(None)
+                .filter(|job| job.status != ReembedStatus::Completed);
+
             RouteResponse::json(
                 200,
                 &LearningStatusResponse {
@@ -4259,9 +6228,10 @@ impl GroovePlugin {
                         preference: preference_count,
                     },
                     embedder: EmbedderStatus {
-                        model: "gte-small".to_string(),
-                        dimensions: 384,
-                        healthy: true, // TODO: Check actual embedder status
+                        model: index.model_id,
+                        dimensions: index.dimensions,
+                        healthy: self.config.embedding.available(),
+                        reembedding,
                     },
                     last_extraction: None, // TODO: Track last extraction time
                 },
@@ -4302,79 +6272,532 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
-                .await
-                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
-
-            // Get all learnings (with optional filters)
-            let all_learnings: Vec<Learning> = if let Some(ref scope_str) = scope_filter {
-                let scope = match scope_str.as_str() {
-                    "project" => Scope::Project("*".to_string()),
-                    "user" => Scope::User("*".to_string()),
-                    "global" => Scope::Global,
-                    _ => Scope::Project("*".to_string()),
-                };
-                store.find_by_scope(&scope).await.unwrap_or_default()
-            } else if let Some(ref cat_str) = category_filter {
-                let category = match cat_str.as_str() {
-                    "correction" => LearningCategory::Correction,
-                    "error_recovery" => LearningCategory::ErrorRecovery,
-                    "pattern" => LearningCategory::CodePattern,
-                    "preference" => LearningCategory::Preference,
-                    _ => LearningCategory::CodePattern,
-                };
-                store.find_by_category(&category).await.unwrap_or_default()
-            } else {
-                // Get all learnings by querying each scope
-                let mut all = Vec::new();
-                if let Ok(learnings) = store.find_by_scope(&Scope::Global).await {
-                    all.extend(learnings);
+            let store = self.open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+
+            // Get all learnings (with optional filters)
+            let all_learnings: Vec<Learning> = if let Some(ref scope_str) = scope_filter {
+                // A kind lists every scope of that kind, anything else is exact
+                let found = if Scope::KINDS.contains(&scope_str.as_str()) {
+                    store.find_by_scope_kind(scope_str).await
+                } else {
+                    match Scope::from_db_string(scope_str) {
+                        Ok(scope) => store.find_by_scope(&scope).await,
+                        Err(_) => {
+                            return RouteResponse::json(
+                                400,
+                                &ErrorResponse {
+                                    error: format!(
+                                        "Unknown scope: {} (expected one of {} or a scope such as repo:github.com/org/repo)",
+                                        scope_str,
+                                        Scope::KINDS.join(", ")
+                                    ),
+                                    code: "INVALID_SCOPE".to_string(),
+                                },
+                            );
+                        }
+                    }
+                };
+                found.unwrap_or_default()
+            } else if let Some(ref cat_str) = category_filter {
+                let category = match cat_str.as_str() {
+                    "correction" => LearningCategory::Correction,
+                    "error_recovery" => LearningCategory::ErrorRecovery,
+                    "pattern" => LearningCategory::CodePattern,
+                    "preference" => LearningCategory::Preference,
+                    _ => LearningCategory::CodePattern,
+                };
+                store.find_by_category(&category).await.unwrap_or_default()
+            } else {
+                // Get all learnings by querying each scope
+                let mut all = Vec::new();
+                if let Ok(learnings) = store.find_by_scope(&Scope::Global).await {
+                    all.extend(learnings);
+                }
+                all
+            };
+
+            let total = all_learnings.len() as u64;
+            let start = (page - 1) * per_page;
+            let end = (start + per_page).min(all_learnings.len());
+
+            let paginated: Vec<LearningSummary> = if start < all_learnings.len() {
+                all_learnings[start..end]
+                    .iter()
+                    .map(|l| LearningSummary {
+                        id: l.id.to_string(),
+                        category: l.category.as_str().to_string(),
+                        confidence: l.confidence,
+                        description: l.content.description.clone(),
+                        scope: l.scope.to_db_string(),
+                        created_at: l.created_at.to_rfc3339(),
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            RouteResponse::json(
+                200,
+                &LearningListResponse {
+                    learnings: paginated,
+                    total,
+                    page,
+                    per_page,
+                },
+            )
+        })
+    }
+
+    fn route_learnings_get(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        let id_str = request
+            .params
+            .get("id")
+            .ok_or_else(|| PluginError::InvalidInput("Missing id parameter".into()))?;
+
+        let id: uuid::Uuid = id_str
+            .parse()
+            .map_err(|_| PluginError::InvalidInput(format!("Invalid UUID: {}", id_str)))?;
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let store = self
+                .open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+
+            match store.get(id).await {
+                Ok(Some(learning)) => {
+                    let (source_type, session_id, message_index): (
+                        String,
+                        Option<String>,
+                        Option<u32>,
+                    ) = match &learning.source {
+                        crate::LearningSource::Transcript {
+                            session_id,
+                            message_index,
+                        } => (
+                            "transcript".to_string(),
+                            Some(session_id.clone()),
+                            Some(*message_index as u32),
+                        ),
+                        crate::LearningSource::UserCreated => ("manual".to_string(), None, None),
+                        crate::LearningSource::Imported { .. } => {
+                            ("import".to_string(), None, None)
+                        }
+                        crate::LearningSource::Promoted { .. } => {
+                            ("promoted".to_string(), None, None)
+                        }
+                        crate::LearningSource::EnterpriseCurated { .. } => {
+                            ("enterprise".to_string(), None, None)
+                        }
+                        crate::LearningSource::Consolidated { .. } => {
+                            ("consolidated".to_string(), None, None)
+                        }
+                    };
+
+                    RouteResponse::json(
+                        200,
+                        &LearningDetailResponse {
+                            id: learning.id.to_string(),
+                            scope: learning.scope.to_db_string(),
+                            category: learning.category.as_str().to_string(),
+                            description: learning.content.description.clone(),
+                            insight: learning.content.insight.clone(),
+                            pattern: learning.content.pattern.clone(),
+                            confidence: learning.confidence,
+                            created_at: learning.created_at.to_rfc3339(),
+                            updated_at: learning.updated_at.to_rfc3339(),
+                            source: LearningSourceResponse {
+                                source_type,
+                                session_id,
+                                message_index,
+                            },
+                            embedding_dimensions: None, // Embeddings stored separately
+                        },
+                    )
+                }
+                Ok(None) => RouteResponse::json(
+                    404,
+                    &ErrorResponse {
+                        error: format!("Learning not found: {}", id),
+                        code: "NOT_FOUND".to_string(),
+                    },
+                ),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Database error: {}", e),
+                        code: "DB_ERROR".to_string(),
+                    },
+                ),
+            }
+        })
+    }
+
+    fn route_learnings_delete(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        let id_str = request
+            .params
+            .get("id")
+            .ok_or_else(|| PluginError::InvalidInput("Missing id parameter".into()))?;
+
+        let id: uuid::Uuid = id_str
+            .parse()
+            .map_err(|_| PluginError::InvalidInput(format!("Invalid UUID: {}", id_str)))?;
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let store = self
+                .open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+
+            match store.delete(id).await {
+                Ok(true) => RouteResponse::json(
+                    200,
+                    &DeleteResponse {
+                        deleted: true,
+                        id: id.to_string(),
+                    },
+                ),
+                Ok(false) => RouteResponse::json(
+                    404,
+                    &ErrorResponse {
+                        error: format!("Learning not found: {}", id),
+                        code: "NOT_FOUND".to_string(),
+                    },
+                ),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Database error: {}", e),
+                        code: "DB_ERROR".to_string(),
+                    },
+                ),
+            }
+        })
+    }
+
+    fn route_learnings_import(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        let mut import_request: ImportRequest =
+            serde_json::from_slice(&request.body).map_err(|e| PluginError::Json(e.to_string()))?;
+        // Policy applies to where the pack reached us, not where the client
+        // says it came from
+        import_request.options.source = upload_source();
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let policy = load_policy_or_default("groove-policy.toml");
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let store = self
+                .open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+
+            let store = Arc::new(store);
+            let mut importer =
+                LearningImporter::new(store.clone(), policy.import_export, policy.scanning)
+                    .map_err(|e| PluginError::custom(e.to_string()))?;
+
+            // Without the embedding model, conflicts are matched by ID only and
+            // imported learnings wait for the re-embedding job
+            match configured_embedder(&self.config.embedding) {
+                Ok(Some(embedder)) => {
+                    importer = importer
+                        .with_dedup(Arc::new(SemanticDedup::new(embedder.clone())))
+                        .with_embedder(embedder, store);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Import dedup disabled: {}", e),
+            }
+
+            match importer
+                .import(&import_request.pack, &import_request.options)
+                .await
+            {
+                Ok(report) => RouteResponse::json(200, &report),
+                Err(GrooveError::Export(e)) => RouteResponse::json(
+                    403,
+                    &ErrorResponse {
+                        error: e,
+                        code: "POLICY_VIOLATION".to_string(),
+                    },
+                ),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Database error: {}", e),
+                        code: "DB_ERROR".to_string(),
+                    },
+                ),
+            }
+        })
+    }
+
+    fn route_consolidate_run(&self) -> Result<RouteResponse, PluginError> {
+        self.with_consolidator(|consolidator| async move { consolidator.run().await })
+    }
+
+    fn route_consolidate_apply(
+        &self,
+        request: &RouteRequest,
+    ) -> Result<RouteResponse, PluginError> {
+        let run_id = Self::consolidation_run_id(request)?;
+        self.with_consolidator(|consolidator| async move { consolidator.apply(run_id).await })
+    }
+
+    fn route_consolidate_revert(
+        &self,
+        request: &RouteRequest,
+    ) -> Result<RouteResponse, PluginError> {
+        let run_id = Self::consolidation_run_id(request)?;
+        self.with_consolidator(|consolidator| async move { consolidator.revert(run_id).await })
+    }
+
+    fn route_consolidate_runs(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        use crate::consolidation::ConsolidationStore;
+
+        let limit: usize = request
+            .query
+            .get("limit")
+            .and_then(|l| l.parse().ok())
+            .unwrap_or(20)
+            .clamp(1, 100);
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let store = self
+                .open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+
+            match store.list_consolidation_runs(limit).await {
+                Ok(runs) => RouteResponse::json(200, &runs),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Database error: {}", e),
+                        code: "DB_ERROR".to_string(),
+                    },
+                ),
+            }
+        })
+    }
+
+    fn consolidation_run_id(request: &RouteRequest) -> Result<uuid::Uuid, PluginError> {
+        let id_str = request
+            .params
+            .get("run_id")
+            .ok_or_else(|| PluginError::InvalidInput("Missing run_id parameter".into()))?;
+        id_str
+            .parse()
+            .map_err(|_| PluginError::InvalidInput(format!("Invalid UUID: {}", id_str)))
+    }
+
+    /// Run a consolidation operation and map its outcome to a response
+    fn with_consolidator<F, Fut>(&self, op: F) -> Result<RouteResponse, PluginError>
+    where
+        F: FnOnce(Consolidator) -> Fut,
+        Fut: std::future::Future<Output = crate::Result<ConsolidationReport>>,
+    {
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let consolidator = match self.open_store(&paths).await {
+                Ok(store) => consolidator(store, &self.config).await,
+                Err(e) => Err(e),
+            };
+            let result = match consolidator {
+                Ok(consolidator) => op(consolidator).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(report) => RouteResponse::json(200, &report),
+                Err(GrooveError::Consolidation(e)) => RouteResponse::json(
+                    409,
+                    &ErrorResponse {
+                        error: e,
+                        code: "CONSOLIDATION_CONFLICT".to_string(),
+                    },
+                ),
+                Err(GrooveError::Embedding(e)) => RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: e,
+                        code: "EMBEDDER_UNAVAILABLE".to_string(),
+                    },
+                ),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Database error: {}", e),
+                        code: "DB_ERROR".to_string(),
+                    },
+                ),
+            }
+        })
+    }
+
+    fn route_conflicts_list(&self) -> Result<RouteResponse, PluginError> {
+        self.with_contradiction_detector(|detector, _| async move { detector.pending().await })
+    }
+
+    fn route_conflicts_scan(&self) -> Result<RouteResponse, PluginError> {
+        let config = &self.config.embedding;
+        self.with_contradiction_detector(|detector, store| async move {
+            let embedder = require_embedder("a contradiction scan", &store, config).await?;
+            detector.backfill(embedder.as_ref()).await
+        })
+    }
+
+    fn route_conflicts_resolve(
+        &self,
+        request: &RouteRequest,
+    ) -> Result<RouteResponse, PluginError> {
+        let body: ResolveConflictRequest =
+            serde_json::from_slice(&request.body).map_err(|e| PluginError::Json(e.to_string()))?;
+        let resolution = match body.keep {
+            Some(id) => Resolution::Keep(id),
+            None => Resolution::Dismiss,
+        };
+
+        self.with_contradiction_detector(|detector, _| async move {
+            detector
+                .resolve(body.learning_id, body.conflicting_id, resolution)
+                .await?;
+            detector.pending().await
+        })
+    }
+
+    fn route_retention_run(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        let dry_run = request.query.get("dry_run").is_some_and(|v| v == "true");
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let result = match self.open_store(&paths).await {
+                Ok(store) => {
+                    let enforcer =
+                        retention_enforcer(store, &paths, &self.config.decay, self.event_log());
+                    if dry_run {
+                        enforcer.preview().await
+                    } else {
+                        enforcer.run().await
+                    }
                 }
-                all
-            };
-
-            let total = all_learnings.len() as u64;
-            let start = (page - 1) * per_page;
-            let end = (start + per_page).min(all_learnings.len());
-
-            let paginated: Vec<LearningSummary> = if start < all_learnings.len() {
-                all_learnings[start..end]
-                    .iter()
-                    .map(|l| LearningSummary {
-                        id: l.id.to_string(),
-                        category: l.category.as_str().to_string(),
-                        confidence: l.confidence,
-                        description: l.content.description.clone(),
-                        scope: l.scope.to_db_string(),
-                        created_at: l.created_at.to_rfc3339(),
-                    })
-                    .collect()
-            } else {
-                vec![]
+                Err(e) => Err(e),
             };
 
-            RouteResponse::json(
-                200,
-                &LearningListResponse {
-                    learnings: paginated,
-                    total,
-                    page,
-                    per_page,
-                },
-            )
+            match result {
+                Ok(report) => RouteResponse::json(200, &report),
+                Err(e) => RouteResponse::json(
+                    500,
+                    &ErrorResponse {
+                        error: format!("Retention error: {}", e),
+                        code: "RETENTION_ERROR".to_string(),
+                    },
+                ),
+            }
         })
     }
 
-    fn route_learnings_get(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
-        let id_str = request
-            .params
-            .get("id")
-            .ok_or_else(|| PluginError::InvalidInput("Missing id parameter".into()))?;
-
-        let id: uuid::Uuid = id_str
-            .parse()
-            .map_err(|_| PluginError::InvalidInput(format!("Invalid UUID: {}", id_str)))?;
-
+    /// Run a contradiction operation and map its outcome to a response
+    fn with_contradiction_detector<F, Fut, T>(&self, op: F) -> Result<RouteResponse, PluginError>
+    where
+        F: FnOnce(ContradictionDetector, Arc<CozoStore>) -> Fut,
+        Fut: std::future::Future<Output = crate::Result<T>>,
+        T: Serialize,
+    {
         let paths = match GroovePaths::new() {
             Some(p) => p,
             None => {
@@ -4392,63 +6815,30 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
-                .await
-                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
-
-            match store.get(id).await {
-                Ok(Some(learning)) => {
-                    let (source_type, session_id, message_index): (
-                        String,
-                        Option<String>,
-                        Option<u32>,
-                    ) = match &learning.source {
-                        crate::LearningSource::Transcript {
-                            session_id,
-                            message_index,
-                        } => (
-                            "transcript".to_string(),
-                            Some(session_id.clone()),
-                            Some(*message_index as u32),
-                        ),
-                        crate::LearningSource::UserCreated => ("manual".to_string(), None, None),
-                        crate::LearningSource::Imported { .. } => {
-                            ("import".to_string(), None, None)
-                        }
-                        crate::LearningSource::Promoted { .. } => {
-                            ("promoted".to_string(), None, None)
-                        }
-                        crate::LearningSource::EnterpriseCurated { .. } => {
-                            ("enterprise".to_string(), None, None)
-                        }
-                    };
-
-                    RouteResponse::json(
-                        200,
-                        &LearningDetailResponse {
-                            id: learning.id.to_string(),
-                            scope: learning.scope.to_db_string(),
-                            category: learning.category.as_str().to_string(),
-                            description: learning.content.description.clone(),
-                            insight: learning.content.insight.clone(),
-                            pattern: learning.content.pattern.clone(),
-                            confidence: learning.confidence,
-                            created_at: learning.created_at.to_rfc3339(),
-                            updated_at: learning.updated_at.to_rfc3339(),
-                            source: LearningSourceResponse {
-                                source_type,
-                                session_id,
-                                message_index,
-                            },
-                            embedding_dimensions: None, // Embeddings stored separately
-                        },
-                    )
+            let result = match self.open_store(&paths).await {
+                Ok(store) => {
+                    let store = Arc::new(store);
+                    let detector =
+                        contradiction_detector(store.clone(), &self.config.contradiction);
+                    op(detector, store).await
                 }
-                Ok(None) => RouteResponse::json(
-                    404,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(value) => RouteResponse::json(200, &value),
+                Err(GrooveError::Contradiction(e)) => RouteResponse::json(
+                    400,
                     &ErrorResponse {
-                        error: format!("Learning not found: {}", id),
-                        code: "NOT_FOUND".to_string(),
+                        error: e,
+                        code: "INVALID_RESOLUTION".to_string(),
+                    },
+                ),
+                Err(GrooveError::Embedding(e)) => RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: e,
+                        code: "EMBEDDER_UNAVAILABLE".to_string(),
                     },
                 ),
                 Err(e) => RouteResponse::json(
@@ -4462,15 +6852,19 @@ impl GroovePlugin {
         })
     }
 
-    fn route_learnings_delete(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
-        let id_str = request
-            .params
-            .get("id")
-            .ok_or_else(|| PluginError::InvalidInput("Missing id parameter".into()))?;
-
-        let id: uuid::Uuid = id_str
-            .parse()
-            .map_err(|_| PluginError::InvalidInput(format!("Invalid UUID: {}", id_str)))?;
+    fn route_learnings_search(&self, request: &RouteRequest) -> Result<RouteResponse, PluginError> {
+        let query = request
+            .query
+            .get("q")
+            .filter(|q| !q.trim().is_empty())
+            .cloned()
+            .ok_or_else(|| PluginError::InvalidInput("Missing q parameter".into()))?;
+        let limit: usize = request
+            .query
+            .get("limit")
+            .and_then(|l| l.parse().ok())
+            .unwrap_or(10)
+            .clamp(1, 100);
 
         let paths = match GroovePaths::new() {
             Some(p) => p,
@@ -4489,33 +6883,41 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
-            match store.delete(id).await {
-                Ok(true) => RouteResponse::json(
-                    200,
-                    &DeleteResponse {
-                        deleted: true,
-                        id: id.to_string(),
-                    },
-                ),
-                Ok(false) => RouteResponse::json(
-                    404,
-                    &ErrorResponse {
-                        error: format!("Learning not found: {}", id),
-                        code: "NOT_FOUND".to_string(),
-                    },
-                ),
-                Err(e) => RouteResponse::json(
-                    500,
-                    &ErrorResponse {
-                        error: format!("Database error: {}", e),
-                        code: "DB_ERROR".to_string(),
+            let results =
+                match search_learnings(&store, self.embedder.as_deref(), &query, limit).await {
+                    Ok(results) => results,
+                    Err(e) => {
+                        return RouteResponse::json(
+                            500,
+                            &ErrorResponse {
+                                error: format!("Database error: {}", e),
+                                code: "DB_ERROR".to_string(),
+                            },
+                        );
+                    }
+                };
+
+            let results = results
+                .into_iter()
+                .map(|(l, score)| LearningSearchHit {
+                    learning: LearningSummary {
+                        id: l.id.to_string(),
+                        category: l.category.as_str().to_string(),
+                        confidence: l.confidence,
+                        description: l.content.description,
+                        scope: l.scope.to_db_string(),
+                        created_at: l.created_at.to_rfc3339(),
                     },
-                ),
-            }
+                    score,
+                })
+                .collect();
+
+            RouteResponse::json(200, &LearningSearchResponse { query, results })
         })
     }
 
@@ -4675,7 +7077,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -4742,7 +7145,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -4811,7 +7215,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -4908,7 +7313,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5030,7 +7436,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5108,7 +7515,7 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self.open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5197,7 +7604,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5283,7 +7691,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5315,6 +7724,73 @@ impl GroovePlugin {
         })
     }
 
+    fn route_dashboard_learnings_search(
+        &self,
+        request: &RouteRequest,
+    ) -> Result<RouteResponse, PluginError> {
+        use crate::attribution::{AttributionStore, LearningStatus};
+        use crate::dashboard::{DashboardData, LearningBrief, LearningsData};
+
+        let query = request
+            .query
+            .get("q")
+            .filter(|q| !q.trim().is_empty())
+            .cloned()
+            .ok_or_else(|| PluginError::InvalidInput("Missing q parameter".into()))?;
+
+        let paths = match GroovePaths::new() {
+            Some(p) => p,
+            None => {
+                return RouteResponse::json(
+                    503,
+                    &ErrorResponse {
+                        error: "Groove not initialized.".to_string(),
+                        code: "NOT_INITIALIZED".to_string(),
+                    },
+                );
+            }
+        };
+
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
+
+        rt.block_on(async {
+            let store = self
+                .open_store(&paths)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
+            let attribution = CozoAttributionStore::new(store.db());
+
+            let results = search_learnings(&store, self.embedder.as_deref(), &query, 50)
+                .await
+                .map_err(|e| PluginError::custom(format!("Failed to search learnings: {}", e)))?;
+
+            let mut learnings = Vec::with_capacity(results.len());
+            for (learning, _) in results {
+                let (status, estimated_value) =
+                    match attribution.get_learning_value(learning.id).await {
+                        Ok(Some(value)) => (value.status, value.estimated_value),
+                        _ => (LearningStatus::Active, 0.0),
+                    };
+                learnings.push(LearningBrief {
+                    id: learning.id,
+                    content: learning.content.description,
+                    category: learning.category,
+                    scope: learning.scope,
+                    status,
+                    estimated_value,
+                    created_at: learning.created_at,
+                });
+            }
+
+            let total = learnings.len() as u32;
+            RouteResponse::json(
+                200,
+                &DashboardData::Learnings(LearningsData { learnings, total }),
+            )
+        })
+    }
+
     fn route_dashboard_attribution(
         &self,
         _request: &RouteRequest,
@@ -5328,6 +7804,15 @@ impl GroovePlugin {
         RouteResponse::json(200, &DashboardData::Health(HealthData::default()))
     }
 
+    fn route_dashboard_conflicts(&self) -> Result<RouteResponse, PluginError> {
+        use crate::dashboard::{ConflictsData, DashboardData};
+        self.with_contradiction_detector(|detector, _| async move {
+            Ok(DashboardData::Conflicts(ConflictsData::from(
+                detector.pending().await?,
+            )))
+        })
+    }
+
     fn route_dashboard_strategy_distributions(&self) -> Result<RouteResponse, PluginError> {
         use crate::dashboard::{DashboardData, StrategyDistributionsData};
         RouteResponse::json(
@@ -5352,7 +7837,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5386,7 +7872,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5410,7 +7897,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5436,7 +7924,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5464,7 +7953,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5489,7 +7979,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5518,7 +8009,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5572,7 +8064,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5596,7 +8089,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5625,7 +8119,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5653,7 +8148,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5690,7 +8186,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5732,7 +8229,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5800,7 +8298,8 @@ impl GroovePlugin {
             .map_err(|e| PluginError::custom(format!("Failed to create runtime: {}", e)))?;
 
         rt.block_on(async {
-            let store = CozoStore::open(&paths.db_path)
+            let store = self
+                .open_store(&paths)
                 .await
                 .map_err(|e| PluginError::custom(format!("Failed to open store: {}", e)))?;
 
@@ -5831,6 +8330,20 @@ mod tests {
         assert_eq!(manifest.name, "groove");
         assert!(!manifest.version.is_empty());
         assert!(manifest.description.contains("Continual learning"));
+        assert!(manifest.permissions.contains(&Permission::AnswerHooks));
+        assert!(manifest.permissions.contains(&Permission::RegisterRoutes));
+    }
+
+    #[test]
+    fn test_on_load_fails_without_route_permission() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        ctx.set_permissions(vec![Permission::ReadEvents]);
+
+        assert!(matches!(
+            plugin.on_load(&mut ctx),
+            Err(PluginError::PermissionDenied(_))
+        ));
     }
 
     #[test]
@@ -6028,11 +8541,11 @@ mod tests {
         assert_eq!(result.status, 200);
 
         let response: TrustHierarchyResponse = serde_json::from_slice(&result.body).unwrap();
-        assert_eq!(response.levels.len(), 7);
+        assert_eq!(response.levels.len(), 8);
         assert_eq!(response.levels[0].name, "Local");
         assert_eq!(response.levels[0].score, 100);
-        assert_eq!(response.levels[6].name, "Quarantined");
-        assert_eq!(response.levels[6].score, 0);
+        assert_eq!(response.levels[7].name, "Quarantined");
+        assert_eq!(response.levels[7].score, 0);
     }
 
     #[test]
@@ -6300,20 +8813,71 @@ mod tests {
     }
 
     #[test]
-    fn test_on_event_returns_results_when_enabled() {
+    fn test_on_event_returns_results_when_enabled() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        // Load plugin (should initialize processor)
+        plugin.on_load(&mut ctx).unwrap();
+
+        let raw = make_raw_event("test-session", "Hello, world!");
+        let results = plugin.on_event(raw, &mut ctx);
+
+        // Should emit lightweight event result
+        assert!(!results.is_empty(), "Should return assessment results");
+        assert_eq!(results[0].result_type, "lightweight");
+        assert_eq!(results[0].session_id, "test-session");
+    }
+
+    #[test]
+    fn test_on_load_registers_tui_views() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        plugin.on_load(&mut ctx).unwrap();
+
+        let views: Vec<_> = ctx
+            .pending_tui_views()
+            .iter()
+            .map(|v| v.id.as_str())
+            .collect();
+        assert_eq!(views, ["learnings", "attribution", "gaps"]);
+        assert!(
+            ctx.pending_tui_commands()
+                .iter()
+                .any(|c| c.name == "dismiss-gap")
+        );
+    }
+
+    #[test]
+    fn test_on_event_pushes_results_to_live_streams() {
         let mut plugin = GroovePlugin::default();
         let mut ctx = create_test_context();
-
-        // Load plugin (should initialize processor)
         plugin.on_load(&mut ctx).unwrap();
+        assert!(ctx.pending_streams().iter().any(|s| s.path == "/live"));
 
-        let raw = make_raw_event("test-session", "Hello, world!");
-        let results = plugin.on_event(raw, &mut ctx);
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let request = RouteRequest {
+            params: HashMap::new(),
+            query: HashMap::new(),
+            body: vec![],
+            headers: HashMap::new(),
+        };
+        plugin
+            .open_stream("/live", request, StreamHandle::new(1, tx), &mut ctx)
+            .unwrap();
 
-        // Should emit lightweight event result
-        assert!(!results.is_empty(), "Should return assessment results");
-        assert_eq!(results[0].result_type, "lightweight");
-        assert_eq!(results[0].session_id, "test-session");
+        let results = plugin.on_event(make_raw_event("test-session", "Hello"), &mut ctx);
+
+        let message = rx.try_recv().unwrap();
+        assert_eq!(
+            message.event.as_deref(),
+            Some(results[0].result_type.as_str())
+        );
+        let pushed: PluginAssessmentResult = serde_json::from_str(&message.data).unwrap();
+        assert_eq!(pushed, results[0]);
+
+        plugin.on_stream_closed(1, &mut ctx);
+        assert!(plugin.live.is_empty());
     }
 
     #[test]
@@ -6998,6 +9562,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_server_url_config_learnings_import_url() {
+        let config = ServerUrlConfig {
+            host: "localhost".to_string(),
+            port: 8080,
+        };
+        assert_eq!(
+            config.learnings_import_url(),
+            "http://localhost:8080/api/groove/learnings/import"
+        );
+    }
+
+    #[test]
+    fn test_server_url_config_learnings_search_url_encodes_query() {
+        let config = ServerUrlConfig {
+            host: "localhost".to_string(),
+            port: 8080,
+        };
+        assert_eq!(
+            config.learnings_search_url("E0502 --dry-run&x", Some(5)),
+            "http://localhost:8080/api/groove/learnings/search?q=E0502+--dry-run%26x&limit=5"
+        );
+        assert_eq!(
+            config.learnings_search_url("serde", None),
+            "http://localhost:8080/api/groove/learnings/search?q=serde"
+        );
+    }
+
+    #[test]
+    fn test_server_url_config_consolidation_urls() {
+        let config = ServerUrlConfig {
+            host: "localhost".to_string(),
+            port: 8080,
+        };
+        assert_eq!(
+            config.learnings_consolidate_url(),
+            "http://localhost:8080/api/groove/learnings/consolidate"
+        );
+        assert_eq!(
+            config.consolidation_runs_url(),
+            "http://localhost:8080/api/groove/learnings/consolidate/runs"
+        );
+        assert_eq!(
+            config.consolidation_run_url("abc", "revert"),
+            "http://localhost:8080/api/groove/learnings/consolidate/abc/revert"
+        );
+    }
+
+    #[test]
+    fn test_server_url_config_conflicts_urls() {
+        let config = ServerUrlConfig {
+            host: "localhost".to_string(),
+            port: 8080,
+        };
+        assert_eq!(
+            config.learnings_conflicts_url(),
+            "http://localhost:8080/api/groove/learnings/conflicts"
+        );
+        assert_eq!(
+            config.conflicts_scan_url(),
+            "http://localhost:8080/api/groove/learnings/conflicts/scan"
+        );
+        assert_eq!(
+            config.conflicts_resolve_url(),
+            "http://localhost:8080/api/groove/learnings/conflicts/resolve"
+        );
+    }
+
+    #[test]
+    fn test_server_url_config_retention_url() {
+        let config = ServerUrlConfig {
+            host: "localhost".to_string(),
+            port: 8080,
+        };
+        assert_eq!(
+            config.learnings_retention_url(false),
+            "http://localhost:8080/api/groove/learnings/retention"
+        );
+        assert_eq!(
+            config.learnings_retention_url(true),
+            "http://localhost:8080/api/groove/learnings/retention?dry_run=true"
+        );
+    }
+
     #[test]
     fn test_server_url_config_history_url_without_session() {
         let config = ServerUrlConfig {
@@ -7203,6 +9851,375 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_on_load_registers_learn_import() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        plugin.on_load(&mut ctx).unwrap();
+
+        let commands: Vec<_> = ctx
+            .pending_commands()
+            .iter()
+            .map(|c| c.path.join(" "))
+            .collect();
+        assert!(commands.contains(&"learn import".to_string()));
+
+        let routes = ctx.pending_routes();
+        assert!(
+            routes
+                .iter()
+                .any(|r| r.method == HttpMethod::Post && r.path == "/learnings/import")
+        );
+    }
+
+    #[test]
+    fn test_on_load_registers_learn_search_before_learning_by_id() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        plugin.on_load(&mut ctx).unwrap();
+
+        let commands: Vec<_> = ctx
+            .pending_commands()
+            .iter()
+            .map(|c| c.path.join(" "))
+            .collect();
+        assert!(commands.contains(&"learn search".to_string()));
+
+        // Routes match in registration order, so the literal path must come first
+        let get_paths: Vec<_> = ctx
+            .pending_routes()
+            .iter()
+            .filter(|r| r.method == HttpMethod::Get)
+            .map(|r| r.path.as_str())
+            .collect();
+        let position = |path: &str| get_paths.iter().position(|p| *p == path).unwrap();
+        assert!(position("/learnings/search") < position("/learnings/:id"));
+        assert!(position("/learnings/conflicts") < position("/learnings/:id"));
+        assert!(position("/dashboard/learnings/search") < position("/dashboard/learnings/:id"));
+    }
+
+    #[test]
+    fn test_on_load_registers_learn_consolidate() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        plugin.on_load(&mut ctx).unwrap();
+
+        let commands: Vec<_> = ctx
+            .pending_commands()
+            .iter()
+            .map(|c| c.path.join(" "))
+            .collect();
+        assert!(commands.contains(&"learn consolidate".to_string()));
+
+        let post_paths: Vec<_> = ctx
+            .pending_routes()
+            .iter()
+            .filter(|r| r.method == HttpMethod::Post)
+            .map(|r| r.path.as_str())
+            .collect();
+        assert!(post_paths.contains(&"/learnings/consolidate"));
+        assert!(post_paths.contains(&"/learnings/consolidate/:run_id/apply"));
+        assert!(post_paths.contains(&"/learnings/consolidate/:run_id/revert"));
+    }
+
+    #[test]
+    fn test_on_load_registers_learn_conflicts() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        plugin.on_load(&mut ctx).unwrap();
+
+        let commands: Vec<_> = ctx
+            .pending_commands()
+            .iter()
+            .map(|c| c.path.join(" "))
+            .collect();
+        assert!(commands.contains(&"learn conflicts".to_string()));
+        assert!(commands.contains(&"learn resolve".to_string()));
+
+        let post_paths: Vec<_> = ctx
+            .pending_routes()
+            .iter()
+            .filter(|r| r.method == HttpMethod::Post)
+            .map(|r| r.path.as_str())
+            .collect();
+        assert!(post_paths.contains(&"/learnings/conflicts/scan"));
+        assert!(post_paths.contains(&"/learnings/conflicts/resolve"));
+    }
+
+    #[test]
+    fn test_on_load_registers_learn_retention() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+
+        plugin.on_load(&mut ctx).unwrap();
+
+        let commands: Vec<_> = ctx
+            .pending_commands()
+            .iter()
+            .map(|c| c.path.join(" "))
+            .collect();
+        assert!(commands.contains(&"learn retention".to_string()));
+        assert!(
+            ctx.pending_routes()
+                .iter()
+                .any(|r| r.method == HttpMethod::Post && r.path == "/learnings/retention")
+        );
+    }
+
+    #[test]
+    fn test_learn_resolve_requires_one_outcome() {
+        let plugin = GroovePlugin::default();
+        let a = uuid::Uuid::now_v7().to_string();
+        let b = uuid::Uuid::now_v7().to_string();
+
+        let neither = vibes_plugin_api::CommandArgs {
+            args: vec![a.clone(), b.clone()],
+            ..Default::default()
+        };
+        assert!(plugin.cmd_learn_resolve(&neither).is_err());
+
+        let both = vibes_plugin_api::CommandArgs {
+            args: vec![a.clone(), b, "--keep".into(), a, "--dismiss".into()],
+            ..Default::default()
+        };
+        assert!(plugin.cmd_learn_resolve(&both).is_err());
+    }
+
+    #[test]
+    fn test_format_conflicts() {
+        let learning = |description: &str| {
+            Learning::new(
+                Scope::Global,
+                LearningCategory::Preference,
+                crate::types::LearningContent {
+                    description: description.to_string(),
+                    pattern: None,
+                    insight: String::new(),
+                },
+                crate::types::LearningSource::UserCreated,
+            )
+        };
+        let conflict = Conflict {
+            learning: learning("Never use unwrap in tests"),
+            conflicting: learning("Use unwrap in tests"),
+            confidence: 0.8,
+            detected_at: chrono::Utc::now(),
+        };
+
+        let output = format_conflicts(std::slice::from_ref(&conflict));
+        assert!(output.starts_with("1 pending conflicts"));
+        assert!(output.contains(&format!(
+            "{} [global] Never use unwrap in tests",
+            conflict.learning.id
+        )));
+        assert!(output.contains("[global] Use unwrap in tests"));
+        assert!(output.contains("confidence 0.80"));
+        assert!(format_conflicts(&[]).contains("No pending conflicts"));
+    }
+
+    #[test]
+    fn test_assessment_log_expires_tier_topics() {
+        use crate::assessment::iggy::log::topics;
+
+        let manager = Arc::new(IggyManager::new(crate::assessment::IggyConfig::default()));
+        let log = assessment_log(manager);
+
+        let retention = AssessmentConfig::default().retention;
+        assert_eq!(
+            log.topic_expiry(topics::LIGHTWEIGHT_TOPIC),
+            iggy::prelude::IggyExpiry::ExpireDuration(iggy::prelude::IggyDuration::new(
+                std::time::Duration::from_secs(retention.lightweight_days as u64 * 86_400)
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retention_enforcer_expires_assessment_events() {
+        use crate::assessment::{AssessmentContext, AssessmentEvent, LightweightEvent};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths = GroovePaths::from_base(temp_dir.path().to_path_buf());
+        let store = CozoStore::open(&paths.db_path).await.unwrap();
+
+        let mut context = AssessmentContext::new("old-session");
+        context.timestamp = chrono::Utc::now() - chrono::Duration::days(365);
+        let events = Arc::new(crate::assessment::InMemoryAssessmentLog::new());
+        events
+            .append(AssessmentEvent::Lightweight(LightweightEvent {
+                context,
+                message_idx: 0,
+                signals: vec![],
+                frustration_ema: 0.0,
+                success_ema: 1.0,
+                triggering_event_id: uuid::Uuid::now_v7(),
+            }))
+            .await
+            .unwrap();
+
+        let report =
+            retention_enforcer(store, &paths, &DecayConfig::default(), Some(events.clone()))
+                .run()
+                .await
+                .unwrap();
+
+        assert_eq!(report.events_expired.lightweight, 1);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_format_retention_report() {
+        let disabled = uuid::Uuid::now_v7();
+        let report = RetentionReport {
+            ran_at: chrono::Utc::now(),
+            dry_run: true,
+            learnings_scanned: 5,
+            decayed: vec![
+                crate::retention::DecayedLearning {
+                    learning_id: uuid::Uuid::now_v7(),
+                    description: "Prefer tracing over println".into(),
+                    from: 0.8,
+                    to: 0.4,
+                },
+                crate::retention::DecayedLearning {
+                    learning_id: disabled,
+                    description: "Use the old CI runner".into(),
+                    from: 0.2,
+                    to: 0.05,
+                },
+            ],
+            disabled: vec![disabled],
+            events_expired: crate::assessment::TierCounts::default(),
+            audit_entries_pruned: 0,
+        };
+
+        let output = format_retention_report(&report);
+        assert!(output.starts_with("Dry run"));
+        assert!(output.contains("Scanned 5 learnings: 2 decayed, 1 disabled"));
+        assert!(output.contains("0.80 -> 0.40  Prefer tracing over println"));
+        assert!(output.contains(&format!(
+            "{} 0.20 -> 0.05 (disabled)  Use the old CI runner",
+            disabled
+        )));
+        assert!(!output.contains("Expired"));
+    }
+
+    #[test]
+    fn test_format_reembed_progress() {
+        let now = chrono::Utc::now();
+        let mut job = ReembedJob {
+            id: uuid::Uuid::now_v7(),
+            index_name: "learning_embeddings_1".into(),
+            model_id: "ollama:nomic-embed-text".into(),
+            dimensions: 768,
+            status: ReembedStatus::Running,
+            total: 200,
+            embedded: 50,
+            error: None,
+            started_at: now,
+            updated_at: now,
+            finished_at: None,
+        };
+        assert_eq!(
+            format_reembed_progress(&job),
+            "Re-embedding to ollama:nomic-embed-text (768 dims): 50/200 (25%) - running"
+        );
+
+        job.status = ReembedStatus::Failed;
+        job.error = Some("connection refused".into());
+        assert!(format_reembed_progress(&job).ends_with("- failed: connection refused"));
+    }
+
+    #[test]
+    fn test_embedder_status_without_reembedding() {
+        let status: EmbedderStatus =
+            serde_json::from_str(r#"{"model":"gte-small","dimensions":384,"healthy":true}"#)
+                .unwrap();
+        assert!(status.reembedding.is_none());
+    }
+
+    #[test]
+    fn test_format_consolidation_report() {
+        let mut learning = Learning::new(
+            Scope::Global,
+            LearningCategory::ToolUsage,
+            crate::types::LearningContent {
+                description: "Run cargo fmt before committing".to_string(),
+                pattern: None,
+                insight: "Formatting drift fails CI".to_string(),
+            },
+            crate::types::LearningSource::UserCreated,
+        );
+        learning.confidence = 0.8;
+        let mut report = ConsolidationReport {
+            run_id: uuid::Uuid::now_v7(),
+            created_at: chrono::Utc::now(),
+            learnings_scanned: 12,
+            merges: vec![crate::MergeProposal {
+                generalized: learning,
+                usage: crate::types::UsageStats::default(),
+                members: vec![uuid::Uuid::now_v7(), uuid::Uuid::now_v7()],
+                min_similarity: 0.91,
+            }],
+            applied_at: None,
+            reverted_at: None,
+        };
+
+        let output = format_consolidation_report(&report);
+        assert!(output.contains("(proposed)"));
+        assert!(output.contains("Scanned 12 learnings: 1 merges covering 2 learnings"));
+        assert!(output.contains("[global] Run cargo fmt before committing"));
+        assert!(output.contains(&format!("--apply {}", report.run_id)));
+
+        report.applied_at = Some(chrono::Utc::now());
+        let output = format_consolidation_report(&report);
+        assert!(output.contains("(applied)"));
+        assert!(output.contains(&format!("--revert {}", report.run_id)));
+
+        report.reverted_at = Some(chrono::Utc::now());
+        assert_eq!(consolidation_status(&report), "reverted");
+        assert!(format_consolidation_runs(&[]).contains("No consolidation runs"));
+    }
+
+    #[test]
+    fn test_format_import_report() {
+        let existing = uuid::Uuid::now_v7();
+        let report = ImportReport {
+            dry_run: true,
+            stats: crate::ImportStats {
+                learnings_imported: 1,
+                learnings_skipped: 1,
+                ..Default::default()
+            },
+            learnings: vec![
+                crate::ImportedLearning {
+                    pack_id: uuid::Uuid::now_v7(),
+                    description: "Use cargo nextest".into(),
+                    scope: "project:/repo".into(),
+                    action: ImportAction::Created {
+                        id: uuid::Uuid::now_v7(),
+                    },
+                },
+                crate::ImportedLearning {
+                    pack_id: uuid::Uuid::now_v7(),
+                    description: "Prefer thiserror".into(),
+                    scope: "global".into(),
+                    action: ImportAction::Skipped { existing },
+                },
+            ],
+        };
+
+        let output = format_import_report(&report);
+
+        assert!(output.starts_with("Dry run"));
+        assert!(output.contains("Imported:    1"));
+        assert!(output.contains("[project:/repo] Use cargo nextest - created"));
+        assert!(output.contains(&format!("skipped (matches {})", existing)));
+    }
+
     #[test]
     fn test_handle_command_attr_status() {
         let mut plugin = GroovePlugin::default();
@@ -7214,6 +10231,123 @@ mod tests {
         assert!(result.is_ok(), "attr status command should succeed");
     }
 
+    #[test]
+    fn test_post_tool_use_records_recent_files() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        let hook = |file: &str| HookInput {
+            session_id: Some("sess-1".into()),
+            hook_type: "PostToolUse".into(),
+            project_path: None,
+            payload: serde_json::json!({
+                "tool_name": "Edit",
+                "tool_input": { "file_path": file },
+            }),
+        };
+
+        for i in 0..RECENT_FILES_LIMIT + 2 {
+            plugin.on_hook(&hook(&format!("src/{i}.rs")), &mut ctx);
+        }
+        plugin.on_hook(&hook("src/5.rs"), &mut ctx);
+
+        let files = &plugin.recent_files["sess-1"];
+        assert_eq!(files.len(), RECENT_FILES_LIMIT);
+        assert_eq!(files.front().unwrap(), "src/2.rs");
+        assert_eq!(files.back().unwrap(), "src/5.rs");
+    }
+
+    #[test]
+    fn test_session_end_drops_cached_scope_chains() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        for session in ["sess-1", "sess-2"] {
+            plugin.scope_chains.insert(
+                (session.into(), "/repo".into()),
+                ScopeChain::new().with_user("default"),
+            );
+        }
+
+        let hook = HookInput {
+            session_id: Some("sess-1".into()),
+            hook_type: "SessionEnd".into(),
+            project_path: None,
+            payload: serde_json::json!({}),
+        };
+        plugin.on_hook(&hook, &mut ctx);
+
+        let sessions: Vec<_> = plugin
+            .scope_chains
+            .keys()
+            .map(|(s, _)| s.as_str())
+            .collect();
+        assert_eq!(sessions, ["sess-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_session_end_queues_transcript_for_extraction() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        let extraction = SessionExtraction::new(
+            tokio::runtime::Handle::current(),
+            Arc::new(FileTranscriptFetcher::new()),
+        );
+        let sessions = extraction.sessions.clone();
+        plugin.extraction = Some(extraction);
+
+        let hook = HookInput {
+            session_id: Some("sess-1".into()),
+            hook_type: "SessionEnd".into(),
+            project_path: None,
+            payload: serde_json::json!({
+                "transcript_path": "/tmp/sess-1.jsonl",
+                "cwd": "/repo",
+            }),
+        };
+        plugin.on_hook(&hook, &mut ctx);
+
+        let mut consumer = sessions.consumer("test").await.unwrap();
+        let mut queued = Vec::new();
+        for _ in 0..50 {
+            queued = consumer
+                .poll(1, std::time::Duration::from_millis(100))
+                .await
+                .unwrap()
+                .events;
+            if !queued.is_empty() {
+                break;
+            }
+        }
+        let (_, event) = queued.first().expect("session queued");
+        assert_eq!(event.context.session_id.as_str(), "sess-1");
+        assert_eq!(
+            event.context.project_id.as_ref().map(|p| p.as_str()),
+            Some("/repo")
+        );
+    }
+
+    #[test]
+    fn test_session_end_without_transcript_queues_nothing() {
+        let mut plugin = GroovePlugin::default();
+        let mut ctx = create_test_context();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let extraction = SessionExtraction::new(
+            runtime.handle().clone(),
+            Arc::new(FileTranscriptFetcher::new()),
+        );
+        let sessions = extraction.sessions.clone();
+        plugin.extraction = Some(extraction);
+
+        let hook = HookInput {
+            session_id: Some("sess-1".into()),
+            hook_type: "SessionEnd".into(),
+            project_path: None,
+            payload: serde_json::json!({}),
+        };
+        plugin.on_hook(&hook, &mut ctx);
+
+        assert!(runtime.block_on(sessions.is_empty()));
+    }
+
     #[test]
     fn test_route_attr_status() {
         let plugin = GroovePlugin::default();
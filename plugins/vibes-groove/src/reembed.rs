@@ -0,0 +1,612 @@
+//! Embedding model versioning and re-embedding migrations
+//!
+//! Vectors from different embedding models are not comparable, even when
+//! their dimensions happen to match. Every model therefore gets its own
+//! vector relation and HNSW index, described by an [`EmbeddingIndex`], and
+//! exactly one index is active for search at a time.
+//!
+//! When the configured [`Embedder`] differs from the active index, the
+//! [`Reembedder`] builds a new index alongside it:
+//!
+//! - every learning without a vector in the new index is embedded in
+//!   batches, and progress is checkpointed to a [`ReembedJob`] after each
+//!   batch, so an interrupted migration resumes where it stopped
+//! - learnings stored while the migration runs land in the old index and
+//!   are picked up by the next batch
+//! - once every learning has a vector, the new index is activated in a
+//!   single write and the old one is dropped
+//!
+//! Search keeps using the old index until the swap, so a migration never
+//! leaves groove with a half-built index. A run can also stop after a number
+//! of batches (see [`Reembedder::with_max_batches`]) so callers that share
+//! the database can release it between steps.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::extraction::Embedder;
+use crate::store::LearningStore;
+use crate::{GrooveError, Learning, LearningId, Result};
+
+/// Vector relation created by the initial schema for gte-small embeddings
+pub const LEGACY_INDEX: &str = "learning_embeddings";
+
+/// Lifecycle of an embedding index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexState {
+    /// Used for search and for newly stored learnings
+    Active,
+    /// Being filled by a re-embedding migration
+    Building,
+    /// Replaced by a newer index
+    Retired,
+}
+
+impl IndexState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Building => "building",
+            Self::Retired => "retired",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "active" => Some(Self::Active),
+            "building" => Some(Self::Building),
+            "retired" => Some(Self::Retired),
+            _ => None,
+        }
+    }
+}
+
+/// A vector relation holding one model's embeddings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingIndex {
+    /// Name of the vector relation
+    pub name: String,
+    pub model_id: String,
+    pub dimensions: usize,
+    pub state: IndexState,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmbeddingIndex {
+    /// Whether `embedder` produces vectors comparable with this index
+    pub fn matches(&self, embedder: &dyn Embedder) -> bool {
+        self.model_id == embedder.model_id() && self.dimensions == embedder.dimensions()
+    }
+
+    /// Reject vectors that cannot be stored in or compared with this index
+    pub fn check_dimensions(&self, embedding: &[f32]) -> Result<()> {
+        if embedding.len() != self.dimensions {
+            return Err(GrooveError::Embedding(format!(
+                "Invalid embedding dimension: {} expects {}, got {}",
+                self.model_id,
+                self.dimensions,
+                embedding.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of a re-embedding migration so far
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReembedStatus {
+    Running,
+    Completed,
+    /// Stopped on an error; the next run resumes it
+    Failed,
+}
+
+/// Progress of one migration to a new embedding model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReembedJob {
+    pub id: Uuid,
+    /// Index being built
+    pub index_name: String,
+    pub model_id: String,
+    pub dimensions: usize,
+    pub status: ReembedStatus,
+    /// Learnings in the store
+    pub total: u64,
+    /// Learnings with a vector in the new index
+    pub embedded: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl ReembedJob {
+    fn new(id: Uuid, index: &EmbeddingIndex, total: u64) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            index_name: index.name.clone(),
+            model_id: index.model_id.clone(),
+            dimensions: index.dimensions,
+            status: ReembedStatus::Running,
+            total,
+            embedded: 0,
+            error: None,
+            started_at: now,
+            updated_at: now,
+            finished_at: None,
+        }
+    }
+
+    /// The index this job builds
+    fn index(&self) -> EmbeddingIndex {
+        EmbeddingIndex {
+            name: self.index_name.clone(),
+            model_id: self.model_id.clone(),
+            dimensions: self.dimensions,
+            state: IndexState::Building,
+            created_at: self.started_at,
+        }
+    }
+
+    /// Fraction of learnings embedded (0.0-1.0)
+    pub fn progress(&self) -> f64 {
+        if self.total == 0 {
+            return if self.status == ReembedStatus::Completed {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        (self.embedded as f64 / self.total as f64).min(1.0)
+    }
+
+    fn finish(&mut self, status: ReembedStatus, error: Option<String>) {
+        let now = Utc::now();
+        self.status = status;
+        self.error = error;
+        self.updated_at = now;
+        if status == ReembedStatus::Completed {
+            self.finished_at = Some(now);
+        }
+    }
+}
+
+/// Storage needed for versioned embeddings
+#[async_trait]
+pub trait EmbeddingStore: LearningStore {
+    /// The index used for search and newly stored vectors
+    async fn active_embedding_index(&self) -> Result<EmbeddingIndex>;
+
+    /// Create an empty index in the `Building` state
+    async fn create_embedding_index(
+        &self,
+        name: &str,
+        model_id: &str,
+        dimensions: usize,
+    ) -> Result<EmbeddingIndex>;
+
+    /// Up to `limit` learnings without a vector in `index`
+    async fn unembedded_learnings(&self, index: &str, limit: usize) -> Result<Vec<Learning>>;
+
+    /// Store a vector in `index`, recording the index's model with it
+    async fn store_indexed_embedding(
+        &self,
+        index: &EmbeddingIndex,
+        id: LearningId,
+        embedding: &[f32],
+    ) -> Result<()>;
+
+    /// Number of vectors in `index`
+    async fn embedding_count(&self, index: &str) -> Result<u64>;
+
+    /// Make `name` the active index and retire the previous one in one write
+    ///
+    /// Returns the name of the previously active index.
+    async fn activate_embedding_index(&self, name: &str) -> Result<Option<String>>;
+
+    /// Remove an index and its vectors
+    async fn drop_embedding_index(&self, name: &str) -> Result<()>;
+
+    async fn save_reembed_job(&self, job: &ReembedJob) -> Result<()>;
+
+    /// The most recently started migration
+    async fn latest_reembed_job(&self) -> Result<Option<ReembedJob>>;
+}
+
+/// Migrates the store to the embedder's model
+pub struct Reembedder {
+    store: Arc<dyn EmbeddingStore>,
+    embedder: Arc<dyn Embedder>,
+    batch_size: usize,
+    max_batches: Option<usize>,
+}
+
+impl Reembedder {
+    pub fn new(store: Arc<dyn EmbeddingStore>, embedder: Arc<dyn Embedder>) -> Self {
+        Self {
+            store,
+            embedder,
+            batch_size: 32,
+            max_batches: None,
+        }
+    }
+
+    /// Embed this many learnings between checkpoints
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Stop after this many batches, leaving the job running for the next run
+    #[must_use]
+    pub fn with_max_batches(mut self, max_batches: usize) -> Self {
+        self.max_batches = Some(max_batches.max(1));
+        self
+    }
+
+    /// Whether the active index was built with a different model
+    pub async fn needed(&self) -> Result<bool> {
+        let active = self.store.active_embedding_index().await?;
+        Ok(!active.matches(self.embedder.as_ref()))
+    }
+
+    /// Start or resume a migration to the embedder's model
+    ///
+    /// Returns `None` when the active index already matches the embedder.
+    /// The returned job is still `Running` if the batch limit was reached.
+    /// On error the job is saved as failed and the next run resumes it.
+    pub async fn run(&self) -> Result<Option<ReembedJob>> {
+        let active = self.store.active_embedding_index().await?;
+        let latest = self.store.latest_reembed_job().await?;
+
+        if active.matches(self.embedder.as_ref()) {
+            // Interrupted after the swap but before the job was closed
+            if let Some(mut job) = latest
+                && job.status != ReembedStatus::Completed
+                && job.index_name == active.name
+            {
+                job.embedded = self.store.embedding_count(&job.index_name).await?;
+                job.finish(ReembedStatus::Completed, None);
+                self.store.save_reembed_job(&job).await?;
+                return Ok(Some(job));
+            }
+            return Ok(None);
+        }
+
+        let mut job = match latest {
+            Some(mut job)
+                if job.status != ReembedStatus::Completed
+                    && job.model_id == self.embedder.model_id()
+                    && job.dimensions == self.embedder.dimensions() =>
+            {
+                info!(index = %job.index_name, embedded = job.embedded, "Resuming re-embedding");
+                job.status = ReembedStatus::Running;
+                job.error = None;
+                job
+            }
+            stale => {
+                if let Some(mut stale) = stale
+                    && stale.status != ReembedStatus::Completed
+                {
+                    self.discard(&stale.index_name).await;
+                    stale.finish(
+                        ReembedStatus::Failed,
+                        Some(format!(
+                            "Superseded by a migration to {}",
+                            self.embedder.model_id()
+                        )),
+                    );
+                    self.store.save_reembed_job(&stale).await?;
+                }
+
+                let id = Uuid::now_v7();
+                let name = format!("{LEGACY_INDEX}_{}", id.simple());
+                let index = self
+                    .store
+                    .create_embedding_index(
+                        &name,
+                        self.embedder.model_id(),
+                        self.embedder.dimensions(),
+                    )
+                    .await?;
+                info!(
+                    from = %active.model_id,
+                    to = %index.model_id,
+                    index = %index.name,
+                    "Starting re-embedding"
+                );
+                ReembedJob::new(id, &index, self.store.count().await?)
+            }
+        };
+        self.store.save_reembed_job(&job).await?;
+
+        match self.fill(&mut job).await {
+            Ok(true) => {}
+            Ok(false) => return Ok(Some(job)),
+            Err(e) => {
+                job.finish(ReembedStatus::Failed, Some(e.to_string()));
+                self.store.save_reembed_job(&job).await?;
+                return Err(e);
+            }
+        }
+
+        let previous = self.store.activate_embedding_index(&job.index_name).await?;
+        job.finish(ReembedStatus::Completed, None);
+        self.store.save_reembed_job(&job).await?;
+        if let Some(previous) = previous.filter(|p| *p != job.index_name) {
+            self.discard(&previous).await;
+        }
+
+        info!(
+            index = %job.index_name,
+            embedded = job.embedded,
+            "Re-embedding complete"
+        );
+        Ok(Some(job))
+    }
+
+    /// Embed learnings missing from the job's index, checkpointing each batch
+    ///
+    /// Returns whether every learning now has a vector.
+    async fn fill(&self, job: &mut ReembedJob) -> Result<bool> {
+        let index = job.index();
+        let mut batches = 0;
+        loop {
+            let batch = self
+                .store
+                .unembedded_learnings(&index.name, self.batch_size)
+                .await?;
+            if batch.is_empty() {
+                return Ok(true);
+            }
+            if self.max_batches.is_some_and(|max| batches >= max) {
+                return Ok(false);
+            }
+            batches += 1;
+
+            let texts: Vec<&str> = batch
+                .iter()
+                .map(|l| l.content.description.as_str())
+                .collect();
+            let vectors = self
+                .embedder
+                .embed_batch(&texts)
+                .await
+                .map_err(|e| GrooveError::Embedding(e.to_string()))?;
+            if vectors.len() != batch.len() {
+                return Err(GrooveError::Embedding(format!(
+                    "Embedder returned {} vectors for {} learnings",
+                    vectors.len(),
+                    batch.len()
+                )));
+            }
+            for (learning, vector) in batch.iter().zip(&vectors) {
+                self.store
+                    .store_indexed_embedding(&index, learning.id, vector)
+                    .await?;
+            }
+
+            job.embedded = self.store.embedding_count(&index.name).await?;
+            job.total = self.store.count().await?.max(job.embedded);
+            job.updated_at = Utc::now();
+            self.store.save_reembed_job(job).await?;
+        }
+    }
+
+    /// Drop an index that is no longer needed, logging rather than failing
+    async fn discard(&self, name: &str) {
+        if let Err(e) = self.store.drop_embedding_index(name).await {
+            warn!(index = %name, error = %e, "Failed to drop embedding index");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::{EmbedderError, EmbedderResult};
+    use crate::store::memory::MemoryStore;
+    use crate::{LearningCategory, LearningContent, LearningSource, Scope};
+    use std::sync::Mutex;
+
+    /// Embeds by text length; fails once `fail_after` texts were embedded
+    struct CountingEmbedder {
+        model_id: &'static str,
+        dims: usize,
+        fail_after: Option<usize>,
+        embedded: Mutex<usize>,
+    }
+
+    impl CountingEmbedder {
+        fn new(model_id: &'static str, dims: usize) -> Self {
+            Self {
+                model_id,
+                dims,
+                fail_after: None,
+                embedded: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Embedder for CountingEmbedder {
+        async fn embed(&self, text: &str) -> EmbedderResult<Vec<f32>> {
+            let mut embedded = self.embedded.lock().unwrap();
+            if self.fail_after.is_some_and(|limit| *embedded >= limit) {
+                return Err(EmbedderError::InferenceError("provider down".into()));
+            }
+            *embedded += 1;
+            let mut v = vec![0.0; self.dims];
+            v[0] = text.len() as f32;
+            Ok(v)
+        }
+
+        fn dimensions(&self) -> usize {
+            self.dims
+        }
+
+        fn model_id(&self) -> &str {
+            self.model_id
+        }
+    }
+
+    async fn store_with(count: usize) -> Arc<MemoryStore> {
+        let store = Arc::new(MemoryStore::default());
+        for i in 0..count {
+            let learning = Learning::new(
+                Scope::Global,
+                LearningCategory::Preference,
+                LearningContent {
+                    description: format!("learning {i}"),
+                    pattern: None,
+                    insight: String::new(),
+                },
+                LearningSource::UserCreated,
+            );
+            store.store(&learning).await.unwrap();
+        }
+        store
+    }
+
+    #[tokio::test]
+    async fn up_to_date_store_needs_no_migration() {
+        let store = store_with(3).await;
+        let reembedder = Reembedder::new(
+            store.clone(),
+            Arc::new(CountingEmbedder::new("gte-small", 384)),
+        );
+
+        assert!(!reembedder.needed().await.unwrap());
+        assert!(reembedder.run().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn migration_builds_new_index_and_swaps_it_in() {
+        let store = store_with(5).await;
+        let embedder = Arc::new(CountingEmbedder::new("mock:tiny", 8));
+        let reembedder = Reembedder::new(store.clone(), embedder.clone()).with_batch_size(2);
+
+        assert!(reembedder.needed().await.unwrap());
+        let job = reembedder.run().await.unwrap().unwrap();
+
+        assert_eq!(job.status, ReembedStatus::Completed);
+        assert_eq!((job.embedded, job.total), (5, 5));
+        assert_eq!(job.progress(), 1.0);
+        let active = store.active_embedding_index().await.unwrap();
+        assert_eq!(active.name, job.index_name);
+        assert_eq!(active.model_id, "mock:tiny");
+        assert_eq!(store.index_names(), vec![job.index_name.clone()]);
+        assert!(!reembedder.needed().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn failed_migration_resumes_where_it_stopped() {
+        let store = store_with(5).await;
+        let flaky = Arc::new(CountingEmbedder {
+            fail_after: Some(2),
+            ..CountingEmbedder::new("mock:tiny", 8)
+        });
+        let reembedder = Reembedder::new(store.clone(), flaky).with_batch_size(2);
+
+        assert!(reembedder.run().await.is_err());
+        let failed = store.latest_reembed_job().await.unwrap().unwrap();
+        assert_eq!(failed.status, ReembedStatus::Failed);
+        assert_eq!(failed.embedded, 2);
+        assert!(failed.error.is_some());
+        // Search still uses the old index
+        assert_eq!(
+            store.active_embedding_index().await.unwrap().name,
+            LEGACY_INDEX
+        );
+
+        let embedder = Arc::new(CountingEmbedder::new("mock:tiny", 8));
+        let job = Reembedder::new(store.clone(), embedder.clone())
+            .with_batch_size(2)
+            .run()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(job.id, failed.id);
+        assert_eq!(job.status, ReembedStatus::Completed);
+        assert_eq!(*embedder.embedded.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn batch_limit_leaves_job_running() {
+        let store = store_with(5).await;
+        let embedder = Arc::new(CountingEmbedder::new("mock:tiny", 8));
+        let step = || {
+            Reembedder::new(store.clone(), embedder.clone())
+                .with_batch_size(2)
+                .with_max_batches(1)
+        };
+
+        let job = step().run().await.unwrap().unwrap();
+        assert_eq!(job.status, ReembedStatus::Running);
+        assert_eq!(job.embedded, 2);
+        assert_eq!(job.progress(), 0.4);
+
+        let mut steps = 1;
+        while step().run().await.unwrap().unwrap().status == ReembedStatus::Running {
+            steps += 1;
+        }
+        assert_eq!(steps, 2);
+        assert!(!step().needed().await.unwrap());
+        assert_eq!(*embedder.embedded.lock().unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn changing_model_mid_migration_discards_partial_index() {
+        let store = store_with(3).await;
+        let flaky = Arc::new(CountingEmbedder {
+            fail_after: Some(1),
+            ..CountingEmbedder::new("mock:tiny", 8)
+        });
+        assert!(
+            Reembedder::new(store.clone(), flaky)
+                .with_batch_size(1)
+                .run()
+                .await
+                .is_err()
+        );
+        let abandoned = store.latest_reembed_job().await.unwrap().unwrap();
+
+        let job = Reembedder::new(
+            store.clone(),
+            Arc::new(CountingEmbedder::new("mock:big", 16)),
+        )
+        .run()
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_ne!(job.index_name, abandoned.index_name);
+        assert_eq!(store.index_names(), vec![job.index_name]);
+    }
+
+    #[test]
+    fn index_rejects_other_dimensions() {
+        let index = EmbeddingIndex {
+            name: LEGACY_INDEX.into(),
+            model_id: "gte-small".into(),
+            dimensions: 384,
+            state: IndexState::Active,
+            created_at: Utc::now(),
+        };
+        assert!(index.check_dimensions(&[0.0; 384]).is_ok());
+        assert!(index.check_dimensions(&[0.0; 8]).is_err());
+        assert_eq!(IndexState::parse("building"), Some(IndexState::Building));
+    }
+}
@@ -0,0 +1,621 @@
+//! Retention enforcement and confidence decay
+//!
+//! Left alone, groove only ever grows: assessment events pile up in every
+//! tier, the audit log is append-only, and a learning that was useful once
+//! keeps its confidence forever. A retention run:
+//!
+//! - expires assessment events older than their tier's [`RetentionConfig`]
+//!   window
+//! - prunes audit entries older than the audit policy's `retention_days`
+//! - halves the confidence of learnings every `half_life_days` once they
+//!   have gone `grace_days` without being injected (see
+//!   [`decayed_confidence`])
+//! - deprecates learnings whose confidence decays below `disable_below`, so
+//!   injection stops returning them
+//!
+//! Every change is written to the audit log as a [`ActorId::System`] entry,
+//! and a dry run reports what would change without touching anything.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::assessment::{AssessmentLog, RetentionConfig, TierCounts};
+use crate::attribution::{AttributionStore, LearningStatus, create_initial_learning_value};
+use crate::consolidation::ConsolidationStore;
+use crate::security::{
+    ActionOutcome, ActorId, AuditAction, AuditContext, AuditLog, AuditLogEntry, ResourceRef,
+};
+use crate::{GrooveError, Learning, LearningId, Result, UsageStats};
+
+/// Confidence changes smaller than this are left for a later run
+const MIN_DECAY_STEP: f64 = 0.01;
+
+/// Tiers whose events expire, in report order
+const EXPIRING_TIERS: &[&str] = &["lightweight", "medium", "heavy"];
+
+/// Configuration for the retention job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecayConfig {
+    /// Whether the periodic job runs
+    pub enabled: bool,
+    /// Hours between runs
+    pub interval_hours: u64,
+    /// Days a learning may go without injection before it starts decaying
+    pub grace_days: u32,
+    /// Days of inactivity that halve a learning's confidence
+    pub half_life_days: f64,
+    /// Learnings that decay below this confidence are deprecated
+    pub disable_below: f64,
+}
+
+impl Default for DecayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: 24,
+            grace_days: 14,
+            half_life_days: 30.0,
+            disable_below: 0.1,
+        }
+    }
+}
+
+/// A confidence change made (or proposed) by a retention run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecayedLearning {
+    pub learning_id: LearningId,
+    pub description: String,
+    pub from: f64,
+    pub to: f64,
+}
+
+/// Outcome of one retention run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionReport {
+    pub ran_at: DateTime<Utc>,
+    /// Whether changes were only reported
+    pub dry_run: bool,
+    pub learnings_scanned: usize,
+    /// Learnings whose confidence decayed
+    pub decayed: Vec<DecayedLearning>,
+    /// Learnings deprecated for decaying below the floor
+    pub disabled: Vec<LearningId>,
+    /// Assessment events expired, by tier
+    pub events_expired: TierCounts,
+    pub audit_entries_pruned: usize,
+}
+
+/// Confidence of `learning` at `now` after decay, if it changed enough to record
+///
+/// A learning is active until `grace_days` after it was last injected (or
+/// created, if it never was). From then on its confidence halves every
+/// `half_life_days`. Decay is measured from the later of that point and the
+/// learning's `updated_at`, so repeated runs compound rather than re-apply
+/// the same stretch of inactivity.
+pub fn decayed_confidence(
+    learning: &Learning,
+    usage: Option<&UsageStats>,
+    config: &DecayConfig,
+    now: DateTime<Utc>,
+) -> Option<f64> {
+    if config.half_life_days <= 0.0 {
+        return None;
+    }
+    let last_active = usage
+        .and_then(|u| u.last_used)
+        .map_or(learning.created_at, |used| used.max(learning.created_at));
+    let idle_since = last_active + Duration::days(i64::from(config.grace_days));
+    if now <= idle_since {
+        return None;
+    }
+
+    let start = learning.updated_at.max(idle_since);
+    let elapsed_days = (now - start).num_seconds() as f64 / 86_400.0;
+    let decayed = learning.confidence * 0.5_f64.powf(elapsed_days / config.half_life_days);
+    (learning.confidence - decayed >= MIN_DECAY_STEP).then_some(decayed)
+}
+
+/// Ages out assessment events and audit entries and decays idle learnings
+pub struct RetentionEnforcer {
+    store: Arc<dyn ConsolidationStore>,
+    attribution: Arc<dyn AttributionStore>,
+    audit: Arc<dyn AuditLog>,
+    config: DecayConfig,
+    events: Option<(Arc<dyn AssessmentLog>, RetentionConfig)>,
+    audit_retention_days: Option<u32>,
+}
+
+impl RetentionEnforcer {
+    pub fn new(
+        store: Arc<dyn ConsolidationStore>,
+        attribution: Arc<dyn AttributionStore>,
+        audit: Arc<dyn AuditLog>,
+        config: DecayConfig,
+    ) -> Self {
+        Self {
+            store,
+            attribution,
+            audit,
+            config,
+            events: None,
+            audit_retention_days: None,
+        }
+    }
+
+    /// Expire events in `log` according to `retention`
+    #[must_use]
+    pub fn with_event_log(
+        mut self,
+        log: Arc<dyn AssessmentLog>,
+        retention: RetentionConfig,
+    ) -> Self {
+        self.events = Some((log, retention));
+        self
+    }
+
+    /// Prune audit entries older than `days`
+    #[must_use]
+    pub fn with_audit_retention(mut self, days: u32) -> Self {
+        self.audit_retention_days = Some(days);
+        self
+    }
+
+    /// Enforce retention now
+    pub async fn run(&self) -> Result<RetentionReport> {
+        self.run_at(Utc::now(), false).await
+    }
+
+    /// Report what a run would change without changing anything
+    pub async fn preview(&self) -> Result<RetentionReport> {
+        self.run_at(Utc::now(), true).await
+    }
+
+    /// Enforce retention as of `now`
+    pub async fn run_at(&self, now: DateTime<Utc>, dry_run: bool) -> Result<RetentionReport> {
+        let mut report = RetentionReport {
+            ran_at: now,
+            dry_run,
+            learnings_scanned: 0,
+            decayed: Vec::new(),
+            disabled: Vec::new(),
+            events_expired: TierCounts::default(),
+            audit_entries_pruned: 0,
+        };
+
+        self.decay_learnings(now, dry_run, &mut report).await?;
+
+        if !dry_run {
+            if let Some((log, retention)) = &self.events {
+                report.events_expired = log.expire(retention, now).await?;
+                self.audit_expired_events(retention, &report.events_expired)
+                    .await?;
+            }
+            if let Some(days) = self.audit_retention_days {
+                let cutoff = now - Duration::days(i64::from(days));
+                report.audit_entries_pruned = self
+                    .audit
+                    .prune_before(cutoff)
+                    .await
+                    .map_err(|e| GrooveError::Retention(e.to_string()))?;
+                if report.audit_entries_pruned > 0 {
+                    self.record(
+                        AuditAction::AuditLogPruned,
+                        ResourceRef::Policy("audit".into()),
+                        serde_json::json!({
+                            "entries": report.audit_entries_pruned,
+                            "retention_days": days,
+                        }),
+                    )
+                    .await?;
+                }
+            }
+        }
+
+        info!(
+            dry_run,
+            scanned = report.learnings_scanned,
+            decayed = report.decayed.len(),
+            disabled = report.disabled.len(),
+            events_expired = report.events_expired.total(),
+            audit_pruned = report.audit_entries_pruned,
+            "Retention run complete"
+        );
+        Ok(report)
+    }
+
+    async fn decay_learnings(
+        &self,
+        now: DateTime<Utc>,
+        dry_run: bool,
+        report: &mut RetentionReport,
+    ) -> Result<()> {
+        let superseded = self.store.superseded_ids().await?;
+        for mut learning in self.store.all_learnings().await? {
+            if superseded.contains(&learning.id) {
+                continue;
+            }
+            let value = self.attribution.get_learning_value(learning.id).await?;
+            if value.as_ref().is_some_and(|v| {
+                matches!(
+                    v.status,
+                    LearningStatus::Disabled | LearningStatus::Deprecated { .. }
+                )
+            }) {
+                continue;
+            }
+            report.learnings_scanned += 1;
+
+            let usage = self.store.get_usage(learning.id).await?;
+            let Some(confidence) = decayed_confidence(&learning, usage.as_ref(), &self.config, now)
+            else {
+                continue;
+            };
+            let from = learning.confidence;
+            let disable = confidence < self.config.disable_below;
+            report.decayed.push(DecayedLearning {
+                learning_id: learning.id,
+                description: learning.content.description.clone(),
+                from,
+                to: confidence,
+            });
+            if disable {
+                report.disabled.push(learning.id);
+            }
+            if dry_run {
+                continue;
+            }
+
+            learning.confidence = confidence;
+            learning.updated_at = now;
+            self.store.update(&learning).await?;
+            self.record(
+                AuditAction::ConfidenceDecayed,
+                ResourceRef::Learning(learning.id),
+                serde_json::json!({ "from": from, "to": confidence }),
+            )
+            .await?;
+
+            if disable {
+                let reason = format!(
+                    "Confidence decayed below {:.2} without activation",
+                    self.config.disable_below
+                );
+                let mut value = value.unwrap_or_else(|| create_initial_learning_value(learning.id));
+                value.status = LearningStatus::Deprecated {
+                    reason: reason.clone(),
+                };
+                value.updated_at = now;
+                self.attribution.update_learning_value(&value).await?;
+                self.record(
+                    AuditAction::LearningAutoDisabled,
+                    ResourceRef::Learning(learning.id),
+                    serde_json::json!({ "confidence": confidence, "reason": reason }),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn audit_expired_events(
+        &self,
+        retention: &RetentionConfig,
+        expired: &TierCounts,
+    ) -> Result<()> {
+        for &tier in EXPIRING_TIERS {
+            let count = match tier {
+                "lightweight" => expired.lightweight,
+                "medium" => expired.medium,
+                _ => expired.heavy,
+            };
+            if count == 0 {
+                continue;
+            }
+            self.record(
+                AuditAction::AssessmentEventsExpired,
+                ResourceRef::AssessmentTier(tier.to_string()),
+                serde_json::json!({
+                    "events": count,
+                    "retention_days": retention.days_for(tier),
+                }),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn record(
+        &self,
+        action: AuditAction,
+        resource: ResourceRef,
+        details: serde_json::Value,
+    ) -> Result<()> {
+        let entry = AuditLogEntry::new(ActorId::System, action, resource, ActionOutcome::Success)
+            .with_context(AuditContext {
+                details: Some(details),
+                ..Default::default()
+            });
+        self.audit.log(entry).await.map_err(|e| {
+            warn!(error = %e, "Failed to write retention audit entry");
+            GrooveError::Retention(e.to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assessment::{
+        AssessmentContext, AssessmentEvent, InMemoryAssessmentLog, LightweightEvent, SessionId,
+    };
+    use crate::attribution::{AblationExperiment, AttributionRecord, LearningValue};
+    use crate::security::{AuditFilter, InMemoryAuditLog};
+    use crate::store::LearningStore;
+    use crate::store::memory::MemoryStore;
+    use crate::{LearningCategory, LearningContent, LearningSource, Scope};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryAttribution {
+        values: Mutex<HashMap<LearningId, LearningValue>>,
+    }
+
+    #[async_trait]
+    impl AttributionStore for MemoryAttribution {
+        async fn store_attribution(&self, _record: &AttributionRecord) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_attributions_for_learning(
+            &self,
+            _id: LearningId,
+        ) -> Result<Vec<AttributionRecord>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_attributions_for_session(
+            &self,
+            _id: &SessionId,
+        ) -> Result<Vec<AttributionRecord>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_learning_value(&self, id: LearningId) -> Result<Option<LearningValue>> {
+            Ok(self.values.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn update_learning_value(&self, value: &LearningValue) -> Result<()> {
+            self.values
+                .lock()
+                .unwrap()
+                .insert(value.learning_id, value.clone());
+            Ok(())
+        }
+
+        async fn list_learning_values(&self, _limit: usize) -> Result<Vec<LearningValue>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_experiment(&self, _id: LearningId) -> Result<Option<AblationExperiment>> {
+            Ok(None)
+        }
+
+        async fn update_experiment(&self, _exp: &AblationExperiment) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn learning_aged(days: i64, confidence: f64, now: DateTime<Utc>) -> Learning {
+        let mut learning = Learning::new(
+            Scope::Global,
+            LearningCategory::Preference,
+            LearningContent {
+                description: format!("learning from {days} days ago"),
+                pattern: None,
+                insight: "insight".into(),
+            },
+            LearningSource::UserCreated,
+        );
+        learning.confidence = confidence;
+        learning.created_at = now - Duration::days(days);
+        learning.updated_at = learning.created_at;
+        learning
+    }
+
+    struct Fixture {
+        store: Arc<MemoryStore>,
+        attribution: Arc<MemoryAttribution>,
+        audit: Arc<InMemoryAuditLog>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                store: Arc::new(MemoryStore::default()),
+                attribution: Arc::new(MemoryAttribution::default()),
+                audit: Arc::new(InMemoryAuditLog::new()),
+            }
+        }
+
+        fn enforcer(&self) -> RetentionEnforcer {
+            RetentionEnforcer::new(
+                self.store.clone(),
+                self.attribution.clone(),
+                self.audit.clone(),
+                DecayConfig::default(),
+            )
+        }
+
+        async fn audited(&self, action: AuditAction) -> usize {
+            self.audit
+                .query(AuditFilter {
+                    action: Some(action),
+                    ..Default::default()
+                })
+                .await
+                .unwrap()
+                .len()
+        }
+    }
+
+    #[test]
+    fn decay_waits_out_grace_period() {
+        let now = Utc::now();
+        let config = DecayConfig::default();
+        let fresh = learning_aged(10, 0.8, now);
+        assert_eq!(decayed_confidence(&fresh, None, &config, now), None);
+
+        let idle = learning_aged(14 + 30, 0.8, now);
+        let decayed = decayed_confidence(&idle, None, &config, now).unwrap();
+        assert!((decayed - 0.4).abs() < 1e-6, "one half-life: {decayed}");
+    }
+
+    #[test]
+    fn decay_restarts_from_last_use() {
+        let now = Utc::now();
+        let config = DecayConfig::default();
+        let learning = learning_aged(200, 0.8, now);
+        let usage = UsageStats {
+            last_used: Some(now - Duration::days(3)),
+            ..Default::default()
+        };
+        assert_eq!(
+            decayed_confidence(&learning, Some(&usage), &config, now),
+            None
+        );
+    }
+
+    #[test]
+    fn decay_compounds_from_last_update() {
+        let now = Utc::now();
+        let config = DecayConfig::default();
+        let mut learning = learning_aged(14 + 60, 0.4, now);
+        // Already decayed once, 30 days ago
+        learning.updated_at = now - Duration::days(30);
+        let decayed = decayed_confidence(&learning, None, &config, now).unwrap();
+        assert!(
+            (decayed - 0.2).abs() < 1e-6,
+            "one more half-life: {decayed}"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_decays_and_deprecates_idle_learnings() {
+        let fixture = Fixture::new();
+        let now = Utc::now();
+        let fresh = learning_aged(1, 0.8, now);
+        let idle = learning_aged(14 + 30, 0.8, now);
+        let dead = learning_aged(14 + 120, 0.8, now);
+        for learning in [&fresh, &idle, &dead] {
+            fixture.store.store(learning).await.unwrap();
+        }
+
+        let report = fixture.enforcer().run_at(now, false).await.unwrap();
+
+        assert_eq!(report.learnings_scanned, 3);
+        assert_eq!(report.decayed.len(), 2);
+        assert_eq!(report.disabled, vec![dead.id]);
+        let stored = fixture.store.get(idle.id).await.unwrap().unwrap();
+        assert!((stored.confidence - 0.4).abs() < 1e-6);
+        assert_eq!(stored.updated_at, now);
+        let value = fixture
+            .attribution
+            .get_learning_value(dead.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(value.status, LearningStatus::Deprecated { .. }));
+        assert_eq!(fixture.audited(AuditAction::ConfidenceDecayed).await, 2);
+        assert_eq!(fixture.audited(AuditAction::LearningAutoDisabled).await, 1);
+
+        // Deprecated learnings are left alone afterwards
+        let again = fixture.enforcer().run_at(now, false).await.unwrap();
+        assert_eq!(again.learnings_scanned, 2);
+        assert!(again.decayed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dry_run_changes_nothing() {
+        let fixture = Fixture::new();
+        let now = Utc::now();
+        let dead = learning_aged(14 + 120, 0.8, now);
+        fixture.store.store(&dead).await.unwrap();
+
+        let report = fixture.enforcer().run_at(now, true).await.unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.disabled, vec![dead.id]);
+        let stored = fixture.store.get(dead.id).await.unwrap().unwrap();
+        assert_eq!(stored.confidence, 0.8);
+        assert!(
+            fixture
+                .attribution
+                .get_learning_value(dead.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            fixture
+                .audit
+                .query(AuditFilter::default())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn run_expires_events_and_prunes_audit_log() {
+        let fixture = Fixture::new();
+        let now = Utc::now();
+
+        let event = |session: &str, age_days: i64| {
+            let mut context = AssessmentContext::new(session);
+            context.timestamp = now - Duration::days(age_days);
+            AssessmentEvent::Lightweight(LightweightEvent {
+                context,
+                message_idx: 0,
+                signals: vec![],
+                frustration_ema: 0.0,
+                success_ema: 1.0,
+                triggering_event_id: uuid::Uuid::now_v7(),
+            })
+        };
+        let events = Arc::new(InMemoryAssessmentLog::new());
+        events.append(event("old-session", 10)).await.unwrap();
+        events.append(event("recent-session", 1)).await.unwrap();
+
+        let mut stale = AuditLogEntry::new(
+            ActorId::System,
+            AuditAction::PolicyLoaded,
+            ResourceRef::Policy("test".into()),
+            ActionOutcome::Success,
+        );
+        stale.timestamp = now - Duration::days(120);
+        fixture.audit.log(stale).await.unwrap();
+
+        let report = fixture
+            .enforcer()
+            .with_event_log(events.clone(), RetentionConfig::default())
+            .with_audit_retention(90)
+            .run_at(now, false)
+            .await
+            .unwrap();
+
+        assert_eq!(report.events_expired.lightweight, 1);
+        assert_eq!(report.audit_entries_pruned, 1);
+        assert_eq!(fixture.audited(AuditAction::PolicyLoaded).await, 0);
+        assert_eq!(
+            fixture.audited(AuditAction::AssessmentEventsExpired).await,
+            1
+        );
+        assert_eq!(fixture.audited(AuditAction::AuditLogPruned).await, 1);
+    }
+}
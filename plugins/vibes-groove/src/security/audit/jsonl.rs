@@ -5,6 +5,7 @@
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tokio::fs::{File, OpenOptions};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 
@@ -138,6 +139,44 @@ impl AuditLog for JsonlAuditLog {
 
         Ok(results)
     }
+
+    async fn prune_before(&self, cutoff: DateTime<Utc>) -> SecurityResult<usize> {
+        if !self.path.exists() {
+            return Ok(0);
+        }
+
+        let contents = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| SecurityError::AuditLog(format!("failed to read audit log: {}", e)))?;
+
+        let mut kept = String::with_capacity(contents.len());
+        let mut pruned = 0;
+        for line in contents.lines() {
+            // Unparseable lines are kept; pruning never destroys what it can't read
+            match Self::parse_line(line) {
+                Some(entry) if entry.timestamp < cutoff => pruned += 1,
+                None if line.trim().is_empty() => {}
+                _ => {
+                    kept.push_str(line);
+                    kept.push('\n');
+                }
+            }
+        }
+        if pruned == 0 {
+            return Ok(0);
+        }
+
+        // Write aside and rename so a crash never leaves a truncated log
+        let tmp = self.path.with_extension("jsonl.tmp");
+        tokio::fs::write(&tmp, kept)
+            .await
+            .map_err(|e| SecurityError::AuditLog(format!("failed to write audit log: {}", e)))?;
+        tokio::fs::rename(&tmp, &self.path)
+            .await
+            .map_err(|e| SecurityError::AuditLog(format!("failed to replace audit log: {}", e)))?;
+
+        Ok(pruned)
+    }
 }
 
 #[cfg(test)]
@@ -317,6 +356,44 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_jsonl_prune_before() {
+        let (_dir, log) = create_test_log().await;
+
+        let mut old = AuditLogEntry::new(
+            ActorId::System,
+            AuditAction::ConfidenceDecayed,
+            ResourceRef::Learning(uuid::Uuid::new_v4()),
+            ActionOutcome::Success,
+        );
+        old.timestamp = Utc::now() - Duration::days(40);
+        log.log(old).await.unwrap();
+        log.log(AuditLogEntry::new(
+            ActorId::System,
+            AuditAction::LearningAutoDisabled,
+            ResourceRef::Learning(uuid::Uuid::new_v4()),
+            ActionOutcome::Success,
+        ))
+        .await
+        .unwrap();
+
+        let pruned = log
+            .prune_before(Utc::now() - Duration::days(30))
+            .await
+            .unwrap();
+
+        assert_eq!(pruned, 1);
+        let results = log.query(AuditFilter::default()).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].action, AuditAction::LearningAutoDisabled);
+        assert_eq!(
+            log.prune_before(Utc::now() - Duration::days(30))
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
     #[tokio::test]
     async fn test_jsonl_creates_parent_dir() {
         let dir = TempDir::new().unwrap();
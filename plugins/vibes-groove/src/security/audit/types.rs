@@ -55,6 +55,12 @@ pub enum AuditAction {
     PolicyLoaded,
     PolicyChanged,
     RescanTriggered,
+
+    // Retention
+    ConfidenceDecayed,
+    LearningAutoDisabled,
+    AssessmentEventsExpired,
+    AuditLogPruned,
 }
 
 /// Reference to a resource
@@ -64,6 +70,8 @@ pub enum ResourceRef {
     Policy(String),
     Session(String),
     Import(String),
+    /// Assessment events of one tier
+    AssessmentTier(String),
 }
 
 /// Context for audit entry
@@ -144,6 +152,9 @@ pub trait AuditLog: Send + Sync {
 
     /// Query audit entries
     async fn query(&self, filter: AuditFilter) -> SecurityResult<Vec<AuditLogEntry>>;
+
+    /// Drop entries recorded before `cutoff`, returning how many were removed
+    async fn prune_before(&self, cutoff: DateTime<Utc>) -> SecurityResult<usize>;
 }
 
 /// In-memory audit log for testing
@@ -206,6 +217,13 @@ impl AuditLog for InMemoryAuditLog {
 
         Ok(results)
     }
+
+    async fn prune_before(&self, cutoff: DateTime<Utc>) -> SecurityResult<usize> {
+        let mut entries = self.entries.write().await;
+        let before = entries.len();
+        entries.retain(|e| e.timestamp >= cutoff);
+        Ok(before - entries.len())
+    }
 }
 
 #[cfg(test)]
@@ -286,6 +304,37 @@ mod tests {
         assert!(json.contains("Failed"));
     }
 
+    #[tokio::test]
+    async fn test_in_memory_prune_before() {
+        let log = InMemoryAuditLog::new();
+        let mut old = AuditLogEntry::new(
+            ActorId::System,
+            AuditAction::ConfidenceDecayed,
+            ResourceRef::Learning(uuid::Uuid::new_v4()),
+            ActionOutcome::Success,
+        );
+        old.timestamp = Utc::now() - chrono::Duration::days(40);
+        log.log(old).await.unwrap();
+        log.log(AuditLogEntry::new(
+            ActorId::System,
+            AuditAction::AuditLogPruned,
+            ResourceRef::Policy("audit".into()),
+            ActionOutcome::Success,
+        ))
+        .await
+        .unwrap();
+
+        let pruned = log
+            .prune_before(Utc::now() - chrono::Duration::days(30))
+            .await
+            .unwrap();
+
+        assert_eq!(pruned, 1);
+        let entries = log.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::AuditLogPruned);
+    }
+
     #[test]
     fn test_audit_filter_default() {
         let filter = AuditFilter::default();
@@ -34,11 +34,11 @@ pub use audit::{
     InMemoryAuditLog, JsonlAuditLog, ResourceRef,
 };
 pub use policy::{
-    AuditPolicy, CapturePolicy, FilePolicyProvider, IdentityPolicy, ImportExportPolicy,
-    InjectionPolicy, MemoryPolicyProvider, Policy, PolicyChangeAction, PolicyProvider,
-    PresentationPolicy, QuarantineAction, QuarantinePolicy, ScanPatterns, ScanningPolicy,
-    TiersPolicy, WrapperConfig, WrapperType, load_policy_from_file, load_policy_or_default,
-    parse_policy, validate_policy,
+    AuditPolicy, CapturePolicy, ConsolidationPolicy, FilePolicyProvider, IdentityPolicy,
+    ImportExportPolicy, InjectionPolicy, MemoryPolicyProvider, Policy, PolicyChangeAction,
+    PolicyProvider, PresentationPolicy, QuarantineAction, QuarantinePolicy, ScanPatterns,
+    ScanningPolicy, TiersPolicy, WrapperConfig, WrapperType, load_policy_from_file,
+    load_policy_or_default, parse_policy, validate_policy,
 };
 
 pub use injector::{InjectableContent, InjectionResult, InjectorConfig, SecureInjector};
@@ -23,6 +23,8 @@ pub struct Policy {
     pub audit: AuditPolicy,
     #[serde(default)]
     pub quarantine: QuarantinePolicy,
+    #[serde(default)]
+    pub consolidation: ConsolidationPolicy,
 }
 
 /// Identity and versioning for the policy
@@ -268,6 +270,25 @@ impl Default for QuarantinePolicy {
     }
 }
 
+/// Learning consolidation policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsolidationPolicy {
+    /// Apply merges as soon as they are proposed instead of waiting for review
+    pub auto_apply: bool,
+    /// Allow merging learnings from different scopes into a broader one
+    pub allow_scope_generalization: bool,
+}
+
+impl Default for ConsolidationPolicy {
+    fn default() -> Self {
+        Self {
+            auto_apply: false,
+            allow_scope_generalization: true,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +299,7 @@ mod tests {
         assert!(policy.tiers.allow_personal_tier);
         assert!(policy.injection.allow_personal_injection);
         assert_eq!(policy.audit.retention_days, 30);
+        assert!(!policy.consolidation.auto_apply);
     }
 
     #[test]
@@ -334,7 +334,7 @@ mod tests {
         let learning = make_learning();
         let secure = SecureLearning::from_import(learning, "patterns.json");
 
-        assert_eq!(secure.trust.level, TrustLevel::PublicUnverified);
+        assert_eq!(secure.trust.level, TrustLevel::Imported);
         assert!(!secure.is_quarantined());
     }
 
@@ -19,6 +19,8 @@ pub enum TrustLevel {
     PublicUnverified = 10,
     /// Public verified - community content, verified by community
     PublicVerified = 30,
+    /// Imported - loaded from a learning pack file, not yet reviewed
+    Imported = 40,
     /// Organization unverified - enterprise content, not yet approved
     OrganizationUnverified = 50,
     /// Organization verified - enterprise content, curator approved
@@ -47,6 +49,7 @@ impl TrustLevel {
             Self::Quarantined => "quarantined",
             Self::PublicUnverified => "public_unverified",
             Self::PublicVerified => "public_verified",
+            Self::Imported => "imported",
             Self::OrganizationUnverified => "organization_unverified",
             Self::OrganizationVerified => "organization_verified",
             Self::PrivateCloud => "private_cloud",
@@ -63,6 +66,7 @@ impl std::str::FromStr for TrustLevel {
             "quarantined" => Ok(Self::Quarantined),
             "public_unverified" => Ok(Self::PublicUnverified),
             "public_verified" => Ok(Self::PublicVerified),
+            "imported" => Ok(Self::Imported),
             "organization_unverified" => Ok(Self::OrganizationUnverified),
             "organization_verified" => Ok(Self::OrganizationVerified),
             "private_cloud" => Ok(Self::PrivateCloud),
@@ -164,7 +168,7 @@ impl TrustContext {
     /// Create a trust context for imported content
     pub fn imported(source: impl Into<String>) -> Self {
         Self {
-            level: TrustLevel::PublicUnverified,
+            level: TrustLevel::Imported,
             source: TrustSource::Imported {
                 source: source.into(),
                 imported_at: Utc::now(),
@@ -206,7 +210,8 @@ mod tests {
         assert!(TrustLevel::Local > TrustLevel::PrivateCloud);
         assert!(TrustLevel::PrivateCloud > TrustLevel::OrganizationVerified);
         assert!(TrustLevel::OrganizationVerified > TrustLevel::OrganizationUnverified);
-        assert!(TrustLevel::OrganizationUnverified > TrustLevel::PublicVerified);
+        assert!(TrustLevel::OrganizationUnverified > TrustLevel::Imported);
+        assert!(TrustLevel::Imported > TrustLevel::PublicVerified);
         assert!(TrustLevel::PublicVerified > TrustLevel::PublicUnverified);
         assert!(TrustLevel::PublicUnverified > TrustLevel::Quarantined);
     }
@@ -238,6 +243,7 @@ mod tests {
         assert!(!TrustLevel::Local.requires_scanning());
         assert!(!TrustLevel::OrganizationVerified.requires_scanning());
         assert!(TrustLevel::OrganizationUnverified.requires_scanning());
+        assert!(TrustLevel::Imported.requires_scanning());
         assert!(TrustLevel::PublicUnverified.requires_scanning());
     }
 
@@ -247,6 +253,7 @@ mod tests {
             TrustLevel::Quarantined,
             TrustLevel::PublicUnverified,
             TrustLevel::PublicVerified,
+            TrustLevel::Imported,
             TrustLevel::OrganizationUnverified,
             TrustLevel::OrganizationVerified,
             TrustLevel::PrivateCloud,
@@ -268,7 +275,7 @@ mod tests {
     #[test]
     fn test_trust_context_imported() {
         let ctx = TrustContext::imported("rust-patterns.json");
-        assert_eq!(ctx.level, TrustLevel::PublicUnverified);
+        assert_eq!(ctx.level, TrustLevel::Imported);
     }
 
     #[test]
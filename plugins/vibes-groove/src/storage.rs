@@ -97,6 +97,11 @@ mod tests {
             ablation: crate::AblationConfig::default(),
             aggregation: crate::AggregationConfig::default(),
             openworld: crate::config::OpenWorldConfig::default(),
+            retrieval: crate::inject::RetrievalConfig::default(),
+            consolidation: crate::consolidation::ConsolidationConfig::default(),
+            contradiction: crate::contradiction::ContradictionConfig::default(),
+            decay: crate::retention::DecayConfig::default(),
+            embedding: crate::extraction::EmbeddingConfig::default(),
         }
     }
 
@@ -164,6 +169,11 @@ mod tests {
             ablation: crate::AblationConfig::default(),
             aggregation: crate::AggregationConfig::default(),
             openworld: crate::OpenWorldConfig::default(),
+            retrieval: crate::inject::RetrievalConfig::default(),
+            consolidation: crate::consolidation::ConsolidationConfig::default(),
+            contradiction: crate::contradiction::ContradictionConfig::default(),
+            decay: crate::retention::DecayConfig::default(),
+            embedding: crate::extraction::EmbeddingConfig::default(),
         };
 
         let storage = GrooveStorage::new(&config)
@@ -219,6 +229,11 @@ mod tests {
             ablation: crate::AblationConfig::default(),
             aggregation: crate::AggregationConfig::default(),
             openworld: crate::config::OpenWorldConfig::default(),
+            retrieval: crate::inject::RetrievalConfig::default(),
+            consolidation: crate::consolidation::ConsolidationConfig::default(),
+            contradiction: crate::contradiction::ContradictionConfig::default(),
+            decay: crate::retention::DecayConfig::default(),
+            embedding: crate::extraction::EmbeddingConfig::default(),
         };
 
         let storage = GrooveStorage::new(&config)
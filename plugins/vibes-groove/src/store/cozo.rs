@@ -4,7 +4,7 @@
 //! RocksDB backend. It handles database creation, schema initialization,
 //! and migrations.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -13,13 +13,20 @@ use chrono::{DateTime, Utc};
 use cozo::{DataValue, DbInstance, NamedRows, Vector};
 use ndarray::Array1;
 
+use super::hybrid::{RRF_K, fts_query, reciprocal_rank_fusion};
 use super::schema::MIGRATIONS;
+use crate::reembed::{EmbeddingIndex, IndexState};
 use crate::{
     AdaptiveParam, GrooveError, Learning, LearningCategory, LearningContent, LearningId,
-    LearningRelation, LearningSource, RelationType, Result, Scope, SystemParam, UsageStats,
+    LearningRelation, LearningSource, RelationType, Result, Scope, ScopeChain, SystemParam,
+    UsageStats,
 };
 
 /// CozoDB-backed learning store
+///
+/// RocksDB allows one open handle per database, so a process opens the store
+/// once and clones it; clones share the database.
+#[derive(Clone)]
 pub struct CozoStore {
     db: Arc<DbInstance>,
     initialized: bool,
@@ -295,6 +302,37 @@ impl CozoStore {
         Ok(learnings)
     }
 
+    /// Find all learnings with a kind of scope, e.g. every `repo:` scope
+    ///
+    /// `kind` is one of [`Scope::KINDS`].
+    pub async fn find_by_scope_kind(&self, kind: &str) -> Result<Vec<Learning>> {
+        if !Scope::KINDS.contains(&kind) {
+            return Err(GrooveError::InvalidScope(kind.to_string()));
+        }
+        let prefix = if kind == "global" {
+            kind.to_string()
+        } else {
+            format!("{kind}:")
+        };
+
+        let mut params = BTreeMap::new();
+        params.insert("prefix".to_string(), DataValue::Str(prefix.into()));
+        let query = r#"?[id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json] :=
+                *learning{id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json},
+                starts_with(scope, $prefix)"#;
+
+        let rows = self.run_query(query, params).await?;
+
+        let mut learnings = Vec::new();
+        for row in &rows.rows {
+            if let Some(learning) = self.row_to_learning(row)? {
+                learnings.push(learning);
+            }
+        }
+
+        Ok(learnings)
+    }
+
     /// Find learnings by category
     pub async fn find_by_category(&self, category: &LearningCategory) -> Result<Vec<Learning>> {
         let category_str = category.as_str();
@@ -338,13 +376,22 @@ impl CozoStore {
         );
         self.run_mutation(&usage_query, Default::default()).await?;
 
-        // Delete from learning_embeddings table
-        let embeddings_query = format!(
-            "?[learning_id] <- [['{}']]:rm learning_embeddings {{learning_id}}",
+        // Delete from every embedding index, including one being built
+        for index in self.embedding_indexes().await? {
+            let embeddings_query = format!(
+                "?[learning_id] <- [['{}']]:rm {} {{learning_id}}",
+                id_str,
+                relation_name(&index.name)?
+            );
+            self.run_mutation(&embeddings_query, Default::default())
+                .await?;
+        }
+        let meta_query = format!(
+            r#"?[index_name, learning_id] := *embedding_meta{{index_name, learning_id}}, learning_id = '{}'
+            :rm embedding_meta {{index_name, learning_id}}"#,
             id_str
         );
-        self.run_mutation(&embeddings_query, Default::default())
-            .await?;
+        self.run_mutation(&meta_query, Default::default()).await?;
 
         Ok(true)
     }
@@ -656,22 +703,52 @@ impl CozoStore {
 
     // ===== Embedding/Semantic Search Implementation =====
 
-    /// Expected embedding dimension (matches GteSmall / all-MiniLM-L6-v2)
-    const EMBEDDING_DIM: usize = 384;
+    /// The index used for search and newly stored vectors
+    pub async fn active_embedding_index(&self) -> Result<EmbeddingIndex> {
+        let query = r#"?[name, model_id, dimensions, state, created_at] :=
+            *embedding_index{name, model_id, dimensions, state, created_at},
+            state = 'active'"#;
+
+        let rows = self.run_query(query, Default::default()).await?;
+        rows.rows
+            .first()
+            .map(|row| row_to_embedding_index(row))
+            .transpose()?
+            .ok_or_else(|| GrooveError::Database("No active embedding index".into()))
+    }
+
+    /// All embedding indexes, whatever their state
+    async fn embedding_indexes(&self) -> Result<Vec<EmbeddingIndex>> {
+        let query = r#"?[name, model_id, dimensions, state, created_at] :=
+            *embedding_index{name, model_id, dimensions, state, created_at}"#;
+
+        let rows = self.run_query(query, Default::default()).await?;
+        rows.rows
+            .iter()
+            .map(|row| row_to_embedding_index(row))
+            .collect()
+    }
 
-    /// Store embedding vector for a learning
+    /// Store embedding vector for a learning in the active index
     ///
     /// # Errors
-    /// Returns an error if the embedding dimension is not exactly 384
+    /// Returns an error if the embedding dimension does not match the
+    /// active index
     pub async fn store_embedding(&self, learning_id: LearningId, embedding: &[f32]) -> Result<()> {
-        // Validate embedding dimension
-        if embedding.len() != Self::EMBEDDING_DIM {
-            return Err(GrooveError::Database(format!(
-                "Invalid embedding dimension: expected {}, got {}",
-                Self::EMBEDDING_DIM,
-                embedding.len()
-            )));
-        }
+        let index = self.active_embedding_index().await?;
+        self.store_indexed_embedding(&index, learning_id, embedding)
+            .await
+    }
+
+    /// Store embedding vector for a learning in a specific index
+    pub async fn store_indexed_embedding(
+        &self,
+        index: &EmbeddingIndex,
+        learning_id: LearningId,
+        embedding: &[f32],
+    ) -> Result<()> {
+        index.check_dimensions(embedding)?;
+        let relation = relation_name(&index.name)?;
 
         // Use CozoDB parameters with proper Vector type
         let mut params = BTreeMap::new();
@@ -682,11 +759,37 @@ impl CozoStore {
         // Convert to ndarray Array1 for the Vector::F32 type
         let array: Array1<f32> = Array1::from_vec(embedding.to_vec());
         params.insert("embedding".to_string(), DataValue::Vec(Vector::F32(array)));
+        params.insert(
+            "index_name".to_string(),
+            DataValue::Str(index.name.clone().into()),
+        );
+        params.insert(
+            "model_id".to_string(),
+            DataValue::Str(index.model_id.clone().into()),
+        );
+        params.insert(
+            "dimensions".to_string(),
+            DataValue::from(index.dimensions as i64),
+        );
+        params.insert(
+            "embedded_at".to_string(),
+            DataValue::from(Utc::now().timestamp()),
+        );
 
-        let query = r#"?[learning_id, embedding] <- [[$learning_id, $embedding]]
-            :put learning_embeddings { learning_id => embedding }"#;
+        // Vector and its model are written in one transaction
+        let query = format!(
+            r#"{{
+                ?[learning_id, embedding] <- [[$learning_id, $embedding]]
+                :put {relation} {{ learning_id => embedding }}
+            }}
+            {{
+                ?[index_name, learning_id, model_id, dimensions, embedded_at] <-
+                    [[$index_name, $learning_id, $model_id, $dimensions, $embedded_at]]
+                :put embedding_meta {{ index_name, learning_id => model_id, dimensions, embedded_at }}
+            }}"#
+        );
 
-        self.run_mutation(query, params).await?;
+        self.run_mutation(&query, params).await?;
         Ok(())
     }
 
@@ -696,20 +799,17 @@ impl CozoStore {
     /// Distance ranges from 0 (identical) to 2 (opposite).
     ///
     /// # Errors
-    /// Returns an error if the embedding dimension is not exactly 384
+    /// Returns an error if the embedding dimension does not match the
+    /// active index
     pub async fn semantic_search(
         &self,
         embedding: &[f32],
         limit: usize,
     ) -> Result<Vec<(Learning, f64)>> {
-        // Validate embedding dimension
-        if embedding.len() != Self::EMBEDDING_DIM {
-            return Err(GrooveError::Database(format!(
-                "Invalid embedding dimension: expected {}, got {}",
-                Self::EMBEDDING_DIM,
-                embedding.len()
-            )));
-        }
+        // Vectors from other models are not comparable with the index
+        let index = self.active_embedding_index().await?;
+        index.check_dimensions(embedding)?;
+        let relation = relation_name(&index.name)?;
 
         // Use CozoDB parameters with proper Vector type
         let mut params = BTreeMap::new();
@@ -720,15 +820,17 @@ impl CozoStore {
         // HNSW search query
         // ef: 50 provides a good balance of speed and accuracy
         // The distance is bound via the bind_distance parameter
-        let query = r#"?[learning_id, distance] := ~learning_embeddings:semantic_idx {
+        let query = format!(
+            r#"?[learning_id, distance] := ~{relation}:semantic_idx {{
                 learning_id |
                 query: $query_vec,
                 k: $k,
                 ef: 50,
                 bind_distance: distance
-            }"#;
+            }}"#
+        );
 
-        let rows = self.run_query(query, params).await?;
+        let rows = self.run_query(&query, params).await?;
 
         // Collect IDs and distances first (avoids N+1 queries)
         let mut id_distances: Vec<(LearningId, f64)> = Vec::new();
@@ -761,6 +863,90 @@ impl CozoStore {
         Ok(results)
     }
 
+    /// Lexical search using the full-text index
+    ///
+    /// Matches any query term against description and insight. Returns
+    /// learnings with their TF-IDF score (higher = more relevant); Cozo's
+    /// full-text index has no BM25 scoring.
+    pub async fn lexical_search(&self, query: &str, limit: usize) -> Result<Vec<(Learning, f64)>> {
+        let Some(fts) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+
+        let mut params = BTreeMap::new();
+        params.insert("q".to_string(), DataValue::Str(fts.into()));
+        params.insert("k".to_string(), DataValue::from(limit as i64));
+
+        let query = r#"?[id, score] := ~learning:text_idx {
+                id |
+                query: $q,
+                k: $k,
+                score_kind: 'tf_idf',
+                bind_score: score
+            }
+            :order -score"#;
+
+        let rows = self.run_query(query, params).await?;
+
+        let mut id_scores: Vec<(LearningId, f64)> = Vec::new();
+        for row in &rows.rows {
+            if let Some(id_str) = row[0].get_str()
+                && let Ok(id) = uuid::Uuid::parse_str(id_str)
+            {
+                id_scores.push((id, row[1].get_float().unwrap_or(0.0)));
+            }
+        }
+
+        let ids: Vec<LearningId> = id_scores.iter().map(|(id, _)| *id).collect();
+        let learning_map: std::collections::HashMap<LearningId, Learning> = self
+            .get_many(&ids)
+            .await?
+            .into_iter()
+            .map(|l| (l.id, l))
+            .collect();
+
+        Ok(id_scores
+            .into_iter()
+            .filter_map(|(id, score)| learning_map.get(&id).cloned().map(|l| (l, score)))
+            .collect())
+    }
+
+    /// Hybrid search fusing the full-text and HNSW rankings
+    ///
+    /// Each retriever contributes up to three times `limit` candidates so a
+    /// learning ranked modestly by both can still surface. Returns learnings
+    /// with their reciprocal rank fusion score (higher = more relevant).
+    pub async fn hybrid_search(
+        &self,
+        query: &str,
+        embedding: Option<&[f32]>,
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        let candidates = limit.saturating_mul(3);
+
+        let lexical = self.lexical_search(query, candidates).await?;
+        let semantic = match embedding {
+            Some(embedding) => self.semantic_search(embedding, candidates).await?,
+            None => Vec::new(),
+        };
+
+        let rankings: [Vec<LearningId>; 2] = [
+            lexical.iter().map(|(l, _)| l.id).collect(),
+            semantic.iter().map(|(l, _)| l.id).collect(),
+        ];
+        let mut learning_map: std::collections::HashMap<LearningId, Learning> = lexical
+            .into_iter()
+            .chain(semantic)
+            .map(|(l, _)| (l.id, l))
+            .collect();
+
+        Ok(reciprocal_rank_fusion(&rankings, RRF_K)
+            .into_iter()
+            .filter_map(|(id, score)| learning_map.remove(&id).map(|l| (l, score)))
+            .take(limit)
+            .collect())
+    }
+
     /// Helper to convert a database row to a SystemParam struct
     fn row_to_system_param(&self, row: &[DataValue]) -> Result<Option<SystemParam>> {
         if row.len() < 7 {
@@ -1073,16 +1259,7 @@ impl CozoStore {
         threshold: f64,
         limit: usize,
     ) -> Result<Vec<(Learning, f64)>> {
-        // Validate embedding dimension
-        if embedding.len() != Self::EMBEDDING_DIM {
-            return Err(GrooveError::Database(format!(
-                "Invalid embedding dimension: expected {}, got {}",
-                Self::EMBEDDING_DIM,
-                embedding.len()
-            )));
-        }
-
-        // Use semantic_search and filter by threshold
+        // Use semantic_search (which validates the dimension) and filter by threshold
         // semantic_search returns cosine distance (0 = identical, 2 = opposite)
         // Convert threshold (similarity) to max_distance: distance = 2 - 2*similarity
         // For similarity >= threshold: distance <= 2 - 2*threshold
@@ -1105,53 +1282,99 @@ impl CozoStore {
         Ok(filtered)
     }
 
-    /// Find learnings suitable for injection based on session context
+    /// Find learnings for injection across a scope chain
     ///
-    /// If a context embedding is provided, returns learnings ordered by relevance.
-    /// Otherwise returns learnings by confidence and recency.
+    /// With a context embedding, candidates come from semantic search;
+    /// otherwise each scope contributes its most confident learnings. The
+    /// candidates are then resolved by [`ScopeChain::resolve`], with
+    /// `Supersedes` relations marking overridden learnings, and only the
+    /// higher-ranked side of each pending `Contradicts` relation is kept.
     pub async fn find_for_injection(
         &self,
-        scope: &Scope,
+        scopes: &ScopeChain,
         context_embedding: Option<&[f32]>,
         limit: usize,
     ) -> Result<Vec<Learning>> {
-        if let Some(embedding) = context_embedding {
-            // Use semantic search filtered by scope
-            let results = self.semantic_search(embedding, limit * 3).await?;
-
-            // Filter by scope and take limit
-            let scope_str = scope.to_db_string();
-            let filtered: Vec<Learning> = results
+        let candidates = if let Some(embedding) = context_embedding {
+            // Over-fetch, since out-of-chain and shadowed results are dropped
+            self.semantic_search(embedding, limit * 3)
+                .await?
                 .into_iter()
-                .filter(|(l, _)| l.scope.to_db_string() == scope_str)
                 .map(|(l, _)| l)
-                .take(limit)
-                .collect();
-
-            Ok(filtered)
+                .collect()
         } else {
-            // Fallback to scope-based retrieval ordered by confidence
-            let scope_str = scope.to_db_string();
-            let query = format!(
-                r#"?[id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json] :=
-                    *learning{{id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json}},
-                    scope == '{}'
-                :order -confidence, -updated_at
-                :limit {}"#,
-                scope_str, limit
-            );
-
-            let rows = self.run_query(&query, Default::default()).await?;
+            let mut candidates = Vec::new();
+            for scope in scopes.scopes() {
+                let query = format!(
+                    r#"?[id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json] :=
+                        *learning{{id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json}},
+                        scope == '{}'
+                    :order -confidence, -updated_at
+                    :limit {}"#,
+                    scope.to_db_string(),
+                    limit
+                );
 
-            let mut learnings = Vec::new();
-            for row in &rows.rows {
-                if let Some(learning) = self.row_to_learning(row)? {
-                    learnings.push(learning);
+                let rows = self.run_query(&query, Default::default()).await?;
+                for row in &rows.rows {
+                    if let Some(learning) = self.row_to_learning(row)? {
+                        candidates.push(learning);
+                    }
                 }
             }
+            candidates
+        };
+
+        let superseded = self.superseded_by(&candidates).await?;
+        let contradictions: Vec<(LearningId, LearningId)> = self
+            .contradiction_relations()
+            .await?
+            .iter()
+            .map(|r| (r.from_id, r.to_id))
+            .collect();
+        let mut resolved =
+            drop_contradicted(scopes.resolve(candidates, &superseded), &contradictions);
+        resolved.truncate(limit);
+        Ok(resolved)
+    }
+
+    /// All pending `Contradicts` relations
+    async fn contradiction_relations(&self) -> Result<Vec<LearningRelation>> {
+        let query = format!(
+            r#"?[from_id, relation_type, to_id, weight, created_at] :=
+                *learning_relations{{from_id, relation_type, to_id, weight, created_at}},
+                relation_type = '{}'"#,
+            RelationType::Contradicts.as_str()
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        let mut relations = Vec::new();
+        for row in &rows.rows {
+            if let Some(relation) = self.row_to_relation(row)? {
+                relations.push(relation);
+            }
+        }
+        Ok(relations)
+    }
+
+    /// IDs of learnings that any of `learnings` supersedes
+    async fn superseded_by(&self, learnings: &[Learning]) -> Result<HashSet<LearningId>> {
+        let mut superseded = HashSet::new();
+        for learning in learnings {
+            let query = format!(
+                r#"?[to_id] := *learning_relations{{from_id, relation_type, to_id}}, from_id = '{}', relation_type = '{}'"#,
+                learning.id,
+                RelationType::Supersedes.as_str()
+            );
 
-            Ok(learnings)
+            let rows = self.run_query(&query, Default::default()).await?;
+            superseded.extend(
+                rows.rows
+                    .iter()
+                    .filter_map(|row| row[0].get_str().and_then(|s| uuid::Uuid::parse_str(s).ok())),
+            );
         }
+        Ok(superseded)
     }
 
     /// Count learnings by scope
@@ -1197,6 +1420,116 @@ impl CozoStore {
     }
 }
 
+// =============================================================================
+// LearningStore / ParamStore Implementation
+// =============================================================================
+
+// Inherent methods take precedence, so `CozoStore::x` below never recurses.
+#[async_trait]
+impl super::LearningStore for CozoStore {
+    async fn store(&self, learning: &Learning) -> Result<LearningId> {
+        CozoStore::store(self, learning).await
+    }
+
+    async fn get(&self, id: LearningId) -> Result<Option<Learning>> {
+        CozoStore::get(self, id).await
+    }
+
+    async fn find_by_scope(&self, scope: &Scope) -> Result<Vec<Learning>> {
+        CozoStore::find_by_scope(self, scope).await
+    }
+
+    async fn find_by_category(&self, category: &LearningCategory) -> Result<Vec<Learning>> {
+        CozoStore::find_by_category(self, category).await
+    }
+
+    async fn semantic_search(
+        &self,
+        embedding: &[f32],
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        CozoStore::semantic_search(self, embedding, limit).await
+    }
+
+    async fn hybrid_search(
+        &self,
+        query: &str,
+        embedding: Option<&[f32]>,
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        CozoStore::hybrid_search(self, query, embedding, limit).await
+    }
+
+    async fn update_usage(&self, id: LearningId, stats: &UsageStats) -> Result<()> {
+        CozoStore::update_usage(self, id, stats).await
+    }
+
+    async fn find_related(
+        &self,
+        id: LearningId,
+        relation_type: Option<&RelationType>,
+    ) -> Result<Vec<Learning>> {
+        CozoStore::find_related(self, id, relation_type).await
+    }
+
+    async fn store_relation(&self, relation: &LearningRelation) -> Result<()> {
+        CozoStore::store_relation(self, relation).await
+    }
+
+    async fn delete(&self, id: LearningId) -> Result<bool> {
+        CozoStore::delete(self, id).await
+    }
+
+    async fn count(&self) -> Result<u64> {
+        CozoStore::count(self).await
+    }
+
+    async fn update(&self, learning: &Learning) -> Result<()> {
+        CozoStore::update(self, learning).await
+    }
+
+    async fn find_similar(
+        &self,
+        embedding: &[f32],
+        threshold: f64,
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        CozoStore::find_similar(self, embedding, threshold, limit).await
+    }
+
+    async fn find_for_injection(
+        &self,
+        scopes: &ScopeChain,
+        context_embedding: Option<&[f32]>,
+        limit: usize,
+    ) -> Result<Vec<Learning>> {
+        CozoStore::find_for_injection(self, scopes, context_embedding, limit).await
+    }
+
+    async fn count_by_scope(&self, scope: &Scope) -> Result<u64> {
+        CozoStore::count_by_scope(self, scope).await
+    }
+
+    async fn count_by_category(&self, category: &LearningCategory) -> Result<u64> {
+        CozoStore::count_by_category(self, category).await
+    }
+}
+
+#[async_trait]
+impl super::ParamStore for CozoStore {
+    async fn get_param(&self, name: &str) -> Result<Option<SystemParam>> {
+        CozoStore::get_param(self, name).await
+    }
+
+    async fn store_param(&self, param: &SystemParam) -> Result<()> {
+        CozoStore::store_param(self, param).await
+    }
+
+    async fn all_params(&self) -> Result<Vec<SystemParam>> {
+        CozoStore::all_params(self).await
+    }
+}
+
 // =============================================================================
 // OpenWorldStore Implementation
 // =============================================================================
@@ -1758,54 +2091,448 @@ impl CozoStore {
             .ok_or_else(|| GrooveError::Database("Invalid session_id type".into()))?;
         let session_id = SessionId::from(session_id_str);
 
-        let failure_type_str = row[2]
-            .get_str()
-            .ok_or_else(|| GrooveError::Database("Invalid failure_type type".into()))?;
-        let failure_type: FailureType = failure_type_str
-            .parse()
-            .map_err(|e: String| GrooveError::Database(e))?;
+        let failure_type_str = row[2]
+            .get_str()
+            .ok_or_else(|| GrooveError::Database("Invalid failure_type type".into()))?;
+        let failure_type: FailureType = failure_type_str
+            .parse()
+            .map_err(|e: String| GrooveError::Database(e))?;
+
+        let context_hash = row[3]
+            .get_int()
+            .ok_or_else(|| GrooveError::Database("Invalid context_hash type".into()))?
+            as u64;
+
+        let learning_ids_json = row[4]
+            .get_str()
+            .ok_or_else(|| GrooveError::Database("Invalid learning_ids_json type".into()))?;
+        let learning_ids: Vec<LearningId> = serde_json::from_str(learning_ids_json)
+            .map_err(|e| GrooveError::Serialization(format!("Invalid learning_ids JSON: {e}")))?;
+
+        let timestamp_ts = row[5]
+            .get_int()
+            .ok_or_else(|| GrooveError::Database("Invalid timestamp type".into()))?;
+        let timestamp = DateTime::from_timestamp(timestamp_ts, 0)
+            .ok_or_else(|| GrooveError::Database("Invalid timestamp".into()))?;
+
+        Ok(Some(FailureRecord {
+            id,
+            session_id,
+            failure_type,
+            context_hash,
+            learning_ids,
+            timestamp,
+        }))
+    }
+
+    fn row_to_event(&self, row: &[DataValue]) -> Result<Option<OpenWorldEvent>> {
+        if row.len() < 4 {
+            return Ok(None);
+        }
+
+        let event_data_json = row[2]
+            .get_str()
+            .ok_or_else(|| GrooveError::Database("Invalid event_data_json type".into()))?;
+
+        let event: OpenWorldEvent = serde_json::from_str(event_data_json)
+            .map_err(|e| GrooveError::Serialization(format!("Invalid event JSON: {e}")))?;
+
+        Ok(Some(event))
+    }
+}
+
+// =============================================================================
+// ConsolidationStore Implementation
+// =============================================================================
+
+use crate::consolidation::{ConsolidationReport, ConsolidationRunId, ConsolidationStore};
+
+#[async_trait]
+impl ConsolidationStore for CozoStore {
+    async fn all_learnings(&self) -> Result<Vec<Learning>> {
+        let query = r#"
+            ?[id, scope, category, description, pattern_json, insight, confidence,
+              created_at, updated_at, source_type, source_json] :=
+            *learning{id, scope, category, description, pattern_json, insight,
+                     confidence, created_at, updated_at, source_type, source_json}
+        "#;
+        let rows = self.run_query(query, Default::default()).await?;
+
+        let mut learnings = Vec::new();
+        for row in &rows.rows {
+            if let Some(learning) = self.row_to_learning(row)? {
+                learnings.push(learning);
+            }
+        }
+        Ok(learnings)
+    }
+
+    async fn superseded_ids(&self) -> Result<HashSet<LearningId>> {
+        let query = format!(
+            r#"?[to_id] := *learning_relations{{relation_type, to_id}}, relation_type = '{}'"#,
+            RelationType::Supersedes.as_str()
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        Ok(rows
+            .rows
+            .iter()
+            .filter_map(|row| row[0].get_str().and_then(|s| uuid::Uuid::parse_str(s).ok()))
+            .collect())
+    }
+
+    async fn get_usage(&self, id: LearningId) -> Result<Option<UsageStats>> {
+        CozoStore::get_usage(self, id).await
+    }
+
+    async fn store_embedding(&self, id: LearningId, embedding: &[f32]) -> Result<()> {
+        CozoStore::store_embedding(self, id, embedding).await
+    }
+
+    async fn delete_relation(&self, relation: &LearningRelation) -> Result<()> {
+        let query = format!(
+            r#"?[from_id, relation_type, to_id] <- [['{}', '{}', '{}']]
+            :rm learning_relations {{from_id, relation_type, to_id}}"#,
+            relation.from_id,
+            relation.relation_type.as_str(),
+            relation.to_id,
+        );
+
+        self.run_mutation(&query, Default::default()).await?;
+        Ok(())
+    }
+
+    async fn save_consolidation_run(&self, report: &ConsolidationReport) -> Result<()> {
+        let report_json = serde_json::to_string(report)
+            .map_err(|e| GrooveError::Serialization(format!("Invalid report: {e}")))?;
+
+        let mut params = BTreeMap::new();
+        params.insert(
+            "id".to_string(),
+            DataValue::Str(report.run_id.to_string().into()),
+        );
+        params.insert(
+            "created_at".to_string(),
+            DataValue::from(report.created_at.timestamp()),
+        );
+        params.insert(
+            "report_json".to_string(),
+            DataValue::Str(report_json.into()),
+        );
+
+        let query = r#"?[id, created_at, report_json] <- [[$id, $created_at, $report_json]]
+            :put consolidation_run { id => created_at, report_json }"#;
+
+        self.run_mutation(query, params).await?;
+        Ok(())
+    }
+
+    async fn get_consolidation_run(
+        &self,
+        id: ConsolidationRunId,
+    ) -> Result<Option<ConsolidationReport>> {
+        let query = format!(
+            r#"?[report_json] := *consolidation_run{{id, report_json}}, id = '{}'"#,
+            id
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        rows.rows
+            .first()
+            .map(|row| parse_report(&row[0]))
+            .transpose()
+    }
+
+    async fn list_consolidation_runs(&self, limit: usize) -> Result<Vec<ConsolidationReport>> {
+        let query = format!(
+            r#"?[created_at, report_json] := *consolidation_run{{created_at, report_json}}
+            :order -created_at
+            :limit {}"#,
+            limit
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        rows.rows.iter().map(|row| parse_report(&row[1])).collect()
+    }
+}
+
+fn parse_report(value: &DataValue) -> Result<ConsolidationReport> {
+    let json = value
+        .get_str()
+        .ok_or_else(|| GrooveError::Database("Invalid report_json type".into()))?;
+    serde_json::from_str(json)
+        .map_err(|e| GrooveError::Serialization(format!("Invalid report JSON: {e}")))
+}
+
+// =============================================================================
+// ContradictionStore Implementation
+// =============================================================================
+
+use crate::contradiction::{ContradictionStore, drop_contradicted};
+
+#[async_trait]
+impl ContradictionStore for CozoStore {
+    async fn related_ids(&self, id: LearningId) -> Result<HashSet<LearningId>> {
+        let query = format!(
+            r#"related[other] := *learning_relations{{from_id, to_id: other}}, from_id = '{id}'
+            related[other] := *learning_relations{{from_id: other, to_id}}, to_id = '{id}'
+            ?[other] := related[other]"#
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        Ok(rows
+            .rows
+            .iter()
+            .filter_map(|row| row[0].get_str().and_then(|s| uuid::Uuid::parse_str(s).ok()))
+            .collect())
+    }
+
+    async fn contradictions(&self) -> Result<Vec<LearningRelation>> {
+        self.contradiction_relations().await
+    }
+}
+
+// =============================================================================
+// EmbeddingStore Implementation
+// =============================================================================
+
+use crate::reembed::{EmbeddingStore, ReembedJob};
+
+#[async_trait]
+impl EmbeddingStore for CozoStore {
+    async fn active_embedding_index(&self) -> Result<EmbeddingIndex> {
+        CozoStore::active_embedding_index(self).await
+    }
+
+    async fn create_embedding_index(
+        &self,
+        name: &str,
+        model_id: &str,
+        dimensions: usize,
+    ) -> Result<EmbeddingIndex> {
+        let relation = relation_name(name)?;
+        let created_at = Utc::now();
+
+        let mut params = BTreeMap::new();
+        params.insert("name".to_string(), DataValue::Str(name.into()));
+        params.insert("model_id".to_string(), DataValue::Str(model_id.into()));
+        params.insert("dimensions".to_string(), DataValue::from(dimensions as i64));
+        params.insert(
+            "created_at".to_string(),
+            DataValue::from(created_at.timestamp()),
+        );
+
+        let query = format!(
+            r#"{{
+                :create {relation} {{
+                    learning_id: String =>
+                    embedding: <F32; {dimensions}>
+                }}
+            }}
+            {{
+                ::hnsw create {relation}:semantic_idx {{
+                    dim: {dimensions},
+                    m: 16,
+                    ef_construction: 200,
+                    fields: [embedding]
+                }}
+            }}
+            {{
+                ?[name, model_id, dimensions, state, created_at] <-
+                    [[$name, $model_id, $dimensions, 'building', $created_at]]
+                :put embedding_index {{ name => model_id, dimensions, state, created_at }}
+            }}"#
+        );
+
+        self.run_mutation(&query, params).await?;
+        Ok(EmbeddingIndex {
+            name: name.to_string(),
+            model_id: model_id.to_string(),
+            dimensions,
+            state: IndexState::Building,
+            created_at,
+        })
+    }
+
+    async fn unembedded_learnings(&self, index: &str, limit: usize) -> Result<Vec<Learning>> {
+        let query = format!(
+            r#"?[id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json] :=
+                *learning{{id, scope, category, description, pattern_json, insight, confidence, created_at, updated_at, source_type, source_json}},
+                not *{relation}{{learning_id: id}}
+            :order id
+            :limit {limit}"#,
+            relation = relation_name(index)?
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+
+        let mut learnings = Vec::new();
+        for row in &rows.rows {
+            if let Some(learning) = self.row_to_learning(row)? {
+                learnings.push(learning);
+            }
+        }
+        Ok(learnings)
+    }
+
+    async fn store_indexed_embedding(
+        &self,
+        index: &EmbeddingIndex,
+        id: LearningId,
+        embedding: &[f32],
+    ) -> Result<()> {
+        CozoStore::store_indexed_embedding(self, index, id, embedding).await
+    }
+
+    async fn embedding_count(&self, index: &str) -> Result<u64> {
+        let query = format!(
+            "?[count(learning_id)] := *{}{{learning_id}}",
+            relation_name(index)?
+        );
+
+        let rows = self.run_query(&query, Default::default()).await?;
+        Ok(rows
+            .rows
+            .first()
+            .and_then(|row| row[0].get_int())
+            .unwrap_or(0) as u64)
+    }
+
+    async fn activate_embedding_index(&self, name: &str) -> Result<Option<String>> {
+        let indexes = self.embedding_indexes().await?;
+        if !indexes.iter().any(|i| i.name == name) {
+            return Err(GrooveError::Database(format!(
+                "Unknown embedding index: {name}"
+            )));
+        }
+        let previous = indexes
+            .into_iter()
+            .find(|i| i.state == IndexState::Active && i.name != name)
+            .map(|i| i.name);
+
+        let mut params = BTreeMap::new();
+        params.insert("target".to_string(), DataValue::Str(name.into()));
+
+        // Activate the new index and retire the old one in a single write
+        let query = r#"?[name, model_id, dimensions, state, created_at] :=
+                *embedding_index{name, model_id, dimensions, state: old_state, created_at},
+                name == $target || old_state == 'active',
+                state = if(name == $target, 'active', 'retired')
+            :put embedding_index { name => model_id, dimensions, state, created_at }"#;
+
+        self.run_mutation(query, params).await?;
+        Ok(previous)
+    }
+
+    async fn drop_embedding_index(&self, name: &str) -> Result<()> {
+        let relation = relation_name(name)?;
+        if self.active_embedding_index().await?.name == name {
+            return Err(GrooveError::Database(format!(
+                "Cannot drop the active embedding index {name}"
+            )));
+        }
+
+        let mut params = BTreeMap::new();
+        params.insert("name".to_string(), DataValue::Str(name.into()));
 
-        let context_hash = row[3]
-            .get_int()
-            .ok_or_else(|| GrooveError::Database("Invalid context_hash type".into()))?
-            as u64;
+        let query = format!(
+            r#"{{
+                ::hnsw drop {relation}:semantic_idx
+            }}
+            {{
+                ::remove {relation}
+            }}
+            {{
+                ?[index_name, learning_id] :=
+                    *embedding_meta{{index_name, learning_id}},
+                    index_name = $name
+                :rm embedding_meta {{ index_name, learning_id }}
+            }}
+            {{
+                ?[name] <- [[$name]]
+                :rm embedding_index {{ name }}
+            }}"#
+        );
 
-        let learning_ids_json = row[4]
-            .get_str()
-            .ok_or_else(|| GrooveError::Database("Invalid learning_ids_json type".into()))?;
-        let learning_ids: Vec<LearningId> = serde_json::from_str(learning_ids_json)
-            .map_err(|e| GrooveError::Serialization(format!("Invalid learning_ids JSON: {e}")))?;
+        self.run_mutation(&query, params).await?;
+        Ok(())
+    }
 
-        let timestamp_ts = row[5]
-            .get_int()
-            .ok_or_else(|| GrooveError::Database("Invalid timestamp type".into()))?;
-        let timestamp = DateTime::from_timestamp(timestamp_ts, 0)
-            .ok_or_else(|| GrooveError::Database("Invalid timestamp".into()))?;
+    async fn save_reembed_job(&self, job: &ReembedJob) -> Result<()> {
+        let job_json = serde_json::to_string(job)
+            .map_err(|e| GrooveError::Serialization(format!("Invalid re-embed job: {e}")))?;
 
-        Ok(Some(FailureRecord {
-            id,
-            session_id,
-            failure_type,
-            context_hash,
-            learning_ids,
-            timestamp,
-        }))
+        let mut params = BTreeMap::new();
+        params.insert("id".to_string(), DataValue::Str(job.id.to_string().into()));
+        params.insert(
+            "started_at".to_string(),
+            DataValue::from(job.started_at.timestamp_millis()),
+        );
+        params.insert("job_json".to_string(), DataValue::Str(job_json.into()));
+
+        let query = r#"?[id, started_at, job_json] <- [[$id, $started_at, $job_json]]
+            :put reembed_job { id => started_at, job_json }"#;
+
+        self.run_mutation(query, params).await?;
+        Ok(())
     }
 
-    fn row_to_event(&self, row: &[DataValue]) -> Result<Option<OpenWorldEvent>> {
-        if row.len() < 4 {
-            return Ok(None);
-        }
+    async fn latest_reembed_job(&self) -> Result<Option<ReembedJob>> {
+        let query = r#"?[started_at, id, job_json] := *reembed_job{id, started_at, job_json}
+            :order -started_at, -id
+            :limit 1"#;
 
-        let event_data_json = row[2]
+        let rows = self.run_query(query, Default::default()).await?;
+        rows.rows
+            .first()
+            .map(|row| {
+                let json = row[2]
+                    .get_str()
+                    .ok_or_else(|| GrooveError::Database("Invalid job_json type".into()))?;
+                serde_json::from_str(json)
+                    .map_err(|e| GrooveError::Serialization(format!("Invalid re-embed job: {e}")))
+            })
+            .transpose()
+    }
+}
+
+/// Parse an `embedding_index` row [name, model_id, dimensions, state, created_at]
+fn row_to_embedding_index(row: &[DataValue]) -> Result<EmbeddingIndex> {
+    let text = |i: usize, field: &str| {
+        row[i]
             .get_str()
-            .ok_or_else(|| GrooveError::Database("Invalid event_data_json type".into()))?;
+            .map(str::to_string)
+            .ok_or_else(|| GrooveError::Database(format!("Invalid {field} type")))
+    };
+    let int = |i: usize, field: &str| {
+        row[i]
+            .get_int()
+            .ok_or_else(|| GrooveError::Database(format!("Invalid {field} type")))
+    };
 
-        let event: OpenWorldEvent = serde_json::from_str(event_data_json)
-            .map_err(|e| GrooveError::Serialization(format!("Invalid event JSON: {e}")))?;
+    let state = text(3, "state")?;
+    Ok(EmbeddingIndex {
+        name: text(0, "name")?,
+        model_id: text(1, "model_id")?,
+        dimensions: int(2, "dimensions")? as usize,
+        state: IndexState::parse(&state)
+            .ok_or_else(|| GrooveError::Database(format!("Invalid index state: {state}")))?,
+        created_at: DateTime::from_timestamp(int(4, "created_at")?, 0)
+            .ok_or_else(|| GrooveError::Database("Invalid created_at timestamp".into()))?,
+    })
+}
 
-        Ok(Some(event))
+/// Index names are spliced into queries as relation names, so only allow
+/// identifier characters
+fn relation_name(name: &str) -> Result<&str> {
+    let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(GrooveError::Database(format!(
+            "Invalid embedding index name: {name}"
+        )));
     }
+    Ok(name)
 }
 
 #[cfg(test)]
@@ -1880,6 +2607,31 @@ mod tests {
         assert_eq!(retrieved.content.description, learning.content.description);
     }
 
+    #[tokio::test]
+    async fn test_usable_as_learning_store() {
+        let tmp = TempDir::new().unwrap();
+        let store: Arc<dyn super::super::LearningStore> =
+            Arc::new(CozoStore::open(tmp.path()).await.unwrap());
+
+        let learning = Learning::new(
+            Scope::Global,
+            LearningCategory::Preference,
+            LearningContent {
+                description: "Through the trait".into(),
+                pattern: None,
+                insight: "insight".into(),
+            },
+            LearningSource::UserCreated,
+        );
+
+        let id = store.store(&learning).await.unwrap();
+        assert_eq!(store.count().await.unwrap(), 1);
+        assert_eq!(
+            store.get(id).await.unwrap().unwrap().content.description,
+            "Through the trait"
+        );
+    }
+
     #[tokio::test]
     async fn test_find_by_scope() {
         let tmp = TempDir::new().unwrap();
@@ -1921,6 +2673,36 @@ mod tests {
         assert_eq!(bob_learnings.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_find_by_scope_kind() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        for scope in [
+            Scope::repository("git@github.com:org/repo.git"),
+            Scope::repository("git@github.com:org/other.git"),
+            Scope::branch("git@github.com:org/repo.git", "main"),
+            Scope::User("alice".into()),
+        ] {
+            let learning = Learning::new(
+                scope,
+                LearningCategory::Preference,
+                LearningContent {
+                    description: "learning".into(),
+                    pattern: None,
+                    insight: "insight".into(),
+                },
+                LearningSource::UserCreated,
+            );
+            store.store(&learning).await.unwrap();
+        }
+
+        assert_eq!(store.find_by_scope_kind("repo").await.unwrap().len(), 2);
+        assert_eq!(store.find_by_scope_kind("branch").await.unwrap().len(), 1);
+        assert!(store.find_by_scope_kind("team").await.unwrap().is_empty());
+        assert!(store.find_by_scope_kind("repository").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_delete_learning() {
         let tmp = TempDir::new().unwrap();
@@ -2349,6 +3131,138 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_consolidation_store_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        let a = make_text_learning("Use tabs", "Indent with tabs");
+        let b = make_text_learning("Prefer tabs", "Tabs over spaces");
+        store.store(&a).await.unwrap();
+        store.store(&b).await.unwrap();
+        assert_eq!(store.all_learnings().await.unwrap().len(), 2);
+
+        let relation = LearningRelation::new(a.id, RelationType::Supersedes, b.id);
+        store.store_relation(&relation).await.unwrap();
+        assert_eq!(store.superseded_ids().await.unwrap(), HashSet::from([b.id]));
+        store.delete_relation(&relation).await.unwrap();
+        assert!(store.superseded_ids().await.unwrap().is_empty());
+
+        let mut report = ConsolidationReport {
+            run_id: uuid::Uuid::now_v7(),
+            created_at: Utc::now(),
+            learnings_scanned: 2,
+            merges: Vec::new(),
+            applied_at: None,
+            reverted_at: None,
+        };
+        store.save_consolidation_run(&report).await.unwrap();
+        report.applied_at = Some(Utc::now());
+        store.save_consolidation_run(&report).await.unwrap();
+
+        let loaded = store
+            .get_consolidation_run(report.run_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(loaded.applied_at.is_some());
+        assert_eq!(store.list_consolidation_runs(10).await.unwrap().len(), 1);
+        assert!(
+            store
+                .get_consolidation_run(uuid::Uuid::now_v7())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    fn make_text_learning(description: &str, insight: &str) -> Learning {
+        Learning::new(
+            Scope::Global,
+            LearningCategory::CodePattern,
+            LearningContent {
+                description: description.into(),
+                pattern: None,
+                insight: insight.into(),
+            },
+            LearningSource::UserCreated,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_lexical_search_matches_exact_identifiers() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        let borrow = make_text_learning(
+            "Borrow checker error E0502",
+            "Clone the value before the mutable borrow",
+        );
+        let flag = make_text_learning("Preview imports", "Run learn import with --dry-run first");
+        store.store(&borrow).await.unwrap();
+        store.store(&flag).await.unwrap();
+
+        let results = store.lexical_search("E0502", 10).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, borrow.id);
+        assert!(results[0].1 > 0.0);
+
+        // Insight text is indexed too
+        let results = store.lexical_search("dry-run", 10).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, flag.id);
+
+        assert!(store.lexical_search("  ", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_fuses_lexical_and_vector() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        // Only reachable lexically
+        let lexical =
+            make_text_learning("Pin tokio-tungstenite", "Newer versions break vibes-core");
+        // Only reachable by embedding
+        let semantic = make_text_learning("Prefer small commits", "Keep each change reviewable");
+        store.store(&lexical).await.unwrap();
+        store.store(&semantic).await.unwrap();
+        store
+            .store_embedding(semantic.id, &make_test_embedding(0))
+            .await
+            .unwrap();
+        store
+            .store_embedding(lexical.id, &make_test_embedding(200))
+            .await
+            .unwrap();
+
+        let query = make_test_embedding(0);
+        let results = store
+            .hybrid_search("tokio-tungstenite", Some(&query), 10)
+            .await
+            .unwrap();
+        let ids: Vec<LearningId> = results.iter().map(|(l, _)| l.id).collect();
+        assert!(ids.contains(&lexical.id));
+        assert!(ids.contains(&semantic.id));
+
+        // Found by both retrievers, so it outranks the vector-only match
+        assert_eq!(ids[0], lexical.id);
+
+        // Without an embedding only the lexical ranking is used
+        let results = store
+            .hybrid_search("tokio-tungstenite", None, 10)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, lexical.id);
+
+        let results = store
+            .hybrid_search("tokio-tungstenite", Some(&query), 1)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_semantic_search_basic() {
         let tmp = TempDir::new().unwrap();
@@ -2891,12 +3805,12 @@ mod tests {
             .await
             .unwrap();
 
-        // Create learning in different scope
+        // Create learning in a scope outside the chain
         let learning2 = Learning::new(
-            Scope::Global,
+            Scope::Project("/other".into()),
             LearningCategory::Preference,
             LearningContent {
-                description: "Global preference".into(),
+                description: "Other project preference".into(),
                 pattern: None,
                 insight: "insight".into(),
             },
@@ -2910,7 +3824,7 @@ mod tests {
 
         // Find for injection with context
         let results = store
-            .find_for_injection(&scope, Some(&embedding1), 10)
+            .find_for_injection(&ScopeChain::from(scope), Some(&embedding1), 10)
             .await
             .unwrap();
 
@@ -2943,7 +3857,10 @@ mod tests {
         }
 
         // Find for injection without context - should order by confidence
-        let results = store.find_for_injection(&scope, None, 10).await.unwrap();
+        let results = store
+            .find_for_injection(&ScopeChain::from(scope), None, 10)
+            .await
+            .unwrap();
 
         assert_eq!(results.len(), 3);
         // Should be ordered by confidence descending
@@ -2952,6 +3869,102 @@ mod tests {
         assert!((results[2].confidence - 0.5).abs() < f64::EPSILON);
     }
 
+    #[tokio::test]
+    async fn test_find_for_injection_resolves_scope_chain() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        let chain = ScopeChain::new()
+            .with_team("platform")
+            .with_repository("git@github.com:org/repo.git", Some("main"));
+        let make = |scope: Scope, description: &str, confidence: f64| {
+            let mut learning = Learning::new(
+                scope,
+                LearningCategory::Preference,
+                LearningContent {
+                    description: description.into(),
+                    pattern: None,
+                    insight: "insight".into(),
+                },
+                LearningSource::UserCreated,
+            );
+            learning.confidence = confidence;
+            learning
+        };
+
+        let team = make(Scope::Team("platform".into()), "Run cargo fmt", 0.9);
+        let repo = make(
+            Scope::repository("github.com/org/repo"),
+            "Run cargo fmt",
+            0.5,
+        );
+        let branch = make(
+            Scope::branch("github.com/org/repo", "main"),
+            "Never push to main",
+            0.6,
+        );
+        let old_global = make(Scope::Global, "Squash before merging", 0.8);
+        let other_repo = make(Scope::repository("github.com/org/other"), "Other", 0.9);
+        for learning in [&team, &repo, &branch, &old_global, &other_repo] {
+            store.store(learning).await.unwrap();
+        }
+        store
+            .store_relation(&LearningRelation::new(
+                branch.id,
+                RelationType::Supersedes,
+                old_global.id,
+            ))
+            .await
+            .unwrap();
+
+        let results = store.find_for_injection(&chain, None, 10).await.unwrap();
+
+        // Branch first, repo shadows the team's copy, superseded and
+        // out-of-chain learnings are gone
+        let ids: Vec<_> = results.iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![branch.id, repo.id]);
+    }
+
+    #[tokio::test]
+    async fn test_find_for_injection_drops_contradicted_side() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        let mut older = make_text_learning("Use tokio::test", "Async tests need a runtime");
+        older.confidence = 0.6;
+        let mut newer = make_text_learning("Never use tokio::test", "This repo uses a harness");
+        newer.confidence = 0.9;
+        let other = make_text_learning("Run cargo fmt", "CI checks formatting");
+        for learning in [&older, &newer, &other] {
+            store.store(learning).await.unwrap();
+        }
+        store
+            .store_relation(&LearningRelation::new(
+                newer.id,
+                RelationType::Contradicts,
+                older.id,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.related_ids(older.id).await.unwrap(),
+            HashSet::from([newer.id])
+        );
+        assert_eq!(store.contradictions().await.unwrap().len(), 1);
+
+        let results = store
+            .find_for_injection(&ScopeChain::from(Scope::Global), None, 10)
+            .await
+            .unwrap();
+
+        // The more confident side ranks first and wins
+        let ids: Vec<_> = results.iter().map(|l| l.id).collect();
+        assert!(ids.contains(&newer.id));
+        assert!(ids.contains(&other.id));
+        assert!(!ids.contains(&older.id));
+    }
+
     #[tokio::test]
     async fn test_count_by_scope() {
         let tmp = TempDir::new().unwrap();
@@ -3370,4 +4383,97 @@ mod tests {
             assert_eq!(version, 2);
         }
     }
+
+    // ===== Embedding Index Tests =====
+
+    /// 8-dim embedder standing in for a newly configured model
+    struct TinyEmbedder;
+
+    #[async_trait]
+    impl crate::extraction::Embedder for TinyEmbedder {
+        async fn embed(&self, text: &str) -> crate::extraction::EmbedderResult<Vec<f32>> {
+            let mut v = vec![0.1; 8];
+            v[0] = text.len() as f32;
+            Ok(v)
+        }
+
+        fn dimensions(&self) -> usize {
+            8
+        }
+
+        fn model_id(&self) -> &str {
+            "mock:tiny"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_embedding_index_is_gte_small() {
+        let tmp = TempDir::new().unwrap();
+        let store = CozoStore::open(tmp.path()).await.unwrap();
+
+        let index = store.active_embedding_index().await.unwrap();
+        assert_eq!(index.name, crate::reembed::LEGACY_INDEX);
+        assert_eq!(index.model_id, "gte-small");
+        assert_eq!(index.dimensions, 384);
+        assert!(store.latest_reembed_job().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reembed_swaps_to_new_model() {
+        let tmp = TempDir::new().unwrap();
+        let store = Arc::new(CozoStore::open(tmp.path()).await.unwrap());
+
+        for (i, description) in ["Use tabs", "Prefer anyhow", "Run clippy"]
+            .into_iter()
+            .enumerate()
+        {
+            let learning = Learning::new(
+                Scope::Global,
+                LearningCategory::Preference,
+                LearningContent {
+                    description: description.into(),
+                    pattern: None,
+                    insight: String::new(),
+                },
+                LearningSource::UserCreated,
+            );
+            store.store(&learning).await.unwrap();
+            store
+                .store_embedding(learning.id, &make_test_embedding(i as u8))
+                .await
+                .unwrap();
+        }
+
+        let job = crate::reembed::Reembedder::new(store.clone(), Arc::new(TinyEmbedder))
+            .with_batch_size(2)
+            .run()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(job.status, crate::reembed::ReembedStatus::Completed);
+        assert_eq!((job.embedded, job.total), (3, 3));
+        let active = store.active_embedding_index().await.unwrap();
+        assert_eq!(active.name, job.index_name);
+        assert_eq!(active.dimensions, 8);
+        assert_eq!(store.embedding_indexes().await.unwrap().len(), 1);
+        assert_eq!(
+            store.latest_reembed_job().await.unwrap().unwrap().id,
+            job.id
+        );
+
+        // Search and storage now use the new model's vectors
+        let results = store.semantic_search(&[8.0; 8], 3).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(
+            store
+                .semantic_search(&make_test_embedding(0), 3)
+                .await
+                .is_err()
+        );
+
+        let learning = results[0].0.clone();
+        assert!(store.delete(learning.id).await.unwrap());
+        assert_eq!(store.embedding_count(&active.name).await.unwrap(), 2);
+    }
 }
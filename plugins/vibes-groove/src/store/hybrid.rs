@@ -0,0 +1,114 @@
+//! Hybrid lexical and vector ranking for learning search
+//!
+//! The HNSW index finds learnings that mean the same thing as a query but
+//! misses exact identifiers (crate names, error codes, CLI flags). The
+//! full-text index catches those. Reciprocal rank fusion combines both
+//! rankings without having to calibrate TF-IDF scores against cosine
+//! distances.
+
+use std::collections::HashMap;
+
+use crate::LearningId;
+
+/// Rank offset for reciprocal rank fusion
+///
+/// 60 is the value from the original RRF paper; it damps the advantage of
+/// the very top ranks so a learning found by both retrievers wins over one
+/// ranked first by only one of them.
+pub const RRF_K: f64 = 60.0;
+
+/// Fuse ranked ID lists with reciprocal rank fusion
+///
+/// Each list contributes `1 / (k + rank)` for every ID it contains, with
+/// ranks starting at 1. Returns IDs by fused score, highest first; ties keep
+/// the order in which IDs were first seen.
+pub fn reciprocal_rank_fusion(rankings: &[Vec<LearningId>], k: f64) -> Vec<(LearningId, f64)> {
+    let mut order: Vec<LearningId> = Vec::new();
+    let mut scores: HashMap<LearningId, f64> = HashMap::new();
+
+    for ranking in rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            let score = scores.entry(*id).or_insert_with(|| {
+                order.push(*id);
+                0.0
+            });
+            *score += 1.0 / (k + rank as f64 + 1.0);
+        }
+    }
+
+    let mut fused: Vec<(LearningId, f64)> = order.into_iter().map(|id| (id, scores[&id])).collect();
+    fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    fused
+}
+
+/// Build a full-text query from free text
+///
+/// Every whitespace-separated term becomes a quoted phrase joined with `OR`,
+/// so punctuation in identifiers like `--dry-run` or `E0502:` can't be read
+/// as query syntax. Returns `None` when nothing searchable is left.
+pub fn fts_query(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split_whitespace()
+        .map(|term| {
+            term.chars()
+                .filter(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+                .collect::<String>()
+        })
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{term}\""))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" OR "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_rrf_prefers_learnings_found_by_both() {
+        let (a, b, c) = (Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7());
+
+        // a tops the lexical list, b tops the vector list, c is second in both
+        let fused = reciprocal_rank_fusion(&[vec![a, c], vec![b, c]], RRF_K);
+
+        assert_eq!(fused.len(), 3);
+        assert_eq!(fused[0].0, c);
+        assert_eq!(fused[1].0, a);
+        assert_eq!(fused[2].0, b);
+        assert!((fused[0].1 - 2.0 / 62.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rrf_single_ranking_keeps_order() {
+        let ids: Vec<LearningId> = (0..4).map(|_| Uuid::now_v7()).collect();
+        let fused = reciprocal_rank_fusion(std::slice::from_ref(&ids), RRF_K);
+
+        let fused_ids: Vec<LearningId> = fused.iter().map(|(id, _)| *id).collect();
+        assert_eq!(fused_ids, ids);
+        assert!(reciprocal_rank_fusion(&[], RRF_K).is_empty());
+    }
+
+    #[test]
+    fn test_fts_query_quotes_terms() {
+        assert_eq!(
+            fts_query("fix E0502 with --dry-run").as_deref(),
+            Some(r#""fix" OR "E0502" OR "with" OR "--dry-run""#)
+        );
+        assert_eq!(
+            fts_query(r#"serde_json "quoted" (parens)"#).as_deref(),
+            Some(r#""serde_json" OR "quoted" OR "parens""#)
+        );
+    }
+
+    #[test]
+    fn test_fts_query_empty() {
+        assert_eq!(fts_query(""), None);
+        assert_eq!(fts_query("  -- ... ()"), None);
+    }
+}
@@ -0,0 +1,437 @@
+//! In-memory learning store for unit tests
+//!
+//! Implements every store trait the jobs build on, so tests of consolidation,
+//! contradiction detection, retention, re-embedding, import and retrieval
+//! share one test double. Full-text and semantic search are not modelled and
+//! return nothing; [`find_similar`](LearningStore::find_similar) ranks by
+//! preset similarities or by cosine similarity of stored embeddings.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::consolidation::{ConsolidationReport, ConsolidationRunId, ConsolidationStore};
+use crate::contradiction::ContradictionStore;
+use crate::extraction::{GTE_SMALL_DIMENSIONS, GTE_SMALL_MODEL_ID, cosine_similarity};
+use crate::reembed::{EmbeddingIndex, EmbeddingStore, IndexState, LEGACY_INDEX, ReembedJob};
+use crate::store::LearningStore;
+use crate::{
+    Learning, LearningCategory, LearningId, LearningRelation, RelationType, Result, Scope,
+    ScopeChain, UsageStats,
+};
+
+/// Learnings, relations, embeddings and job records held in maps
+///
+/// Starts with an active legacy index for the gte-small model.
+pub(crate) struct MemoryStore {
+    pub learnings: Mutex<BTreeMap<LearningId, Learning>>,
+    pub usage: Mutex<HashMap<LearningId, UsageStats>>,
+    /// Vectors written through [`ConsolidationStore::store_embedding`]
+    pub embeddings: Mutex<HashMap<LearningId, Vec<f32>>>,
+    /// Similarities `find_similar` reports regardless of the query
+    pub similarities: Mutex<HashMap<LearningId, f64>>,
+    pub relations: Mutex<Vec<LearningRelation>>,
+    pub runs: Mutex<HashMap<ConsolidationRunId, ConsolidationReport>>,
+    pub indexes: Mutex<Vec<EmbeddingIndex>>,
+    /// Vectors per embedding index name
+    pub vectors: Mutex<HashMap<String, HashMap<LearningId, Vec<f32>>>>,
+    pub jobs: Mutex<Vec<ReembedJob>>,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::with_embedding_model(GTE_SMALL_MODEL_ID, GTE_SMALL_DIMENSIONS)
+    }
+}
+
+impl MemoryStore {
+    /// An empty store whose active index holds vectors from `model_id`
+    pub fn with_embedding_model(model_id: &str, dimensions: usize) -> Self {
+        let legacy = EmbeddingIndex {
+            name: LEGACY_INDEX.into(),
+            model_id: model_id.into(),
+            dimensions,
+            state: IndexState::Active,
+            created_at: Utc::now(),
+        };
+        Self {
+            learnings: Mutex::new(BTreeMap::new()),
+            usage: Mutex::new(HashMap::new()),
+            embeddings: Mutex::new(HashMap::new()),
+            similarities: Mutex::new(HashMap::new()),
+            relations: Mutex::new(Vec::new()),
+            runs: Mutex::new(HashMap::new()),
+            indexes: Mutex::new(vec![legacy]),
+            vectors: Mutex::new(HashMap::new()),
+            jobs: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a learning that `find_similar` always reports at `similarity`
+    pub fn with_similar(self, learning: Learning, similarity: f64) -> Self {
+        self.similarities
+            .lock()
+            .unwrap()
+            .insert(learning.id, similarity);
+        self.learnings.lock().unwrap().insert(learning.id, learning);
+        self
+    }
+
+    /// Names of the embedding indexes, oldest first
+    pub fn index_names(&self) -> Vec<String> {
+        self.indexes
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|i| i.name.clone())
+            .collect()
+    }
+
+    /// Vectors stored in the named embedding index
+    pub fn index_vectors(&self, index: &str) -> HashMap<LearningId, Vec<f32>> {
+        self.vectors
+            .lock()
+            .unwrap()
+            .get(index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn matching(&self, keep: impl Fn(&Learning) -> bool) -> Vec<Learning> {
+        self.learnings
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|l| keep(l))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl LearningStore for MemoryStore {
+    async fn store(&self, learning: &Learning) -> Result<LearningId> {
+        self.learnings
+            .lock()
+            .unwrap()
+            .insert(learning.id, learning.clone());
+        Ok(learning.id)
+    }
+
+    async fn get(&self, id: LearningId) -> Result<Option<Learning>> {
+        Ok(self.learnings.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn find_by_scope(&self, scope: &Scope) -> Result<Vec<Learning>> {
+        Ok(self.matching(|l| &l.scope == scope))
+    }
+
+    async fn find_by_category(&self, category: &LearningCategory) -> Result<Vec<Learning>> {
+        Ok(self.matching(|l| &l.category == category))
+    }
+
+    async fn semantic_search(
+        &self,
+        _embedding: &[f32],
+        _limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        Ok(Vec::new())
+    }
+
+    async fn hybrid_search(
+        &self,
+        _query: &str,
+        _embedding: Option<&[f32]>,
+        _limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        Ok(Vec::new())
+    }
+
+    async fn update_usage(&self, id: LearningId, stats: &UsageStats) -> Result<()> {
+        self.usage.lock().unwrap().insert(id, stats.clone());
+        Ok(())
+    }
+
+    async fn find_related(
+        &self,
+        id: LearningId,
+        relation_type: Option<&RelationType>,
+    ) -> Result<Vec<Learning>> {
+        let related: HashSet<LearningId> = self
+            .relations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.from_id == id && relation_type.is_none_or(|t| &r.relation_type == t))
+            .map(|r| r.to_id)
+            .collect();
+        Ok(self.matching(|l| related.contains(&l.id)))
+    }
+
+    async fn store_relation(&self, relation: &LearningRelation) -> Result<()> {
+        self.relations.lock().unwrap().push(relation.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: LearningId) -> Result<bool> {
+        self.usage.lock().unwrap().remove(&id);
+        self.embeddings.lock().unwrap().remove(&id);
+        for vectors in self.vectors.lock().unwrap().values_mut() {
+            vectors.remove(&id);
+        }
+        Ok(self.learnings.lock().unwrap().remove(&id).is_some())
+    }
+
+    async fn count(&self) -> Result<u64> {
+        Ok(self.learnings.lock().unwrap().len() as u64)
+    }
+
+    async fn update(&self, learning: &Learning) -> Result<()> {
+        self.store(learning).await.map(|_| ())
+    }
+
+    async fn find_similar(
+        &self,
+        embedding: &[f32],
+        threshold: f64,
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>> {
+        let similarities = self.similarities.lock().unwrap();
+        let embeddings = self.embeddings.lock().unwrap();
+        let mut similar: Vec<(Learning, f64)> = self
+            .learnings
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|learning| {
+                let similarity = similarities.get(&learning.id).copied().or_else(|| {
+                    let other = embeddings.get(&learning.id)?;
+                    Some(cosine_similarity(embedding, other) as f64)
+                })?;
+                (similarity >= threshold).then(|| (learning.clone(), similarity))
+            })
+            .collect();
+        similar.sort_by(|a, b| b.1.total_cmp(&a.1));
+        similar.truncate(limit);
+        Ok(similar)
+    }
+
+    async fn find_for_injection(
+        &self,
+        scopes: &ScopeChain,
+        _context_embedding: Option<&[f32]>,
+        _limit: usize,
+    ) -> Result<Vec<Learning>> {
+        let superseded = self.superseded_ids().await?;
+        Ok(scopes.resolve(self.matching(|_| true), &superseded))
+    }
+
+    async fn count_by_scope(&self, scope: &Scope) -> Result<u64> {
+        Ok(self.matching(|l| &l.scope == scope).len() as u64)
+    }
+
+    async fn count_by_category(&self, category: &LearningCategory) -> Result<u64> {
+        Ok(self.matching(|l| &l.category == category).len() as u64)
+    }
+}
+
+#[async_trait]
+impl ConsolidationStore for MemoryStore {
+    async fn all_learnings(&self) -> Result<Vec<Learning>> {
+        Ok(self.matching(|_| true))
+    }
+
+    async fn superseded_ids(&self) -> Result<HashSet<LearningId>> {
+        Ok(self
+            .relations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.relation_type == RelationType::Supersedes)
+            .map(|r| r.to_id)
+            .collect())
+    }
+
+    async fn get_usage(&self, id: LearningId) -> Result<Option<UsageStats>> {
+        Ok(self.usage.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn store_embedding(&self, id: LearningId, embedding: &[f32]) -> Result<()> {
+        self.embeddings
+            .lock()
+            .unwrap()
+            .insert(id, embedding.to_vec());
+        Ok(())
+    }
+
+    async fn delete_relation(&self, relation: &LearningRelation) -> Result<()> {
+        self.relations.lock().unwrap().retain(|r| {
+            !(r.from_id == relation.from_id
+                && r.to_id == relation.to_id
+                && r.relation_type == relation.relation_type)
+        });
+        Ok(())
+    }
+
+    async fn save_consolidation_run(&self, report: &ConsolidationReport) -> Result<()> {
+        self.runs
+            .lock()
+            .unwrap()
+            .insert(report.run_id, report.clone());
+        Ok(())
+    }
+
+    async fn get_consolidation_run(
+        &self,
+        id: ConsolidationRunId,
+    ) -> Result<Option<ConsolidationReport>> {
+        Ok(self.runs.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn list_consolidation_runs(&self, limit: usize) -> Result<Vec<ConsolidationReport>> {
+        let mut runs: Vec<_> = self.runs.lock().unwrap().values().cloned().collect();
+        runs.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+        runs.truncate(limit);
+        Ok(runs)
+    }
+}
+
+#[async_trait]
+impl ContradictionStore for MemoryStore {
+    async fn related_ids(&self, id: LearningId) -> Result<HashSet<LearningId>> {
+        Ok(self
+            .relations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|r| {
+                if r.from_id == id {
+                    Some(r.to_id)
+                } else if r.to_id == id {
+                    Some(r.from_id)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    async fn contradictions(&self) -> Result<Vec<LearningRelation>> {
+        Ok(self
+            .relations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.relation_type == RelationType::Contradicts)
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait]
+impl EmbeddingStore for MemoryStore {
+    async fn active_embedding_index(&self) -> Result<EmbeddingIndex> {
+        Ok(self
+            .indexes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|i| i.state == IndexState::Active)
+            .cloned()
+            .unwrap())
+    }
+
+    async fn create_embedding_index(
+        &self,
+        name: &str,
+        model_id: &str,
+        dimensions: usize,
+    ) -> Result<EmbeddingIndex> {
+        let index = EmbeddingIndex {
+            name: name.into(),
+            model_id: model_id.into(),
+            dimensions,
+            state: IndexState::Building,
+            created_at: Utc::now(),
+        };
+        self.indexes.lock().unwrap().push(index.clone());
+        Ok(index)
+    }
+
+    async fn unembedded_learnings(&self, index: &str, limit: usize) -> Result<Vec<Learning>> {
+        let vectors = self.vectors.lock().unwrap();
+        let embedded = vectors.get(index);
+        Ok(self
+            .learnings
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|l| !embedded.is_some_and(|e| e.contains_key(&l.id)))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn store_indexed_embedding(
+        &self,
+        index: &EmbeddingIndex,
+        id: LearningId,
+        embedding: &[f32],
+    ) -> Result<()> {
+        index.check_dimensions(embedding)?;
+        self.vectors
+            .lock()
+            .unwrap()
+            .entry(index.name.clone())
+            .or_default()
+            .insert(id, embedding.to_vec());
+        Ok(())
+    }
+
+    async fn embedding_count(&self, index: &str) -> Result<u64> {
+        Ok(self
+            .vectors
+            .lock()
+            .unwrap()
+            .get(index)
+            .map_or(0, |v| v.len() as u64))
+    }
+
+    async fn activate_embedding_index(&self, name: &str) -> Result<Option<String>> {
+        let mut previous = None;
+        for index in self.indexes.lock().unwrap().iter_mut() {
+            if index.name == name {
+                index.state = IndexState::Active;
+            } else if index.state == IndexState::Active {
+                index.state = IndexState::Retired;
+                previous = Some(index.name.clone());
+            }
+        }
+        Ok(previous)
+    }
+
+    async fn drop_embedding_index(&self, name: &str) -> Result<()> {
+        self.indexes.lock().unwrap().retain(|i| i.name != name);
+        self.vectors.lock().unwrap().remove(name);
+        Ok(())
+    }
+
+    async fn save_reembed_job(&self, job: &ReembedJob) -> Result<()> {
+        let mut jobs = self.jobs.lock().unwrap();
+        jobs.retain(|j| j.id != job.id);
+        jobs.push(job.clone());
+        Ok(())
+    }
+
+    async fn latest_reembed_job(&self) -> Result<Option<ReembedJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .max_by_key(|j| (j.started_at, j.id))
+            .cloned())
+    }
+}
@@ -1,9 +1,16 @@
 //! Storage traits and implementations for vibes-groove
 
 mod cozo;
+mod hybrid;
+#[cfg(test)]
+pub(crate) mod memory;
 mod schema;
 mod traits;
 
 pub use cozo::CozoStore;
-pub use schema::{CURRENT_SCHEMA_VERSION, INITIAL_SCHEMA, MIGRATIONS, Migration};
+pub use hybrid::{RRF_K, fts_query, reciprocal_rank_fusion};
+pub use schema::{
+    CONSOLIDATION_SCHEMA, CURRENT_SCHEMA_VERSION, EMBEDDING_INDEX_SCHEMA, FULLTEXT_SCHEMA,
+    INITIAL_SCHEMA, MIGRATIONS, Migration,
+};
 pub use traits::{LearningStore, ParamStore};
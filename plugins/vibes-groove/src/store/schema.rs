@@ -1,10 +1,11 @@
 //! CozoDB schema definitions for the groove storage layer
 //!
 //! This module contains the Datalog schema for CozoDB including tables,
-//! indexes, and the HNSW vector index for semantic search.
+//! indexes, the HNSW vector index for semantic search, and the full-text
+//! index for lexical search.
 
 /// Current schema version
-pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+pub const CURRENT_SCHEMA_VERSION: u32 = 5;
 
 /// Initial schema creation script (Datalog)
 ///
@@ -177,6 +178,83 @@ pub const OPENWORLD_SCHEMA: &str = r#"
 }
 "#;
 
+/// Full-text search schema (Migration v3)
+///
+/// Indexes learning description and insight together so exact identifiers
+/// such as crate names, error codes and CLI flags can be matched lexically.
+pub const FULLTEXT_SCHEMA: &str = r#"
+{
+    ::fts create learning:text_idx {
+        extractor: concat(description, ' ', insight),
+        tokenizer: Simple,
+        filters: [Lowercase]
+    }
+}
+"#;
+
+/// Consolidation schema (Migration v4)
+///
+/// Records every consolidation run so its merges can be applied later or
+/// reverted.
+pub const CONSOLIDATION_SCHEMA: &str = r#"
+{
+    :create consolidation_run {
+        id: String =>
+        created_at: Int,
+        report_json: String
+    }
+}
+"#;
+
+/// Embedding model versioning schema (Migration v5)
+///
+/// Each embedding model gets its own vector relation and HNSW index, listed
+/// in `embedding_index`; exactly one is `active`. `embedding_meta` records
+/// the model and dimensions behind every stored vector, and `reembed_job`
+/// tracks migrations between models. Vectors stored before this migration
+/// all came from the bundled gte-small model.
+pub const EMBEDDING_INDEX_SCHEMA: &str = r#"
+{
+    :create embedding_index {
+        name: String =>
+        model_id: String,
+        dimensions: Int,
+        state: String,
+        created_at: Int
+    }
+}
+{
+    :create embedding_meta {
+        index_name: String,
+        learning_id: String =>
+        model_id: String,
+        dimensions: Int,
+        embedded_at: Int?
+    }
+}
+{
+    :create reembed_job {
+        id: String =>
+        started_at: Int,
+        job_json: String
+    }
+}
+{
+    ?[name, model_id, dimensions, state, created_at] <-
+        [['learning_embeddings', 'gte-small', 384, 'active', 0]]
+    :put embedding_index { name => model_id, dimensions, state, created_at }
+}
+{
+    ?[index_name, learning_id, model_id, dimensions, embedded_at] :=
+        *learning_embeddings{learning_id},
+        index_name = 'learning_embeddings',
+        model_id = 'gte-small',
+        dimensions = 384,
+        embedded_at = null
+    :put embedding_meta { index_name, learning_id => model_id, dimensions, embedded_at }
+}
+"#;
+
 /// All migrations in order
 pub static MIGRATIONS: &[Migration] = &[
     Migration {
@@ -189,6 +267,21 @@ pub static MIGRATIONS: &[Migration] = &[
         description: "Open-world adaptation schema",
         script: OPENWORLD_SCHEMA,
     },
+    Migration {
+        version: 3,
+        description: "Full-text search index",
+        script: FULLTEXT_SCHEMA,
+    },
+    Migration {
+        version: 4,
+        description: "Consolidation runs",
+        script: CONSOLIDATION_SCHEMA,
+    },
+    Migration {
+        version: 5,
+        description: "Embedding model versioning",
+        script: EMBEDDING_INDEX_SCHEMA,
+    },
 ];
 
 #[cfg(test)]
@@ -197,12 +290,12 @@ mod tests {
 
     #[test]
     fn test_schema_version_constant() {
-        assert_eq!(CURRENT_SCHEMA_VERSION, 2);
+        assert_eq!(CURRENT_SCHEMA_VERSION, 5);
     }
 
     #[test]
     fn test_migrations_count() {
-        assert_eq!(MIGRATIONS.len(), 2);
+        assert_eq!(MIGRATIONS.len(), 5);
     }
 
     #[test]
@@ -270,4 +363,23 @@ mod tests {
         assert!(OPENWORLD_SCHEMA.contains("::index create capability_gap:by_status"));
         assert!(OPENWORLD_SCHEMA.contains("::index create failure_record:by_context"));
     }
+
+    #[test]
+    fn test_fulltext_schema_indexes_description_and_insight() {
+        assert!(FULLTEXT_SCHEMA.contains("::fts create learning:text_idx"));
+        assert!(FULLTEXT_SCHEMA.contains("concat(description, ' ', insight)"));
+    }
+
+    #[test]
+    fn test_consolidation_schema_contains_runs() {
+        assert!(CONSOLIDATION_SCHEMA.contains(":create consolidation_run {"));
+    }
+
+    #[test]
+    fn test_embedding_index_schema_registers_legacy_vectors() {
+        assert!(EMBEDDING_INDEX_SCHEMA.contains(":create embedding_index {"));
+        assert!(EMBEDDING_INDEX_SCHEMA.contains(":create embedding_meta {"));
+        assert!(EMBEDDING_INDEX_SCHEMA.contains(":create reembed_job {"));
+        assert!(EMBEDDING_INDEX_SCHEMA.contains("'learning_embeddings', 'gte-small', 384"));
+    }
 }
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 
 use crate::{
     GrooveError, Learning, LearningCategory, LearningId, LearningRelation, RelationType, Scope,
-    SystemParam, UsageStats,
+    ScopeChain, SystemParam, UsageStats,
 };
 
 /// Storage operations for a single learning database
@@ -32,6 +32,18 @@ pub trait LearningStore: Send + Sync {
         limit: usize,
     ) -> Result<Vec<(Learning, f64)>, GrooveError>;
 
+    /// Hybrid lexical and vector search over description and insight
+    ///
+    /// Fuses the full-text and embedding rankings with reciprocal rank
+    /// fusion; returns learnings with their fused score (higher = more
+    /// relevant). Without an embedding only the lexical ranking is used.
+    async fn hybrid_search(
+        &self,
+        query: &str,
+        embedding: Option<&[f32]>,
+        limit: usize,
+    ) -> Result<Vec<(Learning, f64)>, GrooveError>;
+
     /// Update usage statistics for a learning
     async fn update_usage(&self, id: LearningId, stats: &UsageStats) -> Result<(), GrooveError>;
 
@@ -64,9 +76,12 @@ pub trait LearningStore: Send + Sync {
     ) -> Result<Vec<(Learning, f64)>, GrooveError>;
 
     /// Find learnings suitable for injection based on session context
+    ///
+    /// Resolves the scope chain most specific first; see
+    /// [`ScopeChain::resolve`] for the override and shadowing rules.
     async fn find_for_injection(
         &self,
-        scope: &Scope,
+        scopes: &ScopeChain,
         context_embedding: Option<&[f32]>,
         limit: usize,
     ) -> Result<Vec<Learning>, GrooveError>;
@@ -141,6 +141,10 @@ pub enum LearningSource {
     EnterpriseCurated {
         curator: String,
     },
+    /// Generalized from a cluster of similar learnings
+    Consolidated {
+        run_id: Uuid,
+    },
 }
 
 impl LearningSource {
@@ -151,6 +155,7 @@ impl LearningSource {
             Self::Promoted { .. } => "promoted",
             Self::Imported { .. } => "imported",
             Self::EnterpriseCurated { .. } => "enterprise_curated",
+            Self::Consolidated { .. } => "consolidated",
         }
     }
 }
@@ -9,5 +9,5 @@ mod usage;
 pub use learning::*;
 pub use params::*;
 pub use relations::*;
-pub use scope::Scope;
+pub use scope::{Scope, ScopeChain};
 pub use usage::*;
@@ -4,6 +4,7 @@
 //! - Full pipeline with heavy events
 //! - Duplicate detection and merging
 //! - Pattern detection on transcripts
+//! - Preference, tool usage, code pattern and harness knowledge fixtures
 //! - Event emission to groove.extraction topic
 
 use std::collections::HashMap;
@@ -16,10 +17,15 @@ use tokio_util::sync::CancellationToken;
 use vibes_iggy::{EventLog as _, InMemoryEventLog};
 
 use vibes_groove::assessment::{AssessmentContext, ExtractionCandidate, HeavyEvent, Outcome};
-use vibes_groove::capture::{ParsedTranscript, TranscriptMessage, TranscriptMetadata};
+use vibes_groove::capture::{
+    ParsedTranscript, TranscriptMessage, TranscriptMetadata, TranscriptToolUse,
+};
+use vibes_groove::extraction::patterns::{
+    CodePatternDetector, HarnessKnowledgeDetector, PreferenceDetector, ToolUsageDetector,
+};
 use vibes_groove::extraction::{
     DeduplicationStrategy, Embedder, EmbedderResult, ExtractionConfig, ExtractionConsumer,
-    ExtractionEvent, extraction_consumer_loop,
+    ExtractionEvent, ExtractionMethod, PatternType, extraction_consumer_loop,
 };
 use vibes_groove::store::LearningStore;
 use vibes_groove::{
@@ -264,6 +270,118 @@ fn make_transcript(messages: Vec<(&str, &str)>) -> ParsedTranscript {
     }
 }
 
+fn tool_use(
+    name: &str,
+    input: serde_json::Value,
+    output: &str,
+    success: bool,
+) -> TranscriptToolUse {
+    TranscriptToolUse {
+        tool_name: name.to_string(),
+        input,
+        output: Some(output.to_string()),
+        success,
+    }
+}
+
+fn with_tool_uses(
+    mut transcript: ParsedTranscript,
+    tool_uses: Vec<TranscriptToolUse>,
+) -> ParsedTranscript {
+    transcript.tool_uses = tool_uses;
+    transcript
+}
+
+/// A user who has to repeat a style instruction
+fn preference_fixture() -> ParsedTranscript {
+    make_transcript(vec![
+        ("user", "Add a config loader. Always use anyhow for errors."),
+        ("assistant", "Got it, I'll use anyhow for errors."),
+        (
+            "user",
+            "The cache module still returns Box<dyn Error>. Please always use anyhow for errors!",
+        ),
+        ("assistant", "Fixed, the cache now returns anyhow::Result."),
+    ])
+}
+
+/// Formatting before every commit, once via Claude's Bash and once via Codex's shell
+fn tool_sequence_fixture() -> ParsedTranscript {
+    let bash = |cmd: &str| tool_use("Bash", serde_json::json!({ "command": cmd }), "", true);
+    let shell = |cmd: &str| {
+        tool_use(
+            "shell",
+            serde_json::json!({ "command": ["bash", "-lc", cmd] }),
+            "",
+            true,
+        )
+    };
+    with_tool_uses(
+        make_transcript(vec![("user", "Fix the parser and commit")]),
+        vec![
+            bash("cargo test"),
+            bash("cargo fmt --all"),
+            bash("git commit -m 'Fix parser'"),
+            shell("cargo test -p parser"),
+            shell("cargo fmt"),
+            shell("git commit -am 'Fix lexer'"),
+        ],
+    )
+}
+
+/// The same error-context shape written into three files
+fn code_shape_fixture() -> ParsedTranscript {
+    let edit = |file: &str, what: &str| {
+        tool_use(
+            "Edit",
+            serde_json::json!({
+                "file_path": file,
+                "old_string": "let text = fs::read_to_string(path)?;",
+                "new_string": format!(
+                    "let text = fs::read_to_string(path).with_context(|| format!(\"reading {what} {{}}\", path.display()))?;"
+                ),
+            }),
+            "ok",
+            true,
+        )
+    };
+    with_tool_uses(
+        make_transcript(vec![("user", "Add context to the file reads")]),
+        vec![
+            edit("/repo/src/config.rs", "config"),
+            edit("/repo/src/cache.rs", "cache"),
+            edit("/repo/src/lockfile.rs", "lockfile"),
+        ],
+    )
+}
+
+/// An edit rejected because the file wasn't read first, then worked around
+fn harness_quirk_fixture() -> ParsedTranscript {
+    with_tool_uses(
+        make_transcript(vec![("user", "Bump the version in Cargo.toml")]),
+        vec![
+            tool_use(
+                "Edit",
+                serde_json::json!({ "file_path": "/repo/Cargo.toml" }),
+                "File has not been read yet. Read it first before writing to it.",
+                false,
+            ),
+            tool_use(
+                "Read",
+                serde_json::json!({ "file_path": "/repo/Cargo.toml" }),
+                "[package]",
+                true,
+            ),
+            tool_use(
+                "Edit",
+                serde_json::json!({ "file_path": "/repo/Cargo.toml" }),
+                "ok",
+                true,
+            ),
+        ],
+    )
+}
+
 fn make_learning(description: &str) -> Learning {
     Learning::new(
         Scope::User("test".to_string()),
@@ -643,3 +761,98 @@ async fn extraction_respects_confidence_threshold() {
         "Only high confidence learning should be stored"
     );
 }
+
+#[test]
+fn preference_fixture_detects_repeated_instruction() {
+    let candidates = PreferenceDetector::new()
+        .detect(&preference_fixture())
+        .unwrap();
+
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].description, "Always use anyhow for errors");
+    assert!((candidates[0].confidence - 0.9).abs() < 1e-9);
+    assert_eq!(
+        candidates[0].source.extraction_method,
+        ExtractionMethod::Pattern(PatternType::Preference)
+    );
+}
+
+#[test]
+fn tool_sequence_fixture_detects_fmt_before_commit() {
+    let candidates = ToolUsageDetector::new()
+        .detect(&tool_sequence_fixture())
+        .unwrap();
+
+    let fmt = candidates
+        .iter()
+        .find(|c| c.description == "Run `cargo fmt` before `git commit`")
+        .expect("fmt before commit");
+    assert!((fmt.confidence - 0.8).abs() < 1e-9);
+    assert_eq!(fmt.source.message_range, Some((1, 5)));
+}
+
+#[test]
+fn code_shape_fixture_detects_recurring_context() {
+    let candidates = CodePatternDetector::new()
+        .detect(&code_shape_fixture())
+        .unwrap();
+
+    assert_eq!(candidates.len(), 1);
+    let pattern = candidates[0].pattern.as_ref().unwrap();
+    assert_eq!(pattern["occurrences"], 3);
+    assert_eq!(pattern["files"].as_array().unwrap().len(), 3);
+    assert!(
+        candidates[0]
+            .description
+            .starts_with("Recurring code in Rust")
+    );
+    assert!((candidates[0].confidence - 0.75).abs() < 1e-9);
+}
+
+#[test]
+fn harness_quirk_fixture_detects_read_before_write() {
+    let candidates = HarnessKnowledgeDetector::new()
+        .detect(&harness_quirk_fixture())
+        .unwrap();
+
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(
+        candidates[0].pattern.as_ref().unwrap()["quirk"],
+        "read_before_write"
+    );
+    assert_eq!(candidates[0].pattern.as_ref().unwrap()["recovered"], true);
+    assert!((candidates[0].confidence - 0.75).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn extraction_stores_new_pattern_categories() {
+    let fixtures = [
+        (preference_fixture(), LearningCategory::Preference),
+        (tool_sequence_fixture(), LearningCategory::ToolUsage),
+        (code_shape_fixture(), LearningCategory::CodePattern),
+        (harness_quirk_fixture(), LearningCategory::HarnessKnowledge),
+    ];
+
+    for (transcript, category) in fixtures {
+        let store = Arc::new(MockStore::new());
+        let consumer = ExtractionConsumer::new(
+            store.clone(),
+            Arc::new(MockEmbedder::new()),
+            Arc::new(MockDedup::new()),
+            Arc::new(MockTranscriptFetcher::new().with_transcript(transcript)),
+            ExtractionConfig::default(),
+        );
+
+        let result = consumer
+            .process_heavy_event(&make_heavy_event(vec![]))
+            .await
+            .unwrap();
+
+        assert!(!result.created.is_empty(), "no learnings for {category:?}");
+        let learnings = store.learnings.lock().unwrap();
+        assert!(
+            learnings.values().any(|l| l.category == category),
+            "expected a {category:?} learning"
+        );
+    }
+}
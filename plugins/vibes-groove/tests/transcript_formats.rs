@@ -0,0 +1,118 @@
+//! Parsing of non-Claude transcript fixtures into the shared transcript model
+
+use std::path::PathBuf;
+
+use vibes_groove::capture::{
+    AiderHistoryParser, CodexRolloutParser, OpenAiMessagesParser, TranscriptFormat, detect_format,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/transcripts")
+        .join(name)
+}
+
+fn read_fixture(name: &str) -> String {
+    std::fs::read_to_string(fixture(name)).unwrap()
+}
+
+#[test]
+fn test_aider_history_fixture() {
+    let parsed = AiderHistoryParser
+        .parse_file(&fixture("aider.chat.history.md"))
+        .unwrap();
+
+    assert_eq!(parsed.session_id, "aider.chat.history");
+    assert_eq!(parsed.metadata.user_messages, 5);
+    assert_eq!(parsed.metadata.assistant_messages, 3);
+    assert_eq!(
+        parsed.messages[1].content,
+        "Load the config path from VIBES_CONFIG when it is set"
+    );
+
+    // Startup banner and token counts are not part of the conversation
+    assert!(
+        parsed
+            .messages
+            .iter()
+            .all(|m| !m.content.contains("Aider v0.82.1"))
+    );
+
+    let tools: Vec<_> = parsed
+        .tool_uses
+        .iter()
+        .map(|t| (t.tool_name.as_str(), t.success))
+        .collect();
+    assert_eq!(tools, vec![("Edit", true), ("Bash", true), ("Edit", false)]);
+    assert!(
+        parsed.tool_uses[1]
+            .output
+            .as_deref()
+            .unwrap()
+            .contains("test result: ok")
+    );
+
+    // The second chat block carries its own start time
+    assert_ne!(
+        parsed.messages.first().unwrap().timestamp,
+        parsed.messages.last().unwrap().timestamp
+    );
+}
+
+#[test]
+fn test_codex_rollout_fixture() {
+    let parsed = CodexRolloutParser
+        .parse(&read_fixture("codex-rollout.jsonl"), "codex-session")
+        .unwrap();
+
+    assert_eq!(parsed.metadata.user_messages, 1);
+    assert_eq!(parsed.metadata.assistant_messages, 1);
+    assert!(parsed.messages[0].content.contains("flaky"));
+
+    let tools = &parsed.tool_uses;
+    assert_eq!(tools.len(), 3);
+    assert_eq!(tools[0].tool_name, "shell");
+    assert!(!tools[0].success);
+    assert!(tools[0].output.as_deref().unwrap().contains("FAILED"));
+    assert_eq!(tools[1].tool_name, "apply_patch");
+    assert!(tools[1].success);
+    assert!(tools[2].success);
+}
+
+#[test]
+fn test_openai_messages_fixture() {
+    let parsed = OpenAiMessagesParser
+        .parse(&read_fixture("openai-messages.json"), "openai-session")
+        .unwrap();
+
+    assert_eq!(parsed.metadata.total_messages, 4);
+    assert_eq!(parsed.metadata.user_messages, 2);
+    assert_eq!(parsed.metadata.assistant_messages, 1);
+
+    assert_eq!(parsed.tool_uses.len(), 1);
+    assert_eq!(parsed.tool_uses[0].tool_name, "grep");
+    assert_eq!(parsed.tool_uses[0].input["pattern"], "DEFAULT_PORT");
+    assert!(
+        parsed.tool_uses[0]
+            .output
+            .as_deref()
+            .unwrap()
+            .contains("7432")
+    );
+}
+
+#[test]
+fn test_detect_format_for_fixtures() {
+    let cases = [
+        ("aider.chat.history.md", "aider"),
+        ("codex-rollout.jsonl", "codex"),
+        ("openai-messages.json", "openai"),
+    ];
+    for (name, harness) in cases {
+        assert_eq!(
+            detect_format(&read_fixture(name)).harness(),
+            harness,
+            "{name}"
+        );
+    }
+}
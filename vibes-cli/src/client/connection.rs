@@ -107,6 +107,7 @@ impl VibesClient {
     /// If `name` is provided and the session doesn't exist, a new session will be
     /// created with this human-readable name.
     /// If `cwd` is provided, the spawned process will use it as its working directory.
+    /// `harness` picks the agent a new session runs (Claude Code if `None`).
     /// Attach to a PTY session with optional initial dimensions
     pub async fn attach(
         &self,
@@ -115,6 +116,7 @@ impl VibesClient {
         cwd: Option<String>,
         cols: Option<u16>,
         rows: Option<u16>,
+        harness: Option<String>,
     ) -> Result<()> {
         self.send(ClientMessage::Attach {
             session_id: session_id.to_string(),
@@ -122,6 +124,7 @@ impl VibesClient {
             cwd,
             cols,
             rows,
+            harness,
         })
         .await
     }
@@ -71,8 +71,6 @@ pub struct ClaudeArgs {
 }
 
 pub async fn run(args: ClaudeArgs) -> Result<()> {
-    let config = ConfigLoader::load()?;
-
     // Explicitly error if continue_session is requested, since not yet supported
     if args.continue_session {
         return Err(anyhow!(
@@ -81,8 +79,21 @@ pub async fn run(args: ClaudeArgs) -> Result<()> {
         ));
     }
 
+    run_session(None, args.session_name, args.resume, args.no_serve).await
+}
+
+/// Run a harness (Claude Code if `None`) in a daemon PTY session and proxy
+/// the local terminal to it
+pub async fn run_session(
+    harness: Option<&str>,
+    session_name: Option<String>,
+    resume: Option<String>,
+    no_serve: bool,
+) -> Result<()> {
+    let config = ConfigLoader::load()?;
+
     // Ensure daemon is running (unless --no-serve is set)
-    if !args.no_serve {
+    if !no_serve {
         ensure_daemon_running(&config.server.host, config.server.port).await?;
     }
 
@@ -91,13 +102,13 @@ pub async fn run(args: ClaudeArgs) -> Result<()> {
     let mut client = VibesClient::connect_url(&url).await?;
 
     // Attach to PTY session (creates it if needed)
-    let (session_id, session_name) = if let Some(resume_id) = args.resume.clone() {
+    let (session_id, session_name) = if let Some(resume_id) = resume {
         // Resuming an existing session - don't set a new name
         (resume_id, None)
     } else {
         // New session - use provided name or None
         let id = uuid::Uuid::new_v4().to_string();
-        (id, args.session_name.clone())
+        (id, session_name)
     };
 
     // Get current working directory
@@ -108,9 +119,16 @@ pub async fn run(args: ClaudeArgs) -> Result<()> {
     // Get initial terminal size to create PTY with correct dimensions
     let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
 
-    // Send attach request with session name, cwd, and initial dimensions
+    // Send attach request with session name, cwd, initial dimensions and harness
     client
-        .attach(&session_id, session_name, cwd, Some(cols), Some(rows))
+        .attach(
+            &session_id,
+            session_name,
+            cwd,
+            Some(cols),
+            Some(rows),
+            harness.map(String::from),
+        )
         .await?;
 
     // Wait for attach acknowledgment
@@ -103,7 +103,13 @@ async fn execute_send(args: SendArgs) -> Result<()> {
         ),
     };
 
-    // 3. Connect to Iggy and authenticate
+    // 3. Send to Iggy
+    send_event(event, &args.stream, &args.topic).await
+}
+
+/// Send an event to the EventLog via the Iggy HTTP API
+pub(crate) async fn send_event(event: VibesEvent, stream: &str, topic: &str) -> Result<()> {
+    // Connect to Iggy and authenticate
     let config = IggyClientConfig::from_env();
     let mut client = IggyHttpClient::from_config(&config);
     client
@@ -111,11 +117,11 @@ async fn execute_send(args: SendArgs) -> Result<()> {
         .await
         .context("Failed to authenticate with Iggy")?;
 
-    // 4. Wrap in StoredEvent (adds event_id) and serialize
+    // Wrap in StoredEvent (adds event_id) and serialize
     let stored = StoredEvent::new(event);
     let serialized = serde_json::to_vec(&stored).context("Failed to serialize event")?;
     client
-        .send_message(&args.stream, &args.topic, &serialized)
+        .send_message(stream, topic, &serialized)
         .await
         .context("Failed to send message to Iggy")?;
 
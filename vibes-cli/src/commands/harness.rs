@@ -0,0 +1,36 @@
+//! Harness commands - run other AI coding agents through the vibes daemon
+//!
+//! `vibes aider`, `vibes codex` and `vibes gemini` start the agent in a PTY
+//! session on the server, with the same mirroring and event capture as
+//! `vibes claude`.
+
+use anyhow::Result;
+use clap::Args;
+
+use super::claude::run_session;
+
+#[derive(Args)]
+#[command(after_long_help = "\
+Examples:
+  vibes codex                          Start interactive session
+  vibes aider --session-name refactor  Start named session
+  vibes gemini -r <id>                 Resume a session by ID
+")]
+pub struct HarnessArgs {
+    /// Human-friendly session name (shown in vibes UI)
+    #[arg(long)]
+    pub session_name: Option<String>,
+
+    /// Disable background server for this session
+    #[arg(long)]
+    pub no_serve: bool,
+
+    /// Resume specific session by ID
+    #[arg(short = 'r', long)]
+    pub resume: Option<String>,
+}
+
+/// Run `harness` (its command name, e.g. "aider") in a daemon PTY session
+pub async fn run(harness: &str, args: HarnessArgs) -> Result<()> {
+    run_session(Some(harness), args.session_name, args.resume, args.no_serve).await
+}
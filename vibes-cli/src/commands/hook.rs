@@ -0,0 +1,199 @@
+//! Hook command: the handler Claude Code runs for every hook
+//!
+//! `vibes hook <type>` reads the hook JSON from stdin, asks the daemon over
+//! its local socket, and prints the daemon's Claude Code hook output. It
+//! always exits successfully so a missing or slow daemon never blocks Claude.
+
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde_json::{Map, Value, json};
+use vibes_core::VibesEvent;
+use vibes_core::hooks::HookEvent;
+use vibes_core::hooks::socket::{self, HookRequest, HookSocketError};
+
+use super::event::send_event;
+
+/// Arguments for the `hook` command
+#[derive(Debug, Args)]
+pub struct HookArgs {
+    /// Hook type, e.g. pre-tool-use, session-start, stop
+    pub hook_type: String,
+
+    /// Milliseconds to wait for the daemon before carrying on
+    #[arg(long, default_value_t = socket::DEFAULT_HOOK_TIMEOUT.as_millis() as u64)]
+    pub timeout_ms: u64,
+
+    /// Daemon hook socket (defaults to ~/.config/vibes/hooks.sock)
+    #[arg(long, hide = true)]
+    pub socket: Option<PathBuf>,
+}
+
+/// Run the hook command
+pub async fn run(args: HookArgs) -> Result<()> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read from stdin")?;
+
+    let session_override = std::env::var("VIBES_SESSION_ID")
+        .ok()
+        .filter(|s| !s.is_empty());
+
+    let output = match build_request(&args.hook_type, &input, session_override) {
+        Ok(request) => answer(request, &args).await,
+        Err(e) => {
+            eprintln!("vibes hook: {:#}", e);
+            json!({})
+        }
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+/// Wrap Claude Code's hook input as a typed hook event
+///
+/// `session_override` (from `VIBES_SESSION_ID`) wins over the session in the
+/// input.
+fn build_request(
+    hook_type: &str,
+    input: &str,
+    session_override: Option<String>,
+) -> Result<HookRequest> {
+    let mut payload: Map<String, Value> = if input.trim().is_empty() {
+        Map::new()
+    } else {
+        serde_json::from_str(input).context("Hook input is not a JSON object")?
+    };
+    payload.insert("type".to_string(), json!(hook_type.replace('-', "_")));
+
+    let event: HookEvent = serde_json::from_value(Value::Object(payload))
+        .with_context(|| format!("Invalid {} hook input", hook_type))?;
+    let session_id = session_override.or_else(|| event.session_id().map(str::to_string));
+
+    Ok(HookRequest { session_id, event })
+}
+
+/// Ask the daemon for a decision, falling back to an empty response
+///
+/// If the daemon is not running the event is recorded in the EventLog
+/// directly; if it is running but slow, the daemon records it itself.
+async fn answer(request: HookRequest, args: &HookArgs) -> Value {
+    let timeout = Duration::from_millis(args.timeout_ms);
+
+    match ask_daemon(&request, args, timeout).await {
+        Ok(output) => return output,
+        Err(HookSocketError::Unavailable(_)) => {}
+        Err(_) => return json!({}),
+    }
+
+    let event = VibesEvent::Hook {
+        session_id: request.session_id,
+        event: request.event,
+    };
+    let _ = tokio::time::timeout(timeout, send_event(event, "vibes", "events")).await;
+    json!({})
+}
+
+/// Send the hook over the daemon's socket
+#[cfg(unix)]
+async fn ask_daemon(
+    request: &HookRequest,
+    args: &HookArgs,
+    timeout: Duration,
+) -> Result<Value, HookSocketError> {
+    let path = args
+        .socket
+        .clone()
+        .unwrap_or_else(socket::default_socket_path);
+    socket::request(&path, request, timeout).await
+}
+
+/// The hook socket is Unix-only, so the daemon is never reachable
+#[cfg(not(unix))]
+async fn ask_daemon(
+    _request: &HookRequest,
+    _args: &HookArgs,
+    _timeout: Duration,
+) -> Result<Value, HookSocketError> {
+    Err(HookSocketError::Unavailable(
+        std::io::ErrorKind::Unsupported.into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use vibes_core::hooks::HookType;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        hook: HookArgs,
+    }
+
+    #[test]
+    fn test_parse_hook_args() {
+        let cli = TestCli::try_parse_from(["test", "pre-tool-use"]).unwrap();
+        assert_eq!(cli.hook.hook_type, "pre-tool-use");
+        assert_eq!(cli.hook.timeout_ms, 2000);
+        assert!(cli.hook.socket.is_none());
+    }
+
+    #[test]
+    fn test_build_request_wraps_input_with_type() {
+        let input = r#"{"session_id":"sess-1","tool_name":"Bash","tool_input":{"command":"ls"}}"#;
+
+        let request = build_request("pre-tool-use", input, None).unwrap();
+
+        assert_eq!(request.session_id.as_deref(), Some("sess-1"));
+        assert_eq!(request.event.hook_type(), HookType::PreToolUse);
+    }
+
+    #[test]
+    fn test_build_request_session_override() {
+        let input = r#"{"session_id":"sess-1","reason":"done"}"#;
+
+        let request = build_request("stop", input, Some("vibes-42".to_string())).unwrap();
+
+        assert_eq!(request.session_id.as_deref(), Some("vibes-42"));
+    }
+
+    #[test]
+    fn test_build_request_rejects_unknown_type_and_bad_json() {
+        assert!(build_request("not-a-hook", "{}", None).is_err());
+        assert!(build_request("stop", "not json", None).is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_answer_returns_daemon_output() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("hooks.sock");
+        let listener = socket::bind(&path).unwrap();
+        let shutdown = tokio_util::sync::CancellationToken::new();
+        tokio::spawn(socket::serve(
+            listener,
+            |_| async { json!({"decision": "block", "reason": "keep going"}) },
+            shutdown.clone(),
+        ));
+
+        let args = HookArgs {
+            hook_type: "stop".to_string(),
+            timeout_ms: 2000,
+            socket: Some(path),
+        };
+        let request = build_request("stop", r#"{"reason":"done"}"#, None).unwrap();
+
+        assert_eq!(
+            answer(request, &args).await,
+            json!({"decision": "block", "reason": "keep going"})
+        );
+        shutdown.cancel();
+    }
+}
@@ -4,6 +4,8 @@ pub mod claude;
 pub mod config;
 pub mod eval;
 pub mod event;
+pub mod harness;
+pub mod hook;
 pub mod models;
 pub mod observe;
 pub mod plugin;
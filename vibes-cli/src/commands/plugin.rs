@@ -1,8 +1,17 @@
 //! Plugin management commands
 
-use anyhow::Result;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
+use dialoguer::{Confirm, theme::ColorfulTheme};
+use vibes_core::plugins::{PluginInstaller, PluginSource, UpdateOutcome};
 use vibes_core::{PluginHost, PluginHostConfig, PluginState};
+use vibes_plugin_api::Permission;
+use vibes_plugin_api::permission::is_granted;
+
+use crate::config::DEFAULT_HOST;
+use crate::daemon::{is_process_alive, read_daemon_state};
 
 /// Plugin management arguments
 #[derive(Args)]
@@ -20,10 +29,13 @@ pub enum PluginCommands {
         #[arg(long)]
         all: bool,
     },
-    /// Enable a plugin
+    /// Enable a plugin after approving the permissions it requests
     Enable {
         /// Plugin name to enable
         name: String,
+        /// Approve the requested permissions without prompting
+        #[arg(long, short)]
+        yes: bool,
     },
     /// Disable a plugin
     Disable {
@@ -35,24 +47,48 @@ pub enum PluginCommands {
         /// Plugin name
         name: String,
     },
-    /// Reload a plugin (development)
+    /// Reload a plugin in the running daemon (development)
     Reload {
         /// Plugin name to reload
         name: String,
     },
+    /// Install a plugin from a git repository, tarball or local path
+    Install {
+        /// Git URL (append #<rev> to pin), .tar.gz/.tgz/.tar URL or path, or local directory
+        source: String,
+        /// Expected SHA-256 of the tarball, or of the prebuilt plugin build for git and
+        /// path sources (sources built with cargo are pinned by commit instead)
+        #[arg(long)]
+        sha256: Option<String>,
+    },
+    /// Rebuild installed plugins from their sources
+    Update {
+        /// Plugin to update (all installed plugins if omitted)
+        name: Option<String>,
+    },
+    /// Remove an installed plugin
+    Uninstall {
+        /// Plugin name to remove
+        name: String,
+    },
 }
 
 /// Run plugin command
-pub fn run(args: PluginArgs) -> Result<()> {
+pub async fn run(args: PluginArgs) -> Result<()> {
     let config = PluginHostConfig::default();
     let mut host = PluginHost::new(config);
 
     match args.command {
         PluginCommands::List { all } => list_plugins(&mut host, all),
-        PluginCommands::Enable { name } => enable_plugin(&mut host, &name),
+        PluginCommands::Enable { name, yes } => enable_plugin(&mut host, &name, yes),
         PluginCommands::Disable { name } => disable_plugin(&mut host, &name),
         PluginCommands::Info { name } => show_plugin_info(&mut host, &name),
-        PluginCommands::Reload { name } => reload_plugin(&mut host, &name),
+        PluginCommands::Reload { name } => reload_plugin(&name).await,
+        PluginCommands::Install { source, sha256 } => {
+            install_plugin(&source, sha256.as_deref()).await
+        }
+        PluginCommands::Update { name } => update_plugins(name.as_deref()).await,
+        PluginCommands::Uninstall { name } => uninstall_plugin(&name),
     }
 }
 
@@ -64,33 +100,17 @@ fn list_plugins(host: &mut PluginHost, _all: bool) -> Result<()> {
 
     let plugins = host.list_plugins(true);
 
-    if plugins.is_empty() {
+    if plugins.is_empty() && host.load_failures().is_empty() {
         let config = PluginHostConfig::default();
         let plugin_dir = config.user_plugin_dir.display();
 
-        // Platform-specific library extension
-        let lib_ext = if cfg!(target_os = "macos") {
-            "dylib"
-        } else if cfg!(target_os = "windows") {
-            "dll"
-        } else {
-            "so"
-        };
-
         println!("No plugins installed");
         println!();
         println!("Plugin directory: {}", plugin_dir);
         println!();
         println!("To install a plugin:");
-        println!(
-            "  1. Create a plugin directory: mkdir -p {}/my-plugin",
-            plugin_dir
-        );
-        println!(
-            "  2. Copy the plugin library: cp libmy_plugin.{} {}/my-plugin/my-plugin.{}",
-            lib_ext, plugin_dir, lib_ext
-        );
-        println!("  3. Enable the plugin: vibes plugin enable my-plugin");
+        println!("  1. Install it: vibes plugin install <git-url|tarball|path>");
+        println!("  2. Enable it:  vibes plugin enable <name>");
         return Ok(());
     }
 
@@ -113,16 +133,73 @@ fn list_plugins(host: &mut PluginHost, _all: bool) -> Result<()> {
         );
     }
 
+    let mut failures: Vec<_> = host.load_failures().iter().collect();
+    failures.sort_by_key(|(name, _)| name.as_str());
+    for (name, error) in failures {
+        println!("✗ {}    Failed to load: {}", name, error);
+        if error.is_permission_denied() {
+            println!(
+                "    Run 'vibes plugin enable {}' to review and approve its permissions.",
+                name
+            );
+        }
+    }
+
     Ok(())
 }
 
-fn enable_plugin(host: &mut PluginHost, name: &str) -> Result<()> {
+fn enable_plugin(host: &mut PluginHost, name: &str, yes: bool) -> Result<()> {
+    let manifest = host
+        .inspect_plugin(name)
+        .with_context(|| format!("Failed to read plugin '{}'", name))?;
+
+    if manifest.permissions.is_empty() {
+        println!("{} v{} requests no permissions.", name, manifest.version);
+    } else {
+        println!("{} v{} requests permission to:", name, manifest.version);
+        for permission in &manifest.permissions {
+            println!("  • {}", permission);
+        }
+        println!();
+        if has_advisory_permissions(&manifest.permissions) {
+            println!(
+                "Note: native plugins run inside vibes, so file access and process spawning are\n\
+                 only limited by the plugin's own checks. Grant them only to plugins you trust."
+            );
+            println!();
+        }
+
+        let approved = yes
+            || Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt("Grant these permissions and enable the plugin?")
+                .default(false)
+                .interact()?;
+        if !approved {
+            println!("Plugin not enabled.");
+            return Ok(());
+        }
+    }
+
+    host.approve_permissions(name, manifest.permissions)?;
     host.enable_plugin(name)?;
     println!("Enabled plugin: {}", name);
     println!("Run 'vibes plugin list' to verify the plugin loads correctly.");
     Ok(())
 }
 
+/// Whether the host can't enforce some of these permissions for native plugins
+///
+/// Native plugins are only stopped from touching files or spawning processes
+/// if they call `PluginContext::check_path` and `check_spawn` themselves.
+fn has_advisory_permissions(permissions: &[Permission]) -> bool {
+    permissions.iter().any(|permission| {
+        matches!(
+            permission,
+            Permission::Filesystem { .. } | Permission::SpawnProcesses
+        )
+    })
+}
+
 fn disable_plugin(host: &mut PluginHost, name: &str) -> Result<()> {
     host.disable_plugin(name)?;
     println!("Disabled plugin: {}", name);
@@ -164,6 +241,18 @@ fn show_plugin_info(host: &mut PluginHost, name: &str) -> Result<()> {
             PluginState::Failed { error } => println!("Status:      Failed ({})", error),
         }
 
+        if !m.permissions.is_empty() {
+            println!();
+            println!("Permissions:");
+            for permission in &m.permissions {
+                if is_granted(&info.permissions, permission) {
+                    println!("  ✓ {}", permission);
+                } else {
+                    println!("  ✗ {} (not approved)", permission);
+                }
+            }
+        }
+
         if !m.commands.is_empty() {
             println!();
             println!("Commands:");
@@ -181,26 +270,105 @@ fn show_plugin_info(host: &mut PluginHost, name: &str) -> Result<()> {
     Ok(())
 }
 
-fn reload_plugin(host: &mut PluginHost, name: &str) -> Result<()> {
-    // For now, just report that reload isn't fully implemented
-    // Full reload would require unloading the library and reloading it
+async fn reload_plugin(name: &str) -> Result<()> {
+    // Plugins are loaded by the daemon; a CLI-side reload would not affect it
+    let Some(daemon) = read_daemon_state().filter(|state| is_process_alive(state.pid)) else {
+        println!("vibes daemon is not running; plugins are loaded fresh when it starts.");
+        return Ok(());
+    };
+
     println!("Reloading plugin: {}", name);
 
-    // First disable
-    host.disable_plugin(name)?;
+    let url = format!(
+        "http://{}:{}/api/plugins/{}/reload",
+        DEFAULT_HOST, daemon.port, name
+    );
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .context("Failed to create HTTP client")?;
+    let response = client
+        .post(&url)
+        .send()
+        .await
+        .context("Failed to reach vibes daemon")?;
+
+    let status = response.status();
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap_or_default()).unwrap_or_default();
+    if !status.is_success() {
+        let error = body["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to reload plugin '{}': {}", name, error);
+    }
 
-    // Then re-enable
-    host.enable_plugin(name)?;
+    match body["version"].as_str() {
+        Some(version) => println!("Plugin '{}' reloaded (v{})", name, version),
+        None => println!("Plugin '{}' reloaded", name),
+    }
 
-    // Reload all plugins
-    if let Err(e) = host.load_all() {
-        println!("Warning: Error reloading plugins: {}", e);
+    Ok(())
+}
+
+async fn install_plugin(source: &str, sha256: Option<&str>) -> Result<()> {
+    let source = PluginSource::parse(source)?;
+    let mut installer = PluginInstaller::new(PluginHostConfig::default());
+
+    println!("Installing plugin from {}...", source);
+    let installed = installer.install(&source, sha256).await?;
+
+    println!(
+        "Installed {} v{} (sha256 {})",
+        installed.name,
+        installed.version,
+        &installed.sha256[..12]
+    );
+    println!(
+        "Run 'vibes plugin enable {}' to review its permissions and enable it.",
+        installed.name
+    );
+    Ok(())
+}
+
+async fn update_plugins(name: Option<&str>) -> Result<()> {
+    let mut installer = PluginInstaller::new(PluginHostConfig::default());
+    let names = match name {
+        Some(name) => vec![name.to_string()],
+        None => installer.installed()?.into_iter().map(|p| p.name).collect(),
+    };
+    if names.is_empty() {
+        println!("No plugins installed with 'vibes plugin install'");
+        return Ok(());
     }
 
-    println!("Plugin '{}' reloaded", name);
-    println!();
-    println!("Note: Some changes may require restarting vibes to take effect.");
+    let mut failed = 0;
+    for name in names {
+        match installer.update(&name).await {
+            Ok(UpdateOutcome::UpToDate(plugin)) => {
+                println!("{} v{} is up to date", plugin.name, plugin.version);
+            }
+            Ok(UpdateOutcome::Updated { previous, current }) => {
+                println!(
+                    "Updated {} v{} -> v{}",
+                    current.name, previous.version, current.version
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to update {}: {}", name, e);
+                failed += 1;
+            }
+        }
+    }
 
+    if failed > 0 {
+        anyhow::bail!("{} plugin(s) failed to update", failed);
+    }
+    Ok(())
+}
+
+fn uninstall_plugin(name: &str) -> Result<()> {
+    let installer = PluginInstaller::new(PluginHostConfig::default());
+    let removed = installer.uninstall(name)?;
+    println!("Uninstalled {} v{}", removed.name, removed.version);
     Ok(())
 }
 
@@ -228,7 +396,13 @@ mod tests {
 
         // Test enable command
         let cli = TestCli::parse_from(["test", "enable", "my-plugin"]);
-        assert!(matches!(cli.cmd, PluginCommands::Enable { name } if name == "my-plugin"));
+        assert!(matches!(
+            cli.cmd,
+            PluginCommands::Enable { name, yes: false } if name == "my-plugin"
+        ));
+
+        let cli = TestCli::parse_from(["test", "enable", "my-plugin", "--yes"]);
+        assert!(matches!(cli.cmd, PluginCommands::Enable { yes: true, .. }));
 
         // Test disable command
         let cli = TestCli::parse_from(["test", "disable", "my-plugin"]);
@@ -237,6 +411,30 @@ mod tests {
         // Test info command
         let cli = TestCli::parse_from(["test", "info", "my-plugin"]);
         assert!(matches!(cli.cmd, PluginCommands::Info { name } if name == "my-plugin"));
+
+        // Test reload command
+        let cli = TestCli::parse_from(["test", "reload", "my-plugin"]);
+        assert!(matches!(cli.cmd, PluginCommands::Reload { name } if name == "my-plugin"));
+
+        // Test install, update and uninstall commands
+        let cli = TestCli::parse_from([
+            "test",
+            "install",
+            "https://github.com/acme/vibes-lint#v1",
+            "--sha256",
+            "abc",
+        ]);
+        assert!(matches!(
+            cli.cmd,
+            PluginCommands::Install { source, sha256: Some(sum) }
+                if source == "https://github.com/acme/vibes-lint#v1" && sum == "abc"
+        ));
+
+        let cli = TestCli::parse_from(["test", "update"]);
+        assert!(matches!(cli.cmd, PluginCommands::Update { name: None }));
+
+        let cli = TestCli::parse_from(["test", "uninstall", "my-plugin"]);
+        assert!(matches!(cli.cmd, PluginCommands::Uninstall { name } if name == "my-plugin"));
     }
 
     #[test]
@@ -246,6 +444,7 @@ mod tests {
             user_plugin_dir: dir.path().to_path_buf(),
             project_plugin_dir: None,
             handler_timeout: std::time::Duration::from_secs(5),
+            wasm_limits: Default::default(),
         };
         let mut host = PluginHost::new(config);
 
@@ -255,4 +454,17 @@ mod tests {
         // Disable it
         host.disable_plugin("test-plugin").unwrap();
     }
+
+    #[test]
+    fn test_filesystem_and_spawn_permissions_are_advisory() {
+        assert!(has_advisory_permissions(&[
+            Permission::ReadEvents,
+            Permission::SpawnProcesses,
+        ]));
+        assert!(has_advisory_permissions(&[Permission::read_path("/tmp")]));
+        assert!(!has_advisory_permissions(&[
+            Permission::ReadEvents,
+            Permission::AnswerHooks,
+        ]));
+    }
 }
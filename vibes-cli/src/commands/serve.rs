@@ -8,6 +8,7 @@
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use tracing::{info, warn};
+use vibes_core::NotificationConfig;
 use vibes_server::{ServerConfig, VibesServer};
 
 use crate::config::ConfigLoader;
@@ -78,6 +79,8 @@ struct ResolvedSettings {
     /// Named tunnel hostname (from config)
     tunnel_hostname: Option<String>,
     notify: bool,
+    /// Notification sinks and routing (from config)
+    notifications: NotificationConfig,
     /// Ollama base URL from config (e.g., "http://localhost:11434")
     ollama_base_url: Option<String>,
 }
@@ -106,6 +109,7 @@ pub async fn run(args: ServeArgs) -> Result<()> {
                 tunnel_name: config.tunnel.name,
                 tunnel_hostname: config.tunnel.hostname,
                 notify: args.notify,
+                notifications: config.notifications,
                 ollama_base_url,
             };
 
@@ -135,7 +139,14 @@ async fn run_foreground(settings: &ResolvedSettings, _ollama: OllamaManager) ->
         tunnel_name: settings.tunnel_name.clone(),
         tunnel_hostname: settings.tunnel_hostname.clone(),
         notify_enabled: settings.notify,
+        notifications: settings.notifications.clone(),
         ollama_base_url: settings.ollama_base_url.clone(),
+        #[cfg(unix)]
+        hook_socket: Some(vibes_core::hooks::socket::default_socket_path()),
+        #[cfg(not(unix))]
+        hook_socket: None,
+        watch_capabilities: true,
+        reinstall_hooks: true,
     };
 
     info!("Starting vibes server on {}:{}", config.host, config.port);
@@ -146,8 +157,12 @@ async fn run_foreground(settings: &ResolvedSettings, _ollama: OllamaManager) ->
         tracing::warn!("Failed to write daemon state file: {}", e);
     }
 
-    // Create server with Iggy persistence
-    let server = VibesServer::new_with_iggy(config).await?;
+    // Create server with Iggy persistence (and notifications if requested)
+    let server = if settings.notify {
+        VibesServer::with_notifications(config).await?
+    } else {
+        VibesServer::new_with_iggy(config).await?
+    };
     let result = server.run().await;
 
     // Clear daemon state file on exit
@@ -130,6 +130,7 @@ impl ConfigLoader {
                     }
                 },
             },
+            notifications: overlay.notifications.or(base.notifications),
         }
     }
 
@@ -145,6 +146,7 @@ impl ConfigLoader {
             tunnel: raw.tunnel,
             models: raw.models,
             auth: raw.auth,
+            notifications: raw.notifications.unwrap_or_default(),
         }
     }
 
@@ -577,6 +579,7 @@ default_model = "claude-sonnet-4"
             tunnel: TunnelConfigSection::default(),
             models: ModelsConfigSection::default(),
             auth: AccessConfig::default(),
+            notifications: None,
         };
 
         let overlay = RawVibesConfig {
@@ -593,6 +596,7 @@ default_model = "claude-sonnet-4"
             tunnel: TunnelConfigSection::default(),
             models: ModelsConfigSection::default(),
             auth: AccessConfig::default(),
+            notifications: None,
         };
 
         let merged = ConfigLoader::merge_raw(base, overlay);
@@ -625,6 +629,7 @@ default_model = "claude-sonnet-4"
             tunnel: TunnelConfigSection::default(),
             models: ModelsConfigSection::default(),
             auth: AccessConfig::default(),
+            notifications: None,
         };
 
         let overlay = RawVibesConfig {
@@ -637,6 +642,7 @@ default_model = "claude-sonnet-4"
             tunnel: TunnelConfigSection::default(),
             models: ModelsConfigSection::default(),
             auth: AccessConfig::default(),
+            notifications: None,
         };
 
         let merged = ConfigLoader::merge_raw(base, overlay);
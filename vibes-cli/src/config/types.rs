@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use vibes_core::AccessConfig;
+use vibes_core::{AccessConfig, NotificationConfig};
 
 /// Default host for the vibes server
 pub const DEFAULT_HOST: &str = "127.0.0.1";
@@ -25,6 +25,9 @@ pub struct RawVibesConfig {
 
     #[serde(default)]
     pub auth: AccessConfig,
+
+    /// Notification settings (replaced wholesale by a higher-priority layer)
+    pub notifications: Option<NotificationConfig>,
 }
 
 /// Server config as stored in TOML (optional fields for proper merging)
@@ -57,6 +60,9 @@ pub struct VibesConfig {
 
     #[serde(default)]
     pub auth: AccessConfig,
+
+    #[serde(default)]
+    pub notifications: NotificationConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -358,6 +364,7 @@ mod tests {
             tunnel: TunnelConfigSection::default(),
             models: ModelsConfigSection::default(),
             auth: AccessConfig::default(),
+            notifications: NotificationConfig::default(),
         };
 
         let toml_str = toml::to_string(&config).unwrap();
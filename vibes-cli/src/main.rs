@@ -77,16 +77,24 @@ fn print_help_with_plugins() {
 enum Commands {
     /// Manage agents
     Agent(commands::agent::AgentArgs),
+    /// Run Aider with vibes enhancements
+    Aider(commands::harness::HarnessArgs),
     /// Manage Cloudflare Access authentication
     Auth(commands::auth::AuthArgs),
     /// Proxy Claude Code with vibes enhancements
     Claude(commands::claude::ClaudeArgs),
+    /// Run Codex CLI with vibes enhancements
+    Codex(commands::harness::HarnessArgs),
     /// Manage configuration
     Config(commands::config::ConfigArgs),
     /// Manage evaluation studies
     Eval(commands::eval::EvalArgs),
     /// Send events to the EventLog
     Event(commands::event::EventArgs),
+    /// Run Gemini CLI with vibes enhancements
+    Gemini(commands::harness::HarnessArgs),
+    /// Handle a Claude Code hook (run by Claude Code)
+    Hook(commands::hook::HookArgs),
     /// Manage AI models and credentials
     Models(commands::models::ModelsArgs),
     /// View and manage observability traces
@@ -121,19 +129,28 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    // Hook output is read by Claude Code, so keep logging off stdout
+    if let Commands::Hook(args) = cli.command {
+        return commands::hook::run(args).await;
+    }
+
     let filter = if cli.verbose { "debug" } else { "info" };
     tracing_subscriber::fmt().with_env_filter(filter).init();
 
     match cli.command {
         Commands::Agent(args) => commands::agent::run(args).await,
+        Commands::Aider(args) => commands::harness::run("aider", args).await,
         Commands::Auth(args) => commands::auth::run(args).await,
         Commands::Claude(args) => commands::claude::run(args).await,
+        Commands::Codex(args) => commands::harness::run("codex", args).await,
         Commands::Config(args) => commands::config::run(args),
         Commands::Eval(args) => commands::eval::run(args).await,
         Commands::Event(args) => commands::event::run(args).await,
+        Commands::Gemini(args) => commands::harness::run("gemini", args).await,
+        Commands::Hook(_) => unreachable!("hook is handled before logging is set up"),
         Commands::Models(args) => commands::models::run(args).await,
         Commands::Observe(args) => commands::observe::run(args).await,
-        Commands::Plugin(args) => commands::plugin::run(args),
+        Commands::Plugin(args) => commands::plugin::run(args).await,
         Commands::Serve(args) => commands::serve::run(args).await,
         Commands::Sessions(args) => commands::sessions::run(args).await,
         Commands::Tui(args) => commands::tui::run(args).await,
@@ -194,4 +211,19 @@ mod tests {
         assert!(is_top_level_help_args(&args));
         assert!(!is_no_args_check(&args));
     }
+
+    #[test]
+    fn test_harness_subcommands_are_not_plugin_commands() {
+        for harness in ["aider", "codex", "gemini"] {
+            let cli = Cli::try_parse_from(["vibes", harness, "--session-name", "work"]).unwrap();
+            match (harness, cli.command) {
+                ("aider", Commands::Aider(args))
+                | ("codex", Commands::Codex(args))
+                | ("gemini", Commands::Gemini(args)) => {
+                    assert_eq!(args.session_name.as_deref(), Some("work"));
+                }
+                _ => panic!("vibes {} should parse as a harness command", harness),
+            }
+        }
+    }
 }
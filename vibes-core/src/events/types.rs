@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use vibes_iggy::Partitionable;
+use vibes_introspection::CapabilityChange;
 
 use crate::hooks::HookEvent;
 
@@ -144,6 +145,23 @@ pub enum VibesEvent {
         session_id: Option<String>,
         event: HookEvent,
     },
+
+    /// A harness's hooks, config files or version changed under vibes
+    CapabilityDrift {
+        /// Harness whose capabilities changed (e.g. "claude")
+        harness: String,
+        changes: Vec<CapabilityChange>,
+    },
+
+    /// Event published by a plugin
+    Plugin {
+        /// Publishing plugin (the event's namespace)
+        plugin: String,
+        /// Event type within the plugin's namespace
+        event_type: String,
+        session_id: Option<String>,
+        payload: serde_json::Value,
+    },
 }
 
 impl VibesEvent {
@@ -158,9 +176,11 @@ impl VibesEvent {
             VibesEvent::OwnershipTransferred { session_id, .. } => Some(session_id),
             VibesEvent::SessionRemoved { session_id, .. } => Some(session_id),
             VibesEvent::Hook { session_id, .. } => session_id.as_deref(),
+            VibesEvent::Plugin { session_id, .. } => session_id.as_deref(),
             VibesEvent::ClientConnected { .. } => None,
             VibesEvent::ClientDisconnected { .. } => None,
             VibesEvent::TunnelStateChanged { .. } => None,
+            VibesEvent::CapabilityDrift { .. } => None,
         }
     }
 }
@@ -572,6 +592,26 @@ mod tests {
         assert_eq!(event.session_id(), None);
     }
 
+    // ==================== CapabilityDrift Tests ====================
+
+    #[test]
+    fn vibes_event_capability_drift_serialization_roundtrip() {
+        let event = VibesEvent::CapabilityDrift {
+            harness: "claude".to_string(),
+            changes: vec![CapabilityChange::VersionChanged {
+                from: Some("1.0.0".to_string()),
+                to: Some("1.1.0".to_string()),
+            }],
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "capability_drift");
+        assert_eq!(json["changes"][0]["kind"], "version_changed");
+
+        let parsed: VibesEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, event);
+        assert_eq!(parsed.session_id(), None);
+    }
+
     // ==================== Hook Event Tests ====================
 
     #[test]
@@ -625,6 +665,26 @@ mod tests {
         assert_eq!(event.session_id(), Some("sess-456"));
     }
 
+    // ==================== Plugin Event Tests ====================
+
+    #[test]
+    fn vibes_event_plugin_serialization_roundtrip() {
+        let event = VibesEvent::Plugin {
+            plugin: "groove".to_string(),
+            event_type: "learning_extracted".to_string(),
+            session_id: Some("sess-789".to_string()),
+            payload: serde_json::json!({"learning_id": "l-1"}),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "plugin");
+        assert_eq!(json["event_type"], "learning_extracted");
+
+        let parsed: VibesEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, event);
+        assert_eq!(parsed.session_id(), Some("sess-789"));
+    }
+
     // ==================== StoredEvent Tests ====================
 
     #[test]
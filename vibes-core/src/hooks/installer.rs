@@ -1,17 +1,27 @@
 //! Hook installer - installs vibes hooks into Claude Code configuration
 //!
-//! This module handles:
-//! - Writing hook scripts to ~/.claude/hooks/vibes/
-//! - Updating ~/.claude/settings.json to register the hooks
+//! This module handles updating ~/.claude/settings.json so each Claude Code
+//! hook runs `vibes hook <type>`. Hook scripts written by earlier versions
+//! (~/.claude/hooks/vibes/) are replaced.
 
 use std::fs;
 use std::path::{Path, PathBuf};
 
-#[cfg(unix)]
-use std::os::unix::fs::PermissionsExt;
 use tracing::{debug, info};
 
-use super::scripts;
+/// Claude Code hook types and the `vibes hook` argument registered for each
+const VIBES_HOOKS: [(&str, &str); 10] = [
+    ("PreToolUse", "pre-tool-use"),
+    ("PostToolUse", "post-tool-use"),
+    ("Stop", "stop"),
+    ("SessionStart", "session-start"),
+    ("UserPromptSubmit", "user-prompt-submit"),
+    ("PermissionRequest", "permission-request"),
+    ("Notification", "notification"),
+    ("SubagentStop", "subagent-stop"),
+    ("PreCompact", "pre-compact"),
+    ("SessionEnd", "session-end"),
+];
 
 /// Error type for hook installation
 #[derive(Debug, thiserror::Error)]
@@ -19,15 +29,6 @@ pub enum InstallError {
     #[error("Failed to determine home directory")]
     NoHomeDir,
 
-    #[error("Failed to create hooks directory: {0}")]
-    CreateDir(std::io::Error),
-
-    #[error("Failed to write script {0}: {1}")]
-    WriteScript(String, std::io::Error),
-
-    #[error("Failed to set script permissions: {0}")]
-    SetPermissions(std::io::Error),
-
     #[error("Failed to read settings.json: {0}")]
     ReadSettings(std::io::Error),
 
@@ -39,21 +40,13 @@ pub enum InstallError {
 }
 
 /// Hook installer configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct HookInstallerConfig {
     /// Claude config directory (default: ~/.claude)
     pub claude_dir: Option<PathBuf>,
-    /// Whether to overwrite existing scripts
-    pub overwrite: bool,
-}
-
-impl Default for HookInstallerConfig {
-    fn default() -> Self {
-        Self {
-            claude_dir: None,
-            overwrite: true,
-        }
-    }
+    /// vibes binary the hooks run (default: the running executable if it is
+    /// vibes, otherwise `vibes` from PATH)
+    pub vibes_bin: Option<PathBuf>,
 }
 
 /// Installs vibes hooks into Claude Code
@@ -78,50 +71,32 @@ impl HookInstaller {
             .ok_or(InstallError::NoHomeDir)
     }
 
-    /// Get the hooks directory
-    fn hooks_dir(&self) -> Result<PathBuf, InstallError> {
+    /// Get the directory earlier versions wrote hook scripts to
+    fn legacy_hooks_dir(&self) -> Result<PathBuf, InstallError> {
         Ok(self.claude_dir()?.join("hooks").join("vibes"))
     }
 
-    /// Install all hook scripts
-    pub fn install_scripts(&self) -> Result<PathBuf, InstallError> {
-        let hooks_dir = self.hooks_dir()?;
-
-        // Create hooks directory
-        fs::create_dir_all(&hooks_dir).map_err(InstallError::CreateDir)?;
-        debug!("Created hooks directory: {:?}", hooks_dir);
-
-        // Write each script
-        for (name, content) in scripts::SCRIPTS {
-            let script_path = hooks_dir.join(name);
-
-            if script_path.exists() && !self.config.overwrite {
-                debug!("Skipping existing script: {:?}", script_path);
-                continue;
-            }
+    /// The vibes binary hooks should run
+    fn vibes_bin(&self) -> PathBuf {
+        if let Some(bin) = &self.config.vibes_bin {
+            return bin.clone();
+        }
 
-            fs::write(&script_path, content)
-                .map_err(|e| InstallError::WriteScript(name.to_string(), e))?;
-
-            // Make executable (Unix only)
-            #[cfg(unix)]
-            {
-                let mut perms = fs::metadata(&script_path)
-                    .map_err(InstallError::SetPermissions)?
-                    .permissions();
-                perms.set_mode(0o755);
-                fs::set_permissions(&script_path, perms).map_err(InstallError::SetPermissions)?;
-            }
+        // Only trust our own path when running as vibes, not e.g. a test binary
+        std::env::current_exe()
+            .ok()
+            .filter(|exe| exe.file_stem().is_some_and(|stem| stem == "vibes"))
+            .unwrap_or_else(|| PathBuf::from("vibes"))
+    }
 
-            debug!("Installed hook script: {:?}", script_path);
+    /// Command registered for a hook, e.g. `/usr/bin/vibes hook pre-tool-use`
+    fn hook_command(&self, hook_arg: &str) -> String {
+        let bin = self.vibes_bin().to_string_lossy().to_string();
+        if bin.contains(char::is_whitespace) {
+            format!("\"{}\" hook {}", bin, hook_arg)
+        } else {
+            format!("{} hook {}", bin, hook_arg)
         }
-
-        info!(
-            "Installed {} hook scripts to {:?}",
-            scripts::SCRIPTS.len(),
-            hooks_dir
-        );
-        Ok(hooks_dir)
     }
 
     /// Update settings.json to register hooks
@@ -129,7 +104,10 @@ impl HookInstaller {
     /// Claude Code's settings.json uses hooks as an object where:
     /// - Each key is a hook type (e.g., "PreToolUse", "SessionStart")
     /// - Each value is an array of hook configurations
-    pub fn update_settings(&self, hooks_dir: &Path) -> Result<(), InstallError> {
+    ///
+    /// Existing vibes entries, including legacy hook scripts, are replaced so
+    /// each hook type has exactly one vibes command.
+    pub fn update_settings(&self) -> Result<(), InstallError> {
         let settings_path = self.claude_dir()?.join("settings.json");
 
         // Read existing settings or create new
@@ -170,24 +148,7 @@ impl HookInstaller {
             ));
         };
 
-        // Hook configurations to add
-        let vibes_hooks = [
-            ("PreToolUse", "pre-tool-use.sh"),
-            ("PostToolUse", "post-tool-use.sh"),
-            ("Stop", "stop.sh"),
-            ("SessionStart", "session-start.sh"),
-            ("UserPromptSubmit", "user-prompt-submit.sh"),
-            ("PermissionRequest", "permission-request.sh"),
-            ("Notification", "notification.sh"),
-            ("SubagentStop", "subagent-stop.sh"),
-            ("PreCompact", "pre-compact.sh"),
-            ("SessionEnd", "session-end.sh"),
-        ];
-
-        for (hook_type, script_name) in vibes_hooks {
-            let script_path = hooks_dir.join(script_name);
-            let script_path_str = script_path.to_string_lossy().to_string();
-
+        for (hook_type, hook_arg) in VIBES_HOOKS {
             // Get or create array for this hook type
             let hook_type_array = hooks_obj
                 .entry(hook_type)
@@ -197,31 +158,24 @@ impl HookInstaller {
                     InstallError::ParseSettings(format!("hooks.{} is not an array", hook_type))
                 })?;
 
-            // Check if vibes hook already exists for this type
-            let vibes_exists = hook_type_array.iter().any(|h| {
-                h.get("hooks")
+            // Drop previous vibes hooks for this type, keeping everything else
+            hook_type_array.retain(|h| {
+                !h.get("hooks")
                     .and_then(|arr| arr.as_array())
-                    .map(|arr| {
+                    .is_some_and(|arr| {
                         arr.iter().any(|cmd| {
                             cmd.get("command")
                                 .and_then(|c| c.as_str())
-                                .map(|c| c.contains("vibes"))
-                                .unwrap_or(false)
+                                .is_some_and(is_vibes_command)
                         })
                     })
-                    .unwrap_or(false)
             });
 
-            if vibes_exists {
-                debug!("Hook {} already registered for vibes", hook_type);
-                continue;
-            }
-
             // Add new hook configuration
             let hook_config = serde_json::json!({
                 "hooks": [{
                     "type": "command",
-                    "command": script_path_str
+                    "command": self.hook_command(hook_arg)
                 }]
             });
 
@@ -230,6 +184,7 @@ impl HookInstaller {
         }
 
         // Write updated settings
+        fs::create_dir_all(self.claude_dir()?).map_err(InstallError::WriteSettings)?;
         let content = serde_json::to_string_pretty(&settings)
             .map_err(|e| InstallError::ParseSettings(e.to_string()))?;
         fs::write(&settings_path, content).map_err(InstallError::WriteSettings)?;
@@ -238,65 +193,220 @@ impl HookInstaller {
         Ok(())
     }
 
-    /// Install hooks and update settings
+    /// Claude Code hook types with no vibes command in settings.json
+    ///
+    /// Empty when every hook is registered. A missing settings.json means
+    /// every hook is missing.
+    pub fn missing_hooks(&self) -> Result<Vec<&'static str>, InstallError> {
+        let settings_path = self.claude_dir()?.join("settings.json");
+        if !settings_path.exists() {
+            return Ok(VIBES_HOOKS
+                .iter()
+                .map(|(hook_type, _)| *hook_type)
+                .collect());
+        }
+
+        let content = fs::read_to_string(&settings_path).map_err(InstallError::ReadSettings)?;
+        let settings: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| InstallError::ParseSettings(e.to_string()))?;
+
+        let registered = |hook_type: &str| {
+            settings
+                .get("hooks")
+                .and_then(|hooks| hooks.get(hook_type))
+                .and_then(|entries| entries.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.get("hooks").and_then(|h| h.as_array()))
+                .flatten()
+                .filter_map(|cmd| cmd.get("command").and_then(|c| c.as_str()))
+                .any(is_vibes_command)
+        };
+
+        Ok(VIBES_HOOKS
+            .iter()
+            .map(|(hook_type, _)| *hook_type)
+            .filter(|hook_type| !registered(hook_type))
+            .collect())
+    }
+
+    /// Install hooks and remove legacy hook scripts
     pub fn install(&self) -> Result<(), InstallError> {
-        let hooks_dir = self.install_scripts()?;
-        self.update_settings(&hooks_dir)?;
+        self.update_settings()?;
+
+        let legacy_dir = self.legacy_hooks_dir()?;
+        if legacy_dir.exists() {
+            match fs::remove_dir_all(&legacy_dir) {
+                Ok(()) => debug!("Removed legacy hook scripts: {:?}", legacy_dir),
+                Err(e) => debug!("Failed to remove legacy hook scripts: {}", e),
+            }
+        }
         Ok(())
     }
 }
 
+/// Whether a hook command was registered by vibes
+///
+/// Matches `vibes hook <type>` (with any path to the binary) and the hook
+/// scripts earlier versions installed under ~/.claude/hooks/vibes/.
+fn is_vibes_command(command: &str) -> bool {
+    if command.contains("hooks/vibes/") {
+        return true;
+    }
+
+    let command = command.trim_start();
+    let (bin, rest) = match command.strip_prefix('"') {
+        Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+        None => command
+            .split_once(char::is_whitespace)
+            .unwrap_or((command, "")),
+    };
+
+    Path::new(bin)
+        .file_stem()
+        .is_some_and(|stem| stem == "vibes")
+        && rest.split_whitespace().next() == Some("hook")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    fn installer(claude_dir: &Path) -> HookInstaller {
+        HookInstaller::new(HookInstallerConfig {
+            claude_dir: Some(claude_dir.to_path_buf()),
+            vibes_bin: Some(PathBuf::from("/opt/vibes/bin/vibes")),
+        })
+    }
+
+    fn commands(settings: &serde_json::Value, hook_type: &str) -> Vec<String> {
+        settings["hooks"][hook_type]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|h| h["hooks"].as_array().unwrap())
+            .map(|cmd| cmd["command"].as_str().unwrap().to_string())
+            .collect()
+    }
+
     #[test]
-    fn test_install_scripts() {
+    fn test_install_registers_vibes_hook_command() {
         let temp_dir = TempDir::new().unwrap();
         let claude_dir = temp_dir.path().join(".claude");
 
-        let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
-        });
+        installer(&claude_dir).install().unwrap();
+
+        let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
+        let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        assert_eq!(
+            commands(&settings, "PreToolUse"),
+            vec!["/opt/vibes/bin/vibes hook pre-tool-use"]
+        );
+        assert_eq!(
+            commands(&settings, "SessionEnd"),
+            vec!["/opt/vibes/bin/vibes hook session-end"]
+        );
+    }
+
+    #[test]
+    fn test_install_replaces_legacy_scripts() {
+        let temp_dir = TempDir::new().unwrap();
+        let claude_dir = temp_dir.path().join(".claude");
+        let legacy_dir = claude_dir.join("hooks").join("vibes");
+        fs::create_dir_all(&legacy_dir).unwrap();
+        fs::write(legacy_dir.join("stop.sh"), "#!/bin/bash").unwrap();
 
-        let hooks_dir = installer.install_scripts().unwrap();
-        assert!(hooks_dir.exists());
-
-        // Check all scripts exist
-        for (name, _) in scripts::SCRIPTS {
-            let script_path = hooks_dir.join(name);
-            assert!(script_path.exists(), "Script {} should exist", name);
-
-            // Check executable on Unix
-            #[cfg(unix)]
-            {
-                let perms = fs::metadata(&script_path).unwrap().permissions();
-                assert!(
-                    perms.mode() & 0o111 != 0,
-                    "Script {} should be executable",
-                    name
-                );
+        let existing = serde_json::json!({
+            "hooks": {
+                "Stop": [
+                    {"hooks": [{"type": "command", "command": legacy_dir.join("stop.sh")}]},
+                    {"hooks": [{"type": "command", "command": "say done"}]}
+                ]
             }
-        }
+        });
+        fs::write(
+            claude_dir.join("settings.json"),
+            serde_json::to_string(&existing).unwrap(),
+        )
+        .unwrap();
+
+        installer(&claude_dir).install().unwrap();
+
+        let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
+        let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        assert_eq!(
+            commands(&settings, "Stop"),
+            vec!["say done", "/opt/vibes/bin/vibes hook stop"]
+        );
+        assert!(!legacy_dir.exists());
     }
 
     #[test]
-    fn test_update_settings_creates_new() {
+    fn test_missing_hooks_after_settings_overwritten() {
         let temp_dir = TempDir::new().unwrap();
         let claude_dir = temp_dir.path().join(".claude");
-        fs::create_dir_all(&claude_dir).unwrap();
+        let installer = installer(&claude_dir);
+
+        assert_eq!(installer.missing_hooks().unwrap().len(), 10);
+
+        installer.install().unwrap();
+        assert!(installer.missing_hooks().unwrap().is_empty());
+
+        // Another tool rewrites settings.json without our hooks
+        let overwritten = serde_json::json!({
+            "model": "opus",
+            "hooks": {
+                "Stop": [{"hooks": [{"type": "command", "command": "say done"}]}]
+            }
+        });
+        fs::write(
+            claude_dir.join("settings.json"),
+            serde_json::to_string(&overwritten).unwrap(),
+        )
+        .unwrap();
 
+        let missing = installer.missing_hooks().unwrap();
+        assert_eq!(missing.len(), 10);
+        assert!(missing.contains(&"Stop"));
+    }
+
+    #[test]
+    fn test_is_vibes_command() {
+        assert!(is_vibes_command("vibes hook stop"));
+        assert!(is_vibes_command("/usr/local/bin/vibes hook pre-tool-use"));
+        assert!(is_vibes_command("\"/Users/me/my tools/vibes\" hook stop"));
+        assert!(is_vibes_command("/home/me/.claude/hooks/vibes/stop.sh"));
+
+        assert!(!is_vibes_command("vibes event send --type hook"));
+        assert!(!is_vibes_command("notify-send 'vibes hook'"));
+        assert!(!is_vibes_command("other-script.sh"));
+    }
+
+    #[test]
+    fn test_hook_command_quotes_paths_with_spaces() {
         let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
+            claude_dir: None,
+            vibes_bin: Some(PathBuf::from("/Users/me/my tools/vibes")),
         });
 
-        let hooks_dir = claude_dir.join("hooks").join("vibes");
-        fs::create_dir_all(&hooks_dir).unwrap();
+        assert_eq!(
+            installer.hook_command("stop"),
+            "\"/Users/me/my tools/vibes\" hook stop"
+        );
+    }
+
+    #[test]
+    fn test_update_settings_creates_new() {
+        let temp_dir = TempDir::new().unwrap();
+        let claude_dir = temp_dir.path().join(".claude");
+        fs::create_dir_all(&claude_dir).unwrap();
 
-        installer.update_settings(&hooks_dir).unwrap();
+        let installer = installer(&claude_dir);
+
+        installer.update_settings().unwrap();
 
         let settings_path = claude_dir.join("settings.json");
         assert!(settings_path.exists());
@@ -341,15 +451,9 @@ mod tests {
         )
         .unwrap();
 
-        let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
-        });
-
-        let hooks_dir = claude_dir.join("hooks").join("vibes");
-        fs::create_dir_all(&hooks_dir).unwrap();
+        let installer = installer(&claude_dir);
 
-        installer.update_settings(&hooks_dir).unwrap();
+        installer.update_settings().unwrap();
 
         let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
         let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
@@ -371,17 +475,11 @@ mod tests {
         let claude_dir = temp_dir.path().join(".claude");
         fs::create_dir_all(&claude_dir).unwrap();
 
-        let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
-        });
-
-        let hooks_dir = claude_dir.join("hooks").join("vibes");
-        fs::create_dir_all(&hooks_dir).unwrap();
+        let installer = installer(&claude_dir);
 
         // Install twice
-        installer.update_settings(&hooks_dir).unwrap();
-        installer.update_settings(&hooks_dir).unwrap();
+        installer.update_settings().unwrap();
+        installer.update_settings().unwrap();
 
         let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
         let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
@@ -418,16 +516,10 @@ mod tests {
         )
         .unwrap();
 
-        let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
-        });
-
-        let hooks_dir = claude_dir.join("hooks").join("vibes");
-        fs::create_dir_all(&hooks_dir).unwrap();
+        let installer = installer(&claude_dir);
 
         // This should NOT fail - it should handle object format
-        installer.update_settings(&hooks_dir).unwrap();
+        installer.update_settings().unwrap();
 
         let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
         let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
@@ -471,16 +563,10 @@ mod tests {
         )
         .unwrap();
 
-        let installer = HookInstaller::new(HookInstallerConfig {
-            claude_dir: Some(claude_dir.clone()),
-            overwrite: true,
-        });
-
-        let hooks_dir = claude_dir.join("hooks").join("vibes");
-        fs::create_dir_all(&hooks_dir).unwrap();
+        let installer = installer(&claude_dir);
 
         // This should migrate the array to object format
-        installer.update_settings(&hooks_dir).unwrap();
+        installer.update_settings().unwrap();
 
         let content = fs::read_to_string(claude_dir.join("settings.json")).unwrap();
         let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
@@ -1,7 +1,9 @@
 //! Claude Code hooks integration
 //!
 //! This module provides structured data capture from Claude Code sessions
-//! via the hooks system. Hook scripts send events directly to Iggy via CLI.
+//! via the hooks system. Every hook runs `vibes hook <type>`, which sends the
+//! event to the daemon over a local socket; the daemon records it and returns
+//! the plugins' merged [`HookResponse`] as Claude Code hook output.
 //!
 //! ## Hook Types
 //!
@@ -14,11 +16,14 @@
 //! ## Architecture
 //!
 //! ```text
-//! Claude Code ---> Hook Script ---> vibes event send ---> Iggy HTTP API
+//! Claude Code ---> vibes hook ---> hooks.sock ---> plugins (on_hook)
+//!                       |               |
+//!                       |               +---> EventLog (Iggy)
+//!                       +---> Iggy HTTP API (daemon down)
 //! ```
 
 mod installer;
-pub mod scripts;
+pub mod socket;
 mod types;
 
 pub use installer::{HookInstaller, HookInstallerConfig, InstallError};
@@ -0,0 +1,253 @@
+//! Local socket protocol between `vibes hook` and the daemon
+//!
+//! Each connection carries one hook: the client writes a [`HookRequest`] as a
+//! single JSON line and the daemon answers with one line of Claude Code hook
+//! output, then the connection is closed.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::HookEvent;
+
+#[cfg(unix)]
+pub use unix::{bind, request, serve};
+
+/// Default time the client waits for the daemon's answer
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A hook event sent to the daemon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookRequest {
+    /// Session the hook belongs to, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// The hook event
+    pub event: HookEvent,
+}
+
+/// Error type for the hook socket
+#[derive(Debug, thiserror::Error)]
+pub enum HookSocketError {
+    /// Nothing is listening on the socket (daemon not running)
+    #[error("Daemon unavailable: {0}")]
+    Unavailable(io::Error),
+
+    #[error("Hook socket I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Invalid hook message: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Daemon did not answer within {0:?}")]
+    Timeout(Duration),
+}
+
+/// Path of the daemon's hook socket
+pub fn default_socket_path() -> PathBuf {
+    vibes_paths::config_dir().join("hooks.sock")
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::future::Future;
+    use std::io;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use serde_json::Value;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio_util::sync::CancellationToken;
+
+    use super::{HookRequest, HookSocketError};
+
+    /// Largest request the daemon will read (tool inputs can carry whole files)
+    const MAX_REQUEST_BYTES: u64 = 16 * 1024 * 1024;
+
+    /// Send a hook to the daemon and wait for its Claude Code hook output
+    ///
+    /// The whole exchange, including connecting, is bounded by `timeout`.
+    pub async fn request(
+        path: &Path,
+        request: &HookRequest,
+        timeout: Duration,
+    ) -> Result<Value, HookSocketError> {
+        let exchange = async {
+            let mut stream = UnixStream::connect(path)
+                .await
+                .map_err(HookSocketError::Unavailable)?;
+
+            let mut line = serde_json::to_vec(request)?;
+            line.push(b'\n');
+            stream.write_all(&line).await?;
+
+            let mut response = String::new();
+            BufReader::new(stream).read_line(&mut response).await?;
+            Ok(serde_json::from_str(&response)?)
+        };
+
+        tokio::time::timeout(timeout, exchange)
+            .await
+            .map_err(|_| HookSocketError::Timeout(timeout))?
+    }
+
+    /// Bind the hook socket, replacing a stale socket file
+    ///
+    /// The socket is only accessible to the current user.
+    pub fn bind(path: &Path) -> io::Result<UnixListener> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        let listener = UnixListener::bind(path)?;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        Ok(listener)
+    }
+
+    /// Answer hook requests until `shutdown` is cancelled
+    ///
+    /// Each connection is handled on its own task, so a slow handler does not
+    /// hold up other hooks.
+    pub async fn serve<H, Fut>(listener: UnixListener, handler: H, shutdown: CancellationToken)
+    where
+        H: Fn(HookRequest) -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = Value> + Send + 'static,
+    {
+        loop {
+            let stream = tokio::select! {
+                () = shutdown.cancelled() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        tracing::warn!("Failed to accept hook connection: {}", e);
+                        continue;
+                    }
+                },
+            };
+
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                if let Err(e) = answer(stream, handler).await {
+                    tracing::debug!("Hook connection failed: {}", e);
+                }
+            });
+        }
+    }
+
+    /// Read one request from a connection and write the handler's answer
+    async fn answer<H, Fut>(stream: UnixStream, handler: H) -> Result<(), HookSocketError>
+    where
+        H: Fn(HookRequest) -> Fut,
+        Fut: Future<Output = Value>,
+    {
+        let (read, mut write) = stream.into_split();
+
+        let mut line = String::new();
+        BufReader::new(read.take(MAX_REQUEST_BYTES))
+            .read_line(&mut line)
+            .await?;
+        let request: HookRequest = serde_json::from_str(&line)?;
+
+        let mut response = serde_json::to_vec(&handler(request).await)?;
+        response.push(b'\n');
+        write.write_all(&response).await?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+    use tokio_util::sync::CancellationToken;
+
+    fn stop_request() -> HookRequest {
+        HookRequest {
+            session_id: Some("sess-1".to_string()),
+            event: serde_json::from_value(json!({"type": "stop", "reason": "done"})).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hooks.sock");
+        let listener = bind(&path).unwrap();
+        let shutdown = CancellationToken::new();
+
+        tokio::spawn(serve(
+            listener,
+            |request: HookRequest| async move {
+                json!({"decision": "block", "reason": request.session_id})
+            },
+            shutdown.clone(),
+        ));
+
+        let output = request(&path, &stop_request(), DEFAULT_HOOK_TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(output, json!({"decision": "block", "reason": "sess-1"}));
+
+        shutdown.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_request_without_daemon_is_unavailable() {
+        let dir = TempDir::new().unwrap();
+
+        let result = request(
+            &dir.path().join("missing.sock"),
+            &stop_request(),
+            DEFAULT_HOOK_TIMEOUT,
+        )
+        .await;
+
+        assert!(matches!(result, Err(HookSocketError::Unavailable(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_times_out_on_slow_daemon() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hooks.sock");
+        let listener = bind(&path).unwrap();
+        let shutdown = CancellationToken::new();
+
+        tokio::spawn(serve(
+            listener,
+            |_| async {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                json!({})
+            },
+            shutdown.clone(),
+        ));
+
+        let result = request(&path, &stop_request(), Duration::from_millis(50)).await;
+        assert!(matches!(result, Err(HookSocketError::Timeout(_))));
+
+        shutdown.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_bind_replaces_stale_socket() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hooks.sock");
+
+        drop(bind(&path).unwrap());
+        assert!(path.exists());
+
+        assert!(bind(&path).is_ok());
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -7,7 +7,8 @@
 //! This allows comparing events in tests and building equality-based logic.
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value, json};
+use vibes_plugin_api::{HookDecision, PermissionDecision};
 
 /// Type of hook event
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -410,6 +411,22 @@ impl HookEvent {
         )
     }
 
+    /// Get the working directory from this event, if available
+    pub fn cwd(&self) -> Option<&str> {
+        match self {
+            HookEvent::PreToolUse(data) => data.cwd.as_deref(),
+            HookEvent::PostToolUse(data) => data.cwd.as_deref(),
+            HookEvent::Stop(data) => data.cwd.as_deref(),
+            HookEvent::SessionStart(data) => data.cwd.as_deref(),
+            HookEvent::UserPromptSubmit(data) => data.cwd.as_deref(),
+            HookEvent::PermissionRequest(data) => data.cwd.as_deref(),
+            HookEvent::Notification(data) => data.cwd.as_deref(),
+            HookEvent::SubagentStop(data) => data.cwd.as_deref(),
+            HookEvent::PreCompact(data) => data.cwd.as_deref(),
+            HookEvent::SessionEnd(data) => data.cwd.as_deref(),
+        }
+    }
+
     /// Get the project path from this event, if available
     pub fn project_path(&self) -> Option<String> {
         match self {
@@ -420,13 +437,28 @@ impl HookEvent {
     }
 }
 
-/// Response to send back to Claude Code for injection hooks
+/// Response to send back to Claude Code for a hook
+///
+/// Combines the decisions of every plugin that answered. Use
+/// [`HookResponse::to_claude_output`] for the JSON Claude Code expects.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HookResponse {
     /// Additional context to inject into Claude's conversation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_context: Option<String>,
+    /// Permission decision for a tool call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission_decision: Option<PermissionDecision>,
+    /// Why the permission decision was made
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission_decision_reason: Option<String>,
+    /// Replacement tool input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_input: Option<Value>,
+    /// Set to keep Claude working instead of stopping, with the reason
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_reason: Option<String>,
 }
 
 impl HookResponse {
@@ -439,10 +471,147 @@ impl HookResponse {
     pub fn with_context(context: impl Into<String>) -> Self {
         Self {
             additional_context: Some(context.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the response carries nothing for Claude Code
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Merge another plugin's response into this one
+    ///
+    /// Merging is order-dependent only where a single value must be chosen,
+    /// so callers merge in a fixed order:
+    /// - context is concatenated
+    /// - the most restrictive permission decision wins, keeping the reasons
+    ///   of every response that made it
+    /// - the first updated input wins
+    /// - any block blocks, keeping every reason
+    pub fn merge(&mut self, other: HookResponse) {
+        self.additional_context = join(self.additional_context.take(), other.additional_context);
+
+        match (self.permission_decision, other.permission_decision) {
+            (_, None) => {}
+            (Some(current), Some(new)) if new < current => {}
+            (Some(current), Some(new)) if new == current => {
+                self.permission_decision_reason = join(
+                    self.permission_decision_reason.take(),
+                    other.permission_decision_reason,
+                );
+            }
+            (_, Some(new)) => {
+                self.permission_decision = Some(new);
+                self.permission_decision_reason = other.permission_decision_reason;
+            }
+        }
+
+        if self.updated_input.is_none() {
+            self.updated_input = other.updated_input;
+        }
+
+        self.block_reason = join(self.block_reason.take(), other.block_reason);
+    }
+
+    /// Render the response as Claude Code hook output for the given hook
+    ///
+    /// Fields that the hook type does not support are dropped.
+    pub fn to_claude_output(&self, hook_type: &HookType) -> Value {
+        let mut specific = Map::new();
+        let mut output = Map::new();
+
+        match hook_type {
+            HookType::PreToolUse => {
+                if let Some(decision) = self.permission_decision {
+                    specific.insert("permissionDecision".into(), json!(decision));
+                }
+                if let Some(reason) = &self.permission_decision_reason {
+                    specific.insert("permissionDecisionReason".into(), json!(reason));
+                }
+                if let Some(input) = self.allowed_updated_input() {
+                    specific.insert("updatedInput".into(), input.clone());
+                }
+            }
+            HookType::PermissionRequest => {
+                // "ask" leaves the normal permission dialog in place
+                let behavior = match self.permission_decision {
+                    Some(PermissionDecision::Allow) => Some("allow"),
+                    Some(PermissionDecision::Deny) => Some("deny"),
+                    Some(PermissionDecision::Ask) | None => None,
+                };
+                if let Some(behavior) = behavior {
+                    let mut decision = Map::new();
+                    decision.insert("behavior".into(), json!(behavior));
+                    if let Some(input) = self.allowed_updated_input() {
+                        decision.insert("updatedInput".into(), input.clone());
+                    }
+                    if behavior == "deny"
+                        && let Some(reason) = &self.permission_decision_reason
+                    {
+                        decision.insert("message".into(), json!(reason));
+                    }
+                    specific.insert("decision".into(), Value::Object(decision));
+                }
+            }
+            HookType::Stop | HookType::SubagentStop => {
+                if let Some(reason) = &self.block_reason {
+                    output.insert("decision".into(), json!("block"));
+                    output.insert("reason".into(), json!(reason));
+                }
+            }
+            HookType::SessionStart | HookType::UserPromptSubmit | HookType::PostToolUse => {
+                if let Some(context) = &self.additional_context {
+                    specific.insert("additionalContext".into(), json!(context));
+                }
+            }
+            HookType::Notification | HookType::PreCompact | HookType::SessionEnd => {}
+        }
+
+        if !specific.is_empty() {
+            specific.insert("hookEventName".into(), json!(hook_type.as_str()));
+            output.insert("hookSpecificOutput".into(), Value::Object(specific));
+        }
+        Value::Object(output)
+    }
+
+    /// Updated input, unless the tool call is denied
+    fn allowed_updated_input(&self) -> Option<&Value> {
+        match self.permission_decision {
+            Some(PermissionDecision::Deny) => None,
+            _ => self.updated_input.as_ref(),
+        }
+    }
+}
+
+impl From<HookDecision> for HookResponse {
+    fn from(decision: HookDecision) -> Self {
+        let (permission_decision_reason, block_reason) = if decision.block {
+            (None, Some(decision.reason.unwrap_or_default()))
+        } else if decision.permission.is_some() {
+            (decision.reason, None)
+        } else {
+            (None, None)
+        };
+
+        Self {
+            additional_context: decision.additional_context,
+            permission_decision: decision.permission,
+            permission_decision_reason,
+            updated_input: decision.updated_input,
+            block_reason,
         }
     }
 }
 
+/// Join two optional strings with a blank line
+fn join(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(format!("{}\n\n{}", a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -676,9 +845,7 @@ mod tests {
 
     #[test]
     fn test_hook_response_serialization() {
-        let response = HookResponse {
-            additional_context: Some("## groove Learnings\n\n- Use pytest".to_string()),
-        };
+        let response = HookResponse::with_context("## groove Learnings\n\n- Use pytest");
 
         let json = serde_json::to_string(&response).unwrap();
         assert!(json.contains("additionalContext"));
@@ -768,4 +935,100 @@ mod tests {
         });
         assert!(!end.supports_response());
     }
+
+    #[test]
+    fn test_hook_response_merge_strictest_permission_wins() {
+        let mut response = HookResponse::from(HookDecision::allow());
+        response.merge(HookDecision::deny("touches .env").into());
+        response.merge(HookDecision::ask("network access").into());
+        response.merge(HookDecision::deny("outside project").into());
+
+        assert_eq!(response.permission_decision, Some(PermissionDecision::Deny));
+        assert_eq!(
+            response.permission_decision_reason.as_deref(),
+            Some("touches .env\n\noutside project")
+        );
+    }
+
+    #[test]
+    fn test_hook_response_merge_context_input_and_block() {
+        let mut response = HookResponse::with_context("first");
+        response.merge(HookResponse::with_context("second"));
+        response.merge(
+            HookDecision::allow()
+                .with_updated_input(json!({"n": 1}))
+                .into(),
+        );
+        response.merge(
+            HookDecision::allow()
+                .with_updated_input(json!({"n": 2}))
+                .into(),
+        );
+        response.merge(HookDecision::block("tests fail").into());
+
+        assert_eq!(
+            response.additional_context.as_deref(),
+            Some("first\n\nsecond")
+        );
+        assert_eq!(response.updated_input, Some(json!({"n": 1})));
+        assert_eq!(response.block_reason.as_deref(), Some("tests fail"));
+    }
+
+    #[test]
+    fn test_pre_tool_use_claude_output() {
+        let response: HookResponse = HookDecision::deny("no rm -rf").into();
+        let output = response.to_claude_output(&HookType::PreToolUse);
+
+        assert_eq!(
+            output,
+            json!({
+                "hookSpecificOutput": {
+                    "hookEventName": "PreToolUse",
+                    "permissionDecision": "deny",
+                    "permissionDecisionReason": "no rm -rf"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_permission_request_claude_output() {
+        let response: HookResponse = HookDecision::allow()
+            .with_updated_input(json!({"command": "ls"}))
+            .into();
+        let output = response.to_claude_output(&HookType::PermissionRequest);
+        assert_eq!(
+            output["hookSpecificOutput"]["decision"],
+            json!({"behavior": "allow", "updatedInput": {"command": "ls"}})
+        );
+
+        // Ask falls through to the normal dialog
+        let response: HookResponse = HookDecision::ask("check").into();
+        assert_eq!(
+            response.to_claude_output(&HookType::PermissionRequest),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn test_stop_and_context_claude_output() {
+        let response: HookResponse = HookDecision::block("tests are failing").into();
+        assert_eq!(
+            response.to_claude_output(&HookType::Stop),
+            json!({"decision": "block", "reason": "tests are failing"})
+        );
+
+        let response = HookResponse::with_context("- Use pytest");
+        assert_eq!(
+            response.to_claude_output(&HookType::SessionStart),
+            json!({
+                "hookSpecificOutput": {
+                    "hookEventName": "SessionStart",
+                    "additionalContext": "- Use pytest"
+                }
+            })
+        );
+        // Context is not supported on Stop
+        assert_eq!(response.to_claude_output(&HookType::Stop), json!({}));
+    }
 }
@@ -65,8 +65,9 @@ pub use hooks::{
     PreToolUseData, StopData,
 };
 pub use notifications::{
-    NotificationConfig, NotificationData, NotificationEvent, NotificationService, PushNotification,
-    PushSubscription, SubscriptionKeys, SubscriptionStore, VapidKeyManager, VapidKeys,
+    ActionSigner, ActionTokenError, NotificationConfig, NotificationData, NotificationEvent,
+    NotificationRoute, NotificationService, NotificationSink, PushNotification, PushSubscription,
+    SinkConfig, SubscriptionKeys, SubscriptionStore, VapidKeyManager, VapidKeys,
 };
 pub use plugins::{PluginHost, PluginHostConfig, PluginHostError, PluginInfo, PluginState};
 pub use tunnel::{
@@ -0,0 +1,339 @@
+//! Signed action tokens for answering permission requests from a notification
+//!
+//! Permission notifications carry approve and deny actions. Each action holds
+//! a short-lived token that names the session, the permission request and the
+//! decision, signed with HMAC-SHA256. Redeeming a token answers the request
+//! once: any later token for the same request is rejected, so a replayed
+//! "approve" cannot follow a "deny" (or itself).
+//!
+//! Token format: `base64url(claims JSON) "." base64url(HMAC)`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use p256::elliptic_curve::rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// How long an action token stays valid by default
+pub const DEFAULT_ACTION_TTL_SECS: i64 = 15 * 60;
+
+/// Kind of action button shown on a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionKind {
+    /// Approve the permission request
+    Approve,
+    /// Deny the permission request
+    Deny,
+    /// Open the session in the web UI
+    Open,
+}
+
+/// An action button attached to a notification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationAction {
+    /// Action identifier
+    pub action: ActionKind,
+    /// Button label
+    pub title: String,
+    /// Signed token to redeem at `POST /api/push/action` (none for `open`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// What a verified action token authorises
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionClaims {
+    /// Session the permission request belongs to
+    pub session_id: String,
+    /// Permission request ID
+    pub request_id: String,
+    /// Whether the request is approved
+    pub approved: bool,
+    /// Expiry as a Unix timestamp in seconds
+    pub expires_at: i64,
+}
+
+/// Reasons an action token is rejected
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ActionTokenError {
+    #[error("malformed action token")]
+    Malformed,
+
+    #[error("invalid action token signature")]
+    InvalidSignature,
+
+    #[error("action token expired")]
+    Expired,
+
+    #[error("permission request already answered")]
+    AlreadyUsed,
+}
+
+/// Issues and redeems action tokens
+///
+/// The key is generated per server process by [`ActionSigner::random`], so
+/// outstanding tokens stop working after a restart.
+pub struct ActionSigner {
+    key: Vec<u8>,
+    ttl: Duration,
+    /// Answered requests, keyed by session and request ID, with token expiry
+    answered: Mutex<HashMap<(String, String), i64>>,
+}
+
+impl ActionSigner {
+    /// Create a signer with the given HMAC key
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            ttl: Duration::seconds(DEFAULT_ACTION_TTL_SECS),
+            answered: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a signer with a random 256-bit key
+    pub fn random() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+        Self::new(key.to_vec())
+    }
+
+    /// Set how long issued tokens stay valid
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Issue a token answering a permission request
+    pub fn issue(
+        &self,
+        session_id: &str,
+        request_id: &str,
+        approved: bool,
+        now: DateTime<Utc>,
+    ) -> String {
+        let claims = ActionClaims {
+            session_id: session_id.to_string(),
+            request_id: request_id.to_string(),
+            approved,
+            expires_at: (now + self.ttl).timestamp(),
+        };
+        let payload = Base64UrlUnpadded::encode_string(
+            &serde_json::to_vec(&claims).expect("claims serialize to JSON"),
+        );
+        let signature = Base64UrlUnpadded::encode_string(&self.sign(payload.as_bytes()));
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Approve, deny and open actions for a permission request
+    pub fn actions_for(
+        &self,
+        session_id: &str,
+        request_id: &str,
+        now: DateTime<Utc>,
+    ) -> Vec<NotificationAction> {
+        vec![
+            NotificationAction {
+                action: ActionKind::Approve,
+                title: "Approve".into(),
+                token: Some(self.issue(session_id, request_id, true, now)),
+            },
+            NotificationAction {
+                action: ActionKind::Deny,
+                title: "Deny".into(),
+                token: Some(self.issue(session_id, request_id, false, now)),
+            },
+            NotificationAction {
+                action: ActionKind::Open,
+                title: "Open".into(),
+                token: None,
+            },
+        ]
+    }
+
+    /// Check a token's signature and expiry without redeeming it
+    pub fn verify(
+        &self,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<ActionClaims, ActionTokenError> {
+        let (payload, signature) = token.split_once('.').ok_or(ActionTokenError::Malformed)?;
+        let signature =
+            Base64UrlUnpadded::decode_vec(signature).map_err(|_| ActionTokenError::Malformed)?;
+
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| ActionTokenError::InvalidSignature)?;
+
+        let claims: ActionClaims = Base64UrlUnpadded::decode_vec(payload)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or(ActionTokenError::Malformed)?;
+
+        if claims.expires_at <= now.timestamp() {
+            return Err(ActionTokenError::Expired);
+        }
+        Ok(claims)
+    }
+
+    /// Verify a token and mark its permission request as answered
+    pub fn redeem(
+        &self,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<ActionClaims, ActionTokenError> {
+        let claims = self.verify(token, now)?;
+
+        let mut answered = self
+            .answered
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Entries can be forgotten once every token for them has expired
+        answered.retain(|_, expires_at| *expires_at > now.timestamp());
+
+        let key = (claims.session_id.clone(), claims.request_id.clone());
+        if answered.contains_key(&key) {
+            return Err(ActionTokenError::AlreadyUsed);
+        }
+        // Tokens for one request are issued together, so this outlives all of them
+        answered.insert(key, (now + self.ttl).timestamp());
+        Ok(claims)
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length")
+    }
+
+    fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        let mut mac = self.mac();
+        mac.update(payload);
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> ActionSigner {
+        ActionSigner::new(b"test-key".to_vec())
+    }
+
+    #[test]
+    fn test_issue_and_verify_roundtrip() {
+        let signer = signer();
+        let now = Utc::now();
+        let token = signer.issue("sess-1", "req-1", true, now);
+
+        let claims = signer.verify(&token, now).unwrap();
+        assert_eq!(claims.session_id, "sess-1");
+        assert_eq!(claims.request_id, "req-1");
+        assert!(claims.approved);
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_payload() {
+        let signer = signer();
+        let now = Utc::now();
+        let deny = signer.issue("sess-1", "req-1", false, now);
+        let approve = signer.issue("sess-1", "req-1", true, now);
+
+        // Deny's signature on approve's claims
+        let (payload, _) = approve.split_once('.').unwrap();
+        let (_, signature) = deny.split_once('.').unwrap();
+        let forged = format!("{}.{}", payload, signature);
+
+        assert_eq!(
+            signer.verify(&forged, now),
+            Err(ActionTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_other_key() {
+        let now = Utc::now();
+        let token = ActionSigner::new(b"other".to_vec()).issue("sess-1", "req-1", true, now);
+        assert_eq!(
+            signer().verify(&token, now),
+            Err(ActionTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed() {
+        let signer = signer();
+        assert_eq!(
+            signer.verify("not-a-token", Utc::now()),
+            Err(ActionTokenError::Malformed)
+        );
+        assert_eq!(
+            signer.verify("abc.!!!", Utc::now()),
+            Err(ActionTokenError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_expired() {
+        let signer = signer().with_ttl(Duration::minutes(5));
+        let now = Utc::now();
+        let token = signer.issue("sess-1", "req-1", true, now);
+
+        assert!(signer.verify(&token, now + Duration::minutes(4)).is_ok());
+        assert_eq!(
+            signer.verify(&token, now + Duration::minutes(5)),
+            Err(ActionTokenError::Expired)
+        );
+    }
+
+    #[test]
+    fn test_redeem_answers_request_once() {
+        let signer = signer();
+        let now = Utc::now();
+        let approve = signer.issue("sess-1", "req-1", true, now);
+        let deny = signer.issue("sess-1", "req-1", false, now);
+
+        assert!(signer.redeem(&approve, now).unwrap().approved);
+        assert_eq!(
+            signer.redeem(&approve, now),
+            Err(ActionTokenError::AlreadyUsed)
+        );
+        assert_eq!(
+            signer.redeem(&deny, now),
+            Err(ActionTokenError::AlreadyUsed)
+        );
+
+        // Other requests are unaffected
+        let other = signer.issue("sess-1", "req-2", false, now);
+        assert!(!signer.redeem(&other, now).unwrap().approved);
+    }
+
+    #[test]
+    fn test_actions_for_permission_request() {
+        let signer = signer();
+        let now = Utc::now();
+        let actions = signer.actions_for("sess-1", "req-1", now);
+
+        let kinds: Vec<_> = actions.iter().map(|a| a.action).collect();
+        assert_eq!(
+            kinds,
+            vec![ActionKind::Approve, ActionKind::Deny, ActionKind::Open]
+        );
+        let approve = signer
+            .verify(actions[0].token.as_ref().unwrap(), now)
+            .unwrap();
+        let deny = signer
+            .verify(actions[1].token.as_ref().unwrap(), now)
+            .unwrap();
+        assert!(approve.approved);
+        assert!(!deny.approved);
+        assert!(actions[2].token.is_none());
+    }
+}
@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::rules::{EscalationConfig, NotificationRule, QuietHours, TokenPricing};
+use super::{NotificationRoute, SinkConfig};
+
 /// Configuration for push notifications
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationConfig {
@@ -20,6 +23,32 @@ pub struct NotificationConfig {
     /// Notify when session fails with an error
     #[serde(default = "default_true")]
     pub notify_error: bool,
+
+    /// Additional delivery channels beyond Web Push
+    #[serde(default)]
+    pub sinks: Vec<SinkConfig>,
+
+    /// Rules choosing which sinks receive which notifications
+    ///
+    /// When empty, every sink receives every notification.
+    #[serde(default)]
+    pub routes: Vec<NotificationRoute>,
+
+    /// Ordered rules deciding whether, when and how notifications go out
+    #[serde(default)]
+    pub rules: Vec<NotificationRule>,
+
+    /// Window during which only urgent notifications are delivered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<QuietHours>,
+
+    /// Escalation for unanswered permission requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation: Option<EscalationConfig>,
+
+    /// Token prices used for cost-based rules
+    #[serde(default)]
+    pub pricing: TokenPricing,
 }
 
 fn default_true() -> bool {
@@ -33,6 +62,12 @@ impl Default for NotificationConfig {
             notify_permission: true,
             notify_completed: true,
             notify_error: true,
+            sinks: Vec::new(),
+            routes: Vec::new(),
+            rules: Vec::new(),
+            quiet_hours: None,
+            escalation: None,
+            pricing: TokenPricing::default(),
         }
     }
 }
@@ -50,6 +85,12 @@ impl NotificationConfig {
             notify_permission: false,
             notify_completed: false,
             notify_error: false,
+            sinks: Vec::new(),
+            routes: Vec::new(),
+            rules: Vec::new(),
+            quiet_hours: None,
+            escalation: None,
+            pricing: TokenPricing::default(),
         }
     }
 
@@ -108,5 +149,50 @@ mod tests {
         let toml = r#""#;
         let config: NotificationConfig = toml::from_str(toml).unwrap();
         assert!(config.enabled); // defaults to true
+        assert!(config.sinks.is_empty());
+        assert!(config.routes.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_toml_sinks_and_routes() {
+        let toml = r#"
+            [[sinks]]
+            type = "webhook"
+            name = "ci"
+            url = "https://example.com/hook"
+            secret = "shh"
+
+            [[routes]]
+            events = ["session_error"]
+            sinks = ["ci"]
+        "#;
+        let config: NotificationConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.sinks.len(), 1);
+        assert_eq!(config.sinks[0].name(), "ci");
+        assert_eq!(config.routes[0].sinks, vec!["ci".to_string()]);
+    }
+
+    #[test]
+    fn test_deserialize_toml_rules_quiet_hours_and_escalation() {
+        let toml = r#"
+            [[rules]]
+            name = "stuck"
+            events = ["permission_needed"]
+            min_wait_secs = 120
+            aggregate_secs = 60
+
+            [quiet_hours]
+            start = "22:00"
+            end = "07:00"
+
+            [escalation]
+            after_minutes = 10
+            sinks = ["team"]
+        "#;
+        let config: NotificationConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.rules[0].min_wait_secs, Some(120));
+        assert_eq!(config.quiet_hours.unwrap().start, "22:00");
+        assert_eq!(config.escalation.unwrap().after_minutes, 10);
+        assert_eq!(config.pricing, TokenPricing::default());
     }
 }
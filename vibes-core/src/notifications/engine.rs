@@ -0,0 +1,751 @@
+//! Stateful evaluation of notification rules
+//!
+//! The [`RuleEngine`] tracks per-session facts (name, project, cost, how
+//! long the session has been waiting) from the event stream, applies
+//! [`NotificationRule`]s to candidate notifications, and produces the
+//! notifications that should actually go out. Time-based behaviour
+//! (deferred rules, aggregation windows, escalation and the quiet hours
+//! digest) is driven by [`RuleEngine::tick`].
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, FixedOffset, Local, Offset, Utc};
+use tracing::{debug, warn};
+
+use super::rules::{
+    EscalationConfig, NotificationRule, QuietHours, RuleAction, RuleContext, RuleMatch,
+    TokenPricing,
+};
+use super::{NotificationConfig, NotificationEvent, PushNotification};
+use crate::events::{ClaudeEvent, VibesEvent};
+use crate::hooks::HookEvent;
+
+/// A notification ready for delivery
+#[derive(Debug, Clone)]
+pub struct Outgoing {
+    pub notification: PushNotification,
+    /// Sinks to use instead of the configured routes
+    pub sinks: Option<Vec<String>>,
+}
+
+impl Outgoing {
+    fn routed(notification: PushNotification) -> Self {
+        Self {
+            notification,
+            sinks: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SessionState {
+    name: Option<String>,
+    project_path: Option<String>,
+    input_tokens: u64,
+    output_tokens: u64,
+    waiting_since: Option<DateTime<Utc>>,
+    pending_permissions: HashMap<String, PendingPermission>,
+}
+
+#[derive(Debug)]
+struct PendingPermission {
+    tool: String,
+    since: DateTime<Utc>,
+    escalated: bool,
+}
+
+#[derive(Debug)]
+struct Deferred {
+    rule: usize,
+    notification: PushNotification,
+    session_id: String,
+    waiting_since: DateTime<Utc>,
+    due: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct Aggregate {
+    rule: usize,
+    notifications: Vec<PushNotification>,
+    flush_at: DateTime<Utc>,
+}
+
+/// Applies notification rules, quiet hours, aggregation and escalation
+pub struct RuleEngine {
+    rules: Vec<NotificationRule>,
+    quiet_hours: Option<QuietHours>,
+    escalation: Option<EscalationConfig>,
+    pricing: TokenPricing,
+    utc_offset: FixedOffset,
+    sessions: HashMap<String, SessionState>,
+    deferred: Vec<Deferred>,
+    aggregates: HashMap<(usize, NotificationEvent), Aggregate>,
+    held_during_quiet_hours: usize,
+}
+
+impl RuleEngine {
+    /// Create an engine from the notification config
+    ///
+    /// Quiet hours are interpreted in the machine's current local offset.
+    pub fn new(config: &NotificationConfig) -> Self {
+        let quiet_hours = config
+            .quiet_hours
+            .clone()
+            .filter(|quiet| match quiet.parse() {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!("Ignoring quiet hours: {}", e);
+                    false
+                }
+            });
+
+        Self {
+            rules: config.rules.clone(),
+            quiet_hours,
+            escalation: config.escalation.clone(),
+            pricing: config.pricing,
+            utc_offset: Local::now().offset().fix(),
+            sessions: HashMap::new(),
+            deferred: Vec::new(),
+            aggregates: HashMap::new(),
+            held_during_quiet_hours: 0,
+        }
+    }
+
+    /// Override the offset used to evaluate quiet hours
+    pub fn with_utc_offset(mut self, offset: FixedOffset) -> Self {
+        self.utc_offset = offset;
+        self
+    }
+
+    /// Update session state from an event
+    pub fn observe(&mut self, event: &VibesEvent, now: DateTime<Utc>) {
+        match event {
+            VibesEvent::SessionCreated { session_id, name } => {
+                self.session(session_id).name = name.clone();
+            }
+            VibesEvent::SessionRemoved { session_id, .. } => {
+                self.sessions.remove(session_id);
+                self.deferred.retain(|d| &d.session_id != session_id);
+            }
+            VibesEvent::Claude { session_id, event } => {
+                let session = self.session(session_id);
+                match event {
+                    ClaudeEvent::PermissionRequest { id, tool, .. } => {
+                        session.pending_permissions.insert(
+                            id.clone(),
+                            PendingPermission {
+                                tool: tool.clone(),
+                                since: now,
+                                escalated: false,
+                            },
+                        );
+                        session.waiting_since.get_or_insert(now);
+                    }
+                    ClaudeEvent::TurnStart => session.stop_waiting(),
+                    ClaudeEvent::TurnComplete { usage } => {
+                        session.input_tokens += u64::from(usage.input_tokens);
+                        session.output_tokens += u64::from(usage.output_tokens);
+                        session.waiting_since.get_or_insert(now);
+                    }
+                    _ => {}
+                }
+            }
+            VibesEvent::PermissionResponse {
+                session_id,
+                request_id,
+                ..
+            } => {
+                let session = self.session(session_id);
+                session.pending_permissions.remove(request_id);
+                session.stop_waiting();
+            }
+            VibesEvent::UserInput { session_id, .. } => {
+                self.session(session_id).stop_waiting();
+            }
+            VibesEvent::Hook {
+                session_id: Some(session_id),
+                event,
+            } => {
+                let session = self.session(session_id);
+                if let Some(path) = event
+                    .project_path()
+                    .or_else(|| event.cwd().map(String::from))
+                {
+                    session.project_path = Some(path);
+                }
+                match event {
+                    HookEvent::Stop(_) => {
+                        session.waiting_since.get_or_insert(now);
+                    }
+                    HookEvent::UserPromptSubmit(_) => session.stop_waiting(),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply rules to a candidate notification
+    ///
+    /// Returns the notifications to deliver right away; deferred and
+    /// aggregated notifications are released later by [`RuleEngine::tick`].
+    pub fn submit(
+        &mut self,
+        notification: PushNotification,
+        tool: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Vec<Outgoing> {
+        let session = notification
+            .data
+            .session_id
+            .as_deref()
+            .and_then(|id| self.sessions.get(id));
+        let waiting_since = session.and_then(|s| s.waiting_since);
+        let ctx = RuleContext {
+            event: Some(notification.data.event_type),
+            session_id: notification.data.session_id.as_deref(),
+            session_name: session.and_then(|s| s.name.as_deref()),
+            project_path: session.and_then(|s| s.project_path.as_deref()),
+            tool,
+            waited_secs: waiting_since
+                .map(|since| (now - since).num_seconds().max(0) as u64)
+                .unwrap_or(0),
+            cost_usd: session
+                .map(|s| self.pricing.cost(s.input_tokens, s.output_tokens))
+                .unwrap_or(0.0),
+        };
+
+        let mut decision = None;
+        for (idx, rule) in self.rules.iter().enumerate() {
+            match rule.evaluate(&ctx) {
+                RuleMatch::No => {}
+                RuleMatch::Yes => {
+                    decision = Some((idx, None));
+                    break;
+                }
+                // Waiting can only accrue while the session is actually waiting
+                RuleMatch::AfterWait(secs) => {
+                    if let Some(since) = waiting_since {
+                        decision = Some((idx, Some((since, now + Duration::seconds(secs as i64)))));
+                        break;
+                    }
+                }
+            }
+        }
+
+        match decision {
+            None => self.release(notification, false, None, now),
+            Some((idx, None)) => self.apply(idx, notification, now),
+            Some((idx, Some((waiting_since, due)))) => {
+                debug!(
+                    "Deferring notification until {} (rule '{}')",
+                    due, self.rules[idx].name
+                );
+                let session_id = ctx.session_id.unwrap_or_default().to_string();
+                self.deferred.push(Deferred {
+                    rule: idx,
+                    notification,
+                    session_id,
+                    waiting_since,
+                    due,
+                });
+                Vec::new()
+            }
+        }
+    }
+
+    /// Release notifications whose time has come
+    ///
+    /// Call this periodically (every few seconds is plenty).
+    pub fn tick(&mut self, now: DateTime<Utc>) -> Vec<Outgoing> {
+        let mut out = Vec::new();
+
+        // Deferred rules: deliver only if the session is still waiting
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.deferred)
+            .into_iter()
+            .partition(|d| d.due <= now);
+        self.deferred = pending;
+        for deferred in due {
+            let still_waiting = self
+                .sessions
+                .get(&deferred.session_id)
+                .and_then(|s| s.waiting_since)
+                == Some(deferred.waiting_since);
+            if still_waiting {
+                out.extend(self.apply(deferred.rule, deferred.notification, now));
+            }
+        }
+
+        // Aggregation windows
+        let ready: Vec<_> = self
+            .aggregates
+            .iter()
+            .filter(|(_, agg)| agg.flush_at <= now)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in ready {
+            if let Some(mut agg) = self.aggregates.remove(&key) {
+                let urgent = self.rules[agg.rule].urgent;
+                let notification = if agg.notifications.len() == 1 {
+                    agg.notifications.remove(0)
+                } else {
+                    PushNotification::aggregated(key.1, &agg.notifications)
+                };
+                out.extend(self.release(notification, urgent, None, now));
+            }
+        }
+
+        // Escalate unanswered permission requests
+        if let Some(escalation) = self.escalation.clone() {
+            let threshold = Duration::minutes(escalation.after_minutes as i64);
+            let sinks = (!escalation.sinks.is_empty()).then_some(escalation.sinks);
+            let mut escalations = Vec::new();
+            for (session_id, session) in &mut self.sessions {
+                for (request_id, pending) in &mut session.pending_permissions {
+                    if !pending.escalated && now - pending.since >= threshold {
+                        pending.escalated = true;
+                        escalations.push(
+                            PushNotification::permission_escalated(
+                                session_id,
+                                &pending.tool,
+                                escalation.after_minutes,
+                            )
+                            .with_request_id(request_id.clone()),
+                        );
+                    }
+                }
+            }
+            for notification in escalations {
+                out.extend(self.release(notification, true, sinks.clone(), now));
+            }
+        }
+
+        // Summarise what was held once quiet hours are over
+        if self.held_during_quiet_hours > 0 && !self.is_quiet(now) {
+            let held = std::mem::take(&mut self.held_during_quiet_hours);
+            out.push(Outgoing::routed(PushNotification::quiet_hours_digest(held)));
+        }
+
+        out
+    }
+
+    fn apply(
+        &mut self,
+        rule: usize,
+        notification: PushNotification,
+        now: DateTime<Utc>,
+    ) -> Vec<Outgoing> {
+        let config = &self.rules[rule];
+        if config.action == RuleAction::Suppress {
+            debug!("Notification suppressed by rule '{}'", config.name);
+            return Vec::new();
+        }
+        if let Some(secs) = config.aggregate_secs {
+            let flush_at = now + Duration::seconds(secs as i64);
+            self.aggregates
+                .entry((rule, notification.data.event_type))
+                .or_insert_with(|| Aggregate {
+                    rule,
+                    notifications: Vec::new(),
+                    flush_at,
+                })
+                .notifications
+                .push(notification);
+            return Vec::new();
+        }
+        let urgent = config.urgent;
+        self.release(notification, urgent, None, now)
+    }
+
+    fn release(
+        &mut self,
+        notification: PushNotification,
+        urgent: bool,
+        sinks: Option<Vec<String>>,
+        now: DateTime<Utc>,
+    ) -> Vec<Outgoing> {
+        if !urgent && self.is_quiet(now) {
+            debug!("Holding notification during quiet hours");
+            self.held_during_quiet_hours += 1;
+            return Vec::new();
+        }
+        vec![Outgoing {
+            notification,
+            sinks,
+        }]
+    }
+
+    fn is_quiet(&self, now: DateTime<Utc>) -> bool {
+        self.quiet_hours
+            .as_ref()
+            .is_some_and(|quiet| quiet.contains(now.with_timezone(&self.utc_offset).time()))
+    }
+
+    fn session(&mut self, session_id: &str) -> &mut SessionState {
+        self.sessions.entry(session_id.to_string()).or_default()
+    }
+}
+
+impl SessionState {
+    /// Clear the waiting marker unless a permission request is still open
+    fn stop_waiting(&mut self) {
+        if self.pending_permissions.is_empty() {
+            self.waiting_since = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::events::Usage;
+
+    fn at(hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 15, hour, min, sec).unwrap()
+    }
+
+    fn engine(config: NotificationConfig) -> RuleEngine {
+        RuleEngine::new(&config).with_utc_offset(FixedOffset::east_opt(0).unwrap())
+    }
+
+    fn permission_request(session: &str, id: &str, tool: &str) -> VibesEvent {
+        VibesEvent::Claude {
+            session_id: session.to_string(),
+            event: ClaudeEvent::PermissionRequest {
+                id: id.to_string(),
+                tool: tool.to_string(),
+                description: String::new(),
+            },
+        }
+    }
+
+    /// Observe a permission request and submit its notification
+    fn request(
+        engine: &mut RuleEngine,
+        session: &str,
+        id: &str,
+        tool: &str,
+        now: DateTime<Utc>,
+    ) -> Vec<Outgoing> {
+        engine.observe(&permission_request(session, id, tool), now);
+        engine.submit(
+            PushNotification::permission_needed(session, tool),
+            Some(tool),
+            now,
+        )
+    }
+
+    #[test]
+    fn test_no_rules_passes_through() {
+        let mut engine = engine(NotificationConfig::default());
+        let out = request(&mut engine, "s1", "r1", "Bash", at(12, 0, 0));
+        assert_eq!(out.len(), 1);
+        assert!(out[0].sinks.is_none());
+    }
+
+    #[test]
+    fn test_suppress_rule_drops_matching_tool() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "reads".into(),
+                tool: Some("Read".into()),
+                action: RuleAction::Suppress,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert!(request(&mut engine, "s1", "r1", "Read", at(12, 0, 0)).is_empty());
+        assert_eq!(
+            request(&mut engine, "s1", "r2", "Bash", at(12, 0, 0)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![
+                NotificationRule {
+                    name: "allow-prod".into(),
+                    session: Some("prod-*".into()),
+                    ..Default::default()
+                },
+                NotificationRule {
+                    name: "mute-rest".into(),
+                    action: RuleAction::Suppress,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            request(&mut engine, "prod-1", "r1", "Bash", at(12, 0, 0)).len(),
+            1
+        );
+        assert!(request(&mut engine, "dev-1", "r1", "Bash", at(12, 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_session_name_and_project_come_from_events() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![
+                NotificationRule {
+                    name: "api".into(),
+                    session: Some("api-*".into()),
+                    project: Some("/work/*".into()),
+                    ..Default::default()
+                },
+                NotificationRule {
+                    name: "mute-rest".into(),
+                    action: RuleAction::Suppress,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        let now = at(12, 0, 0);
+        engine.observe(
+            &VibesEvent::SessionCreated {
+                session_id: "s1".into(),
+                name: Some("api-refactor".into()),
+            },
+            now,
+        );
+        engine.observe(
+            &VibesEvent::Hook {
+                session_id: Some("s1".into()),
+                event: HookEvent::Stop(crate::hooks::StopData {
+                    session_id: Some("s1".into()),
+                    transcript_path: None,
+                    cwd: Some("/work/api".into()),
+                    permission_mode: None,
+                    hook_event_name: None,
+                    stop_hook_active: None,
+                }),
+            },
+            now,
+        );
+
+        assert_eq!(request(&mut engine, "s1", "r1", "Bash", now).len(), 1);
+    }
+
+    #[test]
+    fn test_cost_threshold_uses_turn_usage() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![
+                NotificationRule {
+                    name: "expensive".into(),
+                    min_cost_usd: Some(1.0),
+                    ..Default::default()
+                },
+                NotificationRule {
+                    name: "mute-rest".into(),
+                    action: RuleAction::Suppress,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        let now = at(12, 0, 0);
+        assert!(request(&mut engine, "s1", "r1", "Bash", now).is_empty());
+
+        engine.observe(
+            &VibesEvent::Claude {
+                session_id: "s1".into(),
+                event: ClaudeEvent::TurnComplete {
+                    usage: Usage {
+                        input_tokens: 100_000,
+                        output_tokens: 100_000,
+                    },
+                },
+            },
+            now,
+        );
+        assert_eq!(request(&mut engine, "s1", "r2", "Bash", now).len(), 1);
+    }
+
+    #[test]
+    fn test_min_wait_defers_until_session_has_waited() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "stuck".into(),
+                min_wait_secs: Some(120),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert!(request(&mut engine, "s1", "r1", "Bash", at(12, 0, 0)).is_empty());
+        assert!(engine.tick(at(12, 1, 0)).is_empty());
+
+        let out = engine.tick(at(12, 2, 0));
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].notification.data.event_type,
+            NotificationEvent::PermissionNeeded
+        );
+    }
+
+    #[test]
+    fn test_min_wait_dropped_when_answered() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "stuck".into(),
+                min_wait_secs: Some(120),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        request(&mut engine, "s1", "r1", "Bash", at(12, 0, 0));
+        engine.observe(
+            &VibesEvent::PermissionResponse {
+                session_id: "s1".into(),
+                request_id: "r1".into(),
+                approved: true,
+            },
+            at(12, 0, 30),
+        );
+
+        assert!(engine.tick(at(12, 5, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_aggregation_summarises_sessions() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "batch".into(),
+                aggregate_secs: Some(60),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        for session in ["s1", "s2", "s3"] {
+            assert!(request(&mut engine, session, "r1", "Bash", at(12, 0, 0)).is_empty());
+        }
+        assert!(engine.tick(at(12, 0, 30)).is_empty());
+
+        let out = engine.tick(at(12, 1, 0));
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].notification.title, "3 sessions waiting");
+    }
+
+    #[test]
+    fn test_aggregation_of_one_sends_original() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "batch".into(),
+                aggregate_secs: Some(60),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        request(&mut engine, "s1", "r1", "Bash", at(12, 0, 0));
+        let out = engine.tick(at(12, 1, 0));
+        assert_eq!(out[0].notification.title, "Claude needs approval");
+    }
+
+    #[test]
+    fn test_quiet_hours_hold_then_digest() {
+        let mut engine = engine(NotificationConfig {
+            quiet_hours: Some(QuietHours {
+                start: "22:00".into(),
+                end: "07:00".into(),
+            }),
+            ..Default::default()
+        });
+
+        assert!(request(&mut engine, "s1", "r1", "Bash", at(23, 0, 0)).is_empty());
+        assert!(request(&mut engine, "s2", "r1", "Bash", at(23, 5, 0)).is_empty());
+        assert!(engine.tick(at(6, 59, 0)).is_empty());
+
+        let out = engine.tick(at(7, 0, 0));
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].notification.data.event_type,
+            NotificationEvent::Digest
+        );
+        assert!(out[0].notification.body.contains('2'));
+    }
+
+    #[test]
+    fn test_urgent_rule_bypasses_quiet_hours() {
+        let mut engine = engine(NotificationConfig {
+            rules: vec![NotificationRule {
+                name: "errors".into(),
+                events: vec![NotificationEvent::SessionError],
+                urgent: true,
+                ..Default::default()
+            }],
+            quiet_hours: Some(QuietHours {
+                start: "22:00".into(),
+                end: "07:00".into(),
+            }),
+            ..Default::default()
+        });
+
+        let out = engine.submit(
+            PushNotification::session_error("s1", "boom"),
+            None,
+            at(23, 0, 0),
+        );
+        assert_eq!(out.len(), 1);
+    }
+
+    #[test]
+    fn test_escalation_after_unanswered_minutes() {
+        let mut engine = engine(NotificationConfig {
+            escalation: Some(EscalationConfig {
+                after_minutes: 10,
+                sinks: vec!["team-slack".into()],
+            }),
+            quiet_hours: Some(QuietHours {
+                start: "22:00".into(),
+                end: "07:00".into(),
+            }),
+            ..Default::default()
+        });
+
+        // Held by quiet hours, but escalation is urgent
+        assert!(request(&mut engine, "s1", "r1", "Bash", at(23, 0, 0)).is_empty());
+        assert!(engine.tick(at(23, 9, 0)).is_empty());
+
+        let out = engine.tick(at(23, 10, 0));
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].sinks, Some(vec!["team-slack".to_string()]));
+        assert!(out[0].notification.body.contains("10 minutes"));
+
+        // Only escalates once
+        assert!(engine.tick(at(23, 20, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_no_escalation_once_answered() {
+        let mut engine = engine(NotificationConfig {
+            escalation: Some(EscalationConfig {
+                after_minutes: 10,
+                sinks: vec![],
+            }),
+            ..Default::default()
+        });
+
+        request(&mut engine, "s1", "r1", "Bash", at(12, 0, 0));
+        engine.observe(
+            &VibesEvent::PermissionResponse {
+                session_id: "s1".into(),
+                request_id: "r1".into(),
+                approved: false,
+            },
+            at(12, 1, 0),
+        );
+
+        assert!(engine.tick(at(12, 30, 0)).is_empty());
+    }
+}
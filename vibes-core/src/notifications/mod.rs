@@ -1,13 +1,34 @@
-//! Push notification support for vibes
+//! Notification support for vibes
+//!
+//! Events are converted to [`PushNotification`]s and delivered through one
+//! or more [`NotificationSink`]s: Web Push, webhooks, Slack, ntfy, desktop
+//! notifications and email. Permission notifications can carry signed
+//! approve/deny actions, see [`ActionSigner`].
 
+mod actions;
 mod config;
+mod engine;
+mod routing;
+mod rules;
 mod service;
+pub mod sinks;
 mod store;
 mod types;
 mod vapid;
 
+pub use actions::{
+    ActionClaims, ActionKind, ActionSigner, ActionTokenError, DEFAULT_ACTION_TTL_SECS,
+    NotificationAction,
+};
 pub use config::NotificationConfig;
+pub use engine::{Outgoing, RuleEngine};
+pub use routing::{NotificationRoute, NotificationRouter};
+pub use rules::{
+    EscalationConfig, NotificationRule, QuietHours, RuleAction, RuleContext, RuleMatch,
+    TokenPricing,
+};
 pub use service::NotificationService;
+pub use sinks::{NotificationSink, SinkConfig};
 pub use store::SubscriptionStore;
 pub use types::{
     NotificationData, NotificationEvent, PushNotification, PushSubscription, SubscriptionKeys,
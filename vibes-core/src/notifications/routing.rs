@@ -0,0 +1,176 @@
+//! Routing rules that pick notification sinks per event and session
+
+use serde::{Deserialize, Serialize};
+
+use super::{NotificationEvent, PushNotification};
+
+/// A rule selecting sinks for matching notifications
+///
+/// ```toml
+/// [[routes]]
+/// events = ["permission_needed"]
+/// session = "prod-*"
+/// sinks = ["team-slack", "web_push"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NotificationRoute {
+    /// Event types this route applies to (empty matches every event)
+    #[serde(default)]
+    pub events: Vec<NotificationEvent>,
+
+    /// Session ID pattern, where `*` matches any run of characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+
+    /// Names of the sinks that receive matching notifications
+    pub sinks: Vec<String>,
+}
+
+impl NotificationRoute {
+    /// Check whether this route applies to a notification
+    pub fn matches(&self, notification: &PushNotification) -> bool {
+        if !self.events.is_empty() && !self.events.contains(&notification.data.event_type) {
+            return false;
+        }
+        match (&self.session, &notification.data.session_id) {
+            (None, _) => true,
+            (Some(pattern), Some(session_id)) => wildcard_match(pattern, session_id),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// Resolves which sinks should receive a notification
+#[derive(Debug, Clone, Default)]
+pub struct NotificationRouter {
+    routes: Vec<NotificationRoute>,
+}
+
+impl NotificationRouter {
+    /// Create a router from a list of routes
+    pub fn new(routes: Vec<NotificationRoute>) -> Self {
+        Self { routes }
+    }
+
+    /// Select sink names for a notification
+    ///
+    /// Returns `None` when no routes are configured, meaning every sink
+    /// receives the notification. Otherwise returns the union of sinks from
+    /// all matching routes, in first-seen order.
+    pub fn select(&self, notification: &PushNotification) -> Option<Vec<&str>> {
+        if self.routes.is_empty() {
+            return None;
+        }
+
+        let mut selected: Vec<&str> = Vec::new();
+        for route in self.routes.iter().filter(|r| r.matches(notification)) {
+            for sink in &route.sinks {
+                if !selected.contains(&sink.as_str()) {
+                    selected.push(sink);
+                }
+            }
+        }
+        Some(selected)
+    }
+}
+
+/// Match `value` against a pattern where `*` matches any run of characters
+pub(crate) fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == value;
+    }
+
+    let (first, rest) = parts.split_first().expect("split yields at least one part");
+    let Some(mut remaining) = value.strip_prefix(first) else {
+        return false;
+    };
+    let (last, middle) = rest.split_last().expect("pattern contains a wildcard");
+
+    for part in middle {
+        match remaining.find(part) {
+            Some(idx) => remaining = &remaining[idx + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(
+        events: Vec<NotificationEvent>,
+        session: Option<&str>,
+        sinks: &[&str],
+    ) -> NotificationRoute {
+        NotificationRoute {
+            events,
+            session: session.map(String::from),
+            sinks: sinks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("sess-1", "sess-1"));
+        assert!(!wildcard_match("sess-1", "sess-12"));
+        assert!(wildcard_match("prod-*", "prod-api"));
+        assert!(wildcard_match("*-api", "prod-api"));
+        assert!(wildcard_match("p*d*i", "prod-api"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("prod-*", "dev-api"));
+        assert!(!wildcard_match("a*a", "a"));
+    }
+
+    #[test]
+    fn test_no_routes_selects_everything() {
+        let router = NotificationRouter::default();
+        let notification = PushNotification::session_completed("sess-1");
+        assert_eq!(router.select(&notification), None);
+    }
+
+    #[test]
+    fn test_routes_filter_by_event_type() {
+        let router = NotificationRouter::new(vec![
+            route(vec![NotificationEvent::PermissionNeeded], None, &["slack"]),
+            route(vec![NotificationEvent::SessionError], None, &["email"]),
+        ]);
+
+        let permission = PushNotification::permission_needed("sess-1", "Bash");
+        assert_eq!(router.select(&permission), Some(vec!["slack"]));
+
+        let completed = PushNotification::session_completed("sess-1");
+        assert_eq!(router.select(&completed), Some(vec![]));
+    }
+
+    #[test]
+    fn test_routes_filter_by_session_and_merge() {
+        let router = NotificationRouter::new(vec![
+            route(vec![], Some("prod-*"), &["slack", "ntfy"]),
+            route(vec![], None, &["web_push", "slack"]),
+        ]);
+
+        let prod = PushNotification::session_error("prod-api", "boom");
+        assert_eq!(
+            router.select(&prod),
+            Some(vec!["slack", "ntfy", "web_push"])
+        );
+
+        let dev = PushNotification::session_error("dev-api", "boom");
+        assert_eq!(router.select(&dev), Some(vec!["web_push", "slack"]));
+    }
+
+    #[test]
+    fn test_route_deserialize_toml() {
+        let toml = r#"
+            events = ["permission_needed", "session_error"]
+            session = "prod-*"
+            sinks = ["team"]
+        "#;
+        let route: NotificationRoute = toml::from_str(toml).unwrap();
+        assert_eq!(route.events.len(), 2);
+        assert_eq!(route.session.as_deref(), Some("prod-*"));
+    }
+}
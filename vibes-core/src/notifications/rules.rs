@@ -0,0 +1,385 @@
+//! Notification rules, quiet hours and escalation settings
+//!
+//! Rules are evaluated in order and the first match decides what happens to
+//! a notification. Notifications that match no rule are delivered as usual.
+//!
+//! ```toml
+//! [[rules]]
+//! name = "noisy-reads"
+//! events = ["permission_needed"]
+//! tool = "Read"
+//! action = "suppress"
+//!
+//! [[rules]]
+//! name = "stuck"
+//! events = ["permission_needed"]
+//! min_wait_secs = 120
+//! aggregate_secs = 60
+//!
+//! [quiet_hours]
+//! start = "22:00"
+//! end = "07:00"
+//!
+//! [escalation]
+//! after_minutes = 10
+//! sinks = ["team-slack"]
+//! ```
+
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+use super::NotificationEvent;
+use super::routing::wildcard_match;
+use crate::NotificationError;
+
+/// What to do with a notification matched by a rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    /// Deliver the notification
+    #[default]
+    Notify,
+    /// Drop the notification
+    Suppress,
+}
+
+/// A single notification rule
+///
+/// All conditions that are set must hold for the rule to match.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationRule {
+    /// Rule name, used in logs and aggregation keys
+    pub name: String,
+
+    /// Event types this rule applies to (empty matches every event)
+    #[serde(default)]
+    pub events: Vec<NotificationEvent>,
+
+    /// Pattern matched against the session name or ID (`*` wildcard)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+
+    /// Pattern matched against the session's project path (`*` wildcard)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// Pattern matched against the tool name (`*` wildcard)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+
+    /// Only match once the session has waited this long for input
+    ///
+    /// The notification is held until the wait is reached and dropped if
+    /// the session receives input first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_wait_secs: Option<u64>,
+
+    /// Only match once the session's estimated cost reaches this amount
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_cost_usd: Option<f64>,
+
+    /// What to do when the rule matches
+    #[serde(default)]
+    pub action: RuleAction,
+
+    /// Deliver even during quiet hours
+    #[serde(default)]
+    pub urgent: bool,
+
+    /// Collect matches for this many seconds and send one summary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregate_secs: Option<u64>,
+}
+
+/// Facts about a notification that rules are matched against
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleContext<'a> {
+    pub event: Option<NotificationEvent>,
+    pub session_id: Option<&'a str>,
+    pub session_name: Option<&'a str>,
+    pub project_path: Option<&'a str>,
+    pub tool: Option<&'a str>,
+    /// Seconds the session has been waiting for input (0 if not waiting)
+    pub waited_secs: u64,
+    pub cost_usd: f64,
+}
+
+/// Result of matching a rule against a context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleMatch {
+    /// The rule does not apply
+    No,
+    /// The rule applies now
+    Yes,
+    /// The rule will apply once the session has waited this many more seconds
+    AfterWait(u64),
+}
+
+impl NotificationRule {
+    /// Match this rule against a notification context
+    pub fn evaluate(&self, ctx: &RuleContext<'_>) -> RuleMatch {
+        if !self.events.is_empty() && !ctx.event.is_some_and(|e| self.events.contains(&e)) {
+            return RuleMatch::No;
+        }
+        if let Some(pattern) = &self.session {
+            let by_id = ctx.session_id.is_some_and(|id| wildcard_match(pattern, id));
+            let by_name = ctx
+                .session_name
+                .is_some_and(|name| wildcard_match(pattern, name));
+            if !by_id && !by_name {
+                return RuleMatch::No;
+            }
+        }
+        if !optional_match(self.project.as_deref(), ctx.project_path)
+            || !optional_match(self.tool.as_deref(), ctx.tool)
+        {
+            return RuleMatch::No;
+        }
+        if self.min_cost_usd.is_some_and(|min| ctx.cost_usd < min) {
+            return RuleMatch::No;
+        }
+        match self.min_wait_secs {
+            Some(min) if ctx.waited_secs < min => RuleMatch::AfterWait(min - ctx.waited_secs),
+            _ => RuleMatch::Yes,
+        }
+    }
+}
+
+fn optional_match(pattern: Option<&str>, value: Option<&str>) -> bool {
+    match (pattern, value) {
+        (None, _) => true,
+        (Some(pattern), Some(value)) => wildcard_match(pattern, value),
+        (Some(_), None) => false,
+    }
+}
+
+/// Daily window during which only urgent notifications are delivered
+///
+/// Times are `HH:MM` in local time. A window whose end is before its start
+/// wraps past midnight.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: String,
+    pub end: String,
+}
+
+impl QuietHours {
+    /// Parse the configured start and end times
+    pub fn parse(&self) -> Result<(NaiveTime, NaiveTime), NotificationError> {
+        let parse = |s: &str| {
+            NaiveTime::parse_from_str(s, "%H:%M").map_err(|e| {
+                NotificationError::Config(format!("invalid quiet hours time '{}': {}", s, e))
+            })
+        };
+        Ok((parse(&self.start)?, parse(&self.end)?))
+    }
+
+    /// Check whether a local time falls inside the window
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        let Ok((start, end)) = self.parse() else {
+            return false;
+        };
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+}
+
+/// Escalation for permission requests nobody answers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscalationConfig {
+    /// Minutes a permission request may stay unanswered before escalating
+    pub after_minutes: u64,
+
+    /// Sinks that receive the escalation (empty uses normal routing)
+    #[serde(default)]
+    pub sinks: Vec<String>,
+}
+
+/// Token prices used to estimate session cost for `min_cost_usd`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TokenPricing {
+    /// USD per million input tokens
+    #[serde(default = "default_input_price")]
+    pub input_per_mtok: f64,
+    /// USD per million output tokens
+    #[serde(default = "default_output_price")]
+    pub output_per_mtok: f64,
+}
+
+fn default_input_price() -> f64 {
+    3.0
+}
+
+fn default_output_price() -> f64 {
+    15.0
+}
+
+impl Default for TokenPricing {
+    fn default() -> Self {
+        Self {
+            input_per_mtok: default_input_price(),
+            output_per_mtok: default_output_price(),
+        }
+    }
+}
+
+impl TokenPricing {
+    /// Estimate the cost of a token count
+    pub fn cost(&self, input_tokens: u64, output_tokens: u64) -> f64 {
+        (input_tokens as f64 * self.input_per_mtok + output_tokens as f64 * self.output_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> RuleContext<'static> {
+        RuleContext {
+            event: Some(NotificationEvent::PermissionNeeded),
+            session_id: Some("sess-1"),
+            session_name: Some("api-refactor"),
+            project_path: Some("/home/dev/api"),
+            tool: Some("Bash"),
+            waited_secs: 0,
+            cost_usd: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_empty_rule_matches_everything() {
+        let rule = NotificationRule::default();
+        assert_eq!(rule.evaluate(&ctx()), RuleMatch::Yes);
+    }
+
+    #[test]
+    fn test_rule_matches_session_by_name_or_id() {
+        let by_name = NotificationRule {
+            session: Some("api-*".into()),
+            ..Default::default()
+        };
+        let by_id = NotificationRule {
+            session: Some("sess-*".into()),
+            ..Default::default()
+        };
+        let neither = NotificationRule {
+            session: Some("web-*".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(by_name.evaluate(&ctx()), RuleMatch::Yes);
+        assert_eq!(by_id.evaluate(&ctx()), RuleMatch::Yes);
+        assert_eq!(neither.evaluate(&ctx()), RuleMatch::No);
+    }
+
+    #[test]
+    fn test_rule_matches_project_tool_and_event() {
+        let rule = NotificationRule {
+            events: vec![NotificationEvent::PermissionNeeded],
+            project: Some("/home/dev/*".into()),
+            tool: Some("Bash".into()),
+            ..Default::default()
+        };
+        assert_eq!(rule.evaluate(&ctx()), RuleMatch::Yes);
+
+        let other_tool = RuleContext {
+            tool: Some("Read"),
+            ..ctx()
+        };
+        assert_eq!(rule.evaluate(&other_tool), RuleMatch::No);
+
+        let other_event = RuleContext {
+            event: Some(NotificationEvent::SessionError),
+            ..ctx()
+        };
+        assert_eq!(rule.evaluate(&other_event), RuleMatch::No);
+    }
+
+    #[test]
+    fn test_rule_cost_threshold() {
+        let rule = NotificationRule {
+            min_cost_usd: Some(1.0),
+            ..Default::default()
+        };
+        assert_eq!(rule.evaluate(&ctx()), RuleMatch::No);
+
+        let expensive = RuleContext {
+            cost_usd: 2.5,
+            ..ctx()
+        };
+        assert_eq!(rule.evaluate(&expensive), RuleMatch::Yes);
+    }
+
+    #[test]
+    fn test_rule_wait_threshold_defers() {
+        let rule = NotificationRule {
+            min_wait_secs: Some(120),
+            ..Default::default()
+        };
+        assert_eq!(rule.evaluate(&ctx()), RuleMatch::AfterWait(120));
+
+        let waited = RuleContext {
+            waited_secs: 150,
+            ..ctx()
+        };
+        assert_eq!(rule.evaluate(&waited), RuleMatch::Yes);
+    }
+
+    #[test]
+    fn test_quiet_hours_wrap_midnight() {
+        let quiet = QuietHours {
+            start: "22:00".into(),
+            end: "07:00".into(),
+        };
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert!(quiet.contains(at(23, 30)));
+        assert!(quiet.contains(at(3, 0)));
+        assert!(!quiet.contains(at(7, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+    }
+
+    #[test]
+    fn test_quiet_hours_same_day() {
+        let quiet = QuietHours {
+            start: "12:00".into(),
+            end: "13:00".into(),
+        };
+        assert!(quiet.contains(NaiveTime::from_hms_opt(12, 30, 0).unwrap()));
+        assert!(!quiet.contains(NaiveTime::from_hms_opt(13, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_quiet_hours_invalid_time_is_error() {
+        let quiet = QuietHours {
+            start: "late".into(),
+            end: "07:00".into(),
+        };
+        assert!(quiet.parse().is_err());
+    }
+
+    #[test]
+    fn test_token_pricing_cost() {
+        let pricing = TokenPricing::default();
+        let cost = pricing.cost(1_000_000, 100_000);
+        assert!((cost - 4.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rule_deserialize_toml() {
+        let toml = r#"
+            name = "quiet-reads"
+            events = ["permission_needed"]
+            tool = "Read"
+            action = "suppress"
+        "#;
+        let rule: NotificationRule = toml::from_str(toml).unwrap();
+        assert_eq!(rule.action, RuleAction::Suppress);
+        assert_eq!(rule.tool.as_deref(), Some("Read"));
+        assert!(!rule.urgent);
+    }
+}
@@ -1,56 +1,110 @@
 //! Notification service that listens to events and sends push notifications
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use base64ct::{Base64UrlUnpadded, Encoding};
-use http::Uri;
-use p256::EncodedPoint;
-use p256::elliptic_curve::sec1::FromEncodedPoint;
+use chrono::Utc;
 use tokio::sync::broadcast;
-use tracing::{debug, error, info, warn};
-use web_push_native::jwt_simple::prelude::ES256KeyPair;
-use web_push_native::{Auth, WebPushBuilder};
-
-use super::{NotificationConfig, PushNotification, SubscriptionStore, VapidKeyManager};
+use tracing::{debug, info, warn};
+
+use super::actions::ActionSigner;
+use super::engine::{Outgoing, RuleEngine};
+use super::sinks::{NotificationSink, WebPushSink};
+use super::{
+    NotificationConfig, NotificationEvent, NotificationRouter, PushNotification, SubscriptionStore,
+    VapidKeyManager,
+};
 use crate::NotificationError;
 use crate::events::{ClaudeEvent, VibesEvent};
 
-/// Service that sends push notifications based on vibes events
+/// Service that sends notifications to configured sinks based on vibes events
 pub struct NotificationService {
-    vapid: Arc<VapidKeyManager>,
-    subscriptions: Arc<SubscriptionStore>,
+    sinks: Vec<Arc<dyn NotificationSink>>,
+    router: NotificationRouter,
+    engine: Mutex<RuleEngine>,
+    action_signer: Option<Arc<ActionSigner>>,
     config: NotificationConfig,
-    http_client: reqwest::Client,
 }
 
 impl NotificationService {
-    /// Create a new NotificationService
+    /// Create a new NotificationService with Web Push plus the configured sinks
+    ///
+    /// Sinks that fail to build are logged and skipped so that a bad entry
+    /// does not disable Web Push.
     pub fn new(
         vapid: Arc<VapidKeyManager>,
         subscriptions: Arc<SubscriptionStore>,
         config: NotificationConfig,
     ) -> Self {
-        Self {
+        let http_client = reqwest::Client::new();
+        let mut service = Self::without_sinks(config);
+        service.sinks.push(Arc::new(WebPushSink::new(
             vapid,
             subscriptions,
+            http_client.clone(),
+        )));
+
+        for sink_config in &service.config.sinks {
+            match sink_config.build(&http_client) {
+                Ok(sink) => service.sinks.push(sink),
+                Err(e) => warn!("Skipping notification sink '{}': {}", sink_config.name(), e),
+            }
+        }
+
+        service
+    }
+
+    /// Create a NotificationService using only the sinks in the config
+    ///
+    /// Unlike [`NotificationService::new`], invalid sink configs are an error.
+    pub fn from_config(config: NotificationConfig) -> Result<Self, NotificationError> {
+        let http_client = reqwest::Client::new();
+        let mut service = Self::without_sinks(config);
+        service.sinks = service
+            .config
+            .sinks
+            .iter()
+            .map(|sink_config| sink_config.build(&http_client))
+            .collect::<Result<_, _>>()?;
+        Ok(service)
+    }
+
+    fn without_sinks(config: NotificationConfig) -> Self {
+        Self {
+            sinks: Vec::new(),
+            router: NotificationRouter::new(config.routes.clone()),
+            engine: Mutex::new(RuleEngine::new(&config)),
+            action_signer: None,
             config,
-            http_client: reqwest::Client::new(),
         }
     }
 
+    /// Add a sink (builder style)
+    pub fn with_sink(mut self, sink: Arc<dyn NotificationSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Attach approve/deny actions to permission notifications (builder style)
+    ///
+    /// The same signer must be used to redeem the tokens.
+    pub fn with_action_signer(mut self, signer: Arc<ActionSigner>) -> Self {
+        self.action_signer = Some(signer);
+        self
+    }
+
+    /// Names of the registered sinks
+    pub fn sink_names(&self) -> Vec<&str> {
+        self.sinks.iter().map(|s| s.name()).collect()
+    }
+
     /// Start listening to events and sending notifications
-    #[allow(clippy::collapsible_if)] // Prefer readability over collapsed let chains for side effects
     pub async fn run(&self, mut event_rx: broadcast::Receiver<(u64, VibesEvent)>) {
         info!("NotificationService started");
 
         loop {
             match event_rx.recv().await {
                 Ok((_seq, event)) => {
-                    if let Some(notification) = self.event_to_notification(&event) {
-                        if let Err(e) = self.send_to_all(notification).await {
-                            error!("Failed to send notifications: {}", e);
-                        }
-                    }
+                    self.process_event(&event).await;
                 }
                 Err(broadcast::error::RecvError::Lagged(n)) => {
                     warn!("NotificationService lagged by {} events", n);
@@ -73,9 +127,9 @@ impl NotificationService {
             // Permission requests come from Claude events
             VibesEvent::Claude {
                 session_id,
-                event: ClaudeEvent::PermissionRequest { tool, .. },
+                event: ClaudeEvent::PermissionRequest { id, tool, .. },
             } if self.config.notify_permission => {
-                Some(PushNotification::permission_needed(session_id, tool))
+                Some(PushNotification::permission_needed(session_id, tool).with_request_id(id))
             }
 
             // Errors come from Claude events
@@ -97,108 +151,66 @@ impl NotificationService {
         }
     }
 
-    /// Send a notification to all subscribed browsers
-    #[allow(clippy::collapsible_if)] // Prefer readability over collapsed let chains for side effects
-    async fn send_to_all(&self, notification: PushNotification) -> Result<(), NotificationError> {
-        let subscriptions = self.subscriptions.list().await;
-
-        if subscriptions.is_empty() {
-            debug!("No subscriptions, skipping notification");
-            return Ok(());
-        }
+    /// Select the sinks that should receive a notification
+    fn route(
+        &self,
+        notification: &PushNotification,
+        sinks_override: Option<&[String]>,
+    ) -> Vec<&Arc<dyn NotificationSink>> {
+        let names = match sinks_override {
+            Some(names) => names.iter().map(String::as_str).collect(),
+            None => match self.router.select(notification) {
+                None => return self.sinks.iter().collect(),
+                Some(names) => names,
+            },
+        };
+        self.sinks
+            .iter()
+            .filter(|sink| names.contains(&sink.name()))
+            .collect()
+    }
 
-        let payload = serde_json::to_string(&notification)
-            .map_err(|e| NotificationError::SendFailed(format!("serialization error: {}", e)))?;
+    /// Deliver a notification to every routed sink
+    ///
+    /// A failing sink does not prevent delivery to the others; all
+    /// failures are logged and the number of successful sinks is returned.
+    pub async fn dispatch(&self, notification: &PushNotification) -> usize {
+        self.dispatch_to(notification, None).await
+    }
 
-        let mut stale_ids = Vec::new();
+    /// Deliver a notification to the named sinks, or the routed ones if `None`
+    pub async fn dispatch_to(
+        &self,
+        notification: &PushNotification,
+        sinks: Option<&[String]>,
+    ) -> usize {
+        let sinks = self.route(notification, sinks);
+        if sinks.is_empty() {
+            debug!("No sinks routed for {:?}", notification.data.event_type);
+            return 0;
+        }
 
-        for sub in subscriptions {
-            match self.send_one(&sub, &payload).await {
+        // Copy without action tokens for channels that would only forward them
+        let stripped = (!notification.actions.is_empty()).then(|| PushNotification {
+            actions: Vec::new(),
+            ..notification.clone()
+        });
+
+        let mut delivered = 0;
+        for sink in sinks {
+            let outgoing = match &stripped {
+                Some(stripped) if !sink.renders_actions() => stripped,
+                _ => notification,
+            };
+            match sink.send(outgoing).await {
                 Ok(()) => {
-                    debug!("Sent notification to {}", sub.endpoint);
-                }
-                Err(e) if e.contains("410") || e.contains("404") => {
-                    // Subscription is stale/expired
-                    warn!("Subscription {} is stale, marking for removal", sub.id);
-                    stale_ids.push(sub.id.clone());
+                    debug!("Delivered notification via {}", sink.name());
+                    delivered += 1;
                 }
-                Err(e) => {
-                    warn!("Failed to send to {}: {}", sub.endpoint, e);
-                }
-            }
-        }
-
-        // Clean up stale subscriptions
-        if !stale_ids.is_empty() {
-            if let Err(e) = self.subscriptions.cleanup_stale(&stale_ids).await {
-                error!("Failed to cleanup stale subscriptions: {}", e);
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Send to a single subscription
-    async fn send_one(&self, sub: &super::PushSubscription, payload: &str) -> Result<(), String> {
-        // Parse endpoint as URI
-        let endpoint: Uri = sub
-            .endpoint
-            .parse()
-            .map_err(|e| format!("invalid endpoint URL: {}", e))?;
-
-        // Decode the subscription keys
-        let p256dh_bytes = Base64UrlUnpadded::decode_vec(&sub.keys.p256dh)
-            .map_err(|e| format!("invalid p256dh key: {}", e))?;
-        let auth_bytes = Base64UrlUnpadded::decode_vec(&sub.keys.auth)
-            .map_err(|e| format!("invalid auth key: {}", e))?;
-
-        // Parse p256dh as a P-256 public key (65 bytes uncompressed point)
-        let encoded_point = EncodedPoint::from_bytes(&p256dh_bytes)
-            .map_err(|e| format!("invalid p256dh point: {}", e))?;
-        let ua_public = p256::PublicKey::from_encoded_point(&encoded_point);
-        let ua_public =
-            Option::from(ua_public).ok_or_else(|| "invalid p256dh public key".to_string())?;
-
-        // Convert auth to Auth type (16 bytes)
-        let auth_array: [u8; 16] = auth_bytes
-            .try_into()
-            .map_err(|_| "auth secret must be 16 bytes")?;
-        let auth: Auth = auth_array.into();
-
-        // Create the web push builder
-        let builder = WebPushBuilder::new(endpoint, ua_public, auth);
-
-        // Get the signing key and create a key pair for VAPID
-        let signing_key = self.vapid.signing_key();
-        let key_pair = ES256KeyPair::from_bytes(&signing_key.to_bytes())
-            .map_err(|e| format!("failed to create VAPID key pair: {}", e))?;
-
-        // Build the request with VAPID
-        let request = builder
-            .with_vapid(&key_pair, "mailto:noreply@vibes.local")
-            .build(payload.as_bytes())
-            .map_err(|e| format!("failed to build push request: {}", e))?;
-
-        // Convert to reqwest request and send
-        let (parts, body) = request.into_parts();
-        let url = parts.uri.to_string();
-
-        let mut req = self.http_client.post(&url);
-        for (name, value) in parts.headers.iter() {
-            if let Ok(v) = value.to_str() {
-                req = req.header(name.as_str(), v);
+                Err(e) => warn!("Notification sink {} failed: {}", sink.name(), e),
             }
         }
-        req = req.body(body);
-
-        let response = req.send().await.map_err(|e| format!("HTTP error: {}", e))?;
-
-        let status = response.status();
-        if status.is_success() || status.as_u16() == 201 {
-            Ok(())
-        } else {
-            Err(format!("Push failed with status {}", status.as_u16()))
-        }
+        delivered
     }
 
     /// Get the configuration
@@ -209,20 +221,149 @@ impl NotificationService {
     /// Process a single event for notification dispatch.
     ///
     /// This is the primary entry point for EventLog consumers.
-    /// Converts the event to a notification if applicable and sends to all subscribers.
+    /// Converts the event to a notification if applicable and sends it to the routed sinks.
+    ///
+    /// Every event also updates the rule engine's view of its session, so
+    /// this should see the full event stream, not just notifiable events.
     pub async fn process_event(&self, event: &VibesEvent) {
-        if let Some(notification) = self.event_to_notification(event)
-            && let Err(e) = self.send_to_all(notification).await
-        {
-            error!("Failed to send notifications: {}", e);
+        let now = Utc::now();
+        let outgoing = {
+            let Some(mut engine) = self.lock_engine() else {
+                return;
+            };
+            engine.observe(event, now);
+            match self.event_to_notification(event) {
+                Some(notification) => engine.submit(notification, permission_tool(event), now),
+                None => Vec::new(),
+            }
+        };
+        self.deliver(outgoing).await;
+    }
+
+    /// Release deferred, aggregated, escalated and held notifications.
+    ///
+    /// Call periodically; see [`RuleEngine::tick`].
+    pub async fn tick(&self) {
+        let outgoing = match self.lock_engine() {
+            Some(mut engine) => engine.tick(Utc::now()),
+            None => return,
+        };
+        self.deliver(outgoing).await;
+    }
+
+    async fn deliver(&self, outgoing: Vec<Outgoing>) {
+        for mut item in outgoing {
+            self.attach_actions(&mut item.notification);
+            self.dispatch_to(&item.notification, item.sinks.as_deref())
+                .await;
+        }
+    }
+
+    /// Add signed approve/deny actions to a notification about a permission request
+    ///
+    /// Tokens are issued at delivery time so deferred and escalated
+    /// notifications still carry fresh ones.
+    fn attach_actions(&self, notification: &mut PushNotification) {
+        let Some(signer) = &self.action_signer else {
+            return;
+        };
+        let data = &notification.data;
+        if data.event_type != NotificationEvent::PermissionNeeded {
+            return;
+        }
+        if let (Some(session_id), Some(request_id)) = (&data.session_id, &data.request_id) {
+            notification.actions = signer.actions_for(session_id, request_id, Utc::now());
+        }
+    }
+
+    fn lock_engine(&self) -> Option<std::sync::MutexGuard<'_, RuleEngine>> {
+        match self.engine.lock() {
+            Ok(guard) => Some(guard),
+            Err(_) => {
+                warn!("Notification rule engine mutex poisoned, dropping notification work");
+                None
+            }
         }
     }
 }
 
+/// Tool name for permission request events, used by tool-matching rules
+fn permission_tool(event: &VibesEvent) -> Option<&str> {
+    match event {
+        VibesEvent::Claude {
+            event: ClaudeEvent::PermissionRequest { tool, .. },
+            ..
+        } => Some(tool),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
     use super::*;
     use crate::events::ClaudeEvent;
+    use crate::notifications::{NotificationEvent, NotificationRoute};
+
+    /// Sink that records notifications, optionally failing every send
+    struct RecordingSink {
+        name: String,
+        fail: bool,
+        renders_actions: bool,
+        received: Mutex<Vec<PushNotification>>,
+    }
+
+    impl RecordingSink {
+        fn new(name: &str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name: name.to_string(),
+                fail,
+                renders_actions: false,
+                received: Mutex::new(Vec::new()),
+            })
+        }
+
+        /// A sink that shows approve/deny actions, like Web Push
+        fn with_actions(name: &str) -> Arc<Self> {
+            Arc::new(Self {
+                name: name.to_string(),
+                fail: false,
+                renders_actions: true,
+                received: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn received(&self) -> Vec<String> {
+            self.notifications().into_iter().map(|n| n.title).collect()
+        }
+
+        fn notifications(&self) -> Vec<PushNotification> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl NotificationSink for RecordingSink {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+            self.received.lock().unwrap().push(notification.clone());
+            if self.fail {
+                Err(NotificationError::SendFailed("boom".into()))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn renders_actions(&self) -> bool {
+            self.renders_actions
+        }
+    }
 
     #[test]
     fn test_permission_event_pattern() {
@@ -285,4 +426,229 @@ mod tests {
             panic!("Pattern should match");
         }
     }
+
+    #[tokio::test]
+    async fn test_dispatch_without_routes_reaches_every_sink() {
+        let slack = RecordingSink::new("slack", false);
+        let ntfy = RecordingSink::new("ntfy", false);
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(slack.clone())
+            .with_sink(ntfy.clone());
+
+        let delivered = service
+            .dispatch(&PushNotification::session_completed("sess-1"))
+            .await;
+
+        assert_eq!(delivered, 2);
+        assert_eq!(slack.received().len(), 1);
+        assert_eq!(ntfy.received().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_follows_routes() {
+        let slack = RecordingSink::new("slack", false);
+        let email = RecordingSink::new("email", false);
+        let config = NotificationConfig {
+            routes: vec![NotificationRoute {
+                events: vec![NotificationEvent::PermissionNeeded],
+                session: None,
+                sinks: vec!["slack".into()],
+            }],
+            ..NotificationConfig::default()
+        };
+        let service = NotificationService::from_config(config)
+            .unwrap()
+            .with_sink(slack.clone())
+            .with_sink(email.clone());
+
+        let event = VibesEvent::Claude {
+            session_id: "sess-1".to_string(),
+            event: ClaudeEvent::PermissionRequest {
+                id: "req-1".to_string(),
+                tool: "Bash".to_string(),
+                description: "Run command".to_string(),
+            },
+        };
+        service.process_event(&event).await;
+
+        assert_eq!(slack.received(), vec!["Claude needs approval".to_string()]);
+        assert!(email.received().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_continues_after_sink_failure() {
+        let broken = RecordingSink::new("broken", true);
+        let working = RecordingSink::new("working", false);
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(broken.clone())
+            .with_sink(working.clone());
+
+        let delivered = service
+            .dispatch(&PushNotification::session_error("sess-1", "boom"))
+            .await;
+
+        assert_eq!(delivered, 1);
+        assert_eq!(broken.received().len(), 1);
+        assert_eq!(working.received().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_config_sends_nothing() {
+        let sink = RecordingSink::new("slack", false);
+        let service = NotificationService::from_config(NotificationConfig::all_disabled())
+            .unwrap()
+            .with_sink(sink.clone());
+
+        let event = VibesEvent::SessionStateChanged {
+            session_id: "sess-1".to_string(),
+            state: "Completed".to_string(),
+        };
+        service.process_event(&event).await;
+
+        assert!(sink.received().is_empty());
+    }
+
+    fn permission_request(tool: &str) -> VibesEvent {
+        VibesEvent::Claude {
+            session_id: "sess-1".to_string(),
+            event: ClaudeEvent::PermissionRequest {
+                id: "req-1".to_string(),
+                tool: tool.to_string(),
+                description: "Run command".to_string(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_notification_carries_signed_actions() {
+        let sink = RecordingSink::with_actions("web_push");
+        let signer = Arc::new(ActionSigner::new(b"key".to_vec()));
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(sink.clone())
+            .with_action_signer(signer.clone());
+
+        service.process_event(&permission_request("Bash")).await;
+
+        let notification = &sink.notifications()[0];
+        assert_eq!(notification.data.request_id.as_deref(), Some("req-1"));
+        let approve = notification.actions[0].token.as_ref().unwrap();
+        let claims = signer.redeem(approve, Utc::now()).unwrap();
+        assert_eq!(claims.session_id, "sess-1");
+        assert_eq!(claims.request_id, "req-1");
+        assert!(claims.approved);
+    }
+
+    #[tokio::test]
+    async fn test_actions_only_reach_sinks_that_render_them() {
+        let push = RecordingSink::with_actions("web_push");
+        let slack = RecordingSink::new("slack", false);
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(push.clone())
+            .with_sink(slack.clone())
+            .with_action_signer(Arc::new(ActionSigner::new(b"key".to_vec())));
+
+        service.process_event(&permission_request("Bash")).await;
+
+        assert!(!push.notifications()[0].actions.is_empty());
+        assert!(slack.notifications()[0].actions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_body_carries_no_action_tokens() {
+        use crate::notifications::sinks::WebhookSink;
+        use crate::notifications::sinks::test_support::HttpStub;
+
+        let stub = HttpStub::start(200).await;
+        let signer = Arc::new(ActionSigner::new(b"key".to_vec()));
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(Arc::new(WebhookSink::new(
+                "hook".into(),
+                stub.url.clone(),
+                None,
+                reqwest::Client::new(),
+            )))
+            .with_action_signer(signer.clone());
+
+        service.process_event(&permission_request("Bash")).await;
+
+        let body = &stub.requests()[0].body;
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert!(json.get("actions").is_none());
+        assert!(!body.contains("token"));
+    }
+
+    #[tokio::test]
+    async fn test_no_actions_without_signer() {
+        let sink = RecordingSink::new("slack", false);
+        let service = NotificationService::from_config(NotificationConfig::default())
+            .unwrap()
+            .with_sink(sink.clone());
+
+        service.process_event(&permission_request("Bash")).await;
+
+        assert!(sink.notifications()[0].actions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rules_suppress_before_dispatch() {
+        let sink = RecordingSink::new("slack", false);
+        let config = NotificationConfig {
+            rules: vec![crate::notifications::NotificationRule {
+                name: "no-reads".into(),
+                tool: Some("Read".into()),
+                action: crate::notifications::RuleAction::Suppress,
+                ..Default::default()
+            }],
+            ..NotificationConfig::default()
+        };
+        let service = NotificationService::from_config(config)
+            .unwrap()
+            .with_sink(sink.clone());
+
+        let event = VibesEvent::Claude {
+            session_id: "sess-1".to_string(),
+            event: ClaudeEvent::PermissionRequest {
+                id: "req-1".to_string(),
+                tool: "Read".to_string(),
+                description: "Read file".to_string(),
+            },
+        };
+        service.process_event(&event).await;
+
+        assert!(sink.received().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_to_overrides_routes() {
+        let slack = RecordingSink::new("slack", false);
+        let email = RecordingSink::new("email", false);
+        let config = NotificationConfig {
+            routes: vec![NotificationRoute {
+                events: vec![],
+                session: None,
+                sinks: vec!["slack".into()],
+            }],
+            ..NotificationConfig::default()
+        };
+        let service = NotificationService::from_config(config)
+            .unwrap()
+            .with_sink(slack.clone())
+            .with_sink(email.clone());
+
+        let delivered = service
+            .dispatch_to(
+                &PushNotification::permission_escalated("sess-1", "Bash", 10),
+                Some(&["email".to_string()]),
+            )
+            .await;
+
+        assert_eq!(delivered, 1);
+        assert!(slack.received().is_empty());
+        assert_eq!(email.received().len(), 1);
+    }
 }
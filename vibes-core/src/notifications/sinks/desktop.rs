@@ -0,0 +1,91 @@
+//! Desktop notification sink via `notify-send`
+
+use async_trait::async_trait;
+use tokio::process::Command;
+
+use super::NotificationSink;
+use crate::NotificationError;
+use crate::notifications::{NotificationEvent, PushNotification};
+
+/// Sink that shows a desktop notification with `notify-send`
+pub struct DesktopSink {
+    name: String,
+}
+
+impl DesktopSink {
+    /// Create a new desktop sink
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+
+    /// Arguments passed to `notify-send` for a notification
+    pub fn args(notification: &PushNotification) -> Vec<String> {
+        let urgency = match notification.data.event_type {
+            NotificationEvent::PermissionNeeded | NotificationEvent::SessionError => "critical",
+            NotificationEvent::SessionCompleted | NotificationEvent::Digest => "normal",
+        };
+        vec![
+            "--app-name=vibes".to_string(),
+            format!("--urgency={}", urgency),
+            // End of options, so a title starting with `-` is not parsed as one
+            "--".to_string(),
+            notification.title.clone(),
+            notification.body.clone(),
+        ]
+    }
+}
+
+#[async_trait]
+impl NotificationSink for DesktopSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let binary = which::which("notify-send")
+            .map_err(|_| NotificationError::Config("notify-send not found in PATH".into()))?;
+
+        let status = Command::new(binary)
+            .args(Self::args(notification))
+            .status()
+            .await
+            .map_err(|e| NotificationError::SendFailed(format!("notify-send failed: {}", e)))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(NotificationError::SendFailed(format!(
+                "notify-send exited with {}",
+                status
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args_use_critical_urgency_for_permission() {
+        let args = DesktopSink::args(&PushNotification::permission_needed("sess-1", "Bash"));
+        assert!(args.contains(&"--urgency=critical".to_string()));
+        assert_eq!(args[3], "Claude needs approval");
+    }
+
+    #[test]
+    fn test_args_end_options_before_title() {
+        let mut notification = PushNotification::session_completed("sess-1");
+        notification.title = "--help".to_string();
+        let args = DesktopSink::args(&notification);
+        let end = args.iter().position(|a| a == "--").unwrap();
+        assert_eq!(args[end + 1], "--help");
+        assert_eq!(end + 3, args.len());
+    }
+
+    #[test]
+    fn test_args_use_normal_urgency_for_completion() {
+        let args = DesktopSink::args(&PushNotification::session_completed("sess-1"));
+        assert!(args.contains(&"--urgency=normal".to_string()));
+    }
+}
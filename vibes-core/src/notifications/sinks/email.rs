@@ -0,0 +1,149 @@
+//! SMTP email sink
+
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use super::NotificationSink;
+use crate::NotificationError;
+use crate::notifications::PushNotification;
+
+/// Sink that emails notifications through an SMTP relay
+pub struct EmailSink {
+    name: String,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl EmailSink {
+    /// Create a new email sink
+    ///
+    /// Addresses are validated up front so a bad config fails at startup
+    /// rather than on the first notification.
+    pub fn new(
+        name: String,
+        smtp_host: &str,
+        smtp_port: u16,
+        credentials: Option<(String, String)>,
+        from: &str,
+        to: &[String],
+        starttls: bool,
+    ) -> Result<Self, NotificationError> {
+        let from: Mailbox = from
+            .parse()
+            .map_err(|e| NotificationError::Config(format!("invalid from address: {}", e)))?;
+        if to.is_empty() {
+            return Err(NotificationError::Config(format!(
+                "email sink '{}' has no recipients",
+                name
+            )));
+        }
+        let to = to
+            .iter()
+            .map(|addr| {
+                addr.parse::<Mailbox>().map_err(|e| {
+                    NotificationError::Config(format!("invalid recipient '{}': {}", addr, e))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let builder = if starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(smtp_host)
+                .map_err(|e| NotificationError::Config(format!("invalid SMTP host: {}", e)))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(smtp_host)
+        };
+        let mut builder = builder.port(smtp_port);
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            name,
+            from,
+            to,
+            transport: builder.build(),
+        })
+    }
+
+    /// Build the email message for a notification
+    fn message(&self, notification: &PushNotification) -> Result<Message, NotificationError> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(format!("[vibes] {}", notification.title));
+        for recipient in &self.to {
+            builder = builder.to(recipient.clone());
+        }
+
+        let mut body = notification.body.clone();
+        if let Some(session_id) = &notification.data.session_id {
+            body.push_str(&format!("\n\nSession: {}", session_id));
+        }
+
+        builder
+            .body(body)
+            .map_err(|e| NotificationError::SendFailed(format!("failed to build email: {}", e)))
+    }
+}
+
+#[async_trait]
+impl NotificationSink for EmailSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let message = self.message(notification)?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| NotificationError::SendFailed(format!("SMTP error: {}", e)))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sink(to: &[&str]) -> Result<EmailSink, NotificationError> {
+        let to: Vec<String> = to.iter().map(|s| s.to_string()).collect();
+        EmailSink::new(
+            "mail".into(),
+            "localhost",
+            2525,
+            None,
+            "vibes <vibes@example.com>",
+            &to,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_rejects_invalid_recipient() {
+        assert!(matches!(
+            sink(&["not an address"]),
+            Err(NotificationError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_empty_recipients() {
+        assert!(matches!(sink(&[]), Err(NotificationError::Config(_))));
+    }
+
+    #[test]
+    fn test_message_has_prefixed_subject_and_session() {
+        let sink = sink(&["dev@example.com"]).unwrap();
+        let message = sink
+            .message(&PushNotification::session_error("sess-1", "Out of memory"))
+            .unwrap();
+        let raw = String::from_utf8(message.formatted()).unwrap();
+
+        assert!(raw.contains("Subject: [vibes] Session failed"));
+        assert!(raw.contains("Out of memory"));
+        assert!(raw.contains("Session: sess-1"));
+    }
+}
@@ -0,0 +1,343 @@
+//! Notification delivery sinks
+//!
+//! A [`NotificationSink`] delivers a [`PushNotification`] to one channel.
+//! Web Push is one sink among several; the others are configured through
+//! [`SinkConfig`] entries in the notification config.
+
+mod desktop;
+mod email;
+mod ntfy;
+mod slack;
+mod web_push;
+mod webhook;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::PushNotification;
+use crate::NotificationError;
+
+pub use desktop::DesktopSink;
+pub use email::EmailSink;
+pub use ntfy::NtfySink;
+pub use slack::SlackSink;
+pub use web_push::{WEB_PUSH_SINK_NAME, WebPushSink};
+pub use webhook::{SIGNATURE_HEADER, WebhookSink, sign_payload};
+
+/// A channel that can deliver notifications
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    /// Unique name used by routing rules to select this sink
+    fn name(&self) -> &str;
+
+    /// Deliver a notification through this channel
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError>;
+
+    /// Whether this channel shows approve/deny actions to the user
+    ///
+    /// Action tokens can approve tool use, so they are stripped before a
+    /// notification reaches a channel that would only forward them.
+    fn renders_actions(&self) -> bool {
+        false
+    }
+}
+
+/// Configuration for a single notification sink
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfig {
+    /// Generic webhook receiving the notification as JSON
+    Webhook {
+        name: String,
+        url: String,
+        /// Shared secret for the HMAC-SHA256 signature header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        secret: Option<String>,
+    },
+    /// Slack incoming webhook (or any Slack-compatible endpoint)
+    Slack { name: String, webhook_url: String },
+    /// ntfy topic on a public or self-hosted server
+    Ntfy {
+        name: String,
+        #[serde(default = "default_ntfy_server")]
+        server: String,
+        topic: String,
+        /// Access token for protected topics
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        token: Option<String>,
+    },
+    /// Desktop notification via `notify-send`
+    Desktop { name: String },
+    /// Email over SMTP
+    Email {
+        name: String,
+        smtp_host: String,
+        #[serde(default = "default_smtp_port")]
+        smtp_port: u16,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+        from: String,
+        to: Vec<String>,
+        /// Use STARTTLS (default) rather than a plaintext connection
+        #[serde(default = "default_true")]
+        starttls: bool,
+    },
+}
+
+fn default_ntfy_server() -> String {
+    "https://ntfy.sh".to_string()
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl SinkConfig {
+    /// Name of the configured sink
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Webhook { name, .. }
+            | Self::Slack { name, .. }
+            | Self::Ntfy { name, .. }
+            | Self::Desktop { name }
+            | Self::Email { name, .. } => name,
+        }
+    }
+
+    /// Build the sink described by this config
+    pub fn build(
+        &self,
+        http_client: &reqwest::Client,
+    ) -> Result<Arc<dyn NotificationSink>, NotificationError> {
+        let sink: Arc<dyn NotificationSink> = match self {
+            Self::Webhook { name, url, secret } => Arc::new(WebhookSink::new(
+                name.clone(),
+                url.clone(),
+                secret.clone(),
+                http_client.clone(),
+            )),
+            Self::Slack { name, webhook_url } => Arc::new(SlackSink::new(
+                name.clone(),
+                webhook_url.clone(),
+                http_client.clone(),
+            )),
+            Self::Ntfy {
+                name,
+                server,
+                topic,
+                token,
+            } => Arc::new(NtfySink::new(
+                name.clone(),
+                server.clone(),
+                topic.clone(),
+                token.clone(),
+                http_client.clone(),
+            )),
+            Self::Desktop { name } => Arc::new(DesktopSink::new(name.clone())),
+            Self::Email {
+                name,
+                smtp_host,
+                smtp_port,
+                username,
+                password,
+                from,
+                to,
+                starttls,
+            } => {
+                let credentials = match (username, password) {
+                    (Some(user), Some(pass)) => Some((user.clone(), pass.clone())),
+                    (None, None) => None,
+                    _ => {
+                        return Err(NotificationError::Config(format!(
+                            "email sink '{}' needs both username and password",
+                            name
+                        )));
+                    }
+                };
+                Arc::new(EmailSink::new(
+                    name.clone(),
+                    smtp_host,
+                    *smtp_port,
+                    credentials,
+                    from,
+                    to,
+                    *starttls,
+                )?)
+            }
+        };
+        Ok(sink)
+    }
+}
+
+/// Check that an HTTP response from a sink endpoint succeeded
+async fn check_response(sink: &str, response: reqwest::Response) -> Result<(), NotificationError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        let body = response.text().await.unwrap_or_default();
+        Err(NotificationError::SendFailed(format!(
+            "{} sink returned status {}: {}",
+            sink,
+            status.as_u16(),
+            body
+        )))
+    }
+}
+
+/// Minimal HTTP server that records request bodies, for sink tests
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// A request captured by [`HttpStub`]
+    #[derive(Debug, Clone)]
+    pub struct CapturedRequest {
+        pub head: String,
+        pub body: String,
+    }
+
+    impl CapturedRequest {
+        /// Look up a header value (case-insensitive)
+        pub fn header(&self, name: &str) -> Option<String> {
+            self.head.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name)
+                    .then(|| value.trim().to_string())
+            })
+        }
+    }
+
+    /// Local HTTP stub answering every request with a fixed status
+    pub struct HttpStub {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<CapturedRequest>>>,
+    }
+
+    impl HttpStub {
+        pub async fn start(status: u16) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let captured = requests.clone();
+
+            tokio::spawn(async move {
+                while let Ok((mut stream, _)) = listener.accept().await {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 4096];
+                    let (head, body) = loop {
+                        let n = stream.read(&mut chunk).await.unwrap_or(0);
+                        if n == 0 {
+                            break (String::from_utf8_lossy(&buf).to_string(), String::new());
+                        }
+                        buf.extend_from_slice(&chunk[..n]);
+                        let text = String::from_utf8_lossy(&buf).to_string();
+                        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                            let len = head
+                                .lines()
+                                .find_map(|l| {
+                                    let (k, v) = l.split_once(':')?;
+                                    k.eq_ignore_ascii_case("content-length")
+                                        .then(|| v.trim().parse::<usize>().ok())
+                                        .flatten()
+                                })
+                                .unwrap_or(0);
+                            if body.len() >= len {
+                                break (head.to_string(), body.to_string());
+                            }
+                        }
+                    };
+                    captured
+                        .lock()
+                        .unwrap()
+                        .push(CapturedRequest { head, body });
+                    let response = format!(
+                        "HTTP/1.1 {} STUB\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                        status
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<CapturedRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sink_config_deserialize_toml() {
+        let toml = r#"
+            [[sinks]]
+            type = "slack"
+            name = "team"
+            webhook_url = "https://hooks.slack.com/services/T/B/X"
+
+            [[sinks]]
+            type = "ntfy"
+            name = "phone"
+            topic = "vibes-alerts"
+        "#;
+
+        #[derive(Deserialize)]
+        struct Wrapper {
+            sinks: Vec<SinkConfig>,
+        }
+
+        let wrapper: Wrapper = toml::from_str(toml).unwrap();
+        assert_eq!(wrapper.sinks.len(), 2);
+        assert_eq!(wrapper.sinks[0].name(), "team");
+        match &wrapper.sinks[1] {
+            SinkConfig::Ntfy { server, topic, .. } => {
+                assert_eq!(server, "https://ntfy.sh");
+                assert_eq!(topic, "vibes-alerts");
+            }
+            other => panic!("expected ntfy sink, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_email_sink_requires_both_credentials() {
+        let config = SinkConfig::Email {
+            name: "mail".into(),
+            smtp_host: "smtp.example.com".into(),
+            smtp_port: 587,
+            username: Some("me".into()),
+            password: None,
+            from: "vibes@example.com".into(),
+            to: vec!["me@example.com".into()],
+            starttls: true,
+        };
+
+        let result = config.build(&reqwest::Client::new());
+        assert!(matches!(result, Err(NotificationError::Config(_))));
+    }
+
+    #[test]
+    fn test_build_desktop_sink_uses_configured_name() {
+        let config = SinkConfig::Desktop {
+            name: "laptop".into(),
+        };
+        let sink = config.build(&reqwest::Client::new()).unwrap();
+        assert_eq!(sink.name(), "laptop");
+    }
+}
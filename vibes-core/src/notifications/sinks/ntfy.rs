@@ -0,0 +1,123 @@
+//! ntfy-compatible push sink
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use super::{NotificationSink, check_response};
+use crate::NotificationError;
+use crate::notifications::{NotificationEvent, PushNotification};
+
+/// Sink that publishes to an ntfy topic using the JSON publish API
+pub struct NtfySink {
+    name: String,
+    server: String,
+    topic: String,
+    token: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl NtfySink {
+    /// Create a new ntfy sink
+    pub fn new(
+        name: String,
+        server: String,
+        topic: String,
+        token: Option<String>,
+        http_client: reqwest::Client,
+    ) -> Self {
+        Self {
+            name,
+            server: server.trim_end_matches('/').to_string(),
+            topic,
+            token,
+            http_client,
+        }
+    }
+
+    /// Build the ntfy JSON publish payload for a notification
+    pub fn payload(&self, notification: &PushNotification) -> serde_json::Value {
+        // ntfy priorities: 3 = default, 4 = high
+        let (priority, tag) = match notification.data.event_type {
+            NotificationEvent::PermissionNeeded => (4, "raised_hand"),
+            NotificationEvent::SessionCompleted => (3, "white_check_mark"),
+            NotificationEvent::SessionError => (4, "x"),
+            NotificationEvent::Digest => (3, "bell"),
+        };
+        json!({
+            "topic": self.topic,
+            "title": notification.title,
+            "message": notification.body,
+            "priority": priority,
+            "tags": [tag],
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for NtfySink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let mut req = self
+            .http_client
+            .post(&self.server)
+            .json(&self.payload(notification));
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+
+        let response = req
+            .send()
+            .await
+            .map_err(|e| NotificationError::SendFailed(format!("HTTP error: {}", e)))?;
+        check_response(&self.name, response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::sinks::test_support::HttpStub;
+
+    #[test]
+    fn test_payload_maps_permission_to_high_priority() {
+        let sink = NtfySink::new(
+            "phone".into(),
+            "https://ntfy.sh/".into(),
+            "vibes".into(),
+            None,
+            reqwest::Client::new(),
+        );
+        let payload = sink.payload(&PushNotification::permission_needed("sess-1", "Bash"));
+
+        assert_eq!(payload["topic"], "vibes");
+        assert_eq!(payload["priority"], 4);
+        assert_eq!(payload["title"], "Claude needs approval");
+    }
+
+    #[tokio::test]
+    async fn test_ntfy_publishes_with_token() {
+        let stub = HttpStub::start(200).await;
+        let sink = NtfySink::new(
+            "phone".into(),
+            stub.url.clone(),
+            "vibes".into(),
+            Some("tk_abc".into()),
+            reqwest::Client::new(),
+        );
+
+        sink.send(&PushNotification::session_error("sess-1", "boom"))
+            .await
+            .unwrap();
+
+        let request = &stub.requests()[0];
+        assert_eq!(
+            request.header("authorization").as_deref(),
+            Some("Bearer tk_abc")
+        );
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["message"], "boom");
+    }
+}
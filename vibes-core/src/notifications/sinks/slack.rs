@@ -0,0 +1,89 @@
+//! Slack-compatible incoming webhook sink
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use super::{NotificationSink, check_response};
+use crate::NotificationError;
+use crate::notifications::{NotificationEvent, PushNotification};
+
+/// Sink that posts a Slack message through an incoming webhook
+pub struct SlackSink {
+    name: String,
+    webhook_url: String,
+    http_client: reqwest::Client,
+}
+
+impl SlackSink {
+    /// Create a new Slack sink
+    pub fn new(name: String, webhook_url: String, http_client: reqwest::Client) -> Self {
+        Self {
+            name,
+            webhook_url,
+            http_client,
+        }
+    }
+
+    /// Build the Slack message payload for a notification
+    pub fn payload(notification: &PushNotification) -> serde_json::Value {
+        let emoji = match notification.data.event_type {
+            NotificationEvent::PermissionNeeded => ":raised_hand:",
+            NotificationEvent::SessionCompleted => ":white_check_mark:",
+            NotificationEvent::SessionError => ":x:",
+            NotificationEvent::Digest => ":bell:",
+        };
+        let mut text = format!("{} *{}*\n{}", emoji, notification.title, notification.body);
+        if let Some(session_id) = &notification.data.session_id {
+            text.push_str(&format!("\n_session `{}`_", session_id));
+        }
+        json!({ "text": text })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SlackSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let response = self
+            .http_client
+            .post(&self.webhook_url)
+            .json(&Self::payload(notification))
+            .send()
+            .await
+            .map_err(|e| NotificationError::SendFailed(format!("HTTP error: {}", e)))?;
+        check_response(&self.name, response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::sinks::test_support::HttpStub;
+
+    #[test]
+    fn test_payload_includes_title_body_and_session() {
+        let notification = PushNotification::permission_needed("sess-9", "Bash");
+        let payload = SlackSink::payload(&notification);
+        let text = payload["text"].as_str().unwrap();
+
+        assert!(text.contains("*Claude needs approval*"));
+        assert!(text.contains("Bash"));
+        assert!(text.contains("sess-9"));
+    }
+
+    #[tokio::test]
+    async fn test_slack_posts_text_payload() {
+        let stub = HttpStub::start(200).await;
+        let sink = SlackSink::new("team".into(), stub.url.clone(), reqwest::Client::new());
+
+        sink.send(&PushNotification::session_completed("sess-1"))
+            .await
+            .unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&stub.requests()[0].body).unwrap();
+        assert!(body["text"].as_str().unwrap().contains("Session completed"));
+    }
+}
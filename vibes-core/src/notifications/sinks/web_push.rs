@@ -0,0 +1,158 @@
+//! Web Push sink using VAPID
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64ct::{Base64UrlUnpadded, Encoding};
+use http::Uri;
+use p256::EncodedPoint;
+use p256::elliptic_curve::sec1::FromEncodedPoint;
+use tracing::{debug, error, warn};
+use web_push_native::jwt_simple::prelude::ES256KeyPair;
+use web_push_native::{Auth, WebPushBuilder};
+
+use super::NotificationSink;
+use crate::NotificationError;
+use crate::notifications::{
+    PushNotification, PushSubscription, SubscriptionStore, VapidKeyManager,
+};
+
+/// Name routing rules use to select the Web Push sink
+pub const WEB_PUSH_SINK_NAME: &str = "web_push";
+
+/// Sink that delivers notifications to every subscribed browser
+pub struct WebPushSink {
+    vapid: Arc<VapidKeyManager>,
+    subscriptions: Arc<SubscriptionStore>,
+    http_client: reqwest::Client,
+}
+
+impl WebPushSink {
+    /// Create a new Web Push sink
+    pub fn new(
+        vapid: Arc<VapidKeyManager>,
+        subscriptions: Arc<SubscriptionStore>,
+        http_client: reqwest::Client,
+    ) -> Self {
+        Self {
+            vapid,
+            subscriptions,
+            http_client,
+        }
+    }
+
+    /// Send to a single subscription
+    async fn send_one(&self, sub: &PushSubscription, payload: &str) -> Result<(), String> {
+        // Parse endpoint as URI
+        let endpoint: Uri = sub
+            .endpoint
+            .parse()
+            .map_err(|e| format!("invalid endpoint URL: {}", e))?;
+
+        // Decode the subscription keys
+        let p256dh_bytes = Base64UrlUnpadded::decode_vec(&sub.keys.p256dh)
+            .map_err(|e| format!("invalid p256dh key: {}", e))?;
+        let auth_bytes = Base64UrlUnpadded::decode_vec(&sub.keys.auth)
+            .map_err(|e| format!("invalid auth key: {}", e))?;
+
+        // Parse p256dh as a P-256 public key (65 bytes uncompressed point)
+        let encoded_point = EncodedPoint::from_bytes(&p256dh_bytes)
+            .map_err(|e| format!("invalid p256dh point: {}", e))?;
+        let ua_public = p256::PublicKey::from_encoded_point(&encoded_point);
+        let ua_public =
+            Option::from(ua_public).ok_or_else(|| "invalid p256dh public key".to_string())?;
+
+        // Convert auth to Auth type (16 bytes)
+        let auth_array: [u8; 16] = auth_bytes
+            .try_into()
+            .map_err(|_| "auth secret must be 16 bytes")?;
+        let auth: Auth = auth_array.into();
+
+        // Create the web push builder
+        let builder = WebPushBuilder::new(endpoint, ua_public, auth);
+
+        // Get the signing key and create a key pair for VAPID
+        let signing_key = self.vapid.signing_key();
+        let key_pair = ES256KeyPair::from_bytes(&signing_key.to_bytes())
+            .map_err(|e| format!("failed to create VAPID key pair: {}", e))?;
+
+        // Build the request with VAPID
+        let request = builder
+            .with_vapid(&key_pair, "mailto:noreply@vibes.local")
+            .build(payload.as_bytes())
+            .map_err(|e| format!("failed to build push request: {}", e))?;
+
+        // Convert to reqwest request and send
+        let (parts, body) = request.into_parts();
+        let url = parts.uri.to_string();
+
+        let mut req = self.http_client.post(&url);
+        for (name, value) in parts.headers.iter() {
+            if let Ok(v) = value.to_str() {
+                req = req.header(name.as_str(), v);
+            }
+        }
+        req = req.body(body);
+
+        let response = req.send().await.map_err(|e| format!("HTTP error: {}", e))?;
+
+        let status = response.status();
+        if status.is_success() || status.as_u16() == 201 {
+            Ok(())
+        } else {
+            Err(format!("Push failed with status {}", status.as_u16()))
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for WebPushSink {
+    fn name(&self) -> &str {
+        WEB_PUSH_SINK_NAME
+    }
+
+    fn renders_actions(&self) -> bool {
+        true
+    }
+
+    /// Send a notification to all subscribed browsers
+    #[allow(clippy::collapsible_if)] // Prefer readability over collapsed let chains for side effects
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let subscriptions = self.subscriptions.list().await;
+
+        if subscriptions.is_empty() {
+            debug!("No subscriptions, skipping notification");
+            return Ok(());
+        }
+
+        let payload = serde_json::to_string(notification)
+            .map_err(|e| NotificationError::SendFailed(format!("serialization error: {}", e)))?;
+
+        let mut stale_ids = Vec::new();
+
+        for sub in subscriptions {
+            match self.send_one(&sub, &payload).await {
+                Ok(()) => {
+                    debug!("Sent notification to {}", sub.endpoint);
+                }
+                Err(e) if e.contains("410") || e.contains("404") => {
+                    // Subscription is stale/expired
+                    warn!("Subscription {} is stale, marking for removal", sub.id);
+                    stale_ids.push(sub.id.clone());
+                }
+                Err(e) => {
+                    warn!("Failed to send to {}: {}", sub.endpoint, e);
+                }
+            }
+        }
+
+        // Clean up stale subscriptions
+        if !stale_ids.is_empty() {
+            if let Err(e) = self.subscriptions.cleanup_stale(&stale_ids).await {
+                error!("Failed to cleanup stale subscriptions: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+//! Generic webhook sink with HMAC signing
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{NotificationSink, check_response};
+use crate::NotificationError;
+use crate::notifications::PushNotification;
+
+/// Header carrying the payload signature (`sha256=<hex>`)
+pub const SIGNATURE_HEADER: &str = "X-Vibes-Signature";
+
+/// Sign a payload with HMAC-SHA256, returning the header value
+pub fn sign_payload(secret: &str, payload: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Sink that POSTs the notification JSON to an arbitrary URL
+pub struct WebhookSink {
+    name: String,
+    url: String,
+    secret: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl WebhookSink {
+    /// Create a new webhook sink
+    pub fn new(
+        name: String,
+        url: String,
+        secret: Option<String>,
+        http_client: reqwest::Client,
+    ) -> Self {
+        Self {
+            name,
+            url,
+            secret,
+            http_client,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for WebhookSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, notification: &PushNotification) -> Result<(), NotificationError> {
+        let payload = serde_json::to_vec(notification)
+            .map_err(|e| NotificationError::SendFailed(format!("serialization error: {}", e)))?;
+
+        let mut req = self
+            .http_client
+            .post(&self.url)
+            .header(http::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = &self.secret {
+            req = req.header(SIGNATURE_HEADER, sign_payload(secret, &payload));
+        }
+
+        let response = req
+            .body(payload)
+            .send()
+            .await
+            .map_err(|e| NotificationError::SendFailed(format!("HTTP error: {}", e)))?;
+        check_response(&self.name, response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::sinks::test_support::HttpStub;
+
+    #[test]
+    fn test_sign_payload_is_deterministic() {
+        let a = sign_payload("secret", b"{}");
+        let b = sign_payload("secret", b"{}");
+        assert_eq!(a, b);
+        assert!(a.starts_with("sha256="));
+        assert_ne!(a, sign_payload("other", b"{}"));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_posts_signed_json() {
+        let stub = HttpStub::start(200).await;
+        let sink = WebhookSink::new(
+            "hook".into(),
+            format!("{}/notify", stub.url),
+            Some("s3cret".into()),
+            reqwest::Client::new(),
+        );
+
+        let notification = PushNotification::permission_needed("sess-1", "Bash");
+        sink.send(&notification).await.unwrap();
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert!(request.head.starts_with("POST /notify"));
+        assert!(request.body.contains("permission_needed"));
+        assert_eq!(
+            request.header(SIGNATURE_HEADER),
+            Some(sign_payload("s3cret", request.body.as_bytes()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_without_secret_is_unsigned() {
+        let stub = HttpStub::start(200).await;
+        let sink = WebhookSink::new(
+            "hook".into(),
+            stub.url.clone(),
+            None,
+            reqwest::Client::new(),
+        );
+
+        sink.send(&PushNotification::session_completed("sess-1"))
+            .await
+            .unwrap();
+
+        assert!(stub.requests()[0].header(SIGNATURE_HEADER).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_error_status_fails() {
+        let stub = HttpStub::start(500).await;
+        let sink = WebhookSink::new(
+            "hook".into(),
+            stub.url.clone(),
+            None,
+            reqwest::Client::new(),
+        );
+
+        let result = sink
+            .send(&PushNotification::session_error("sess-1", "boom"))
+            .await;
+        assert!(matches!(result, Err(NotificationError::SendFailed(_))));
+    }
+}
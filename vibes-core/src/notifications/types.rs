@@ -3,6 +3,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::NotificationAction;
+
 /// A browser's push subscription
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PushSubscription {
@@ -39,6 +41,9 @@ pub struct PushNotification {
     pub icon: Option<String>,
     /// Tag for notification grouping/replacement
     pub tag: String,
+    /// Action buttons (approve/deny for permission requests)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<NotificationAction>,
     /// Data payload for click handling
     pub data: NotificationData,
 }
@@ -53,10 +58,13 @@ pub struct NotificationData {
     pub session_id: Option<String>,
     /// Type of event that triggered this notification
     pub event_type: NotificationEvent,
+    /// Permission request this notification is about (if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 /// Types of events that trigger notifications
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum NotificationEvent {
     /// Claude needs permission to proceed
@@ -65,6 +73,20 @@ pub enum NotificationEvent {
     SessionCompleted,
     /// Session failed with an error
     SessionError,
+    /// Summary of notifications held during quiet hours
+    Digest,
+}
+
+impl NotificationEvent {
+    /// Get the event type as its serialized string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationEvent::PermissionNeeded => "permission_needed",
+            NotificationEvent::SessionCompleted => "session_completed",
+            NotificationEvent::SessionError => "session_error",
+            NotificationEvent::Digest => "digest",
+        }
+    }
 }
 
 impl PushSubscription {
@@ -92,14 +114,22 @@ impl PushNotification {
             body: format!("Permission requested for {}", tool_name),
             icon: Some("/icon-192.png".into()),
             tag: format!("permission-{}", session_id),
+            actions: Vec::new(),
             data: NotificationData {
                 url: format!("/session/{}?permission=pending", session_id),
                 session_id: Some(session_id.to_string()),
                 event_type: NotificationEvent::PermissionNeeded,
+                request_id: None,
             },
         }
     }
 
+    /// Attach the permission request ID (builder style)
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.data.request_id = Some(request_id.into());
+        self
+    }
+
     /// Create a session completed notification
     pub fn session_completed(session_id: &str) -> Self {
         Self {
@@ -107,10 +137,12 @@ impl PushNotification {
             body: "Claude finished the task".into(),
             icon: Some("/icon-192.png".into()),
             tag: format!("completed-{}", session_id),
+            actions: Vec::new(),
             data: NotificationData {
                 url: format!("/session/{}", session_id),
                 session_id: Some(session_id.to_string()),
                 event_type: NotificationEvent::SessionCompleted,
+                request_id: None,
             },
         }
     }
@@ -122,10 +154,85 @@ impl PushNotification {
             body: error.to_string(),
             icon: Some("/icon-192.png".into()),
             tag: format!("error-{}", session_id),
+            actions: Vec::new(),
             data: NotificationData {
                 url: format!("/session/{}", session_id),
                 session_id: Some(session_id.to_string()),
                 event_type: NotificationEvent::SessionError,
+                request_id: None,
+            },
+        }
+    }
+
+    /// Create an escalation for a permission request left unanswered
+    pub fn permission_escalated(session_id: &str, tool_name: &str, minutes: u64) -> Self {
+        Self {
+            title: "Approval still pending".into(),
+            body: format!(
+                "{} has been waiting {} minutes for approval",
+                tool_name, minutes
+            ),
+            icon: Some("/icon-192.png".into()),
+            tag: format!("escalation-{}", session_id),
+            actions: Vec::new(),
+            data: NotificationData {
+                url: format!("/session/{}?permission=pending", session_id),
+                session_id: Some(session_id.to_string()),
+                event_type: NotificationEvent::PermissionNeeded,
+                request_id: None,
+            },
+        }
+    }
+
+    /// Create one summary for several notifications of the same type
+    pub fn aggregated(event_type: NotificationEvent, notifications: &[PushNotification]) -> Self {
+        let mut sessions: Vec<&str> = notifications
+            .iter()
+            .filter_map(|n| n.data.session_id.as_deref())
+            .collect();
+        sessions.sort_unstable();
+        sessions.dedup();
+
+        let count = sessions.len().max(1);
+        let title = match event_type {
+            NotificationEvent::PermissionNeeded => format!("{} sessions waiting", count),
+            NotificationEvent::SessionCompleted => format!("{} sessions completed", count),
+            NotificationEvent::SessionError => format!("{} sessions failed", count),
+            NotificationEvent::Digest => format!("{} digests", count),
+        };
+
+        Self {
+            title,
+            body: notifications
+                .iter()
+                .map(|n| n.body.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            icon: Some("/icon-192.png".into()),
+            tag: format!("aggregate-{}", event_type.as_str()),
+            actions: Vec::new(),
+            data: NotificationData {
+                url: "/".into(),
+                session_id: None,
+                event_type,
+                request_id: None,
+            },
+        }
+    }
+
+    /// Create a summary of notifications held during quiet hours
+    pub fn quiet_hours_digest(held: usize) -> Self {
+        Self {
+            title: "Quiet hours summary".into(),
+            body: format!("{} notifications were held during quiet hours", held),
+            icon: Some("/icon-192.png".into()),
+            tag: "quiet-hours-digest".into(),
+            actions: Vec::new(),
+            data: NotificationData {
+                url: "/".into(),
+                session_id: None,
+                event_type: NotificationEvent::Digest,
+                request_id: None,
             },
         }
     }
@@ -155,6 +262,19 @@ mod tests {
         assert_eq!(notif.data.event_type, NotificationEvent::PermissionNeeded);
     }
 
+    #[test]
+    fn test_notification_omits_empty_actions_and_request_id() {
+        let json = serde_json::to_value(PushNotification::session_completed("sess-1")).unwrap();
+        assert!(json.get("actions").is_none());
+        assert!(json["data"].get("request_id").is_none());
+
+        let json = serde_json::to_value(
+            PushNotification::permission_needed("sess-1", "Bash").with_request_id("req-1"),
+        )
+        .unwrap();
+        assert_eq!(json["data"]["request_id"], "req-1");
+    }
+
     #[test]
     fn test_completed_notification() {
         let notif = PushNotification::session_completed("sess-123");
@@ -170,6 +290,27 @@ mod tests {
         assert_eq!(notif.data.event_type, NotificationEvent::SessionError);
     }
 
+    #[test]
+    fn test_aggregated_notification_counts_distinct_sessions() {
+        let notifs = vec![
+            PushNotification::permission_needed("sess-1", "Bash"),
+            PushNotification::permission_needed("sess-2", "Edit"),
+            PushNotification::permission_needed("sess-1", "Write"),
+        ];
+        let notif = PushNotification::aggregated(NotificationEvent::PermissionNeeded, &notifs);
+        assert_eq!(notif.title, "2 sessions waiting");
+        assert!(notif.body.contains("Edit"));
+        assert_eq!(notif.tag, "aggregate-permission_needed");
+    }
+
+    #[test]
+    fn test_escalation_notification() {
+        let notif = PushNotification::permission_escalated("sess-1", "Bash", 10);
+        assert_eq!(notif.data.event_type, NotificationEvent::PermissionNeeded);
+        assert!(notif.body.contains("10 minutes"));
+        assert_eq!(notif.tag, "escalation-sess-1");
+    }
+
     #[test]
     fn test_notification_serialization() {
         let notif = PushNotification::permission_needed("sess-123", "Bash");
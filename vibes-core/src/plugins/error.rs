@@ -23,6 +23,14 @@ pub enum PluginHostError {
     #[error("Failed to load plugin library: {0}")]
     LibraryLoad(#[from] libloading::Error),
 
+    /// WASM plugin failed to compile, instantiate or run
+    #[error("WASM plugin error: {0}")]
+    Wasm(String),
+
+    /// Failed to watch plugin directories for changes
+    #[error("Plugin watcher error: {0}")]
+    Watch(#[from] notify::Error),
+
     /// Plugin initialization failed
     #[error("Plugin initialization failed: {0}")]
     InitFailed(#[from] vibes_plugin_api::PluginError),
@@ -35,6 +43,26 @@ pub enum PluginHostError {
     #[error("Plugin '{name}' not found")]
     NotFound { name: String },
 
+    /// Fetching or building a plugin for installation failed
+    #[error("Plugin install failed: {0}")]
+    Install(String),
+
+    /// Installed build does not match the expected checksum
+    #[error("Checksum mismatch: expected {expected}, got {found}")]
+    ChecksumMismatch { expected: String, found: String },
+
+    /// Plugin is already installed
+    #[error("Plugin '{name}' is already installed")]
+    AlreadyInstalled { name: String },
+
+    /// Plugin was not installed with `vibes plugin install`
+    #[error("Plugin '{name}' is not in the plugin lockfile")]
+    NotInstalled { name: String },
+
+    /// Lockfile error (parsing, saving, etc.)
+    #[error("Lockfile error: {0}")]
+    Lockfile(String),
+
     /// Plugin timed out
     #[error("Plugin '{name}' timed out after {timeout:?}")]
     Timeout { name: String, timeout: Duration },
@@ -64,6 +92,16 @@ pub enum PluginHostError {
     },
 }
 
+impl PluginHostError {
+    /// Whether a plugin failed because it used a permission it was not granted
+    pub fn is_permission_denied(&self) -> bool {
+        matches!(
+            self,
+            Self::InitFailed(vibes_plugin_api::PluginError::PermissionDenied(_))
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +163,14 @@ mod tests {
         assert!(msg.contains("groove"));
     }
 
+    #[test]
+    fn test_is_permission_denied() {
+        let err: PluginHostError =
+            vibes_plugin_api::PluginError::PermissionDenied("register routes".into()).into();
+        assert!(err.is_permission_denied());
+        assert!(!PluginHostError::Registry("bad".into()).is_permission_denied());
+    }
+
     #[test]
     fn test_route_conflict_error() {
         let err = PluginHostError::RouteConflict {
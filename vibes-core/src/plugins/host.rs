@@ -1,22 +1,33 @@
 //! PluginHost - manages plugin lifecycle and event dispatch
 
 use libloading::Library;
+use std::any::Any;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
 use vibes_plugin_api::{
-    API_VERSION, AssessmentQuery, AssessmentQueryResponse, CommandArgs, CommandOutput, HttpMethod,
-    Plugin, PluginAssessmentResult, PluginConfig, PluginContext, PluginManifest, RawEvent,
-    RouteRequest, RouteResponse,
+    API_VERSION, AssessmentQuery, AssessmentQueryResponse, CommandArgs, CommandOutput,
+    EventPublisher, HookInput, HttpMethod, Permission, Plugin, PluginAssessmentResult,
+    PluginConfig, PluginContext, PluginManifest, RawEvent, RouteRequest, RouteResponse,
+    StreamHandle, StreamId, StreamMessage,
 };
 
 use super::commands::CommandRegistry;
 use super::error::PluginHostError;
+use super::publisher::EventLogPublisher;
 use super::registry::PluginRegistry;
 use super::routes::RouteRegistry;
+use super::tui::TuiRegistry;
+use super::wasm::{WasmFault, WasmLimits, WasmRuntime};
 use crate::events::{ClaudeEvent, StoredEvent, VibesEvent};
+use crate::hooks::{HookEvent, HookResponse};
 
 /// A loaded plugin with its runtime state
 struct LoadedPlugin {
@@ -26,14 +37,49 @@ struct LoadedPlugin {
     instance: Box<dyn Plugin>,
     /// Plugin context for callbacks
     pub(crate) context: PluginContext,
-    /// Keep the library loaded
-    _library: Library,
+    /// Directory the plugin was loaded from
+    dir: PathBuf,
+    /// What the instance runs on (dropped after the instance)
+    backend: PluginBackend,
     /// Current plugin state
     state: PluginState,
+    /// Child of the daemon shutdown token, set by `on_ready` and cancelled
+    /// when this instance is unloaded
+    shutdown: Option<CancellationToken>,
+}
+
+/// Runtime backing a loaded plugin
+enum PluginBackend {
+    /// Native dynamic library, kept loaded for the lifetime of the instance
+    Native { _library: Library },
+    /// Sandboxed WASM component, with the fault recorded if it traps
+    Wasm(WasmFault),
+}
+
+impl LoadedPlugin {
+    /// Mark a WASM plugin failed once its instance has trapped
+    fn check_fault(&mut self) {
+        if let PluginBackend::Wasm(fault) = &self.backend
+            && let Some(error) = fault.get()
+            && self.state == PluginState::Loaded
+        {
+            tracing::error!(plugin = %self.manifest.name, "WASM plugin trapped, disabling");
+            self.state = PluginState::Failed {
+                error: error.clone(),
+            };
+        }
+    }
 }
 
 impl Drop for LoadedPlugin {
     fn drop(&mut self) {
+        // Stop the plugin's background tasks first: they run code from the
+        // plugin library and must not be polled once it is released
+        if let Some(shutdown) = &self.shutdown {
+            shutdown.cancel();
+            wait_for_plugin_tasks();
+        }
+
         // Call on_unload before the library is dropped
         // This gives plugins a chance to clean up resources (cancel tasks, etc.)
         // that hold references to types defined in the plugin library.
@@ -47,6 +93,27 @@ impl Drop for LoadedPlugin {
     }
 }
 
+/// Time a plugin's tasks get to observe their cancelled shutdown token
+/// before `on_unload` runs
+const UNLOAD_GRACE: Duration = Duration::from_millis(100);
+
+/// Give tasks spawned by an unloading plugin a chance to run to completion
+///
+/// On a multi-threaded runtime the current worker is handed off first, so
+/// tasks queued on it are not starved while we wait. A current-thread
+/// runtime cannot run them while we block, so there is nothing to wait for.
+fn wait_for_plugin_tasks() {
+    use tokio::runtime::{Handle, RuntimeFlavor};
+
+    match Handle::try_current().map(|h| h.runtime_flavor()) {
+        Ok(RuntimeFlavor::MultiThread) => {
+            tokio::task::block_in_place(|| std::thread::sleep(UNLOAD_GRACE));
+        }
+        Ok(_) => {}
+        Err(_) => std::thread::sleep(UNLOAD_GRACE),
+    }
+}
+
 /// State of a loaded plugin
 #[derive(Debug, Clone, PartialEq)]
 pub enum PluginState {
@@ -66,6 +133,8 @@ pub struct PluginHostConfig {
     pub project_plugin_dir: Option<PathBuf>,
     /// Timeout for plugin handlers
     pub handler_timeout: Duration,
+    /// Fuel and memory limits for WASM plugins
+    pub wasm_limits: WasmLimits,
 }
 
 impl Default for PluginHostConfig {
@@ -77,6 +146,7 @@ impl Default for PluginHostConfig {
             user_plugin_dir,
             project_plugin_dir: None,
             handler_timeout: Duration::from_secs(5),
+            wasm_limits: WasmLimits::default(),
         }
     }
 }
@@ -90,6 +160,29 @@ pub struct PluginInfo {
     pub manifest: PluginManifest,
     /// Current state
     pub state: PluginState,
+    /// Permissions granted to the plugin (declared and approved)
+    pub permissions: Vec<Permission>,
+}
+
+/// Runtime handles passed to plugins in `on_ready`, kept for reloads
+#[derive(Clone)]
+struct RuntimeContext {
+    handle: tokio::runtime::Handle,
+    event_log: Arc<dyn Any + Send + Sync>,
+    shutdown: CancellationToken,
+    iggy_manager: Option<Arc<vibes_iggy::IggyManager>>,
+    publisher: Option<Arc<dyn EventPublisher>>,
+}
+
+/// Messages a stream client may fall behind by before sends fail
+const STREAM_BUFFER: usize = 64;
+
+/// A stream opened by a plugin, to be served by the HTTP layer
+pub struct OpenStream {
+    /// Identifier passed back to the plugin for client messages and close
+    pub id: StreamId,
+    /// Messages pushed by the plugin through its `StreamHandle`
+    pub messages: mpsc::Receiver<StreamMessage>,
 }
 
 /// The plugin host manages loading, unloading, and dispatching events to plugins
@@ -107,6 +200,18 @@ pub struct PluginHost {
     command_registry: CommandRegistry,
     /// Registry of plugin HTTP routes
     route_registry: RouteRegistry,
+    /// Registry of plugin TUI views and commands
+    tui_registry: TuiRegistry,
+    /// Limits for WASM plugins
+    wasm_limits: WasmLimits,
+    /// WASM engine, created when the first WASM plugin loads
+    wasm_runtime: Option<WasmRuntime>,
+    /// Set by `notify_ready`, so reloaded plugins can be readied too
+    runtime: Option<RuntimeContext>,
+    /// Identifier for the next stream opened by a plugin
+    next_stream_id: StreamId,
+    /// Enabled plugins that failed to load in `load_all`, with the error
+    load_failures: HashMap<String, PluginHostError>,
 }
 
 impl PluginHost {
@@ -129,6 +234,12 @@ impl PluginHost {
             handler_timeout: config.handler_timeout,
             command_registry: CommandRegistry::new(),
             route_registry: RouteRegistry::new(),
+            tui_registry: TuiRegistry::new(),
+            wasm_limits: config.wasm_limits,
+            wasm_runtime: None,
+            runtime: None,
+            next_stream_id: 1,
+            load_failures: HashMap::new(),
         }
     }
 
@@ -159,10 +270,12 @@ impl PluginHost {
                         version = %plugin.manifest.version,
                         "Plugin loaded"
                     );
+                    self.load_failures.remove(&name);
                     self.plugins.insert(name, plugin);
                 }
                 Err(e) => {
                     tracing::error!(plugin = %name, error = %e, "Failed to load plugin");
+                    self.load_failures.insert(name, e);
                 }
             }
         }
@@ -194,34 +307,73 @@ impl PluginHost {
 
     /// Load a single plugin from its directory
     fn load_plugin(&mut self, dir: &Path, name: &str) -> Result<LoadedPlugin, PluginHostError> {
-        // 1. Find library file
-        let lib_path = self.find_library(dir, name)?;
+        let mut plugin = self.stage_plugin(dir, name, false)?;
+        self.commit_registrations(&mut plugin);
+        Ok(plugin)
+    }
 
-        // 2. Load dynamic library
-        // SAFETY: We're loading a plugin that the user explicitly enabled.
-        // The plugin is expected to follow the Plugin trait contract.
-        let library = unsafe { Library::new(&lib_path)? };
-
-        // 3. Check API version
-        // SAFETY: We're calling a C function exported by the plugin.
-        let api_version_fn: libloading::Symbol<extern "C" fn() -> u32> =
-            unsafe { library.get(b"_vibes_plugin_api_version")? };
-
-        let plugin_api_version = api_version_fn();
-        if plugin_api_version != API_VERSION {
-            return Err(PluginHostError::ApiVersionMismatch {
-                expected: API_VERSION,
-                found: plugin_api_version,
-            });
-        }
+    /// Instantiate a plugin and run `on_load`, validating its registrations
+    /// without committing them
+    ///
+    /// A `<name>.wasm` component is preferred over a native library. When
+    /// `replacing` is set, the plugin's own current registrations are not
+    /// treated as conflicts and native libraries are loaded from a fresh
+    /// copy, so a rebuilt library is not served from the loader's cache.
+    fn stage_plugin(
+        &mut self,
+        dir: &Path,
+        name: &str,
+        replacing: bool,
+    ) -> Result<LoadedPlugin, PluginHostError> {
+        let wasm_path = dir.join(format!("{}.wasm", name));
+        let (instance, backend) = if wasm_path.exists() {
+            self.instantiate_wasm(&wasm_path, name)?
+        } else {
+            self.instantiate_native(dir, name, replacing)?
+        };
+        self.init_plugin(dir, name, backend, instance, replacing)
+    }
 
-        // 4. Create plugin instance
-        // SAFETY: We're calling the plugin's create function which returns a raw pointer
-        // that we convert back to a Box<dyn Plugin>.
-        let create_fn: libloading::Symbol<extern "C" fn() -> *mut dyn Plugin> =
-            unsafe { library.get(b"_vibes_plugin_create")? };
+    /// Instantiate a sandboxed WASM plugin component
+    fn instantiate_wasm(
+        &mut self,
+        path: &Path,
+        name: &str,
+    ) -> Result<(Box<dyn Plugin>, PluginBackend), PluginHostError> {
+        let runtime = match &mut self.wasm_runtime {
+            Some(runtime) => runtime,
+            None => self
+                .wasm_runtime
+                .insert(WasmRuntime::new(self.wasm_limits)?),
+        };
+        let plugin = runtime.load(path, name)?;
+        let fault = plugin.fault();
+        Ok((Box::new(plugin), PluginBackend::Wasm(fault)))
+    }
 
-        let instance = unsafe { Box::from_raw(create_fn()) };
+    /// Instantiate a native plugin from its dynamic library
+    fn instantiate_native(
+        &self,
+        dir: &Path,
+        name: &str,
+        fresh_copy: bool,
+    ) -> Result<(Box<dyn Plugin>, PluginBackend), PluginHostError> {
+        let lib_path = self.find_library(dir, name)?;
+        open_native(&lib_path, name, fresh_copy)
+    }
+
+    /// Run `on_load` and check the plugin's command and route registrations
+    ///
+    /// `backend` is declared before `instance` so that on an early return the
+    /// instance is dropped first, while its library is still loaded.
+    fn init_plugin(
+        &mut self,
+        dir: &Path,
+        name: &str,
+        backend: PluginBackend,
+        instance: Box<dyn Plugin>,
+        replacing: bool,
+    ) -> Result<LoadedPlugin, PluginHostError> {
         let manifest = instance.manifest();
 
         // 5. Create context and load config
@@ -229,6 +381,25 @@ impl PluginHost {
         let config = PluginConfig::load(&config_path).unwrap_or_default();
         let mut context = PluginContext::with_config(name.to_string(), dir.to_path_buf(), config);
 
+        // Plugins enabled before approvals existed keep what they declare now
+        let mut registry = PluginRegistry::load(&self.registry_path)?;
+        if registry.needs_approval_migration(name) {
+            tracing::warn!(
+                plugin = %name,
+                "Plugin was enabled before permission approval; approving its declared \
+                 permissions. Run `vibes plugin enable {name}` to review them"
+            );
+            registry.migrate_approval(name, manifest.permissions.clone());
+            registry.save(&self.registry_path)?;
+        }
+
+        // Grant only what the manifest declares and the user approved
+        context.set_permissions(granted_permissions(
+            name,
+            &manifest.permissions,
+            registry.approved(name),
+        ));
+
         // 6. Call on_load
         // Note: instance is Box<dyn Plugin> which is not mutable, but the trait
         // requires &mut self. We need to use interior mutability or change the approach.
@@ -236,11 +407,15 @@ impl PluginHost {
         let mut instance = instance;
         instance.on_load(&mut context)?;
 
+        // A plugin being replaced may re-register its own commands and routes
+        let conflicts = |existing: &&str| !(replacing && *existing == manifest.name);
+
         // 7. Validate command registrations
         for spec in context.pending_commands() {
             if let Some(existing) = self
                 .command_registry
                 .check_conflict(&manifest.name, &spec.path)
+                .filter(conflicts)
             {
                 return Err(PluginHostError::CommandConflict {
                     command: spec.path.join(" "),
@@ -252,7 +427,11 @@ impl PluginHost {
 
         // 8. Validate route registrations
         for spec in context.pending_routes() {
-            if let Some(existing) = self.route_registry.check_conflict(&manifest.name, spec) {
+            if let Some(existing) = self
+                .route_registry
+                .check_conflict(&manifest.name, spec)
+                .filter(conflicts)
+            {
                 return Err(PluginHostError::RouteConflict {
                     route: format!("{:?} {}", spec.method, spec.path),
                     existing_plugin: existing.to_string(),
@@ -260,23 +439,47 @@ impl PluginHost {
                 });
             }
         }
-
-        // 9. Commit registrations
-        let commands = context.take_pending_commands();
-        let routes = context.take_pending_routes();
-
-        self.command_registry.register(&manifest.name, commands);
-        self.route_registry.register(&manifest.name, routes);
+        for spec in context.pending_streams() {
+            if let Some(existing) = self
+                .route_registry
+                .check_stream_conflict(&manifest.name, spec)
+                .filter(conflicts)
+            {
+                return Err(PluginHostError::RouteConflict {
+                    route: format!("{:?} {}", spec.kind, spec.path),
+                    existing_plugin: existing.to_string(),
+                    new_plugin: manifest.name.clone(),
+                });
+            }
+        }
 
         Ok(LoadedPlugin {
             manifest,
             instance,
             context,
-            _library: library,
+            dir: dir.to_path_buf(),
+            backend,
             state: PluginState::Loaded,
+            shutdown: None,
         })
     }
 
+    /// Commit a staged plugin's pending command, route, stream and TUI registrations
+    fn commit_registrations(&mut self, plugin: &mut LoadedPlugin) {
+        let commands = plugin.context.take_pending_commands();
+        let routes = plugin.context.take_pending_routes();
+        let streams = plugin.context.take_pending_streams();
+        let (tui_views, tui_commands) = plugin.context.take_pending_tui();
+
+        self.command_registry
+            .register(&plugin.manifest.name, commands);
+        self.route_registry.register(&plugin.manifest.name, routes);
+        self.route_registry
+            .register_streams(&plugin.manifest.name, streams);
+        self.tui_registry
+            .register(&plugin.manifest.name, tui_views, tui_commands);
+    }
+
     /// Unload a plugin and clean up its registrations
     pub fn unload_plugin(&mut self, name: &str) -> Result<(), PluginHostError> {
         if self.plugins.remove(name).is_none() {
@@ -288,64 +491,157 @@ impl PluginHost {
         // Clean up registrations
         self.command_registry.unregister(name);
         self.route_registry.unregister(name);
+        self.tui_registry.unregister(name);
 
         Ok(())
     }
 
+    /// Reload a plugin from disk without restarting the host
+    ///
+    /// The new build is staged alongside the running one: if it fails to
+    /// load, fails `on_load`, or conflicts with another plugin, the running
+    /// version is left untouched and the error is returned. Otherwise the old
+    /// instance is unloaded (calling `on_unload` and releasing its library),
+    /// the new registrations are committed, and, once the runtime is ready,
+    /// `on_ready` is re-run for the new instance.
+    ///
+    /// A plugin that is not currently loaded is loaded if it is enabled; a
+    /// disabled plugin is left alone.
+    pub fn reload_plugin(&mut self, name: &str) -> Result<(), PluginHostError> {
+        let dir = match self.plugins.get(name) {
+            Some(plugin) => plugin.dir.clone(),
+            None => self.find_plugin_dir(name)?,
+        };
+
+        if !PluginRegistry::load(&self.registry_path)?.is_enabled(name) {
+            tracing::debug!(plugin = %name, "Plugin disabled, not reloading");
+            return Ok(());
+        }
+
+        let replacing = self.plugins.contains_key(name);
+        let mut plugin = self
+            .stage_plugin(&dir, name, replacing)
+            .inspect_err(|e| {
+                tracing::error!(plugin = %name, error = %e, "Plugin reload failed, keeping current version");
+            })?;
+
+        // The old instance runs on_unload and releases its library when dropped
+        if let Some(old) = self.plugins.remove(name) {
+            tracing::debug!(plugin = %name, version = %old.manifest.version, "Unloading previous version");
+            self.command_registry.unregister(name);
+            self.route_registry.unregister(name);
+            self.tui_registry.unregister(name);
+            drop(old);
+        }
+        self.commit_registrations(&mut plugin);
+
+        if let Some(runtime) = &self.runtime {
+            ready_plugin(name, &mut plugin, runtime);
+        }
+
+        tracing::info!(
+            plugin = %name,
+            version = %plugin.manifest.version,
+            "Plugin reloaded"
+        );
+        self.plugins.insert(name.to_string(), plugin);
+        Ok(())
+    }
+
+    /// Find an installed plugin's directory by name
+    fn find_plugin_dir(&self, name: &str) -> Result<PathBuf, PluginHostError> {
+        self.discover_plugins()?
+            .into_iter()
+            .find(|dir| dir.file_name().and_then(|n| n.to_str()) == Some(name))
+            .ok_or_else(|| PluginHostError::NotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Read an installed plugin's manifest without loading it
+    ///
+    /// The plugin is instantiated to ask for its manifest but `on_load` is
+    /// not called. Used to show requested permissions before enabling.
+    pub fn inspect_plugin(&mut self, name: &str) -> Result<PluginManifest, PluginHostError> {
+        let dir = self.find_plugin_dir(name)?;
+        let wasm_path = dir.join(format!("{}.wasm", name));
+        if wasm_path.exists() {
+            self.inspect_artifact(&wasm_path)
+        } else {
+            let lib_path = self.find_library(&dir, name)?;
+            self.inspect_artifact(&lib_path)
+        }
+    }
+
+    /// Read the manifest of a plugin build (`.wasm` component or native library)
+    ///
+    /// Fails if the build does not match this host's API version. Native
+    /// libraries are loaded from a private copy, so inspecting a rebuilt
+    /// library at the same path sees the new build.
+    pub fn inspect_artifact(&mut self, path: &Path) -> Result<PluginManifest, PluginHostError> {
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("plugin")
+            .to_string();
+        let (instance, backend) = if path.extension().is_some_and(|ext| ext == "wasm") {
+            self.instantiate_wasm(path, &name)?
+        } else {
+            open_native(path, &name, true)?
+        };
+        let manifest = instance.manifest();
+
+        // The instance must go before the library backing it
+        drop(instance);
+        drop(backend);
+        Ok(manifest)
+    }
+
+    /// Directories searched for plugins, project directory first
+    pub fn plugin_dirs(&self) -> &[PathBuf] {
+        &self.plugin_dirs
+    }
+
     /// Notify all loaded plugins that the runtime is ready.
     ///
     /// This sets the runtime handle, event log, shutdown token, and Iggy manager
     /// on each plugin's context, then calls `on_ready()` to allow plugins to start
-    /// background tasks.
+    /// background tasks. Each plugin gets a child of `shutdown` that is also
+    /// cancelled when the plugin is unloaded. If the event log is an `Arc<dyn EventLog<StoredEvent>>`,
+    /// plugins can also publish events to it with `PluginContext::publish`.
     ///
     /// Should be called once after the server is fully initialized.
     pub fn notify_ready(
         &mut self,
-        event_log: std::sync::Arc<dyn std::any::Any + Send + Sync>,
-        shutdown: tokio_util::sync::CancellationToken,
-        iggy_manager: Option<std::sync::Arc<vibes_iggy::IggyManager>>,
+        event_log: Arc<dyn Any + Send + Sync>,
+        shutdown: CancellationToken,
+        iggy_manager: Option<Arc<vibes_iggy::IggyManager>>,
     ) {
         // Get the tokio runtime handle from the current context.
         // This handle will be passed to plugins so they can run async operations.
-        let runtime_handle = tokio::runtime::Handle::current();
+        let handle = tokio::runtime::Handle::current();
+        let publisher = event_log
+            .downcast_ref::<Arc<dyn vibes_iggy::EventLog<StoredEvent>>>()
+            .map(|log| {
+                Arc::new(EventLogPublisher::new(Arc::clone(log), handle.clone()))
+                    as Arc<dyn EventPublisher>
+            });
+        let runtime = RuntimeContext {
+            handle,
+            event_log,
+            shutdown,
+            iggy_manager,
+            publisher,
+        };
 
         for (name, plugin) in &mut self.plugins {
             if plugin.state != PluginState::Loaded {
                 continue;
             }
-
-            // Set runtime on context - runtime_handle MUST be set first so plugins
-            // can use it in on_ready() for async operations
-            plugin.context.set_runtime_handle(runtime_handle.clone());
-            plugin.context.set_event_log(event_log.clone());
-            plugin.context.set_shutdown(shutdown.clone());
-            if let Some(ref manager) = iggy_manager {
-                plugin.context.set_iggy_manager(manager.clone());
-            }
-
-            // Call on_ready with panic isolation
-            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                plugin.instance.on_ready(&mut plugin.context)
-            }));
-
-            match result {
-                Ok(Ok(())) => {
-                    tracing::debug!(plugin = %name, "Plugin ready");
-                }
-                Ok(Err(e)) => {
-                    tracing::error!(plugin = %name, error = %e, "Plugin on_ready error");
-                    plugin.state = PluginState::Failed {
-                        error: e.to_string(),
-                    };
-                }
-                Err(_) => {
-                    tracing::error!(plugin = %name, "Plugin panicked in on_ready, disabling");
-                    plugin.state = PluginState::Failed {
-                        error: "Plugin panicked in on_ready".to_string(),
-                    };
-                }
-            }
+            ready_plugin(name, plugin, &runtime);
         }
+
+        self.runtime = Some(runtime);
     }
 
     /// Get a service registered by a plugin.
@@ -408,10 +704,13 @@ impl PluginHost {
     /// Dispatch an event to all loaded plugins
     ///
     /// Events are dispatched with panic isolation - if a plugin panics,
-    /// it is disabled and other plugins continue to receive events.
+    /// it is disabled and other plugins continue to receive events. Plugins
+    /// without `Permission::ReadEvents` are skipped.
     pub fn dispatch_event(&mut self, event: &VibesEvent) {
         for (name, plugin) in &mut self.plugins {
-            if plugin.state != PluginState::Loaded {
+            if plugin.state != PluginState::Loaded
+                || !plugin.context.has_permission(&Permission::ReadEvents)
+            {
                 continue;
             }
 
@@ -431,7 +730,61 @@ impl PluginHost {
                     };
                 }
             }
+            plugin.check_fault();
+        }
+    }
+
+    /// Ask every loaded plugin to answer a Claude Code hook
+    ///
+    /// Only plugins granted `Permission::AnswerHooks` are asked. They are
+    /// called in name order and their decisions merged with
+    /// [`HookResponse::merge`], so the response does not depend on load order.
+    /// A plugin that panics is disabled and contributes nothing.
+    pub fn dispatch_hook(&mut self, session_id: Option<&str>, event: &HookEvent) -> HookResponse {
+        let input = HookInput {
+            session_id: session_id
+                .or_else(|| event.session_id())
+                .map(str::to_string),
+            hook_type: event.hook_type().as_str().to_string(),
+            project_path: event.project_path(),
+            payload: serde_json::to_value(event).unwrap_or_default(),
+        };
+
+        let mut names: Vec<_> = self.plugins.keys().cloned().collect();
+        names.sort();
+
+        let mut response = HookResponse::empty();
+        for name in names {
+            let Some(plugin) = self.plugins.get_mut(&name) else {
+                continue;
+            };
+            if plugin.state != PluginState::Loaded
+                || !plugin.context.has_permission(&Permission::AnswerHooks)
+            {
+                continue;
+            }
+
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                plugin.instance.on_hook(&input, &mut plugin.context)
+            }));
+
+            match result {
+                Ok(Some(decision)) => {
+                    tracing::debug!(plugin = %name, hook = %input.hook_type, ?decision, "Plugin answered hook");
+                    response.merge(decision.into());
+                }
+                Ok(None) => {}
+                Err(_) => {
+                    tracing::error!(plugin = %name, "Plugin panicked in on_hook, disabling");
+                    plugin.state = PluginState::Failed {
+                        error: "Plugin panicked in on_hook".to_string(),
+                    };
+                }
+            }
+            plugin.check_fault();
         }
+
+        response
     }
 
     /// List all plugins
@@ -442,10 +795,16 @@ impl PluginHost {
                 name: name.clone(),
                 manifest: p.manifest.clone(),
                 state: p.state.clone(),
+                permissions: granted(&p.context),
             })
             .collect()
     }
 
+    /// Enabled plugins that failed to load, with the error
+    pub fn load_failures(&self) -> &HashMap<String, PluginHostError> {
+        &self.load_failures
+    }
+
     /// Enable a plugin in the registry
     pub fn enable_plugin(&mut self, name: &str) -> Result<(), PluginHostError> {
         let mut registry = PluginRegistry::load(&self.registry_path)?;
@@ -454,7 +813,21 @@ impl PluginHost {
         Ok(())
     }
 
-    /// Disable a plugin
+    /// Record the permissions the user approved for a plugin
+    ///
+    /// Takes effect the next time the plugin is loaded or reloaded.
+    pub fn approve_permissions(
+        &mut self,
+        name: &str,
+        permissions: Vec<Permission>,
+    ) -> Result<(), PluginHostError> {
+        let mut registry = PluginRegistry::load(&self.registry_path)?;
+        registry.approve(name, permissions);
+        registry.save(&self.registry_path)?;
+        Ok(())
+    }
+
+    /// Disable a plugin, forgetting its approved permissions
     pub fn disable_plugin(&mut self, name: &str) -> Result<(), PluginHostError> {
         let mut registry = PluginRegistry::load(&self.registry_path)?;
         registry.disable(name);
@@ -476,6 +849,7 @@ impl PluginHost {
             name: name.to_string(),
             manifest: p.manifest.clone(),
             state: p.state.clone(),
+            permissions: granted(&p.context),
         })
     }
 
@@ -494,6 +868,11 @@ impl PluginHost {
         &self.route_registry
     }
 
+    /// Get read access to the TUI registry
+    pub fn tui_registry(&self) -> &TuiRegistry {
+        &self.tui_registry
+    }
+
     /// Dispatch a CLI command to the appropriate plugin
     pub fn dispatch_command(
         &mut self,
@@ -514,10 +893,12 @@ impl PluginHost {
             });
         }
 
-        plugin
+        let result = plugin
             .instance
             .handle_command(path, args, &mut plugin.context)
-            .map_err(PluginHostError::InitFailed)
+            .map_err(PluginHostError::InitFailed);
+        plugin.check_fault();
+        result
     }
 
     /// Dispatch an HTTP route to the appropriate plugin
@@ -541,16 +922,89 @@ impl PluginHost {
             });
         }
 
-        plugin
+        let result = plugin
             .instance
             .handle_route(method, path, request, &mut plugin.context)
-            .map_err(PluginHostError::InitFailed)
+            .map_err(PluginHostError::InitFailed);
+        plugin.check_fault();
+        result
+    }
+
+    /// Open a plugin stream for a newly connected client
+    ///
+    /// The plugin receives a `StreamHandle` feeding the returned receiver.
+    /// The caller serves the messages and must call [`close_stream`] once
+    /// the client disconnects.
+    ///
+    /// [`close_stream`]: Self::close_stream
+    pub fn open_stream(
+        &mut self,
+        plugin_name: &str,
+        path: &str,
+        request: RouteRequest,
+    ) -> Result<OpenStream, PluginHostError> {
+        let id = self.next_stream_id;
+        let plugin = self.loaded_plugin(plugin_name)?;
+
+        let (sender, messages) = mpsc::channel(STREAM_BUFFER);
+        let result = plugin
+            .instance
+            .open_stream(
+                path,
+                request,
+                StreamHandle::new(id, sender),
+                &mut plugin.context,
+            )
+            .map_err(PluginHostError::InitFailed);
+        plugin.check_fault();
+        result?;
+
+        self.next_stream_id += 1;
+        Ok(OpenStream { id, messages })
+    }
+
+    /// Forward a text message from a WebSocket stream client to its plugin
+    pub fn dispatch_stream_message(
+        &mut self,
+        plugin_name: &str,
+        stream: StreamId,
+        message: String,
+    ) -> Result<(), PluginHostError> {
+        let plugin = self.loaded_plugin(plugin_name)?;
+        plugin
+            .instance
+            .on_stream_message(stream, message, &mut plugin.context);
+        plugin.check_fault();
+        Ok(())
+    }
+
+    /// Tell a plugin that a stream's client has disconnected
+    ///
+    /// Plugins that have since been unloaded are skipped.
+    pub fn close_stream(&mut self, plugin_name: &str, stream: StreamId) {
+        if let Ok(plugin) = self.loaded_plugin(plugin_name) {
+            plugin
+                .instance
+                .on_stream_closed(stream, &mut plugin.context);
+            plugin.check_fault();
+        }
+    }
+
+    /// Look up a plugin that is loaded and not disabled or failed
+    fn loaded_plugin(&mut self, plugin_name: &str) -> Result<&mut LoadedPlugin, PluginHostError> {
+        self.plugins
+            .get_mut(plugin_name)
+            .filter(|plugin| plugin.state == PluginState::Loaded)
+            .ok_or_else(|| PluginHostError::NotFound {
+                name: plugin_name.to_string(),
+            })
     }
 
     /// Dispatch a raw event to all loaded plugins and collect assessment results.
     ///
-    /// This converts the StoredEvent to an FFI-safe RawEvent and calls each
-    /// plugin's `on_event()` handler. Results are aggregated from all plugins.
+    /// This converts the StoredEvent to an FFI-safe RawEvent and calls the
+    /// `on_event()` handler of each plugin subscribed to it (plugins never
+    /// receive events they published). Results are aggregated from all plugins.
     ///
     /// Events are dispatched with panic isolation - if a plugin panics,
     /// it is disabled and other plugins continue to receive events.
@@ -564,7 +1018,7 @@ impl PluginHost {
         let mut all_results = Vec::new();
 
         for (name, plugin) in &mut self.plugins {
-            if plugin.state != PluginState::Loaded {
+            if plugin.state != PluginState::Loaded || !plugin.context.wants_event(&raw) {
                 continue;
             }
 
@@ -583,6 +1037,7 @@ impl PluginHost {
                     };
                 }
             }
+            plugin.check_fault();
         }
 
         all_results
@@ -642,6 +1097,7 @@ impl PluginHost {
                     };
                 }
             }
+            plugin.check_fault();
         }
 
         // Apply limit to aggregated results
@@ -665,18 +1121,23 @@ impl PluginHost {
         let payload = serde_json::to_string(&stored.event).unwrap_or_default();
 
         // Derive event_type from the VibesEvent variant
-        let event_type = match &stored.event {
-            VibesEvent::Claude { .. } => "Claude",
-            VibesEvent::UserInput { .. } => "UserInput",
-            VibesEvent::PermissionResponse { .. } => "PermissionResponse",
-            VibesEvent::SessionCreated { .. } => "SessionCreated",
-            VibesEvent::SessionStateChanged { .. } => "SessionStateChanged",
-            VibesEvent::ClientConnected { .. } => "ClientConnected",
-            VibesEvent::ClientDisconnected { .. } => "ClientDisconnected",
-            VibesEvent::TunnelStateChanged { .. } => "TunnelStateChanged",
-            VibesEvent::OwnershipTransferred { .. } => "OwnershipTransferred",
-            VibesEvent::SessionRemoved { .. } => "SessionRemoved",
-            VibesEvent::Hook { .. } => "Hook",
+        let event_type: Cow<'_, str> = match &stored.event {
+            VibesEvent::Claude { .. } => "Claude".into(),
+            VibesEvent::UserInput { .. } => "UserInput".into(),
+            VibesEvent::PermissionResponse { .. } => "PermissionResponse".into(),
+            VibesEvent::SessionCreated { .. } => "SessionCreated".into(),
+            VibesEvent::SessionStateChanged { .. } => "SessionStateChanged".into(),
+            VibesEvent::ClientConnected { .. } => "ClientConnected".into(),
+            VibesEvent::ClientDisconnected { .. } => "ClientDisconnected".into(),
+            VibesEvent::TunnelStateChanged { .. } => "TunnelStateChanged".into(),
+            VibesEvent::OwnershipTransferred { .. } => "OwnershipTransferred".into(),
+            VibesEvent::SessionRemoved { .. } => "SessionRemoved".into(),
+            VibesEvent::Hook { .. } => "Hook".into(),
+            VibesEvent::CapabilityDrift { .. } => "CapabilityDrift".into(),
+            // Plugin events are namespaced by the plugin that published them
+            VibesEvent::Plugin {
+                plugin, event_type, ..
+            } => format!("{}.{}", plugin, event_type).into(),
         };
 
         // Extract timestamp from UUIDv7 (milliseconds since Unix epoch)
@@ -690,12 +1151,151 @@ impl PluginHost {
             stored.event_id.into_bytes(),
             timestamp_ms,
             stored.session_id().map(|s| s.to_string()),
-            event_type.to_string(),
+            event_type.into_owned(),
             payload,
         )
     }
 }
 
+/// Set the runtime on a plugin's context and call its `on_ready`
+fn ready_plugin(name: &str, plugin: &mut LoadedPlugin, runtime: &RuntimeContext) {
+    // Set runtime on context - runtime_handle MUST be set first so plugins
+    // can use it in on_ready() for async operations
+    plugin.context.set_runtime_handle(runtime.handle.clone());
+    plugin.context.set_event_log(runtime.event_log.clone());
+    // Each plugin gets its own child token, so unloading or reloading it
+    // stops its tasks without touching the rest of the daemon
+    let shutdown = runtime.shutdown.child_token();
+    plugin.context.set_shutdown(shutdown.clone());
+    plugin.shutdown = Some(shutdown);
+    if let Some(ref manager) = runtime.iggy_manager {
+        plugin.context.set_iggy_manager(manager.clone());
+    }
+    if let Some(ref publisher) = runtime.publisher {
+        plugin.context.set_event_publisher(publisher.clone());
+    }
+
+    // Call on_ready with panic isolation
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        plugin.instance.on_ready(&mut plugin.context)
+    }));
+
+    match result {
+        Ok(Ok(())) => {
+            tracing::debug!(plugin = %name, "Plugin ready");
+        }
+        Ok(Err(e)) => {
+            tracing::error!(plugin = %name, error = %e, "Plugin on_ready error");
+            plugin.state = PluginState::Failed {
+                error: e.to_string(),
+            };
+        }
+        Err(_) => {
+            tracing::error!(plugin = %name, "Plugin panicked in on_ready, disabling");
+            plugin.state = PluginState::Failed {
+                error: "Plugin panicked in on_ready".to_string(),
+            };
+        }
+    }
+    plugin.check_fault();
+}
+
+/// Load a native plugin library, check its API version and create the instance
+fn open_native(
+    lib_path: &Path,
+    name: &str,
+    fresh_copy: bool,
+) -> Result<(Box<dyn Plugin>, PluginBackend), PluginHostError> {
+    // 1. Load dynamic library
+    // SAFETY: We're loading a plugin that the user explicitly enabled.
+    // The plugin is expected to follow the Plugin trait contract.
+    let library = if fresh_copy {
+        load_library_copy(lib_path, name)?
+    } else {
+        unsafe { Library::new(lib_path)? }
+    };
+
+    // 2. Check API version
+    // SAFETY: We're calling a C function exported by the plugin.
+    let api_version_fn: libloading::Symbol<extern "C" fn() -> u32> =
+        unsafe { library.get(b"_vibes_plugin_api_version")? };
+
+    let plugin_api_version = api_version_fn();
+    if plugin_api_version != API_VERSION {
+        return Err(PluginHostError::ApiVersionMismatch {
+            expected: API_VERSION,
+            found: plugin_api_version,
+        });
+    }
+
+    // 3. Create plugin instance
+    // SAFETY: We're calling the plugin's create function which returns a raw pointer
+    // that we convert back to a Box<dyn Plugin>.
+    let create_fn: libloading::Symbol<extern "C" fn() -> *mut dyn Plugin> =
+        unsafe { library.get(b"_vibes_plugin_create")? };
+
+    let instance = unsafe { Box::from_raw(create_fn()) };
+    Ok((instance, PluginBackend::Native { _library: library }))
+}
+
+/// Load a private copy of a plugin library
+///
+/// The dynamic loader caches libraries by path, so reopening a rebuilt
+/// library at the same path can return the old image. Loading from a
+/// uniquely named copy guarantees the new build is used.
+fn load_library_copy(lib_path: &Path, name: &str) -> Result<Library, PluginHostError> {
+    let extension = lib_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("so");
+    let copy_path = std::env::temp_dir().join(format!(
+        "vibes-plugin-{}-{}.{}",
+        name,
+        uuid::Uuid::new_v4(),
+        extension
+    ));
+    std::fs::copy(lib_path, &copy_path)?;
+
+    // SAFETY: Same contract as loading the plugin library in place.
+    let library = unsafe { Library::new(&copy_path) };
+
+    // The image stays mapped once loaded; where loaded libraries are locked
+    // the copy is left for the temp directory cleanup.
+    let _ = std::fs::remove_file(&copy_path);
+    Ok(library?)
+}
+
+/// Permissions a plugin declared that the user also approved
+///
+/// Declared permissions missing from the approval (e.g. added by an update)
+/// are withheld until the user re-approves with `vibes plugin enable`.
+fn granted_permissions(
+    name: &str,
+    declared: &[Permission],
+    approved: &[Permission],
+) -> Vec<Permission> {
+    declared
+        .iter()
+        .filter(|permission| {
+            let granted = vibes_plugin_api::permission::is_granted(approved, permission);
+            if !granted {
+                tracing::warn!(
+                    plugin = %name,
+                    permission = %permission,
+                    "Permission not approved; run `vibes plugin enable {name}` to review"
+                );
+            }
+            granted
+        })
+        .cloned()
+        .collect()
+}
+
+/// Permissions granted to a loaded plugin's context
+fn granted(context: &PluginContext) -> Vec<Permission> {
+    context.permissions().map(<[_]>::to_vec).unwrap_or_default()
+}
+
 /// Dispatch a VibesEvent to the appropriate plugin handler
 fn dispatch_to_plugin(
     plugin: &mut Box<dyn Plugin>,
@@ -723,20 +1323,15 @@ fn dispatch_to_plugin(
         | VibesEvent::ClientDisconnected { .. }
         | VibesEvent::TunnelStateChanged { .. }
         | VibesEvent::OwnershipTransferred { .. }
-        | VibesEvent::SessionRemoved { .. } => {
+        | VibesEvent::SessionRemoved { .. }
+        | VibesEvent::CapabilityDrift { .. } => {
             // These events are not dispatched to plugins (they're client -> server or system events)
         }
-        VibesEvent::Hook { session_id, event } => {
-            // Dispatch hook events to plugins for processing
-            let hook_type = event.hook_type().as_str();
-            let project_path = event.project_path();
-            let _response = plugin.on_hook(
-                session_id.as_deref(),
-                hook_type,
-                project_path.as_deref(),
-                ctx,
-            );
-            // Response handling will be implemented when hook responses are wired to the receiver
+        VibesEvent::Hook { .. } => {
+            // Hooks are answered synchronously via PluginHost::dispatch_hook
+        }
+        VibesEvent::Plugin { .. } => {
+            // Plugin events reach subscribers through on_event (dispatch_raw_event)
         }
     }
     Ok(())
@@ -807,6 +1402,7 @@ fn parse_session_state(state: &str) -> vibes_plugin_api::SessionState {
 mod tests {
     use super::*;
     use tempfile::TempDir;
+    use vibes_plugin_api::HookDecision;
 
     #[test]
     fn test_plugin_host_config_default() {
@@ -838,6 +1434,7 @@ mod tests {
             user_plugin_dir: dir.path().to_path_buf(),
             project_plugin_dir: None,
             handler_timeout: Duration::from_secs(5),
+            wasm_limits: WasmLimits::default(),
         };
         let mut host = PluginHost::new(config);
         host.load_all().unwrap();
@@ -851,6 +1448,7 @@ mod tests {
             user_plugin_dir: dir.path().to_path_buf(),
             project_plugin_dir: None,
             handler_timeout: Duration::from_secs(5),
+            wasm_limits: WasmLimits::default(),
         };
         let mut host = PluginHost::new(config);
 
@@ -869,6 +1467,522 @@ mod tests {
         assert!(!registry.is_enabled("test-plugin"));
     }
 
+    #[test]
+    fn test_load_all_skips_invalid_wasm_plugin() {
+        let dir = TempDir::new().unwrap();
+        let plugin_dir = dir.path().join("broken");
+        std::fs::create_dir(&plugin_dir).unwrap();
+        std::fs::write(plugin_dir.join("broken.wasm"), b"not wasm").unwrap();
+
+        let config = PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            project_plugin_dir: None,
+            handler_timeout: Duration::from_secs(5),
+            wasm_limits: WasmLimits::default(),
+        };
+        let mut host = PluginHost::new(config);
+        host.enable_plugin("broken").unwrap();
+
+        host.load_all().unwrap();
+        assert_eq!(host.plugin_count(), 0);
+        assert!(matches!(
+            host.load_plugin(&plugin_dir, "broken"),
+            Err(PluginHostError::Wasm(_))
+        ));
+    }
+
+    /// Plugin that registers one command, for exercising reloads
+    struct StubPlugin {
+        version: &'static str,
+    }
+
+    impl Plugin for StubPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest {
+                name: "stub".to_string(),
+                version: self.version.to_string(),
+                ..Default::default()
+            }
+        }
+
+        fn on_load(
+            &mut self,
+            ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            ctx.register_command(vibes_plugin_api::CommandSpec {
+                path: vec!["status".to_string()],
+                description: "Show status".to_string(),
+                args: vec![],
+            })
+        }
+
+        fn on_unload(&mut self) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+    }
+
+    fn stub_backend() -> PluginBackend {
+        PluginBackend::Wasm(WasmFault::default())
+    }
+
+    /// Host with a loaded "stub" plugin whose directory holds a broken build
+    fn host_with_stub(dir: &TempDir) -> (PluginHost, PathBuf) {
+        let plugin_dir = dir.path().join("stub");
+        std::fs::create_dir(&plugin_dir).unwrap();
+        std::fs::write(plugin_dir.join("stub.wasm"), b"not wasm").unwrap();
+
+        let config = PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut host = PluginHost::new(config);
+        host.enable_plugin("stub").unwrap();
+
+        let instance = Box::new(StubPlugin { version: "1.0.0" });
+        let mut plugin = host
+            .init_plugin(&plugin_dir, "stub", stub_backend(), instance, false)
+            .unwrap();
+        host.commit_registrations(&mut plugin);
+        host.plugins.insert("stub".to_string(), plugin);
+        (host, plugin_dir)
+    }
+
+    /// Plugin that runs a background task until its shutdown token fires
+    struct TaskPlugin {
+        stopped: Arc<std::sync::atomic::AtomicBool>,
+        stopped_before_unload: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Plugin for TaskPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest {
+                name: "tasks".to_string(),
+                version: "1.0.0".to_string(),
+                ..Default::default()
+            }
+        }
+
+        fn on_load(
+            &mut self,
+            _ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+
+        fn on_ready(
+            &mut self,
+            ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            let shutdown = ctx.shutdown().unwrap();
+            let stopped = Arc::clone(&self.stopped);
+            ctx.runtime_handle().unwrap().spawn(async move {
+                shutdown.cancelled().await;
+                stopped.store(true, std::sync::atomic::Ordering::SeqCst);
+            });
+            Ok(())
+        }
+
+        fn on_unload(&mut self) -> Result<(), vibes_plugin_api::PluginError> {
+            let stopped = self.stopped.load(std::sync::atomic::Ordering::SeqCst);
+            self.stopped_before_unload
+                .store(stopped, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unload_stops_plugin_tasks_before_on_unload() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let stopped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stopped_before_unload = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let instance = Box::new(TaskPlugin {
+            stopped: Arc::clone(&stopped),
+            stopped_before_unload: Arc::clone(&stopped_before_unload),
+        });
+        let plugin = host
+            .init_plugin(dir.path(), "tasks", stub_backend(), instance, false)
+            .unwrap();
+        host.plugins.insert("tasks".to_string(), plugin);
+
+        let shutdown = CancellationToken::new();
+        host.notify_ready(Arc::new(()), shutdown.clone(), None);
+        assert!(!stopped.load(std::sync::atomic::Ordering::SeqCst));
+
+        host.unload_plugin("tasks").unwrap();
+        assert!(stopped_before_unload.load(std::sync::atomic::Ordering::SeqCst));
+        // Only the plugin's own token is cancelled
+        assert!(!shutdown.is_cancelled());
+    }
+
+    /// Plugin that gives a fixed answer to every hook
+    struct GuardPlugin {
+        name: &'static str,
+        decision: HookDecision,
+    }
+
+    impl Plugin for GuardPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest {
+                name: self.name.to_string(),
+                version: "1.0.0".to_string(),
+                permissions: vec![Permission::AnswerHooks],
+                ..Default::default()
+            }
+        }
+
+        fn on_load(
+            &mut self,
+            _ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+
+        fn on_unload(&mut self) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+
+        fn on_hook(&mut self, hook: &HookInput, _ctx: &mut PluginContext) -> Option<HookDecision> {
+            (hook.tool_name() == Some("Bash")).then(|| self.decision.clone())
+        }
+    }
+
+    #[test]
+    fn test_dispatch_hook_merges_plugin_decisions() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        for (name, decision) in [
+            ("allow-all", HookDecision::allow()),
+            ("no-rm", HookDecision::deny("rm is blocked")),
+        ] {
+            host.approve_permissions(name, vec![Permission::AnswerHooks])
+                .unwrap();
+            let instance = Box::new(GuardPlugin { name, decision });
+            let plugin = host
+                .init_plugin(dir.path(), name, stub_backend(), instance, false)
+                .unwrap();
+            host.plugins.insert(name.to_string(), plugin);
+        }
+
+        let event: HookEvent = serde_json::from_value(serde_json::json!({
+            "type": "pre_tool_use",
+            "session_id": "sess-1",
+            "tool_name": "Bash",
+            "tool_input": {"command": "rm -rf /"}
+        }))
+        .unwrap();
+        let response = host.dispatch_hook(None, &event);
+
+        assert_eq!(
+            response.permission_decision,
+            Some(vibes_plugin_api::PermissionDecision::Deny)
+        );
+        assert_eq!(
+            response.permission_decision_reason.as_deref(),
+            Some("rm is blocked")
+        );
+    }
+
+    #[test]
+    fn test_unapproved_permissions_are_withheld() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let instance = Box::new(GuardPlugin {
+            name: "guard",
+            decision: HookDecision::deny("blocked"),
+        });
+        let plugin = host
+            .init_plugin(dir.path(), "guard", stub_backend(), instance, false)
+            .unwrap();
+        host.plugins.insert("guard".to_string(), plugin);
+
+        let event: HookEvent = serde_json::from_value(serde_json::json!({
+            "type": "pre_tool_use",
+            "session_id": "sess-1",
+            "tool_name": "Bash",
+            "tool_input": {"command": "ls"}
+        }))
+        .unwrap();
+        assert_eq!(host.dispatch_hook(None, &event).permission_decision, None);
+        assert!(
+            host.get_plugin_info("guard")
+                .unwrap()
+                .permissions
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_route_registration_requires_permission() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+
+        let result = host.init_plugin(
+            dir.path(),
+            "echo",
+            stub_backend(),
+            Box::new(EchoPlugin::default()),
+            false,
+        );
+        assert!(matches!(
+            result,
+            Err(PluginHostError::InitFailed(
+                vibes_plugin_api::PluginError::PermissionDenied(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_pre_approval_registry_approves_declared_permissions() {
+        let dir = TempDir::new().unwrap();
+        let registry_path = dir.path().join("registry.toml");
+        std::fs::write(&registry_path, "enabled = [\"echo\"]\n").unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+
+        let plugin = host
+            .init_plugin(
+                dir.path(),
+                "echo",
+                stub_backend(),
+                Box::new(EchoPlugin::default()),
+                false,
+            )
+            .unwrap();
+        assert_eq!(granted(&plugin.context), vec![Permission::RegisterRoutes]);
+
+        let registry = PluginRegistry::load(&registry_path).unwrap();
+        assert_eq!(registry.approved("echo"), &[Permission::RegisterRoutes]);
+        assert!(!registry.needs_approval_migration("echo"));
+    }
+
+    /// Plugin with a WebSocket stream that echoes client messages
+    #[derive(Default)]
+    struct EchoPlugin {
+        streams: Vec<StreamHandle>,
+        closed: Vec<StreamId>,
+    }
+
+    impl Plugin for EchoPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest {
+                name: "echo".to_string(),
+                version: "1.0.0".to_string(),
+                permissions: vec![Permission::RegisterRoutes],
+                ..Default::default()
+            }
+        }
+
+        fn on_load(
+            &mut self,
+            ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            ctx.register_stream(vibes_plugin_api::StreamSpec::websocket("/echo"))?;
+            ctx.register_tui_view(vibes_plugin_api::TuiViewSpec {
+                id: "echoes".to_string(),
+                title: "Echoes".to_string(),
+                widgets: vec![],
+            })?;
+            ctx.register_tui_command(vibes_plugin_api::TuiCommandSpec::open_view(
+                "echoes",
+                "Show echoes",
+                "echoes",
+            ))
+        }
+
+        fn on_unload(&mut self) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+
+        fn open_stream(
+            &mut self,
+            path: &str,
+            _request: RouteRequest,
+            stream: StreamHandle,
+            _ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            stream.send(StreamMessage::text(format!("opened {}", path)))?;
+            self.streams.push(stream);
+            Ok(())
+        }
+
+        fn on_stream_message(
+            &mut self,
+            stream: StreamId,
+            message: String,
+            _ctx: &mut PluginContext,
+        ) {
+            if let Some(handle) = self.streams.iter().find(|h| h.id() == stream) {
+                let _ = handle.send(StreamMessage::text(message));
+            }
+        }
+
+        fn on_stream_closed(&mut self, stream: StreamId, _ctx: &mut PluginContext) {
+            self.streams.retain(|h| h.id() != stream);
+            self.closed.push(stream);
+        }
+    }
+
+    fn empty_request() -> RouteRequest {
+        RouteRequest {
+            params: HashMap::new(),
+            query: HashMap::new(),
+            body: vec![],
+            headers: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_open_stream_forwards_plugin_messages() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        host.approve_permissions("echo", vec![Permission::RegisterRoutes])
+            .unwrap();
+        let mut plugin = host
+            .init_plugin(
+                dir.path(),
+                "echo",
+                stub_backend(),
+                Box::new(EchoPlugin::default()),
+                false,
+            )
+            .unwrap();
+        host.commit_registrations(&mut plugin);
+        host.plugins.insert("echo".to_string(), plugin);
+
+        let (stream, _) = host
+            .route_registry()
+            .match_stream("/api/echo/echo")
+            .unwrap();
+        assert_eq!(stream.spec.path, "/echo");
+
+        let mut first = host.open_stream("echo", "/echo", empty_request()).unwrap();
+        let second = host.open_stream("echo", "/echo", empty_request()).unwrap();
+        assert_ne!(first.id, second.id);
+
+        host.dispatch_stream_message("echo", first.id, "ping".to_string())
+            .unwrap();
+        assert_eq!(first.messages.try_recv().unwrap().data, "opened /echo");
+        assert_eq!(first.messages.try_recv().unwrap().data, "ping");
+
+        host.close_stream("echo", first.id);
+        host.close_stream("missing", second.id);
+        assert!(matches!(
+            host.open_stream("missing", "/echo", empty_request()),
+            Err(PluginHostError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tui_registrations_follow_plugin_lifecycle() {
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        host.approve_permissions("echo", vec![Permission::RegisterRoutes])
+            .unwrap();
+        let mut plugin = host
+            .init_plugin(
+                dir.path(),
+                "echo",
+                stub_backend(),
+                Box::new(EchoPlugin::default()),
+                false,
+            )
+            .unwrap();
+        host.commit_registrations(&mut plugin);
+        host.plugins.insert("echo".to_string(), plugin);
+
+        let tui = host.tui_registry().get("echo").unwrap();
+        assert_eq!(tui.view("echoes").unwrap().title, "Echoes");
+        assert_eq!(tui.commands[0].name, "echoes");
+
+        host.unload_plugin("echo").unwrap();
+        assert!(host.tui_registry().list().is_empty());
+    }
+
+    #[test]
+    fn test_reload_plugin_not_found() {
+        let dir = TempDir::new().unwrap();
+        let config = PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut host = PluginHost::new(config);
+
+        assert!(matches!(
+            host.reload_plugin("missing"),
+            Err(PluginHostError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reload_plugin_failure_keeps_current_version() {
+        let dir = TempDir::new().unwrap();
+        let (mut host, _) = host_with_stub(&dir);
+
+        assert!(matches!(
+            host.reload_plugin("stub"),
+            Err(PluginHostError::Wasm(_))
+        ));
+
+        // The running version and its registrations are untouched
+        let info = host.get_plugin_info("stub").unwrap();
+        assert_eq!(info.manifest.version, "1.0.0");
+        assert_eq!(info.state, PluginState::Loaded);
+        assert!(
+            host.command_registry()
+                .find(&["stub".to_string(), "status".to_string()])
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_reload_plugin_skips_disabled() {
+        let dir = TempDir::new().unwrap();
+        let (mut host, _) = host_with_stub(&dir);
+        host.disable_plugin("stub").unwrap();
+
+        host.reload_plugin("stub").unwrap();
+        assert!(host.get_plugin_info("stub").is_some());
+    }
+
+    #[test]
+    fn test_replacement_may_reuse_own_registrations() {
+        let dir = TempDir::new().unwrap();
+        let (mut host, plugin_dir) = host_with_stub(&dir);
+
+        let instance = Box::new(StubPlugin { version: "1.0.1" });
+        assert!(matches!(
+            host.init_plugin(&plugin_dir, "stub", stub_backend(), instance, false),
+            Err(PluginHostError::CommandConflict { .. })
+        ));
+
+        let instance = Box::new(StubPlugin { version: "1.0.1" });
+        let staged = host
+            .init_plugin(&plugin_dir, "stub", stub_backend(), instance, true)
+            .unwrap();
+        assert_eq!(staged.manifest.version, "1.0.1");
+    }
+
     #[test]
     fn test_find_library_not_found() {
         let dir = TempDir::new().unwrap();
@@ -985,4 +2099,96 @@ mod tests {
         let results = host.dispatch_raw_event(&event);
         assert!(results.is_empty());
     }
+
+    /// Plugin that records the type of every event it receives
+    struct RecorderPlugin {
+        name: &'static str,
+        subscription: Option<vibes_plugin_api::EventSubscription>,
+        seen: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl Plugin for RecorderPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest {
+                name: self.name.to_string(),
+                version: "1.0.0".to_string(),
+                permissions: vec![Permission::ReadEvents],
+                ..Default::default()
+            }
+        }
+
+        fn on_load(
+            &mut self,
+            ctx: &mut PluginContext,
+        ) -> Result<(), vibes_plugin_api::PluginError> {
+            if let Some(subscription) = self.subscription.take() {
+                ctx.subscribe(subscription);
+            }
+            Ok(())
+        }
+
+        fn on_unload(&mut self) -> Result<(), vibes_plugin_api::PluginError> {
+            Ok(())
+        }
+
+        fn on_event(
+            &mut self,
+            event: RawEvent,
+            _ctx: &mut PluginContext,
+        ) -> Vec<PluginAssessmentResult> {
+            self.seen.lock().unwrap().push(event.event_type);
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_dispatch_raw_event_respects_subscriptions() {
+        use crate::events::{StoredEvent, VibesEvent};
+        use vibes_plugin_api::EventSubscription;
+
+        let dir = TempDir::new().unwrap();
+        let mut host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let watcher_seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let groove_seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        for (name, subscription, seen) in [
+            (
+                "watcher",
+                Some(EventSubscription::event_type("groove.*")),
+                &watcher_seen,
+            ),
+            ("groove", None, &groove_seen),
+        ] {
+            host.approve_permissions(name, vec![Permission::ReadEvents])
+                .unwrap();
+            let instance = Box::new(RecorderPlugin {
+                name,
+                subscription,
+                seen: Arc::clone(seen),
+            });
+            let plugin = host
+                .init_plugin(dir.path(), name, stub_backend(), instance, false)
+                .unwrap();
+            host.plugins.insert(name.to_string(), plugin);
+        }
+
+        host.dispatch_raw_event(&StoredEvent::new(VibesEvent::Plugin {
+            plugin: "groove".to_string(),
+            event_type: "learning_extracted".to_string(),
+            session_id: None,
+            payload: serde_json::json!({}),
+        }));
+        host.dispatch_raw_event(&StoredEvent::new(VibesEvent::ClientConnected {
+            client_id: "c1".to_string(),
+        }));
+
+        assert_eq!(
+            *watcher_seen.lock().unwrap(),
+            vec!["groove.learning_extracted"]
+        );
+        // groove gets everything except the events it published itself
+        assert_eq!(*groove_seen.lock().unwrap(), vec!["ClientConnected"]);
+    }
 }
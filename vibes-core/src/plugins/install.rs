@@ -0,0 +1,1033 @@
+//! Plugin installation from git repositories, tarballs and local paths
+//!
+//! [`PluginInstaller`] fetches a plugin, builds it with cargo unless the
+//! package ships a prebuilt `.wasm` component or library, checks it against
+//! the host API version by reading its manifest, and places it in the user
+//! plugin directory where [`PluginHost`] discovers it:
+//!
+//! ```text
+//! ~/.config/vibes/plugins/
+//! ├── plugins.lock           # Installed plugins, versions and checksums
+//! └── hello/
+//!     ├── hello.0.1.0.so     # Installed build
+//!     └── hello.so           # Symlink to the installed build
+//! ```
+//!
+//! Installing does not enable a plugin; `vibes plugin enable` does that once
+//! the user has approved its permissions.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use tokio::process::Command;
+use vibes_plugin_api::PluginManifest;
+
+use super::error::PluginHostError;
+use super::host::{PluginHost, PluginHostConfig};
+use super::lockfile::{LockedPlugin, PluginLockfile};
+use super::registry::PluginRegistry;
+
+/// File extensions of plugin builds the host can load
+const ARTIFACT_EXTENSIONS: &[&str] = &["wasm", "so", "dylib", "dll"];
+
+/// Archive extensions accepted as tarball sources
+const TARBALL_EXTENSIONS: &[&str] = &[".tar.gz", ".tgz", ".tar"];
+
+/// Where a plugin is installed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginSource {
+    /// Git repository, optionally at a branch, tag or commit
+    Git { url: String, rev: Option<String> },
+    /// `.tar.gz`, `.tgz` or `.tar` archive, by URL or local path
+    Tarball { location: String },
+    /// Local package directory or plugin build
+    Path(PathBuf),
+}
+
+impl PluginSource {
+    /// Parse a source given to `vibes plugin install`
+    ///
+    /// - A URL or path ending in `.tar.gz`, `.tgz` or `.tar` is a tarball
+    /// - `git+<url-or-path>`, any other URL, and `git@host:repo` are git
+    ///   repositories; append `#<rev>` to pin a branch, tag or commit
+    /// - Anything else must be an existing local path
+    pub fn parse(source: &str) -> Result<Self, PluginHostError> {
+        let lowercase = source.to_ascii_lowercase();
+        if TARBALL_EXTENSIONS
+            .iter()
+            .any(|ext| lowercase.ends_with(ext))
+        {
+            let location = if is_url(source) {
+                source.to_string()
+            } else {
+                canonicalize(source)?.display().to_string()
+            };
+            return Ok(Self::Tarball { location });
+        }
+
+        let git = source.strip_prefix("git+");
+        if git.is_some() || is_url(source) || source.starts_with("git@") {
+            let spec = git.unwrap_or(source);
+            let (url, rev) = match spec.rsplit_once('#') {
+                Some((url, rev)) => (url, Some(rev.to_string())),
+                None => (spec, None),
+            };
+            check_git_arg("URL", url)?;
+            if let Some(rev) = &rev {
+                check_git_arg("revision", rev)?;
+            }
+            return Ok(Self::Git {
+                url: url.to_string(),
+                rev,
+            });
+        }
+
+        Ok(Self::Path(canonicalize(source)?))
+    }
+}
+
+impl fmt::Display for PluginSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Git { url, rev: None } => write!(f, "git+{}", url),
+            Self::Git {
+                url,
+                rev: Some(rev),
+            } => write!(f, "git+{}#{}", url, rev),
+            Self::Tarball { location } => write!(f, "{}", location),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn is_url(source: &str) -> bool {
+    source.contains("://")
+}
+
+/// Refuse git URLs and revisions that git would read as options
+fn check_git_arg(what: &str, value: &str) -> Result<(), PluginHostError> {
+    if value.starts_with('-') {
+        return Err(PluginHostError::Install(format!(
+            "Invalid git {}: {}",
+            what, value
+        )));
+    }
+    Ok(())
+}
+
+fn canonicalize(path: &str) -> Result<PathBuf, PluginHostError> {
+    std::fs::canonicalize(path)
+        .map_err(|e| PluginHostError::Install(format!("Cannot read {}: {}", path, e)))
+}
+
+/// Result of `PluginInstaller::update`
+#[derive(Debug, Clone)]
+pub enum UpdateOutcome {
+    /// The source is unchanged: same commit or tarball and same version
+    UpToDate(LockedPlugin),
+    /// A new build was installed
+    Updated {
+        previous: LockedPlugin,
+        current: LockedPlugin,
+    },
+}
+
+/// A fetched and verified plugin build, ready to place
+struct PluginBuild {
+    manifest: PluginManifest,
+    artifact: PathBuf,
+    sha256: String,
+    rev: Option<String>,
+    /// SHA-256 of the tarball, for tarball sources
+    source_sha256: Option<String>,
+    /// Checksum the build was verified against
+    pinned_sha256: Option<String>,
+}
+
+/// Installs, updates and uninstalls plugins in the user plugin directory
+pub struct PluginInstaller {
+    plugins_dir: PathBuf,
+    /// Host used to read and version-check plugin builds
+    host: PluginHost,
+}
+
+impl PluginInstaller {
+    /// Create an installer for the user plugin directory in `config`
+    pub fn new(config: PluginHostConfig) -> Self {
+        Self {
+            plugins_dir: config.user_plugin_dir.clone(),
+            host: PluginHost::new(config),
+        }
+    }
+
+    fn lockfile_path(&self) -> PathBuf {
+        self.plugins_dir.join("plugins.lock")
+    }
+
+    /// Plugins installed with `vibes plugin install`
+    pub fn installed(&self) -> Result<Vec<LockedPlugin>, PluginHostError> {
+        Ok(PluginLockfile::load(&self.lockfile_path())?.plugins)
+    }
+
+    /// Fetch, build, verify and install a plugin
+    ///
+    /// When `expected_sha256` is given, the tarball (for tarball sources) or
+    /// the prebuilt plugin build (for git and path sources) must match it
+    /// before any plugin code runs. Cargo builds are not reproducible, so a
+    /// git or path source that has to be built cannot be pinned this way;
+    /// pin its commit with `#<rev>` instead. The pin is kept in the lockfile
+    /// and checked again by [`update`](Self::update).
+    pub async fn install(
+        &mut self,
+        source: &PluginSource,
+        expected_sha256: Option<&str>,
+    ) -> Result<LockedPlugin, PluginHostError> {
+        let staging = StagingDir::new()?;
+        let build = self.build(source, &staging, expected_sha256).await?;
+
+        let name = &build.manifest.name;
+        let lockfile = PluginLockfile::load(&self.lockfile_path())?;
+        if lockfile.get(name).is_some() || self.plugins_dir.join(name).exists() {
+            return Err(PluginHostError::AlreadyInstalled { name: name.clone() });
+        }
+
+        self.place(build, source)
+    }
+
+    /// Rebuild an installed plugin from its source, installing it if it changed
+    ///
+    /// A plugin is up to date when its source still resolves to the same git
+    /// commit or tarball and the manifest version is unchanged; build
+    /// checksums are only compared for prebuilt path sources.
+    pub async fn update(&mut self, name: &str) -> Result<UpdateOutcome, PluginHostError> {
+        let previous = PluginLockfile::load(&self.lockfile_path())?
+            .get(name)
+            .cloned()
+            .ok_or_else(|| PluginHostError::NotInstalled {
+                name: name.to_string(),
+            })?;
+        let source = PluginSource::parse(&previous.source)?;
+
+        let staging = StagingDir::new()?;
+        let build = self
+            .build(&source, &staging, previous.pinned_sha256.as_deref())
+            .await?;
+        if build.manifest.name != name {
+            return Err(PluginHostError::Install(format!(
+                "{} now provides plugin '{}', not '{}'",
+                previous.source, build.manifest.name, name
+            )));
+        }
+        if is_same_build(&previous, &build) {
+            return Ok(UpdateOutcome::UpToDate(previous));
+        }
+
+        let current = self.place(build, &source)?;
+        Ok(UpdateOutcome::Updated { previous, current })
+    }
+
+    /// Remove an installed plugin, its directory and its registry entry
+    pub fn uninstall(&self, name: &str) -> Result<LockedPlugin, PluginHostError> {
+        let lockfile_path = self.lockfile_path();
+        let mut lockfile = PluginLockfile::load(&lockfile_path)?;
+        let removed = lockfile
+            .remove(name)
+            .ok_or_else(|| PluginHostError::NotInstalled {
+                name: name.to_string(),
+            })?;
+
+        let dir = self.plugins_dir.join(name);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+
+        let registry_path = self.plugins_dir.join("registry.toml");
+        let mut registry = PluginRegistry::load(&registry_path)?;
+        registry.disable(name);
+        registry.save(&registry_path)?;
+
+        lockfile.save(&lockfile_path)?;
+        Ok(removed)
+    }
+
+    /// Fetch a source into `staging`, locate or build the plugin and verify it
+    async fn build(
+        &mut self,
+        source: &PluginSource,
+        staging: &StagingDir,
+        expected_sha256: Option<&str>,
+    ) -> Result<PluginBuild, PluginHostError> {
+        let checkout = staging.path().join("src");
+        let (package, rev, source_sha256) = match source {
+            PluginSource::Git { url, rev } => {
+                let commit = clone_git(url, rev.as_deref(), &checkout).await?;
+                (checkout, Some(commit), None)
+            }
+            PluginSource::Tarball { location } => {
+                let archive = fetch_tarball(location, staging.path()).await?;
+                let sha256 = sha256_file(&archive)?;
+                // Check the pin before extracting or building anything
+                if let Some(expected) = expected_sha256 {
+                    verify_checksum(expected, &sha256)?;
+                }
+                let package = extract_tarball(&archive, &checkout).await?;
+                (package, None, Some(sha256))
+            }
+            PluginSource::Path(path) => (path.clone(), None, None),
+        };
+
+        // Without a tarball the pin covers the build, which must then be
+        // prebuilt: cargo would run the package's build scripts unverified
+        if expected_sha256.is_some() && source_sha256.is_none() && !ships_build(&package)? {
+            return Err(PluginHostError::Install(format!(
+                "{} has to be built from source, so --sha256 cannot pin it; \
+                 pin a git commit with #<rev> instead",
+                source
+            )));
+        }
+
+        let artifact = locate_artifact(&package, &staging.path().join("target")).await?;
+        let sha256 = sha256_file(&artifact)?;
+
+        // Check the pin before running any plugin code
+        if let Some(expected) = expected_sha256
+            && source_sha256.is_none()
+        {
+            verify_checksum(expected, &sha256)?;
+        }
+
+        let manifest = self.host.inspect_artifact(&artifact)?;
+        validate_name(&manifest.name)?;
+
+        Ok(PluginBuild {
+            manifest,
+            artifact,
+            sha256,
+            rev,
+            source_sha256,
+            pinned_sha256: expected_sha256.map(|sum| sum.trim().to_ascii_lowercase()),
+        })
+    }
+
+    /// Copy a verified build into the plugin directory and lock it
+    fn place(
+        &self,
+        build: PluginBuild,
+        source: &PluginSource,
+    ) -> Result<LockedPlugin, PluginHostError> {
+        let PluginBuild {
+            manifest,
+            artifact,
+            sha256,
+            rev,
+            source_sha256,
+            pinned_sha256,
+        } = build;
+        let dir = self.plugins_dir.join(&manifest.name);
+        std::fs::create_dir_all(&dir)?;
+
+        let extension = artifact
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let versioned = format!("{}.{}.{}", manifest.name, manifest.version, extension);
+        let current = format!("{}.{}", manifest.name, extension);
+
+        // Copy under a temporary name so the watcher never sees a partial build
+        let partial = dir.join(format!(".{}.partial", versioned));
+        std::fs::copy(&artifact, &partial)?;
+        std::fs::rename(&partial, dir.join(&versioned))?;
+        link_current(&dir, &current, &versioned)?;
+        remove_stale_builds(&dir, &manifest.name, &[&versioned, &current])?;
+
+        let locked = LockedPlugin {
+            name: manifest.name,
+            version: manifest.version,
+            source: source.to_string(),
+            rev,
+            source_sha256,
+            sha256,
+            pinned_sha256,
+            installed_at: Utc::now(),
+        };
+        let lockfile_path = self.lockfile_path();
+        let mut lockfile = PluginLockfile::load(&lockfile_path)?;
+        lockfile.upsert(locked.clone());
+        lockfile.save(&lockfile_path)?;
+
+        tracing::info!(plugin = %locked.name, version = %locked.version, "Plugin installed");
+        Ok(locked)
+    }
+}
+
+// ─── Fetching ────────────────────────────────────────────────────────
+
+/// Temporary directory for fetching and building, removed when dropped
+struct StagingDir(PathBuf);
+
+impl StagingDir {
+    fn new() -> Result<Self, PluginHostError> {
+        let path =
+            std::env::temp_dir().join(format!("vibes-plugin-install-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Run a fetch or build step, returning its trimmed stdout
+async fn run(command: &mut Command, step: &str) -> Result<String, PluginHostError> {
+    let output = command
+        .output()
+        .await
+        .map_err(|e| PluginHostError::Install(format!("Failed to run {}: {}", step, e)))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(PluginHostError::Install(format!(
+            "{} failed: {}",
+            step,
+            stderr.trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Clone a repository into `dest`, returning the checked out commit
+async fn clone_git(url: &str, rev: Option<&str>, dest: &Path) -> Result<String, PluginHostError> {
+    // Sources from a lockfile skip `PluginSource::parse`
+    check_git_arg("URL", url)?;
+    let mut clone = Command::new("git");
+    clone.args(["clone", "--quiet"]);
+    if rev.is_none() {
+        clone.args(["--depth", "1"]);
+    }
+    run(clone.arg("--").arg(url).arg(dest), "git clone").await?;
+
+    if let Some(rev) = rev {
+        check_git_arg("revision", rev)?;
+        // The trailing `--` keeps a revision from being read as a path
+        run(
+            Command::new("git")
+                .arg("-C")
+                .arg(dest)
+                .args(["checkout", "--quiet", rev, "--"]),
+            "git checkout",
+        )
+        .await?;
+    }
+
+    run(
+        Command::new("git")
+            .arg("-C")
+            .arg(dest)
+            .args(["rev-parse", "HEAD"]),
+        "git rev-parse",
+    )
+    .await
+}
+
+/// Get a local path to a tarball, downloading it into `staging` if needed
+async fn fetch_tarball(location: &str, staging: &Path) -> Result<PathBuf, PluginHostError> {
+    if !is_url(location) {
+        return Ok(PathBuf::from(location));
+    }
+
+    let fetch_error = |e: reqwest::Error| {
+        PluginHostError::Install(format!("Failed to download {}: {}", location, e))
+    };
+    let bytes = reqwest::get(location)
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(fetch_error)?
+        .bytes()
+        .await
+        .map_err(fetch_error)?;
+
+    let file_name = location
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("plugin.tar.gz");
+    let archive = staging.join(file_name);
+    std::fs::write(&archive, &bytes)?;
+    Ok(archive)
+}
+
+/// Extract a tarball into `dest`, returning the package root
+///
+/// Archives that wrap everything in a single top-level directory (as
+/// `git archive --prefix` and most release tarballs do) are unwrapped.
+async fn extract_tarball(archive: &Path, dest: &Path) -> Result<PathBuf, PluginHostError> {
+    std::fs::create_dir_all(dest)?;
+    run(
+        Command::new("tar")
+            .arg("-xf")
+            .arg(archive)
+            .arg("-C")
+            .arg(dest),
+        "tar",
+    )
+    .await?;
+
+    let entries: Vec<PathBuf> = std::fs::read_dir(dest)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    match entries.as_slice() {
+        [only] if only.is_dir() => Ok(only.clone()),
+        _ => Ok(dest.to_path_buf()),
+    }
+}
+
+// ─── Building and Verifying ──────────────────────────────────────────
+
+/// Find the plugin build in a package, building it with cargo if needed
+///
+/// `package` may itself be a build. Otherwise a prebuilt `.wasm` component or
+/// library at the top of the package is used, and failing that the package
+/// is built with `cargo build --release` into `target_dir`.
+async fn locate_artifact(package: &Path, target_dir: &Path) -> Result<PathBuf, PluginHostError> {
+    if package.is_file() {
+        return if is_artifact(package) {
+            Ok(package.to_path_buf())
+        } else {
+            Err(PluginHostError::Install(format!(
+                "{} is not a plugin build",
+                package.display()
+            )))
+        };
+    }
+
+    if let Some(prebuilt) = find_artifact(package)? {
+        return Ok(prebuilt);
+    }
+
+    let cargo_manifest = package.join("Cargo.toml");
+    if !cargo_manifest.exists() {
+        return Err(PluginHostError::Install(format!(
+            "No plugin build or Cargo.toml in {}",
+            package.display()
+        )));
+    }
+    run(
+        Command::new("cargo")
+            .args(["build", "--release", "--manifest-path"])
+            .arg(&cargo_manifest)
+            .arg("--target-dir")
+            .arg(target_dir),
+        "cargo build",
+    )
+    .await?;
+
+    find_artifact(&target_dir.join("release"))?.ok_or_else(|| {
+        PluginHostError::Install(
+            "cargo build produced no plugin library (is the crate a cdylib?)".to_string(),
+        )
+    })
+}
+
+/// Whether a package is, or ships, a plugin build that needs no cargo build
+fn ships_build(package: &Path) -> Result<bool, PluginHostError> {
+    if package.is_file() {
+        return Ok(is_artifact(package));
+    }
+    Ok(find_artifact(package)?.is_some())
+}
+
+/// Whether a fresh build comes from the same source as an installed plugin
+///
+/// Cargo builds are not reproducible, so git sources compare the commit and
+/// tarballs the archive; only path sources compare the build itself.
+fn is_same_build(previous: &LockedPlugin, build: &PluginBuild) -> bool {
+    let same_source = match (&build.rev, &build.source_sha256) {
+        (Some(rev), _) => previous.rev.as_ref() == Some(rev),
+        (None, Some(archive)) => previous.source_sha256.as_ref() == Some(archive),
+        (None, None) => previous.sha256 == build.sha256,
+    };
+    same_source && previous.version == build.manifest.version
+}
+
+/// The plugin build directly inside `dir`, if there is exactly one
+fn find_artifact(dir: &Path) -> Result<Option<PathBuf>, PluginHostError> {
+    let mut found = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && is_artifact(&path) {
+            found.push(path);
+        }
+    }
+    found.sort();
+
+    match found.len() {
+        0 | 1 => Ok(found.pop()),
+        _ => Err(PluginHostError::Install(format!(
+            "Found several plugin builds in {}: {}",
+            dir.display(),
+            found
+                .iter()
+                .filter_map(|p| p.file_name()?.to_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+fn is_artifact(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| ARTIFACT_EXTENSIONS.contains(&ext))
+}
+
+fn sha256_file(path: &Path) -> Result<String, PluginHostError> {
+    let bytes = std::fs::read(path)?;
+    Ok(hex::encode(Sha256::digest(&bytes)))
+}
+
+fn verify_checksum(expected: &str, found: &str) -> Result<(), PluginHostError> {
+    if expected.trim().eq_ignore_ascii_case(found) {
+        Ok(())
+    } else {
+        Err(PluginHostError::ChecksumMismatch {
+            expected: expected.trim().to_ascii_lowercase(),
+            found: found.to_string(),
+        })
+    }
+}
+
+/// Plugin names become directory and file names, so keep them plain
+fn validate_name(name: &str) -> Result<(), PluginHostError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(PluginHostError::Install(format!(
+            "Invalid plugin name in manifest: '{}'",
+            name
+        )))
+    }
+}
+
+// ─── Placing ─────────────────────────────────────────────────────────
+
+/// Point `<dir>/<link>` at `target`, replacing any previous link atomically
+#[cfg(unix)]
+fn link_current(dir: &Path, link: &str, target: &str) -> Result<(), PluginHostError> {
+    let partial = dir.join(format!(".{}.partial", link));
+    let _ = std::fs::remove_file(&partial);
+    std::os::unix::fs::symlink(target, &partial)?;
+    std::fs::rename(&partial, dir.join(link))?;
+    Ok(())
+}
+
+/// Copy `target` to `<dir>/<link>` where symlinks are not available
+#[cfg(not(unix))]
+fn link_current(dir: &Path, link: &str, target: &str) -> Result<(), PluginHostError> {
+    std::fs::copy(dir.join(target), dir.join(link))?;
+    Ok(())
+}
+
+/// Remove builds of the plugin other than `keep`, including a build of the
+/// other kind (a leftover `.wasm` would otherwise shadow a native library)
+fn remove_stale_builds(dir: &Path, name: &str, keep: &[&str]) -> Result<(), PluginHostError> {
+    let prefixes = [format!("{}.", name), format!("lib{}.", name)];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let stale = is_artifact(&path)
+            && prefixes.iter().any(|p| file_name.starts_with(p.as_str()))
+            && !keep.contains(&file_name);
+        if stale {
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn installer(dir: &TempDir) -> PluginInstaller {
+        PluginInstaller::new(PluginHostConfig {
+            user_plugin_dir: dir.path().join("plugins"),
+            ..Default::default()
+        })
+    }
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    /// Local git repository with one commit per set of files
+    fn git_fixture(dir: &TempDir, commits: &[&[(&str, &[u8])]]) -> PathBuf {
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--quiet"]);
+        git(&repo, &["config", "user.email", "dev@example.com"]);
+        git(&repo, &["config", "user.name", "dev"]);
+        for (i, files) in commits.iter().enumerate() {
+            for (name, contents) in *files {
+                std::fs::write(repo.join(name), contents).unwrap();
+            }
+            git(&repo, &["add", "."]);
+            git(
+                &repo,
+                &["commit", "--quiet", "-m", &format!("commit {}", i)],
+            );
+        }
+        repo
+    }
+
+    fn build(name: &str, version: &str, artifact: PathBuf) -> PluginBuild {
+        PluginBuild {
+            manifest: PluginManifest {
+                name: name.to_string(),
+                version: version.to_string(),
+                ..Default::default()
+            },
+            sha256: sha256_file(&artifact).unwrap(),
+            artifact,
+            rev: None,
+            source_sha256: None,
+            pinned_sha256: None,
+        }
+    }
+
+    /// Contents of the WASM component fixture, a loadable plugin build
+    fn fixture_component() -> Vec<u8> {
+        std::fs::read(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/wasm/fixture-plugin.wat"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_sources() {
+        assert_eq!(
+            PluginSource::parse("https://github.com/acme/vibes-lint#v1.2.0").unwrap(),
+            PluginSource::Git {
+                url: "https://github.com/acme/vibes-lint".to_string(),
+                rev: Some("v1.2.0".to_string()),
+            }
+        );
+        assert_eq!(
+            PluginSource::parse("git@github.com:acme/vibes-lint.git").unwrap(),
+            PluginSource::Git {
+                url: "git@github.com:acme/vibes-lint.git".to_string(),
+                rev: None,
+            }
+        );
+        assert_eq!(
+            PluginSource::parse("https://example.com/lint-1.0.tar.gz").unwrap(),
+            PluginSource::Tarball {
+                location: "https://example.com/lint-1.0.tar.gz".to_string(),
+            }
+        );
+
+        let dir = TempDir::new().unwrap();
+        let local = PluginSource::parse(dir.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            local,
+            PluginSource::Path(dir.path().canonicalize().unwrap())
+        );
+        assert!(PluginSource::parse("/nonexistent/plugin").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_git_options() {
+        assert!(PluginSource::parse("git+--upload-pack=touch /tmp/pwned").is_err());
+        assert!(PluginSource::parse("https://github.com/acme/vibes-lint#--orphan").is_err());
+    }
+
+    #[test]
+    fn test_source_display_parses_back() {
+        let source = PluginSource::Git {
+            url: "/srv/plugins/lint".to_string(),
+            rev: Some("main".to_string()),
+        };
+        assert_eq!(source.to_string(), "git+/srv/plugins/lint#main");
+        assert_eq!(PluginSource::parse(&source.to_string()).unwrap(), source);
+    }
+
+    #[tokio::test]
+    async fn test_clone_git_fixture_at_rev() {
+        let dir = TempDir::new().unwrap();
+        let repo = git_fixture(
+            &dir,
+            &[&[("demo.wasm", b"first")], &[("demo.wasm", b"second")]],
+        );
+        let first = git(&repo, &["rev-parse", "HEAD~1"]);
+
+        let dest = dir.path().join("clone");
+        let rev = clone_git(repo.to_str().unwrap(), Some(&first), &dest)
+            .await
+            .unwrap();
+        assert_eq!(rev, first);
+        assert_eq!(std::fs::read(dest.join("demo.wasm")).unwrap(), b"first");
+
+        let latest = dir.path().join("latest");
+        let rev = clone_git(repo.to_str().unwrap(), None, &latest)
+            .await
+            .unwrap();
+        assert_eq!(rev, git(&repo, &["rev-parse", "HEAD"]));
+    }
+
+    #[tokio::test]
+    async fn test_clone_git_refuses_options() {
+        let dir = TempDir::new().unwrap();
+        let marker = dir.path().join("pwned");
+        let url = format!("--upload-pack=touch {}", marker.display());
+
+        assert!(
+            clone_git(&url, None, &dir.path().join("clone"))
+                .await
+                .is_err()
+        );
+        assert!(!marker.exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_rejects_invalid_build() {
+        let dir = TempDir::new().unwrap();
+        let repo = git_fixture(&dir, &[&[("demo.wasm", b"not a component")]]);
+        let mut installer = installer(&dir);
+
+        let source = PluginSource::parse(&format!("git+{}", repo.display())).unwrap();
+        let result = installer.install(&source, None).await;
+
+        assert!(matches!(result, Err(PluginHostError::Wasm(_))));
+        assert!(installer.installed().unwrap().is_empty());
+        assert!(!dir.path().join("plugins").join("demo").exists());
+    }
+
+    #[tokio::test]
+    async fn test_checksum_is_checked_before_loading() {
+        let dir = TempDir::new().unwrap();
+        let repo = git_fixture(&dir, &[&[("demo.wasm", b"not a component")]]);
+        let mut installer = installer(&dir);
+
+        let source = PluginSource::parse(&format!("git+{}", repo.display())).unwrap();
+        let result = installer.install(&source, Some(&"0".repeat(64))).await;
+
+        assert!(matches!(
+            result,
+            Err(PluginHostError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pin_rejects_source_builds_before_building() {
+        let dir = TempDir::new().unwrap();
+        let repo = git_fixture(&dir, &[&[("Cargo.toml", b"not a manifest")]]);
+        let mut installer = installer(&dir);
+
+        let source = PluginSource::parse(&format!("git+{}", repo.display())).unwrap();
+        let result = installer.install(&source, Some(&"0".repeat(64))).await;
+
+        let Err(PluginHostError::Install(message)) = result else {
+            panic!("expected the pin to be refused, got {:?}", result);
+        };
+        assert!(message.contains("#<rev>"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_update_compares_commit_and_keeps_pin() {
+        let dir = TempDir::new().unwrap();
+        let component = fixture_component();
+        let repo = git_fixture(&dir, &[&[("wasm-fixture.wasm", &component)]]);
+        let source = PluginSource::parse(&format!("git+{}", repo.display())).unwrap();
+        let mut installer = installer(&dir);
+
+        let pin = hex::encode(Sha256::digest(&component));
+        let installed = installer.install(&source, Some(&pin)).await.unwrap();
+        assert_eq!(installed.pinned_sha256.as_deref(), Some(pin.as_str()));
+
+        assert!(matches!(
+            installer.update("wasm-fixture").await.unwrap(),
+            UpdateOutcome::UpToDate(_)
+        ));
+
+        // A new commit changes the build, which no longer matches the pin
+        let mut changed = component.clone();
+        changed.extend_from_slice(b";; rebuilt\n");
+        std::fs::write(repo.join("wasm-fixture.wasm"), &changed).unwrap();
+        git(&repo, &["commit", "--quiet", "-am", "rebuild"]);
+        assert!(matches!(
+            installer.update("wasm-fixture").await,
+            Err(PluginHostError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_is_same_build_compares_source_not_build() {
+        let dir = TempDir::new().unwrap();
+        let artifact = dir.path().join("demo.wasm");
+        std::fs::write(&artifact, b"v1").unwrap();
+        let installed = build("demo", "1.0.0", artifact.clone());
+        let previous = LockedPlugin {
+            name: "demo".to_string(),
+            version: "1.0.0".to_string(),
+            source: "git+/srv/demo".to_string(),
+            rev: Some("abc".to_string()),
+            source_sha256: None,
+            sha256: installed.sha256.clone(),
+            pinned_sha256: None,
+            installed_at: Utc::now(),
+        };
+
+        // Rebuilding the same commit gives a different binary
+        std::fs::write(&artifact, b"v1 rebuilt").unwrap();
+        let mut rebuilt = build("demo", "1.0.0", artifact);
+        rebuilt.rev = Some("abc".to_string());
+        assert!(is_same_build(&previous, &rebuilt));
+
+        rebuilt.manifest.version = "1.0.1".to_string();
+        assert!(!is_same_build(&previous, &rebuilt));
+        rebuilt.manifest.version = "1.0.0".to_string();
+        rebuilt.rev = Some("def".to_string());
+        assert!(!is_same_build(&previous, &rebuilt));
+    }
+
+    #[tokio::test]
+    async fn test_extract_tarball_unwraps_top_level_dir() {
+        let dir = TempDir::new().unwrap();
+        let package = dir.path().join("demo-1.0");
+        std::fs::create_dir(&package).unwrap();
+        std::fs::write(package.join("demo.wasm"), b"wasm").unwrap();
+        let archive = dir.path().join("demo-1.0.tar.gz");
+        let status = std::process::Command::new("tar")
+            .arg("-czf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(dir.path())
+            .arg("demo-1.0")
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let root = extract_tarball(&archive, &dir.path().join("out"))
+            .await
+            .unwrap();
+        assert_eq!(root.file_name().unwrap(), "demo-1.0");
+        let artifact = locate_artifact(&root, &dir.path().join("target"))
+            .await
+            .unwrap();
+        assert_eq!(artifact.file_name().unwrap(), "demo.wasm");
+    }
+
+    #[test]
+    fn test_find_artifact_rejects_several_builds() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.wasm"), b"a").unwrap();
+        std::fs::write(dir.path().join("README.md"), b"docs").unwrap();
+        assert!(find_artifact(dir.path()).unwrap().is_some());
+
+        std::fs::write(dir.path().join("b.wasm"), b"b").unwrap();
+        assert!(matches!(
+            find_artifact(dir.path()),
+            Err(PluginHostError::Install(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        let found = "ab".repeat(32);
+        assert!(verify_checksum(&found.to_ascii_uppercase(), &found).is_ok());
+        assert!(matches!(
+            verify_checksum(&"cd".repeat(32), &found),
+            Err(PluginHostError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("vibes-lint_2").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("../escape").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_place_links_current_build_and_locks_it() {
+        let dir = TempDir::new().unwrap();
+        let installer = installer(&dir);
+        let source = PluginSource::Path(dir.path().to_path_buf());
+        let artifact = dir.path().join("demo.wasm");
+
+        std::fs::write(&artifact, b"v1").unwrap();
+        installer
+            .place(build("demo", "1.0.0", artifact.clone()), &source)
+            .unwrap();
+        std::fs::write(&artifact, b"v2").unwrap();
+        let locked = installer
+            .place(build("demo", "1.1.0", artifact), &source)
+            .unwrap();
+
+        let plugin_dir = dir.path().join("plugins").join("demo");
+        assert_eq!(
+            std::fs::read_link(plugin_dir.join("demo.wasm")).unwrap(),
+            PathBuf::from("demo.1.1.0.wasm")
+        );
+        assert_eq!(std::fs::read(plugin_dir.join("demo.wasm")).unwrap(), b"v2");
+        assert!(!plugin_dir.join("demo.1.0.0.wasm").exists());
+
+        assert_eq!(locked.version, "1.1.0");
+        assert_eq!(installer.installed().unwrap(), vec![locked]);
+    }
+
+    #[test]
+    fn test_uninstall_removes_plugin_and_registry_entry() {
+        let dir = TempDir::new().unwrap();
+        let installer = installer(&dir);
+        let artifact = dir.path().join("demo.wasm");
+        std::fs::write(&artifact, b"v1").unwrap();
+        installer
+            .place(
+                build("demo", "1.0.0", artifact),
+                &PluginSource::Path(dir.path().to_path_buf()),
+            )
+            .unwrap();
+
+        let registry_path = dir.path().join("plugins").join("registry.toml");
+        let mut registry = PluginRegistry::default();
+        registry.enable("demo");
+        registry.save(&registry_path).unwrap();
+
+        installer.uninstall("demo").unwrap();
+
+        assert!(!dir.path().join("plugins").join("demo").exists());
+        assert!(installer.installed().unwrap().is_empty());
+        assert!(
+            !PluginRegistry::load(&registry_path)
+                .unwrap()
+                .is_enabled("demo")
+        );
+        assert!(matches!(
+            installer.uninstall("demo"),
+            Err(PluginHostError::NotInstalled { .. })
+        ));
+    }
+}
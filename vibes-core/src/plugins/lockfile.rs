@@ -0,0 +1,147 @@
+//! Plugin lockfile - tracks plugins installed with `vibes plugin install`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::error::PluginHostError;
+
+/// An installed plugin and where it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedPlugin {
+    /// Plugin name (also its directory name)
+    pub name: String,
+    /// Installed version, from the plugin's manifest
+    pub version: String,
+    /// Source it was installed from, as accepted by `PluginSource::parse`
+    pub source: String,
+    /// Git commit the build came from, for git sources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// SHA-256 of the tarball the build came from, for tarball sources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_sha256: Option<String>,
+    /// SHA-256 of the installed build
+    pub sha256: String,
+    /// Checksum pinned with `--sha256`, checked again on every update
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_sha256: Option<String>,
+    /// When this version was installed
+    pub installed_at: DateTime<Utc>,
+}
+
+/// Lockfile of installed plugins
+///
+/// Stored as TOML in `~/.config/vibes/plugins/plugins.lock`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PluginLockfile {
+    /// Installed plugins, sorted by name
+    #[serde(default, rename = "plugin")]
+    pub plugins: Vec<LockedPlugin>,
+}
+
+impl PluginLockfile {
+    /// Load the lockfile from a TOML file
+    ///
+    /// Returns an empty lockfile if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self, PluginHostError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content).map_err(|e| PluginHostError::Lockfile(e.to_string()))
+    }
+
+    /// Save the lockfile to a TOML file
+    pub fn save(&self, path: &Path) -> Result<(), PluginHostError> {
+        let content =
+            toml::to_string_pretty(self).map_err(|e| PluginHostError::Lockfile(e.to_string()))?;
+
+        if let Some(parent) = path.parent().filter(|p| !p.exists()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Get an installed plugin by name
+    pub fn get(&self, name: &str) -> Option<&LockedPlugin> {
+        self.plugins.iter().find(|p| p.name == name)
+    }
+
+    /// Add or replace an installed plugin
+    pub fn upsert(&mut self, plugin: LockedPlugin) {
+        self.plugins.retain(|p| p.name != plugin.name);
+        self.plugins.push(plugin);
+        self.plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Remove an installed plugin, returning its entry
+    pub fn remove(&mut self, name: &str) -> Option<LockedPlugin> {
+        let index = self.plugins.iter().position(|p| p.name == name)?;
+        Some(self.plugins.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn locked(name: &str, version: &str) -> LockedPlugin {
+        LockedPlugin {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: format!("git+https://example.com/{}.git", name),
+            rev: Some("0123abcd".to_string()),
+            source_sha256: None,
+            sha256: "ff".repeat(32),
+            pinned_sha256: None,
+            installed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_lockfile_load_missing_file() {
+        let lockfile = PluginLockfile::load(Path::new("/nonexistent/plugins.lock")).unwrap();
+        assert!(lockfile.plugins.is_empty());
+    }
+
+    #[test]
+    fn test_lockfile_upsert_replaces_and_sorts() {
+        let mut lockfile = PluginLockfile::default();
+        lockfile.upsert(locked("zeta", "1.0.0"));
+        lockfile.upsert(locked("alpha", "1.0.0"));
+        lockfile.upsert(locked("zeta", "1.1.0"));
+
+        let names: Vec<_> = lockfile.plugins.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["alpha", "zeta"]);
+        assert_eq!(lockfile.get("zeta").unwrap().version, "1.1.0");
+    }
+
+    #[test]
+    fn test_lockfile_save_load_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("plugins.lock");
+
+        let mut lockfile = PluginLockfile::default();
+        lockfile.upsert(locked("hello", "0.1.0"));
+        lockfile.save(&path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("[[plugin]]"));
+
+        let loaded = PluginLockfile::load(&path).unwrap();
+        assert_eq!(loaded.get("hello"), lockfile.get("hello"));
+    }
+
+    #[test]
+    fn test_lockfile_remove() {
+        let mut lockfile = PluginLockfile::default();
+        lockfile.upsert(locked("hello", "0.1.0"));
+
+        assert_eq!(lockfile.remove("hello").unwrap().name, "hello");
+        assert!(lockfile.remove("hello").is_none());
+    }
+}
@@ -4,7 +4,11 @@
 //!
 //! - [`PluginHost`]: The main plugin manager that loads, unloads, and dispatches events
 //! - [`PluginRegistry`]: Tracks which plugins are enabled/disabled
+//! - [`PluginInstaller`]: Installs, updates and uninstalls plugins, tracked in
+//!   a [`PluginLockfile`]
 //! - [`PluginHostError`]: Error types for plugin operations
+//! - [`PluginWatcher`]: Hot-reloads plugins when their files change
+//! - [`EventLogPublisher`]: Appends events plugins publish to the event log
 //!
 //! # Plugin Discovery
 //!
@@ -19,6 +23,14 @@
 //! - `<name>.so` - symlink to the versioned binary
 //! - `config.toml` (optional) - plugin configuration
 //!
+//! Or, for a sandboxed WebAssembly plugin:
+//! - `<name>.wasm` - a WASI component targeting `wit/vibes-plugin.wit`
+//! - `config.toml` (optional) - plugin configuration
+//!
+//! WASM plugins are checked against [`WASM_API_VERSION`] instead of the
+//! native `API_VERSION`, and run under the fuel and memory limits in
+//! [`WasmLimits`].
+//!
 //! # Example
 //!
 //! ```ignore
@@ -41,11 +53,23 @@
 mod commands;
 mod error;
 mod host;
+mod install;
+mod lockfile;
+mod publisher;
 mod registry;
 mod routes;
+mod tui;
+mod wasm;
+mod watcher;
 
 pub use commands::{CommandRegistry, RegisteredPluginCommand};
 pub use error::PluginHostError;
-pub use host::{PluginHost, PluginHostConfig, PluginInfo, PluginState};
+pub use host::{OpenStream, PluginHost, PluginHostConfig, PluginInfo, PluginState};
+pub use install::{PluginInstaller, PluginSource, UpdateOutcome};
+pub use lockfile::{LockedPlugin, PluginLockfile};
+pub use publisher::EventLogPublisher;
 pub use registry::PluginRegistry;
 pub use routes::{RegisteredPluginRoute, RouteRegistry};
+pub use tui::TuiRegistry;
+pub use wasm::{WASM_API_VERSION, WasmLimits, WasmPlugin};
+pub use watcher::{DEFAULT_RELOAD_DEBOUNCE, PluginWatcher};
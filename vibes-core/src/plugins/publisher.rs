@@ -0,0 +1,103 @@
+//! Publishes plugin events to the shared event log
+
+use std::sync::Arc;
+
+use tokio::runtime::Handle;
+use vibes_iggy::EventLog;
+use vibes_plugin_api::{EventPublisher, PluginError, PluginEvent};
+
+use crate::events::{StoredEvent, VibesEvent};
+
+/// [`EventPublisher`] that appends plugin events to the event log
+///
+/// Appends are spawned on the host runtime, so publishing never blocks a
+/// plugin callback. Published events reach the firehose and other plugins'
+/// `on_event()` through the log like any other event.
+pub struct EventLogPublisher {
+    event_log: Arc<dyn EventLog<StoredEvent>>,
+    handle: Handle,
+}
+
+impl EventLogPublisher {
+    /// Create a publisher appending to `event_log` on the runtime behind `handle`
+    pub fn new(event_log: Arc<dyn EventLog<StoredEvent>>, handle: Handle) -> Self {
+        Self { event_log, handle }
+    }
+}
+
+impl EventPublisher for EventLogPublisher {
+    fn publish(&self, plugin: &str, event: PluginEvent) -> Result<(), PluginError> {
+        if event.event_type.is_empty() {
+            return Err(PluginError::InvalidInput(
+                "event type must not be empty".to_string(),
+            ));
+        }
+
+        let stored = StoredEvent::new(VibesEvent::Plugin {
+            plugin: plugin.to_string(),
+            event_type: event.event_type,
+            session_id: event.session_id,
+            payload: event.payload,
+        });
+
+        let event_log = Arc::clone(&self.event_log);
+        let plugin = plugin.to_string();
+        self.handle.spawn(async move {
+            if let Err(e) = event_log.append(stored).await {
+                tracing::warn!(plugin = %plugin, error = %e, "Failed to append plugin event");
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+    use vibes_iggy::InMemoryEventLog;
+
+    #[tokio::test]
+    async fn test_publish_appends_namespaced_event() {
+        let log = Arc::new(InMemoryEventLog::<StoredEvent>::new());
+        let publisher = EventLogPublisher::new(log.clone(), Handle::current());
+
+        publisher
+            .publish(
+                "groove",
+                PluginEvent::new("learning_extracted", json!({"id": 1})).with_session("sess-1"),
+            )
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while log.is_empty().await {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let mut consumer = log.consumer("test").await.unwrap();
+        let batch = consumer.poll(10, Duration::from_millis(10)).await.unwrap();
+        let (_, stored) = batch.into_iter().next().unwrap();
+        assert_eq!(
+            stored.event,
+            VibesEvent::Plugin {
+                plugin: "groove".to_string(),
+                event_type: "learning_extracted".to_string(),
+                session_id: Some("sess-1".to_string()),
+                payload: json!({"id": 1}),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_publish_rejects_empty_event_type() {
+        let log = Arc::new(InMemoryEventLog::<StoredEvent>::new());
+        let publisher = EventLogPublisher::new(log, Handle::current());
+
+        let result = publisher.publish("groove", PluginEvent::new("", json!(null)));
+        assert!(matches!(result, Err(PluginError::InvalidInput(_))));
+    }
+}
@@ -1,19 +1,42 @@
-//! Plugin registry - tracks enabled/disabled plugins
+//! Plugin registry - tracks enabled/disabled plugins and approved permissions
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use vibes_plugin_api::Permission;
 
 use super::error::PluginHostError;
 
+/// Current registry format version
+///
+/// Version 1 added permission approvals. Files without a version predate
+/// them, so their enabled plugins have nothing approved yet.
+pub const REGISTRY_VERSION: u32 = 1;
+
 /// Registry of enabled plugins
 ///
 /// Stored as TOML in `~/.config/vibes/plugins/registry.toml`
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PluginRegistry {
+    /// Format version (0 for files written before versioning)
+    #[serde(default)]
+    pub version: u32,
     /// Set of enabled plugin names
     #[serde(default)]
     pub enabled: HashSet<String>,
+    /// Permissions the user approved for each plugin
+    #[serde(default)]
+    pub approved: HashMap<String, Vec<Permission>>,
+}
+
+impl Default for PluginRegistry {
+    fn default() -> Self {
+        Self {
+            version: REGISTRY_VERSION,
+            enabled: HashSet::new(),
+            approved: HashMap::new(),
+        }
+    }
 }
 
 impl PluginRegistry {
@@ -54,9 +77,48 @@ impl PluginRegistry {
         self.enabled.insert(name.to_string());
     }
 
-    /// Disable a plugin
+    /// Disable a plugin, forgetting its approved permissions
+    ///
+    /// Re-enabling asks for approval again.
     pub fn disable(&mut self, name: &str) {
         self.enabled.remove(name);
+        self.approved.remove(name);
+    }
+
+    /// Record the permissions the user approved for a plugin
+    pub fn approve(&mut self, name: &str, permissions: Vec<Permission>) {
+        self.approved.insert(name.to_string(), permissions);
+    }
+
+    /// Get the permissions the user approved for a plugin
+    pub fn approved(&self, name: &str) -> &[Permission] {
+        self.approved
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Whether a plugin was enabled before permission approvals existed and
+    /// has not been migrated yet
+    pub fn needs_approval_migration(&self, name: &str) -> bool {
+        self.version < REGISTRY_VERSION
+            && self.is_enabled(name)
+            && !self.approved.contains_key(name)
+    }
+
+    /// Approve the permissions a pre-approval plugin declares, once
+    ///
+    /// The registry is marked current when every enabled plugin has an
+    /// approval, after which no further plugins are migrated.
+    pub fn migrate_approval(&mut self, name: &str, permissions: Vec<Permission>) {
+        self.approve(name, permissions);
+        if self
+            .enabled
+            .iter()
+            .all(|name| self.approved.contains_key(name))
+        {
+            self.version = REGISTRY_VERSION;
+        }
     }
 
     /// Get iterator over enabled plugins
@@ -133,6 +195,74 @@ mod tests {
         assert!(enabled.contains(&"b"));
     }
 
+    #[test]
+    fn test_registry_approvals_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("registry.toml");
+
+        let mut registry = PluginRegistry::default();
+        registry.enable("groove");
+        registry.approve(
+            "groove",
+            vec![
+                Permission::ReadEvents,
+                Permission::write_path("/data/groove"),
+            ],
+        );
+        registry.save(&path).unwrap();
+
+        let loaded = PluginRegistry::load(&path).unwrap();
+        assert_eq!(
+            loaded.approved("groove"),
+            &[
+                Permission::ReadEvents,
+                Permission::write_path("/data/groove")
+            ]
+        );
+        assert!(loaded.approved("other").is_empty());
+    }
+
+    #[test]
+    fn test_registry_disable_forgets_approvals() {
+        let mut registry = PluginRegistry::default();
+        registry.enable("groove");
+        registry.approve("groove", vec![Permission::AnswerHooks]);
+
+        registry.disable("groove");
+        assert!(registry.approved("groove").is_empty());
+    }
+
+    #[test]
+    fn test_registry_migrates_pre_approval_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("registry.toml");
+        std::fs::write(&path, "enabled = [\"groove\", \"history\"]\n").unwrap();
+
+        let mut registry = PluginRegistry::load(&path).unwrap();
+        assert_eq!(registry.version, 0);
+        assert!(registry.needs_approval_migration("groove"));
+        assert!(!registry.needs_approval_migration("other"));
+
+        registry.migrate_approval("groove", vec![Permission::RegisterRoutes]);
+        assert!(!registry.needs_approval_migration("groove"));
+        assert!(registry.needs_approval_migration("history"));
+        assert_eq!(registry.version, 0);
+
+        registry.migrate_approval("history", vec![]);
+        registry.save(&path).unwrap();
+
+        let loaded = PluginRegistry::load(&path).unwrap();
+        assert_eq!(loaded.version, REGISTRY_VERSION);
+        assert_eq!(loaded.approved("groove"), &[Permission::RegisterRoutes]);
+    }
+
+    #[test]
+    fn test_new_registry_needs_no_migration() {
+        let mut registry = PluginRegistry::default();
+        registry.enable("groove");
+        assert!(!registry.needs_approval_migration("groove"));
+    }
+
     #[test]
     fn test_registry_toml_format() {
         let mut registry = PluginRegistry::default();
@@ -1,12 +1,14 @@
 //! Route registry for plugin HTTP routes
 
 use std::collections::HashMap;
-use vibes_plugin_api::{HttpMethod, RouteSpec};
+use vibes_plugin_api::{HttpMethod, RouteSpec, StreamSpec};
 
 /// Registry of all plugin HTTP routes
 pub struct RouteRegistry {
     /// Registered routes with compiled path matchers
     routes: Vec<RegisteredPluginRoute>,
+    /// Registered streaming routes (SSE and WebSocket)
+    streams: Vec<RegisteredPluginStream>,
 }
 
 /// A route registered by a plugin
@@ -21,6 +23,18 @@ pub struct RegisteredPluginRoute {
     matcher: PathMatcher,
 }
 
+/// A streaming route registered by a plugin
+pub struct RegisteredPluginStream {
+    /// Name of the plugin that owns this stream
+    pub plugin_name: String,
+    /// Stream specification
+    pub spec: StreamSpec,
+    /// Full path including /api/<plugin>/ prefix
+    pub full_path: String,
+    /// Compiled path matcher
+    matcher: PathMatcher,
+}
+
 /// Simple path matcher supporting :param patterns
 struct PathMatcher {
     segments: Vec<PathSegment>,
@@ -77,7 +91,10 @@ impl PathMatcher {
 impl RouteRegistry {
     /// Create a new empty route registry
     pub fn new() -> Self {
-        Self { routes: Vec::new() }
+        Self {
+            routes: Vec::new(),
+            streams: Vec::new(),
+        }
     }
 
     /// Register routes for a plugin
@@ -99,6 +116,8 @@ impl RouteRegistry {
 
     /// Check if a route would conflict with existing registrations
     ///
+    /// A GET route also conflicts with a stream at the same path.
+    ///
     /// Returns the name of the plugin that owns the conflicting route, if any
     pub fn check_conflict(&self, plugin_name: &str, spec: &RouteSpec) -> Option<&str> {
         let full_path = format!("/api/{}{}", plugin_name, spec.path);
@@ -107,6 +126,13 @@ impl RouteRegistry {
             .iter()
             .find(|r| r.spec.method == spec.method && r.full_path == full_path)
             .map(|r| r.plugin_name.as_str())
+            .or_else(|| {
+                // Streams are served over GET
+                self.streams
+                    .iter()
+                    .find(|s| spec.method == HttpMethod::Get && s.full_path == full_path)
+                    .map(|s| s.plugin_name.as_str())
+            })
     }
 
     /// Find a route matching the given method and path
@@ -128,9 +154,57 @@ impl RouteRegistry {
         None
     }
 
-    /// Unregister all routes for a plugin
+    /// Register streaming routes for a plugin
+    ///
+    /// Streams are prefixed with /api/<plugin>/ like regular routes
+    pub fn register_streams(&mut self, plugin_name: &str, streams: Vec<StreamSpec>) {
+        for spec in streams {
+            let full_path = format!("/api/{}{}", plugin_name, spec.path);
+            let matcher = PathMatcher::new(&full_path);
+
+            self.streams.push(RegisteredPluginStream {
+                plugin_name: plugin_name.to_string(),
+                spec,
+                full_path,
+                matcher,
+            });
+        }
+    }
+
+    /// Check if a stream would conflict with existing streams or GET routes
+    ///
+    /// Returns the name of the plugin that owns the conflicting route, if any
+    pub fn check_stream_conflict(&self, plugin_name: &str, spec: &StreamSpec) -> Option<&str> {
+        let full_path = format!("/api/{}{}", plugin_name, spec.path);
+
+        self.streams
+            .iter()
+            .find(|s| s.full_path == full_path)
+            .map(|s| s.plugin_name.as_str())
+            .or_else(|| {
+                self.routes
+                    .iter()
+                    .find(|r| r.spec.method == HttpMethod::Get && r.full_path == full_path)
+                    .map(|r| r.plugin_name.as_str())
+            })
+    }
+
+    /// Find a stream matching the given path
+    ///
+    /// Returns the stream and extracted path parameters
+    pub fn match_stream(
+        &self,
+        path: &str,
+    ) -> Option<(&RegisteredPluginStream, HashMap<String, String>)> {
+        self.streams
+            .iter()
+            .find_map(|s| s.matcher.match_path(path).map(|params| (s, params)))
+    }
+
+    /// Unregister all routes and streams for a plugin
     pub fn unregister(&mut self, plugin_name: &str) {
         self.routes.retain(|r| r.plugin_name != plugin_name);
+        self.streams.retain(|s| s.plugin_name != plugin_name);
     }
 }
 
@@ -340,6 +414,52 @@ mod tests {
         assert_eq!(params.get("msg_id"), Some(&"123".to_string()));
     }
 
+    #[test]
+    fn test_match_stream_extracts_params() {
+        let mut registry = RouteRegistry::new();
+
+        registry.register_streams("groove", vec![StreamSpec::sse("/sessions/:id/live")]);
+
+        let (stream, params) = registry
+            .match_stream("/api/groove/sessions/abc/live")
+            .unwrap();
+        assert_eq!(stream.plugin_name, "groove");
+        assert_eq!(stream.spec.path, "/sessions/:id/live");
+        assert_eq!(params.get("id"), Some(&"abc".to_string()));
+        assert!(registry.match_stream("/api/groove/live").is_none());
+    }
+
+    #[test]
+    fn test_check_stream_conflict_with_get_route() {
+        let mut registry = RouteRegistry::new();
+
+        registry.register(
+            "groove",
+            vec![RouteSpec {
+                method: HttpMethod::Get,
+                path: "/live".into(),
+            }],
+        );
+
+        let conflict = registry.check_stream_conflict("groove", &StreamSpec::websocket("/live"));
+        assert_eq!(conflict, Some("groove"));
+        assert!(
+            registry
+                .check_stream_conflict("groove", &StreamSpec::sse("/feed"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_unregister_removes_streams() {
+        let mut registry = RouteRegistry::new();
+
+        registry.register_streams("groove", vec![StreamSpec::sse("/live")]);
+        registry.unregister("groove");
+
+        assert!(registry.match_stream("/api/groove/live").is_none());
+    }
+
     #[test]
     fn test_default_creates_empty_registry() {
         let registry = RouteRegistry::default();
@@ -0,0 +1,90 @@
+//! TUI registry for plugin views and command-bar commands
+
+use std::collections::HashMap;
+use vibes_plugin_api::{PluginTui, TuiCommandSpec, TuiViewSpec};
+
+/// Registry of what each plugin contributes to the TUI
+#[derive(Default)]
+pub struct TuiRegistry {
+    plugins: HashMap<String, PluginTui>,
+}
+
+impl TuiRegistry {
+    /// Create a new empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a plugin's views and commands, replacing any it had before
+    pub fn register(
+        &mut self,
+        plugin_name: &str,
+        views: Vec<TuiViewSpec>,
+        commands: Vec<TuiCommandSpec>,
+    ) {
+        if views.is_empty() && commands.is_empty() {
+            self.plugins.remove(plugin_name);
+            return;
+        }
+        self.plugins.insert(
+            plugin_name.to_string(),
+            PluginTui {
+                plugin: plugin_name.to_string(),
+                views,
+                commands,
+            },
+        );
+    }
+
+    /// Remove everything a plugin registered
+    pub fn unregister(&mut self, plugin_name: &str) {
+        self.plugins.remove(plugin_name);
+    }
+
+    /// Get one plugin's contributions
+    pub fn get(&self, plugin_name: &str) -> Option<&PluginTui> {
+        self.plugins.get(plugin_name)
+    }
+
+    /// All contributions, sorted by plugin name
+    pub fn list(&self) -> Vec<PluginTui> {
+        let mut plugins: Vec<_> = self.plugins.values().cloned().collect();
+        plugins.sort_by(|a, b| a.plugin.cmp(&b.plugin));
+        plugins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(id: &str) -> TuiViewSpec {
+        TuiViewSpec {
+            id: id.to_string(),
+            title: id.to_string(),
+            widgets: vec![],
+        }
+    }
+
+    #[test]
+    fn test_register_lists_sorted_and_unregisters() {
+        let mut registry = TuiRegistry::new();
+        registry.register("zeta", vec![view("z")], vec![]);
+        registry.register("alpha", vec![view("a")], vec![]);
+
+        let names: Vec<_> = registry.list().into_iter().map(|p| p.plugin).collect();
+        assert_eq!(names, ["alpha", "zeta"]);
+
+        registry.unregister("zeta");
+        assert!(registry.get("zeta").is_none());
+    }
+
+    #[test]
+    fn test_register_nothing_removes_plugin() {
+        let mut registry = TuiRegistry::new();
+        registry.register("groove", vec![view("gaps")], vec![]);
+        registry.register("groove", vec![], vec![]);
+
+        assert!(registry.list().is_empty());
+    }
+}
@@ -0,0 +1,640 @@
+//! WebAssembly plugin runtime
+//!
+//! WASM plugins are WASI components targeting the `vibes-plugin` world in
+//! `vibes-core/wit/vibes-plugin.wit`. [`WasmPlugin`] adapts a component
+//! instance to the [`Plugin`] trait so the host loads, dispatches to and
+//! unloads it exactly like a native plugin.
+//!
+//! Each plugin runs in its own store with:
+//! - a fuel budget per call, so a runaway handler traps instead of hanging
+//! - a cap on linear memory
+//! - a WASI context with no filesystem, network or environment access
+//!
+//! Registrations and event access go through the plugin's `PluginContext`,
+//! so they are subject to the permissions declared in the WASM manifest.
+//!
+//! A trap (including running out of fuel) poisons the instance: the plugin
+//! records the fault, ignores further calls, and the host marks it failed.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
+
+use serde::Deserialize;
+use vibes_plugin_api::{
+    ArgSpec, AssessmentQuery, AssessmentQueryResponse, CommandArgs, CommandOutput, CommandSpec,
+    HookDecision, HookInput, HttpMethod, Permission, Plugin, PluginAssessmentResult, PluginContext,
+    PluginError, PluginManifest, RawEvent, RouteRequest, RouteResponse, RouteSpec,
+};
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
+
+use super::error::PluginHostError;
+
+/// Generated bindings, kept apart from the plugin API types they mirror
+mod bindings {
+    wasmtime::component::bindgen!({
+        world: "vibes-plugin",
+        path: "wit",
+    });
+}
+
+use bindings::VibesPlugin;
+use bindings::vibes::plugin::host::{self as wit_host, LogLevel};
+use bindings::vibes::plugin::types as wit;
+
+/// Major version of the WASM host interface (the WIT package version)
+pub const WASM_API_VERSION: u32 = 1;
+
+/// Resource limits applied to each WASM plugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmLimits {
+    /// Fuel available to each call into the plugin
+    pub fuel_per_call: u64,
+    /// Maximum linear memory in bytes
+    pub max_memory_bytes: usize,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            fuel_per_call: 1_000_000_000,
+            max_memory_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// Shared record of the trap that poisoned a plugin instance
+pub(crate) type WasmFault = Arc<OnceLock<String>>;
+
+/// Engine and linker shared by all WASM plugins of a host
+pub(crate) struct WasmRuntime {
+    engine: Engine,
+    linker: Linker<WasmState>,
+    limits: WasmLimits,
+}
+
+impl WasmRuntime {
+    /// Create a runtime with fuel metering and the vibes host interface
+    pub(crate) fn new(limits: WasmLimits) -> Result<Self, PluginHostError> {
+        let mut config = Config::new();
+        config.wasm_component_model(true);
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(wasm_error)?;
+
+        let mut linker = Linker::new(&engine);
+        wasmtime_wasi::add_to_linker_sync(&mut linker).map_err(wasm_error)?;
+        VibesPlugin::add_to_linker(&mut linker, |state: &mut WasmState| state)
+            .map_err(wasm_error)?;
+
+        Ok(Self {
+            engine,
+            linker,
+            limits,
+        })
+    }
+
+    /// Compile and instantiate a plugin component
+    ///
+    /// Fails if the plugin was built against a different major version of
+    /// the host interface.
+    pub(crate) fn load(&self, path: &Path, name: &str) -> Result<WasmPlugin, PluginHostError> {
+        let component = Component::from_file(&self.engine, path).map_err(wasm_error)?;
+
+        let state = WasmState {
+            plugin_name: name.to_string(),
+            wasi: WasiCtxBuilder::new().inherit_stderr().build(),
+            table: ResourceTable::new(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(self.limits.max_memory_bytes)
+                .build(),
+        };
+        let mut store = Store::new(&self.engine, state);
+        store.limiter(|state| &mut state.limits);
+        store
+            .set_fuel(self.limits.fuel_per_call)
+            .map_err(wasm_error)?;
+
+        let bindings =
+            VibesPlugin::instantiate(&mut store, &component, &self.linker).map_err(wasm_error)?;
+
+        let found = bindings.call_api_version(&mut store).map_err(wasm_error)?;
+        if found != WASM_API_VERSION {
+            return Err(PluginHostError::ApiVersionMismatch {
+                expected: WASM_API_VERSION,
+                found,
+            });
+        }
+
+        store
+            .set_fuel(self.limits.fuel_per_call)
+            .map_err(wasm_error)?;
+        let manifest_json = bindings.call_manifest(&mut store).map_err(wasm_error)?;
+        let manifest: WasmManifest = serde_json::from_str(&manifest_json)
+            .map_err(|e| PluginHostError::Wasm(format!("invalid manifest: {}", e)))?;
+
+        Ok(WasmPlugin {
+            manifest: manifest.into_manifest(),
+            bindings,
+            store: Mutex::new(store),
+            fuel_per_call: self.limits.fuel_per_call,
+            fault: WasmFault::default(),
+        })
+    }
+}
+
+fn wasm_error(e: wasmtime::Error) -> PluginHostError {
+    PluginHostError::Wasm(format!("{:#}", e))
+}
+
+/// Per-plugin store data
+struct WasmState {
+    plugin_name: String,
+    wasi: WasiCtx,
+    table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl WasiView for WasmState {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl wit::Host for WasmState {}
+
+impl wit_host::Host for WasmState {
+    fn log(&mut self, level: LogLevel, message: String) {
+        let plugin = self.plugin_name.as_str();
+        match level {
+            LogLevel::Trace => tracing::trace!(plugin, "{}", message),
+            LogLevel::Debug => tracing::debug!(plugin, "{}", message),
+            LogLevel::Info => tracing::info!(plugin, "{}", message),
+            LogLevel::Warn => tracing::warn!(plugin, "{}", message),
+            LogLevel::Error => tracing::error!(plugin, "{}", message),
+        }
+    }
+}
+
+/// Manifest fields a WASM plugin reports
+#[derive(Debug, Deserialize)]
+struct WasmManifest {
+    name: String,
+    version: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    permissions: Vec<Permission>,
+}
+
+impl WasmManifest {
+    fn into_manifest(self) -> PluginManifest {
+        PluginManifest {
+            name: self.name,
+            version: self.version,
+            description: self.description,
+            author: self.author,
+            permissions: self.permissions,
+            ..Default::default()
+        }
+    }
+}
+
+/// A WASM component instance exposed through the [`Plugin`] trait
+///
+/// Typed session handlers are not forwarded; WASM plugins receive every
+/// event through `on-event` as a [`RawEvent`].
+pub struct WasmPlugin {
+    manifest: PluginManifest,
+    bindings: VibesPlugin,
+    store: Mutex<Store<WasmState>>,
+    fuel_per_call: u64,
+    fault: WasmFault,
+}
+
+impl WasmPlugin {
+    /// Handle to the fault recorded when the instance traps
+    pub(crate) fn fault(&self) -> WasmFault {
+        Arc::clone(&self.fault)
+    }
+
+    /// Call into the component with a fresh fuel budget
+    ///
+    /// A trap is recorded as the plugin's fault; calls after a fault fail
+    /// without entering the component.
+    fn call<R>(
+        &self,
+        export: &str,
+        f: impl FnOnce(&VibesPlugin, &mut Store<WasmState>) -> wasmtime::Result<R>,
+    ) -> Result<R, PluginError> {
+        if let Some(fault) = self.fault.get() {
+            return Err(PluginError::custom(format!("plugin faulted: {}", fault)));
+        }
+
+        let mut store = self.lock_store();
+        store
+            .set_fuel(self.fuel_per_call)
+            .map_err(|e| PluginError::custom(e.to_string()))?;
+
+        f(&self.bindings, &mut store).map_err(|e| {
+            let fault = format!("{} trapped: {:#}", export, e);
+            tracing::error!(plugin = %self.manifest.name, "{}", fault);
+            let _ = self.fault.set(fault.clone());
+            PluginError::Custom(fault)
+        })
+    }
+
+    fn lock_store(&self) -> MutexGuard<'_, Store<WasmState>> {
+        self.store.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Plugin for WasmPlugin {
+    fn manifest(&self) -> PluginManifest {
+        self.manifest.clone()
+    }
+
+    fn on_load(&mut self, ctx: &mut PluginContext) -> Result<(), PluginError> {
+        let config = load_config_json(&ctx.plugin_dir().join("config.toml"))?;
+        let registrations = self
+            .call("on-load", |b, s| b.call_on_load(s, &config))?
+            .map_err(PluginError::Custom)?;
+
+        for command in registrations.commands {
+            ctx.register_command(command_spec(command))?;
+        }
+        for route in registrations.routes {
+            ctx.register_route(RouteSpec {
+                method: route.method.into(),
+                path: route.path,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn on_unload(&mut self) -> Result<(), PluginError> {
+        if self.fault.get().is_some() {
+            return Ok(());
+        }
+        self.call("on-unload", |b, s| b.call_on_unload(s))
+    }
+
+    /// WASM plugins can inject context but not make permission decisions
+    fn on_hook(&mut self, hook: &HookInput, ctx: &mut PluginContext) -> Option<HookDecision> {
+        self.call("on-hook", |b, s| {
+            b.call_on_hook(
+                s,
+                hook.session_id.as_deref(),
+                &hook.hook_type,
+                hook.project_path.as_deref(),
+            )
+        })
+        .unwrap_or_else(|e| {
+            ctx.log_error(&e.to_string());
+            None
+        })
+        .map(HookDecision::context)
+    }
+
+    fn on_event(
+        &mut self,
+        event: RawEvent,
+        ctx: &mut PluginContext,
+    ) -> Vec<PluginAssessmentResult> {
+        let result = serde_json::to_string(&event)
+            .map_err(|e| PluginError::Json(e.to_string()))
+            .and_then(|json| self.call("on-event", |b, s| b.call_on_event(s, &json)))
+            .and_then(|json| {
+                serde_json::from_str(&json).map_err(|e| PluginError::Json(e.to_string()))
+            });
+
+        result.unwrap_or_else(|e| {
+            ctx.log_error(&e.to_string());
+            Vec::new()
+        })
+    }
+
+    fn query_assessment_results(
+        &self,
+        query: AssessmentQuery,
+        ctx: &PluginContext,
+    ) -> AssessmentQueryResponse {
+        let result = serde_json::to_string(&query)
+            .map_err(|e| PluginError::Json(e.to_string()))
+            .and_then(|json| {
+                self.call("query-assessments", |b, s| {
+                    b.call_query_assessments(s, &json)
+                })
+            })
+            .and_then(|json| {
+                serde_json::from_str(&json).map_err(|e| PluginError::Json(e.to_string()))
+            });
+
+        result.unwrap_or_else(|e| {
+            ctx.log_error(&e.to_string());
+            AssessmentQueryResponse::default()
+        })
+    }
+
+    fn handle_command(
+        &mut self,
+        path: &[&str],
+        args: &CommandArgs,
+        _ctx: &mut PluginContext,
+    ) -> Result<CommandOutput, PluginError> {
+        let path: Vec<String> = path.iter().map(|p| p.to_string()).collect();
+        let args = wit::CommandArgs {
+            args: args.args.clone(),
+            flags: args
+                .flags
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        };
+
+        let output = self
+            .call("handle-command", |b, s| {
+                b.call_handle_command(s, &path, &args)
+            })?
+            .map_err(PluginError::Command)?;
+
+        Ok(match output {
+            wit::CommandOutput::Text(text) => CommandOutput::Text(text),
+            wit::CommandOutput::Table(table) => CommandOutput::Table {
+                headers: table.headers,
+                rows: table.rows,
+            },
+            wit::CommandOutput::Success => CommandOutput::Success,
+            wit::CommandOutput::Exit(code) => CommandOutput::Exit(code),
+        })
+    }
+
+    fn handle_route(
+        &mut self,
+        method: HttpMethod,
+        path: &str,
+        request: RouteRequest,
+        _ctx: &mut PluginContext,
+    ) -> Result<RouteResponse, PluginError> {
+        let request = wit::RouteRequest {
+            params: request.params.into_iter().collect(),
+            query: request.query.into_iter().collect(),
+            headers: request.headers.into_iter().collect(),
+            body: request.body,
+        };
+
+        let response = self
+            .call("handle-route", |b, s| {
+                b.call_handle_route(s, method.into(), path, &request)
+            })?
+            .map_err(PluginError::Custom)?;
+
+        Ok(RouteResponse {
+            status: response.status,
+            body: response.body,
+            content_type: response.content_type,
+        })
+    }
+}
+
+/// Read a plugin's config.toml as a JSON object (empty if missing)
+fn load_config_json(path: &Path) -> Result<String, PluginError> {
+    let value = match std::fs::read_to_string(path) {
+        Ok(contents) => toml::from_str::<toml::Value>(&contents)
+            .map_err(|e| PluginError::config(format!("{}: {}", path.display(), e)))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            toml::Value::Table(Default::default())
+        }
+        Err(e) => return Err(e.into()),
+    };
+    serde_json::to_string(&value).map_err(|e| PluginError::Json(e.to_string()))
+}
+
+fn command_spec(command: wit::CommandSpec) -> CommandSpec {
+    CommandSpec {
+        path: command.path,
+        description: command.description,
+        args: command
+            .args
+            .into_iter()
+            .map(|arg| ArgSpec {
+                name: arg.name,
+                description: arg.description,
+                required: arg.required,
+            })
+            .collect(),
+    }
+}
+
+impl From<wit::HttpMethod> for HttpMethod {
+    fn from(method: wit::HttpMethod) -> Self {
+        match method {
+            wit::HttpMethod::Get => HttpMethod::Get,
+            wit::HttpMethod::Post => HttpMethod::Post,
+            wit::HttpMethod::Put => HttpMethod::Put,
+            wit::HttpMethod::Delete => HttpMethod::Delete,
+            wit::HttpMethod::Patch => HttpMethod::Patch,
+        }
+    }
+}
+
+impl From<HttpMethod> for wit::HttpMethod {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Get => wit::HttpMethod::Get,
+            HttpMethod::Post => wit::HttpMethod::Post,
+            HttpMethod::Put => wit::HttpMethod::Put,
+            HttpMethod::Delete => wit::HttpMethod::Delete,
+            HttpMethod::Patch => wit::HttpMethod::Patch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn load_fixture(limits: WasmLimits) -> WasmPlugin {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/wasm/fixture-plugin.wat");
+        WasmRuntime::new(limits)
+            .unwrap()
+            .load(&path, "wasm-fixture")
+            .unwrap()
+    }
+
+    fn hook(hook_type: &str) -> HookInput {
+        HookInput {
+            session_id: Some("sess-1".to_string()),
+            hook_type: hook_type.to_string(),
+            project_path: None,
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    fn event() -> RawEvent {
+        RawEvent::new(
+            [0; 16],
+            0,
+            None,
+            "SessionCreated".to_string(),
+            "{}".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_wasm_limits_default() {
+        let limits = WasmLimits::default();
+        assert_eq!(limits.max_memory_bytes, 64 * 1024 * 1024);
+        assert!(limits.fuel_per_call > 0);
+    }
+
+    #[test]
+    fn test_runtime_rejects_invalid_component() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("broken.wasm");
+        std::fs::write(&path, b"not wasm").unwrap();
+
+        let runtime = WasmRuntime::new(WasmLimits::default()).unwrap();
+        let result = runtime.load(&path, "broken");
+        assert!(matches!(result, Err(PluginHostError::Wasm(_))));
+    }
+
+    #[test]
+    fn test_load_fixture_component() {
+        let dir = TempDir::new().unwrap();
+        let mut plugin = load_fixture(WasmLimits::default());
+        let manifest = plugin.manifest();
+        assert_eq!(manifest.name, "wasm-fixture");
+        assert_eq!(manifest.version, "0.1.0");
+        assert_eq!(manifest.permissions, vec![Permission::AnswerHooks]);
+
+        let mut ctx = PluginContext::new("wasm-fixture".to_string(), dir.path().to_path_buf());
+        plugin.on_load(&mut ctx).unwrap();
+        plugin.on_unload().unwrap();
+    }
+
+    #[test]
+    fn test_call_passes_arguments_and_results() {
+        let dir = TempDir::new().unwrap();
+        let mut ctx = PluginContext::new("wasm-fixture".to_string(), dir.path().to_path_buf());
+        let mut plugin = load_fixture(WasmLimits::default());
+
+        let decision = plugin.on_hook(&hook("Stop"), &mut ctx).unwrap();
+        assert_eq!(decision.additional_context.as_deref(), Some("Stop"));
+        assert!(plugin.fault().get().is_none());
+    }
+
+    #[test]
+    fn test_fuel_exhaustion_faults_plugin() {
+        let dir = TempDir::new().unwrap();
+        let mut ctx = PluginContext::new("wasm-fixture".to_string(), dir.path().to_path_buf());
+        let mut plugin = load_fixture(WasmLimits {
+            fuel_per_call: 100_000,
+            ..WasmLimits::default()
+        });
+
+        let result = plugin.handle_command(&["spin"], &CommandArgs::default(), &mut ctx);
+        assert!(result.is_err());
+        assert!(
+            plugin
+                .fault()
+                .get()
+                .is_some_and(|fault| fault.starts_with("handle-command trapped"))
+        );
+
+        // A faulted instance is not entered again
+        assert!(plugin.on_hook(&hook("Stop"), &mut ctx).is_none());
+    }
+
+    #[test]
+    fn test_memory_growth_within_cap() {
+        let dir = TempDir::new().unwrap();
+        let mut ctx = PluginContext::new("wasm-fixture".to_string(), dir.path().to_path_buf());
+        let mut plugin = load_fixture(WasmLimits::default());
+
+        assert!(plugin.on_event(event(), &mut ctx).is_empty());
+        assert!(plugin.fault().get().is_none());
+    }
+
+    #[test]
+    fn test_memory_cap_faults_plugin() {
+        let dir = TempDir::new().unwrap();
+        let mut ctx = PluginContext::new("wasm-fixture".to_string(), dir.path().to_path_buf());
+        let mut plugin = load_fixture(WasmLimits {
+            max_memory_bytes: 1024 * 1024,
+            ..WasmLimits::default()
+        });
+
+        assert!(plugin.on_event(event(), &mut ctx).is_empty());
+        assert!(
+            plugin
+                .fault()
+                .get()
+                .is_some_and(|fault| fault.starts_with("on-event trapped"))
+        );
+    }
+
+    #[test]
+    fn test_manifest_optional_fields_default() {
+        let manifest: WasmManifest =
+            serde_json::from_str(r#"{"name": "demo", "version": "0.1.0"}"#).unwrap();
+        let manifest = manifest.into_manifest();
+        assert_eq!(manifest.name, "demo");
+        assert!(manifest.description.is_empty());
+        assert!(manifest.permissions.is_empty());
+    }
+
+    #[test]
+    fn test_manifest_parses_permissions() {
+        let manifest: WasmManifest = serde_json::from_str(
+            r#"{"name": "demo", "version": "0.1.0",
+                "permissions": [{"type": "register_routes"}, {"type": "answer_hooks"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.into_manifest().permissions,
+            vec![Permission::RegisterRoutes, Permission::AnswerHooks]
+        );
+    }
+
+    #[test]
+    fn test_load_config_json_missing_file_is_empty_object() {
+        let dir = TempDir::new().unwrap();
+        let json = load_config_json(&dir.path().join("config.toml")).unwrap();
+        assert_eq!(json, "{}");
+    }
+
+    #[test]
+    fn test_load_config_json_converts_toml() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "threshold = 3\n[nested]\nkey = \"v\"\n").unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&load_config_json(&path).unwrap()).unwrap();
+        assert_eq!(json["threshold"], 3);
+        assert_eq!(json["nested"]["key"], "v");
+    }
+
+    #[test]
+    fn test_http_method_roundtrip() {
+        for method in [
+            HttpMethod::Get,
+            HttpMethod::Post,
+            HttpMethod::Put,
+            HttpMethod::Delete,
+            HttpMethod::Patch,
+        ] {
+            let wit_method: wit::HttpMethod = method.into();
+            assert_eq!(HttpMethod::from(wit_method), method);
+        }
+    }
+}
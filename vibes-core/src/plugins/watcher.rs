@@ -0,0 +1,186 @@
+//! File watcher that hot-reloads plugins when their files change
+
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher, recommended_watcher};
+use tokio::sync::{RwLock, mpsc};
+
+use super::error::PluginHostError;
+use super::host::PluginHost;
+
+/// Default quiet period before reloading, long enough for a build to finish
+/// writing a library
+pub const DEFAULT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches plugin directories and reloads plugins whose files change
+///
+/// Changes are debounced: once a quiet period passes, every plugin touched
+/// since the first change is reloaded with [`PluginHost::reload_plugin`].
+pub struct PluginWatcher {
+    _watcher: notify::RecommendedWatcher,
+    _task: tokio::task::JoinHandle<()>,
+}
+
+impl PluginWatcher {
+    /// Start watching the host's plugin directories
+    ///
+    /// Directories that do not exist yet are not watched.
+    pub async fn new(
+        host: Arc<RwLock<PluginHost>>,
+        debounce: Duration,
+    ) -> Result<Self, PluginHostError> {
+        let plugin_dirs = host.read().await.plugin_dirs().to_vec();
+
+        let (tx, rx) = mpsc::channel::<notify::Result<notify::Event>>(100);
+        let mut watcher = recommended_watcher(move |event| {
+            // Use blocking_send since this callback runs in the notify thread
+            let _ = tx.blocking_send(event);
+        })?;
+
+        for dir in &plugin_dirs {
+            if dir.exists() {
+                tracing::debug!(dir = %dir.display(), "Watching plugin directory");
+                watcher.watch(dir, RecursiveMode::Recursive)?;
+            }
+        }
+
+        let task = tokio::spawn(Self::debounce_loop(rx, host, plugin_dirs, debounce));
+
+        Ok(Self {
+            _watcher: watcher,
+            _task: task,
+        })
+    }
+
+    /// Collect changed plugins until a quiet period passes, then reload them
+    async fn debounce_loop(
+        mut rx: mpsc::Receiver<notify::Result<notify::Event>>,
+        host: Arc<RwLock<PluginHost>>,
+        plugin_dirs: Vec<PathBuf>,
+        debounce: Duration,
+    ) {
+        loop {
+            // Wait for first event
+            let Some(event) = rx.recv().await else {
+                break;
+            };
+
+            let mut changed = BTreeSet::new();
+            collect_changed(&event, &plugin_dirs, &mut changed);
+
+            // Drain any pending events and wait for quiet period
+            loop {
+                match tokio::time::timeout(debounce, rx.recv()).await {
+                    Ok(Some(event)) => collect_changed(&event, &plugin_dirs, &mut changed),
+                    Ok(None) => return,
+                    Err(_) => break,
+                }
+            }
+
+            for name in changed {
+                tracing::info!(plugin = %name, "Plugin files changed, reloading");
+                if let Err(e) = host.write().await.reload_plugin(&name) {
+                    tracing::warn!(plugin = %name, error = %e, "Plugin hot reload failed");
+                }
+            }
+        }
+    }
+}
+
+/// Add the plugins touched by a watch event to `changed`
+fn collect_changed(
+    event: &notify::Result<notify::Event>,
+    plugin_dirs: &[PathBuf],
+    changed: &mut BTreeSet<String>,
+) {
+    match event {
+        Ok(event) if !event.kind.is_access() => {
+            changed.extend(
+                event
+                    .paths
+                    .iter()
+                    .filter_map(|path| plugin_for_path(path, plugin_dirs)),
+            );
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!(error = %e, "Plugin watcher error"),
+    }
+}
+
+/// Name of the plugin a file belongs to, if it is inside a plugin directory
+///
+/// Only files within `<plugin_dir>/<name>/` count, so changes to
+/// `registry.toml` at the top level do not trigger a reload.
+fn plugin_for_path(path: &Path, plugin_dirs: &[PathBuf]) -> Option<String> {
+    plugin_dirs.iter().find_map(|base| {
+        let mut components = path.strip_prefix(base).ok()?.components();
+        let name = match components.next()? {
+            Component::Normal(name) => name.to_str()?,
+            _ => return None,
+        };
+        components.next()?;
+        Some(name.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::PluginHostConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plugin_for_path() {
+        let dirs = vec![
+            PathBuf::from("/project/.vibes/plugins"),
+            PathBuf::from("/home/u/.config/vibes/plugins"),
+        ];
+
+        assert_eq!(
+            plugin_for_path(Path::new("/project/.vibes/plugins/groove/groove.so"), &dirs),
+            Some("groove".to_string())
+        );
+        assert_eq!(
+            plugin_for_path(
+                Path::new("/home/u/.config/vibes/plugins/hello/config.toml"),
+                &dirs
+            ),
+            Some("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_plugin_for_path_ignores_top_level_and_outside_files() {
+        let dirs = vec![PathBuf::from("/plugins")];
+
+        assert_eq!(
+            plugin_for_path(Path::new("/plugins/registry.toml"), &dirs),
+            None
+        );
+        assert_eq!(plugin_for_path(Path::new("/plugins"), &dirs), None);
+        assert_eq!(
+            plugin_for_path(Path::new("/other/groove/x.so"), &dirs),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watcher_starts_on_missing_and_existing_dirs() {
+        let dir = TempDir::new().unwrap();
+        let config = PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            project_plugin_dir: Some(dir.path().join("missing")),
+            ..Default::default()
+        };
+        let host = Arc::new(RwLock::new(PluginHost::new(config)));
+
+        assert!(
+            PluginWatcher::new(host, DEFAULT_RELOAD_DEBOUNCE)
+                .await
+                .is_ok()
+        );
+    }
+}
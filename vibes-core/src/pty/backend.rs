@@ -18,6 +18,7 @@ pub trait PtyBackend: Send + Sync {
     /// Create a new PTY session
     ///
     /// If cols/rows are provided, they override the config defaults.
+    /// `harness` selects the agent to run; `None` runs Claude Code.
     fn create_session(
         &self,
         id: String,
@@ -25,6 +26,7 @@ pub trait PtyBackend: Send + Sync {
         cwd: Option<String>,
         cols: Option<u16>,
         rows: Option<u16>,
+        harness: Option<&str>,
     ) -> Result<PtySession, PtyError>;
 }
 
@@ -48,10 +50,12 @@ impl PtyBackend for RealPtyBackend {
         cwd: Option<String>,
         cols: Option<u16>,
         rows: Option<u16>,
+        harness: Option<&str>,
     ) -> Result<PtySession, PtyError> {
         // Use provided dimensions or fall back to config defaults
         let actual_cols = cols.unwrap_or(self.config.initial_cols);
         let actual_rows = rows.unwrap_or(self.config.initial_rows);
+        let (program, args) = self.config.command_for(harness);
 
         tracing::info!(
             id = %id,
@@ -59,7 +63,7 @@ impl PtyBackend for RealPtyBackend {
             cwd = ?cwd,
             cols = actual_cols,
             rows = actual_rows,
-            command = %self.config.claude_path.display(),
+            command = %program.display(),
             "Spawning real PTY session"
         );
 
@@ -74,8 +78,8 @@ impl PtyBackend for RealPtyBackend {
             })
             .map_err(|e| PtyError::CreateFailed(e.to_string()))?;
 
-        let mut cmd = CommandBuilder::new(&self.config.claude_path);
-        for arg in &self.config.claude_args {
+        let mut cmd = CommandBuilder::new(&program);
+        for arg in &args {
             cmd.arg(arg);
         }
 
@@ -178,6 +182,7 @@ impl PtyBackend for MockPtyBackend {
         cwd: Option<String>,
         cols: Option<u16>,
         rows: Option<u16>,
+        harness: Option<&str>,
     ) -> Result<PtySession, PtyError> {
         // Use provided dimensions or defaults for mock
         let actual_cols = cols.unwrap_or(80);
@@ -189,6 +194,7 @@ impl PtyBackend for MockPtyBackend {
             cwd = ?cwd,
             cols = actual_cols,
             rows = actual_rows,
+            harness = ?harness,
             "Creating mock PTY session (no real process)"
         );
 
@@ -264,6 +270,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         assert!(session.is_ok());
         let session = session.unwrap();
@@ -291,6 +298,7 @@ mod tests {
             cwd,
             None,
             None,
+            None,
         );
         assert!(session.is_ok());
         let session = session.unwrap();
@@ -311,7 +319,7 @@ mod tests {
         };
         let backend = RealPtyBackend::new(config);
         let session = backend
-            .create_session("test-env".to_string(), None, None, None, None)
+            .create_session("test-env".to_string(), None, None, None, None, None)
             .expect("Failed to create session");
 
         // Give the process time to run and produce output
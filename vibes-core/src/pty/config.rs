@@ -55,3 +55,37 @@ impl Default for PtyConfig {
         }
     }
 }
+
+impl PtyConfig {
+    /// Program and arguments to spawn for a harness
+    ///
+    /// Claude Code (`None` or "claude") uses the configured path and arguments;
+    /// other harnesses run the command of the same name.
+    pub fn command_for(&self, harness: Option<&str>) -> (PathBuf, Vec<String>) {
+        match harness {
+            None | Some("claude") => (self.claude_path.clone(), self.claude_args.clone()),
+            Some(command) => (PathBuf::from(command), Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_for_claude_uses_configured_command() {
+        let config = PtyConfig {
+            claude_path: "cat".into(),
+            claude_args: vec!["-u".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.command_for(None),
+            ("cat".into(), vec!["-u".to_string()])
+        );
+        assert_eq!(config.command_for(Some("claude")), config.command_for(None));
+        assert_eq!(config.command_for(Some("codex")), ("codex".into(), vec![]));
+    }
+}
@@ -10,6 +10,9 @@ pub enum PtyError {
     #[error("Failed to spawn process: {0}")]
     SpawnFailed(String),
 
+    #[error("Unknown harness: {0}")]
+    UnknownHarness(String),
+
     #[error("Session not found: {0}")]
     SessionNotFound(String),
 
@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use tracing::instrument;
 use uuid::Uuid;
+use vibes_introspection::harness_for_command;
 
 use super::backend::{PtyBackend, create_backend};
 use super::session::PtyState;
@@ -50,12 +51,13 @@ impl PtyManager {
         cwd: Option<String>,
     ) -> Result<String, PtyError> {
         let id = Uuid::new_v4().to_string();
-        self.create_session_with_id(id, name, cwd, None, None)
+        self.create_session_with_id(id, name, cwd, None, None, None)
     }
 
     /// Create a new PTY session with a specific ID and optional dimensions
     ///
     /// If cols/rows are provided, they override the config defaults.
+    /// `harness` names the agent to run (e.g. "aider"); `None` runs Claude Code.
     #[instrument(name = "pty::create_session_with_id", skip(self), fields(session_id = %id))]
     pub fn create_session_with_id(
         &mut self,
@@ -64,10 +66,18 @@ impl PtyManager {
         cwd: Option<String>,
         cols: Option<u16>,
         rows: Option<u16>,
+        harness: Option<&str>,
     ) -> Result<String, PtyError> {
+        // Only spawn agents we have an adapter for
+        if let Some(harness) = harness
+            && harness_for_command(harness).is_none()
+        {
+            return Err(PtyError::UnknownHarness(harness.to_string()));
+        }
+
         let session = self
             .backend
-            .create_session(id.clone(), name, cwd, cols, rows)?;
+            .create_session(id.clone(), name, cwd, cols, rows, harness)?;
         self.sessions.insert(id.clone(), session);
         Ok(id)
     }
@@ -124,6 +134,7 @@ impl PtyManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pty::MockPtyBackend;
 
     fn test_config() -> PtyConfig {
         PtyConfig {
@@ -190,6 +201,21 @@ mod tests {
         assert!(handle2.is_some());
     }
 
+    #[test]
+    fn create_session_with_unknown_harness_fails() {
+        let mut manager = PtyManager::with_backend(Box::new(MockPtyBackend::new()));
+
+        let result =
+            manager.create_session_with_id("id".into(), None, None, None, None, Some("rm"));
+        assert!(matches!(result, Err(PtyError::UnknownHarness(h)) if h == "rm"));
+        assert_eq!(manager.session_count(), 0);
+
+        manager
+            .create_session_with_id("id".into(), None, None, None, None, Some("aider"))
+            .unwrap();
+        assert_eq!(manager.session_count(), 1);
+    }
+
     #[tokio::test]
     async fn kill_session_removes_and_kills() {
         let mut manager = PtyManager::new(test_config());
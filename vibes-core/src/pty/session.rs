@@ -162,7 +162,7 @@ mod tests {
     #[test]
     fn backend_creates_running_session() {
         let backend = RealPtyBackend::new(test_config());
-        let session = backend.create_session("test-id".to_string(), None, None, None, None);
+        let session = backend.create_session("test-id".to_string(), None, None, None, None, None);
         assert!(session.is_ok());
 
         let session = session.unwrap();
@@ -180,6 +180,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -193,7 +194,7 @@ mod tests {
             ..Default::default()
         };
         let backend = RealPtyBackend::new(config);
-        let result = backend.create_session("test-id".to_string(), None, None, None, None);
+        let result = backend.create_session("test-id".to_string(), None, None, None, None, None);
         assert!(result.is_err());
     }
 
@@ -201,7 +202,7 @@ mod tests {
     async fn write_and_read_data() {
         let backend = RealPtyBackend::new(test_config());
         let session = backend
-            .create_session("test-id".to_string(), None, None, None, None)
+            .create_session("test-id".to_string(), None, None, None, None, None)
             .unwrap();
 
         // Write some data
@@ -219,7 +220,7 @@ mod tests {
     async fn resize_pty() {
         let backend = RealPtyBackend::new(test_config());
         let session = backend
-            .create_session("test-id".to_string(), None, None, None, None)
+            .create_session("test-id".to_string(), None, None, None, None, None)
             .unwrap();
 
         // Resize should not error
@@ -231,7 +232,7 @@ mod tests {
     async fn handle_provides_scrollback_access() {
         let backend = RealPtyBackend::new(test_config());
         let session = backend
-            .create_session("test-id".to_string(), None, None, None, None)
+            .create_session("test-id".to_string(), None, None, None, None, None)
             .unwrap();
 
         // Write data and read it back (cat echoes)
@@ -0,0 +1,146 @@
+;; Minimal component implementing the `vibes-plugin` world, used by the WASM
+;; runtime tests. Wasmtime compiles the text format directly.
+;;
+;; - `on-hook` echoes the hook type back as context
+;; - `on-event` grows memory by 16 MiB and traps if the host refuses
+;; - `handle-command` spins forever, so it only returns by running out of fuel
+(component
+  (core module $m
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 1024))
+
+    ;; Bump allocator: nothing handed out is ever freed
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+
+    (func (export "api-version") (result i32)
+      (i32.const 1))
+
+    (func (export "manifest") (result i32)
+      (i32.const 0))
+
+    ;; ok(registrations) with no commands or routes
+    (func (export "on-load") (param i32 i32) (result i32)
+      (i32.const 48))
+
+    (func (export "on-unload"))
+
+    (func (export "on-event") (param i32 i32) (result i32)
+      (if (i32.eq (memory.grow (i32.const 256)) (i32.const -1))
+        (then (unreachable)))
+      (i32.const 16))
+
+    ;; some(hook-type)
+    (func (export "on-hook") (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+      (i32.store8 (i32.const 80) (i32.const 1))
+      (i32.store (i32.const 84) (local.get 3))
+      (i32.store (i32.const 88) (local.get 4))
+      (i32.const 80))
+
+    (func (export "query-assessments") (param i32 i32) (result i32)
+      (i32.const 32))
+
+    (func (export "handle-command") (param i32 i32 i32 i32 i32 i32) (result i32)
+      (loop $spin (br $spin))
+      (unreachable))
+
+    (func (export "handle-route")
+      (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+      (unreachable))
+
+    ;; Return areas: (ptr, len) pairs for the manifest, "[]" and "{}"
+    (data (i32.const 0) "\00\01\00\00\51\00\00\00")
+    (data (i32.const 16) "\00\02\00\00\02\00\00\00")
+    (data (i32.const 32) "\08\02\00\00\02\00\00\00")
+    (data (i32.const 256)
+      "{\"name\":\"wasm-fixture\",\"version\":\"0.1.0\",\"permissions\":[{\"type\":\"answer_hooks\"}]}")
+    (data (i32.const 512) "[]")
+    (data (i32.const 520) "{}")
+  )
+  (core instance $i (instantiate $m))
+  (alias core export $i "memory" (core memory $mem))
+  (alias core export $i "realloc" (core func $realloc))
+
+  (type $arg-spec' (record
+    (field "name" string)
+    (field "description" string)
+    (field "required" bool)))
+  (export $arg-spec "arg-spec" (type $arg-spec'))
+  (type $command-spec' (record
+    (field "path" (list string))
+    (field "description" string)
+    (field "args" (list $arg-spec))))
+  (export $command-spec "command-spec" (type $command-spec'))
+  (type $http-method' (enum "get" "post" "put" "delete" "patch"))
+  (export $http-method "http-method" (type $http-method'))
+  (type $route-spec' (record
+    (field "method" $http-method)
+    (field "path" string)))
+  (export $route-spec "route-spec" (type $route-spec'))
+  (type $registrations' (record
+    (field "commands" (list $command-spec))
+    (field "routes" (list $route-spec))))
+  (export $registrations "registrations" (type $registrations'))
+  (type $command-args' (record
+    (field "args" (list string))
+    (field "flags" (list (tuple string string)))))
+  (export $command-args "command-args" (type $command-args'))
+  (type $table' (record
+    (field "headers" (list string))
+    (field "rows" (list (list string)))))
+  (export $table "table" (type $table'))
+  (type $command-output' (variant
+    (case "text" string)
+    (case "table" $table)
+    (case "success")
+    (case "exit" s32)))
+  (export $command-output "command-output" (type $command-output'))
+  (type $route-request' (record
+    (field "params" (list (tuple string string)))
+    (field "query" (list (tuple string string)))
+    (field "headers" (list (tuple string string)))
+    (field "body" (list u8))))
+  (export $route-request "route-request" (type $route-request'))
+  (type $route-response' (record
+    (field "status" u16)
+    (field "body" (list u8))
+    (field "content-type" string)))
+  (export $route-response "route-response" (type $route-response'))
+
+  (func (export "api-version") (result u32)
+    (canon lift (core func $i "api-version")))
+  (func (export "manifest") (result string)
+    (canon lift (core func $i "manifest") (memory $mem)))
+  (func (export "on-load") (param "config" string)
+    (result (result $registrations (error string)))
+    (canon lift (core func $i "on-load") (memory $mem) (realloc $realloc)))
+  (func (export "on-unload")
+    (canon lift (core func $i "on-unload")))
+  (func (export "on-event") (param "event" string) (result string)
+    (canon lift (core func $i "on-event") (memory $mem) (realloc $realloc)))
+  (func (export "on-hook")
+    (param "session-id" (option string))
+    (param "hook-type" string)
+    (param "project-path" (option string))
+    (result (option string))
+    (canon lift (core func $i "on-hook") (memory $mem) (realloc $realloc)))
+  (func (export "query-assessments") (param "query" string) (result string)
+    (canon lift (core func $i "query-assessments") (memory $mem) (realloc $realloc)))
+  (func (export "handle-command")
+    (param "path" (list string))
+    (param "args" $command-args)
+    (result (result $command-output (error string)))
+    (canon lift (core func $i "handle-command") (memory $mem) (realloc $realloc)))
+  (func (export "handle-route")
+    (param "method" $http-method)
+    (param "path" string)
+    (param "request" $route-request)
+    (result (result $route-response (error string)))
+    (canon lift (core func $i "handle-route") (memory $mem) (realloc $realloc)))
+)
@@ -0,0 +1,135 @@
+//! Aider harness implementation
+//!
+//! Aider reads `.aider.conf.yml` from the home directory and the project
+//! root, and picks up project conventions from `CONVENTIONS.md` (loaded with
+//! `read: CONVENTIONS.md`). It has no hook system.
+
+use crate::detect::{config_file, injection_target, parse_version, scoped, version_output};
+use crate::{
+    ConfigFormat, ConfigPaths, Harness, HarnessCapabilities, InjectionScope, IntrospectionError,
+    Result, ScopedCapabilities,
+};
+use async_trait::async_trait;
+use std::path::Path;
+
+/// Aider's config file name, in the home directory or project root
+const CONFIG_FILE: &str = ".aider.conf.yml";
+
+/// Conventions file aider is pointed at for project guidance
+const CONVENTIONS_FILE: &str = "CONVENTIONS.md";
+
+/// Aider harness implementation
+#[derive(Debug, Clone, Default)]
+pub struct AiderHarness;
+
+#[async_trait]
+impl Harness for AiderHarness {
+    fn harness_type(&self) -> &'static str {
+        "aider"
+    }
+
+    async fn version(&self) -> Option<String> {
+        let output = version_output("aider").await?;
+        Some(parse_version(&output, &["aider "]))
+    }
+
+    fn config_paths(&self, project_root: Option<&Path>) -> Result<ConfigPaths> {
+        // Aider keeps its config as dotfiles rather than in a config directory
+        let user = dirs::home_dir().ok_or(IntrospectionError::NoHomeDir)?;
+        Ok(ConfigPaths {
+            system: None,
+            user,
+            project: project_root.map(Path::to_path_buf),
+        })
+    }
+
+    async fn introspect(&self, project_root: Option<&Path>) -> Result<HarnessCapabilities> {
+        let paths = self.config_paths(project_root)?;
+        let version = self.version().await;
+
+        let user = config_file(paths.user.join(CONFIG_FILE), ConfigFormat::Yaml)
+            .await
+            .map(|file| ScopedCapabilities {
+                config_files: vec![file],
+                ..Default::default()
+            })
+            .unwrap_or_default();
+
+        let project = match paths.project {
+            Some(root) => {
+                let config_files = config_file(root.join(CONFIG_FILE), ConfigFormat::Yaml).await;
+                let targets = injection_target(
+                    root.join(CONVENTIONS_FILE),
+                    ConfigFormat::Markdown,
+                    InjectionScope::Project,
+                )
+                .await;
+                scoped(
+                    config_files.into_iter().collect(),
+                    targets.into_iter().collect(),
+                )
+            }
+            None => None,
+        };
+
+        Ok(HarnessCapabilities {
+            harness_type: self.harness_type().to_string(),
+            version,
+            system: None,
+            user,
+            project,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    #[test]
+    fn test_harness_type_returns_aider() {
+        assert_eq!(AiderHarness.harness_type(), "aider");
+    }
+
+    #[test]
+    fn test_config_paths_use_project_root() {
+        let paths = AiderHarness
+            .config_paths(Some(Path::new("/tmp/my-project")))
+            .unwrap();
+        assert_eq!(paths.project, Some("/tmp/my-project".into()));
+        assert!(paths.system.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_introspect_with_empty_project() {
+        let temp = TempDir::new().unwrap();
+        let caps = AiderHarness.introspect(Some(temp.path())).await.unwrap();
+
+        assert_eq!(caps.harness_type, "aider");
+        assert!(caps.project.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_introspect_finds_config_and_conventions() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join(".aider.conf.yml"), "read: CONVENTIONS.md")
+            .await
+            .unwrap();
+        fs::write(temp.path().join("CONVENTIONS.md"), "# Conventions")
+            .await
+            .unwrap();
+
+        let caps = AiderHarness.introspect(Some(temp.path())).await.unwrap();
+
+        let project = caps.project.unwrap();
+        assert_eq!(project.config_files[0].format, ConfigFormat::Yaml);
+        assert!(
+            project.injection_targets[0]
+                .path
+                .ends_with("CONVENTIONS.md")
+        );
+        assert!(project.hooks.is_none());
+    }
+}
@@ -18,6 +18,7 @@ pub enum HookType {
 pub enum ConfigFormat {
     Json,
     Toml,
+    Yaml,
     Markdown,
 }
 
@@ -30,7 +31,7 @@ pub enum InjectionScope {
 }
 
 /// An installed hook we detected
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InstalledHook {
     pub hook_type: HookType,
     pub name: String,
@@ -51,6 +52,9 @@ pub struct ConfigFile {
     pub path: PathBuf,
     pub format: ConfigFormat,
     pub writable: bool,
+    /// Hash of the file content when detected, used to notice edits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<u64>,
 }
 
 /// A file we can inject learnings into
@@ -1,5 +1,6 @@
 //! Claude Code detection utilities for discovering capabilities
 
+use crate::detect::{content_hash, is_writable};
 use crate::{
     ConfigFile, ConfigFormat, HookCapabilities, HookType, InjectionScope, InjectionTarget,
     InstalledHook, ScopedCapabilities,
@@ -30,14 +31,23 @@ pub async fn detect_scope(base_path: &Path, scope: InjectionScope) -> Option<Sco
 }
 
 /// Detect hook capabilities at a base path
+///
+/// Hooks are either scripts in `hooks/` or commands registered in the
+/// `hooks` object of `settings.json`.
 pub async fn detect_hooks(base_path: &Path) -> Option<HookCapabilities> {
     let hooks_dir = base_path.join("hooks");
+    let settings_hooks = find_settings_hooks(&base_path.join("settings.json")).await;
 
-    if !hooks_dir.exists() {
+    if !hooks_dir.exists() && settings_hooks.is_empty() {
         return None;
     }
 
-    let installed_hooks = find_installed_hooks(&hooks_dir).await;
+    let mut installed_hooks = if hooks_dir.exists() {
+        find_installed_hooks(&hooks_dir).await
+    } else {
+        Vec::new()
+    };
+    installed_hooks.extend(settings_hooks);
 
     Some(HookCapabilities {
         supported_types: vec![
@@ -46,7 +56,7 @@ pub async fn detect_hooks(base_path: &Path) -> Option<HookCapabilities> {
             HookType::Stop,
             HookType::Notification,
         ],
-        hooks_dir: Some(hooks_dir),
+        hooks_dir: hooks_dir.exists().then_some(hooks_dir),
         installed_hooks,
     })
 }
@@ -62,6 +72,7 @@ pub async fn detect_config_files(base_path: &Path) -> Vec<ConfigFile> {
             path: settings_path.clone(),
             format: ConfigFormat::Json,
             writable: is_writable(&settings_path).await,
+            content_hash: content_hash(&settings_path).await,
         });
     }
 
@@ -72,6 +83,7 @@ pub async fn detect_config_files(base_path: &Path) -> Vec<ConfigFile> {
             path: clauderc_path.clone(),
             format: ConfigFormat::Json,
             writable: is_writable(&clauderc_path).await,
+            content_hash: content_hash(&clauderc_path).await,
         });
     }
 
@@ -115,6 +127,51 @@ pub async fn detect_injection_targets(
     targets
 }
 
+/// Find hook commands registered in settings.json
+///
+/// Each command becomes an [`InstalledHook`] named after the command, for the
+/// hook types vibes can observe.
+pub async fn find_settings_hooks(settings_path: &Path) -> Vec<InstalledHook> {
+    let Ok(content) = fs::read_to_string(settings_path).await else {
+        return Vec::new();
+    };
+    let Ok(settings) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+    let Some(hooks) = settings.get("hooks").and_then(|h| h.as_object()) else {
+        return Vec::new();
+    };
+
+    let mut installed = Vec::new();
+    for (event, matchers) in hooks {
+        let hook_type = match event.as_str() {
+            "PreToolUse" => HookType::PreToolUse,
+            "PostToolUse" => HookType::PostToolUse,
+            "Stop" => HookType::Stop,
+            "Notification" => HookType::Notification,
+            _ => continue,
+        };
+
+        let commands = matchers
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|matcher| matcher.get("hooks").and_then(|h| h.as_array()))
+            .flatten()
+            .filter_map(|hook| hook.get("command").and_then(|c| c.as_str()));
+
+        for command in commands {
+            installed.push(InstalledHook {
+                hook_type: hook_type.clone(),
+                name: command.to_string(),
+                path: settings_path.to_path_buf(),
+            });
+        }
+    }
+
+    installed
+}
+
 /// Find installed hooks in a hooks directory
 pub async fn find_installed_hooks(hooks_dir: &Path) -> Vec<InstalledHook> {
     let mut hooks = Vec::new();
@@ -162,17 +219,6 @@ pub async fn find_installed_hooks(hooks_dir: &Path) -> Vec<InstalledHook> {
     hooks
 }
 
-/// Check if a path is writable
-pub async fn is_writable(path: &Path) -> bool {
-    // Try to open the file for writing without truncating
-    fs::OpenOptions::new()
-        .write(true)
-        .create(false)
-        .open(path)
-        .await
-        .is_ok()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,6 +305,37 @@ mod tests {
         assert_eq!(hooks.supported_types.len(), 4);
     }
 
+    #[tokio::test]
+    async fn test_detect_hooks_reads_settings_json() {
+        let temp = create_test_dir().await;
+        let settings = r#"{
+            "hooks": {
+                "PreToolUse": [{"hooks": [{"type": "command", "command": "vibes hook pre-tool-use"}]}],
+                "SessionStart": [{"hooks": [{"type": "command", "command": "vibes hook session-start"}]}]
+            }
+        }"#;
+        fs::write(temp.path().join("settings.json"), settings)
+            .await
+            .unwrap();
+
+        let hooks = detect_hooks(temp.path()).await.unwrap();
+        assert!(hooks.hooks_dir.is_none());
+        assert_eq!(hooks.installed_hooks.len(), 1);
+        assert_eq!(hooks.installed_hooks[0].hook_type, HookType::PreToolUse);
+        assert_eq!(hooks.installed_hooks[0].name, "vibes hook pre-tool-use");
+        assert!(hooks.installed_hooks[0].path.ends_with("settings.json"));
+    }
+
+    #[tokio::test]
+    async fn test_find_settings_hooks_ignores_invalid_settings() {
+        let temp = create_test_dir().await;
+        let path = temp.path().join("settings.json");
+        assert!(find_settings_hooks(&path).await.is_empty());
+
+        fs::write(&path, "not json").await.unwrap();
+        assert!(find_settings_hooks(&path).await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_detect_config_files_finds_settings_json() {
         let temp = create_test_dir().await;
@@ -413,21 +490,4 @@ mod tests {
         let hooks = find_installed_hooks(&hooks_dir).await;
         assert_eq!(hooks.len(), 3);
     }
-
-    #[tokio::test]
-    async fn test_is_writable_returns_true_for_writable_file() {
-        let temp = create_test_dir().await;
-        let file_path = temp.path().join("test.txt");
-        fs::write(&file_path, "test").await.unwrap();
-
-        assert!(is_writable(&file_path).await);
-    }
-
-    #[tokio::test]
-    async fn test_is_writable_returns_false_for_nonexistent_file() {
-        let temp = create_test_dir().await;
-        let file_path = temp.path().join("nonexistent.txt");
-
-        assert!(!is_writable(&file_path).await);
-    }
 }
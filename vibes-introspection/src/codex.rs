@@ -0,0 +1,126 @@
+//! Codex CLI harness implementation
+//!
+//! Codex keeps `config.toml` and global instructions (`AGENTS.md`) in
+//! `~/.codex`, or `$CODEX_HOME` when set, and reads `AGENTS.md` from the
+//! project root. It has no hook system.
+
+use crate::detect::{config_file, injection_target, parse_version, scoped, version_output};
+use crate::{
+    ConfigFormat, ConfigPaths, Harness, HarnessCapabilities, InjectionScope, Result,
+    ScopedCapabilities,
+};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+/// Instructions file Codex reads at user and project scope
+const AGENTS_FILE: &str = "AGENTS.md";
+
+/// Codex CLI harness implementation
+#[derive(Debug, Clone, Default)]
+pub struct CodexHarness;
+
+#[async_trait]
+impl Harness for CodexHarness {
+    fn harness_type(&self) -> &'static str {
+        "codex"
+    }
+
+    async fn version(&self) -> Option<String> {
+        let output = version_output("codex").await?;
+        Some(parse_version(&output, &["codex-cli ", "codex "]))
+    }
+
+    fn config_paths(&self, project_root: Option<&Path>) -> Result<ConfigPaths> {
+        let mut paths = ConfigPaths::resolve("codex", project_root)?;
+        if let Some(home) = std::env::var_os("CODEX_HOME") {
+            paths.user = PathBuf::from(home);
+        }
+        // Codex has no system config, and project instructions live in the root
+        paths.system = None;
+        paths.project = project_root.map(Path::to_path_buf);
+        Ok(paths)
+    }
+
+    async fn introspect(&self, project_root: Option<&Path>) -> Result<HarnessCapabilities> {
+        let paths = self.config_paths(project_root)?;
+        let version = self.version().await;
+
+        let user_config = config_file(paths.user.join("config.toml"), ConfigFormat::Toml).await;
+        let user_targets = injection_target(
+            paths.user.join(AGENTS_FILE),
+            ConfigFormat::Markdown,
+            InjectionScope::User,
+        )
+        .await;
+        let user = ScopedCapabilities {
+            hooks: None,
+            config_files: user_config.into_iter().collect(),
+            injection_targets: user_targets.into_iter().collect(),
+        };
+
+        let project = match paths.project {
+            Some(root) => {
+                let targets = injection_target(
+                    root.join(AGENTS_FILE),
+                    ConfigFormat::Markdown,
+                    InjectionScope::Project,
+                )
+                .await;
+                scoped(vec![], targets.into_iter().collect())
+            }
+            None => None,
+        };
+
+        Ok(HarnessCapabilities {
+            harness_type: self.harness_type().to_string(),
+            version,
+            system: None,
+            user,
+            project,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    #[test]
+    fn test_harness_type_returns_codex() {
+        assert_eq!(CodexHarness.harness_type(), "codex");
+    }
+
+    #[test]
+    fn test_config_paths_use_project_root() {
+        let paths = CodexHarness
+            .config_paths(Some(Path::new("/tmp/my-project")))
+            .unwrap();
+        assert_eq!(paths.project, Some("/tmp/my-project".into()));
+    }
+
+    #[tokio::test]
+    async fn test_introspect_with_empty_project() {
+        let temp = TempDir::new().unwrap();
+        let caps = CodexHarness.introspect(Some(temp.path())).await.unwrap();
+
+        assert_eq!(caps.harness_type, "codex");
+        assert!(caps.project.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_introspect_finds_agents_md() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("AGENTS.md"), "# Agents")
+            .await
+            .unwrap();
+
+        let caps = CodexHarness.introspect(Some(temp.path())).await.unwrap();
+
+        let project = caps.project.unwrap();
+        assert_eq!(project.injection_targets.len(), 1);
+        assert!(project.injection_targets[0].path.ends_with("AGENTS.md"));
+        assert_eq!(project.injection_targets[0].scope, InjectionScope::Project);
+    }
+}
@@ -0,0 +1,176 @@
+//! File detection helpers shared by harness implementations
+
+use crate::{ConfigFile, ConfigFormat, InjectionScope, InjectionTarget, ScopedCapabilities};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::process::Command;
+
+/// Run `<binary> --version` and return its trimmed output
+pub async fn version_output(binary: &str) -> Option<String> {
+    let output = Command::new(binary).arg("--version").output().await.ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_line = stdout.lines().next()?.trim();
+    (!first_line.is_empty()).then(|| first_line.to_string())
+}
+
+/// Strip the first matching program-name prefix from version output
+pub fn parse_version(output: &str, prefixes: &[&str]) -> String {
+    let output = output.trim();
+    prefixes
+        .iter()
+        .find_map(|prefix| output.strip_prefix(prefix))
+        .unwrap_or(output)
+        .trim()
+        .to_string()
+}
+
+/// A config file at `path`, if it exists
+pub async fn config_file(path: PathBuf, format: ConfigFormat) -> Option<ConfigFile> {
+    if !path.is_file() {
+        return None;
+    }
+    let writable = is_writable(&path).await;
+    let content_hash = content_hash(&path).await;
+    Some(ConfigFile {
+        path,
+        format,
+        writable,
+        content_hash,
+    })
+}
+
+/// An injection target at `path`, if it exists
+pub async fn injection_target(
+    path: PathBuf,
+    format: ConfigFormat,
+    scope: InjectionScope,
+) -> Option<InjectionTarget> {
+    if !path.is_file() {
+        return None;
+    }
+    let writable = is_writable(&path).await;
+    Some(InjectionTarget {
+        path,
+        format,
+        writable,
+        scope,
+    })
+}
+
+/// Capabilities for a scope without hooks, or `None` if nothing was found
+pub fn scoped(
+    config_files: Vec<ConfigFile>,
+    injection_targets: Vec<InjectionTarget>,
+) -> Option<ScopedCapabilities> {
+    if config_files.is_empty() && injection_targets.is_empty() {
+        return None;
+    }
+
+    Some(ScopedCapabilities {
+        hooks: None,
+        config_files,
+        injection_targets,
+    })
+}
+
+/// Hash of a file's content, or `None` if it can't be read
+pub async fn content_hash(path: &Path) -> Option<u64> {
+    let content = fs::read(path).await.ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Check if a path is writable
+pub async fn is_writable(path: &Path) -> bool {
+    // Try to open the file for writing without truncating
+    fs::OpenOptions::new()
+        .write(true)
+        .create(false)
+        .open(path)
+        .await
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_version_strips_known_prefix() {
+        assert_eq!(parse_version("aider 0.82.1\n", &["aider "]), "0.82.1");
+        assert_eq!(
+            parse_version("codex-cli 0.21.0", &["codex-cli ", "codex "]),
+            "0.21.0"
+        );
+        assert_eq!(parse_version("0.1.18", &["gemini "]), "0.1.18");
+    }
+
+    #[tokio::test]
+    async fn test_config_file_requires_existing_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        assert!(
+            config_file(path.clone(), ConfigFormat::Toml)
+                .await
+                .is_none()
+        );
+
+        fs::write(&path, "model = \"o3\"").await.unwrap();
+        let file = config_file(path.clone(), ConfigFormat::Toml).await.unwrap();
+        assert_eq!(file.path, path);
+        assert!(file.writable);
+    }
+
+    #[tokio::test]
+    async fn test_injection_target_ignores_directories() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("AGENTS.md");
+        fs::create_dir(&path).await.unwrap();
+
+        let target = injection_target(path, ConfigFormat::Markdown, InjectionScope::Project).await;
+        assert!(target.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_content_hash_tracks_content() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("settings.json");
+        assert!(content_hash(&path).await.is_none());
+
+        fs::write(&path, "{}").await.unwrap();
+        let before = content_hash(&path).await;
+        fs::write(&path, r#"{"hooks": {}}"#).await.unwrap();
+        assert_ne!(content_hash(&path).await, before);
+    }
+
+    #[test]
+    fn test_scoped_is_none_when_empty() {
+        assert!(scoped(vec![], vec![]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_is_writable_returns_true_for_writable_file() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("test.txt");
+        fs::write(&file_path, "test").await.unwrap();
+
+        assert!(is_writable(&file_path).await);
+    }
+
+    #[tokio::test]
+    async fn test_is_writable_returns_false_for_nonexistent_file() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("nonexistent.txt");
+
+        assert!(!is_writable(&file_path).await);
+    }
+}
@@ -0,0 +1,339 @@
+//! Capability drift - what changed between two introspections
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{HarnessCapabilities, InjectionScope, InstalledHook, ScopedCapabilities};
+
+/// A single difference between two capability snapshots
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CapabilityChange {
+    /// The harness binary reports a different version
+    VersionChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// Hooks that were installed are gone
+    HooksRemoved {
+        scope: InjectionScope,
+        hooks: Vec<InstalledHook>,
+    },
+    /// New hooks appeared
+    HooksAdded {
+        scope: InjectionScope,
+        hooks: Vec<InstalledHook>,
+    },
+    /// A config file appeared
+    ConfigFileAdded { path: PathBuf },
+    /// A config file was deleted
+    ConfigFileRemoved { path: PathBuf },
+    /// A config file's content changed
+    ConfigFileChanged { path: PathBuf },
+    /// An injection target appeared
+    InjectionTargetAdded { path: PathBuf },
+    /// An injection target was deleted
+    InjectionTargetRemoved { path: PathBuf },
+}
+
+/// Everything that changed for a harness between two introspections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityDrift {
+    pub harness_type: String,
+    pub changes: Vec<CapabilityChange>,
+}
+
+impl CapabilityDrift {
+    /// Compare two snapshots of the same harness
+    pub fn between(old: &HarnessCapabilities, new: &HarnessCapabilities) -> Self {
+        let mut changes = Vec::new();
+
+        if old.version != new.version {
+            changes.push(CapabilityChange::VersionChanged {
+                from: old.version.clone(),
+                to: new.version.clone(),
+            });
+        }
+
+        let empty = ScopedCapabilities::default();
+        let scopes = [
+            (
+                InjectionScope::System,
+                old.system.as_ref(),
+                new.system.as_ref(),
+            ),
+            (InjectionScope::User, Some(&old.user), Some(&new.user)),
+            (
+                InjectionScope::Project,
+                old.project.as_ref(),
+                new.project.as_ref(),
+            ),
+        ];
+        for (scope, old, new) in scopes {
+            diff_scope(
+                scope,
+                old.unwrap_or(&empty),
+                new.unwrap_or(&empty),
+                &mut changes,
+            );
+        }
+
+        Self {
+            harness_type: new.harness_type.clone(),
+            changes,
+        }
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Hooks that disappeared, across all scopes
+    pub fn removed_hooks(&self) -> impl Iterator<Item = &InstalledHook> {
+        self.changes.iter().flat_map(|change| match change {
+            CapabilityChange::HooksRemoved { hooks, .. } => hooks.as_slice(),
+            _ => &[],
+        })
+    }
+
+    /// Whether a config file was added, removed or edited
+    pub fn config_changed(&self) -> bool {
+        self.changes.iter().any(|change| {
+            matches!(
+                change,
+                CapabilityChange::ConfigFileAdded { .. }
+                    | CapabilityChange::ConfigFileRemoved { .. }
+                    | CapabilityChange::ConfigFileChanged { .. }
+            )
+        })
+    }
+}
+
+fn diff_scope(
+    scope: InjectionScope,
+    old: &ScopedCapabilities,
+    new: &ScopedCapabilities,
+    changes: &mut Vec<CapabilityChange>,
+) {
+    let old_hooks = old
+        .hooks
+        .as_ref()
+        .map(|h| h.installed_hooks.as_slice())
+        .unwrap_or_default();
+    let new_hooks = new
+        .hooks
+        .as_ref()
+        .map(|h| h.installed_hooks.as_slice())
+        .unwrap_or_default();
+
+    let removed = missing_from(old_hooks, new_hooks);
+    if !removed.is_empty() {
+        changes.push(CapabilityChange::HooksRemoved {
+            scope: scope.clone(),
+            hooks: removed,
+        });
+    }
+    let added = missing_from(new_hooks, old_hooks);
+    if !added.is_empty() {
+        changes.push(CapabilityChange::HooksAdded {
+            scope,
+            hooks: added,
+        });
+    }
+
+    for file in &new.config_files {
+        match old.config_files.iter().find(|f| f.path == file.path) {
+            None => changes.push(CapabilityChange::ConfigFileAdded {
+                path: file.path.clone(),
+            }),
+            Some(previous) if previous.content_hash != file.content_hash => {
+                changes.push(CapabilityChange::ConfigFileChanged {
+                    path: file.path.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for file in &old.config_files {
+        if !new.config_files.iter().any(|f| f.path == file.path) {
+            changes.push(CapabilityChange::ConfigFileRemoved {
+                path: file.path.clone(),
+            });
+        }
+    }
+
+    for target in &new.injection_targets {
+        if !old.injection_targets.iter().any(|t| t.path == target.path) {
+            changes.push(CapabilityChange::InjectionTargetAdded {
+                path: target.path.clone(),
+            });
+        }
+    }
+    for target in &old.injection_targets {
+        if !new.injection_targets.iter().any(|t| t.path == target.path) {
+            changes.push(CapabilityChange::InjectionTargetRemoved {
+                path: target.path.clone(),
+            });
+        }
+    }
+}
+
+/// Hooks in `hooks` that are not in `other`
+fn missing_from(hooks: &[InstalledHook], other: &[InstalledHook]) -> Vec<InstalledHook> {
+    hooks
+        .iter()
+        .filter(|hook| !other.contains(hook))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConfigFile, ConfigFormat, HookCapabilities, HookType, InjectionTarget};
+
+    fn hook(name: &str) -> InstalledHook {
+        InstalledHook {
+            hook_type: HookType::PreToolUse,
+            name: name.to_string(),
+            path: PathBuf::from("/home/user/.claude/settings.json"),
+        }
+    }
+
+    fn settings(content_hash: u64) -> ConfigFile {
+        ConfigFile {
+            path: PathBuf::from("/home/user/.claude/settings.json"),
+            format: ConfigFormat::Json,
+            writable: true,
+            content_hash: Some(content_hash),
+        }
+    }
+
+    fn caps(
+        version: &str,
+        hooks: Vec<InstalledHook>,
+        files: Vec<ConfigFile>,
+    ) -> HarnessCapabilities {
+        HarnessCapabilities {
+            harness_type: "claude".to_string(),
+            version: Some(version.to_string()),
+            system: None,
+            user: ScopedCapabilities {
+                hooks: Some(HookCapabilities {
+                    installed_hooks: hooks,
+                    ..Default::default()
+                }),
+                config_files: files,
+                injection_targets: vec![],
+            },
+            project: None,
+        }
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_drift() {
+        let snapshot = caps(
+            "1.0.0",
+            vec![hook("vibes hook pre-tool-use")],
+            vec![settings(1)],
+        );
+        let drift = CapabilityDrift::between(&snapshot, &snapshot.clone());
+        assert!(drift.is_empty());
+        assert_eq!(drift.harness_type, "claude");
+    }
+
+    #[test]
+    fn test_detects_version_change() {
+        let drift = CapabilityDrift::between(
+            &caps("1.0.0", vec![], vec![]),
+            &caps("1.1.0", vec![], vec![]),
+        );
+        assert_eq!(
+            drift.changes,
+            vec![CapabilityChange::VersionChanged {
+                from: Some("1.0.0".to_string()),
+                to: Some("1.1.0".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_overwritten_settings_removes_hooks() {
+        let old = caps(
+            "1.0.0",
+            vec![hook("vibes hook pre-tool-use")],
+            vec![settings(1)],
+        );
+        let new = caps("1.0.0", vec![], vec![settings(2)]);
+
+        let drift = CapabilityDrift::between(&old, &new);
+
+        let removed: Vec<_> = drift.removed_hooks().collect();
+        assert_eq!(removed, vec![&hook("vibes hook pre-tool-use")]);
+        assert!(drift.config_changed());
+        assert!(
+            drift
+                .changes
+                .contains(&CapabilityChange::ConfigFileChanged {
+                    path: PathBuf::from("/home/user/.claude/settings.json"),
+                })
+        );
+    }
+
+    #[test]
+    fn test_detects_added_hooks_and_removed_files() {
+        let old = caps("1.0.0", vec![], vec![settings(1)]);
+        let new = caps("1.0.0", vec![hook("other-tool")], vec![]);
+
+        let drift = CapabilityDrift::between(&old, &new);
+
+        assert_eq!(drift.removed_hooks().count(), 0);
+        assert!(matches!(
+            &drift.changes[0],
+            CapabilityChange::HooksAdded { scope: InjectionScope::User, hooks } if hooks.len() == 1
+        ));
+        assert!(matches!(
+            &drift.changes[1],
+            CapabilityChange::ConfigFileRemoved { .. }
+        ));
+    }
+
+    #[test]
+    fn test_detects_project_injection_targets() {
+        let old = caps("1.0.0", vec![], vec![]);
+        let mut new = old.clone();
+        new.project = Some(ScopedCapabilities {
+            injection_targets: vec![InjectionTarget {
+                path: PathBuf::from("/work/CLAUDE.md"),
+                format: ConfigFormat::Markdown,
+                writable: true,
+                scope: InjectionScope::Project,
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            CapabilityDrift::between(&old, &new).changes,
+            vec![CapabilityChange::InjectionTargetAdded {
+                path: PathBuf::from("/work/CLAUDE.md"),
+            }]
+        );
+        assert_eq!(
+            CapabilityDrift::between(&new, &old).changes,
+            vec![CapabilityChange::InjectionTargetRemoved {
+                path: PathBuf::from("/work/CLAUDE.md"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_change_serializes_with_kind_tag() {
+        let change = CapabilityChange::ConfigFileChanged {
+            path: PathBuf::from("/a/settings.json"),
+        };
+        let json = serde_json::to_value(&change).unwrap();
+        assert_eq!(json["kind"], "config_file_changed");
+    }
+}
@@ -0,0 +1,132 @@
+//! Gemini CLI harness implementation
+//!
+//! Gemini CLI reads `settings.json` and `GEMINI.md` from `~/.gemini`, and
+//! from `.gemini/settings.json` and `GEMINI.md` in the project. It has no
+//! hook system.
+
+use crate::detect::{config_file, injection_target, parse_version, scoped, version_output};
+use crate::{
+    ConfigFormat, ConfigPaths, Harness, HarnessCapabilities, InjectionScope, Result,
+    ScopedCapabilities,
+};
+use async_trait::async_trait;
+use std::path::Path;
+
+/// Context file Gemini CLI loads at user and project scope
+const CONTEXT_FILE: &str = "GEMINI.md";
+
+/// Gemini CLI harness implementation
+#[derive(Debug, Clone, Default)]
+pub struct GeminiHarness;
+
+#[async_trait]
+impl Harness for GeminiHarness {
+    fn harness_type(&self) -> &'static str {
+        "gemini"
+    }
+
+    async fn version(&self) -> Option<String> {
+        let output = version_output("gemini").await?;
+        Some(parse_version(&output, &["gemini "]))
+    }
+
+    fn config_paths(&self, project_root: Option<&Path>) -> Result<ConfigPaths> {
+        ConfigPaths::resolve("gemini", project_root)
+    }
+
+    async fn introspect(&self, project_root: Option<&Path>) -> Result<HarnessCapabilities> {
+        let paths = self.config_paths(project_root)?;
+        let version = self.version().await;
+
+        let system = match paths.system {
+            Some(dir) => {
+                let config = config_file(dir.join("settings.json"), ConfigFormat::Json).await;
+                scoped(config.into_iter().collect(), vec![])
+            }
+            None => None,
+        };
+
+        let user_config = config_file(paths.user.join("settings.json"), ConfigFormat::Json).await;
+        let user_targets = injection_target(
+            paths.user.join(CONTEXT_FILE),
+            ConfigFormat::Markdown,
+            InjectionScope::User,
+        )
+        .await;
+        let user = ScopedCapabilities {
+            hooks: None,
+            config_files: user_config.into_iter().collect(),
+            injection_targets: user_targets.into_iter().collect(),
+        };
+
+        // Settings live in `.gemini/`, while GEMINI.md sits in the project root
+        let project = match (&paths.project, project_root) {
+            (Some(dir), Some(root)) => {
+                let config = config_file(dir.join("settings.json"), ConfigFormat::Json).await;
+                let targets = injection_target(
+                    root.join(CONTEXT_FILE),
+                    ConfigFormat::Markdown,
+                    InjectionScope::Project,
+                )
+                .await;
+                scoped(config.into_iter().collect(), targets.into_iter().collect())
+            }
+            _ => None,
+        };
+
+        Ok(HarnessCapabilities {
+            harness_type: self.harness_type().to_string(),
+            version,
+            system,
+            user,
+            project,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    #[test]
+    fn test_harness_type_returns_gemini() {
+        assert_eq!(GeminiHarness.harness_type(), "gemini");
+    }
+
+    #[test]
+    fn test_config_paths_with_project_root() {
+        let paths = GeminiHarness
+            .config_paths(Some(Path::new("/tmp/my-project")))
+            .unwrap();
+        assert_eq!(paths.project, Some("/tmp/my-project/.gemini".into()));
+    }
+
+    #[tokio::test]
+    async fn test_introspect_with_empty_project() {
+        let temp = TempDir::new().unwrap();
+        let caps = GeminiHarness.introspect(Some(temp.path())).await.unwrap();
+
+        assert_eq!(caps.harness_type, "gemini");
+        assert!(caps.project.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_introspect_finds_settings_and_context_file() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(".gemini")).await.unwrap();
+        fs::write(temp.path().join(".gemini").join("settings.json"), "{}")
+            .await
+            .unwrap();
+        fs::write(temp.path().join("GEMINI.md"), "# Context")
+            .await
+            .unwrap();
+
+        let caps = GeminiHarness.introspect(Some(temp.path())).await.unwrap();
+
+        let project = caps.project.unwrap();
+        assert!(project.config_files[0].path.ends_with("settings.json"));
+        assert!(project.injection_targets[0].path.ends_with("GEMINI.md"));
+    }
+}
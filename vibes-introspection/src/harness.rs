@@ -8,7 +8,7 @@ use std::sync::Arc;
 /// Core trait - any AI coding assistant we can enhance
 #[async_trait]
 pub trait Harness: Send + Sync {
-    /// Unique identifier (e.g., "claude", "cursor", "aider")
+    /// Unique identifier, also the command that launches it (e.g., "claude", "aider")
     fn harness_type(&self) -> &'static str;
 
     /// Detect version from binary or config
@@ -24,16 +24,14 @@ pub trait Harness: Send + Sync {
 /// Create the appropriate harness from CLI subcommand
 pub fn harness_for_command(command: &str) -> Option<Arc<dyn Harness>> {
     match command {
-        "claude" => {
-            #[cfg(feature = "claude-code")]
-            {
-                Some(Arc::new(crate::claude_code::ClaudeCodeHarness))
-            }
-            #[cfg(not(feature = "claude-code"))]
-            {
-                None
-            }
-        }
+        #[cfg(feature = "claude-code")]
+        "claude" => Some(Arc::new(crate::claude_code::ClaudeCodeHarness)),
+        #[cfg(feature = "aider")]
+        "aider" => Some(Arc::new(crate::aider::AiderHarness)),
+        #[cfg(feature = "codex")]
+        "codex" => Some(Arc::new(crate::codex::CodexHarness)),
+        #[cfg(feature = "gemini")]
+        "gemini" => Some(Arc::new(crate::gemini::GeminiHarness)),
         _ => None,
     }
 }
@@ -52,7 +50,6 @@ mod tests {
     fn test_harness_for_unknown_command_returns_none() {
         assert!(harness_for_command("unknown").is_none());
         assert!(harness_for_command("cursor").is_none());
-        assert!(harness_for_command("aider").is_none());
     }
 
     #[test]
@@ -68,4 +65,13 @@ mod tests {
         assert!(harness.is_some());
         assert_eq!(harness.unwrap().harness_type(), "claude");
     }
+
+    #[test]
+    #[cfg(all(feature = "aider", feature = "codex", feature = "gemini"))]
+    fn test_harness_for_other_agents() {
+        for command in ["aider", "codex", "gemini"] {
+            let harness = harness_for_command(command).unwrap();
+            assert_eq!(harness.harness_type(), command);
+        }
+    }
 }
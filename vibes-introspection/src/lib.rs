@@ -1,19 +1,34 @@
 //! vibes-introspection - Harness capability discovery
 
 pub mod capabilities;
+mod detect;
+pub mod drift;
 pub mod error;
 pub mod harness;
 pub mod paths;
 pub mod watcher;
 
+#[cfg(feature = "aider")]
+pub mod aider;
 #[cfg(feature = "claude-code")]
 pub mod claude_code;
+#[cfg(feature = "codex")]
+pub mod codex;
+#[cfg(feature = "gemini")]
+pub mod gemini;
 
 pub use capabilities::*;
+pub use drift::{CapabilityChange, CapabilityDrift};
 pub use error::{IntrospectionError, Result};
 pub use harness::{Harness, harness_for_command};
 pub use paths::ConfigPaths;
 pub use watcher::CapabilityWatcher;
 
+#[cfg(feature = "aider")]
+pub use aider::AiderHarness;
 #[cfg(feature = "claude-code")]
 pub use claude_code::ClaudeCodeHarness;
+#[cfg(feature = "codex")]
+pub use codex::CodexHarness;
+#[cfg(feature = "gemini")]
+pub use gemini::GeminiHarness;
@@ -1,15 +1,22 @@
 //! File watcher for capability changes with debouncing
+//!
+//! Each re-introspection is diffed against the previous snapshot, and any
+//! [`CapabilityDrift`] is broadcast to subscribers.
 
-use crate::{Harness, HarnessCapabilities, Result};
+use crate::{CapabilityDrift, Harness, HarnessCapabilities, Result};
 use notify::{RecursiveMode, Watcher, recommended_watcher};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{RwLock, mpsc};
+use tokio::sync::{RwLock, broadcast, mpsc};
+
+/// Drift notifications buffered per subscriber
+const DRIFT_CHANNEL_CAPACITY: usize = 16;
 
 /// Watches config files and re-introspects on changes with debouncing
 pub struct CapabilityWatcher {
     harness: Arc<dyn Harness>,
     capabilities: Arc<RwLock<HarnessCapabilities>>,
+    drift_tx: broadcast::Sender<CapabilityDrift>,
     project_root: Option<PathBuf>,
     _watcher: notify::RecommendedWatcher,
     _task: tokio::task::JoinHandle<()>,
@@ -30,6 +37,7 @@ impl CapabilityWatcher {
         // Initial introspection
         let initial_caps = harness.introspect(project_root.as_deref()).await?;
         let capabilities = Arc::new(RwLock::new(initial_caps));
+        let (drift_tx, _) = broadcast::channel(DRIFT_CHANNEL_CAPACITY);
 
         // Set up file watching
         let (tx, rx) = mpsc::channel::<notify::Result<notify::Event>>(100);
@@ -67,6 +75,7 @@ impl CapabilityWatcher {
         // Spawn the debounce loop
         let harness_clone = harness.clone();
         let capabilities_clone = capabilities.clone();
+        let drift_tx_clone = drift_tx.clone();
         let project_root_clone = project_root.clone();
 
         let task = tokio::spawn(async move {
@@ -74,6 +83,7 @@ impl CapabilityWatcher {
                 rx,
                 harness_clone,
                 capabilities_clone,
+                drift_tx_clone,
                 project_root_clone,
                 debounce_ms,
             )
@@ -83,6 +93,7 @@ impl CapabilityWatcher {
         Ok(Self {
             harness,
             capabilities,
+            drift_tx,
             project_root,
             _watcher: watcher,
             _task: task,
@@ -94,6 +105,11 @@ impl CapabilityWatcher {
         self.capabilities.read().await.clone()
     }
 
+    /// Subscribe to capability drift detected on re-introspection
+    pub fn subscribe(&self) -> broadcast::Receiver<CapabilityDrift> {
+        self.drift_tx.subscribe()
+    }
+
     /// Force a re-introspection
     pub async fn refresh(&self) -> Result<()> {
         tracing::info!("Forcing capability refresh");
@@ -101,15 +117,40 @@ impl CapabilityWatcher {
             .harness
             .introspect(self.project_root.as_deref())
             .await?;
-        *self.capabilities.write().await = new_caps;
+        Self::update(&self.capabilities, &self.drift_tx, new_caps).await;
         Ok(())
     }
 
+    /// Store new capabilities, broadcasting what changed since the last snapshot
+    async fn update(
+        capabilities: &RwLock<HarnessCapabilities>,
+        drift_tx: &broadcast::Sender<CapabilityDrift>,
+        new_caps: HarnessCapabilities,
+    ) {
+        let drift = {
+            let mut current = capabilities.write().await;
+            let drift = CapabilityDrift::between(&current, &new_caps);
+            *current = new_caps;
+            drift
+        };
+
+        if !drift.is_empty() {
+            tracing::info!(
+                "{} capabilities drifted: {} change(s)",
+                drift.harness_type,
+                drift.changes.len()
+            );
+            // No subscribers is fine - the snapshot is still updated
+            let _ = drift_tx.send(drift);
+        }
+    }
+
     /// Debounce loop that waits for events, then waits for quiet period before re-introspecting
     async fn debounce_loop(
         mut rx: mpsc::Receiver<notify::Result<notify::Event>>,
         harness: Arc<dyn Harness>,
         capabilities: Arc<RwLock<HarnessCapabilities>>,
+        drift_tx: broadcast::Sender<CapabilityDrift>,
         project_root: Option<PathBuf>,
         debounce_ms: u64,
     ) {
@@ -147,7 +188,7 @@ impl CapabilityWatcher {
 
             match harness.introspect(project_root.as_deref()).await {
                 Ok(new_caps) => {
-                    *capabilities.write().await = new_caps;
+                    Self::update(&capabilities, &drift_tx, new_caps).await;
                     tracing::info!("Capabilities refreshed successfully");
                 }
                 Err(e) => {
@@ -267,6 +308,71 @@ mod tests {
         assert_eq!(caps.harness_type, "mock");
     }
 
+    #[tokio::test]
+    async fn test_refresh_broadcasts_drift() {
+        /// Harness whose version goes up on every introspection
+        struct UpgradingHarness(AtomicUsize);
+
+        #[async_trait]
+        impl Harness for UpgradingHarness {
+            fn harness_type(&self) -> &'static str {
+                "mock"
+            }
+
+            async fn version(&self) -> Option<String> {
+                None
+            }
+
+            fn config_paths(&self, _project_root: Option<&std::path::Path>) -> Result<ConfigPaths> {
+                Ok(ConfigPaths {
+                    system: None,
+                    user: PathBuf::from("/tmp/mock-harness-user"),
+                    project: None,
+                })
+            }
+
+            async fn introspect(
+                &self,
+                _project_root: Option<&std::path::Path>,
+            ) -> Result<HarnessCapabilities> {
+                let n = self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(HarnessCapabilities {
+                    harness_type: "mock".to_string(),
+                    version: Some(format!("1.{}.0", n)),
+                    system: None,
+                    user: ScopedCapabilities::default(),
+                    project: None,
+                })
+            }
+        }
+
+        let harness = Arc::new(UpgradingHarness(AtomicUsize::new(0)));
+        let watcher = CapabilityWatcher::new(harness, None, 100).await.unwrap();
+        let mut drift_rx = watcher.subscribe();
+
+        watcher.refresh().await.unwrap();
+
+        let drift = drift_rx.try_recv().unwrap();
+        assert_eq!(
+            drift.changes,
+            vec![crate::CapabilityChange::VersionChanged {
+                from: Some("1.0.0".to_string()),
+                to: Some("1.1.0".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_without_changes_broadcasts_nothing() {
+        let harness = Arc::new(MockHarness::new());
+        let watcher = CapabilityWatcher::new(harness, None, 100).await.unwrap();
+        let mut drift_rx = watcher.subscribe();
+
+        watcher.refresh().await.unwrap();
+
+        assert!(drift_rx.try_recv().is_err());
+    }
+
     /// Mock harness that uses a specific config directory for file watching tests
     struct MockHarnessWithConfigDir {
         config_dir: PathBuf,
@@ -101,6 +101,7 @@ impl TestHarness {
                 path: settings_path,
                 format: ConfigFormat::Json,
                 writable: true,
+                content_hash: None,
             });
         }
 
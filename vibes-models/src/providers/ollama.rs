@@ -136,6 +136,31 @@ impl OllamaChatResponse {
     }
 }
 
+/// Request body for Ollama's `/api/embed` endpoint.
+#[derive(Debug, serde::Serialize)]
+pub struct OllamaEmbedRequest {
+    pub model: String,
+    pub input: Vec<String>,
+}
+
+/// Response from Ollama's `/api/embed` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct OllamaEmbedResponse {
+    pub model: String,
+    pub embeddings: Vec<Vec<f32>>,
+    #[serde(default)]
+    pub prompt_eval_count: Option<u64>,
+}
+
+impl From<OllamaEmbedResponse> for super::EmbedResponse {
+    fn from(response: OllamaEmbedResponse) -> Self {
+        Self {
+            embeddings: response.embeddings,
+            usage: super::Usage::new(response.prompt_eval_count.unwrap_or(0), 0),
+        }
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // OllamaProvider
 // ────────────────────────────────────────────────────────────────────────────
@@ -281,6 +306,40 @@ impl OllamaProvider {
         Ok(ollama_response.into())
     }
 
+    /// Generate embeddings for a batch of texts.
+    pub async fn embed(&self, request: super::EmbedRequest) -> crate::Result<super::EmbedResponse> {
+        let url = format!("{}/api/embed", self.base_url);
+
+        let ollama_request = OllamaEmbedRequest {
+            model: request.model,
+            input: request.texts,
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&ollama_request)
+            .send()
+            .await
+            .map_err(|e| crate::Error::Request(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(crate::Error::ProviderApi(format!(
+                "Ollama API returned {}: {}",
+                status, body
+            )));
+        }
+
+        let ollama_response: OllamaEmbedResponse = response
+            .json()
+            .await
+            .map_err(|e| crate::Error::Request(e.to_string()))?;
+
+        Ok(ollama_response.into())
+    }
+
     /// Perform a streaming chat completion request.
     pub async fn chat_stream(
         &self,
@@ -401,6 +460,10 @@ impl super::ModelProvider for OllamaProvider {
     async fn chat_stream(&self, request: super::ChatRequest) -> crate::Result<super::ChatStream> {
         self.chat_stream(request).await
     }
+
+    async fn embed(&self, request: super::EmbedRequest) -> crate::Result<super::EmbedResponse> {
+        self.embed(request).await
+    }
 }
 
 #[cfg(test)]
@@ -628,6 +691,39 @@ mod tests {
         assert_eq!(usage.output_tokens, 15);
     }
 
+    // ────────────────────────────────────────────────────────────────────────────
+    // Embedding API Tests
+    // ────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ollama_embed_response_converts_to_embed_response() {
+        use crate::providers::EmbedResponse;
+
+        let json = r#"{
+            "model": "nomic-embed-text",
+            "embeddings": [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]],
+            "total_duration": 14143917,
+            "load_duration": 1019500,
+            "prompt_eval_count": 8
+        }"#;
+
+        let response: OllamaEmbedResponse = serde_json::from_str(json).unwrap();
+        let embed: EmbedResponse = response.into();
+        assert_eq!(embed.embeddings.len(), 2);
+        assert_eq!(embed.embeddings[1], vec![0.4, 0.5, 0.6]);
+        assert_eq!(embed.usage.input_tokens, 8);
+    }
+
+    #[test]
+    fn embed_request_serializes_texts_as_input() {
+        let request = OllamaEmbedRequest {
+            model: "nomic-embed-text".to_string(),
+            input: vec!["hello".to_string()],
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["input"][0], "hello");
+    }
+
     // ────────────────────────────────────────────────────────────────────────────
     // Integration Tests (require Ollama running)
     // ────────────────────────────────────────────────────────────────────────────
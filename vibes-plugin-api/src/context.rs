@@ -2,7 +2,10 @@
 
 use crate::command::CommandSpec;
 use crate::error::PluginError;
-use crate::http::RouteSpec;
+use crate::event::{EventSubscription, PluginEvent, RawEvent};
+use crate::http::{HttpMethod, RouteSpec, StreamSpec};
+use crate::permission::{self, Permission};
+use crate::tui::{TuiAction, TuiCommandSpec, TuiViewSpec};
 use serde::{Serialize, de::DeserializeOwned};
 use std::any::Any;
 use std::collections::HashMap;
@@ -64,6 +67,17 @@ pub trait Harness: Send + Sync {
     }
 }
 
+// ─── Event Publishing ────────────────────────────────────────────────
+
+/// Sink for events published by plugins.
+///
+/// Implemented by vibes-core to append plugin events to the shared event log,
+/// where they reach the firehose and other plugins' `on_event()`.
+pub trait EventPublisher: Send + Sync {
+    /// Publish an event in the namespace of `plugin`
+    fn publish(&self, plugin: &str, event: PluginEvent) -> Result<(), PluginError>;
+}
+
 /// Plugin's interface to vibes-core capabilities.
 ///
 /// This is passed to plugins during lifecycle events and provides access to:
@@ -74,6 +88,13 @@ pub trait Harness: Send + Sync {
 /// - Harness for groove integration (optional)
 /// - Available capabilities
 ///
+/// # Permissions
+///
+/// The host restricts each context to the permissions the plugin declared in
+/// its manifest and the user approved. Restricted contexts refuse route
+/// registration, publishing and event access that was not granted. A context
+/// that was never restricted (e.g. in plugin unit tests) allows everything.
+///
 /// # Harness vs Capabilities
 ///
 /// The context stores both a `harness` (optional) and a `capabilities` vector.
@@ -96,11 +117,19 @@ pub struct PluginContext {
     pending_commands: Vec<CommandSpec>,
     /// Routes pending registration
     pending_routes: Vec<RouteSpec>,
+    /// Streaming routes pending registration
+    pending_streams: Vec<StreamSpec>,
+    /// TUI views pending registration
+    pending_tui_views: Vec<TuiViewSpec>,
+    /// TUI command-bar commands pending registration
+    pending_tui_commands: Vec<TuiCommandSpec>,
     /// Optional harness for groove integration.
     /// When present, its capabilities should match the `capabilities` field.
     harness: Option<Arc<dyn Harness>>,
     /// Authoritative list of capabilities available to this plugin.
     capabilities: Vec<Capability>,
+    /// Permissions granted by the host (`None` = unrestricted)
+    permissions: Option<Vec<Permission>>,
     // ─── Runtime Fields (set after server initialization) ──────────────
     /// Tokio runtime handle from the host process.
     /// Plugins MUST use this handle for async operations because dynamically
@@ -114,6 +143,10 @@ pub struct PluginContext {
     shutdown: Option<CancellationToken>,
     /// Iggy manager for persistent storage (if available)
     iggy_manager: Option<Arc<IggyManager>>,
+    /// Publisher for plugin events
+    event_publisher: Option<Arc<dyn EventPublisher>>,
+    /// Filters for events delivered to `on_event()` (empty = all events)
+    subscriptions: Vec<EventSubscription>,
     /// Services registered by the plugin (type-erased for flexibility).
     /// Plugins can register shared resources here during on_ready().
     services: HashMap<String, Arc<dyn Any + Send + Sync>>,
@@ -143,12 +176,18 @@ impl PluginContext {
             config: PluginConfig::new(),
             pending_commands: Vec::new(),
             pending_routes: Vec::new(),
+            pending_streams: Vec::new(),
+            pending_tui_views: Vec::new(),
+            pending_tui_commands: Vec::new(),
             harness: None,
             capabilities: Vec::new(),
+            permissions: None,
             runtime_handle: None,
             event_log: None,
             shutdown: None,
             iggy_manager: None,
+            event_publisher: None,
+            subscriptions: Vec::new(),
             services: HashMap::new(),
         }
     }
@@ -161,12 +200,18 @@ impl PluginContext {
             config,
             pending_commands: Vec::new(),
             pending_routes: Vec::new(),
+            pending_streams: Vec::new(),
+            pending_tui_views: Vec::new(),
+            pending_tui_commands: Vec::new(),
             harness: None,
             capabilities: Vec::new(),
+            permissions: None,
             runtime_handle: None,
             event_log: None,
             shutdown: None,
             iggy_manager: None,
+            event_publisher: None,
+            subscriptions: Vec::new(),
             services: HashMap::new(),
         }
     }
@@ -227,6 +272,61 @@ impl PluginContext {
         &mut self.config
     }
 
+    // ─── Permissions ─────────────────────────────────────────────────
+
+    /// Restrict the plugin to `granted` (called by PluginHost before on_load)
+    pub fn set_permissions(&mut self, granted: Vec<Permission>) {
+        self.permissions = Some(granted);
+    }
+
+    /// Get the granted permissions, or `None` if the context is unrestricted
+    pub fn permissions(&self) -> Option<&[Permission]> {
+        self.permissions.as_deref()
+    }
+
+    /// Check whether a permission was granted
+    pub fn has_permission(&self, requested: &Permission) -> bool {
+        self.permissions
+            .as_deref()
+            .is_none_or(|granted| permission::is_granted(granted, requested))
+    }
+
+    /// Fail with `PluginError::PermissionDenied` unless a permission was granted
+    pub fn require_permission(&self, requested: &Permission) -> Result<(), PluginError> {
+        if self.has_permission(requested) {
+            Ok(())
+        } else {
+            Err(PluginError::PermissionDenied(requested.to_string()))
+        }
+    }
+
+    /// Check that the plugin may access `path` before touching it.
+    ///
+    /// The plugin's own directory is always accessible; anything else needs a
+    /// `Permission::Filesystem` covering the path.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ctx.check_path(&export_path, true)?;
+    /// std::fs::write(&export_path, data)?;
+    /// ```
+    pub fn check_path(&self, path: &Path, write: bool) -> Result<(), PluginError> {
+        let own_dir = Permission::write_path(&self.plugin_dir);
+        let requested = Permission::Filesystem {
+            path: path.to_path_buf(),
+            write,
+        };
+        if own_dir.covers(&requested) {
+            return Ok(());
+        }
+        self.require_permission(&requested)
+    }
+
+    /// Check that the plugin may spawn child processes.
+    pub fn check_spawn(&self) -> Result<(), PluginError> {
+        self.require_permission(&Permission::SpawnProcesses)
+    }
+
     // ─── CommandSpec Registration ─────────────────────────────────────
 
     /// Register a CLI command for this plugin using CommandSpec.
@@ -261,8 +361,10 @@ impl PluginContext {
     ///
     /// Path parameters use `:name` syntax: `/quarantine/:id/review`
     ///
-    /// Returns error if route (same method+path) is duplicate within this plugin.
+    /// Returns error if route (same method+path) is duplicate within this plugin,
+    /// or if the plugin was not granted `Permission::RegisterRoutes`.
     pub fn register_route(&mut self, spec: RouteSpec) -> Result<(), PluginError> {
+        self.require_permission(&Permission::RegisterRoutes)?;
         if self
             .pending_routes
             .iter()
@@ -287,6 +389,100 @@ impl PluginContext {
         std::mem::take(&mut self.pending_routes)
     }
 
+    /// Register a streaming route (server-sent events or WebSocket).
+    ///
+    /// Streams are `GET` routes under the same `/api/<plugin-name>/...` prefix.
+    /// When a client connects, the host calls `Plugin::open_stream` with a
+    /// handle for pushing messages.
+    ///
+    /// Returns error if a stream or `GET` route with the same path is already
+    /// registered by this plugin, or if the plugin was not granted
+    /// `Permission::RegisterRoutes`.
+    pub fn register_stream(&mut self, spec: StreamSpec) -> Result<(), PluginError> {
+        self.require_permission(&Permission::RegisterRoutes)?;
+        let taken = self.pending_streams.iter().any(|s| s.path == spec.path)
+            || self
+                .pending_routes
+                .iter()
+                .any(|r| r.method == HttpMethod::Get && r.path == spec.path);
+        if taken {
+            return Err(PluginError::DuplicateRoute(format!("Get {}", spec.path)));
+        }
+        self.pending_streams.push(spec);
+        Ok(())
+    }
+
+    /// Get streaming routes pending registration (used by PluginHost)
+    pub fn pending_streams(&self) -> &[StreamSpec] {
+        &self.pending_streams
+    }
+
+    /// Take pending streaming routes (used by PluginHost after validation)
+    pub fn take_pending_streams(&mut self) -> Vec<StreamSpec> {
+        std::mem::take(&mut self.pending_streams)
+    }
+
+    // ─── TUI Registration ──────────────────────────────────────────
+
+    /// Register a view for the TUI.
+    ///
+    /// Widgets read from this plugin's own `GET` routes, so this needs the
+    /// same `Permission::RegisterRoutes` as registering those routes.
+    ///
+    /// Returns error if a view with the same id is already registered by
+    /// this plugin.
+    pub fn register_tui_view(&mut self, spec: TuiViewSpec) -> Result<(), PluginError> {
+        self.require_permission(&Permission::RegisterRoutes)?;
+        if self.pending_tui_views.iter().any(|v| v.id == spec.id) {
+            return Err(PluginError::DuplicateView(spec.id));
+        }
+        self.pending_tui_views.push(spec);
+        Ok(())
+    }
+
+    /// Register a command-bar command for the TUI.
+    ///
+    /// Commands are namespaced under the plugin name: `:<plugin-name> <name>`.
+    /// A command that opens a view must be registered after that view.
+    pub fn register_tui_command(&mut self, spec: TuiCommandSpec) -> Result<(), PluginError> {
+        self.require_permission(&Permission::RegisterRoutes)?;
+        if self
+            .pending_tui_commands
+            .iter()
+            .any(|c| c.name == spec.name)
+        {
+            return Err(PluginError::DuplicateCommand(format!("tui {}", spec.name)));
+        }
+        if let TuiAction::OpenView { view } = &spec.action
+            && !self.pending_tui_views.iter().any(|v| &v.id == view)
+        {
+            return Err(PluginError::InvalidInput(format!(
+                "TUI command '{}' opens unknown view '{}'",
+                spec.name, view
+            )));
+        }
+        self.pending_tui_commands.push(spec);
+        Ok(())
+    }
+
+    /// Get TUI views pending registration (used by PluginHost)
+    pub fn pending_tui_views(&self) -> &[TuiViewSpec] {
+        &self.pending_tui_views
+    }
+
+    /// Get TUI commands pending registration (used by PluginHost)
+    pub fn pending_tui_commands(&self) -> &[TuiCommandSpec] {
+        &self.pending_tui_commands
+    }
+
+    /// Take pending TUI views and commands (used by PluginHost)
+    pub fn take_pending_tui(&mut self) -> (Vec<TuiViewSpec>, Vec<TuiCommandSpec>) {
+        (
+            std::mem::take(&mut self.pending_tui_views),
+            std::mem::take(&mut self.pending_tui_commands),
+        )
+    }
+
     // ─── Logging ─────────────────────────────────────────────────────
 
     /// Log an info message (automatically prefixed with plugin name)
@@ -385,7 +581,8 @@ impl PluginContext {
     /// Get the event log, downcasting to the expected type.
     ///
     /// For vibes internal plugins, this is typically `Arc<dyn EventLog<StoredEvent>>`.
-    /// Returns `None` if not set or if the downcast fails.
+    /// Returns `None` if not set, if the downcast fails, or if the plugin was
+    /// not granted `Permission::ReadEvents`.
     ///
     /// # Example
     ///
@@ -402,6 +599,9 @@ impl PluginContext {
     /// }
     /// ```
     pub fn event_log<T: ?Sized + 'static>(&self) -> Option<Arc<T>> {
+        if !self.has_permission(&Permission::ReadEvents) {
+            return None;
+        }
         self.event_log
             .as_ref()
             .and_then(|any| any.downcast_ref::<Arc<T>>().cloned())
@@ -426,11 +626,94 @@ impl PluginContext {
 
     /// Get the Iggy manager for persistent storage.
     ///
-    /// Returns `None` if Iggy is not available or not set.
+    /// Returns `None` if Iggy is not available or not set, or if the plugin
+    /// was not granted `Permission::ReadEvents`.
     pub fn iggy_manager(&self) -> Option<Arc<IggyManager>> {
+        if !self.has_permission(&Permission::ReadEvents) {
+            return None;
+        }
         self.iggy_manager.clone()
     }
 
+    /// Set the event publisher (called by PluginHost before on_ready).
+    pub fn set_event_publisher(&mut self, publisher: Arc<dyn EventPublisher>) {
+        self.event_publisher = Some(publisher);
+    }
+
+    /// Publish an event to the shared event log.
+    ///
+    /// The event is namespaced by this plugin's name, so it appears to
+    /// subscribers and in the firehose as `"<plugin>.<event_type>"`. A plugin
+    /// never receives its own events back.
+    ///
+    /// Returns `PluginError::PermissionDenied` without `Permission::PublishEvents`,
+    /// and `PluginError::NotReady` before on_ready is called.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ctx.publish(
+    ///     PluginEvent::new("learning_extracted", json!({"id": id})).with_session(session_id),
+    /// )?;
+    /// ```
+    pub fn publish(&self, event: PluginEvent) -> Result<(), PluginError> {
+        self.require_permission(&Permission::PublishEvents)?;
+        let publisher = self
+            .event_publisher
+            .as_ref()
+            .ok_or_else(|| PluginError::NotReady("event publisher not available".into()))?;
+        publisher.publish(&self.plugin_name, event)
+    }
+
+    // ─── Event Subscriptions ──────────────────────────────────────────
+
+    /// Subscribe to events delivered to `on_event()`.
+    ///
+    /// Plugins without subscriptions receive every event. Once a plugin
+    /// subscribes, it only receives events matching at least one of its
+    /// subscriptions.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// fn on_load(&mut self, ctx: &mut PluginContext) -> Result<(), PluginError> {
+    ///     ctx.subscribe(EventSubscription::event_type("Hook").with_type("groove.*"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subscribe(&mut self, subscription: EventSubscription) {
+        self.subscriptions.push(subscription);
+    }
+
+    /// Remove all subscriptions, receiving every event again.
+    pub fn clear_subscriptions(&mut self) {
+        self.subscriptions.clear();
+    }
+
+    /// Get the plugin's subscriptions.
+    pub fn subscriptions(&self) -> &[EventSubscription] {
+        &self.subscriptions
+    }
+
+    /// Whether an event should be delivered to this plugin's `on_event()`.
+    ///
+    /// Events the plugin published itself are never delivered back, and
+    /// nothing is delivered without `Permission::ReadEvents`.
+    pub fn wants_event(&self, event: &RawEvent) -> bool {
+        if !self.has_permission(&Permission::ReadEvents) {
+            return false;
+        }
+
+        let own_event = event
+            .event_type
+            .strip_prefix(self.plugin_name.as_str())
+            .is_some_and(|rest| rest.starts_with('.'));
+
+        !own_event
+            && (self.subscriptions.is_empty()
+                || self.subscriptions.iter().any(|s| s.matches(event)))
+    }
+
     /// Check if runtime dependencies are available.
     ///
     /// Returns `true` if runtime_handle, event_log, and shutdown are set.
@@ -820,4 +1103,185 @@ mod tests {
         assert_eq!(routes.len(), 1);
         assert!(ctx.pending_routes().is_empty());
     }
+
+    #[test]
+    fn test_register_stream_conflicts_with_get_route() {
+        use crate::http::{HttpMethod, RouteSpec, StreamSpec};
+
+        let mut ctx = PluginContext::new("test".into(), PathBuf::from("/tmp"));
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/overview".into(),
+        })
+        .unwrap();
+
+        assert!(ctx.register_stream(StreamSpec::sse("/overview")).is_err());
+        ctx.register_stream(StreamSpec::websocket("/live")).unwrap();
+        assert!(ctx.register_stream(StreamSpec::sse("/live")).is_err());
+
+        assert_eq!(ctx.take_pending_streams().len(), 1);
+        assert!(ctx.pending_streams().is_empty());
+    }
+
+    #[test]
+    fn test_register_tui_view_and_commands() {
+        let mut ctx = PluginContext::new("test".into(), PathBuf::from("/tmp"));
+        let view = TuiViewSpec {
+            id: "stats".into(),
+            title: "Stats".into(),
+            widgets: vec![],
+        };
+
+        ctx.register_tui_view(view.clone()).unwrap();
+        assert!(matches!(
+            ctx.register_tui_view(view),
+            Err(PluginError::DuplicateView(_))
+        ));
+
+        ctx.register_tui_command(TuiCommandSpec::open_view("stats", "Show stats", "stats"))
+            .unwrap();
+        assert!(matches!(
+            ctx.register_tui_command(TuiCommandSpec::open_view("other", "?", "missing")),
+            Err(PluginError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            ctx.register_tui_command(TuiCommandSpec::post("stats", "Reset", "/reset")),
+            Err(PluginError::DuplicateCommand(_))
+        ));
+
+        let (views, commands) = ctx.take_pending_tui();
+        assert_eq!((views.len(), commands.len()), (1, 1));
+        assert!(ctx.pending_tui_views().is_empty());
+    }
+
+    fn raw_event(event_type: &str, session_id: Option<&str>) -> RawEvent {
+        RawEvent::new(
+            [0; 16],
+            0,
+            session_id.map(str::to_string),
+            event_type.to_string(),
+            "{}".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_wants_event_filters_by_subscription() {
+        let mut ctx = PluginContext::new("observer".to_string(), PathBuf::from("/tmp"));
+        assert!(ctx.wants_event(&raw_event("SessionCreated", None)));
+
+        ctx.subscribe(EventSubscription::event_type("groove.*").with_session("sess-1"));
+        assert!(ctx.wants_event(&raw_event("groove.learning_extracted", Some("sess-1"))));
+        assert!(!ctx.wants_event(&raw_event("groove.learning_extracted", Some("sess-2"))));
+        assert!(!ctx.wants_event(&raw_event("SessionCreated", Some("sess-1"))));
+
+        ctx.clear_subscriptions();
+        assert!(ctx.wants_event(&raw_event("SessionCreated", None)));
+    }
+
+    #[test]
+    fn test_wants_event_skips_own_events() {
+        let ctx = PluginContext::new("groove".to_string(), PathBuf::from("/tmp"));
+
+        assert!(!ctx.wants_event(&raw_event("groove.learning_extracted", None)));
+        assert!(ctx.wants_event(&raw_event("groovy.tune", None)));
+    }
+
+    struct RecordingPublisher(std::sync::Mutex<Vec<(String, PluginEvent)>>);
+
+    impl EventPublisher for RecordingPublisher {
+        fn publish(&self, plugin: &str, event: PluginEvent) -> Result<(), PluginError> {
+            self.0.lock().unwrap().push((plugin.to_string(), event));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_publish_uses_plugin_namespace() {
+        let mut ctx = PluginContext::new("groove".to_string(), PathBuf::from("/tmp"));
+        let event = PluginEvent::new("learning_extracted", serde_json::json!({}));
+        assert!(matches!(
+            ctx.publish(event.clone()),
+            Err(PluginError::NotReady(_))
+        ));
+
+        let publisher = Arc::new(RecordingPublisher(Default::default()));
+        ctx.set_event_publisher(publisher.clone());
+        ctx.publish(event.clone()).unwrap();
+
+        let published = publisher.0.lock().unwrap();
+        assert_eq!(published.as_slice(), &[("groove".to_string(), event)]);
+    }
+
+    #[test]
+    fn test_unrestricted_context_allows_everything() {
+        let ctx = PluginContext::new("test".into(), PathBuf::from("/tmp/test"));
+
+        assert!(ctx.permissions().is_none());
+        assert!(ctx.has_permission(&Permission::SpawnProcesses));
+        assert!(ctx.check_path(Path::new("/etc/passwd"), true).is_ok());
+    }
+
+    #[test]
+    fn test_restricted_context_refuses_ungranted_permissions() {
+        use crate::http::{HttpMethod, RouteSpec, StreamSpec};
+
+        let mut ctx = PluginContext::new("test".into(), PathBuf::from("/tmp/test"));
+        ctx.set_permissions(vec![Permission::RegisterRoutes]);
+
+        ctx.register_route(RouteSpec {
+            method: HttpMethod::Get,
+            path: "/ok".into(),
+        })
+        .unwrap();
+        ctx.register_stream(StreamSpec::sse("/live")).unwrap();
+
+        ctx.set_event_publisher(Arc::new(RecordingPublisher(Default::default())));
+        let event = PluginEvent::new("noise", serde_json::json!({}));
+        assert!(matches!(
+            ctx.publish(event),
+            Err(PluginError::PermissionDenied(_))
+        ));
+
+        ctx.set_event_log(Arc::new(Arc::new(42u32)));
+        assert!(ctx.event_log::<u32>().is_none());
+        assert!(!ctx.wants_event(&raw_event("SessionCreated", None)));
+        assert!(ctx.check_spawn().is_err());
+
+        ctx.set_permissions(vec![]);
+        assert!(matches!(
+            ctx.register_route(RouteSpec {
+                method: HttpMethod::Post,
+                path: "/denied".into(),
+            }),
+            Err(PluginError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            ctx.register_tui_view(TuiViewSpec {
+                id: "denied".into(),
+                title: "Denied".into(),
+                widgets: vec![],
+            }),
+            Err(PluginError::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_path_allows_plugin_dir_and_granted_paths() {
+        let mut ctx = PluginContext::new("test".into(), PathBuf::from("/plugins/test"));
+        ctx.set_permissions(vec![Permission::read_path("/data/shared")]);
+
+        assert!(
+            ctx.check_path(Path::new("/plugins/test/state.db"), true)
+                .is_ok()
+        );
+        assert!(
+            ctx.check_path(Path::new("/data/shared/a.json"), false)
+                .is_ok()
+        );
+        assert!(matches!(
+            ctx.check_path(Path::new("/data/shared/a.json"), true),
+            Err(PluginError::PermissionDenied(_))
+        ));
+        assert!(ctx.check_path(Path::new("/home/user/.ssh"), false).is_err());
+    }
 }
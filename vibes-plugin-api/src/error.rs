@@ -33,6 +33,10 @@ pub enum PluginError {
     #[error("Duplicate route: {0}")]
     DuplicateRoute(String),
 
+    /// Duplicate TUI view registration
+    #[error("Duplicate view: {0}")]
+    DuplicateView(String),
+
     /// Unknown command dispatch
     #[error("Unknown command: {0}")]
     UnknownCommand(String),
@@ -52,6 +56,14 @@ pub enum PluginError {
     /// Runtime not ready (called before on_ready or missing dependencies)
     #[error("Runtime not ready: {0}")]
     NotReady(String),
+
+    /// Stream closed or not accepting messages
+    #[error("Stream error: {0}")]
+    Stream(String),
+
+    /// Plugin used a permission it was not granted
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
 }
 
 impl PluginError {
@@ -143,4 +155,10 @@ mod tests {
         let err = PluginError::InvalidInput("missing param".into());
         assert!(err.to_string().contains("missing param"));
     }
+
+    #[test]
+    fn test_permission_denied_error() {
+        let err = PluginError::PermissionDenied("spawn processes".into());
+        assert_eq!(err.to_string(), "Permission denied: spawn processes");
+    }
 }
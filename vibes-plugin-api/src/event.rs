@@ -4,6 +4,7 @@
 //! the host-plugin boundary without TypeId mismatches.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// A raw event suitable for FFI across the host-plugin boundary.
 ///
@@ -206,6 +207,107 @@ impl PluginAssessmentResult {
     }
 }
 
+/// An event a plugin publishes to the shared event log.
+///
+/// Published events are namespaced by the publishing plugin: subscribers see
+/// them with the event type `"<plugin>.<event_type>"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginEvent {
+    /// Event type within the plugin's namespace (e.g., "learning_extracted").
+    pub event_type: String,
+
+    /// Session the event relates to, if any.
+    pub session_id: Option<String>,
+
+    /// Event payload.
+    pub payload: Value,
+}
+
+impl PluginEvent {
+    /// Create an event that is not tied to a session.
+    #[must_use]
+    pub fn new(event_type: impl Into<String>, payload: Value) -> Self {
+        Self {
+            event_type: event_type.into(),
+            session_id: None,
+            payload,
+        }
+    }
+
+    /// Attribute the event to a session.
+    #[must_use]
+    pub fn with_session(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+}
+
+/// A filter selecting which events a plugin receives in `on_event()`.
+///
+/// Event types are [`RawEvent::event_type`] names: built-in events use the
+/// variant name ("SessionCreated", "Hook"), plugin events use
+/// `"<plugin>.<event_type>"`. A type ending in `.*` matches every event in
+/// that namespace (e.g., "groove.*").
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSubscription {
+    /// Event types to receive (empty = all types).
+    pub event_types: Vec<String>,
+
+    /// Only receive events from this session (None = all sessions).
+    pub session_id: Option<String>,
+}
+
+impl EventSubscription {
+    /// Subscribe to every event.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe to a single event type.
+    #[must_use]
+    pub fn event_type(event_type: impl Into<String>) -> Self {
+        Self::default().with_type(event_type)
+    }
+
+    /// Also match an event type (can be called multiple times).
+    #[must_use]
+    pub fn with_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_types.push(event_type.into());
+        self
+    }
+
+    /// Only match events from a session.
+    #[must_use]
+    pub fn with_session(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+
+    /// Whether an event passes this filter.
+    #[must_use]
+    pub fn matches(&self, event: &RawEvent) -> bool {
+        let type_matches = self.event_types.is_empty()
+            || self
+                .event_types
+                .iter()
+                .any(|pattern| match pattern.strip_suffix(".*") {
+                    Some(namespace) => event
+                        .event_type
+                        .strip_prefix(namespace)
+                        .is_some_and(|rest| rest.starts_with('.')),
+                    None => *pattern == event.event_type,
+                });
+
+        let session_matches = self
+            .session_id
+            .as_ref()
+            .is_none_or(|session| event.session_id.as_ref() == Some(session));
+
+        type_matches && session_matches
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +386,42 @@ mod tests {
         assert_eq!(parsed.session_id, "s1");
         assert_eq!(parsed.payload, r#"{"score": 0.5}"#);
     }
+
+    #[test]
+    fn test_subscription_matches_type_and_session() {
+        let event = RawEvent::new(
+            [0; 16],
+            0,
+            Some("sess-1".to_string()),
+            "groove.learning_extracted".to_string(),
+            "{}".to_string(),
+        );
+
+        assert!(EventSubscription::all().matches(&event));
+        assert!(EventSubscription::event_type("groove.learning_extracted").matches(&event));
+        assert!(EventSubscription::event_type("groove.*").matches(&event));
+        assert!(
+            EventSubscription::event_type("Hook")
+                .with_type("groove.*")
+                .with_session("sess-1")
+                .matches(&event)
+        );
+
+        assert!(!EventSubscription::event_type("Hook").matches(&event));
+        assert!(!EventSubscription::event_type("groo.*").matches(&event));
+        assert!(
+            !EventSubscription::all()
+                .with_session("sess-2")
+                .matches(&event)
+        );
+    }
+
+    #[test]
+    fn test_plugin_event_builder() {
+        let event = PluginEvent::new("learning_extracted", serde_json::json!({"id": 1}))
+            .with_session("sess-1");
+
+        assert_eq!(event.event_type, "learning_extracted");
+        assert_eq!(event.session_id.as_deref(), Some("sess-1"));
+    }
 }
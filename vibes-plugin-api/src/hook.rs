@@ -0,0 +1,164 @@
+//! Claude Code hook types for plugins
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A Claude Code hook event passed to [`Plugin::on_hook`](crate::Plugin::on_hook)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookInput {
+    /// Session the hook fired in, if known
+    pub session_id: Option<String>,
+    /// Hook type, e.g. "PreToolUse", "Stop", "SessionStart"
+    pub hook_type: String,
+    /// Project path, if the hook carries one
+    pub project_path: Option<String>,
+    /// The hook payload as received from Claude Code
+    pub payload: Value,
+}
+
+impl HookInput {
+    /// Tool name, for tool hooks (PreToolUse, PostToolUse, PermissionRequest)
+    pub fn tool_name(&self) -> Option<&str> {
+        self.payload.get("tool_name").and_then(Value::as_str)
+    }
+
+    /// Tool input, for tool hooks
+    pub fn tool_input(&self) -> Option<&Value> {
+        self.payload.get("tool_input")
+    }
+
+    /// Submitted prompt, for UserPromptSubmit
+    pub fn prompt(&self) -> Option<&str> {
+        self.payload.get("prompt").and_then(Value::as_str)
+    }
+}
+
+/// Whether a tool call may go ahead
+///
+/// Ordered from least to most restrictive, so the strictest of several
+/// decisions is the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionDecision {
+    /// Run the tool without asking the user
+    Allow,
+    /// Ask the user to confirm
+    Ask,
+    /// Refuse the tool call
+    Deny,
+}
+
+/// A plugin's answer to a hook
+///
+/// Fields that don't apply to the hook type are ignored: permission decisions
+/// and updated input apply to PreToolUse and PermissionRequest, blocking to
+/// Stop and SubagentStop, and additional context to SessionStart,
+/// UserPromptSubmit and PostToolUse.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HookDecision {
+    /// Context to inject into the conversation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<String>,
+    /// Permission decision for the tool call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<PermissionDecision>,
+    /// Replacement tool input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_input: Option<Value>,
+    /// Keep Claude working instead of stopping
+    #[serde(default)]
+    pub block: bool,
+    /// Explanation shown to Claude (for deny and block) or the user (for ask)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl HookDecision {
+    /// Inject context into the conversation
+    pub fn context(context: impl Into<String>) -> Self {
+        Self {
+            additional_context: Some(context.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Allow the tool call without asking
+    pub fn allow() -> Self {
+        Self {
+            permission: Some(PermissionDecision::Allow),
+            ..Default::default()
+        }
+    }
+
+    /// Ask the user to confirm the tool call
+    pub fn ask(reason: impl Into<String>) -> Self {
+        Self {
+            permission: Some(PermissionDecision::Ask),
+            reason: Some(reason.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Refuse the tool call
+    pub fn deny(reason: impl Into<String>) -> Self {
+        Self {
+            permission: Some(PermissionDecision::Deny),
+            reason: Some(reason.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Prevent Claude from stopping, telling it why
+    pub fn block(reason: impl Into<String>) -> Self {
+        Self {
+            block: true,
+            reason: Some(reason.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Replace the tool input
+    pub fn with_updated_input(mut self, input: Value) -> Self {
+        self.updated_input = Some(input);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_hook_input_tool_accessors() {
+        let input = HookInput {
+            session_id: Some("sess-1".to_string()),
+            hook_type: "PreToolUse".to_string(),
+            project_path: None,
+            payload: json!({"tool_name": "Bash", "tool_input": {"command": "ls"}}),
+        };
+
+        assert_eq!(input.tool_name(), Some("Bash"));
+        assert_eq!(input.tool_input(), Some(&json!({"command": "ls"})));
+        assert_eq!(input.prompt(), None);
+    }
+
+    #[test]
+    fn test_permission_decision_ordering() {
+        assert!(PermissionDecision::Deny > PermissionDecision::Ask);
+        assert!(PermissionDecision::Ask > PermissionDecision::Allow);
+    }
+
+    #[test]
+    fn test_decision_constructors() {
+        let deny = HookDecision::deny("rm -rf is not allowed");
+        assert_eq!(deny.permission, Some(PermissionDecision::Deny));
+        assert_eq!(deny.reason.as_deref(), Some("rm -rf is not allowed"));
+
+        let allow = HookDecision::allow().with_updated_input(json!({"command": "ls -a"}));
+        assert_eq!(allow.permission, Some(PermissionDecision::Allow));
+        assert_eq!(allow.updated_input, Some(json!({"command": "ls -a"})));
+
+        assert!(HookDecision::block("tests are failing").block);
+    }
+}
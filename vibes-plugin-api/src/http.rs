@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 
 use serde::Serialize;
+use tokio::sync::mpsc;
 
 use crate::error::PluginError;
 
@@ -78,6 +79,127 @@ impl RouteResponse {
     }
 }
 
+// ─── Streaming Routes ────────────────────────────────────────────────
+
+/// How a streaming route is served to clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    /// Server-sent events (`text/event-stream`)
+    Sse,
+    /// WebSocket upgrade; clients can also send text messages to the plugin
+    WebSocket,
+}
+
+/// Specification for a streaming route
+///
+/// Streaming routes are always `GET` and share the `/api/<plugin-name>/`
+/// prefix with regular routes.
+#[derive(Debug, Clone)]
+pub struct StreamSpec {
+    /// Transport used for the stream
+    pub kind: StreamKind,
+    /// Path pattern, e.g., "/live" or "/sessions/:id/live"
+    pub path: String,
+}
+
+impl StreamSpec {
+    /// Server-sent events stream at `path`
+    pub fn sse(path: impl Into<String>) -> Self {
+        Self {
+            kind: StreamKind::Sse,
+            path: path.into(),
+        }
+    }
+
+    /// WebSocket stream at `path`
+    pub fn websocket(path: impl Into<String>) -> Self {
+        Self {
+            kind: StreamKind::WebSocket,
+            path: path.into(),
+        }
+    }
+}
+
+/// Message pushed by a plugin to a stream client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamMessage {
+    /// Event name (the SSE `event:` field; ignored for WebSockets)
+    pub event: Option<String>,
+    /// Message data, sent as the SSE `data:` field or a WebSocket text frame
+    pub data: String,
+}
+
+impl StreamMessage {
+    /// Create a plain text message
+    pub fn text(data: impl Into<String>) -> Self {
+        Self {
+            event: None,
+            data: data.into(),
+        }
+    }
+
+    /// Create a JSON message
+    pub fn json<T: Serialize>(data: &T) -> Result<Self, PluginError> {
+        Ok(Self::text(
+            serde_json::to_string(data).map_err(|e| PluginError::Json(e.to_string()))?,
+        ))
+    }
+
+    /// Builder: set the event name
+    pub fn with_event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+}
+
+/// Identifier of an open stream, unique for the lifetime of the host
+pub type StreamId = u64;
+
+/// Handle for pushing messages to one connected stream client
+///
+/// Created by the host when a client opens a streaming route and passed to
+/// `Plugin::open_stream`. The host owns the socket: plugins keep the handle
+/// and call [`send`](Self::send) whenever they have something to push.
+/// Once the client disconnects, sends fail and [`is_closed`](Self::is_closed)
+/// returns true, so plugins can drop the handle.
+#[derive(Debug, Clone)]
+pub struct StreamHandle {
+    id: StreamId,
+    sender: mpsc::Sender<StreamMessage>,
+}
+
+impl StreamHandle {
+    /// Create a handle feeding `sender` (used by PluginHost)
+    pub fn new(id: StreamId, sender: mpsc::Sender<StreamMessage>) -> Self {
+        Self { id, sender }
+    }
+
+    /// The stream's identifier
+    pub fn id(&self) -> StreamId {
+        self.id
+    }
+
+    /// Queue a message for the client without blocking
+    ///
+    /// Fails if the client has disconnected or is too far behind to accept
+    /// more messages.
+    pub fn send(&self, message: StreamMessage) -> Result<(), PluginError> {
+        self.sender.try_send(message).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => {
+                PluginError::Stream(format!("stream {} is full", self.id))
+            }
+            mpsc::error::TrySendError::Closed(_) => {
+                PluginError::Stream(format!("stream {} is closed", self.id))
+            }
+        })
+    }
+
+    /// Whether the client has disconnected
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +251,28 @@ mod tests {
         assert_eq!(resp.content_type, "application/json");
         assert!(String::from_utf8_lossy(&resp.body).contains("42"));
     }
+
+    #[test]
+    fn test_stream_message_json_with_event() {
+        let message = StreamMessage::json(&serde_json::json!({"count": 3}))
+            .unwrap()
+            .with_event("overview");
+        assert_eq!(message.event.as_deref(), Some("overview"));
+        assert_eq!(message.data, r#"{"count":3}"#);
+    }
+
+    #[test]
+    fn test_stream_handle_send_and_close() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let handle = StreamHandle::new(7, tx);
+
+        handle.send(StreamMessage::text("one")).unwrap();
+        // Buffer of one is full until the client reads
+        assert!(handle.send(StreamMessage::text("two")).is_err());
+        assert_eq!(rx.try_recv().unwrap(), StreamMessage::text("one"));
+
+        drop(rx);
+        assert!(handle.is_closed());
+        assert!(handle.send(StreamMessage::text("three")).is_err());
+    }
 }
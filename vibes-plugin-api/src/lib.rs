@@ -39,19 +39,31 @@ pub mod command;
 pub mod context;
 pub mod error;
 pub mod event;
+pub mod hook;
 pub mod http;
+pub mod permission;
+pub mod tui;
 pub mod types;
 
 pub use command::{ArgSpec, CommandOutput, CommandSpec};
-pub use context::{Capability, CommandArgs, Harness, PluginConfig, PluginContext};
+pub use context::{Capability, CommandArgs, EventPublisher, Harness, PluginConfig, PluginContext};
 pub use error::PluginError;
-pub use event::{AssessmentQuery, AssessmentQueryResponse, PluginAssessmentResult, RawEvent};
-pub use http::{HttpMethod, RouteRequest, RouteResponse, RouteSpec};
+pub use event::{
+    AssessmentQuery, AssessmentQueryResponse, EventSubscription, PluginAssessmentResult,
+    PluginEvent, RawEvent,
+};
+pub use hook::{HookDecision, HookInput, PermissionDecision};
+pub use http::{
+    HttpMethod, RouteRequest, RouteResponse, RouteSpec, StreamHandle, StreamId, StreamKind,
+    StreamMessage, StreamSpec,
+};
+pub use permission::Permission;
+pub use tui::{PluginTui, TuiAction, TuiColumn, TuiCommandSpec, TuiField, TuiViewSpec, TuiWidget};
 pub use types::*;
 
 /// Current plugin API version. Plugins must match this exactly.
 /// This will be checked when loading plugins to ensure compatibility.
-pub const API_VERSION: u32 = 3;
+pub const API_VERSION: u32 = 8;
 
 /// The core plugin trait - implement this to create a vibes plugin.
 ///
@@ -165,17 +177,14 @@ pub trait Plugin: Send + Sync {
     ) {
     }
 
-    /// Called when a Claude Code hook event occurs
+    /// Called when a Claude Code hook event occurs, before Claude continues
     ///
     /// Hook types: "SessionStart", "UserPromptSubmit", "PreToolUse", "PostToolUse", "Stop"
-    /// Returns optional additional context to inject into the session.
-    fn on_hook(
-        &mut self,
-        _session_id: Option<&str>,
-        _hook_type: &str,
-        _project_path: Option<&str>,
-        _ctx: &mut PluginContext,
-    ) -> Option<String> {
+    /// Returns an optional decision: context to inject, a permission decision
+    /// or replacement input for a tool call, or a request to keep Claude
+    /// working. When several plugins answer, the host merges their decisions
+    /// (the most restrictive permission wins).
+    fn on_hook(&mut self, _hook: &HookInput, _ctx: &mut PluginContext) -> Option<HookDecision> {
         None
     }
 
@@ -185,6 +194,10 @@ pub trait Plugin: Send + Sync {
     /// assessment results. The host handles all async I/O (reading from EventLog,
     /// writing to AssessmentLog), while plugins focus on pure event processing.
     ///
+    /// Only events matching the plugin's subscriptions are delivered (see
+    /// [`PluginContext::subscribe`]); this includes events other plugins publish
+    /// with [`PluginContext::publish`].
+    ///
     /// # Arguments
     ///
     /// * `event` - The raw event with JSON-serialized payload
@@ -269,6 +282,37 @@ pub trait Plugin: Send + Sync {
     ) -> Result<RouteResponse, PluginError> {
         Err(PluginError::UnknownRoute("no routes registered".into()))
     }
+
+    // ─── Stream Handlers ───────────────────────────────────────────
+
+    /// Handle a client connecting to a streaming route.
+    ///
+    /// Called with the registered stream path (e.g., "/live") and a handle
+    /// for pushing messages. Keep the handle and send through it as events
+    /// arrive; the host forwards messages until the client disconnects.
+    /// Returning an error rejects the connection.
+    ///
+    /// Default: returns UnknownRoute error (override if registering streams)
+    fn open_stream(
+        &mut self,
+        _path: &str,
+        _request: RouteRequest,
+        _stream: StreamHandle,
+        _ctx: &mut PluginContext,
+    ) -> Result<(), PluginError> {
+        Err(PluginError::UnknownRoute("no streams registered".into()))
+    }
+
+    /// Handle a text message sent by a WebSocket stream client.
+    ///
+    /// Default: ignores the message
+    fn on_stream_message(&mut self, _stream: StreamId, _message: String, _ctx: &mut PluginContext) {
+    }
+
+    /// Called once a stream's client has disconnected.
+    ///
+    /// Default: no-op
+    fn on_stream_closed(&mut self, _stream: StreamId, _ctx: &mut PluginContext) {}
 }
 
 /// Export a plugin type for dynamic loading.
@@ -327,7 +371,7 @@ mod tests {
 
     #[test]
     fn test_api_version_is_set() {
-        assert_eq!(API_VERSION, 3);
+        assert_eq!(API_VERSION, 8);
     }
 
     #[test]
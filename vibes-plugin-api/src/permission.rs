@@ -0,0 +1,165 @@
+//! Plugin permissions declared in the manifest
+//!
+//! A plugin lists what it needs in [`PluginManifest::permissions`]. The user
+//! approves that list at `vibes plugin enable`, and the host only grants
+//! permissions that are both declared and approved. Anything else is refused
+//! by `PluginContext`.
+//!
+//! [`PluginManifest::permissions`]: crate::PluginManifest::permissions
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Something a plugin is allowed to do
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Permission {
+    /// Receive events in `on_event()` and read the event log
+    ReadEvents,
+    /// Publish events with `PluginContext::publish`
+    PublishEvents,
+    /// Register HTTP routes and streaming routes
+    RegisterRoutes,
+    /// Answer Claude Code hooks in `on_hook()`
+    AnswerHooks,
+    /// Access files under a directory
+    Filesystem {
+        /// Directory the plugin may access (including everything below it)
+        path: PathBuf,
+        /// Whether the plugin may also write there
+        #[serde(default)]
+        write: bool,
+    },
+    /// Spawn child processes
+    SpawnProcesses,
+}
+
+impl Permission {
+    /// Read-only access to files under `path`
+    pub fn read_path(path: impl Into<PathBuf>) -> Self {
+        Self::Filesystem {
+            path: path.into(),
+            write: false,
+        }
+    }
+
+    /// Read and write access to files under `path`
+    pub fn write_path(path: impl Into<PathBuf>) -> Self {
+        Self::Filesystem {
+            path: path.into(),
+            write: true,
+        }
+    }
+
+    /// Whether holding this permission covers `requested`
+    ///
+    /// Filesystem permissions cover any path below their directory, and
+    /// write access covers read access.
+    pub fn covers(&self, requested: &Permission) -> bool {
+        match (self, requested) {
+            (
+                Self::Filesystem { path, write },
+                Self::Filesystem {
+                    path: wanted,
+                    write: wants_write,
+                },
+            ) => (*write || !*wants_write) && covers_path(path, wanted),
+            _ => self == requested,
+        }
+    }
+}
+
+/// Whether `wanted` is `dir` or lies below it, without touching the disk
+fn covers_path(dir: &Path, wanted: &Path) -> bool {
+    // Refuse `..` outright rather than trying to normalize it away
+    let escapes = wanted
+        .components()
+        .any(|c| matches!(c, std::path::Component::ParentDir));
+    !escapes && wanted.starts_with(dir)
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadEvents => write!(f, "read events"),
+            Self::PublishEvents => write!(f, "publish events"),
+            Self::RegisterRoutes => write!(f, "register HTTP routes"),
+            Self::AnswerHooks => write!(f, "answer Claude Code hooks"),
+            Self::Filesystem { path, write: false } => {
+                write!(f, "read files under {}", path.display())
+            }
+            Self::Filesystem { path, write: true } => {
+                write!(f, "read and write files under {}", path.display())
+            }
+            Self::SpawnProcesses => write!(f, "spawn processes"),
+        }
+    }
+}
+
+/// Whether any permission in `granted` covers `requested`
+pub fn is_granted(granted: &[Permission], requested: &Permission) -> bool {
+    granted.iter().any(|p| p.covers(requested))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_permissions_cover_only_themselves() {
+        assert!(Permission::ReadEvents.covers(&Permission::ReadEvents));
+        assert!(!Permission::ReadEvents.covers(&Permission::PublishEvents));
+        assert!(!Permission::SpawnProcesses.covers(&Permission::read_path("/tmp")));
+    }
+
+    #[test]
+    fn test_filesystem_permission_covers_subpaths() {
+        let granted = Permission::read_path("/data/plugin");
+
+        assert!(granted.covers(&Permission::read_path("/data/plugin")));
+        assert!(granted.covers(&Permission::read_path("/data/plugin/cache/x.db")));
+        assert!(!granted.covers(&Permission::read_path("/data/plugin-other")));
+        assert!(!granted.covers(&Permission::read_path("/data/plugin/../secrets")));
+    }
+
+    #[test]
+    fn test_write_permission_covers_reads_but_not_vice_versa() {
+        let read = Permission::read_path("/data");
+        let write = Permission::write_path("/data");
+
+        assert!(write.covers(&Permission::read_path("/data/a")));
+        assert!(write.covers(&Permission::write_path("/data/a")));
+        assert!(!read.covers(&Permission::write_path("/data/a")));
+    }
+
+    #[test]
+    fn test_permission_serialization() {
+        let perms = vec![
+            Permission::ReadEvents,
+            Permission::write_path("/data/groove"),
+        ];
+        let json = serde_json::to_string(&perms).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"type":"read_events"},{"type":"filesystem","path":"/data/groove","write":true}]"#
+        );
+
+        let parsed: Vec<Permission> =
+            serde_json::from_str(r#"[{"type":"filesystem","path":"/tmp"}]"#).unwrap();
+        assert_eq!(parsed, vec![Permission::read_path("/tmp")]);
+    }
+
+    #[test]
+    fn test_permission_display() {
+        assert_eq!(
+            Permission::AnswerHooks.to_string(),
+            "answer Claude Code hooks"
+        );
+        assert_eq!(
+            Permission::write_path("/data").to_string(),
+            "read and write files under /data"
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! Declarative TUI views for plugins
+//!
+//! Plugins can't draw to the terminal themselves. Instead they describe a
+//! view as a stack of widgets, each backed by one of the plugin's own `GET`
+//! routes, and the TUI fetches the JSON and renders it with its theme.
+//!
+//! Fields inside a route's response are addressed with JSON pointers
+//! (RFC 6901), e.g. `/learnings` or `/counts_by_scope/project`. The empty
+//! pointer `""` refers to the whole response.
+
+use serde::{Deserialize, Serialize};
+
+/// A view a plugin contributes to the TUI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuiViewSpec {
+    /// Identifier, unique within the plugin (e.g. "learnings")
+    pub id: String,
+    /// Title shown in the view's header
+    pub title: String,
+    /// Widgets, stacked top to bottom
+    pub widgets: Vec<TuiWidget>,
+}
+
+/// A widget inside a plugin view
+///
+/// `source` is a `GET` route path relative to the plugin's prefix, and may
+/// carry a query string: `/learnings?per_page=50`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TuiWidget {
+    /// One line per item, with an optional dimmed detail
+    List {
+        title: String,
+        source: String,
+        /// Pointer to the array of items
+        #[serde(default)]
+        items: String,
+        /// Pointer, within each item, to the main text
+        label: String,
+        /// Pointer, within each item, to secondary text
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+    /// Rows of items with one column per field
+    Table {
+        title: String,
+        source: String,
+        /// Pointer to the array of items
+        #[serde(default)]
+        items: String,
+        columns: Vec<TuiColumn>,
+    },
+    /// Labelled values picked out of a single response
+    KeyValue {
+        title: String,
+        source: String,
+        fields: Vec<TuiField>,
+    },
+    /// Small bar chart of a numeric series
+    Sparkline {
+        title: String,
+        source: String,
+        /// Pointer to the array of items
+        #[serde(default)]
+        items: String,
+        /// Pointer, within each item, to the number (`""` for plain numbers)
+        #[serde(default)]
+        value: String,
+    },
+}
+
+impl TuiWidget {
+    /// Title shown on the widget's border
+    pub fn title(&self) -> &str {
+        match self {
+            Self::List { title, .. }
+            | Self::Table { title, .. }
+            | Self::KeyValue { title, .. }
+            | Self::Sparkline { title, .. } => title,
+        }
+    }
+
+    /// Route the widget reads from
+    pub fn source(&self) -> &str {
+        match self {
+            Self::List { source, .. }
+            | Self::Table { source, .. }
+            | Self::KeyValue { source, .. }
+            | Self::Sparkline { source, .. } => source,
+        }
+    }
+}
+
+/// A table column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuiColumn {
+    /// Column header
+    pub header: String,
+    /// Pointer, within each item, to the cell value
+    pub field: String,
+    /// Fixed width in cells; columns without one share the remaining space
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+}
+
+impl TuiColumn {
+    /// Column that shares the remaining width
+    pub fn new(header: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            field: field.into(),
+            width: None,
+        }
+    }
+
+    /// Set a fixed width
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+/// A labelled value in a key-value pane
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuiField {
+    /// Label shown before the value
+    pub label: String,
+    /// Pointer to the value
+    pub field: String,
+}
+
+impl TuiField {
+    /// Labelled value at `field`
+    pub fn new(label: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            field: field.into(),
+        }
+    }
+}
+
+/// A command-bar command, run as `:<plugin-name> <name> [args...]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuiCommandSpec {
+    /// Subcommand name (e.g. "learnings")
+    pub name: String,
+    /// Short description for completions and help
+    pub description: String,
+    /// What running the command does
+    pub action: TuiAction,
+}
+
+impl TuiCommandSpec {
+    /// Command that opens one of the plugin's views
+    pub fn open_view(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        view: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            action: TuiAction::OpenView { view: view.into() },
+        }
+    }
+
+    /// Command that sends a `POST` to one of the plugin's routes
+    pub fn post(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            action: TuiAction::Post { path: path.into() },
+        }
+    }
+}
+
+/// Action behind a command-bar command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TuiAction {
+    /// Open a view registered by the same plugin
+    OpenView { view: String },
+    /// `POST` to a route; `:param` segments are filled from the command's
+    /// arguments in order
+    Post { path: String },
+}
+
+/// Everything one plugin contributes to the TUI
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PluginTui {
+    /// Plugin name, which is also its command-bar command
+    pub plugin: String,
+    pub views: Vec<TuiViewSpec>,
+    pub commands: Vec<TuiCommandSpec>,
+}
+
+impl PluginTui {
+    /// Find a view by id
+    pub fn view(&self, id: &str) -> Option<&TuiViewSpec> {
+        self.views.iter().find(|v| v.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widget_serialization() {
+        let widget = TuiWidget::Table {
+            title: "Learnings".into(),
+            source: "/learnings".into(),
+            items: "/learnings".into(),
+            columns: vec![TuiColumn::new("Category", "/category").width(14)],
+        };
+        let json = serde_json::to_value(&widget).unwrap();
+        assert_eq!(json["type"], "table");
+        assert_eq!(json["columns"][0]["width"], 14);
+
+        let parsed: TuiWidget = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, widget);
+    }
+
+    #[test]
+    fn test_widget_items_default_to_whole_response() {
+        let parsed: TuiWidget =
+            serde_json::from_str(r#"{"type":"sparkline","title":"Values","source":"/values"}"#)
+                .unwrap();
+        let TuiWidget::Sparkline { items, value, .. } = parsed else {
+            panic!("expected sparkline");
+        };
+        assert_eq!(items, "");
+        assert_eq!(value, "");
+    }
+
+    #[test]
+    fn test_command_action_serialization() {
+        let cmd = TuiCommandSpec::post("dismiss", "Dismiss a gap", "/gaps/:id/dismiss");
+        let json = serde_json::to_string(&cmd.action).unwrap();
+        assert_eq!(json, r#"{"type":"post","path":"/gaps/:id/dismiss"}"#);
+    }
+
+    #[test]
+    fn test_plugin_tui_finds_view() {
+        let tui = PluginTui {
+            plugin: "groove".into(),
+            views: vec![TuiViewSpec {
+                id: "gaps".into(),
+                title: "Gaps".into(),
+                widgets: vec![],
+            }],
+            commands: vec![],
+        };
+        assert_eq!(tui.view("gaps").unwrap().title, "Gaps");
+        assert!(tui.view("missing").is_none());
+    }
+}
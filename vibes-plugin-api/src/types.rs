@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::permission::Permission;
+
 /// Plugin manifest containing metadata about the plugin
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginManifest {
@@ -19,6 +21,9 @@ pub struct PluginManifest {
     pub license: PluginLicense,
     /// Commands this plugin provides
     pub commands: Vec<CommandSpec>,
+    /// What the plugin needs to do; only approved permissions are granted
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
 }
 
 /// Plugin license type
@@ -72,6 +77,7 @@ impl Default for PluginManifest {
             author: String::new(),
             license: PluginLicense::default(),
             commands: Vec::new(),
+            permissions: Vec::new(),
         }
     }
 }
@@ -140,6 +146,7 @@ mod tests {
                     required: false,
                 }],
             }],
+            permissions: vec![Permission::ReadEvents, Permission::read_path("/tmp/test")],
         };
 
         let toml_str = toml::to_string(&manifest).expect("Failed to serialize");
@@ -148,6 +155,24 @@ mod tests {
         assert_eq!(manifest.name, parsed.name);
         assert_eq!(manifest.version, parsed.version);
         assert_eq!(manifest.commands.len(), parsed.commands.len());
+        assert_eq!(manifest.permissions, parsed.permissions);
+    }
+
+    #[test]
+    fn test_manifest_permissions_default_to_empty() {
+        let toml_str = r#"
+            name = "old-plugin"
+            version = "1.0.0"
+            api_version = 1
+            description = ""
+            author = ""
+            commands = []
+
+            [license]
+            type = "free"
+        "#;
+        let parsed: PluginManifest = toml::from_str(toml_str).expect("Failed to parse");
+        assert!(parsed.permissions.is_empty());
     }
 
     #[test]
@@ -0,0 +1,197 @@
+//! Harness capability watching
+//!
+//! A Claude Code upgrade, or another tool rewriting `~/.claude/settings.json`,
+//! can drop vibes' hooks and silently disable event capture. The daemon keeps
+//! a [`CapabilityWatcher`] running, publishes every drift it reports as a
+//! [`VibesEvent::CapabilityDrift`], and reinstalls the hooks when they vanish.
+
+use std::sync::Arc;
+
+use tokio::sync::broadcast::error::RecvError;
+use vibes_core::{HookInstaller, HookInstallerConfig, VibesEvent};
+use vibes_introspection::{
+    CapabilityChange, CapabilityDrift, CapabilityWatcher, ClaudeCodeHarness,
+};
+
+use crate::AppState;
+
+/// Quiet period before re-introspecting after a config file change
+const DEBOUNCE_MS: u64 = 500;
+
+/// Start watching Claude Code's config for capability drift
+///
+/// When `reinstall_hooks` is set, vibes hooks missing from settings.json are
+/// installed again as soon as the drift is noticed.
+pub(crate) async fn start(state: Arc<AppState>, reinstall_hooks: bool) {
+    let harness = Arc::new(ClaudeCodeHarness);
+    let watcher = match CapabilityWatcher::new(harness, None, DEBOUNCE_MS).await {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            // Not fatal - hooks were installed at startup, we just can't watch them
+            tracing::warn!("Failed to start capability watcher: {}", e);
+            return;
+        }
+    };
+    tracing::info!("Watching Claude Code capabilities for drift");
+
+    let installer = reinstall_hooks.then(|| HookInstaller::new(HookInstallerConfig::default()));
+    let mut drift_rx = watcher.subscribe();
+    let shutdown = state.consumer_shutdown_token();
+
+    tokio::spawn(async move {
+        // The watcher stops when dropped, so it lives as long as this task
+        let _watcher = watcher;
+        loop {
+            tokio::select! {
+                () = shutdown.cancelled() => break,
+                drift = drift_rx.recv() => match drift {
+                    Ok(drift) => handle_drift(&state, &drift, installer.as_ref()),
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Capability watcher skipped {} drift reports", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    });
+}
+
+/// Publish a drift report and repair vibes hooks if they went missing
+fn handle_drift(state: &AppState, drift: &CapabilityDrift, installer: Option<&HookInstaller>) {
+    for change in &drift.changes {
+        match change {
+            CapabilityChange::HooksRemoved { scope, hooks } => {
+                let names: Vec<_> = hooks.iter().map(|h| h.name.as_str()).collect();
+                tracing::warn!(
+                    "{} hooks removed from {:?} scope: {}",
+                    drift.harness_type,
+                    scope,
+                    names.join(", ")
+                );
+            }
+            CapabilityChange::VersionChanged { from, to } => {
+                tracing::info!(
+                    "{} version changed: {} -> {}",
+                    drift.harness_type,
+                    from.as_deref().unwrap_or("unknown"),
+                    to.as_deref().unwrap_or("unknown")
+                );
+            }
+            other => tracing::debug!("{} capability changed: {:?}", drift.harness_type, other),
+        }
+    }
+
+    state.append_event(VibesEvent::CapabilityDrift {
+        harness: drift.harness_type.clone(),
+        changes: drift.changes.clone(),
+    });
+
+    // Only hook or settings changes can have removed our hooks
+    let Some(installer) = installer else {
+        return;
+    };
+    if drift.removed_hooks().next().is_none() && !drift.config_changed() {
+        return;
+    }
+
+    match installer.missing_hooks() {
+        Ok(missing) if missing.is_empty() => {}
+        Ok(missing) => {
+            tracing::warn!(
+                "vibes hooks missing from settings.json ({}), reinstalling",
+                missing.join(", ")
+            );
+            match installer.install() {
+                Ok(()) => tracing::info!("Reinstalled Claude Code hooks"),
+                Err(e) => tracing::warn!("Failed to reinstall Claude Code hooks: {}", e),
+            }
+        }
+        Err(e) => tracing::warn!("Failed to check Claude Code hooks: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+    use tempfile::TempDir;
+    use vibes_iggy::SeekPosition;
+    use vibes_introspection::{HookType, InjectionScope, InstalledHook};
+
+    fn installer(claude_dir: &Path) -> HookInstaller {
+        HookInstaller::new(HookInstallerConfig {
+            claude_dir: Some(claude_dir.to_path_buf()),
+            vibes_bin: Some(PathBuf::from("/opt/vibes/bin/vibes")),
+        })
+    }
+
+    fn hooks_removed(settings: &Path) -> CapabilityDrift {
+        CapabilityDrift {
+            harness_type: "claude".to_string(),
+            changes: vec![CapabilityChange::HooksRemoved {
+                scope: InjectionScope::User,
+                hooks: vec![InstalledHook {
+                    hook_type: HookType::Stop,
+                    name: "/opt/vibes/bin/vibes hook stop".to_string(),
+                    path: settings.to_path_buf(),
+                }],
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_drift_reinstalls_missing_hooks() {
+        let temp = TempDir::new().unwrap();
+        let installer = installer(temp.path());
+        let settings = temp.path().join("settings.json");
+        std::fs::write(&settings, r#"{"model": "opus"}"#).unwrap();
+
+        let state = AppState::new();
+        handle_drift(&state, &hooks_removed(&settings), Some(&installer));
+
+        assert!(installer.missing_hooks().unwrap().is_empty());
+        let content = std::fs::read_to_string(&settings).unwrap();
+        assert!(content.contains("\"model\": \"opus\""));
+    }
+
+    #[tokio::test]
+    async fn test_handle_drift_without_installer_leaves_settings() {
+        let temp = TempDir::new().unwrap();
+        let settings = temp.path().join("settings.json");
+        std::fs::write(&settings, "{}").unwrap();
+
+        let state = AppState::new();
+        handle_drift(&state, &hooks_removed(&settings), None);
+
+        assert_eq!(std::fs::read_to_string(&settings).unwrap(), "{}");
+    }
+
+    #[tokio::test]
+    async fn test_handle_drift_publishes_event() {
+        let state = AppState::new();
+        let drift = CapabilityDrift {
+            harness_type: "claude".to_string(),
+            changes: vec![CapabilityChange::VersionChanged {
+                from: Some("1.0.0".to_string()),
+                to: Some("2.0.0".to_string()),
+            }],
+        };
+
+        handle_drift(&state, &drift, None);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let mut consumer = state.event_log.consumer("drift-reader").await.unwrap();
+        consumer.seek(SeekPosition::Beginning).await.unwrap();
+        let batch = consumer.poll(10, Duration::from_millis(100)).await.unwrap();
+
+        assert_eq!(batch.events.len(), 1);
+        assert_eq!(
+            batch.events[0].1.event,
+            VibesEvent::CapabilityDrift {
+                harness: "claude".to_string(),
+                changes: drift.changes,
+            }
+        );
+    }
+}
@@ -66,6 +66,9 @@ pub type EventHandler =
 pub type OffsetEventHandler =
     Arc<dyn Fn(Offset, StoredEvent) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 
+/// Type alias for periodic task callbacks.
+pub type TickHandler = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
 /// Configuration for a consumer.
 pub struct ConsumerConfig {
     /// Consumer group name (determines offset tracking).
@@ -299,6 +302,37 @@ impl ConsumerManager {
         self.spawn_consumer_with_offset(config, handler).await
     }
 
+    /// Spawn a task that calls `handler` every `interval` until shutdown.
+    ///
+    /// Used by consumers with time-based work (e.g. notification escalation)
+    /// so it stops together with the event-driven tasks.
+    pub fn spawn_periodic(
+        &mut self,
+        name: impl Into<String>,
+        interval: Duration,
+        handler: TickHandler,
+    ) {
+        let shutdown = self.shutdown.clone();
+        let name = name.into();
+
+        let handle = tokio::spawn(async move {
+            info!(task = %name, "Periodic task started");
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = ticker.tick() => handler().await,
+                }
+            }
+
+            info!(task = %name, "Periodic task stopped");
+        });
+
+        self.handles.push(handle);
+    }
+
     /// Signal all consumers to shut down gracefully.
     pub fn shutdown(&self) {
         info!("Signaling consumer shutdown");
@@ -533,4 +567,30 @@ mod tests {
         manager.shutdown();
         manager.wait_for_shutdown().await;
     }
+
+    #[tokio::test]
+    async fn test_spawn_periodic_runs_until_shutdown() {
+        let log = Arc::new(InMemoryEventLog::<StoredEvent>::new());
+        let mut manager = ConsumerManager::new(log);
+
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticks_clone = ticks.clone();
+        let handler: TickHandler = Arc::new(move || {
+            let ticks = ticks_clone.clone();
+            Box::pin(async move {
+                ticks.fetch_add(1, Ordering::SeqCst);
+            })
+        });
+
+        manager.spawn_periodic("ticker", Duration::from_millis(10), handler);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        manager.shutdown();
+        manager.wait_for_shutdown().await;
+
+        let after_shutdown = ticks.load(Ordering::SeqCst);
+        assert!(after_shutdown >= 2);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), after_shutdown);
+    }
 }
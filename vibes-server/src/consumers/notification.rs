@@ -1,14 +1,20 @@
 //! Notification event consumer.
 //!
 //! This consumer reads from the EventLog and dispatches events to the
-//! NotificationService for push notification delivery.
+//! NotificationService, which evaluates the notification rules and delivers
+//! through the configured sinks. A periodic tick releases time-based
+//! notifications: deferred rules, aggregation windows, escalations and the
+//! quiet hours digest.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use vibes_core::NotificationService;
 
-use super::{ConsumerConfig, ConsumerManager, EventHandler, Result};
+use super::{ConsumerConfig, ConsumerManager, EventHandler, Result, TickHandler};
+
+/// How often time-based notification rules are evaluated
+const RULE_TICK_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Start the notification consumer that dispatches events to the notification service.
 ///
@@ -28,15 +34,26 @@ pub async fn start_notification_consumer(
         .with_poll_timeout(Duration::from_millis(100))
         .with_batch_size(50);
 
+    let service = notification_service.clone();
     let handler: EventHandler = Arc::new(move |stored| {
-        let service = notification_service.clone();
+        let service = service.clone();
         Box::pin(async move {
             // Extract inner event - NotificationService works with VibesEvent
             service.process_event(&stored.event).await;
         })
     });
 
-    manager.spawn_consumer(config, handler).await
+    manager.spawn_consumer(config, handler).await?;
+
+    let tick: TickHandler = Arc::new(move || {
+        let service = notification_service.clone();
+        Box::pin(async move {
+            service.tick().await;
+        })
+    });
+    manager.spawn_periodic("notification-rules", RULE_TICK_INTERVAL, tick);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -50,7 +67,7 @@ mod tests {
         let log = Arc::new(InMemoryEventLog::<StoredEvent>::new());
         let manager = ConsumerManager::new(log);
 
-        // We can't use a real NotificationService without VAPID keys,
+        // A NotificationService with Web Push needs VAPID keys,
         // so we test the consumer configuration instead.
         let config = ConsumerConfig::live("notifications")
             .with_poll_timeout(Duration::from_millis(100))
@@ -63,6 +80,25 @@ mod tests {
         manager.wait_for_shutdown().await;
     }
 
+    #[tokio::test]
+    async fn test_notification_consumer_starts_rule_ticker() {
+        let log = Arc::new(InMemoryEventLog::<StoredEvent>::new());
+        let mut manager = ConsumerManager::new(log);
+        let service = Arc::new(
+            NotificationService::from_config(vibes_core::NotificationConfig::default()).unwrap(),
+        );
+
+        start_notification_consumer(&mut manager, service)
+            .await
+            .unwrap();
+
+        // Event consumer plus the periodic rule tick
+        assert_eq!(manager.consumer_count(), 2);
+
+        manager.shutdown();
+        manager.wait_for_shutdown().await;
+    }
+
     #[tokio::test]
     async fn test_notification_consumer_uses_live_mode() {
         // Verify the consumer starts at End (live mode) - only new events
@@ -0,0 +1,107 @@
+//! Claude Code hook API handler
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::instrument;
+use vibes_core::VibesEvent;
+use vibes_core::hooks::HookEvent;
+
+use crate::AppState;
+
+/// Query parameters for POST /api/hooks
+#[derive(Debug, Default, Deserialize)]
+pub struct HookQuery {
+    /// Session ID override (defaults to the session in the hook payload)
+    pub session_id: Option<String>,
+}
+
+/// Record a hook event and return the plugins' decision for Claude Code
+///
+/// The event is appended to the EventLog, then dispatched to plugins
+/// synchronously. The response body is Claude Code hook output, ready to be
+/// printed by the hook command.
+#[instrument(name = "hooks::handle", skip_all)]
+pub async fn handle_hook(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HookQuery>,
+    Json(event): Json<HookEvent>,
+) -> Json<Value> {
+    let session_id = query
+        .session_id
+        .or_else(|| event.session_id().map(str::to_string));
+
+    Json(answer_hook(&state, session_id, event).await)
+}
+
+/// Dispatch a hook to plugins, record it, and return Claude Code hook output
+///
+/// Shared by the HTTP endpoint and the `vibes hook` socket.
+pub(crate) async fn answer_hook(
+    state: &AppState,
+    session_id: Option<String>,
+    event: HookEvent,
+) -> Value {
+    let hook_type = event.hook_type();
+
+    let response = state
+        .plugin_host()
+        .write()
+        .await
+        .dispatch_hook(session_id.as_deref(), &event);
+
+    state.append_event(VibesEvent::Hook { session_id, event });
+
+    response.to_claude_output(&hook_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, routing::post};
+    use axum_test::TestServer;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_hook_without_plugins_returns_empty_output() {
+        let state = Arc::new(AppState::new());
+        let app = Router::new()
+            .route("/api/hooks", post(handle_hook))
+            .with_state(state);
+        let server = TestServer::new(app).unwrap();
+
+        let response = server
+            .post("/api/hooks")
+            .json(&json!({
+                "type": "pre_tool_use",
+                "session_id": "sess-1",
+                "tool_name": "Bash",
+                "tool_input": {"command": "ls"}
+            }))
+            .await;
+
+        response.assert_status_ok();
+        response.assert_json(&json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_hook_rejects_unknown_type() {
+        let state = Arc::new(AppState::new());
+        let app = Router::new()
+            .route("/api/hooks", post(handle_hook))
+            .with_state(state);
+        let server = TestServer::new(app).unwrap();
+
+        let response = server
+            .post("/api/hooks")
+            .json(&json!({"type": "not_a_hook"}))
+            .await;
+
+        assert!(response.status_code().is_client_error());
+    }
+}
@@ -1,6 +1,7 @@
 //! HTTP server module
 
 mod api;
+pub(crate) mod hooks;
 pub mod plugins;
 mod push;
 mod static_files;
@@ -22,8 +23,8 @@ pub use api::{
     TunnelStatusResponse,
 };
 pub use push::{
-    PushErrorResponse, SubscribeRequest, SubscribeResponse, SubscriptionInfo,
-    SubscriptionListResponse, VapidKeyResponse,
+    ActionRequest, ActionResponse, PushErrorResponse, SubscribeRequest, SubscribeResponse,
+    SubscriptionInfo, SubscriptionListResponse, VapidKeyResponse,
 };
 
 /// Create the HTTP router with all routes configured
@@ -40,6 +41,12 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/api/push/subscribe", post(push::subscribe))
         .route("/api/push/subscribe/:id", delete(push::unsubscribe))
         .route("/api/push/subscriptions", get(push::list_subscriptions))
+        .route("/api/push/action", post(push::notification_action))
+        // Claude Code hook decisions
+        .route("/api/hooks", post(hooks::handle_hook))
+        // Plugin management
+        .route("/api/plugins/tui", get(plugins::list_tui))
+        .route("/api/plugins/:name/reload", post(plugins::reload_plugin))
         .route("/ws", get(ws_handler))
         .route("/ws/firehose", get(firehose_ws))
         .route("/ws/assessment", get(assessment_ws))
@@ -97,6 +104,38 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_reload_unknown_plugin_returns_not_found() {
+        use tokio::sync::RwLock;
+        use vibes_core::{PluginHost, PluginHostConfig};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let host = PluginHost::new(PluginHostConfig {
+            user_plugin_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let state = AppState::with_plugin_host(Arc::new(RwLock::new(host)));
+        let router = create_router(Arc::new(state));
+        let server =
+            TestServer::new(router.into_make_service_with_connect_info::<SocketAddr>()).unwrap();
+
+        let response = server.post("/api/plugins/missing/reload").await;
+        response.assert_status_not_found();
+        assert!(response.text().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_list_tui_without_plugins_is_empty() {
+        let state = Arc::new(AppState::new());
+        let router = create_router(state);
+        let server =
+            TestServer::new(router.into_make_service_with_connect_info::<SocketAddr>()).unwrap();
+
+        let response = server.get("/api/plugins/tui").await;
+        response.assert_status_ok();
+        response.assert_json(&serde_json::json!([]));
+    }
+
     #[tokio::test]
     async fn test_compression_gzip_enabled() {
         let state = Arc::new(AppState::new());
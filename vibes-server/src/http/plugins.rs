@@ -1,19 +1,30 @@
 //! Plugin HTTP route handler
 
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
-    Router,
+    Json, Router,
     body::Body,
-    extract::{Request, State},
+    extract::{
+        FromRequest, Path, Request, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
     http::StatusCode,
-    response::{IntoResponse, Response},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
     routing::any,
 };
+use futures::{SinkExt, StreamExt, stream};
 use serde_json::json;
-use tracing::instrument;
-use vibes_plugin_api::{HttpMethod, RouteRequest};
+use tokio::sync::mpsc;
+use tracing::{debug, instrument};
+use vibes_core::PluginHostError;
+use vibes_plugin_api::{HttpMethod, PluginTui, RouteRequest, StreamId, StreamKind, StreamMessage};
 
 use crate::AppState;
 
@@ -45,6 +56,30 @@ pub async fn handle_plugin_route(State(state): State<Arc<AppState>>, request: Re
 
     // Get plugin host and find matching route
     let plugin_host = state.plugin_host().read().await;
+    if method == HttpMethod::Get
+        && let Some((stream, params)) = plugin_host.route_registry().match_stream(&path)
+    {
+        let plugin_name = stream.plugin_name.clone();
+        let stream_path = stream.spec.path.clone();
+        let kind = stream.spec.kind;
+        drop(plugin_host);
+
+        let route_request = RouteRequest {
+            params,
+            query,
+            body: vec![],
+            headers,
+        };
+        return open_plugin_stream(
+            state,
+            plugin_name,
+            stream_path,
+            kind,
+            route_request,
+            request,
+        )
+        .await;
+    }
     let Some((route, params)) = plugin_host.route_registry().match_route(method, &path) else {
         return (StatusCode::NOT_FOUND, r#"{"error":"Not found"}"#).into_response();
     };
@@ -79,20 +114,195 @@ pub async fn handle_plugin_route(State(state): State<Arc<AppState>>, request: Re
                 tracing::error!("Failed to build HTTP response: {e}");
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
             }),
-        Err(e) => {
-            let error_json = json!({"error": e.to_string()});
-            Response::builder()
-                .status(500)
-                .header("Content-Type", "application/json")
-                .body(Body::from(error_json.to_string()))
-                .unwrap_or_else(|e| {
-                    tracing::error!("Failed to build HTTP error response: {e}");
-                    (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
-                })
+        Err(e) => plugin_error_response(e),
+    }
+}
+
+/// JSON 500 response for a plugin failure
+fn plugin_error_response(e: PluginHostError) -> Response {
+    let error_json = json!({"error": e.to_string()});
+    Response::builder()
+        .status(500)
+        .header("Content-Type", "application/json")
+        .body(Body::from(error_json.to_string()))
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to build HTTP error response: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
+        })
+}
+
+// ─── Plugin Streams ──────────────────────────────────────────────────
+
+/// Tells the plugin its stream closed once the connection is gone
+struct StreamGuard {
+    state: Arc<AppState>,
+    plugin_name: String,
+    id: StreamId,
+}
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        let state = Arc::clone(&self.state);
+        let plugin_name = std::mem::take(&mut self.plugin_name);
+        let id = self.id;
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                state
+                    .plugin_host()
+                    .write()
+                    .await
+                    .close_stream(&plugin_name, id);
+            });
+        }
+    }
+}
+
+/// Connect a client to a plugin stream over SSE or WebSocket
+///
+/// The host owns the connection: the plugin only sees a `StreamHandle`,
+/// and messages it pushes are forwarded until either side goes away or the
+/// server shuts down.
+async fn open_plugin_stream(
+    state: Arc<AppState>,
+    plugin_name: String,
+    path: String,
+    kind: StreamKind,
+    route_request: RouteRequest,
+    request: Request,
+) -> Response {
+    // Reject bad upgrades before the plugin sees the stream
+    let upgrade = match kind {
+        StreamKind::WebSocket => match WebSocketUpgrade::from_request(request, &state).await {
+            Ok(upgrade) => Some(upgrade),
+            Err(rejection) => return rejection.into_response(),
+        },
+        StreamKind::Sse => None,
+    };
+
+    let opened = state
+        .plugin_host()
+        .write()
+        .await
+        .open_stream(&plugin_name, &path, route_request);
+    let stream = match opened {
+        Ok(stream) => stream,
+        Err(e) => return plugin_error_response(e),
+    };
+    debug!(plugin = %plugin_name, path = %path, id = stream.id, ?kind, "Plugin stream opened");
+
+    let guard = StreamGuard {
+        state: Arc::clone(&state),
+        plugin_name,
+        id: stream.id,
+    };
+    match upgrade {
+        Some(upgrade) => {
+            upgrade.on_upgrade(move |socket| serve_websocket_stream(socket, stream.messages, guard))
         }
+        None => serve_sse_stream(stream.messages, guard),
     }
 }
 
+/// Serve plugin messages as server-sent events
+fn serve_sse_stream(messages: mpsc::Receiver<StreamMessage>, guard: StreamGuard) -> Response {
+    let shutdown = guard.state.consumer_shutdown_token();
+    let events = stream::unfold((messages, guard), |(mut messages, guard)| async move {
+        let message = messages.recv().await?;
+        Some((
+            Ok::<_, Infallible>(to_sse_event(message)),
+            (messages, guard),
+        ))
+    })
+    .take_until(shutdown.cancelled_owned());
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+        .into_response()
+}
+
+/// Convert a plugin message to an SSE event
+fn to_sse_event(message: StreamMessage) -> Event {
+    let event = Event::default().data(message.data);
+    match message.event {
+        Some(name) => event.event(name),
+        None => event,
+    }
+}
+
+/// Forward plugin messages to a WebSocket and client text back to the plugin
+async fn serve_websocket_stream(
+    socket: WebSocket,
+    mut messages: mpsc::Receiver<StreamMessage>,
+    guard: StreamGuard,
+) {
+    let (mut sender, mut receiver) = socket.split();
+    let shutdown = guard.state.consumer_shutdown_token();
+
+    loop {
+        tokio::select! {
+            () = shutdown.cancelled() => break,
+            message = messages.recv() => {
+                let Some(message) = message else { break };
+                if sender.send(Message::Text(message.data)).await.is_err() {
+                    break;
+                }
+            }
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    let result = guard
+                        .state
+                        .plugin_host()
+                        .write()
+                        .await
+                        .dispatch_stream_message(&guard.plugin_name, guard.id, text);
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {} // Ignore binary, ping and pong frames
+            },
+        }
+    }
+
+    let _ = sender.send(Message::Close(None)).await;
+    debug!(plugin = %guard.plugin_name, id = guard.id, "Plugin stream closed");
+}
+
+/// Reload a plugin from disk without restarting the daemon
+///
+/// If the new build fails to load, the running version is kept and the
+/// error is returned.
+#[instrument(name = "plugin::reload", skip(state))]
+pub async fn reload_plugin(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Response {
+    let mut plugin_host = state.plugin_host().write().await;
+    match plugin_host.reload_plugin(&name) {
+        Ok(()) => {
+            let version = plugin_host
+                .get_plugin_info(&name)
+                .map(|info| info.manifest.version);
+            Json(json!({"plugin": name, "version": version})).into_response()
+        }
+        Err(e @ PluginHostError::NotFound { .. }) => {
+            (StatusCode::NOT_FOUND, Json(json!({"error": e.to_string()}))).into_response()
+        }
+        Err(e) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
+
+/// List the views and command-bar commands plugins contribute to the TUI
+#[instrument(name = "plugin::tui", skip(state))]
+pub async fn list_tui(State(state): State<Arc<AppState>>) -> Json<Vec<PluginTui>> {
+    Json(state.plugin_host().read().await.tui_registry().list())
+}
+
 fn parse_query(query: Option<&str>) -> HashMap<String, String> {
     query
         .map(|q| {
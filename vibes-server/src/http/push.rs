@@ -9,8 +9,8 @@ use axum::{
     response::IntoResponse,
 };
 use serde::{Deserialize, Serialize};
-use tracing::instrument;
-use vibes_core::{PushSubscription, SubscriptionKeys};
+use tracing::{info, instrument};
+use vibes_core::{ActionTokenError, PushSubscription, SubscriptionKeys, VibesEvent};
 
 use crate::AppState;
 
@@ -71,6 +71,24 @@ pub struct SubscriptionInfo {
     pub created_at: String,
 }
 
+/// Request body for POST /api/push/action
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActionRequest {
+    /// Signed action token from the notification
+    pub token: String,
+}
+
+/// Response for POST /api/push/action
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActionResponse {
+    /// Session the permission request belongs to
+    pub session_id: String,
+    /// Permission request that was answered
+    pub request_id: String,
+    /// Whether the request was approved
+    pub approved: bool,
+}
+
 /// Error response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PushErrorResponse {
@@ -230,6 +248,70 @@ pub async fn list_subscriptions(State(state): State<Arc<AppState>>) -> impl Into
         .into_response()
 }
 
+/// POST /api/push/action - Answer a permission request from a notification action
+///
+/// Redeems a signed action token and emits the matching
+/// `VibesEvent::PermissionResponse`. Each request can be answered once.
+#[instrument(name = "api::push::action", skip_all)]
+pub async fn notification_action(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<ActionRequest>,
+) -> impl IntoResponse {
+    let signer = match &state.action_signer {
+        Some(s) => s,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(PushErrorResponse {
+                    error: "Notification actions not configured".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let claims = match signer.redeem(&request.token, chrono::Utc::now()) {
+        Ok(claims) => claims,
+        Err(e) => {
+            let status = match e {
+                ActionTokenError::Malformed => StatusCode::BAD_REQUEST,
+                ActionTokenError::InvalidSignature => StatusCode::FORBIDDEN,
+                ActionTokenError::Expired => StatusCode::GONE,
+                ActionTokenError::AlreadyUsed => StatusCode::CONFLICT,
+            };
+            return (
+                status,
+                Json(PushErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    info!(
+        session_id = %claims.session_id,
+        request_id = %claims.request_id,
+        approved = claims.approved,
+        "Permission answered from notification"
+    );
+    state.append_event(VibesEvent::PermissionResponse {
+        session_id: claims.session_id.clone(),
+        request_id: claims.request_id.clone(),
+        approved: claims.approved,
+    });
+
+    (
+        StatusCode::OK,
+        Json(ActionResponse {
+            session_id: claims.session_id,
+            request_id: claims.request_id,
+            approved: claims.approved,
+        }),
+    )
+        .into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,6 +320,7 @@ mod tests {
         routing::{delete, get, post},
     };
     use axum_test::TestServer;
+    use vibes_core::ActionSigner;
 
     fn create_test_app() -> Router {
         let state = Arc::new(AppState::new());
@@ -246,9 +329,18 @@ mod tests {
             .route("/api/push/subscribe", post(subscribe))
             .route("/api/push/subscribe/:id", delete(unsubscribe))
             .route("/api/push/subscriptions", get(list_subscriptions))
+            .route("/api/push/action", post(notification_action))
             .with_state(state)
     }
 
+    fn create_action_app(signer: Arc<ActionSigner>) -> (Router, Arc<AppState>) {
+        let state = Arc::new(AppState::new().with_action_signer(signer));
+        let router = Router::new()
+            .route("/api/push/action", post(notification_action))
+            .with_state(state.clone());
+        (router, state)
+    }
+
     #[tokio::test]
     async fn test_vapid_key_not_configured() {
         let server = TestServer::new(create_test_app()).unwrap();
@@ -294,4 +386,113 @@ mod tests {
         let response = server.get("/api/push/subscriptions").await;
         response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
     }
+
+    #[tokio::test]
+    async fn test_action_not_configured() {
+        let server = TestServer::new(create_test_app()).unwrap();
+
+        let response = server
+            .post("/api/push/action")
+            .json(&ActionRequest {
+                token: "anything".to_string(),
+            })
+            .await;
+        response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_action_emits_permission_response() {
+        use vibes_iggy::SeekPosition;
+
+        let signer = Arc::new(ActionSigner::new(b"key".to_vec()));
+        let token = signer.issue("sess-1", "req-1", true, chrono::Utc::now());
+        let (router, state) = create_action_app(signer);
+        let server = TestServer::new(router).unwrap();
+
+        let response = server
+            .post("/api/push/action")
+            .json(&ActionRequest { token })
+            .await;
+        response.assert_status_ok();
+        let body: ActionResponse = response.json();
+        assert!(body.approved);
+
+        // Give the spawned append task time to complete
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let mut consumer = state.event_log.consumer("test-reader").await.unwrap();
+        consumer.seek(SeekPosition::Beginning).await.unwrap();
+        let batch = consumer
+            .poll(10, std::time::Duration::from_millis(100))
+            .await
+            .unwrap();
+        assert_eq!(
+            batch.events[0].1.event,
+            VibesEvent::PermissionResponse {
+                session_id: "sess-1".to_string(),
+                request_id: "req-1".to_string(),
+                approved: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_action_rejects_replay() {
+        let signer = Arc::new(ActionSigner::new(b"key".to_vec()));
+        let now = chrono::Utc::now();
+        let approve = signer.issue("sess-1", "req-1", true, now);
+        let deny = signer.issue("sess-1", "req-1", false, now);
+        let (router, _state) = create_action_app(signer);
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest {
+                token: approve.clone(),
+            })
+            .await
+            .assert_status_ok();
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest { token: approve })
+            .await
+            .assert_status(StatusCode::CONFLICT);
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest { token: deny })
+            .await
+            .assert_status(StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_action_rejects_forged_and_expired_tokens() {
+        let signer = Arc::new(ActionSigner::new(b"key".to_vec()));
+        let forged =
+            ActionSigner::new(b"other".to_vec()).issue("sess-1", "req-1", true, chrono::Utc::now());
+        let expired = signer.issue(
+            "sess-1",
+            "req-2",
+            true,
+            chrono::Utc::now() - chrono::Duration::hours(1),
+        );
+        let (router, _state) = create_action_app(signer);
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest { token: forged })
+            .await
+            .assert_status(StatusCode::FORBIDDEN);
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest { token: expired })
+            .await
+            .assert_status(StatusCode::GONE);
+        server
+            .post("/api/push/action")
+            .json(&ActionRequest {
+                token: "garbage".to_string(),
+            })
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
 }
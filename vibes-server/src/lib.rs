@@ -4,6 +4,7 @@
 //! and PluginHost. Both CLI and Web UI connect as WebSocket clients.
 
 mod agent_registry;
+mod capability_watch;
 pub mod consumers;
 mod error;
 pub mod http;
@@ -18,9 +19,12 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use tokio::net::TcpListener;
+#[cfg(unix)]
+use vibes_core::hooks::socket::{self as hook_socket, HookRequest};
+use vibes_core::plugins::{DEFAULT_RELOAD_DEBOUNCE, PluginWatcher};
 use vibes_core::{
-    HookInstaller, HookInstallerConfig, NotificationConfig, NotificationService, SubscriptionStore,
-    TunnelConfig, TunnelEvent, VapidKeyManager,
+    ActionSigner, HookInstaller, HookInstallerConfig, NotificationConfig, NotificationService,
+    SubscriptionStore, TunnelConfig, TunnelEvent, VapidKeyManager,
 };
 
 use consumers::{
@@ -119,22 +123,28 @@ impl VibesServer {
             .map_err(|e| ServerError::Internal(format!("Failed to load subscriptions: {}", e)))?;
         let subscriptions = Arc::new(subscriptions);
 
+        // Action tokens are signed with a per-process key, so the service
+        // that issues them and the endpoint that redeems them share it
+        let action_signer = Arc::new(ActionSigner::random());
+
         // Create state with Iggy and push notification components
         let state = AppState::new_with_iggy()
             .await
             .map_err(|e| ServerError::Internal(format!("Failed to start Iggy: {}", e)))?
-            .with_push(vapid.clone(), subscriptions.clone());
+            .with_push(vapid.clone(), subscriptions.clone())
+            .with_action_signer(action_signer.clone());
         let state = Arc::new(state);
 
-        // Create notification service
-        let notification_config = NotificationConfig::default();
-        let notification_service = Arc::new(NotificationService::new(
-            vapid,
-            subscriptions,
-            notification_config,
-        ));
+        // Create notification service (Web Push plus any configured sinks)
+        let notification_service = Arc::new(
+            NotificationService::new(vapid, subscriptions, config.notifications.clone())
+                .with_action_signer(action_signer),
+        );
 
-        tracing::info!("Push notifications initialized");
+        tracing::info!(
+            "Notifications initialized with sinks: {}",
+            notification_service.sink_names().join(", ")
+        );
 
         Ok(Self {
             config,
@@ -205,6 +215,11 @@ impl VibesServer {
         // Install Claude Code hooks
         self.install_hooks();
 
+        // Keep them installed if the harness config changes under us
+        if self.config.watch_capabilities {
+            capability_watch::start(Arc::clone(&self.state), self.config.reinstall_hooks).await;
+        }
+
         // Load plugins
         self.load_plugins().await;
 
@@ -219,9 +234,20 @@ impl VibesServer {
         self.start_event_log_consumers(self.notification_service.clone())
             .await;
 
+        // Answer `vibes hook` on the local hook socket
+        self.start_hook_socket();
+
         let state = Arc::clone(&self.state);
         let router = create_router(self.state);
 
+        // Cancel the shutdown token as soon as the signal fires, so that
+        // long-lived plugin streams end instead of holding up graceful shutdown
+        let shutdown = state.consumer_shutdown_token();
+        let shutdown_signal = async move {
+            shutdown_signal.await;
+            shutdown.cancel();
+        };
+
         axum::serve(
             listener,
             router.into_make_service_with_connect_info::<SocketAddr>(),
@@ -281,6 +307,26 @@ impl VibesServer {
             plugin_host.notify_ready(event_log_any, shutdown.clone(), self.state.iggy_manager());
         }
 
+        // Hot-reload plugins when their files change, until shutdown
+        match PluginWatcher::new(
+            Arc::clone(self.state.plugin_host()),
+            DEFAULT_RELOAD_DEBOUNCE,
+        )
+        .await
+        {
+            Ok(watcher) => {
+                let shutdown = shutdown.clone();
+                tokio::spawn(async move {
+                    shutdown.cancelled().await;
+                    drop(watcher);
+                });
+                tracing::info!("Plugin hot reload enabled");
+            }
+            Err(e) => {
+                tracing::warn!("Failed to watch plugin directories: {}", e);
+            }
+        }
+
         // Start plugin event consumer
         // This consumer polls events and routes them to plugins via dispatch_raw_event
         // Results are broadcast via AppState for WebSocket clients
@@ -314,6 +360,38 @@ impl VibesServer {
         });
     }
 
+    /// Serve Claude Code hooks on the local hook socket until shutdown
+    #[cfg(unix)]
+    fn start_hook_socket(&self) {
+        let Some(path) = self.config.hook_socket.clone() else {
+            return;
+        };
+
+        let listener = match hook_socket::bind(&path) {
+            Ok(listener) => listener,
+            Err(e) => {
+                // Hooks fall back to recording events directly - not fatal
+                tracing::warn!("Failed to bind hook socket {}: {}", path.display(), e);
+                return;
+            }
+        };
+        tracing::info!("Hook socket listening on {}", path.display());
+
+        let state = Arc::clone(&self.state);
+        let shutdown = self.state.consumer_shutdown_token();
+        tokio::spawn(async move {
+            let handler = move |request: HookRequest| {
+                let state = Arc::clone(&state);
+                async move { http::hooks::answer_hook(&state, request.session_id, request.event).await }
+            };
+            hook_socket::serve(listener, handler, shutdown).await;
+            let _ = std::fs::remove_file(&path);
+        });
+    }
+
+    #[cfg(not(unix))]
+    fn start_hook_socket(&self) {}
+
     /// Install Claude Code hooks for structured event capture
     fn install_hooks(&self) {
         let installer = HookInstaller::new(HookInstallerConfig::default());
@@ -480,8 +558,16 @@ pub struct ServerConfig {
     pub tunnel_hostname: Option<String>,
     /// Enable push notifications
     pub notify_enabled: bool,
+    /// Notification filters, sinks and routing rules
+    pub notifications: NotificationConfig,
     /// Ollama base URL (e.g., "http://localhost:11434")
     pub ollama_base_url: Option<String>,
+    /// Local socket answering `vibes hook` (disabled if None)
+    pub hook_socket: Option<PathBuf>,
+    /// Watch harness config for capability drift and publish it as events
+    pub watch_capabilities: bool,
+    /// Reinstall vibes hooks when drift shows they were removed
+    pub reinstall_hooks: bool,
 }
 
 impl Default for ServerConfig {
@@ -494,7 +580,11 @@ impl Default for ServerConfig {
             tunnel_name: None,
             tunnel_hostname: None,
             notify_enabled: false,
+            notifications: NotificationConfig::default(),
             ollama_base_url: None,
+            hook_socket: None,
+            watch_capabilities: false,
+            reinstall_hooks: false,
         }
     }
 }
@@ -510,7 +600,11 @@ impl ServerConfig {
             tunnel_name: None,
             tunnel_hostname: None,
             notify_enabled: false,
+            notifications: NotificationConfig::default(),
             ollama_base_url: None,
+            hook_socket: None,
+            watch_capabilities: false,
+            reinstall_hooks: false,
         }
     }
 
@@ -6,8 +6,8 @@ use chrono::{DateTime, Utc};
 use tokio::sync::{RwLock, broadcast};
 use tokio_util::sync::CancellationToken;
 use vibes_core::{
-    AccessConfig, PluginHost, PluginHostConfig, StoredEvent, SubscriptionStore, TunnelConfig,
-    TunnelManager, VapidKeyManager, VibesEvent,
+    AccessConfig, ActionSigner, PluginHost, PluginHostConfig, StoredEvent, SubscriptionStore,
+    TunnelConfig, TunnelManager, VapidKeyManager, VibesEvent,
     pty::{PtyConfig, PtyManager},
 };
 use vibes_evals::{CreateStudy, PeriodType, Study, StudyConfig, StudyId, StudyManager};
@@ -63,6 +63,8 @@ pub struct AppState {
     pub vapid: Option<Arc<VapidKeyManager>>,
     /// Push subscription store (optional)
     pub subscriptions: Option<Arc<SubscriptionStore>>,
+    /// Signer for notification action tokens (optional)
+    pub action_signer: Option<Arc<ActionSigner>>,
     /// PTY session manager for terminal sessions
     pub pty_manager: Arc<RwLock<PtyManager>>,
     /// Broadcast channel for PTY output distribution
@@ -113,6 +115,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager: None,
             assessment_broadcaster,
@@ -137,6 +140,7 @@ impl AppState {
             user_plugin_dir: PathBuf::from("/nonexistent/vibes/plugins"),
             project_plugin_dir: None,
             handler_timeout: std::time::Duration::from_secs(5),
+            wasm_limits: Default::default(),
         };
 
         let event_log: Arc<dyn EventLog<StoredEvent>> =
@@ -160,6 +164,7 @@ impl AppState {
             event_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             pty_broadcaster,
             iggy_manager: None,
@@ -196,6 +201,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager: None,
             assessment_broadcaster,
@@ -242,6 +248,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager,
             assessment_broadcaster,
@@ -284,6 +291,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager,
             assessment_broadcaster,
@@ -357,6 +365,12 @@ impl AppState {
         self
     }
 
+    /// Configure the signer used to redeem notification action tokens
+    pub fn with_action_signer(mut self, signer: Arc<ActionSigner>) -> Self {
+        self.action_signer = Some(signer);
+        self
+    }
+
     /// Configure PTY settings for this state
     pub fn with_pty_config(mut self, config: PtyConfig) -> Self {
         self.pty_manager = Arc::new(RwLock::new(PtyManager::new(config)));
@@ -387,6 +401,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager: None,
             assessment_broadcaster,
@@ -421,6 +436,7 @@ impl AppState {
             pty_broadcaster,
             vapid: None,
             subscriptions: None,
+            action_signer: None,
             pty_manager,
             iggy_manager: None,
             assessment_broadcaster,
@@ -386,6 +386,7 @@ async fn handle_text_message(
             cwd,
             cols,
             rows,
+            harness,
         } => {
             debug!(
                 "PTY attach requested for session: {} ({}x{})",
@@ -400,71 +401,76 @@ async fn handle_text_message(
             let mut pty_manager = state.pty_manager.write().await;
 
             // Check if session exists and capture scrollback length for replay limiting
-            let (attach_cols, attach_rows, _scrollback_len) = if let Some(handle) =
-                pty_manager.get_handle(&session_id)
-            {
-                // Session exists - capture scrollback length BEFORE marking as attached.
-                // This prevents duplicate content: any output that arrives after this point
-                // will be sent as pty_output AND would also be in scrollback for replay.
-                // By recording the length now, replay will only include content up to here.
-                let scrollback_len = handle.get_scrollback().len();
-
-                // Mark as attached with the scrollback snapshot length
-                conn_state.attach_pty(&session_id, scrollback_len);
-
-                // Resize PTY to match client dimensions immediately.
-                // This ensures future output uses correct dimensions.
-                // Note: Scrollback was generated with old dimensions and may look wrong.
-                let attach_cols = cols.unwrap_or(120);
-                let attach_rows = rows.unwrap_or(40);
-                if let Err(e) = handle.resize(attach_cols, attach_rows).await {
-                    warn!("Failed to resize PTY on attach: {}", e);
-                }
+            let (attach_cols, attach_rows, _scrollback_len) =
+                if let Some(handle) = pty_manager.get_handle(&session_id) {
+                    // Session exists - capture scrollback length BEFORE marking as attached.
+                    // This prevents duplicate content: any output that arrives after this point
+                    // will be sent as pty_output AND would also be in scrollback for replay.
+                    // By recording the length now, replay will only include content up to here.
+                    let scrollback_len = handle.get_scrollback().len();
+
+                    // Mark as attached with the scrollback snapshot length
+                    conn_state.attach_pty(&session_id, scrollback_len);
+
+                    // Resize PTY to match client dimensions immediately.
+                    // This ensures future output uses correct dimensions.
+                    // Note: Scrollback was generated with old dimensions and may look wrong.
+                    let attach_cols = cols.unwrap_or(120);
+                    let attach_rows = rows.unwrap_or(40);
+                    if let Err(e) = handle.resize(attach_cols, attach_rows).await {
+                        warn!("Failed to resize PTY on attach: {}", e);
+                    }
 
-                (attach_cols, attach_rows, scrollback_len)
-            } else {
-                // Create new PTY session with client's requested dimensions
-                match pty_manager.create_session_with_id(session_id.clone(), name, cwd, cols, rows)
-                {
-                    Ok(created_id) => {
-                        debug!("Created new PTY session: {}", created_id);
-
-                        // Append session created event to EventLog for consumer processing
-                        state.append_event(VibesEvent::SessionCreated {
-                            session_id: created_id.clone(),
-                            name: session_name,
-                        });
-
-                        // New session has no scrollback yet
-                        conn_state.attach_pty(&session_id, 0);
-
-                        // Get handle for output reading
-                        if let Some(handle) = pty_manager.get_handle(&created_id) {
-                            // Spawn background task to read PTY output
-                            let state_clone = state.clone();
-                            let session_id_clone = created_id.clone();
-                            tokio::spawn(async move {
-                                pty_output_reader(state_clone, session_id_clone, handle).await;
+                    (attach_cols, attach_rows, scrollback_len)
+                } else {
+                    // Create new PTY session with client's requested dimensions
+                    match pty_manager.create_session_with_id(
+                        session_id.clone(),
+                        name,
+                        cwd,
+                        cols,
+                        rows,
+                        harness.as_deref(),
+                    ) {
+                        Ok(created_id) => {
+                            debug!("Created new PTY session: {}", created_id);
+
+                            // Append session created event to EventLog for consumer processing
+                            state.append_event(VibesEvent::SessionCreated {
+                                session_id: created_id.clone(),
+                                name: session_name,
                             });
-                        }
 
-                        // Return the dimensions that were actually used for the new PTY
-                        // (client-provided or defaults from config)
-                        // scrollback_len is 0 for new sessions
-                        (cols.unwrap_or(120), rows.unwrap_or(40), 0)
-                    }
-                    Err(e) => {
-                        let error = ServerMessage::Error {
-                            session_id: Some(session_id),
-                            message: format!("Failed to create PTY session: {}", e),
-                            code: "PTY_CREATE_FAILED".to_string(),
-                        };
-                        let json = serde_json::to_string(&error)?;
-                        sender.send(Message::Text(json)).await?;
-                        return Ok(());
+                            // New session has no scrollback yet
+                            conn_state.attach_pty(&session_id, 0);
+
+                            // Get handle for output reading
+                            if let Some(handle) = pty_manager.get_handle(&created_id) {
+                                // Spawn background task to read PTY output
+                                let state_clone = state.clone();
+                                let session_id_clone = created_id.clone();
+                                tokio::spawn(async move {
+                                    pty_output_reader(state_clone, session_id_clone, handle).await;
+                                });
+                            }
+
+                            // Return the dimensions that were actually used for the new PTY
+                            // (client-provided or defaults from config)
+                            // scrollback_len is 0 for new sessions
+                            (cols.unwrap_or(120), rows.unwrap_or(40), 0)
+                        }
+                        Err(e) => {
+                            let error = ServerMessage::Error {
+                                session_id: Some(session_id),
+                                message: format!("Failed to create PTY session: {}", e),
+                                code: "PTY_CREATE_FAILED".to_string(),
+                            };
+                            let json = serde_json::to_string(&error)?;
+                            sender.send(Message::Text(json)).await?;
+                            return Ok(());
+                        }
                     }
-                }
-            };
+                };
 
             // Note: Scrollback replay is deferred until first PtyResize.
             // This ensures the PTY dimensions match the client's terminal size,
@@ -395,8 +395,11 @@ fn event_display_category(event: &VibesEvent) -> &'static str {
         | VibesEvent::UserInput { .. }
         | VibesEvent::PermissionResponse { .. } => "claude",
 
-        // Hook events
-        VibesEvent::Hook { .. } => "hook",
+        // Hook events, including hooks vanishing from the harness config
+        VibesEvent::Hook { .. } | VibesEvent::CapabilityDrift { .. } => "hook",
+
+        // Events published by plugins
+        VibesEvent::Plugin { .. } => "plugin",
     }
 }
 
@@ -759,6 +762,15 @@ mod tests {
             url: None,
         };
         assert_eq!(event_display_category(&event), "session");
+
+        // Plugin-published events map to "plugin" category
+        let event = VibesEvent::Plugin {
+            plugin: "groove".to_string(),
+            event_type: "learning_extracted".to_string(),
+            session_id: None,
+            payload: serde_json::json!({}),
+        };
+        assert_eq!(event_display_category(&event), "plugin");
     }
 
     #[test]
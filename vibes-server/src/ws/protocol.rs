@@ -107,6 +107,9 @@ pub enum ClientMessage {
         /// Initial terminal rows (used when creating new session)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         rows: Option<u16>,
+        /// Agent to run when creating a new session (e.g. "aider"); defaults to Claude Code
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        harness: Option<String>,
     },
 
     /// Detach from a session
@@ -510,6 +513,8 @@ pub fn vibes_event_to_server_message(event: &VibesEvent) -> Option<ServerMessage
         VibesEvent::ClientConnected { .. } => None,
         VibesEvent::ClientDisconnected { .. } => None,
         VibesEvent::Hook { .. } => None,
+        VibesEvent::CapabilityDrift { .. } => None,
+        VibesEvent::Plugin { .. } => None,
     }
 }
 
@@ -596,6 +601,7 @@ mod tests {
             cwd: None,
             cols: None,
             rows: None,
+            harness: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
@@ -611,6 +617,7 @@ mod tests {
             cwd: None,
             cols: None,
             rows: None,
+            harness: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
@@ -625,8 +632,8 @@ mod tests {
         let parsed: ClientMessage = serde_json::from_str(json).unwrap();
         assert!(matches!(
             parsed,
-            ClientMessage::Attach { session_id, name, cwd, cols, rows }
-            if session_id == "sess-1" && name.is_none() && cwd.is_none() && cols.is_none() && rows.is_none()
+            ClientMessage::Attach { session_id, name, cwd, cols, rows, harness }
+            if session_id == "sess-1" && name.is_none() && cwd.is_none() && cols.is_none() && rows.is_none() && harness.is_none()
         ));
     }
 
@@ -638,6 +645,7 @@ mod tests {
             cwd: Some("/home/user/project".to_string()),
             cols: None,
             rows: None,
+            harness: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
@@ -652,8 +660,8 @@ mod tests {
         let parsed: ClientMessage = serde_json::from_str(json).unwrap();
         assert!(matches!(
             parsed,
-            ClientMessage::Attach { session_id, name, cwd, cols, rows }
-            if session_id == "sess-1" && name.is_none() && cwd.is_none() && cols.is_none() && rows.is_none()
+            ClientMessage::Attach { session_id, name, cwd, cols, rows, harness }
+            if session_id == "sess-1" && name.is_none() && cwd.is_none() && cols.is_none() && rows.is_none() && harness.is_none()
         ));
     }
 
@@ -665,6 +673,7 @@ mod tests {
             cwd: None,
             cols: Some(80),
             rows: Some(24),
+            harness: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
@@ -673,6 +682,22 @@ mod tests {
         assert!(json.contains(r#""rows":24"#));
     }
 
+    #[test]
+    fn test_client_message_attach_with_harness_roundtrip() {
+        let msg = ClientMessage::Attach {
+            session_id: "sess-1".to_string(),
+            name: None,
+            cwd: None,
+            cols: None,
+            rows: None,
+            harness: Some("aider".to_string()),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, parsed);
+        assert!(json.contains(r#""harness":"aider""#));
+    }
+
     #[test]
     fn test_client_message_detach_roundtrip() {
         let msg = ClientMessage::Detach {
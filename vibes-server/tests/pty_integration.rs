@@ -323,6 +323,42 @@ async fn immediate_pty_output_is_received_on_new_session() {
     }
 }
 
+#[tokio::test]
+async fn attach_with_unknown_harness_is_rejected() {
+    let (_state, addr) = common::create_test_server_with_pty_config(test_pty_config()).await;
+    let mut client = TestClient::connect(addr).await;
+
+    let session_id = Uuid::new_v4().to_string();
+    client
+        .conn
+        .send_json(&serde_json::json!({
+            "type": "attach",
+            "session_id": session_id,
+            "harness": "rm",
+        }))
+        .await;
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        let Some(text) = client.conn.recv_timeout(Duration::from_millis(50)).await else {
+            continue;
+        };
+        let msg: serde_json::Value = serde_json::from_str(&text).unwrap();
+        if msg["type"] == "error" {
+            assert_eq!(msg["code"], "PTY_CREATE_FAILED");
+            assert!(
+                msg["message"]
+                    .as_str()
+                    .unwrap()
+                    .contains("Unknown harness: rm")
+            );
+            return;
+        }
+        assert_ne!(msg["type"], "attach_ack", "unknown harness must not attach");
+    }
+    panic!("Expected an error for the unknown harness");
+}
+
 #[tokio::test]
 async fn ctrl_c_terminates_pty_process() {
     // Test that Ctrl+C (byte 0x03) is correctly sent to PTY and triggers SIGINT
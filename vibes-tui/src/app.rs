@@ -11,12 +11,15 @@ use ratatui::{
 };
 
 use crate::client::{ReconnectConfig, TuiClient};
+use vibes_plugin_api::PluginTui;
+
 use crate::commands::{
-    CommandInput, CommandRegistry, CommandResult, SettingsCommand, ThemeCommand,
+    CommandInput, CommandRegistry, CommandResult, PluginCommand, SettingsCommand, ThemeCommand,
 };
 use crate::keybindings::{Action, KeyBindings};
+use crate::plugin_ui::{PluginUi, PluginUiUpdate};
 use crate::views::{
-    AgentView, DashboardView, SettingsView, SwarmView, View, ViewRenderer, ViewStack,
+    AgentView, DashboardView, PluginView, SettingsView, SwarmView, View, ViewRenderer, ViewStack,
 };
 use crate::widgets::{
     ActivityEvent, ActivityFeedWidget, CommandBarWidget, ConnectionStatus, SessionInfo,
@@ -61,6 +64,10 @@ pub struct App {
     pub theme_loader: ThemeLoader,
     /// Settings view state (when in settings view).
     pub settings_state: Option<SettingsState>,
+    /// Plugin-contributed views and their data.
+    pub plugin_ui: PluginUi,
+    /// Names of commands registered from plugins.
+    plugin_commands: Vec<String>,
 }
 
 impl App {
@@ -90,6 +97,8 @@ impl App {
             command_registry,
             theme_loader: loader,
             settings_state: None,
+            plugin_ui: PluginUi::new(),
+            plugin_commands: Vec::new(),
         }
     }
 
@@ -126,6 +135,8 @@ impl App {
             command_registry,
             theme_loader: loader,
             settings_state: None,
+            plugin_ui: PluginUi::new(),
+            plugin_commands: Vec::new(),
         }
     }
 
@@ -158,6 +169,8 @@ impl App {
             command_registry,
             theme_loader: loader,
             settings_state: None,
+            plugin_ui: PluginUi::new(),
+            plugin_commands: Vec::new(),
         }
     }
 
@@ -166,6 +179,8 @@ impl App {
         let loader = ThemeLoader::from_default_config();
         let theme = loader.active().cloned().unwrap_or_else(vibes_default);
         let command_registry = Self::create_command_registry(loader.clone());
+        let mut plugin_ui = PluginUi::new();
+        plugin_ui.set_server_url(&url);
 
         Self {
             state: AppState::default(),
@@ -190,6 +205,8 @@ impl App {
             command_registry,
             theme_loader: loader,
             settings_state: None,
+            plugin_ui,
+            plugin_commands: Vec::new(),
         }
     }
 
@@ -353,6 +370,8 @@ impl App {
             Action::NavigateUp => {
                 if self.views.current == View::Dashboard {
                     self.session_widget.select_prev();
+                } else if matches!(self.views.current, View::Plugin { .. }) {
+                    self.plugin_ui.select_prev();
                 } else if self.views.current == View::Settings
                     && let Some(ref mut settings) = self.settings_state
                 {
@@ -370,6 +389,9 @@ impl App {
             Action::NavigateDown => {
                 if self.views.current == View::Dashboard {
                     self.session_widget.select_next();
+                } else if let View::Plugin { plugin, view } = &self.views.current {
+                    let len = self.plugin_ui.selectable_len(plugin, view);
+                    self.plugin_ui.select_next(len);
                 } else if self.views.current == View::Settings
                     && let Some(ref mut settings) = self.settings_state
                 {
@@ -578,6 +600,9 @@ impl App {
             View::Agent(agent_id) => AgentView::new(agent_id.clone()).render(frame, area, self),
             View::Swarm(swarm_id) => SwarmView::new(swarm_id.clone()).render(frame, area, self),
             View::Settings => SettingsView.render(frame, area, self),
+            View::Plugin { plugin, view } => {
+                PluginView::new(plugin.clone(), view.clone(), self).render(frame, area, self)
+            }
             // Other views will be implemented in later stories
             _ => DashboardView.render(frame, area, self),
         }
@@ -602,6 +627,66 @@ impl App {
         for msg in messages {
             self.handle_server_message(msg);
         }
+
+        self.update_plugin_ui();
+    }
+
+    /// Applies finished plugin UI requests and keeps the visible plugin view fresh.
+    fn update_plugin_ui(&mut self) {
+        if self.client.as_ref().is_some_and(|c| c.is_connected()) {
+            self.plugin_ui.request_catalog();
+        }
+
+        while let Some(update) = self.plugin_ui.try_recv() {
+            match update {
+                PluginUiUpdate::Catalog(Ok(catalog)) => self.install_plugin_catalog(catalog),
+                PluginUiUpdate::Catalog(Err(e)) => {
+                    tracing::warn!("Failed to load plugin views: {}", e);
+                }
+                PluginUiUpdate::Data { path, result } => self.plugin_ui.set_data(path, result),
+                PluginUiUpdate::CommandDone { command, result } => match result {
+                    Ok(()) => self
+                        .command_input
+                        .set_message(format!("{}: done", command), false),
+                    Err(e) => self
+                        .command_input
+                        .set_message(format!("{}: {}", command, e), true),
+                },
+            }
+        }
+
+        if let View::Plugin { plugin, view } = &self.views.current
+            && self.plugin_ui.refresh_due()
+        {
+            self.plugin_ui.refresh(plugin, view);
+        }
+    }
+
+    /// Installs a freshly fetched plugin catalog and its command-bar commands.
+    ///
+    /// Plugins can't shadow built-in commands; a plugin named like one is
+    /// still browsable but gets no command.
+    pub fn install_plugin_catalog(&mut self, catalog: Vec<PluginTui>) {
+        for name in self.plugin_commands.drain(..) {
+            self.command_registry.unregister(&name);
+        }
+
+        for plugin in catalog.iter().filter(|p| !p.commands.is_empty()) {
+            if self.command_registry.get(&plugin.plugin).is_some() {
+                tracing::warn!(
+                    plugin = %plugin.plugin,
+                    "Plugin commands would shadow a built-in command, skipping"
+                );
+                continue;
+            }
+            self.command_registry.register(Box::new(PluginCommand::new(
+                plugin.plugin.clone(),
+                plugin.commands.clone(),
+            )));
+            self.plugin_commands.push(plugin.plugin.clone());
+        }
+
+        self.plugin_ui.set_catalog(catalog);
     }
 
     /// Checks the connection status and attempts reconnection if needed.
@@ -664,6 +749,7 @@ impl App {
                 self.stats_widget.connection_status = ConnectionStatus::Connected;
                 self.reconnect_attempt = 0;
                 self.error_message = None;
+                self.plugin_ui.invalidate_catalog();
 
                 // Request fresh data after reconnect
                 if let Some(client) = &self.client {
@@ -1398,4 +1484,72 @@ mod tests {
             assert!(app.settings_state.is_none());
         }
     }
+
+    // === Plugin view tests ===
+
+    fn plugin_catalog(plugin: &str) -> Vec<PluginTui> {
+        use vibes_plugin_api::{TuiCommandSpec, TuiViewSpec, TuiWidget};
+
+        vec![PluginTui {
+            plugin: plugin.to_string(),
+            views: vec![TuiViewSpec {
+                id: "gaps".into(),
+                title: "Gaps".into(),
+                widgets: vec![TuiWidget::List {
+                    title: "Gaps".into(),
+                    source: "/gaps".into(),
+                    items: String::new(),
+                    label: "/context_pattern".into(),
+                    detail: None,
+                }],
+            }],
+            commands: vec![TuiCommandSpec::open_view("gaps", "Show gaps", "gaps")],
+        }]
+    }
+
+    #[test]
+    fn install_plugin_catalog_registers_and_replaces_commands() {
+        let mut app = App::new();
+
+        app.install_plugin_catalog(plugin_catalog("groove"));
+        assert!(app.command_registry.get("groove").is_some());
+
+        app.install_plugin_catalog(vec![]);
+        assert!(app.command_registry.get("groove").is_none());
+        assert!(app.plugin_ui.catalog().is_empty());
+    }
+
+    #[test]
+    fn install_plugin_catalog_does_not_shadow_builtins() {
+        let mut app = App::new();
+
+        app.install_plugin_catalog(plugin_catalog("theme"));
+        app.install_plugin_catalog(vec![]);
+
+        // The built-in theme command survives both installs
+        assert!(app.command_registry.get("theme").is_some());
+    }
+
+    #[test]
+    fn plugin_view_navigation_moves_selection() {
+        let mut app = App::new();
+        app.install_plugin_catalog(plugin_catalog("groove"));
+        app.plugin_ui.set_data(
+            "/api/groove/gaps".into(),
+            Ok(serde_json::json!([{"context_pattern": "a"}, {"context_pattern": "b"}])),
+        );
+
+        let mut registry = std::mem::take(&mut app.command_registry);
+        registry.execute("groove gaps", &mut app);
+        app.command_registry = registry;
+        assert!(matches!(app.views.current, View::Plugin { .. }));
+
+        for _ in 0..3 {
+            app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        }
+        assert_eq!(app.plugin_ui.selected(), 1);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(app.plugin_ui.selected(), 0);
+    }
 }
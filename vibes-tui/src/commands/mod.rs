@@ -3,11 +3,13 @@
 //! Provides the infrastructure for `:command` style commands like `:theme`.
 
 mod input;
+mod plugin;
 mod registry;
 mod settings;
 mod theme;
 
 pub use input::CommandInput;
+pub use plugin::PluginCommand;
 pub use registry::CommandRegistry;
 pub use settings::SettingsCommand;
 pub use theme::ThemeCommand;
@@ -0,0 +1,157 @@
+//! Plugin command implementation.
+
+use vibes_plugin_api::{TuiAction, TuiCommandSpec};
+
+use super::{Command, CommandResult};
+use crate::App;
+use crate::views::View;
+
+/// Command-bar commands contributed by a plugin, run as `:<plugin> <name>`.
+pub struct PluginCommand {
+    plugin: String,
+    commands: Vec<TuiCommandSpec>,
+    help: String,
+}
+
+impl PluginCommand {
+    /// Creates the command for a plugin's TUI commands.
+    pub fn new(plugin: String, commands: Vec<TuiCommandSpec>) -> Self {
+        let help = format!("{} commands", plugin);
+        Self {
+            plugin,
+            commands,
+            help,
+        }
+    }
+
+    fn usage(&self) -> String {
+        let names: Vec<&str> = self.commands.iter().map(|c| c.name.as_str()).collect();
+        format!("Usage: :{} <{}>", self.plugin, names.join("|"))
+    }
+}
+
+impl Command for PluginCommand {
+    fn name(&self) -> &str {
+        &self.plugin
+    }
+
+    fn execute(&mut self, args: &[&str], app: &mut App) -> CommandResult {
+        let Some((name, rest)) = args.split_first() else {
+            return CommandResult::err(self.usage());
+        };
+        let Some(spec) = self.commands.iter().find(|c| c.name == *name) else {
+            return CommandResult::err(self.usage());
+        };
+
+        match &spec.action {
+            TuiAction::OpenView { view } => {
+                let target = View::Plugin {
+                    plugin: self.plugin.clone(),
+                    view: view.clone(),
+                };
+                app.plugin_ui.open(&self.plugin, view);
+                if app.views.current != target {
+                    app.views.push(target);
+                }
+                CommandResult::ok_empty()
+            }
+            TuiAction::Post { path } => {
+                match app.plugin_ui.post(&self.plugin, &spec.name, path, rest) {
+                    Ok(()) => CommandResult::ok(format!("{} {}…", self.plugin, spec.name)),
+                    Err(e) => CommandResult::err(e),
+                }
+            }
+        }
+    }
+
+    fn completions(&self, args: &[&str], _app: &App) -> Vec<String> {
+        let partial = match args {
+            [] => "",
+            [partial] => partial,
+            _ => return vec![],
+        };
+        self.commands
+            .iter()
+            .filter(|c| c.name.starts_with(partial))
+            .map(|c| c.name.clone())
+            .collect()
+    }
+
+    fn help(&self) -> &str {
+        &self.help
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groove_command() -> PluginCommand {
+        PluginCommand::new(
+            "groove".into(),
+            vec![
+                TuiCommandSpec::open_view("gaps", "Show gaps", "gaps"),
+                TuiCommandSpec::post("dismiss", "Dismiss a gap", "/gaps/:id/dismiss"),
+            ],
+        )
+    }
+
+    #[test]
+    fn plugin_command_is_named_after_plugin() {
+        let cmd = groove_command();
+        assert_eq!(cmd.name(), "groove");
+        assert_eq!(cmd.help(), "groove commands");
+    }
+
+    #[test]
+    fn plugin_command_opens_view() {
+        let mut cmd = groove_command();
+        let mut app = App::new();
+
+        let result = cmd.execute(&["gaps"], &mut app);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            app.views.current,
+            View::Plugin {
+                plugin: "groove".into(),
+                view: "gaps".into()
+            }
+        );
+
+        // Running it again doesn't stack the same view twice
+        cmd.execute(&["gaps"], &mut app);
+        assert_eq!(app.views.history.len(), 1);
+    }
+
+    #[test]
+    fn plugin_command_rejects_unknown_subcommand() {
+        let mut cmd = groove_command();
+        let mut app = App::new();
+
+        let result = cmd.execute(&["nope"], &mut app);
+
+        assert_eq!(result, CommandResult::err("Usage: :groove <gaps|dismiss>"));
+        assert_eq!(app.views.current, View::Dashboard);
+    }
+
+    #[test]
+    fn plugin_command_post_needs_connection() {
+        let mut cmd = groove_command();
+        let mut app = App::new();
+
+        let result = cmd.execute(&["dismiss", "gap-1"], &mut app);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn plugin_command_completes_subcommands() {
+        let cmd = groove_command();
+        let app = App::new();
+
+        assert_eq!(cmd.completions(&[], &app), vec!["gaps", "dismiss"]);
+        assert_eq!(cmd.completions(&["d"], &app), vec!["dismiss"]);
+        assert!(cmd.completions(&["dismiss", "x"], &app).is_empty());
+    }
+}
@@ -27,6 +27,11 @@ impl CommandRegistry {
             .map(|c| c.as_ref())
     }
 
+    /// Remove a command by name.
+    pub fn unregister(&mut self, name: &str) {
+        self.commands.retain(|c| c.name() != name);
+    }
+
     /// Get mutable reference for execution.
     fn get_mut(&mut self, name: &str) -> Option<&mut Box<dyn Command>> {
         self.commands.iter_mut().find(|c| c.name() == name)
@@ -158,6 +163,17 @@ mod tests {
         assert_eq!(cmd.unwrap().name(), "quit");
     }
 
+    #[test]
+    fn registry_unregister_removes_command() {
+        let mut registry = CommandRegistry::new();
+        registry.register(Box::new(EchoCommand));
+        registry.register(Box::new(QuitCommand));
+
+        registry.unregister("echo");
+
+        assert_eq!(registry.list(), vec!["quit"]);
+    }
+
     #[test]
     fn registry_get_returns_none_for_unknown() {
         let registry = CommandRegistry::new();
@@ -8,6 +8,7 @@ mod client;
 mod clipboard;
 pub mod commands;
 mod keybindings;
+mod plugin_ui;
 mod state;
 mod terminal;
 mod theme;
@@ -17,6 +18,7 @@ mod widgets;
 pub use app::App;
 pub use client::TuiClient;
 pub use keybindings::{Action, KeyBindings};
+pub use plugin_ui::{PluginUi, PluginUiUpdate, SourceData};
 pub use state::{
     AgentId, AgentState, AppState, Mode, Selection, SessionId, SettingsFocus, SettingsState,
     SwarmId, SwarmState,
@@ -26,7 +28,7 @@ pub use theme::{
     Theme, ThemeConfig, ThemeConfigRaw, ThemeLoadError, ThemeLoader, ThemeSection, parse_hex_color,
     vibes_default,
 };
-pub use views::{DashboardView, View, ViewRenderer, ViewStack};
+pub use views::{DashboardView, PluginView, View, ViewRenderer, ViewStack};
 pub use widgets::{
     ActivityEvent, ActivityFeedWidget, SessionInfo, SessionListWidget, SessionStatus,
 };
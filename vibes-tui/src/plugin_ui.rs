@@ -0,0 +1,431 @@
+//! Plugin-contributed views for the TUI.
+//!
+//! Plugins describe views declaratively (see `vibes_plugin_api::tui`). This
+//! module fetches those descriptions, and the JSON behind each widget, from
+//! the daemon's HTTP API. Requests run on background tasks and report back
+//! through a channel that the event loop drains on every tick.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::mpsc;
+use vibes_plugin_api::{PluginTui, TuiViewSpec, TuiWidget};
+
+/// How often the visible plugin view is refetched.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// State of one widget data source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourceData {
+    Loading,
+    Ready(Value),
+    Failed(String),
+}
+
+/// Result of a background request.
+#[derive(Debug)]
+pub enum PluginUiUpdate {
+    /// The list of plugin views and commands.
+    Catalog(Result<Vec<PluginTui>, String>),
+    /// Data for a widget source, keyed by its full path.
+    Data {
+        path: String,
+        result: Result<Value, String>,
+    },
+    /// A command-bar `POST` finished.
+    CommandDone {
+        command: String,
+        result: Result<(), String>,
+    },
+}
+
+/// Fetches and holds plugin views and their data.
+#[derive(Debug)]
+pub struct PluginUi {
+    /// Daemon HTTP base URL (None = offline; nothing is fetched).
+    base_url: Option<String>,
+    http: reqwest::Client,
+    catalog: Vec<PluginTui>,
+    catalog_requested: bool,
+    /// Widget data keyed by full path (`/api/<plugin><source>`).
+    data: HashMap<String, SourceData>,
+    /// Selected row in the current view's first list or table.
+    selected: usize,
+    last_refresh: Option<Instant>,
+    tx: mpsc::UnboundedSender<PluginUiUpdate>,
+    rx: mpsc::UnboundedReceiver<PluginUiUpdate>,
+}
+
+impl PluginUi {
+    /// Creates an offline instance with no plugin views.
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            base_url: None,
+            http: reqwest::Client::new(),
+            catalog: Vec::new(),
+            catalog_requested: false,
+            data: HashMap::new(),
+            selected: 0,
+            last_refresh: None,
+            tx,
+            rx,
+        }
+    }
+
+    /// Points requests at the daemon behind the given WebSocket URL.
+    pub fn set_server_url(&mut self, ws_url: &str) {
+        self.base_url = http_base_url(ws_url);
+        self.catalog_requested = false;
+    }
+
+    /// All plugin contributions, sorted by plugin name.
+    pub fn catalog(&self) -> &[PluginTui] {
+        &self.catalog
+    }
+
+    /// Replaces the catalog with a freshly fetched one.
+    pub fn set_catalog(&mut self, catalog: Vec<PluginTui>) {
+        self.catalog = catalog;
+    }
+
+    /// Finds a plugin's view.
+    pub fn view(&self, plugin: &str, id: &str) -> Option<&TuiViewSpec> {
+        self.catalog
+            .iter()
+            .find(|p| p.plugin == plugin)
+            .and_then(|p| p.view(id))
+    }
+
+    /// Data for a widget source of `plugin`, if it was ever requested.
+    pub fn data(&self, plugin: &str, source: &str) -> Option<&SourceData> {
+        self.data.get(&plugin_path(plugin, source))
+    }
+
+    /// Stores the result of a data request.
+    pub fn set_data(&mut self, path: String, result: Result<Value, String>) {
+        let data = match result {
+            Ok(value) => SourceData::Ready(value),
+            Err(e) => SourceData::Failed(e),
+        };
+        self.data.insert(path, data);
+    }
+
+    /// Selected row in the current view.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Moves the selection down, stopping at the last of `len` rows.
+    pub fn select_next(&mut self, len: usize) {
+        if self.selected + 1 < len {
+            self.selected += 1;
+        }
+    }
+
+    /// Moves the selection up.
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Number of rows in the view's first list or table.
+    pub fn selectable_len(&self, plugin: &str, id: &str) -> usize {
+        let Some(spec) = self.view(plugin, id) else {
+            return 0;
+        };
+        spec.widgets
+            .iter()
+            .find_map(|widget| match widget {
+                TuiWidget::List { source, items, .. } | TuiWidget::Table { source, items, .. } => {
+                    Some(match self.data(plugin, source) {
+                        Some(SourceData::Ready(value)) => items_at(value, items).len(),
+                        _ => 0,
+                    })
+                }
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
+    /// Prepares a view for display: resets the selection and fetches its data.
+    pub fn open(&mut self, plugin: &str, id: &str) {
+        self.selected = 0;
+        self.refresh(plugin, id);
+    }
+
+    /// Whether the visible view's data is stale.
+    pub fn refresh_due(&self) -> bool {
+        self.last_refresh
+            .is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    /// Requests the catalog once per connection.
+    pub fn request_catalog(&mut self) {
+        let Some(base) = self.base_url.clone() else {
+            return;
+        };
+        if self.catalog_requested {
+            return;
+        }
+        self.catalog_requested = true;
+
+        let http = self.http.clone();
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            let result = get_json(&http, &format!("{}/api/plugins/tui", base))
+                .await
+                .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()));
+            let _ = tx.send(PluginUiUpdate::Catalog(result));
+        });
+    }
+
+    /// Forgets the catalog so it is fetched again (e.g. after reconnecting).
+    pub fn invalidate_catalog(&mut self) {
+        self.catalog_requested = false;
+    }
+
+    /// Fetches every source used by a view.
+    pub fn refresh(&mut self, plugin: &str, id: &str) {
+        self.last_refresh = Some(Instant::now());
+        let Some(base) = self.base_url.clone() else {
+            return;
+        };
+        let Some(spec) = self.view(plugin, id) else {
+            return;
+        };
+
+        let mut paths: Vec<String> = spec
+            .widgets
+            .iter()
+            .map(|w| plugin_path(plugin, w.source()))
+            .collect();
+        paths.sort();
+        paths.dedup();
+
+        for path in paths {
+            self.data.entry(path.clone()).or_insert(SourceData::Loading);
+            let http = self.http.clone();
+            let tx = self.tx.clone();
+            let url = format!("{}{}", base, path);
+            tokio::spawn(async move {
+                let result = get_json(&http, &url).await;
+                let _ = tx.send(PluginUiUpdate::Data { path, result });
+            });
+        }
+    }
+
+    /// Sends a command-bar `POST`, filling `:param` segments from `args`.
+    pub fn post(
+        &self,
+        plugin: &str,
+        command: &str,
+        path: &str,
+        args: &[&str],
+    ) -> Result<(), String> {
+        let base = self
+            .base_url
+            .clone()
+            .ok_or_else(|| "Not connected to the daemon".to_string())?;
+        let url = format!("{}{}", base, plugin_path(plugin, &fill_params(path, args)?));
+
+        let http = self.http.clone();
+        let tx = self.tx.clone();
+        let command = format!("{} {}", plugin, command);
+        tokio::spawn(async move {
+            let result = match http.post(&url).send().await {
+                Ok(response) if response.status().is_success() => Ok(()),
+                Ok(response) => Err(error_message(response).await),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = tx.send(PluginUiUpdate::CommandDone { command, result });
+        });
+        Ok(())
+    }
+
+    /// Takes the next finished request without blocking.
+    pub fn try_recv(&mut self) -> Option<PluginUiUpdate> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl Default for PluginUi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// GET a URL and parse the body as JSON.
+async fn get_json(http: &reqwest::Client, url: &str) -> Result<Value, String> {
+    let response = http.get(url).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(error_message(response).await);
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+/// Human-readable error for a failed response, preferring its `error` field.
+async fn error_message(response: reqwest::Response) -> String {
+    let status = response.status();
+    let body: Option<Value> = response.json().await.ok();
+    match body.as_ref().and_then(|b| b["error"].as_str()) {
+        Some(error) => format!("HTTP {}: {}", status.as_u16(), error),
+        None => format!("HTTP {}", status.as_u16()),
+    }
+}
+
+/// HTTP base URL of the daemon behind a WebSocket URL.
+///
+/// `ws://127.0.0.1:7432/ws` becomes `http://127.0.0.1:7432`.
+pub fn http_base_url(ws_url: &str) -> Option<String> {
+    let (scheme, rest) = if let Some(rest) = ws_url.strip_prefix("wss://") {
+        ("https", rest)
+    } else {
+        ("http", ws_url.strip_prefix("ws://")?)
+    };
+    let authority = rest.split('/').next().filter(|a| !a.is_empty())?;
+    Some(format!("{}://{}", scheme, authority))
+}
+
+/// Full API path of a plugin route.
+pub fn plugin_path(plugin: &str, path: &str) -> String {
+    format!("/api/{}{}", plugin, path)
+}
+
+/// Fill `:param` segments of a route path from positional arguments.
+pub fn fill_params(path: &str, args: &[&str]) -> Result<String, String> {
+    let mut args = args.iter();
+    let segments = path
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => args
+                .next()
+                .map(|arg| arg.to_string())
+                .ok_or_else(|| format!("Missing <{}>", name)),
+            None => Ok(segment.to_string()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(segments.join("/"))
+}
+
+/// Items of the array at `pointer` (empty if it isn't an array).
+pub fn items_at<'a>(value: &'a Value, pointer: &str) -> &'a [Value] {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+/// Display text for the value at `pointer`.
+pub fn text_at(value: &Value, pointer: &str) -> String {
+    match value.pointer(pointer) {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) if n.is_f64() => format!("{:.2}", n.as_f64().unwrap_or_default()),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn http_base_url_converts_websocket_urls() {
+        assert_eq!(
+            http_base_url("ws://127.0.0.1:7432/ws").as_deref(),
+            Some("http://127.0.0.1:7432")
+        );
+        assert_eq!(
+            http_base_url("wss://vibes.example.com/ws").as_deref(),
+            Some("https://vibes.example.com")
+        );
+        assert_eq!(http_base_url("127.0.0.1:7432"), None);
+    }
+
+    #[test]
+    fn fill_params_uses_args_in_order() {
+        assert_eq!(
+            fill_params("/gaps/:id/apply/:solution", &["g1", "2"]).unwrap(),
+            "/gaps/g1/apply/2"
+        );
+        assert_eq!(fill_params("/reset", &[]).unwrap(), "/reset");
+        assert_eq!(
+            fill_params("/gaps/:id/dismiss", &[]).unwrap_err(),
+            "Missing <id>"
+        );
+    }
+
+    #[test]
+    fn text_at_formats_values() {
+        let value = json!({"name": "fmt", "count": 3, "score": 0.8512, "gone": null});
+
+        assert_eq!(text_at(&value, "/name"), "fmt");
+        assert_eq!(text_at(&value, "/count"), "3");
+        assert_eq!(text_at(&value, "/score"), "0.85");
+        assert_eq!(text_at(&value, "/gone"), "-");
+        assert_eq!(text_at(&value, "/missing"), "-");
+    }
+
+    #[test]
+    fn items_at_reads_arrays() {
+        let value = json!({"learnings": [{"id": 1}, {"id": 2}]});
+
+        assert_eq!(items_at(&value, "/learnings").len(), 2);
+        assert!(items_at(&value, "/missing").is_empty());
+        assert_eq!(items_at(&json!([1, 2, 3]), "").len(), 3);
+    }
+
+    fn catalog() -> Vec<PluginTui> {
+        vec![PluginTui {
+            plugin: "groove".into(),
+            views: vec![TuiViewSpec {
+                id: "gaps".into(),
+                title: "Gaps".into(),
+                widgets: vec![TuiWidget::Table {
+                    title: "Gaps".into(),
+                    source: "/gaps".into(),
+                    items: String::new(),
+                    columns: vec![],
+                }],
+            }],
+            commands: vec![],
+        }]
+    }
+
+    #[test]
+    fn selection_is_bounded_by_loaded_rows() {
+        let mut ui = PluginUi::new();
+        ui.set_catalog(catalog());
+        assert_eq!(ui.selectable_len("groove", "gaps"), 0);
+
+        ui.set_data("/api/groove/gaps".into(), Ok(json!([{}, {}])));
+        let len = ui.selectable_len("groove", "gaps");
+        assert_eq!(len, 2);
+
+        ui.select_next(len);
+        ui.select_next(len);
+        assert_eq!(ui.selected(), 1);
+        ui.select_prev();
+        ui.select_prev();
+        assert_eq!(ui.selected(), 0);
+    }
+
+    #[test]
+    fn offline_instance_does_not_fetch() {
+        let mut ui = PluginUi::new();
+        ui.set_catalog(catalog());
+
+        ui.request_catalog();
+        ui.open("groove", "gaps");
+
+        assert!(ui.data("groove", "/gaps").is_none());
+        assert!(
+            ui.post("groove", "dismiss", "/gaps/:id/dismiss", &["x"])
+                .is_err()
+        );
+        assert!(ui.try_recv().is_none());
+    }
+}
@@ -5,9 +5,11 @@
 //! - `ViewStack` for stack-based navigation
 //! - `ViewRenderer` trait for custom view rendering
 //! - Built-in view implementations (Dashboard, Agent, etc.)
+//! - `PluginView` for views declared by plugins
 
 mod agent;
 mod dashboard;
+mod plugin;
 mod settings;
 mod stack;
 mod swarm;
@@ -15,6 +17,7 @@ mod traits;
 
 pub use agent::AgentView;
 pub use dashboard::DashboardView;
+pub use plugin::PluginView;
 pub use settings::SettingsView;
 pub use stack::{View, ViewStack};
 pub use swarm::SwarmView;
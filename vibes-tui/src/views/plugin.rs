@@ -0,0 +1,251 @@
+//! Plugin view - renders a view declared by a plugin.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, List, ListItem, ListState, Paragraph, Row, Sparkline, Table, TableState,
+    },
+};
+use serde_json::Value;
+use vibes_plugin_api::{TuiColumn, TuiField, TuiWidget};
+
+use super::traits::ViewRenderer;
+use crate::plugin_ui::{SourceData, items_at, text_at};
+use crate::{App, Theme};
+
+/// Height of a sparkline widget, including its border.
+const SPARKLINE_HEIGHT: u16 = 6;
+
+/// A view contributed by a plugin, drawn from its declarative spec.
+#[derive(Debug, Clone)]
+pub struct PluginView {
+    plugin: String,
+    view: String,
+    title: String,
+}
+
+impl PluginView {
+    /// Creates a renderer for a plugin's view.
+    pub fn new(plugin: String, view: String, app: &App) -> Self {
+        let title = app
+            .plugin_ui
+            .view(&plugin, &view)
+            .map(|spec| spec.title.clone())
+            .unwrap_or_else(|| view.clone());
+        Self {
+            plugin,
+            view,
+            title,
+        }
+    }
+
+    /// Renders one widget; `selected` is set for the view's selectable widget.
+    fn render_widget(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        widget: &TuiWidget,
+        selected: Option<usize>,
+        app: &App,
+    ) {
+        let theme = &app.theme;
+        let block = Block::default()
+            .title(format!(" {} ", widget.title()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border));
+
+        let value = match app.plugin_ui.data(&self.plugin, widget.source()) {
+            Some(SourceData::Ready(value)) => value,
+            Some(SourceData::Failed(error)) => {
+                let text = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(theme.error))
+                    .block(block);
+                frame.render_widget(text, area);
+                return;
+            }
+            Some(SourceData::Loading) | None => {
+                let text = Paragraph::new("Loading…")
+                    .style(Style::default().fg(theme.fg).add_modifier(Modifier::DIM))
+                    .block(block);
+                frame.render_widget(text, area);
+                return;
+            }
+        };
+
+        match widget {
+            TuiWidget::List {
+                items,
+                label,
+                detail,
+                ..
+            } => {
+                let rows: Vec<ListItem> = items_at(value, items)
+                    .iter()
+                    .map(|item| {
+                        let mut spans = vec![Span::styled(
+                            text_at(item, label),
+                            Style::default().fg(theme.fg),
+                        )];
+                        if let Some(detail) = detail {
+                            spans.push(Span::styled(
+                                format!("  {}", text_at(item, detail)),
+                                Style::default().fg(theme.fg).add_modifier(Modifier::DIM),
+                            ));
+                        }
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+                let list = List::new(rows)
+                    .block(block)
+                    .highlight_style(Style::default().bg(theme.selection).fg(theme.fg));
+                let mut state = ListState::default().with_selected(selected);
+                frame.render_stateful_widget(list, area, &mut state);
+            }
+            TuiWidget::Table { items, columns, .. } => {
+                let table = table(items_at(value, items), columns, theme).block(block);
+                let mut state = TableState::default().with_selected(selected);
+                frame.render_stateful_widget(table, area, &mut state);
+            }
+            TuiWidget::KeyValue { fields, .. } => {
+                let text = Paragraph::new(key_value_lines(value, fields, theme)).block(block);
+                frame.render_widget(text, area);
+            }
+            TuiWidget::Sparkline {
+                items,
+                value: field,
+                ..
+            } => {
+                let series: Vec<f64> = items_at(value, items)
+                    .iter()
+                    .filter_map(|item| item.pointer(field).and_then(Value::as_f64))
+                    .collect();
+                let data = scale_series(&series);
+                let sparkline = Sparkline::default()
+                    .block(block)
+                    .data(&data)
+                    .style(Style::default().fg(theme.accent));
+                frame.render_widget(sparkline, area);
+            }
+        }
+    }
+}
+
+/// Builds a table of items with a bold accent header.
+fn table<'a>(items: &[Value], columns: &'a [TuiColumn], theme: &Theme) -> Table<'a> {
+    let header = Row::new(columns.iter().map(|c| c.header.as_str())).style(
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows = items.iter().map(|item| {
+        Row::new(columns.iter().map(|c| text_at(item, &c.field)))
+            .style(Style::default().fg(theme.fg))
+    });
+    let widths = columns.iter().map(|c| match c.width {
+        Some(width) => Constraint::Length(width),
+        None => Constraint::Fill(1),
+    });
+
+    Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(Style::default().bg(theme.selection).fg(theme.fg))
+}
+
+/// One `label: value` line per field.
+fn key_value_lines<'a>(value: &Value, fields: &'a [TuiField], theme: &Theme) -> Vec<Line<'a>> {
+    fields
+        .iter()
+        .map(|field| {
+            Line::from(vec![
+                Span::styled(field.label.as_str(), Style::default().fg(theme.accent)),
+                Span::styled(": ", Style::default().fg(theme.fg)),
+                Span::styled(text_at(value, &field.field), Style::default().fg(theme.fg)),
+            ])
+        })
+        .collect()
+}
+
+/// Scales a series to 0..=100 for the sparkline; negative values show as 0.
+fn scale_series(series: &[f64]) -> Vec<u64> {
+    let max = series.iter().copied().fold(0.0_f64, f64::max);
+    if max <= 0.0 {
+        return vec![0; series.len()];
+    }
+    series
+        .iter()
+        .map(|v| (v.max(0.0) / max * 100.0).round() as u64)
+        .collect()
+}
+
+/// Layout constraint for a widget: fixed-size panes, lists and tables fill.
+fn constraint(widget: &TuiWidget) -> Constraint {
+    match widget {
+        TuiWidget::KeyValue { fields, .. } => Constraint::Length(fields.len() as u16 + 2),
+        TuiWidget::Sparkline { .. } => Constraint::Length(SPARKLINE_HEIGHT),
+        TuiWidget::List { .. } | TuiWidget::Table { .. } => Constraint::Fill(1),
+    }
+}
+
+impl ViewRenderer for PluginView {
+    fn render(&self, frame: &mut Frame, area: Rect, app: &App) {
+        let chunks = Layout::default()
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let title = Paragraph::new(format!("{} · {}", self.plugin, self.title))
+            .style(Style::default().fg(app.theme.fg))
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Style::default().fg(app.theme.border)),
+            );
+        frame.render_widget(title, chunks[0]);
+
+        let Some(spec) = app.plugin_ui.view(&self.plugin, &self.view) else {
+            let text = Paragraph::new(format!("{} has no view named '{}'", self.plugin, self.view))
+                .style(Style::default().fg(app.theme.error));
+            frame.render_widget(text, chunks[1]);
+            return;
+        };
+
+        let areas = Layout::default()
+            .constraints(spec.widgets.iter().map(constraint))
+            .split(chunks[1]);
+
+        // Only the first list or table follows the selection
+        let selectable = spec
+            .widgets
+            .iter()
+            .position(|w| matches!(w, TuiWidget::List { .. } | TuiWidget::Table { .. }));
+        for (i, (widget, area)) in spec.widgets.iter().zip(areas.iter()).enumerate() {
+            let selected = (selectable == Some(i)).then(|| app.plugin_ui.selected());
+            self.render_widget(frame, *area, widget, selected, app);
+        }
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugin_view_title_falls_back_to_view_id() {
+        let app = App::new();
+        let view = PluginView::new("groove".into(), "gaps".into(), &app);
+        assert_eq!(view.title(), "gaps");
+    }
+
+    #[test]
+    fn scale_series_maps_max_to_100() {
+        assert_eq!(scale_series(&[0.5, 1.0, -2.0]), vec![50, 100, 0]);
+        assert_eq!(scale_series(&[0.0, 0.0]), vec![0, 0]);
+        assert!(scale_series(&[]).is_empty());
+    }
+}
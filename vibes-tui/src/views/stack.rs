@@ -17,6 +17,11 @@ pub enum View {
     Observe,
     Evals,
     Settings,
+    /// A view declared by a plugin.
+    Plugin {
+        plugin: String,
+        view: String,
+    },
 }
 
 /// Stack-based view navigation.
@@ -91,6 +96,10 @@ mod tests {
         let _observe = View::Observe;
         let _evals = View::Evals;
         let _settings = View::Settings;
+        let _plugin = View::Plugin {
+            plugin: "groove".into(),
+            view: "gaps".into(),
+        };
     }
 
     #[test]
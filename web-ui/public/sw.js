@@ -2,7 +2,7 @@
 // This service worker handles push notifications and click events
 
 // Service Worker version for cache busting
-const SW_VERSION = '1.0.0';
+const SW_VERSION = '1.1.0';
 
 // Handle push notification events
 self.addEventListener('push', (event) => {
@@ -46,6 +46,18 @@ self.addEventListener('push', (event) => {
         data.data.url = payload.data.url;
       }
       data.data.event_type = eventType;
+
+      // Action buttons (approve/deny for permission requests). Tokens stay in
+      // notification data; only the id and label are shown.
+      if (Array.isArray(payload.actions) && payload.actions.length > 0) {
+        data.actions = payload.actions.map((a) => ({ action: a.action, title: a.title }));
+        data.data.action_tokens = {};
+        for (const a of payload.actions) {
+          if (a.token) {
+            data.data.action_tokens[a.action] = a.token;
+          }
+        }
+      }
     } catch (e) {
       console.warn('[SW] Failed to parse push data:', e);
       // Try to use the text directly
@@ -64,6 +76,7 @@ self.addEventListener('push', (event) => {
       badge: data.badge,
       tag: data.tag,
       requireInteraction: data.requireInteraction,
+      actions: data.actions,
       data: data.data,
     })
   );
@@ -76,6 +89,25 @@ self.addEventListener('notificationclick', (event) => {
   event.notification.close();
 
   const data = event.notification.data || {};
+
+  // Approve/deny answer the permission request without opening the app
+  const token = event.action && data.action_tokens?.[event.action];
+  if (token) {
+    event.waitUntil(
+      fetch('/api/push/action', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        credentials: 'include',
+        body: JSON.stringify({ token }),
+      }).then((response) => {
+        if (!response.ok) {
+          console.warn('[SW] Notification action failed:', response.status);
+        }
+      })
+    );
+    return;
+  }
+
   // Use URL from notification data, or fallback to session URL, or home
   let targetUrl = data.url || (data.session_id ? `/sessions/${data.session_id}` : '/');
 
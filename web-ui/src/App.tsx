@@ -26,7 +26,7 @@ import { ModelsPage } from './pages/Models'
 import { AgentsPage } from './pages/Agents'
 import { Traces } from './pages/Traces'
 import { NotFound } from './pages/NotFound'
-import { useAuth, useTheme } from './hooks'
+import { useAuth, useGrooveLive, useTheme } from './hooks'
 import { useWebSocket } from './hooks/useWebSocket'
 import { useGrooveSettings } from './hooks/useGrooveSettings'
 import { LearningIndicator } from './components/LearningIndicator'
@@ -41,6 +41,7 @@ function RootLayout() {
   const { settings: grooveSettings } = useGrooveSettings();
 
   const isGroovePath = location.pathname.startsWith('/groove');
+  useGrooveLive(isGroovePath);
 
   const navItems = [
     { label: 'SESSIONS', href: '/sessions', isActive: location.pathname.startsWith('/sessions') },
@@ -0,0 +1,78 @@
+/**
+ * Tests for ConflictsPanel component
+ */
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { ConflictsPanel } from './ConflictsPanel';
+import type { ConflictBrief } from '../../../hooks/useDashboard';
+
+const conflict: ConflictBrief = {
+  learning: {
+    id: 'newer-1',
+    content: 'Never use unwrap in tests',
+    insight: '',
+    scope: { Project: '/repo' },
+    confidence: 0.8,
+    created_at: '2026-10-02T00:00:00Z',
+  },
+  conflicting: {
+    id: 'older-1',
+    content: 'Use unwrap in tests',
+    insight: '',
+    scope: { Project: '/repo' },
+    confidence: 0.7,
+    created_at: '2026-09-01T00:00:00Z',
+  },
+  confidence: 0.85,
+  detected_at: '2026-10-02T00:00:00Z',
+};
+
+describe('ConflictsPanel', () => {
+  it('renders nothing without conflicts', () => {
+    const { container } = render(<ConflictsPanel conflicts={[]} onResolve={vi.fn()} />);
+
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('shows both sides of each conflict', () => {
+    render(<ConflictsPanel conflicts={[conflict]} onResolve={vi.fn()} />);
+
+    expect(screen.getByText('1 conflicting pair')).toBeInTheDocument();
+    expect(screen.getByText('Never use unwrap in tests')).toBeInTheDocument();
+    expect(screen.getByText('Use unwrap in tests')).toBeInTheDocument();
+    expect(screen.getByText('85% confidence')).toBeInTheDocument();
+  });
+
+  it('keeps the chosen side', () => {
+    const onResolve = vi.fn();
+    render(<ConflictsPanel conflicts={[conflict]} onResolve={onResolve} />);
+
+    fireEvent.click(screen.getByRole('button', { name: /keep older/i }));
+
+    expect(onResolve).toHaveBeenCalledWith({
+      learning_id: 'newer-1',
+      conflicting_id: 'older-1',
+      keep: 'older-1',
+    });
+  });
+
+  it('dismisses a false conflict', () => {
+    const onResolve = vi.fn();
+    render(<ConflictsPanel conflicts={[conflict]} onResolve={onResolve} />);
+
+    fireEvent.click(screen.getByRole('button', { name: /not a conflict/i }));
+
+    expect(onResolve).toHaveBeenCalledWith({
+      learning_id: 'newer-1',
+      conflicting_id: 'older-1',
+      keep: undefined,
+    });
+  });
+
+  it('disables actions while resolving', () => {
+    render(<ConflictsPanel conflicts={[conflict]} onResolve={vi.fn()} isResolving />);
+
+    expect(screen.getByRole('button', { name: /not a conflict/i })).toBeDisabled();
+    expect(screen.getByRole('button', { name: /keep newer/i })).toBeDisabled();
+  });
+});
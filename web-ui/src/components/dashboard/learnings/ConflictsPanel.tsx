@@ -0,0 +1,86 @@
+import type { ConflictBrief, ConflictSide, ResolveConflictRequest } from '../../../hooks/useDashboard';
+import './ConflictsPanel.css';
+
+export interface ConflictsPanelProps {
+  conflicts: ConflictBrief[];
+  isResolving?: boolean;
+  onResolve: (request: ResolveConflictRequest) => void;
+  onSelect?: (id: string) => void;
+}
+
+export function ConflictsPanel({
+  conflicts,
+  isResolving,
+  onResolve,
+  onSelect,
+}: ConflictsPanelProps) {
+  // Nothing to review; keep the learnings list uncluttered
+  if (conflicts.length === 0) {
+    return null;
+  }
+
+  const resolve = (conflict: ConflictBrief, keep?: string) =>
+    onResolve({
+      learning_id: conflict.learning.id,
+      conflicting_id: conflict.conflicting.id,
+      keep,
+    });
+
+  const renderSide = (conflict: ConflictBrief, side: ConflictSide, label: string) => (
+    <div className="conflict-item__side">
+      <button
+        type="button"
+        className="conflict-item__content"
+        onClick={() => onSelect?.(side.id)}
+      >
+        <span className="conflict-item__label">{label}</span>
+        {side.content}
+      </button>
+      <button
+        type="button"
+        className="conflict-item__button conflict-item__button--keep"
+        onClick={() => resolve(conflict, side.id)}
+        disabled={isResolving}
+        aria-label={`Keep ${label.toLowerCase()}: ${side.content}`}
+      >
+        Keep
+      </button>
+    </div>
+  );
+
+  return (
+    <section className="conflicts-panel" aria-label="Conflicting learnings">
+      <h3 className="conflicts-panel__title">
+        {conflicts.length} conflicting {conflicts.length === 1 ? 'pair' : 'pairs'}
+      </h3>
+      <p className="conflicts-panel__hint">
+        Only the newer learning is injected until a conflict is resolved.
+      </p>
+      <ul className="conflicts-panel__list" role="list">
+        {conflicts.map((conflict) => (
+          <li
+            key={`${conflict.learning.id}-${conflict.conflicting.id}`}
+            role="listitem"
+            className="conflict-item"
+          >
+            {renderSide(conflict, conflict.learning, 'Newer')}
+            {renderSide(conflict, conflict.conflicting, 'Older')}
+            <div className="conflict-item__footer">
+              <span className="conflict-item__confidence">
+                {Math.round(conflict.confidence * 100)}% confidence
+              </span>
+              <button
+                type="button"
+                className="conflict-item__button conflict-item__button--dismiss"
+                onClick={() => resolve(conflict)}
+                disabled={isResolving}
+              >
+                Not a conflict
+              </button>
+            </div>
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
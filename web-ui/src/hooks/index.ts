@@ -18,10 +18,13 @@ export {
   useDashboardOverview,
   useDashboardLearnings,
   useDashboardLearningDetail,
+  useDashboardConflicts,
+  useResolveConflict,
   useDashboardAttribution,
   useDashboardHealth,
   useDashboardStrategyDistributions,
   useDashboardStrategyOverrides,
+  useGrooveLive,
 } from './useDashboard';
 export type {
   TrendDirection,
@@ -39,6 +42,9 @@ export type {
   OverviewData,
   LearningsData,
   LearningDetailData,
+  ConflictSide,
+  ConflictBrief,
+  ConflictsData,
   AttributionEntry,
   AblationCoverage,
   AttributionData,
@@ -19,6 +19,7 @@ import {
   useDashboardHealth,
   useDashboardStrategyDistributions,
   useDashboardStrategyOverrides,
+  useGrooveLive,
 } from './useDashboard';
 
 // Mock fetch
@@ -344,3 +345,60 @@ describe('useDashboardAttribution', () => {
     expect(mockFetch).toHaveBeenCalledWith('/api/groove/dashboard/attribution?days=7');
   });
 });
+
+describe('useGrooveLive', () => {
+  class MockEventSource {
+    static instances: MockEventSource[] = [];
+    listeners = new Map<string, () => void>();
+    closed = false;
+
+    constructor(public url: string) {
+      MockEventSource.instances.push(this);
+    }
+
+    addEventListener(type: string, listener: () => void) {
+      this.listeners.set(type, listener);
+    }
+
+    close() {
+      this.closed = true;
+    }
+  }
+
+  beforeEach(() => {
+    MockEventSource.instances = [];
+    vi.stubGlobal('EventSource', MockEventSource);
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('invalidates dashboard queries when groove pushes a result', () => {
+    const queryClient = new QueryClient();
+    const invalidate = vi.spyOn(queryClient, 'invalidateQueries');
+    const wrapper = ({ children }: { children: ReactNode }) =>
+      createElement(QueryClientProvider, { client: queryClient }, children);
+
+    const { unmount } = renderHook(() => useGrooveLive(), { wrapper });
+
+    const source = MockEventSource.instances[0];
+    expect(source.url).toBe('/api/groove/live');
+    source.listeners.get('lightweight')?.();
+    source.listeners.get('checkpoint')?.();
+    vi.runAllTimers();
+
+    expect(invalidate).toHaveBeenCalledTimes(1);
+    expect(invalidate).toHaveBeenCalledWith({ queryKey: ['dashboard'] });
+
+    unmount();
+    expect(source.closed).toBe(true);
+  });
+
+  it('does not connect when disabled', () => {
+    renderHook(() => useGrooveLive(false), { wrapper: createWrapper() });
+    expect(MockEventSource.instances).toHaveLength(0);
+  });
+});
@@ -1,3 +1,4 @@
+import { useEffect } from 'react';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 
 // ============================================================================
@@ -103,6 +104,36 @@ export interface LearningDetailData {
   extraction_method: string;
 }
 
+// ─── Conflict Types ──────────────────────────────────────────────────────────
+
+export interface ConflictSide {
+  id: string;
+  content: string;
+  insight: string;
+  scope: Scope;
+  confidence: number;
+  created_at: string;
+}
+
+export interface ConflictBrief {
+  learning: ConflictSide;
+  conflicting: ConflictSide;
+  confidence: number;
+  detected_at: string;
+}
+
+export interface ConflictsData {
+  data_type: 'conflicts';
+  conflicts: ConflictBrief[];
+  total: number;
+}
+
+export interface ResolveConflictRequest {
+  learning_id: string;
+  conflicting_id: string;
+  keep?: string;
+}
+
 // ─── Attribution Types ───────────────────────────────────────────────────────
 
 export interface AttributionEntry {
@@ -316,6 +347,42 @@ export function useDashboardLearningDetail(id?: string) {
   });
 }
 
+export function useDashboardConflicts() {
+  return useQuery<ConflictsData>({
+    queryKey: ['dashboard', 'conflicts'],
+    queryFn: async () => {
+      const response = await fetch('/api/groove/dashboard/conflicts');
+      if (!response.ok) {
+        throw new Error('Failed to fetch conflicts');
+      }
+      return response.json();
+    },
+    refetchInterval: 30000,
+  });
+}
+
+export function useResolveConflict() {
+  const queryClient = useQueryClient();
+
+  return useMutation<void, Error, ResolveConflictRequest>({
+    mutationFn: async (request: ResolveConflictRequest) => {
+      const response = await fetch('/api/groove/learnings/conflicts/resolve', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(request),
+      });
+      if (!response.ok) {
+        const error = await response.json();
+        throw new Error(error.error || 'Failed to resolve conflict');
+      }
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['dashboard', 'conflicts'] });
+      queryClient.invalidateQueries({ queryKey: ['dashboard', 'learnings'] });
+    },
+  });
+}
+
 export function useDashboardAttribution(days?: number) {
   const params = days ? `?days=${days}` : '';
 
@@ -654,3 +721,45 @@ export function useOpenWorldActivity() {
     refetchInterval: 10000, // Poll more frequently for activity
   });
 }
+
+// ============================================================================
+// Live Updates
+// ============================================================================
+
+/** Assessment result types groove pushes over its live feed */
+const LIVE_EVENT_TYPES = ['lightweight', 'checkpoint', 'session_end'] as const;
+
+/** Coalesce bursts of live events into one refresh */
+const LIVE_REFRESH_DELAY_MS = 1000;
+
+/**
+ * Refresh dashboard queries when groove pushes assessment results over its
+ * `/api/groove/live` server-sent events feed, rather than waiting for the
+ * next poll. Polling stays on as a fallback if the feed is unavailable.
+ */
+export function useGrooveLive(enabled = true) {
+  const queryClient = useQueryClient();
+
+  useEffect(() => {
+    if (!enabled || typeof EventSource === 'undefined') return;
+
+    const source = new EventSource('/api/groove/live');
+    let timer: ReturnType<typeof setTimeout> | null = null;
+    const refresh = () => {
+      if (timer) return;
+      timer = setTimeout(() => {
+        timer = null;
+        queryClient.invalidateQueries({ queryKey: ['dashboard'] });
+      }, LIVE_REFRESH_DELAY_MS);
+    };
+
+    for (const type of LIVE_EVENT_TYPES) {
+      source.addEventListener(type, refresh);
+    }
+
+    return () => {
+      if (timer) clearTimeout(timer);
+      source.close();
+    };
+  }, [enabled, queryClient]);
+}
@@ -19,7 +19,7 @@ export type ClientMessage =
   | { type: 'list_models'; request_id: string }
   | { type: 'kill_session'; session_id: string }
   // PTY messages (preferred)
-  | { type: 'attach'; session_id: string; name?: string; cols?: number; rows?: number }
+  | { type: 'attach'; session_id: string; name?: string; cols?: number; rows?: number; harness?: string }
   | { type: 'detach'; session_id: string }
   | { type: 'pty_input'; session_id: string; data: string }  // base64 encoded
   | { type: 'pty_resize'; session_id: string; cols: number; rows: number }
@@ -100,7 +100,16 @@ export type VibesEvent =
   | { type: 'tunnel_state_changed'; state: string; url?: string }
   | { type: 'ownership_transferred'; session_id: string; new_owner_id: string }
   | { type: 'session_removed'; session_id: string; reason: string }
-  | { type: 'hook'; session_id?: string; event: HookEvent };
+  | { type: 'hook'; session_id?: string; event: HookEvent }
+  | { type: 'capability_drift'; harness: string; changes: CapabilityChange[] }
+  | { type: 'plugin'; plugin: string; event_type: string; session_id?: string; payload: unknown };
+
+// Matches vibes-introspection/src/drift.rs
+export type CapabilityChange =
+  | { kind: 'version_changed'; from?: string | null; to?: string | null }
+  | { kind: 'hooks_removed' | 'hooks_added'; scope: string; hooks: { hook_type: string; name: string; path: string }[] }
+  | { kind: 'config_file_added' | 'config_file_removed' | 'config_file_changed'; path: string }
+  | { kind: 'injection_target_added' | 'injection_target_removed'; path: string };
 
 export type HookEvent =
   | { type: 'pre_tool_use'; tool_name: string; input: string; session_id?: string }
@@ -7,7 +7,7 @@ import type { VibesEvent } from '../lib/types';
 import { extractTimestampFromUuidv7 } from '../lib/uuidv7';
 import './Firehose.css';
 
-const EVENT_TYPES = ['SESSION', 'HOOK', 'ERROR'] as const;
+const EVENT_TYPES = ['SESSION', 'HOOK', 'PLUGIN', 'ERROR'] as const;
 
 interface SessionInfo {
   id: string;
@@ -37,6 +37,10 @@ function toDisplayEvent(event: VibesEvent, eventId: string): DisplayEvent {
       return { ...baseEvent, type: 'CLAUDE', session: event.session_id, summary: `Permission: ${event.approved ? 'approved' : 'denied'}` };
     case 'hook':
       return { ...baseEvent, type: 'HOOK', session: event.session_id, summary: summarizeHookEvent(event.event) };
+    case 'capability_drift':
+      return { ...baseEvent, type: 'HOOK', summary: `${event.harness} drift: ${event.changes.map(c => c.kind).join(', ')}` };
+    case 'plugin':
+      return { ...baseEvent, type: 'PLUGIN', session: event.session_id, summary: `${event.plugin}.${event.event_type}` };
     case 'client_connected':
       return { ...baseEvent, type: 'SESSION', summary: `Client connected: ${event.client_id}` };
     case 'client_disconnected':
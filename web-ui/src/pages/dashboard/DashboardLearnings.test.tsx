@@ -65,6 +65,40 @@ const mockDetailData = {
   extraction_method: 'explicit_instruction',
 };
 
+const mockConflictsData = {
+  data_type: 'conflicts',
+  conflicts: [
+    {
+      learning: {
+        id: '3',
+        content: 'Never use unwrap in tests',
+        insight: '',
+        scope: { Project: 'vibes' },
+        confidence: 0.8,
+        created_at: '2024-01-16T10:00:00Z',
+      },
+      conflicting: {
+        id: '4',
+        content: 'Use unwrap in tests',
+        insight: '',
+        scope: { Project: 'vibes' },
+        confidence: 0.7,
+        created_at: '2024-01-10T10:00:00Z',
+      },
+      confidence: 0.85,
+      detected_at: '2024-01-16T10:00:00Z',
+    },
+  ],
+  total: 1,
+};
+
+function respond(data: unknown) {
+  return Promise.resolve({
+    ok: true,
+    json: () => Promise.resolve(data),
+  });
+}
+
 beforeEach(() => {
   mockFetch.mockReset();
 });
@@ -104,15 +138,11 @@ describe('DashboardLearnings', () => {
   });
 
   it('shows detail when learning is selected', async () => {
-    mockFetch
-      .mockResolvedValueOnce({
-        ok: true,
-        json: () => Promise.resolve(mockLearningsData),
-      })
-      .mockResolvedValueOnce({
-        ok: true,
-        json: () => Promise.resolve(mockDetailData),
-      });
+    mockFetch.mockImplementation((url: string) => {
+      if (url === '/api/groove/dashboard/learnings/1') return respond(mockDetailData);
+      if (url === '/api/groove/dashboard/conflicts') return respond(mockConflictsData);
+      return respond(mockLearningsData);
+    });
 
     render(<DashboardLearnings />, { wrapper: createWrapper() });
 
@@ -130,6 +160,19 @@ describe('DashboardLearnings', () => {
     });
   });
 
+  it('shows pending conflicts above the list', async () => {
+    mockFetch.mockImplementation((url: string) =>
+      respond(url === '/api/groove/dashboard/conflicts' ? mockConflictsData : mockLearningsData)
+    );
+
+    render(<DashboardLearnings />, { wrapper: createWrapper() });
+
+    await waitFor(() => {
+      expect(screen.getByText('1 conflicting pair')).toBeInTheDocument();
+    });
+    expect(screen.getByText('Never use unwrap in tests')).toBeInTheDocument();
+  });
+
   it('filters learnings when filter changes', async () => {
     mockFetch.mockResolvedValue({
       ok: true,
@@ -1,10 +1,16 @@
 import { useState, useCallback } from 'react';
 import { PageHeader } from '@vibes/design-system';
-import { useDashboardLearnings, useDashboardLearningDetail } from '../../hooks';
+import {
+  useDashboardLearnings,
+  useDashboardLearningDetail,
+  useDashboardConflicts,
+  useResolveConflict,
+} from '../../hooks';
 import type { LearningsFilter } from '../../hooks/useDashboard';
 import { LearningsFilters, type SortOption } from '../../components/dashboard/learnings/LearningsFilters';
 import { LearningsList } from '../../components/dashboard/learnings/LearningsList';
 import { LearningDetail } from '../../components/dashboard/learnings/LearningDetail';
+import { ConflictsPanel } from '../../components/dashboard/learnings/ConflictsPanel';
 import './DashboardLearnings.css';
 
 export function DashboardLearnings() {
@@ -23,6 +29,9 @@ export function DashboardLearnings() {
     isLoading: detailLoading,
   } = useDashboardLearningDetail(selectedId);
 
+  const { data: conflictsData } = useDashboardConflicts();
+  const resolveConflict = useResolveConflict();
+
   const handleFilterChange = useCallback((newFilters: LearningsFilter) => {
     setFilters(newFilters);
     setSelectedId(undefined); // Clear selection when filters change
@@ -63,6 +72,12 @@ export function DashboardLearnings() {
       <div className="dashboard-learnings__layout">
         {/* Left Panel: Filters + List */}
         <div className="dashboard-learnings__left">
+          <ConflictsPanel
+            conflicts={conflictsData?.conflicts ?? []}
+            isResolving={resolveConflict.isPending}
+            onResolve={(request) => resolveConflict.mutate(request)}
+            onSelect={handleSelect}
+          />
           <LearningsFilters
             value={filters}
             sortBy={sortBy}